@@ -1,6 +1,9 @@
 //! Common utilities shared across the workspace.
 //!
-//! Currently provides basic mathematical helpers used by multiple crates.
+//! Provides text, configuration and basic mathematical helpers used by
+//! multiple crates.
+
+use anyhow::{Context, Result};
 
 /// Return trimmed model text unless it is empty or an empty quoted literal.
 ///
@@ -27,6 +30,41 @@ fn is_empty_quoted_literal(text: &str) -> bool {
     inner_start <= inner_end && text[inner_start..inner_end].trim().is_empty()
 }
 
+/// Trimmed value of the environment variable `key`, or `None` when it is
+/// unset or blank.
+///
+/// `from_env` configs use this so `FOO=` in a `.env` file means "use the
+/// default" rather than an unparsable empty value.
+pub fn env_var(key: &str) -> Option<String> {
+    std::env::var(key)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Parse the environment variable `key`, or return `default` when it is
+/// unset or blank (see [`env_var`]). `T` may fail to parse with any error
+/// that converts into [`anyhow::Error`], including `anyhow::Error` itself.
+///
+/// # Examples
+/// ```
+/// let port: u16 = common::env_or("COMMON_DOC_UNSET_PORT", 8080).unwrap();
+/// assert_eq!(port, 8080);
+/// ```
+pub fn env_or<T>(key: &str, default: T) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    match env_var(key) {
+        Some(value) => value
+            .parse()
+            .map_err(Into::into)
+            .with_context(|| format!("invalid {key}")),
+        None => Ok(default),
+    }
+}
+
 /// Compute the cosine similarity between two vectors.
 ///
 /// Returns 0.0 if either vector is empty.
@@ -52,6 +90,60 @@ pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn env_var_trims_and_skips_blank_values() {
+        // SAFETY: these keys are only touched by this test.
+        unsafe {
+            std::env::set_var("COMMON_TEST_ENV_VAR_SET", "  value ");
+            std::env::set_var("COMMON_TEST_ENV_VAR_BLANK", "   ");
+        }
+        assert_eq!(env_var("COMMON_TEST_ENV_VAR_SET").as_deref(), Some("value"));
+        assert_eq!(env_var("COMMON_TEST_ENV_VAR_BLANK"), None);
+        assert_eq!(env_var("COMMON_TEST_ENV_VAR_UNSET"), None);
+    }
+
+    #[test]
+    fn env_or_parses_or_falls_back() {
+        // SAFETY: these keys are only touched by this test.
+        unsafe {
+            std::env::set_var("COMMON_TEST_ENV_OR_SET", " 42 ");
+            std::env::set_var("COMMON_TEST_ENV_OR_BLANK", " ");
+            std::env::set_var("COMMON_TEST_ENV_OR_BAD", "many");
+        }
+        assert_eq!(env_or("COMMON_TEST_ENV_OR_SET", 7u32).unwrap(), 42);
+        assert_eq!(env_or("COMMON_TEST_ENV_OR_BLANK", 7u32).unwrap(), 7);
+        assert_eq!(env_or("COMMON_TEST_ENV_OR_UNSET", 7u32).unwrap(), 7);
+        let err = env_or("COMMON_TEST_ENV_OR_BAD", 7u32).unwrap_err();
+        assert_eq!(err.to_string(), "invalid COMMON_TEST_ENV_OR_BAD");
+    }
+
+    #[test]
+    fn env_or_accepts_anyhow_parse_errors() {
+        #[derive(Debug, PartialEq)]
+        struct Mode;
+        impl std::str::FromStr for Mode {
+            type Err = anyhow::Error;
+
+            fn from_str(value: &str) -> Result<Self> {
+                match value {
+                    "mode" => Ok(Mode),
+                    other => anyhow::bail!("unknown mode {other:?}"),
+                }
+            }
+        }
+        // SAFETY: these keys are only touched by this test.
+        unsafe {
+            std::env::set_var("COMMON_TEST_ENV_OR_MODE", "mode");
+            std::env::set_var("COMMON_TEST_ENV_OR_BAD_MODE", "other");
+        }
+        assert_eq!(env_or("COMMON_TEST_ENV_OR_MODE", Mode).unwrap(), Mode);
+        let err = env_or("COMMON_TEST_ENV_OR_BAD_MODE", Mode).unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            "invalid COMMON_TEST_ENV_OR_BAD_MODE: unknown mode \"other\""
+        );
+    }
+
     #[test]
     fn zero_similarity_for_empty() {
         assert_eq!(cosine_similarity(&[], &[]), 0.0);
@@ -25,6 +25,7 @@
   }
   const mien = document.getElementById("mien");
   const words = document.getElementById("words");
+  const liveCaption = document.getElementById("live-caption");
   const thought = document.getElementById("thought");
   const thoughtTabs = document.getElementById("thought-tabs");
   const thoughtImage = document.getElementById("thought-image");
@@ -32,6 +33,7 @@
   const swapCameraButton = document.getElementById("swap-camera");
   const player = document.getElementById("audio-player");
   const face = document.getElementById("face");
+  const mouth = document.getElementById("mouth");
   const audioQueue = [];
   const conversationLog = document.getElementById("conversation-log");
   const typescriptSourceCode = document.getElementById("typescript-source-code");
@@ -45,8 +47,10 @@
   let logAtBottom = true;
   let wordsAtBottom = true;
   let currentSpeechText = null;
+  let interruptCurrentSpeech = null;
   let currentSpeechKey = null;
   let resumeSpeechPlayback = null;
+  let lipSyncFrame = null;
 
   function animateDetails(details) {
     const summary = details.querySelector("summary");
@@ -177,9 +181,18 @@
             words.scrollTop = prevScrollTop;
             wordsAtBottom = false;
           }
-          enqueueAudio({ audio: m.data.audio || null, text: m.data.words });
+          enqueueAudio({
+            audio: m.data.audio || null,
+            text: m.data.words,
+            visemes: m.data.visemes || null,
+          });
           break;
         }
+        case "SpeechPlayback":
+          if (m.data.status === "Interrupted") {
+            stopSpeechPlayback();
+          }
+          break;
         case "Think":
           handleThink(m);
           break;
@@ -190,7 +203,11 @@
           setSystemPrompt(m.data);
           updateConversation();
           break;
+        case "Interim":
+          showLiveCaption(m.data);
+          break;
         case "ConversationEntry":
+          if (m.data.role === "user") clearLiveCaption();
           conversationMsgs.push(m.data);
           updateConversation();
           break;
@@ -206,6 +223,18 @@
     }
   }
 
+  function showLiveCaption(interim) {
+    if (!liveCaption) return;
+    liveCaption.textContent = interim.text || "";
+    liveCaption.classList.toggle("stable", !!interim.stable);
+  }
+
+  function clearLiveCaption() {
+    if (!liveCaption) return;
+    liveCaption.textContent = "";
+    liveCaption.classList.remove("stable");
+  }
+
   function enqueueAudio(item) {
     const key = speechQueueKey(item);
     if (key && (currentSpeechKey === key || audioQueue.some((queued) => queued.key === key))) {
@@ -236,7 +265,37 @@
     document.addEventListener("click", resumeSpeechPlayback, { once: true });
   }
 
+  // Shape the mouth from the viseme cue under the player's position, so
+  // the face stays in sync even when playback stalls or starts late.
+  function startLipSync(visemes) {
+    stopLipSync();
+    if (!mouth || !visemes || !visemes.length) return;
+    const step = () => {
+      const ms = player.currentTime * 1000;
+      const cue = visemes.find((c) => ms >= c.start_ms && ms < c.end_ms);
+      mouth.dataset.viseme = cue ? cue.viseme : "sil";
+      lipSyncFrame = requestAnimationFrame(step);
+    };
+    lipSyncFrame = requestAnimationFrame(step);
+  }
+
+  function stopLipSync() {
+    if (lipSyncFrame !== null) {
+      cancelAnimationFrame(lipSyncFrame);
+      lipSyncFrame = null;
+    }
+    if (mouth) mouth.dataset.viseme = "sil";
+  }
+
+  function stopSpeechPlayback() {
+    audioQueue.length = 0;
+    if (interruptCurrentSpeech) {
+      interruptCurrentSpeech();
+    }
+  }
+
   function playNext() {
+    interruptCurrentSpeech = null;
     const next = audioQueue.shift();
     if (!next) {
       playing = false;
@@ -274,6 +333,7 @@
     const done = (status) => {
       if (settled) return;
       settled = true;
+      stopLipSync();
       player.removeEventListener("ended", onEnded);
       player.removeEventListener("error", onError);
       player.removeEventListener("error", speakWithBrowserVoice);
@@ -296,6 +356,13 @@
     };
     const onEnded = () => done("Finished");
     const onError = () => done("Interrupted");
+    interruptCurrentSpeech = () => {
+      player.pause();
+      if ("speechSynthesis" in window) {
+        window.speechSynthesis.cancel();
+      }
+      done("Interrupted");
+    };
     const speakWithBrowserVoice = () => {
       if (settled || usingSpeechSynthesis) return;
       usingSpeechSynthesis = true;
@@ -315,7 +382,10 @@
     };
     const tryAudioPlayback = () => {
       if (settled) return;
-      player.play().then(onStarted).catch((err) => {
+      player.play().then(() => {
+        onStarted();
+        if (!settled) startLipSync(next.visemes);
+      }).catch((err) => {
         if (err?.name === "NotAllowedError") {
           console.warn("audio autoplay blocked; speech remains queued", err);
           waitForSpeechGesture(tryAudioPlayback);
@@ -673,7 +743,11 @@
     }
     audioStarted = true;
     try {
-      const stream = await navigator.mediaDevices.getUserMedia({ audio: true });
+      // Echo cancellation and the playback echo gate keep Pete's own
+      // playback from sounding like the user barging in.
+      const stream = await navigator.mediaDevices.getUserMedia({
+        audio: { echoCancellation: true, noiseSuppression: true },
+      });
       const audioContext = new AudioContext();
       const source = audioContext.createMediaStreamSource(stream);
       const processor = audioContext.createScriptProcessor(4096, 1, 1);
@@ -703,14 +777,16 @@
         }
       };
 
+      const echoGate = createPlaybackEchoGate();
+
       processor.onaudioprocess = (event) => {
-        if (playing) {
+        const input = event.inputBuffer.getChannelData(0);
+        if (!echoGate(input, playing)) {
           queuedAudio = [];
           queuedAudioSamples = 0;
           queuedAudioStartedAt = null;
           return;
         }
-        const input = event.inputBuffer.getChannelData(0);
         const pcm = floatTo16BitPcm(resample(input, audioContext.sampleRate, targetSampleRate));
         if (!pcm.byteLength) return;
         queueAudioClip(pcm);
@@ -797,6 +873,50 @@
     return output;
   }
 
+  // While Pete is speaking the microphone still picks up some of his own
+  // playback, which would set off voice activity on the server and cut him
+  // off. Only stream audio during playback once it is clearly louder than
+  // that echo.
+  const BARGE_IN_MIN_RMS = 0.04;
+  const BARGE_IN_ECHO_RATIO = 3;
+
+  function rootMeanSquare(samples) {
+    if (!samples.length) return 0;
+    let sum = 0;
+    for (let i = 0; i < samples.length; i += 1) {
+      sum += samples[i] * samples[i];
+    }
+    return Math.sqrt(sum / samples.length);
+  }
+
+  // Returns a gate `(samples, playing) => boolean` that says whether a
+  // microphone chunk should be sent. The first chunk of each playback only
+  // measures the echo level; the gate stays open once the user is heard
+  // over Pete until playback stops.
+  function createPlaybackEchoGate() {
+    let echoLevel = null;
+    let open = false;
+    return (samples, playing) => {
+      if (!playing) {
+        echoLevel = null;
+        open = false;
+        return true;
+      }
+      if (open) return true;
+      const level = rootMeanSquare(samples);
+      if (echoLevel === null) {
+        echoLevel = level;
+        return false;
+      }
+      if (level >= BARGE_IN_MIN_RMS && level >= echoLevel * BARGE_IN_ECHO_RATIO) {
+        open = true;
+        return true;
+      }
+      echoLevel = echoLevel * 0.8 + level * 0.2;
+      return false;
+    };
+  }
+
   function arrayBufferToBase64(buffer) {
     const bytes = new Uint8Array(buffer);
     let binary = "";
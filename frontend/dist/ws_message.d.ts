@@ -64,13 +64,19 @@ export interface ConversationEntry {
   timestamp: string;
 }
 
+export interface VisemeCue {
+  viseme: string;
+  start_ms: number;
+  end_ms: number;
+}
+
 export type WsMessage =
-  | { type: "Say"; data: { words: string; audio?: string | null } }
+  | { type: "Say"; data: { words: string; audio?: string | null; visemes?: VisemeCue[] | null } }
   | { type: "Emote"; data: string }
   | { type: "Think"; data: WitReport }
   | { type: "Text"; data: { text: string; at?: string } }
   | { type: "Echo"; text: string; at?: string }
-  | { type: "See"; data: string; at?: string }
+  | { type: "See"; data: string; at?: string; source?: string }
   | { type: "Hear"; data: AudioData; at?: string }
   | { type: "Geolocate"; data: GeoLoc; at?: string }
   | { type: "Motion"; data: BrowserMotion; at?: string }
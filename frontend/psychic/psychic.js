@@ -1087,8 +1087,8 @@
   }
 
   function timelineImagePreviewElement(node, media = mediaForNode(node)) {
-    const src = media.base64 && media.mime.startsWith("image/")
-      ? timelineImageSrcForNode(node) || dataUrl(media.mime, media.base64)
+    const src = hasMedia(media) && media.mime.startsWith("image/")
+      ? timelineImageSrcForNode(node) || mediaSrc(media)
       : "";
     if (src) {
       const image = document.createElement("img");
@@ -1700,7 +1700,7 @@
     }
     requestMovieForTimelineCursor();
     const media = mediaForNode(imageNode);
-    if (media.base64 && media.mime.startsWith("image/")) {
+    if (hasMedia(media) && media.mime.startsWith("image/")) {
       presentImageNodeId = imageNode.id;
       const overlay = renderPresentImageFrame(imageNode, media);
       renderPresentSpeechOverlays(overlay, imageNode);
@@ -1736,7 +1736,7 @@
     let frame = presentMediaEl.querySelector(".present-frame");
     let image = frame?.querySelector("img");
     let overlay = frame?.querySelector(".present-speech-overlay");
-    const src = timelineImageSrcForNode(imageNode) || dataUrl(media.mime, media.base64);
+    const src = timelineImageSrcForNode(imageNode) || mediaSrc(media);
 
     if (!frame || frame.dataset.nodeId !== imageNode.id) {
       frame = document.createElement("div");
@@ -2034,11 +2034,11 @@
     visibleImageItems.forEach((item) => preloadTimelineImageNode(item.node));
 
     const loadableImageItems = visibleImageItems
-      .filter((item) => !mediaForNode(item.node).base64)
+      .filter((item) => !hasMedia(mediaForNode(item.node)))
       .filter((item) => !timelineDetailLoadingIds.has(item.node.id));
     const loadableAudioItems = visibleMediaItems
       .filter((item) => item.kind === "AudioClip")
-      .filter((item) => !mediaForNode(item.node).base64)
+      .filter((item) => !hasMedia(mediaForNode(item.node)))
       .filter((item) => !timelineDetailLoadingIds.has(item.node.id));
     const loadable = [...loadableImageItems, ...loadableAudioItems]
       .slice(0, timelineDetailLoadLimit);
@@ -2086,8 +2086,8 @@
 
   function timelineImageDataSrcForNode(node) {
     const media = mediaForNode(node);
-    if (!media.base64 || !media.mime.startsWith("image/")) return "";
-    return dataUrl(media.mime, media.base64);
+    if (!hasMedia(media) || !media.mime.startsWith("image/")) return "";
+    return mediaSrc(media);
   }
 
   function pruneTimelineImagePreloadCache(visibleIds) {
@@ -2486,16 +2486,16 @@
       preview.preload = "metadata";
       preview.dataset.audioClipId = node.id;
       preview.src = audioClipAudioSrc(node);
-    } else if (base64 && mime.startsWith("image/")) {
+    } else if (hasMedia(media) && mime.startsWith("image/")) {
       preview = document.createElement("img");
       preview.alt = nodeLabel(node);
-      preview.src = dataUrl(mime, base64);
+      preview.src = mediaSrc(media);
       if (nodeKind(node) === "FaceInstance") preview.className = "face-preview";
-    } else if (base64 && mime.startsWith("audio/")) {
+    } else if (hasMedia(media) && mime.startsWith("audio/")) {
       preview = document.createElement("audio");
       preview.controls = true;
       preview.preload = "metadata";
-      preview.src = audioSource(props, mime, base64);
+      preview.src = base64 ? audioSource(props, mime, base64) : media.src;
     } else if (base64 && mime.startsWith("video/")) {
       preview = document.createElement("video");
       preview.controls = true;
@@ -2799,12 +2799,29 @@
               : "",
       };
     }
+    const mime = String(props.mime || "").toLowerCase();
     return {
-      mime: String(props.mime || "").toLowerCase(),
+      mime,
       base64: typeof props.base64 === "string" ? props.base64.trim() : "",
+      src: blobUrl(props.blob_hash, mime),
     };
   }
 
+  // Payloads moved to the blob store are served by hash instead of inline.
+  function blobUrl(hash, mime) {
+    if (typeof hash !== "string" || !/^[0-9a-f]{64}$/.test(hash)) return "";
+    return `/blobs/${hash}${mime ? `?mime=${encodeURIComponent(mime)}` : ""}`;
+  }
+
+  function hasMedia(media) {
+    return !!(media.base64 || media.src);
+  }
+
+  function mediaSrc(media) {
+    if (media.base64) return dataUrl(media.mime, media.base64);
+    return media.src || "";
+  }
+
   function largeMediaProperty(key) {
     return key === "base64" || key === "crop_base64";
   }
@@ -3212,7 +3229,7 @@
     if (nodeKind(node) === "Face" && Array.isArray(node.properties?.face_images)) {
       return node.properties.face_images.every((faceImage) => typeof faceImage?.base64 === "string" && faceImage.base64.trim());
     }
-    return !media.mime || !!media.base64;
+    return !media.mime || hasMedia(media);
   }
 
   function findGraphNode(id) {
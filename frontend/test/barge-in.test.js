@@ -0,0 +1,45 @@
+const assert = require('assert');
+const fs = require('fs');
+
+const script = fs.readFileSync('frontend/dist/app.js', 'utf8');
+assert(script.includes('case "SpeechPlayback":'));
+assert(script.includes('if (m.data.status === "Interrupted")'));
+assert(script.includes('function stopSpeechPlayback()'));
+assert(script.includes('audioQueue.length = 0'));
+assert(script.includes('window.speechSynthesis.cancel()'));
+assert(script.includes('echoCancellation: true'));
+assert(script.includes('if (!echoGate(input, playing)) {'));
+
+// Load the echo gate on its own so it can be driven with synthetic audio.
+const start = script.indexOf('  const BARGE_IN_MIN_RMS');
+const end = script.indexOf('  function arrayBufferToBase64');
+assert(start >= 0 && end > start);
+const createPlaybackEchoGate = new Function(
+  `${script.slice(start, end)}\nreturn createPlaybackEchoGate;`,
+)();
+
+function tone(amplitude, length = 4096) {
+  const samples = new Float32Array(length);
+  for (let i = 0; i < length; i += 1) {
+    samples[i] = amplitude * Math.sin((2 * Math.PI * 220 * i) / 16000);
+  }
+  return samples;
+}
+
+// Playback alone: the residual echo of Pete's voice never reaches the server.
+let gate = createPlaybackEchoGate();
+for (let i = 0; i < 50; i += 1) {
+  const echo = tone(0.08 + 0.02 * Math.sin(i));
+  assert.strictEqual(gate(echo, true), false, `echo chunk ${i} was streamed`);
+}
+
+// The user talking clearly over the echo is streamed, and keeps streaming.
+assert.strictEqual(gate(tone(0.6), true), true);
+assert.strictEqual(gate(tone(0.05), true), true);
+
+// Without playback every chunk goes through, however quiet.
+gate = createPlaybackEchoGate();
+assert.strictEqual(gate(tone(0.001), false), true);
+assert.strictEqual(gate(tone(0.3), false), true);
+
+console.log('barge-in ok');
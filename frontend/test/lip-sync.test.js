@@ -0,0 +1,14 @@
+const assert = require('assert');
+const fs = require('fs');
+
+const script = fs.readFileSync('frontend/dist/app.js', 'utf8');
+const html = fs.readFileSync('frontend/dist/index.html', 'utf8');
+const css = fs.readFileSync('frontend/dist/styles.css', 'utf8');
+assert(html.includes('id="mouth"'));
+assert(script.includes('visemes: m.data.visemes || null'));
+assert(script.includes('function startLipSync(visemes)'));
+assert(script.includes('player.currentTime * 1000'));
+assert(script.includes('mouth.dataset.viseme = cue ? cue.viseme : "sil"'));
+assert(script.includes('stopLipSync();'));
+assert(css.includes('.mouth[data-viseme="aa"]'));
+console.log('lip-sync ok');
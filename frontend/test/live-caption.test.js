@@ -0,0 +1,11 @@
+const assert = require('assert');
+const fs = require('fs');
+
+const script = fs.readFileSync('frontend/dist/app.js', 'utf8');
+const html = fs.readFileSync('frontend/dist/index.html', 'utf8');
+assert(html.includes('id="live-caption"'));
+assert(script.includes('case "Interim":'));
+assert(script.includes('showLiveCaption(m.data)'));
+assert(script.includes('if (m.data.role === "user") clearLiveCaption();'));
+assert(script.includes('liveCaption.classList.toggle("stable", !!interim.stable)'));
+console.log('live caption ok');
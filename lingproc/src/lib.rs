@@ -1,15 +1,17 @@
 //! Linguistic processing utilities.
 //!
 //! This crate provides traits for interacting with language models, an
-//! [`OllamaProvider`] implementation, and helpers for splitting LLM output into
-//! sentences or words.
+//! [`OllamaProvider`] implementation, a priority [`LlmScheduler`] for shared
+//! hosts, and helpers for splitting LLM output into sentences or words.
 
 pub mod math;
 pub mod provider;
+pub mod scheduler;
 pub mod segment;
 pub mod types;
 
 pub use crate::math::*;
 pub use crate::provider::*;
+pub use crate::scheduler::*;
 pub use crate::segment::*;
 pub use crate::types::*;
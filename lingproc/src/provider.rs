@@ -1,6 +1,8 @@
+use crate::scheduler::{PRIORITY_HEADER, Priority};
 use crate::types::{Chatter, Doer, LlmInstruction, Message, Role, TextStream, Vectorizer};
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
+use ollama_rs::headers::{HeaderMap, HeaderValue};
 use ollama_rs::models::ModelOptions;
 use ollama_rs::{
     Ollama,
@@ -63,6 +65,17 @@ impl OllamaProvider {
         Self::new(hosts, model)
     }
 
+    /// Tag every request with `priority` in the [`PRIORITY_HEADER`], so a
+    /// scheduling proxy in front of the hosts can queue it by class.
+    pub fn with_priority_header(mut self, priority: Priority) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(PRIORITY_HEADER, HeaderValue::from_static(priority.as_str()));
+        for client in &mut self.clients {
+            client.set_headers(Some(headers.clone()));
+        }
+        self
+    }
+
     fn client(&self) -> &Ollama {
         let idx = self.next.fetch_add(1, Ordering::SeqCst) % self.clients.len();
         &self.clients[idx]
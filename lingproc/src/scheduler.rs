@@ -0,0 +1,523 @@
+//! Priority scheduling for shared language-model hosts.
+//!
+//! Every pipeline stage talks to the same one or two Ollama servers, so a
+//! burst of background labelling can starve the conversation. An
+//! [`LlmScheduler`] hands out per-host permits by [`Priority`], and
+//! [`Scheduled`] wraps any [`Doer`], [`Chatter`] or [`Vectorizer`] so each
+//! request waits for a permit before reaching the model.
+//!
+//! A scheduler only orders the requests of its own process. Workers running
+//! as separate binaries share one queue by pointing their hosts at the
+//! `llm_proxy` binary, which reads each request's class from the
+//! [`PRIORITY_HEADER`] that priority-tagged providers send.
+
+use crate::types::{Chatter, Doer, LlmInstruction, Message, TextStream, Vectorizer};
+use anyhow::Result;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+use tokio_stream::StreamExt;
+use tracing::{debug, warn};
+
+/// Default number of requests allowed to run concurrently against one host.
+pub const DEFAULT_HOST_CONCURRENCY: usize = 2;
+
+/// Default number of requests allowed to wait for one host.
+pub const DEFAULT_MAX_QUEUED: usize = 64;
+
+/// HTTP header naming a request's [`Priority`] for a scheduling proxy.
+pub const PRIORITY_HEADER: &str = "x-llm-priority";
+
+/// Importance of a language-model request, highest first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Labelling, clustering, captioning and other enrichment.
+    Background,
+    /// Situation summaries from the Combobulator.
+    Combobulation,
+    /// Decisions from the Will.
+    Will,
+    /// Pete's spoken replies.
+    Conversation,
+}
+
+impl Priority {
+    /// All priority classes from highest to lowest.
+    pub const ALL: [Priority; 4] = [
+        Priority::Conversation,
+        Priority::Will,
+        Priority::Combobulation,
+        Priority::Background,
+    ];
+
+    /// Stable lowercase name used in logs and metrics.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Priority::Conversation => "conversation",
+            Priority::Will => "will",
+            Priority::Combobulation => "combobulation",
+            Priority::Background => "background",
+        }
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Priority {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Priority::ALL
+            .into_iter()
+            .find(|p| p.as_str() == s.trim().to_ascii_lowercase())
+            .ok_or_else(|| anyhow::anyhow!("unknown LLM priority {s:?}"))
+    }
+}
+
+/// Error returned when queued work is displaced by higher-priority requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preempted {
+    /// Host the request was waiting for.
+    pub host: String,
+    /// Priority of the displaced request.
+    pub priority: Priority,
+}
+
+impl fmt::Display for Preempted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} request for {} was preempted by higher-priority work",
+            self.priority, self.host
+        )
+    }
+}
+
+impl std::error::Error for Preempted {}
+
+/// Queue statistics for one [`Priority`] class.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PriorityMetrics {
+    /// Requests currently waiting for a permit.
+    pub waiting: usize,
+    /// Requests that obtained a permit.
+    pub started: u64,
+    /// Requests dropped from the queue by higher-priority work.
+    pub preempted: u64,
+    /// Sum of time spent waiting by started requests.
+    pub total_wait: Duration,
+    /// Longest time a started request waited.
+    pub max_wait: Duration,
+}
+
+impl PriorityMetrics {
+    /// Mean queue wait of started requests.
+    pub fn mean_wait(&self) -> Duration {
+        if self.started == 0 {
+            Duration::ZERO
+        } else {
+            self.total_wait / self.started as u32
+        }
+    }
+}
+
+struct Waiter {
+    priority: Priority,
+    seq: u64,
+    enqueued: Instant,
+    grant: oneshot::Sender<Permit>,
+}
+
+#[derive(Default)]
+struct HostQueue {
+    running: usize,
+    waiting: Vec<Waiter>,
+}
+
+impl HostQueue {
+    /// Index of the next waiter to run: highest priority, then oldest.
+    fn next_index(&self) -> Option<usize> {
+        self.waiting
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.priority.cmp(&b.priority).then(b.seq.cmp(&a.seq)))
+            .map(|(i, _)| i)
+    }
+
+    /// Index of the waiter to evict: lowest priority, then newest.
+    fn victim_index(&self) -> Option<usize> {
+        self.waiting
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.priority.cmp(&b.priority).then(b.seq.cmp(&a.seq)))
+            .map(|(i, _)| i)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    hosts: HashMap<String, HostQueue>,
+    metrics: HashMap<Priority, PriorityMetrics>,
+    seq: u64,
+}
+
+struct Inner {
+    concurrency: usize,
+    host_concurrency: HashMap<String, usize>,
+    max_queued: usize,
+    preempt_on_arrival: AtomicBool,
+    state: Mutex<State>,
+}
+
+/// Grants access to language-model hosts by [`Priority`].
+///
+/// Each host runs at most its configured number of requests at once. Waiting
+/// requests start highest-priority first, so queued lower-priority work runs
+/// once the higher classes are served. Only when a host's queue is full is
+/// the lowest-priority waiter preempted with a [`Preempted`] error, unless
+/// [`with_preemption`](Self::with_preemption) also drops it whenever a
+/// higher-priority request has to wait.
+///
+/// ```
+/// use lingproc::{LlmScheduler, Priority};
+///
+/// futures::executor::block_on(async {
+/// let scheduler = LlmScheduler::new(1);
+/// let permit = scheduler.acquire("http://ollama:11434", Priority::Conversation).await.unwrap();
+/// drop(permit);
+/// let metrics = scheduler.metrics();
+/// assert_eq!(metrics[&Priority::Conversation].started, 1);
+/// });
+/// ```
+#[derive(Clone)]
+pub struct LlmScheduler {
+    inner: Arc<Inner>,
+}
+
+static GLOBAL: Lazy<LlmScheduler> = Lazy::new(LlmScheduler::from_env);
+
+impl LlmScheduler {
+    /// Create a scheduler allowing `concurrency` running requests per host.
+    pub fn new(concurrency: usize) -> Self {
+        Self::with_limits(concurrency, HashMap::new(), DEFAULT_MAX_QUEUED)
+    }
+
+    /// Create a scheduler with per-host overrides and a queue bound.
+    pub fn with_limits(
+        concurrency: usize,
+        host_concurrency: HashMap<String, usize>,
+        max_queued: usize,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                concurrency: concurrency.max(1),
+                host_concurrency,
+                max_queued,
+                preempt_on_arrival: AtomicBool::new(false),
+                state: Mutex::new(State::default()),
+            }),
+        }
+    }
+
+    /// Whether waiting higher-priority requests preempt queued lower-priority
+    /// ones. Off by default: lower-priority work then waits behind them and is
+    /// only dropped on queue overflow. Preempted requests are not retried.
+    /// Applies to every clone of this scheduler.
+    pub fn with_preemption(self, preempt_on_arrival: bool) -> Self {
+        self.inner
+            .preempt_on_arrival
+            .store(preempt_on_arrival, Ordering::Relaxed);
+        self
+    }
+
+    /// Build a scheduler from `LLM_HOST_CONCURRENCY`, `LLM_HOST_LIMITS`,
+    /// `LLM_MAX_QUEUED` and `LLM_PREEMPT_QUEUED`.
+    ///
+    /// `LLM_HOST_LIMITS` is a comma-separated list of `host=limit` pairs.
+    /// Setting `LLM_PREEMPT_QUEUED=true` drops queued lower-priority work as
+    /// soon as a higher-priority request has to wait.
+    pub fn from_env() -> Self {
+        let concurrency = std::env::var("LLM_HOST_CONCURRENCY")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_HOST_CONCURRENCY);
+        let max_queued = std::env::var("LLM_MAX_QUEUED")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MAX_QUEUED);
+        let host_concurrency = std::env::var("LLM_HOST_LIMITS")
+            .map(|v| parse_host_limits(&v))
+            .unwrap_or_default();
+        let preempt = std::env::var("LLM_PREEMPT_QUEUED")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(false);
+        Self::with_limits(concurrency, host_concurrency, max_queued).with_preemption(preempt)
+    }
+
+    /// Process-wide scheduler shared by every [`Scheduled`] provider that does
+    /// not supply its own.
+    pub fn global() -> Self {
+        GLOBAL.clone()
+    }
+
+    fn limit(&self, host: &str) -> usize {
+        self.inner
+            .host_concurrency
+            .get(host)
+            .copied()
+            .unwrap_or(self.inner.concurrency)
+            .max(1)
+    }
+
+    /// Wait for permission to send a `priority` request to `host`.
+    ///
+    /// The request runs until the returned [`Permit`] is dropped.
+    pub async fn acquire(&self, host: &str, priority: Priority) -> Result<Permit> {
+        let enqueued = Instant::now();
+        let rx = {
+            let mut state = self.inner.state.lock().unwrap();
+            let limit = self.limit(host);
+            let queue = state.hosts.entry(host.to_string()).or_default();
+            let outranked = queue.waiting.iter().any(|w| w.priority >= priority);
+            if queue.running < limit && !outranked {
+                queue.running += 1;
+                record_start(&mut state, priority, Duration::ZERO);
+                return Ok(self.permit(host, priority));
+            }
+            let (tx, rx) = oneshot::channel();
+            state.seq += 1;
+            let seq = state.seq;
+            let queue = state.hosts.get_mut(host).expect("host queue exists");
+            queue.waiting.push(Waiter {
+                priority,
+                seq,
+                enqueued,
+                grant: tx,
+            });
+            state.metrics.entry(priority).or_default().waiting += 1;
+            if self.inner.preempt_on_arrival.load(Ordering::Relaxed) {
+                self.evict_below(&mut state, host, priority);
+            }
+            self.evict_overflow(&mut state, host);
+            rx
+        };
+        // The permit travels through the channel, so if this future is
+        // dropped after the grant the permit is dropped with it and the slot
+        // is released.
+        match rx.await {
+            Ok(permit) => {
+                debug!(%host, %priority, waited_ms = enqueued.elapsed().as_millis() as u64, "LLM permit granted");
+                Ok(permit)
+            }
+            Err(_) => Err(Preempted {
+                host: host.to_string(),
+                priority,
+            }
+            .into()),
+        }
+    }
+
+    /// Preempt every waiter for `host` below `priority`.
+    fn evict_below(&self, state: &mut State, host: &str, priority: Priority) {
+        let Some(queue) = state.hosts.get_mut(host) else {
+            return;
+        };
+        let (evicted, kept) = std::mem::take(&mut queue.waiting)
+            .into_iter()
+            .partition(|w| w.priority < priority);
+        queue.waiting = kept;
+        self.record_evicted(state, host, evicted);
+    }
+
+    fn evict_overflow(&self, state: &mut State, host: &str) {
+        let max_queued = self.inner.max_queued;
+        let Some(queue) = state.hosts.get_mut(host) else {
+            return;
+        };
+        let mut evicted = Vec::new();
+        while queue.waiting.len() > max_queued {
+            let Some(idx) = queue.victim_index() else {
+                break;
+            };
+            evicted.push(queue.waiting.swap_remove(idx));
+        }
+        self.record_evicted(state, host, evicted);
+    }
+
+    /// Count `evicted` as preempted. Dropping their grant senders fails
+    /// their `acquire` with [`Preempted`].
+    fn record_evicted(&self, state: &mut State, host: &str, evicted: Vec<Waiter>) {
+        for Waiter { priority, .. } in evicted {
+            warn!(%host, %priority, "preempting queued LLM request");
+            let metrics = state.metrics.entry(priority).or_default();
+            metrics.waiting = metrics.waiting.saturating_sub(1);
+            metrics.preempted += 1;
+        }
+    }
+
+    fn permit(&self, host: &str, priority: Priority) -> Permit {
+        Permit {
+            scheduler: self.clone(),
+            host: host.to_string(),
+            priority,
+            held: true,
+        }
+    }
+
+    fn release(&self, host: &str) {
+        loop {
+            let waiter = {
+                let mut state = self.inner.state.lock().unwrap();
+                let Some(queue) = state.hosts.get_mut(host) else {
+                    return;
+                };
+                let Some(idx) = queue.next_index() else {
+                    queue.running = queue.running.saturating_sub(1);
+                    return;
+                };
+                let waiter = queue.waiting.swap_remove(idx);
+                let metrics = state.metrics.entry(waiter.priority).or_default();
+                metrics.waiting = metrics.waiting.saturating_sub(1);
+                waiter
+            };
+            // The slot passes straight to the waiter. Send outside the lock:
+            // a permit dropped on the way releases the slot again.
+            let waited = waiter.enqueued.elapsed();
+            let priority = waiter.priority;
+            match waiter.grant.send(self.permit(host, priority)) {
+                Ok(()) => {
+                    let mut state = self.inner.state.lock().unwrap();
+                    record_start(&mut state, priority, waited);
+                    return;
+                }
+                // The waiter gave up; keep the slot and offer it to the next.
+                Err(mut permit) => permit.held = false,
+            }
+        }
+    }
+
+    /// Snapshot queue metrics for every priority class.
+    pub fn metrics(&self) -> HashMap<Priority, PriorityMetrics> {
+        let state = self.inner.state.lock().unwrap();
+        Priority::ALL
+            .into_iter()
+            .map(|p| (p, state.metrics.get(&p).cloned().unwrap_or_default()))
+            .collect()
+    }
+}
+
+fn record_start(state: &mut State, priority: Priority, waited: Duration) {
+    let metrics = state.metrics.entry(priority).or_default();
+    metrics.started += 1;
+    metrics.total_wait += waited;
+    metrics.max_wait = metrics.max_wait.max(waited);
+}
+
+fn parse_host_limits(spec: &str) -> HashMap<String, usize> {
+    spec.split(',')
+        .filter_map(|pair| {
+            let (host, limit) = pair.rsplit_once('=')?;
+            Some((host.trim().to_string(), limit.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+/// Permission to run one request against a host. Dropping it frees the slot.
+pub struct Permit {
+    scheduler: LlmScheduler,
+    host: String,
+    priority: Priority,
+    held: bool,
+}
+
+impl Permit {
+    /// Priority class this permit was granted for.
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        if self.held {
+            self.scheduler.release(&self.host);
+        }
+    }
+}
+
+/// Language-model provider whose requests pass through an [`LlmScheduler`].
+///
+/// Chat permits are held until the response stream ends so a long reply still
+/// counts against the host's concurrency.
+#[derive(Clone)]
+pub struct Scheduled<P> {
+    inner: P,
+    scheduler: LlmScheduler,
+    host: String,
+    priority: Priority,
+}
+
+impl<P> Scheduled<P> {
+    /// Schedule `inner`'s requests to `host` at `priority` on the global scheduler.
+    pub fn new(inner: P, host: impl Into<String>, priority: Priority) -> Self {
+        Self::with_scheduler(inner, LlmScheduler::global(), host, priority)
+    }
+
+    /// Schedule `inner`'s requests on a specific `scheduler`.
+    pub fn with_scheduler(
+        inner: P,
+        scheduler: LlmScheduler,
+        host: impl Into<String>,
+        priority: Priority,
+    ) -> Self {
+        Self {
+            inner,
+            scheduler,
+            host: host.into(),
+            priority,
+        }
+    }
+
+    /// Priority class used for this provider's requests.
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+}
+
+#[async_trait]
+impl<P: Doer> Doer for Scheduled<P> {
+    async fn follow(&self, instruction: LlmInstruction) -> Result<String> {
+        let _permit = self.scheduler.acquire(&self.host, self.priority).await?;
+        self.inner.follow(instruction).await
+    }
+}
+
+#[async_trait]
+impl<P: Chatter> Chatter for Scheduled<P> {
+    async fn chat(&self, system_prompt: &str, history: &[Message]) -> Result<TextStream> {
+        let permit = self.scheduler.acquire(&self.host, self.priority).await?;
+        let stream = self.inner.chat(system_prompt, history).await?;
+        Ok(Box::pin(stream.map(move |chunk| {
+            let _held = &permit;
+            chunk
+        })))
+    }
+}
+
+#[async_trait]
+impl<P: Vectorizer> Vectorizer for Scheduled<P> {
+    async fn vectorize(&self, text: &str) -> Result<Vec<f32>> {
+        let _permit = self.scheduler.acquire(&self.host, self.priority).await?;
+        self.inner.vectorize(text).await
+    }
+}
@@ -68,6 +68,8 @@ pub struct ImageData {
     pub base64: String, // base64-encoded content
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub captured_at: Option<String>, // RFC3339 time when the frame was captured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>, // camera or stream that produced the frame
 }
 
 /// Use `LlmInstruction` to pass natural language plus multimedia context to the
@@ -81,6 +83,7 @@ pub struct ImageData {
 ///         mime: "image/png".to_string(),
 ///         base64: capture_base64_image(), // <- User-defined
 ///         captured_at: None,
+///         source: None,
 ///     }],
 /// };
 /// let result = doer.follow(instruction).await?;
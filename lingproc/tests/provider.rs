@@ -46,6 +46,7 @@ async fn follow_includes_images() {
                 mime: "image/jpeg".into(),
                 base64: "abcd".into(),
                 captured_at: None,
+                source: None,
             }],
         })
         .await
@@ -0,0 +1,170 @@
+use lingproc::{LlmScheduler, Preempted, Priority};
+use std::collections::HashMap;
+use std::time::Duration;
+
+const HOST: &str = "http://ollama:11434";
+
+#[tokio::test]
+async fn higher_priority_waiters_run_first() {
+    let scheduler = LlmScheduler::new(1);
+    let running = scheduler.acquire(HOST, Priority::Background).await.unwrap();
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    for priority in [Priority::Background, Priority::Will, Priority::Conversation] {
+        let scheduler = scheduler.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let permit = scheduler.acquire(HOST, priority).await.unwrap();
+            tx.send(permit.priority()).unwrap();
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        });
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    drop(running);
+
+    let mut order = Vec::new();
+    for _ in 0..3 {
+        order.push(rx.recv().await.unwrap());
+    }
+    assert_eq!(
+        order,
+        vec![Priority::Conversation, Priority::Will, Priority::Background]
+    );
+    let metrics = scheduler.metrics();
+    assert_eq!(metrics[&Priority::Background].started, 2);
+    assert!(metrics[&Priority::Background].max_wait > Duration::ZERO);
+}
+
+#[tokio::test]
+async fn full_queue_preempts_lowest_priority() {
+    let scheduler = LlmScheduler::with_limits(1, HashMap::new(), 1);
+    let running = scheduler.acquire(HOST, Priority::Will).await.unwrap();
+
+    let background = {
+        let scheduler = scheduler.clone();
+        tokio::spawn(async move { scheduler.acquire(HOST, Priority::Background).await })
+    };
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    let conversation = {
+        let scheduler = scheduler.clone();
+        tokio::spawn(async move { scheduler.acquire(HOST, Priority::Conversation).await })
+    };
+
+    let err = match background.await.unwrap() {
+        Ok(_) => panic!("background work should be preempted"),
+        Err(err) => err,
+    };
+    assert_eq!(
+        err.downcast_ref::<Preempted>().map(|p| p.priority),
+        Some(Priority::Background)
+    );
+    drop(running);
+    assert!(conversation.await.unwrap().is_ok());
+    assert_eq!(scheduler.metrics()[&Priority::Background].preempted, 1);
+}
+
+#[tokio::test]
+async fn lower_priority_work_queued_behind_a_burst_still_completes() {
+    let scheduler = LlmScheduler::new(1);
+    let running = scheduler
+        .acquire(HOST, Priority::Conversation)
+        .await
+        .unwrap();
+
+    let background = {
+        let scheduler = scheduler.clone();
+        tokio::spawn(async move {
+            let permit = scheduler.acquire(HOST, Priority::Background).await?;
+            anyhow::Ok(permit.priority())
+        })
+    };
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    let burst: Vec<_> = (0..3)
+        .map(|_| {
+            let scheduler = scheduler.clone();
+            tokio::spawn(async move {
+                let _permit = scheduler.acquire(HOST, Priority::Conversation).await?;
+                tokio::time::sleep(Duration::from_millis(2)).await;
+                anyhow::Ok(())
+            })
+        })
+        .collect();
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    drop(running);
+
+    for handle in burst {
+        handle.await.unwrap().unwrap();
+    }
+    assert_eq!(background.await.unwrap().unwrap(), Priority::Background);
+    let metrics = scheduler.metrics();
+    assert_eq!(metrics[&Priority::Background].preempted, 0);
+    assert_eq!(metrics[&Priority::Background].started, 1);
+    assert_eq!(metrics[&Priority::Conversation].started, 4);
+}
+
+#[tokio::test]
+async fn waiting_higher_priority_preempts_queued_lower_priority_when_enabled() {
+    let scheduler = LlmScheduler::new(1).with_preemption(true);
+    let running = scheduler.acquire(HOST, Priority::Will).await.unwrap();
+
+    let queued: Vec<_> = [Priority::Background, Priority::Combobulation]
+        .into_iter()
+        .map(|priority| {
+            let scheduler = scheduler.clone();
+            tokio::spawn(async move { scheduler.acquire(HOST, priority).await })
+        })
+        .collect();
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    let will = {
+        let scheduler = scheduler.clone();
+        tokio::spawn(async move { scheduler.acquire(HOST, Priority::Will).await })
+    };
+
+    for handle in queued {
+        let err = match handle.await.unwrap() {
+            Ok(_) => panic!("lower-priority work should be preempted"),
+            Err(err) => err,
+        };
+        assert!(err.downcast_ref::<Preempted>().is_some());
+    }
+    drop(running);
+    assert_eq!(will.await.unwrap().unwrap().priority(), Priority::Will);
+    let metrics = scheduler.metrics();
+    assert_eq!(metrics[&Priority::Background].preempted, 1);
+    assert_eq!(metrics[&Priority::Combobulation].preempted, 1);
+    assert_eq!(metrics[&Priority::Will].preempted, 0);
+}
+
+#[tokio::test]
+async fn abandoned_grant_frees_the_slot() {
+    let scheduler = LlmScheduler::new(1);
+    let running = scheduler.acquire(HOST, Priority::Will).await.unwrap();
+
+    let waiter = {
+        let scheduler = scheduler.clone();
+        tokio::spawn(async move {
+            let _permit = scheduler.acquire(HOST, Priority::Will).await;
+            std::future::pending::<()>().await;
+        })
+    };
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    // Grant the slot to the waiter, then cancel it before it runs again.
+    drop(running);
+    waiter.abort();
+    let _ = waiter.await;
+
+    let permit = tokio::time::timeout(
+        Duration::from_secs(1),
+        scheduler.acquire(HOST, Priority::Background),
+    )
+    .await
+    .expect("slot should be free again");
+    assert!(permit.is_ok());
+}
+
+#[test]
+fn priorities_parse_from_names() {
+    assert_eq!("will".parse::<Priority>().unwrap(), Priority::Will);
+    assert!(Priority::Conversation > Priority::Combobulation);
+    assert!("urgent".parse::<Priority>().is_err());
+}
@@ -3,7 +3,7 @@ use std::env;
 use std::io::Cursor;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result, anyhow};
 use base64::Engine;
@@ -13,14 +13,16 @@ use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
 use serde::Serialize;
 #[cfg(feature = "voice")]
 use tokio::sync::Mutex as AsyncMutex;
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 use tokio::time::{MissedTickBehavior, interval};
 #[cfg(feature = "voice")]
 use tracing::warn;
 use tracing::{error, info, trace};
 use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
 
-use psyche::{AudioClip, Sensation, Topic, TopicBus};
+use crate::diarize::WordTiming;
+use crate::vad::{self, Vad, VadConfig, VadEvent};
+use psyche::{AudioClip, HeardWord, Sensation, Topic, TopicBus};
 #[cfg(feature = "voice")]
 use psyche::{QdrantClient, VoiceInfo, audio_clip_id};
 
@@ -31,6 +33,8 @@ pub const HIGH_QUALITY_MULTILINGUAL_MODEL_PATH: &str = "models/whisper/ggml-larg
 pub const DEFAULT_VOICE_EMBEDDING_MODEL_PATH: &str =
     "models/voice/speaker_embedding_extractor.onnx";
 const DEFAULT_PCM_QUEUE_CAPACITY: usize = 4;
+/// Speech needed in the buffer before the first interim decode is worth it.
+const INTERIM_MIN_AUDIO: Duration = Duration::from_millis(500);
 
 #[derive(Clone)]
 pub struct AsrService {
@@ -41,17 +45,11 @@ pub struct AsrService {
     hop: Duration,
     min_duration: Duration,
     max_buffer_duration: Duration,
-    silence_threshold: f32,
-    silence_duration: Duration,
+    interim_interval: Option<Duration>,
+    vad: VadConfig,
     pcm_queue_capacity: usize,
     topic_bus: Option<TopicBus>,
-}
-
-#[derive(Clone, Debug, Serialize)]
-pub struct WordTiming {
-    pub text: String,
-    pub start_ms: u32,
-    pub end_ms: u32,
+    speech_onsets: broadcast::Sender<DateTime<Utc>>,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -106,6 +104,33 @@ impl AsrTranscript {
             captured_at: Some(self.occurred_at.to_rfc3339()),
         }
     }
+
+    /// Recognised words placed at the times they were spoken.
+    pub fn heard_words(&self) -> Vec<HeardWord> {
+        let at = |ms: u32| {
+            self.occurred_at + chrono::Duration::milliseconds(ms as i64 - self.start_ms as i64)
+        };
+        self.segments
+            .iter()
+            .flat_map(|segment| &segment.words)
+            .map(|word| HeardWord {
+                text: word.text.trim().to_string(),
+                start: at(word.start_ms),
+                end: at(word.end_ms),
+            })
+            .collect()
+    }
+}
+
+/// A provisional hypothesis for speech that is still in progress.
+///
+/// `stable` is set once two consecutive re-decodes agree, which is a good
+/// hint that the final transcript will read the same.
+#[derive(Clone, Debug, Serialize)]
+pub struct AsrInterim {
+    pub text: String,
+    pub stable: bool,
+    pub occurred_at: DateTime<Utc>,
 }
 
 #[derive(Clone, Debug)]
@@ -167,9 +192,10 @@ impl AsrService {
         let min_duration_ms = parse_env("ASR_MIN_DURATION_MS", 2_000u64)?.max(100);
         let max_buffer_ms =
             parse_env("ASR_MAX_BUFFER_MS", 8_000u64)?.clamp(min_duration_ms, 60_000);
-        let silence_threshold = parse_env("ASR_SILENCE_THRESHOLD", 0.015f32)?.clamp(0.0, 1.0);
-        let silence_duration_ms =
-            parse_env("ASR_SILENCE_DURATION_MS", 1_200u64)?.clamp(100, 10_000);
+        let interim_ms = parse_env("ASR_INTERIM_MS", 0u64)?;
+        let interim_interval =
+            (interim_ms > 0).then(|| Duration::from_millis(interim_ms.max(hop_ms)));
+        let vad = VadConfig::from_env()?;
         let pcm_queue_capacity =
             parse_env("ASR_PCM_QUEUE_CAPACITY", DEFAULT_PCM_QUEUE_CAPACITY)?.clamp(1usize, 64usize);
         let whisper_use_gpu = parse_env("ASR_USE_GPU", whisper_gpu_enabled_by_default())?;
@@ -210,10 +236,11 @@ impl AsrService {
             hop: Duration::from_millis(hop_ms),
             min_duration: Duration::from_millis(min_duration_ms),
             max_buffer_duration: Duration::from_millis(max_buffer_ms),
-            silence_threshold,
-            silence_duration: Duration::from_millis(silence_duration_ms),
+            interim_interval,
+            vad,
             pcm_queue_capacity,
             topic_bus: None,
+            speech_onsets: broadcast::channel(16).0,
         }))
     }
 
@@ -227,8 +254,9 @@ impl AsrService {
 
     /// Transcribe a stored audio clip and return text with segment timings.
     ///
-    /// Stored clips are expected to be 16-bit little-endian PCM or WAV audio at
-    /// the service sample rate. Multi-channel clips are downmixed to mono.
+    /// Stored clips may be 16-bit little-endian PCM, WAV, or Opus in WebM or
+    /// Ogg. Multi-channel clips are downmixed to mono and every clip is
+    /// resampled to the service sample rate.
     pub async fn transcribe_clip(&self, clip: &AudioClip) -> Result<ClipTranscription> {
         anyhow::ensure!(self.has_whisper_model(), "Whisper model not configured");
         let audio = decode_audio_clip_samples(clip, self.sample_rate)?;
@@ -290,6 +318,12 @@ impl AsrService {
         self.topic_bus = Some(bus);
     }
 
+    /// Subscribe to the moments the VAD hears a user start talking on any
+    /// connection, used to barge in on Pete's speech.
+    pub fn subscribe_speech_onsets(&self) -> broadcast::Receiver<DateTime<Utc>> {
+        self.speech_onsets.subscribe()
+    }
+
     #[cfg(feature = "voice")]
     pub fn enable_voice_embeddings_from_env(
         &mut self,
@@ -314,17 +348,32 @@ impl AsrService {
         )?));
         Ok(())
     }
+
+    /// Whether connections publish interim hypotheses (`ASR_INTERIM_MS`).
+    pub fn interim_enabled(&self) -> bool {
+        self.interim_interval.is_some() && self.context.is_some()
+    }
+
+    /// Start a streaming connection.
+    ///
+    /// Returns the PCM sink, final transcripts, and interim hypotheses. The
+    /// interim channel stays quiet unless [`interim_enabled`](Self::interim_enabled).
     pub fn spawn_connection(
         self: Arc<Self>,
-    ) -> (mpsc::Sender<AudioChunk>, mpsc::Receiver<AsrTranscript>) {
+    ) -> (
+        mpsc::Sender<AudioChunk>,
+        mpsc::Receiver<AsrTranscript>,
+        mpsc::Receiver<AsrInterim>,
+    ) {
         let (pcm_tx, pcm_rx) = mpsc::channel(self.pcm_queue_capacity);
         let (transcript_tx, transcript_rx) = mpsc::channel(64);
+        let (interim_tx, interim_rx) = mpsc::channel(16);
         tokio::spawn(async move {
-            if let Err(err) = run_connection(self, pcm_rx, transcript_tx).await {
+            if let Err(err) = run_connection(self, pcm_rx, transcript_tx, interim_tx).await {
                 error!(error = %err, "ASR connection failed");
             }
         });
-        (pcm_tx, transcript_rx)
+        (pcm_tx, transcript_rx, interim_rx)
     }
 
     async fn transcribe(&self, audio: Vec<f32>) -> Result<Vec<SegmentInternal>> {
@@ -530,6 +579,9 @@ fn decode_audio_clip_samples(clip: &AudioClip, target_sample_rate: u32) -> Resul
     }
 
     let mime = clip.mime.to_ascii_lowercase();
+    if crate::codec::is_compressed_mime(&mime) || crate::codec::starts_stream(&bytes) {
+        return crate::codec::decode_compressed_audio(&bytes, &mime, target_sample_rate);
+    }
     let (sample_rate, channels, samples) = if mime.starts_with("audio/wav")
         || mime.starts_with("audio/x-wav")
         || bytes.starts_with(b"RIFF")
@@ -545,13 +597,15 @@ fn decode_audio_clip_samples(clip: &AudioClip, target_sample_rate: u32) -> Resul
         return Err(anyhow!("unsupported audio clip MIME type {}", clip.mime));
     };
 
-    if sample_rate != target_sample_rate {
-        return Err(anyhow!(
-            "audio clip sample rate {sample_rate} does not match Whisper sample rate {target_sample_rate}"
-        ));
+    let samples = downmix_to_mono(samples, channels);
+    if sample_rate == 0 {
+        return Err(anyhow!("audio clip has no sample rate"));
     }
-
-    Ok(downmix_to_mono(samples, channels))
+    Ok(crate::codec::resample(
+        &samples,
+        sample_rate,
+        target_sample_rate,
+    ))
 }
 
 fn decode_wav_samples(bytes: &[u8]) -> Result<(u32, u16, Vec<f32>)> {
@@ -608,6 +662,24 @@ fn is_pcm_s16_mime(mime: &str) -> bool {
     mime.starts_with("audio/pcm") || mime.starts_with("audio/l16") || mime.contains("format=s16")
 }
 
+/// Two hypotheses agree when they match word for word, ignoring case and
+/// punctuation, which Whisper tends to revise as more audio arrives.
+fn interim_is_stable(previous: &str, current: &str) -> bool {
+    fn words(text: &str) -> Vec<String> {
+        text.split_whitespace()
+            .map(|word| {
+                word.chars()
+                    .filter(|c| c.is_alphanumeric() || *c == '\'')
+                    .flat_map(char::to_lowercase)
+                    .collect::<String>()
+            })
+            .filter(|word| !word.is_empty())
+            .collect()
+    }
+    let current = words(current);
+    !current.is_empty() && words(previous) == current
+}
+
 fn join_segments(segments: &[SegmentInternal]) -> String {
     segments
         .iter()
@@ -621,32 +693,59 @@ async fn run_connection(
     service: Arc<AsrService>,
     mut pcm_rx: mpsc::Receiver<AudioChunk>,
     out_tx: mpsc::Sender<AsrTranscript>,
+    interim_tx: mpsc::Sender<AsrInterim>,
 ) -> Result<()> {
     let mut buffer = VecDeque::<f32>::new();
     let mut buffer_started_at = None::<DateTime<Utc>>;
     let mut total_consumed_samples = 0usize;
     let sample_rate = service.sample_rate as f32;
-    let mut silence_tracker = SilenceTracker::new(
-        service.sample_rate,
-        service.silence_threshold,
-        service.silence_duration,
-    );
+    let mut vad = Vad::new(service.vad.clone(), service.sample_rate);
     let min_samples = (service.min_duration.as_secs_f32() * sample_rate) as usize;
     let max_samples = (service.max_buffer_duration.as_secs_f32() * sample_rate) as usize;
     let mut pcm_open = true;
     let mut ticker = interval(service.hop);
     ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let interim_interval = service
+        .interim_interval
+        .filter(|_| service.context.is_some());
+    let min_interim_samples = (INTERIM_MIN_AUDIO.as_secs_f32() * sample_rate) as usize;
+    // Decodes come back tagged with the utterance they were taken from so a
+    // hypothesis that lands after its final transcript is dropped.
+    let (decoded_tx, mut decoded_rx) = mpsc::channel::<(u64, DateTime<Utc>, String)>(1);
+    let mut utterance = 0u64;
+    let mut interim_in_flight = false;
+    let mut last_interim_at = None::<Instant>;
+    let mut last_hypothesis = String::new();
 
     while pcm_open || !buffer.is_empty() {
         tokio::select! {
+            Some((generation, occurred_at, text)) = decoded_rx.recv() => {
+                interim_in_flight = false;
+                if generation != utterance || text.is_empty() {
+                    continue;
+                }
+                let stable = interim_is_stable(&last_hypothesis, &text);
+                last_hypothesis = text.clone();
+                let _ = interim_tx.try_send(AsrInterim { text, stable, occurred_at });
+            }
             chunk = pcm_rx.recv(), if pcm_open => {
                 match chunk {
                     Some(chunk) => {
                         if buffer.is_empty() {
                             buffer_started_at = Some(chunk.captured_at);
                         }
+                        let buffered_before = buffer.len();
                         let samples = extend_buffer(&mut buffer, &chunk.bytes);
-                        silence_tracker.ingest_samples(&samples);
+                        for event in vad.push(&samples) {
+                            if let VadEvent::SpeechStart { sample } = event {
+                                // Pre-roll may reach back into earlier chunks.
+                                let into_chunk = sample as f32 - buffered_before as f32;
+                                let offset_ms = ((into_chunk / sample_rate) * 1000.0) as i64;
+                                let at = chunk.captured_at + chrono::Duration::milliseconds(offset_ms);
+                                trace!(%at, "user speech onset");
+                                let _ = service.speech_onsets.send(at);
+                            }
+                        }
                     }
                     None => pcm_open = false,
                 }
@@ -659,12 +758,47 @@ async fn run_connection(
                     continue;
                 }
 
-                let boundary_sample = silence_tracker.boundary_sample();
+                let boundary_sample = vad.boundary_sample();
                 let has_pause = boundary_sample.is_some();
                 let hit_max_buffer = buffer.len() >= max_samples;
                 let should_transcribe =
                     !pcm_open || (buffer.len() >= min_samples && (has_pause || hit_max_buffer));
                 if !should_transcribe {
+                    let due = match (interim_interval, last_interim_at) {
+                        (Some(every), Some(last)) => last.elapsed() >= every,
+                        (Some(_), None) => true,
+                        (None, _) => false,
+                    };
+                    if due
+                        && !interim_in_flight
+                        && vad.is_speaking()
+                        && buffer.len() >= min_interim_samples
+                    {
+                        let snapshot = buffer.iter().copied().collect::<Vec<_>>();
+                        let (trimmed, leading_trim) =
+                            vad::trim_silence(&snapshot, service.sample_rate, &service.vad);
+                        if !trimmed.is_empty() {
+                            interim_in_flight = true;
+                            last_interim_at = Some(Instant::now());
+                            let leading_trim_ms =
+                                ((leading_trim as f32 / sample_rate) * 1000.0) as i64;
+                            let occurred_at = buffer_started_at.unwrap_or_else(Utc::now)
+                                + chrono::Duration::milliseconds(leading_trim_ms);
+                            let service = Arc::clone(&service);
+                            let decoded_tx = decoded_tx.clone();
+                            let generation = utterance;
+                            tokio::spawn(async move {
+                                let text = match service.transcribe(trimmed).await {
+                                    Ok(segments) => join_segments(&segments),
+                                    Err(err) => {
+                                        trace!(error = %err, "interim decode failed");
+                                        String::new()
+                                    }
+                                };
+                                let _ = decoded_tx.send((generation, occurred_at, text)).await;
+                            });
+                        }
+                    }
                     continue;
                 }
 
@@ -676,15 +810,14 @@ async fn run_connection(
                 let audio = drain_buffer_front(&mut buffer, submitted_samples);
                 let utterance_start_samples = total_consumed_samples;
                 let utterance_started_at = buffer_started_at.unwrap_or_else(Utc::now);
-                let (trimmed_audio, leading_trim) = trim_silence(
-                    &audio,
-                    service.sample_rate,
-                    service.silence_threshold,
-                    service.silence_duration,
-                );
+                let (trimmed_audio, leading_trim) =
+                    vad::trim_silence(&audio, service.sample_rate, &service.vad);
 
                 total_consumed_samples += submitted_samples;
-                silence_tracker.reset();
+                utterance += 1;
+                last_hypothesis.clear();
+                last_interim_at = None;
+                vad.reset();
                 if buffer.is_empty() {
                     buffer_started_at = None;
                 } else {
@@ -692,7 +825,7 @@ async fn run_connection(
                     buffer_started_at =
                         Some(utterance_started_at + chrono::Duration::milliseconds(elapsed_ms));
                     let remaining = buffer.iter().copied().collect::<Vec<_>>();
-                    silence_tracker.ingest_samples(&remaining);
+                    vad.push(&remaining);
                 }
 
                 if trimmed_audio.is_empty() {
@@ -780,64 +913,6 @@ async fn process_utterance(
     }
 }
 
-struct SilenceTracker {
-    threshold: f32,
-    required_samples: usize,
-    window_samples: usize,
-    tail_silence_samples: usize,
-    total_samples: usize,
-    boundary_sample: Option<usize>,
-}
-
-impl SilenceTracker {
-    fn new(sample_rate: u32, threshold: f32, duration: Duration) -> Self {
-        let mut required_samples = (sample_rate as f32 * duration.as_secs_f32()).round() as usize;
-        if required_samples == 0 {
-            required_samples = (sample_rate as f32 * 0.2).round() as usize;
-        }
-        let window_samples = ((sample_rate as f32 * 0.02).round() as usize).max(1);
-        Self {
-            threshold,
-            required_samples,
-            window_samples,
-            tail_silence_samples: 0,
-            total_samples: 0,
-            boundary_sample: None,
-        }
-    }
-
-    fn ingest_samples(&mut self, samples: &[f32]) {
-        if samples.is_empty() {
-            return;
-        }
-        for chunk in samples.chunks(self.window_samples) {
-            let chunk_len = chunk.len();
-            self.total_samples = self.total_samples.saturating_add(chunk_len);
-            if rms(chunk) <= self.threshold {
-                self.tail_silence_samples = self.tail_silence_samples.saturating_add(chunk_len);
-                if self.boundary_sample.is_none()
-                    && self.required_samples > 0
-                    && self.tail_silence_samples >= self.required_samples
-                {
-                    self.boundary_sample = Some(self.total_samples);
-                }
-            } else {
-                self.tail_silence_samples = 0;
-            }
-        }
-    }
-
-    fn boundary_sample(&self) -> Option<usize> {
-        self.boundary_sample
-    }
-
-    fn reset(&mut self) {
-        self.tail_silence_samples = 0;
-        self.total_samples = 0;
-        self.boundary_sample = None;
-    }
-}
-
 fn extend_buffer(buffer: &mut VecDeque<f32>, bytes: &[u8]) -> Vec<f32> {
     let mut samples = Vec::with_capacity(bytes.len() / 2);
     for chunk in bytes.chunks_exact(2) {
@@ -854,62 +929,6 @@ fn drain_buffer_front(buffer: &mut VecDeque<f32>, samples: usize) -> Vec<f32> {
     buffer.drain(..count).collect()
 }
 
-fn trim_silence(
-    samples: &[f32],
-    sample_rate: u32,
-    threshold: f32,
-    silence_duration: Duration,
-) -> (Vec<f32>, usize) {
-    let len = samples.len();
-    if len == 0 {
-        return (Vec::new(), 0);
-    }
-
-    let window_ms = silence_duration.as_millis().clamp(20, 500) as usize;
-    let window = ((sample_rate as usize) * window_ms / 1000).max(1);
-    let step = (window / 2).max(1);
-
-    let mut start = 0usize;
-    while start + window <= len {
-        let rms = rms(&samples[start..start + window]);
-        if rms > threshold {
-            break;
-        }
-        start = (start + step).min(len);
-    }
-
-    if start >= len {
-        return (Vec::new(), len);
-    }
-
-    let mut end = len;
-    while end >= window && end > start {
-        let from = end.saturating_sub(window);
-        let rms = rms(&samples[from..end]);
-        if rms > threshold {
-            break;
-        }
-        end = end.saturating_sub(step);
-    }
-
-    if end <= start {
-        return (Vec::new(), len);
-    }
-
-    (samples[start..end].to_vec(), start)
-}
-
-fn rms(samples: &[f32]) -> f32 {
-    let sum_sq = samples
-        .iter()
-        .map(|s| {
-            let v = f64::from(*s);
-            v * v
-        })
-        .sum::<f64>();
-    (sum_sq / samples.len().max(1) as f64).sqrt() as f32
-}
-
 fn centiseconds_to_ms(centiseconds: i64) -> u32 {
     centiseconds
         .max(0)
@@ -1137,9 +1156,10 @@ fn encode_wav(samples: &[f32], sample_rate: u32) -> Result<Vec<u8>> {
 #[cfg(test)]
 mod tests {
     use super::{
-        SegmentInternal, SilenceTracker, WordTiming, decode_audio_clip_samples, emit_transcript,
-        encode_wav, finish_word, push_word_token, segment_to_messages, trim_silence,
+        SegmentInternal, WordTiming, decode_audio_clip_samples, emit_transcript, encode_wav,
+        finish_word, push_word_token, segment_to_messages,
     };
+    use crate::vad::{self, Vad, VadConfig};
     use base64::Engine;
     use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
     use chrono::Utc;
@@ -1230,43 +1250,55 @@ mod tests {
         assert_eq!(messages[1].end_ms, 2800);
     }
 
-    #[test]
-    fn silence_tracker_detects_required_duration() {
-        let mut tracker = SilenceTracker::new(16_000, 0.01, Duration::from_millis(500));
-        // assert!(!tracker.has_boundary());
-
-        tracker.ingest_samples(&vec![0.005; 8_000]);
-        // assert!(tracker.has_boundary());
-        assert_eq!(tracker.boundary_sample(), Some(8_000));
+    fn tone(seconds: f32) -> Vec<f32> {
+        (0..(16_000.0 * seconds) as usize)
+            .map(|i| 0.2 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 16_000.0).sin())
+            .collect()
+    }
 
-        tracker.reset();
-        tracker.ingest_samples(&vec![0.02; 8_000]);
-        // assert!(!tracker.has_boundary());
+    fn vad_config() -> VadConfig {
+        VadConfig {
+            hangover: Duration::from_millis(500),
+            ..VadConfig::default()
+        }
     }
 
     #[test]
-    fn silence_tracker_finds_boundary_inside_mixed_frame() {
-        let mut tracker = SilenceTracker::new(100, 0.01, Duration::from_millis(500));
-        let mut samples = vec![0.2; 20];
-        samples.extend(vec![0.0; 60]);
-
-        tracker.ingest_samples(&samples);
-
-        // assert!(tracker.has_boundary());
-        assert_eq!(tracker.boundary_sample(), Some(70));
+    fn vad_detects_required_pause() {
+        let mut vad = Vad::new(vad_config(), 16_000);
+        vad.push(&vec![0.005; 8_000]);
+        assert_eq!(vad.boundary_sample(), Some(8_000));
+
+        vad.reset();
+        vad.push(&tone(0.5));
+        assert_eq!(vad.boundary_sample(), None);
+        assert!(vad.is_speaking());
+    }
 
-        tracker.ingest_samples(&vec![0.2; 20]);
-        assert_eq!(tracker.boundary_sample(), Some(70));
+    #[test]
+    fn vad_boundary_survives_later_speech() {
+        let mut vad = Vad::new(vad_config(), 16_000);
+        let mut samples = tone(0.25);
+        samples.extend(vec![0.0; 16_000]);
+        vad.push(&samples);
+        // The 20 ms frame holding the end of the tone still counts as speech.
+        assert_eq!(vad.boundary_sample(), Some(12_160));
+
+        vad.push(&tone(0.25));
+        assert_eq!(vad.boundary_sample(), Some(12_160));
     }
 
     #[test]
-    fn trim_silence_keeps_middle_audio() {
-        let samples = [0.0, 0.0, 0.2, 0.2, 0.0, 0.0];
+    fn trim_silence_keeps_speech_with_pre_roll() {
+        let mut samples = vec![0.0; 16_000];
+        samples.extend(tone(0.5));
+        samples.extend(vec![0.0; 16_000]);
 
-        let (trimmed, leading_trim) = trim_silence(&samples, 50, 0.01, Duration::from_millis(20));
+        let (trimmed, leading_trim) = vad::trim_silence(&samples, 16_000, &vad_config());
 
-        assert_eq!(trimmed, vec![0.2, 0.2]);
-        assert_eq!(leading_trim, 2);
+        // 200 ms of pre-roll either side of the tone.
+        assert_eq!(leading_trim, 12_800);
+        assert_eq!(trimmed.len(), 8_000 + 2 * 3_200);
     }
 
     #[test]
@@ -1308,6 +1340,24 @@ mod tests {
         assert!((samples[1] + 0.25).abs() < 0.001);
     }
 
+    #[test]
+    fn resamples_clip_to_whisper_rate() {
+        let wav = encode_wav(&vec![0.25; 4_800], 48_000).unwrap();
+        let clip = AudioClip {
+            mime: "audio/wav".into(),
+            base64: BASE64_STANDARD.encode(wav),
+            sample_rate: 48_000,
+            channels: 1,
+            transcript: None,
+            captured_at: None,
+        };
+
+        let samples = decode_audio_clip_samples(&clip, 16_000).unwrap();
+
+        assert_eq!(samples.len(), 1_600);
+        assert!((samples[800] - 0.25).abs() < 0.001);
+    }
+
     #[tokio::test]
     async fn emit_transcript_skips_empty_segments() {
         let (tx, mut rx) = mpsc::channel(1);
@@ -1398,4 +1448,14 @@ mod tests {
         assert!(!is_hallucination("[Music]"));
         assert!(!is_hallucination("Hello world"));
     }
+
+    #[test]
+    fn interim_stability_ignores_case_and_punctuation() {
+        use super::interim_is_stable;
+        assert!(interim_is_stable("hello there", "Hello, there."));
+        assert!(interim_is_stable("I don't know", "i don't know"));
+        assert!(!interim_is_stable("hello", "hello there"));
+        assert!(!interim_is_stable("", ""));
+        assert!(!interim_is_stable("", "hello"));
+    }
 }
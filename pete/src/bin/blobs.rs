@@ -0,0 +1,112 @@
+//! Maintain the content-addressed blob store holding image and audio payloads.
+//!
+//! `migrate` moves payloads that older versions stored as base64 on `Image`
+//! and `AudioClip` graph nodes into the store configured by `BLOB_*`, leaving
+//! the nodes with `blob_hash`, `blob_size` and `mime`. It can be interrupted
+//! and run again. `get` writes one blob to stdout.
+//!
+//! ```bash
+//! cargo run -p pete --bin blobs -- --dry-run migrate
+//! cargo run -p pete --bin blobs -- migrate
+//! cargo run -p pete --bin blobs -- get <hash> > frame.jpg
+//! ```
+
+use std::io::Write;
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use dotenvy::dotenv;
+use pete::{EventBus, init_logging};
+use psyche::{BlobConfig, Neo4jClient};
+use tracing::info;
+
+#[derive(Parser)]
+#[command(
+    author,
+    version,
+    about = "Move image and audio payloads into the blob store and read blobs back"
+)]
+struct Cli {
+    /// Neo4j bolt or HTTP URI.
+    #[arg(long, env = "NEO4J_URI", default_value = "bolt://localhost:7687")]
+    neo4j_uri: String,
+    /// Neo4j username.
+    #[arg(long, env = "NEO4J_USER", default_value = "neo4j")]
+    neo4j_user: String,
+    /// Neo4j password.
+    #[arg(long, env = "NEO4J_PASS", default_value = "password")]
+    neo4j_pass: String,
+    /// Print what would change without changing anything.
+    #[arg(long)]
+    dry_run: bool,
+    #[command(subcommand)]
+    command: Cmd,
+}
+
+#[derive(Subcommand)]
+enum Cmd {
+    /// Move inline payloads of `Image` and `AudioClip` nodes into the store.
+    Migrate {
+        /// Number of nodes to move per batch.
+        #[arg(long, env = "BLOB_MIGRATE_BATCH_SIZE", default_value_t = 100)]
+        batch_size: usize,
+    },
+    /// Write the blob with `hash` to stdout.
+    Get { hash: String },
+}
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() -> anyhow::Result<()> {
+    let (bus, _user_rx) = EventBus::new();
+    init_logging(bus.log_sender());
+    dotenv().ok();
+
+    let cli = Cli::parse();
+    let blobs = BlobConfig::from_env()?.open();
+    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass)
+        .with_blob_store(blobs.clone());
+
+    match cli.command {
+        Cmd::Migrate { batch_size } => {
+            if cli.dry_run {
+                let count = graph
+                    .count_inline_media_payloads()
+                    .await
+                    .context("failed to count inline media payloads")?;
+                println!("would move {count} inline image and audio payloads into the blob store");
+                return Ok(());
+            }
+            let mut moved = 0;
+            loop {
+                let payloads = graph
+                    .inline_media_payloads(batch_size)
+                    .await
+                    .context("failed to load inline media payloads")?;
+                if payloads.is_empty() {
+                    break;
+                }
+                let count = graph
+                    .move_media_payloads_to_blobs(&payloads)
+                    .await
+                    .context("failed to move media payloads")?;
+                anyhow::ensure!(
+                    count > 0,
+                    "no payloads were moved; stopping to avoid looping"
+                );
+                moved += count;
+                info!(moved, "moved media payloads into the blob store");
+            }
+            println!("moved {moved} inline image and audio payloads into the blob store");
+            Ok(())
+        }
+        Cmd::Get { hash } => {
+            let bytes = blobs
+                .get(&hash)
+                .await?
+                .with_context(|| format!("blob {hash} not found"))?;
+            std::io::stdout()
+                .write_all(&bytes)
+                .context("failed to write blob")
+        }
+    }
+}
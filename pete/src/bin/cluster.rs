@@ -3,11 +3,12 @@ use std::time::Duration;
 use anyhow::Context;
 use clap::Parser;
 use dotenvy::dotenv;
-use lingproc::{Doer, LlmInstruction};
-use pete::{EventBus, init_logging, ollama_provider_from_args};
+use lingproc::{Doer, LlmInstruction, Priority};
+use pete::{EventBus, init_logging, scheduled_ollama_provider};
 use psyche::{
-    GraphClusterItem, GraphFaceIdentityLabel, GraphVoiceIdentityLabel, Neo4jClient, QdrantClient,
-    VectorCluster, find_vector_clusters, qdrant_vector_collections,
+    GraphClusterItem, GraphFaceIdentityLabel, GraphVoiceIdentityLabel, Neo4jClient,
+    PersonLinkConfig, PersonLinker, QdrantClient, VectorCluster, find_vector_clusters,
+    qdrant_vector_collections,
 };
 use tokio::time::{MissedTickBehavior, interval};
 use tracing::{debug, error, info, warn};
@@ -70,6 +71,9 @@ struct Cli {
     /// Run one clustering pass and exit.
     #[arg(long)]
     once: bool,
+    /// Do not link faces and voices that keep co-occurring into people.
+    #[arg(long, env = "CLUSTER_NO_PERSON_LINK")]
+    no_person_link: bool,
     /// Print results without writing cluster nodes to Neo4j or identifying faces/voices.
     #[arg(long)]
     dry_run: bool,
@@ -97,13 +101,19 @@ async fn main() -> anyhow::Result<()> {
         None
     } else {
         Some(ClusterLabelProcessor {
-            doer: ollama_provider_from_args(&cli.wits_host, &cli.wits_model)?,
+            doer: scheduled_ollama_provider(&cli.wits_host, &cli.wits_model, Priority::Background)?,
             llm_model: cli.wits_model.clone(),
         })
     };
 
+    let linker = if cli.no_person_link {
+        None
+    } else {
+        Some(PersonLinker::new(PersonLinkConfig::from_env()?))
+    };
+
     if cli.once || cli.dry_run {
-        run_cluster_pass(&cli, &qdrant, &graph, labeler.as_ref()).await?;
+        run_cluster_pass(&cli, &qdrant, &graph, labeler.as_ref(), linker.as_ref()).await?;
         return Ok(());
     }
 
@@ -120,7 +130,9 @@ async fn main() -> anyhow::Result<()> {
     );
     loop {
         ticker.tick().await;
-        if let Err(err) = run_cluster_pass(&cli, &qdrant, &graph, labeler.as_ref()).await {
+        if let Err(err) =
+            run_cluster_pass(&cli, &qdrant, &graph, labeler.as_ref(), linker.as_ref()).await
+        {
             error!(
                 error = %err,
                 error_debug = ?err,
@@ -135,6 +147,7 @@ async fn run_cluster_pass(
     qdrant: &QdrantClient,
     graph: &Neo4jClient,
     labeler: Option<&ClusterLabelProcessor>,
+    linker: Option<&PersonLinker>,
 ) -> anyhow::Result<()> {
     let collections = selected_collections(&cli.collection);
     let skip_missing_collections = cli.collection.is_empty();
@@ -151,6 +164,44 @@ async fn run_cluster_pass(
         .await?;
     }
 
+    if let Some(linker) = linker {
+        link_people(cli, graph, linker).await?;
+    }
+    Ok(())
+}
+
+/// Attach faces and voices recognised together often enough to people.
+async fn link_people(cli: &Cli, graph: &Neo4jClient, linker: &PersonLinker) -> anyhow::Result<()> {
+    if cli.dry_run {
+        let candidates = linker
+            .candidates(graph)
+            .await
+            .context("failed to find co-occurring faces and voices")?;
+        println!(
+            "found {} face and voice pairs to link from windows tallied so far",
+            candidates.len()
+        );
+        for candidate in &candidates {
+            println!(
+                "{} + {} windows={}",
+                candidate.face_id, candidate.voice_id, candidate.windows
+            );
+        }
+        return Ok(());
+    }
+    let links = linker
+        .link(graph, chrono::Utc::now())
+        .await
+        .context("failed to link faces and voices into people")?;
+    for link in &links {
+        info!(
+            person_id = %link.person_id,
+            face_id = %link.face_id,
+            voice_id = %link.voice_id,
+            windows = link.windows,
+            "linked face and voice to person"
+        );
+    }
     Ok(())
 }
 
@@ -376,7 +427,7 @@ async fn identify_new_voice_cluster(
 }
 
 struct ClusterLabelProcessor {
-    doer: lingproc::OllamaProvider,
+    doer: lingproc::Scheduled<lingproc::OllamaProvider>,
     llm_model: String,
 }
 
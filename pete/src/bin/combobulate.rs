@@ -5,8 +5,8 @@ use anyhow::Context;
 use chrono::Utc;
 use clap::Parser;
 use dotenvy::dotenv;
-use lingproc::{Doer, LlmInstruction, Vectorizer};
-use pete::{EventBus, init_logging, ollama_provider_from_args};
+use lingproc::{Doer, LlmInstruction, Priority, Vectorizer};
+use pete::{EventBus, init_logging, scheduled_ollama_provider};
 use psyche::{
     CONVERSATION_SPEAKER_NOTE, ConversationEntry, GraphAwareness, GraphSensationTimelineItem,
     GraphTimelineWindow, Neo4jClient, QdrantClient, SENSOR_GROUNDING_RULES, Sensation,
@@ -88,8 +88,16 @@ async fn main() -> anyhow::Result<()> {
     ));
     let observer = SensationGraphObserver::new(graph.clone());
     let qdrant = QdrantClient::new(cli.qdrant_url);
-    let doer = ollama_provider_from_args(&cli.combobulator_host, &cli.combobulator_model)?;
-    let vectorizer = ollama_provider_from_args(&cli.embeddings_host, &cli.embeddings_model)?;
+    let doer = scheduled_ollama_provider(
+        &cli.combobulator_host,
+        &cli.combobulator_model,
+        Priority::Combobulation,
+    )?;
+    let vectorizer = scheduled_ollama_provider(
+        &cli.embeddings_host,
+        &cli.embeddings_model,
+        Priority::Combobulation,
+    )?;
     let processor = CombobulationProcessor {
         doer,
         vectorizer,
@@ -241,8 +249,8 @@ async fn process_window(
 }
 
 struct CombobulationProcessor {
-    doer: lingproc::OllamaProvider,
-    vectorizer: lingproc::OllamaProvider,
+    doer: lingproc::Scheduled<lingproc::OllamaProvider>,
+    vectorizer: lingproc::Scheduled<lingproc::OllamaProvider>,
     llm_model: String,
     embedding_model: String,
 }
@@ -4,12 +4,14 @@ use anyhow::Context;
 use chrono::{DateTime, Utc};
 use clap::Parser;
 use dotenvy::dotenv;
-use lingproc::{Chatter, Message};
-use pete::{EventBus, init_logging, ollama_provider_from_args};
+use lingproc::{Chatter, Message, Priority};
+use pete::{EventBus, init_logging, scheduled_ollama_provider};
 use psyche::{
-    CONVERSATION_SPEAKER_NOTE, ConversationEntry, GraphLatestCombobulation,
-    GraphSensationTimelineItem, Impression, Neo4jClient, Sensation, SensationGraphObserver,
-    SensationObserver, Stimulus, Thought, WitReport, with_default_system_prompt,
+    AddresseeConfig, AddresseeCues, AddresseeDetector, AddresseeVerdict, CONVERSATION_SPEAKER_NOTE,
+    ChatterAddresseeJudge, ConversationEntry, GraphFaceIdentityTarget, GraphLatestCombobulation,
+    GraphSensationTimelineItem, GraphVoiceIdentityTarget, Impression, Neo4jClient, PersonNames,
+    Sensation, SensationGraphObserver, SensationObserver, Stimulus, Thought, WitReport,
+    with_default_system_prompt,
 };
 use tokio::time::{MissedTickBehavior, interval};
 use tracing::{error, info, trace};
@@ -52,6 +54,13 @@ struct Cli {
     /// Process at most the latest combobulation and exit.
     #[arg(long)]
     once: bool,
+    /// Only speak when someone has said something to Pete that he has not
+    /// answered (tuned by ADDRESSEE_*).
+    #[arg(long, env = "CONVERSANT_ONLY_WHEN_ADDRESSED")]
+    only_when_addressed: bool,
+    /// Ask the chatter model about speech whose addressee is unclear.
+    #[arg(long, env = "ADDRESSEE_JUDGE")]
+    addressee_judge: bool,
 }
 
 #[tokio::main(flavor = "multi_thread")]
@@ -67,10 +76,27 @@ async fn main() -> anyhow::Result<()> {
         cli.neo4j_pass.clone(),
     ));
     let observer = SensationGraphObserver::new(graph.clone());
-    let chatter = ollama_provider_from_args(&cli.chatter_host, &cli.chatter_model)?;
+    let chatter = scheduled_ollama_provider(
+        &cli.chatter_host,
+        &cli.chatter_model,
+        Priority::Conversation,
+    )?;
+    let addressee = if cli.only_when_addressed {
+        let detector = AddresseeDetector::new(AddresseeConfig::from_env()?);
+        Some(if cli.addressee_judge {
+            detector.with_judge(std::sync::Arc::new(ChatterAddresseeJudge::new(
+                std::sync::Arc::new(chatter.clone()),
+            )))
+        } else {
+            detector
+        })
+    } else {
+        None
+    };
     let processor = ConversantProcessor {
         chatter,
         graph: graph.clone(),
+        addressee,
     };
 
     if cli.once {
@@ -128,6 +154,18 @@ async fn process_latest_combobulation(
             )
         })?;
 
+    if let Some(verdict) = action.addressee.take() {
+        observer
+            .observe_sensation(&Sensation::of_at(verdict, Utc::now()))
+            .await;
+    }
+    if !action.addressed && action.say.take().is_some() {
+        info!(
+            combobulation_id = %combobulation.id,
+            "conversant stays quiet; nothing was said to Pete"
+        );
+    }
+
     store_active_face_sensation(observer, &combobulation, &action.emoji).await;
     info!(target: "thought_stream", "face: {}", action.emoji.trim());
     if let Some(words) = action.say.as_deref() {
@@ -157,8 +195,9 @@ async fn process_latest_combobulation(
 }
 
 struct ConversantProcessor {
-    chatter: lingproc::OllamaProvider,
+    chatter: lingproc::Scheduled<lingproc::OllamaProvider>,
     graph: std::sync::Arc<Neo4jClient>,
+    addressee: Option<AddresseeDetector>,
 }
 
 impl ConversantProcessor {
@@ -167,7 +206,8 @@ impl ConversantProcessor {
         combobulation: &GraphLatestCombobulation,
     ) -> anyhow::Result<ConversantAction> {
         let vision = self.graph.latest_image_description().await.unwrap_or(None);
-        let system_prompt = conversant_system_prompt(combobulation, vision);
+        let people = self.recent_people().await;
+        let system_prompt = conversant_system_prompt(combobulation, vision, &people);
 
         let history = self
             .graph
@@ -184,6 +224,9 @@ impl ConversantProcessor {
         }
 
         let mut action = parse_conversant_action(raw.trim());
+        if let Some(detector) = &self.addressee {
+            (action.addressed, action.addressee) = self.judge_addressee(detector, &history).await;
+        }
         action.system_prompt = system_prompt.clone();
         action.history = map_conversation_to_entries(history);
         action.report = Some(WitReport {
@@ -194,6 +237,99 @@ impl ConversantProcessor {
 
         Ok(action)
     }
+
+    /// People recently seen or heard.
+    async fn recent_people(&self) -> Vec<String> {
+        let names = PersonNames::new(&self.graph.people().await.unwrap_or_default());
+        let faces = self
+            .graph
+            .recent_face_identity_targets(5)
+            .await
+            .unwrap_or_default();
+        let voices = self
+            .graph
+            .recent_voice_identity_targets(5)
+            .await
+            .unwrap_or_default();
+        people_names(&faces, &voices, &names)
+    }
+
+    /// Whether the latest unanswered speech was meant for Pete, with the
+    /// verdict to store on it. Typed text always is; no speech never is.
+    /// Speech already judged keeps its verdict across combobulations.
+    async fn judge_addressee(
+        &self,
+        detector: &AddresseeDetector,
+        history: &[GraphSensationTimelineItem],
+    ) -> (bool, Option<AddresseeVerdict>) {
+        let Some(heard) = unanswered_speech(history) else {
+            return (false, None);
+        };
+        if heard.typed {
+            return (true, None);
+        }
+        if let Some(verdict) = detector.verdict(&heard.id) {
+            return (verdict.addressed, Some(verdict));
+        }
+        let mut cues = AddresseeCues::new(heard.text, heard.occurred_at);
+        if let Some((said, at)) = heard.pete_said {
+            cues = cues.with_pete_speech(said, at);
+        }
+        let face = self
+            .graph
+            .recent_face_identity_targets(1)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .find_map(|face| Some((parse_utc(&face.occurred_at)?, face.pose)));
+        if let Some((at, pose)) = face {
+            cues = cues.with_face(at, pose);
+        }
+        let verdict = detector.detect(&heard.id, &cues).await;
+        (verdict.addressed, Some(verdict))
+    }
+}
+
+/// Something heard after Pete last spoke.
+struct HeardSpeech {
+    id: String,
+    text: String,
+    occurred_at: DateTime<Utc>,
+    /// Typed into the web interface rather than spoken aloud.
+    typed: bool,
+    /// What Pete said before it, and when.
+    pete_said: Option<(String, DateTime<Utc>)>,
+}
+
+fn unanswered_speech(history: &[GraphSensationTimelineItem]) -> Option<HeardSpeech> {
+    let mut heard: Option<HeardSpeech> = None;
+    for item in history.iter().rev() {
+        let Some((content, is_assistant)) = strip_utterance_content(&item.text) else {
+            continue;
+        };
+        let Some(occurred_at) = parse_utc(&item.occurred_at) else {
+            continue;
+        };
+        match heard.as_mut() {
+            None if is_assistant => return None,
+            None if content == "I hear silence." => {}
+            None => {
+                heard = Some(HeardSpeech {
+                    id: item.id.clone(),
+                    text: content,
+                    occurred_at,
+                    typed: item.kind == "web_interface_text",
+                    pete_said: None,
+                });
+            }
+            Some(heard) if is_assistant => {
+                heard.pete_said = Some((content, occurred_at));
+                break;
+            }
+            Some(_) => {}
+        }
+    }
+    heard
 }
 
 fn strip_utterance_content(text: &str) -> Option<(String, bool)> {
@@ -261,9 +397,38 @@ fn map_conversation_to_messages(items: Vec<GraphSensationTimelineItem>) -> Vec<M
         .collect()
 }
 
+/// Distinct names of the people behind `faces` and `voices`: a linked face
+/// or voice goes by its person, an unlinked one by its identity, and
+/// unnamed unlinked ones are left out.
+fn people_names(
+    faces: &[GraphFaceIdentityTarget],
+    voices: &[GraphVoiceIdentityTarget],
+    names: &PersonNames,
+) -> Vec<String> {
+    let seen = faces
+        .iter()
+        .map(|face| (face.identity.as_deref(), face.person_id.as_deref()));
+    let heard = voices
+        .iter()
+        .map(|voice| (voice.identity.as_deref(), voice.person_id.as_deref()));
+    let mut people: Vec<String> = Vec::new();
+    for (identity, person_id) in seen.chain(heard) {
+        let name = match (person_id, identity.map(str::trim)) {
+            (Some(person_id), identity) => names.name(person_id, identity),
+            (None, Some(identity)) if !identity.is_empty() => identity.to_string(),
+            (None, _) => continue,
+        };
+        if !people.contains(&name) {
+            people.push(name);
+        }
+    }
+    people
+}
+
 fn conversant_system_prompt(
     combobulation: &GraphLatestCombobulation,
     vision: Option<String>,
+    people: &[String],
 ) -> String {
     let prior_emoji = combobulation
         .emoji
@@ -273,11 +438,19 @@ fn conversant_system_prompt(
     let vision_context = vision
         .map(|v| format!("\nVision (what you see): {v}"))
         .unwrap_or_default();
+    let people_context = if people.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "\nPeople you have recently seen or heard: {}",
+            people.join(", ")
+        )
+    };
 
     with_default_system_prompt(format!(
         "This is the situation as you understand it:\n\
          {}\n\
-         Formed at: {}{}{}\n\n\
+         Formed at: {}{}{}{}\n\n\
          You manage the conversation with the user. Respond directly to the user with what you want to say. \
          {CONVERSATION_SPEAKER_NOTE} \
          Keep the response to no more than two sentences. \
@@ -289,7 +462,8 @@ fn conversant_system_prompt(
         combobulation.text.trim(),
         combobulation.formed_at,
         prior_emoji,
-        vision_context
+        vision_context,
+        people_context
     ))
 }
 
@@ -297,6 +471,9 @@ fn conversant_system_prompt(
 struct ConversantAction {
     emoji: String,
     say: Option<String>,
+    /// Whether Pete was spoken to; speech is dropped when he was not.
+    addressed: bool,
+    addressee: Option<AddresseeVerdict>,
     system_prompt: String,
     history: Vec<ConversationEntry>,
     report: Option<WitReport>,
@@ -318,6 +495,8 @@ fn parse_conversant_action(raw: &str) -> ConversantAction {
     ConversantAction {
         emoji,
         say,
+        addressed: true,
+        addressee: None,
         system_prompt: String::new(),
         history: Vec::new(),
         report: None,
@@ -426,7 +605,7 @@ mod tests {
 
     #[test]
     fn conversant_prompt_has_no_tools_or_thinking_requirement() {
-        let prompt = conversant_system_prompt(&latest(), None);
+        let prompt = conversant_system_prompt(&latest(), None, &[]);
 
         assert!(prompt.contains("You are PETE"));
         assert!(prompt.contains("no more than two sentences"));
@@ -437,6 +616,48 @@ mod tests {
         assert!(!prompt.contains("read_source"));
     }
 
+    #[test]
+    fn conversant_prompt_refers_to_people_not_faces_and_voices() {
+        let face = |identity: Option<&str>, person: Option<&str>| GraphFaceIdentityTarget {
+            target_id: "face:1".into(),
+            target_label: "Face".into(),
+            face_instance_id: "face-instance:1".into(),
+            source_image_id: None,
+            vector_id: None,
+            identity: identity.map(Into::into),
+            occurred_at: "2026-05-07T12:00:00Z".into(),
+            person_id: person.map(Into::into),
+            pose: None,
+        };
+        let voice = |identity: Option<&str>, person: Option<&str>| GraphVoiceIdentityTarget {
+            target_id: "voice:1".into(),
+            target_label: "Voice".into(),
+            voice_signature_id: "voice-signature:1".into(),
+            audio_clip_id: None,
+            vector_id: None,
+            identity: identity.map(Into::into),
+            occurred_at: "2026-05-07T12:00:00Z".into(),
+            person_id: person.map(Into::into),
+        };
+        let names = PersonNames::new(&[psyche::GraphPerson {
+            person_id: "person:1".into(),
+            name: None,
+            face_ids: vec!["face:1".into()],
+            voice_ids: vec!["voice:1".into()],
+            ordinal: Some(1),
+        }]);
+
+        let people = people_names(
+            &[face(None, Some("person:1")), face(Some("Anna"), None)],
+            &[voice(None, Some("person:1")), voice(None, None)],
+            &names,
+        );
+        let prompt = conversant_system_prompt(&latest(), None, &people);
+
+        assert_eq!(people, vec!["unknown person 1", "Anna"]);
+        assert!(prompt.contains("People you have recently seen or heard: unknown person 1, Anna"));
+    }
+
     #[test]
     fn parses_short_chat_response_with_emoji() {
         let action = parse_conversant_action("Hello there! 🙂");
@@ -518,4 +739,60 @@ mod tests {
         assert_eq!(messages[0].role, lingproc::Role::Assistant);
         assert_eq!(messages[0].content, "Hello there.");
     }
+
+    fn heard(id: &str, kind: &str, text: &str, occurred_at: &str) -> GraphSensationTimelineItem {
+        GraphSensationTimelineItem {
+            id: id.into(),
+            labels: vec!["GraphNode".into(), "Sensation".into()],
+            kind: kind.into(),
+            text: text.into(),
+            occurred_at: occurred_at.into(),
+            formed_at: None,
+        }
+    }
+
+    #[test]
+    fn finds_speech_pete_has_not_answered() {
+        let history = vec![
+            heard("s:1", "cognitive", "I say: Hello.", "2026-05-07T12:00:00Z"),
+            heard(
+                "s:2",
+                "heard_user_voice",
+                "I heard: hi Pete",
+                "2026-05-07T12:00:03Z",
+            ),
+            heard("s:3", "silence", "I hear silence.", "2026-05-07T12:00:05Z"),
+        ];
+
+        let speech = unanswered_speech(&history).unwrap();
+
+        assert_eq!(speech.id, "s:2");
+        assert_eq!(speech.text, "hi Pete");
+        assert!(!speech.typed);
+        assert_eq!(speech.pete_said.unwrap().0, "Hello.");
+    }
+
+    #[test]
+    fn answered_speech_is_not_pending() {
+        let history = vec![
+            heard(
+                "s:1",
+                "heard_user_voice",
+                "I heard: hi",
+                "2026-05-07T12:00:00Z",
+            ),
+            heard("s:2", "cognitive", "I say: Hello.", "2026-05-07T12:00:01Z"),
+        ];
+
+        assert!(unanswered_speech(&history).is_none());
+    }
+
+    #[test]
+    fn cli_accepts_addressee_options() {
+        let cli = Cli::try_parse_from(["conversant", "--only-when-addressed", "--addressee-judge"])
+            .unwrap();
+
+        assert!(cli.only_when_addressed);
+        assert!(cli.addressee_judge);
+    }
 }
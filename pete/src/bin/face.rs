@@ -25,12 +25,14 @@ use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use chrono::{DateTime, Utc};
 use clap::Parser;
 use dotenvy::dotenv;
+use pete::codec;
+use pete::vad::{self, Vad, VadConfig};
+use pete::{EventBus, MediaEvent, TtsBackend, TtsConfig, init_logging, parse_data_url};
 #[cfg(feature = "tts")]
-use pete::{CoquiTts, synthesize_speech_audio};
-use pete::{EventBus, MediaEvent, init_logging, parse_data_url};
+use pete::{Tts, synthesize_speech_with_visemes};
 use psyche::{
-    AudioClip, ImageData, Impression, Neo4jClient, Sensation, SensationGraphObserver,
-    SensationObserver, Stimulus, Thought, WillTypeScriptExecution, image_content_id,
+    AudioClip, BlobConfig, ImageData, Impression, Neo4jClient, Sensation, SensationGraphObserver,
+    SensationObserver, Stimulus, Thought, VisemeCue, WillTypeScriptExecution, image_content_id,
 };
 use serde::Deserialize;
 use shared::{SpeechPlaybackStatus, WsPayload};
@@ -66,13 +68,7 @@ struct Cli {
     /// Path to TLS private key in PEM format.
     #[arg(long)]
     tls_key: Option<String>,
-    /// RMS threshold below which an audio chunk is treated as silence.
-    #[arg(long, env = "FACE_SILENCE_THRESHOLD", default_value_t = 0.015)]
-    silence_threshold: f32,
-    /// Required trailing silence before an audio line is flushed.
-    #[arg(long, env = "FACE_SILENCE_MS", default_value_t = 1200)]
-    silence_ms: u64,
-    /// Minimum buffered audio before silence can flush a line.
+    /// Minimum buffered audio before a pause can flush a line.
     #[arg(long, env = "FACE_AUDIO_MIN_MS", default_value_t = 250)]
     audio_min_ms: u64,
     /// Maximum buffered audio before forced flush.
@@ -88,19 +84,28 @@ struct Cli {
     /// Poll interval for speech intentions chosen by Will.
     #[arg(long, env = "FACE_SPEECH_POLL_MS", default_value_t = 1000)]
     speech_poll_ms: u64,
-    /// URL of the Coqui TTS server used for Will speech.
-    #[arg(
-        long,
-        env = "COQUI_URL",
-        default_value = "http://localhost:5002/api/tts"
-    )]
-    tts_url: String,
+    /// TTS backend used for Will speech: coqui, piper, openai or command.
+    #[arg(long, env = "TTS_BACKEND", default_value = "coqui")]
+    tts_backend: TtsBackend,
+    /// URL of the TTS server used for Will speech; defaults to the backend's
+    /// usual address.
+    #[arg(long, env = "TTS_URL")]
+    tts_url: Option<String>,
     /// Speaker ID for the TTS voice.
     #[arg(long, env = "SPEAKER", default_value = "p228")]
     tts_speaker_id: String,
     /// Language ID for the TTS voice.
     #[arg(long, default_value = "en")]
     tts_language_id: String,
+    /// Model name for an OpenAI-compatible TTS server.
+    #[arg(long, env = "TTS_MODEL")]
+    tts_model: Option<String>,
+    /// Bearer token for an OpenAI-compatible TTS server.
+    #[arg(long, env = "TTS_API_KEY")]
+    tts_api_key: Option<String>,
+    /// Command that reads text on stdin and writes WAV to stdout.
+    #[arg(long, env = "TTS_COMMAND")]
+    tts_command: Option<String>,
 }
 
 #[derive(Clone)]
@@ -118,8 +123,7 @@ struct FaceState {
 
 #[derive(Clone)]
 struct AudioLineConfig {
-    silence_threshold: f32,
-    silence_duration: Duration,
+    vad: VadConfig,
     min_duration: Duration,
     max_duration: Duration,
 }
@@ -131,11 +135,14 @@ async fn main() -> anyhow::Result<()> {
     dotenv().ok();
 
     let cli = Cli::parse();
-    let graph_store = Arc::new(Neo4jClient::new(
-        cli.neo4j_uri.clone(),
-        cli.neo4j_user.clone(),
-        cli.neo4j_pass.clone(),
-    ));
+    let graph_store = Arc::new(
+        Neo4jClient::new(
+            cli.neo4j_uri.clone(),
+            cli.neo4j_user.clone(),
+            cli.neo4j_pass.clone(),
+        )
+        .with_blob_store(BlobConfig::from_env()?.open()),
+    );
     let emotes = broadcast::channel(64).0;
     let latest_emote = Arc::new(Mutex::new(None));
     let latest_thought = Arc::new(Mutex::new(None));
@@ -148,8 +155,7 @@ async fn main() -> anyhow::Result<()> {
         image_sequence: Arc::new(AtomicU64::new(0)),
         audio_line_sequence: Arc::new(AtomicU64::new(0)),
         audio_config: AudioLineConfig {
-            silence_threshold: cli.silence_threshold,
-            silence_duration: Duration::from_millis(cli.silence_ms),
+            vad: VadConfig::from_env()?,
             min_duration: Duration::from_millis(cli.audio_min_ms),
             max_duration: Duration::from_millis(cli.audio_max_ms),
         },
@@ -169,9 +175,18 @@ async fn main() -> anyhow::Result<()> {
         state.emotes.clone(),
         state.connections.clone(),
         Duration::from_millis(cli.speech_poll_ms.max(100)),
-        cli.tts_url,
-        Some(cli.tts_speaker_id),
-        Some(cli.tts_language_id),
+        TtsConfig {
+            backend: cli.tts_backend,
+            url: cli
+                .tts_url
+                .unwrap_or_else(|| cli.tts_backend.default_url().into()),
+            voice: Some(cli.tts_speaker_id),
+            language: Some(cli.tts_language_id),
+            model: cli.tts_model,
+            api_key: cli.tts_api_key,
+            command: cli.tts_command,
+            ..TtsConfig::default()
+        },
     );
     spawn_thought_poller(
         graph_store.clone(),
@@ -295,7 +310,7 @@ async fn handle_socket(mut socket: WebSocket, state: FaceState) {
 
 async fn handle_request(request: WsPayload, state: &FaceState, audio_lines: &mut AudioLineBuffer) {
     match request {
-        WsPayload::See { data, at } => {
+        WsPayload::See { data, at, source } => {
             let Some((mime, base64)) = parse_data_url(&data) else {
                 warn!("invalid image data URL");
                 return;
@@ -311,6 +326,7 @@ async fn handle_request(request: WsPayload, state: &FaceState, audio_lines: &mut
                 mime,
                 base64,
                 captured_at: Some(occurred_at.to_rfc3339()),
+                source,
             };
             let content_id = image_content_id(&image);
             let sensation = Sensation::of_at(image.clone(), occurred_at);
@@ -328,11 +344,24 @@ async fn handle_request(request: WsPayload, state: &FaceState, audio_lines: &mut
         }
         WsPayload::Hear { data, at } => {
             let received_at = Utc::now();
+            let captured_at = parse_ws_at(at.as_deref()).unwrap_or(received_at);
+            if codec::is_compressed_mime(&data.mime) {
+                match audio_lines.ingest_compressed_base64(
+                    data.base64.trim(),
+                    &data.mime,
+                    captured_at,
+                    received_at,
+                ) {
+                    Ok(Some(line)) => store_audio_line(line, state).await,
+                    Ok(None) => {}
+                    Err(err) => warn!(%err, mime = %data.mime, "failed to decode audio frame"),
+                }
+                return;
+            }
             if !is_pcm_mime(&data.mime) {
-                warn!(mime = %data.mime, "ignored audio frame; expected 16-bit PCM");
+                warn!(mime = %data.mime, "ignored audio frame; expected 16-bit PCM or Opus");
                 return;
             }
-            let captured_at = parse_ws_at(at.as_deref()).unwrap_or(received_at);
             let sample_rate = data.sample_rate.unwrap_or(16_000);
             let channels = data.channels.unwrap_or(1).clamp(1, u16::MAX as u32) as u16;
             match audio_lines.ingest_base64(
@@ -427,7 +456,9 @@ struct AudioLineBuffer {
     channels: u16,
     started_at: Option<DateTime<Utc>>,
     last_received_at: Option<DateTime<Utc>>,
-    tail_silence_samples: usize,
+    vad: Vad,
+    /// Decoder for the connection's compressed recording, if it sends one.
+    decoder: Option<codec::CompressedAudioDecoder>,
 }
 
 struct CompletedAudioLine {
@@ -444,6 +475,7 @@ struct CompletedAudioLine {
 impl AudioLineBuffer {
     fn new(config: AudioLineConfig) -> Self {
         Self {
+            vad: Vad::new(config.vad.clone(), 16_000),
             config,
             samples: VecDeque::new(),
             mime: "audio/pcm;format=s16le;rate=16000".to_string(),
@@ -451,10 +483,46 @@ impl AudioLineBuffer {
             channels: 1,
             started_at: None,
             last_received_at: None,
-            tail_silence_samples: 0,
+            decoder: None,
         }
     }
 
+    /// Decode the next fragment of an Opus recording and buffer it as 16 kHz
+    /// mono PCM. A fragment that opens a new stream restarts the decoder.
+    fn ingest_compressed_base64(
+        &mut self,
+        base64: &str,
+        mime: &str,
+        captured_at: DateTime<Utc>,
+        received_at: DateTime<Utc>,
+    ) -> anyhow::Result<Option<CompletedAudioLine>> {
+        let bytes = BASE64_STANDARD.decode(base64.as_bytes())?;
+        if bytes.is_empty() {
+            return Ok(None);
+        }
+        let decoder = match self.decoder.take() {
+            Some(current) if !codec::starts_stream(&bytes) => self.decoder.insert(current),
+            _ => self
+                .decoder
+                .insert(codec::CompressedAudioDecoder::new(mime, 16_000)?),
+        };
+        let samples = match decoder.push(&bytes) {
+            Ok(samples) => samples,
+            Err(err) => {
+                self.decoder = None;
+                return Err(err);
+            }
+        };
+        self.ingest_pcm(
+            codec::encode_pcm_s16le(&samples),
+            "audio/pcm;format=s16le;rate=16000".to_string(),
+            16_000,
+            1,
+            captured_at,
+            received_at,
+        )
+    }
+
     fn ingest_base64(
         &mut self,
         base64: &str,
@@ -465,6 +533,18 @@ impl AudioLineBuffer {
         received_at: DateTime<Utc>,
     ) -> anyhow::Result<Option<CompletedAudioLine>> {
         let bytes = BASE64_STANDARD.decode(base64.as_bytes())?;
+        self.ingest_pcm(bytes, mime, sample_rate, channels, captured_at, received_at)
+    }
+
+    fn ingest_pcm(
+        &mut self,
+        bytes: Vec<u8>,
+        mime: String,
+        sample_rate: u32,
+        channels: u16,
+        captured_at: DateTime<Utc>,
+        received_at: DateTime<Utc>,
+    ) -> anyhow::Result<Option<CompletedAudioLine>> {
         if bytes.is_empty() {
             return Ok(None);
         }
@@ -473,29 +553,23 @@ impl AudioLineBuffer {
             self.sample_rate = sample_rate;
             self.channels = channels;
             self.started_at = Some(captured_at);
-            self.tail_silence_samples = 0;
+            if self.vad.sample_rate() != sample_rate {
+                self.vad = Vad::new(self.config.vad.clone(), sample_rate);
+            }
         }
         self.last_received_at = Some(received_at);
 
-        let mut sum_sq = 0.0f64;
-        let mut count = 0usize;
+        let mut chunk_samples = Vec::with_capacity(bytes.len() / 2);
         for chunk in bytes.chunks_exact(2) {
             let sample = i16::from_le_bytes([chunk[0], chunk[1]]);
-            let normalized = sample as f32 / i16::MAX as f32;
-            sum_sq += f64::from(normalized) * f64::from(normalized);
+            chunk_samples.push(sample as f32 / i16::MAX as f32);
             self.samples.push_back(sample);
-            count += 1;
         }
-        if count == 0 {
+        if chunk_samples.is_empty() {
             return Ok(None);
         }
-
-        let rms = (sum_sq / count as f64).sqrt() as f32;
-        if rms <= self.config.silence_threshold {
-            self.tail_silence_samples = self.tail_silence_samples.saturating_add(count);
-        } else {
-            self.tail_silence_samples = 0;
-        }
+        self.vad
+            .push(&vad::downmix(&chunk_samples, usize::from(self.channels)));
 
         if self.should_flush() {
             return Ok(self.flush(received_at));
@@ -514,13 +588,8 @@ impl AudioLineBuffer {
             self.sample_rate,
             u32::from(self.channels),
         );
-        let silence_samples = duration_samples(
-            self.config.silence_duration,
-            self.sample_rate,
-            u32::from(self.channels),
-        );
         self.samples.len() >= max_samples
-            || (self.samples.len() >= min_samples && self.tail_silence_samples >= silence_samples)
+            || (self.samples.len() >= min_samples && self.vad.is_paused())
     }
 
     fn flush(&mut self, received_at: DateTime<Utc>) -> Option<CompletedAudioLine> {
@@ -528,12 +597,7 @@ impl AudioLineBuffer {
             return None;
         }
 
-        trim_silence(
-            &mut self.samples,
-            self.sample_rate,
-            u32::from(self.channels),
-            self.config.silence_threshold,
-        );
+        self.trim_silence();
         if self.samples.is_empty() {
             self.reset();
             return None;
@@ -562,10 +626,28 @@ impl AudioLineBuffer {
         Some(line)
     }
 
+    /// Keep only the audio between the first and last speech segment.
+    fn trim_silence(&mut self) {
+        let channels = usize::from(self.channels).max(1);
+        let interleaved: Vec<f32> = self
+            .samples
+            .iter()
+            .map(|s| *s as f32 / i16::MAX as f32)
+            .collect();
+        let mono = vad::downmix(&interleaved, channels);
+        match vad::speech_span(&mono, self.sample_rate, &self.config.vad) {
+            Some(span) => {
+                self.samples.truncate(span.end * channels);
+                self.samples.drain(..span.start * channels);
+            }
+            None => self.samples.clear(),
+        }
+    }
+
     fn reset(&mut self) {
         self.started_at = None;
         self.last_received_at = None;
-        self.tail_silence_samples = 0;
+        self.vad.reset();
     }
 }
 
@@ -573,35 +655,6 @@ fn duration_samples(duration: Duration, sample_rate: u32, channels: u32) -> usiz
     (duration.as_secs_f32() * sample_rate as f32 * channels as f32).round() as usize
 }
 
-fn trim_silence(samples: &mut VecDeque<i16>, sample_rate: u32, channels: u32, threshold: f32) {
-    let window = ((sample_rate * channels) / 50).max(1) as usize;
-    while samples.len() >= window && rms(samples.iter().take(window).copied()) <= threshold {
-        for _ in 0..window {
-            samples.pop_front();
-        }
-    }
-    while samples.len() >= window && rms(samples.iter().rev().take(window).copied()) <= threshold {
-        for _ in 0..window {
-            samples.pop_back();
-        }
-    }
-}
-
-fn rms(samples: impl Iterator<Item = i16>) -> f32 {
-    let mut sum_sq = 0.0f64;
-    let mut count = 0usize;
-    for sample in samples {
-        let normalized = sample as f32 / i16::MAX as f32;
-        sum_sq += f64::from(normalized) * f64::from(normalized);
-        count += 1;
-    }
-    if count == 0 {
-        0.0
-    } else {
-        (sum_sq / count as f64).sqrt() as f32
-    }
-}
-
 fn is_pcm_mime(mime: &str) -> bool {
     let lower = mime.to_ascii_lowercase();
     lower.starts_with("audio/pcm") || lower.starts_with("audio/l16") || lower.contains("format=s16")
@@ -653,17 +706,18 @@ fn spawn_speech_intention_poller(
     tx: broadcast::Sender<WsPayload>,
     connections: Arc<AtomicUsize>,
     poll_interval: Duration,
-    tts_url: String,
-    speaker_id: Option<String>,
-    language_id: Option<String>,
+    tts: TtsConfig,
 ) {
     tokio::spawn(async move {
         let mut last_id: Option<String> = None;
         #[cfg(feature = "tts")]
-        let tts = CoquiTts::new(tts_url, speaker_id, language_id);
+        let tts = tts
+            .build()
+            .inspect_err(|err| error!(%err, "failed to build TTS backend; queued speech is silent"))
+            .ok();
         #[cfg(not(feature = "tts"))]
         let tts = {
-            let _ = (tts_url, speaker_id, language_id);
+            let _ = tts;
             ()
         };
         loop {
@@ -674,11 +728,12 @@ fn spawn_speech_intention_poller(
             match graph.latest_pending_speech_intention().await {
                 Ok(Some(intention)) if last_id.as_deref() != Some(intention.id.as_str()) => {
                     last_id = Some(intention.id);
-                    let audio = speech_audio(&intention.text, &tts).await;
+                    let (audio, visemes) = speech_audio(&intention.text, &tts).await;
                     if tx
                         .send(WsPayload::Say {
                             words: intention.text.clone(),
                             audio,
+                            visemes,
                         })
                         .is_ok()
                     {
@@ -818,20 +873,26 @@ fn parse_will_report_payload(raw: &str) -> Option<WillReportPayload> {
     })
 }
 
+/// Base64 WAV for queued speech and the mouth shapes to show while it plays.
+type SpeechAudio = (Option<String>, Option<Vec<VisemeCue>>);
+
 #[cfg(feature = "tts")]
-async fn speech_audio(text: &str, tts: &CoquiTts) -> Option<String> {
-    match synthesize_speech_audio(tts, text).await {
-        Ok(audio) => audio,
+async fn speech_audio(text: &str, tts: &Option<Arc<dyn Tts>>) -> SpeechAudio {
+    let Some(tts) = tts.as_deref() else {
+        return (None, None);
+    };
+    match synthesize_speech_with_visemes(tts, text, &Default::default()).await {
+        Ok(spoken) => (spoken.audio, spoken.visemes),
         Err(err) => {
             warn!(%err, "tts request failed for queued speech");
-            None
+            (None, None)
         }
     }
 }
 
 #[cfg(not(feature = "tts"))]
-async fn speech_audio(_text: &str, _tts: &()) -> Option<String> {
-    None
+async fn speech_audio(_text: &str, _tts: &()) -> SpeechAudio {
+    (None, None)
 }
 
 async fn store_speech_playback_sensation(
@@ -958,7 +1019,11 @@ fn parse_flat_ws_request(text: &str) -> Option<WsPayload> {
                 .get("at")
                 .and_then(|at| at.as_str())
                 .map(ToString::to_string);
-            Some(WsPayload::See { data, at })
+            let source = value
+                .get("source")
+                .and_then(|source| source.as_str())
+                .map(ToString::to_string);
+            Some(WsPayload::See { data, at, source })
         }
         "Hear" => {
             let data = serde_json::from_value(value.get("data")?.clone()).ok()?;
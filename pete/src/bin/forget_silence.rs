@@ -8,6 +8,8 @@ use chrono::Utc;
 use clap::Parser;
 use dotenvy::dotenv;
 use hound::{SampleFormat, WavReader};
+use pete::codec;
+use pete::vad::{self, VadConfig};
 use pete::{EventBus, init_logging};
 use reqwest::Url;
 use serde::Deserialize;
@@ -37,12 +39,6 @@ struct Cli {
     /// Number of unchecked AudioClip nodes to inspect per loop.
     #[arg(long, env = "FORGET_SILENCE_BATCH_SIZE", default_value_t = 100)]
     batch_size: usize,
-    /// RMS threshold at or below which an audio window is treated as silence.
-    #[arg(long, env = "FORGET_SILENCE_THRESHOLD", default_value_t = 0.015)]
-    silence_threshold: f32,
-    /// RMS window size for speech detection.
-    #[arg(long, env = "FORGET_SILENCE_WINDOW_MS", default_value_t = 20)]
-    window_ms: u64,
     /// Print decisions without deleting or marking graph nodes.
     #[arg(long)]
     dry_run: bool,
@@ -93,6 +89,7 @@ async fn main() -> Result<()> {
     dotenv().ok();
 
     let cli = Cli::parse();
+    let vad_config = VadConfig::from_env()?;
     let graph = Neo4jHttp::new(
         cli.neo4j_uri.clone(),
         cli.neo4j_user.clone(),
@@ -103,21 +100,20 @@ async fn main() -> Result<()> {
 
     info!(
         batch_size = cli.batch_size,
-        threshold = cli.silence_threshold,
-        window_ms = cli.window_ms,
+        vad = ?vad_config,
         dry_run = cli.dry_run,
         "forget-silence loop started"
     );
 
     loop {
         ticker.tick().await;
-        if let Err(err) = sweep_once(&graph, &cli).await {
+        if let Err(err) = sweep_once(&graph, &cli, &vad_config).await {
             error!(error = %err, "forget-silence loop iteration failed");
         }
     }
 }
 
-async fn sweep_once(graph: &Neo4jHttp, cli: &Cli) -> Result<()> {
+async fn sweep_once(graph: &Neo4jHttp, cli: &Cli, vad_config: &VadConfig) -> Result<()> {
     let candidates = graph.fetch_candidates(cli.batch_size.max(1)).await?;
     if candidates.is_empty() {
         trace!("no unchecked audio clips found");
@@ -127,7 +123,7 @@ async fn sweep_once(graph: &Neo4jHttp, cli: &Cli) -> Result<()> {
     let mut silent_ids = Vec::new();
     let mut checked = Vec::new();
     for candidate in candidates {
-        match classify_candidate(&candidate, cli.silence_threshold, cli.window_ms) {
+        match classify_candidate(&candidate, vad_config) {
             Ok(stats) => {
                 info!(
                     clip_id = %candidate.id,
@@ -185,13 +181,9 @@ async fn sweep_once(graph: &Neo4jHttp, cli: &Cli) -> Result<()> {
     Ok(())
 }
 
-fn classify_candidate(
-    candidate: &AudioCandidate,
-    threshold: f32,
-    window_ms: u64,
-) -> Result<AudioStats> {
+fn classify_candidate(candidate: &AudioCandidate, vad_config: &VadConfig) -> Result<AudioStats> {
     let blank_audio_only = has_only_blank_audio_transcription(candidate);
-    let stats = match classify_audio_content(candidate, threshold, window_ms) {
+    let stats = match classify_audio_content(candidate, vad_config) {
         Ok(mut stats) => {
             stats.blank_audio_only = blank_audio_only;
             stats.silent = stats.silent || blank_audio_only;
@@ -212,8 +204,7 @@ fn classify_candidate(
 
 fn classify_audio_content(
     candidate: &AudioCandidate,
-    threshold: f32,
-    window_ms: u64,
+    vad_config: &VadConfig,
 ) -> Result<AudioStats> {
     let decoded = BASE64_STANDARD
         .decode(candidate.base64.as_bytes())
@@ -228,6 +219,10 @@ fn classify_audio_content(
         || decoded.starts_with(b"RIFF")
     {
         decode_wav(&decoded)?
+    } else if codec::is_compressed_mime(&mime) || codec::starts_stream(&decoded) {
+        let samples = codec::decode_compressed_audio(&decoded, &mime, 16_000)
+            .with_context(|| format!("failed to decode compressed audio {}", candidate.id))?;
+        (samples, 16_000, 1)
     } else {
         decode_pcm_s16(
             &decoded,
@@ -247,8 +242,11 @@ fn classify_audio_content(
         .iter()
         .map(|sample| sample.abs())
         .fold(0.0, f32::max);
-    let silent =
-        !contains_audio_above_threshold(&samples, sample_rate, channels, threshold, window_ms);
+    let silent = !vad::contains_speech(
+        &vad::downmix(&samples, channel_count),
+        sample_rate,
+        vad_config,
+    );
 
     Ok(AudioStats {
         duration_ms,
@@ -321,35 +319,6 @@ fn decode_wav(bytes: &[u8]) -> Result<(Vec<f32>, u32, u16)> {
     Ok((samples, spec.sample_rate, spec.channels))
 }
 
-fn contains_audio_above_threshold(
-    samples: &[f32],
-    sample_rate: u32,
-    channels: u16,
-    threshold: f32,
-    window_ms: u64,
-) -> bool {
-    if samples.is_empty() {
-        return false;
-    }
-    let channels = usize::from(channels.max(1));
-    let window =
-        ((u128::from(sample_rate.max(1)) * channels as u128 * u128::from(window_ms.max(1))) / 1000)
-            .max(1)
-            .min(usize::MAX as u128) as usize;
-    if samples.len() <= window {
-        return rms(samples) > threshold;
-    }
-    let step = (window / 2).max(1);
-    let mut start = 0;
-    while start + window <= samples.len() {
-        if rms(&samples[start..start + window]) > threshold {
-            return true;
-        }
-        start += step;
-    }
-    false
-}
-
 fn rms(samples: &[f32]) -> f32 {
     if samples.is_empty() {
         return 0.0;
@@ -634,28 +603,61 @@ mod tests {
     use base64::Engine;
     use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 
-    use super::{
-        AudioCandidate, classify_candidate, contains_audio_above_threshold,
-        has_only_blank_audio_transcription, rms,
-    };
+    use super::{AudioCandidate, classify_candidate, has_only_blank_audio_transcription, rms};
+    use pete::vad::VadConfig;
+
+    fn pcm_candidate(id: &str, samples: impl IntoIterator<Item = f32>) -> AudioCandidate {
+        let mut bytes = Vec::new();
+        for sample in samples {
+            bytes.extend_from_slice(&((sample * i16::MAX as f32) as i16).to_le_bytes());
+        }
+        AudioCandidate {
+            id: id.into(),
+            mime: None,
+            base64: BASE64_STANDARD.encode(bytes),
+            sample_rate: Some(16_000),
+            channels: Some(1),
+            transcript: None,
+            transcriptions: Vec::new(),
+        }
+    }
 
     #[test]
     fn short_quiet_clip_is_silence() {
-        let samples = vec![0.001; 320];
+        let candidate = pcm_candidate("audio:quiet", vec![0.001; 320]);
 
-        assert!(!contains_audio_above_threshold(
-            &samples, 16_000, 1, 0.015, 20
-        ));
+        let stats = classify_candidate(&candidate, &VadConfig::default()).unwrap();
+
+        assert!(stats.silent);
     }
 
     #[test]
-    fn window_above_threshold_is_not_silence() {
-        let mut samples = vec![0.001; 640];
-        samples[320..640].fill(0.05);
+    fn voiced_audio_is_not_silence() {
+        let mut samples = vec![0.001; 3_200];
+        samples.extend(
+            (0..8_000)
+                .map(|i| 0.2 * (2.0 * std::f32::consts::PI * 220.0 * i as f32 / 16_000.0).sin()),
+        );
+        let candidate = pcm_candidate("audio:voiced", samples);
+
+        let stats = classify_candidate(&candidate, &VadConfig::default()).unwrap();
 
-        assert!(contains_audio_above_threshold(
-            &samples, 16_000, 1, 0.015, 20
-        ));
+        assert!(!stats.silent);
+    }
+
+    #[test]
+    fn loud_broadband_noise_is_silence() {
+        let mut seed = 7u32;
+        let noise = (0..16_000).map(|_| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            ((seed >> 16) as f32 / 32_768.0 - 1.0) * 0.2
+        });
+        let candidate = pcm_candidate("audio:hiss", noise);
+
+        let stats = classify_candidate(&candidate, &VadConfig::default()).unwrap();
+
+        assert!(stats.silent);
+        assert!(stats.rms > 0.05);
     }
 
     #[test]
@@ -679,7 +681,8 @@ mod tests {
             transcriptions: vec!["[BLANK_AUDIO]".into()],
         };
 
-        let stats = classify_candidate(&candidate, 0.015, 20).expect("clip should classify");
+        let stats =
+            classify_candidate(&candidate, &VadConfig::default()).expect("clip should classify");
 
         assert!(stats.blank_audio_only);
         assert!(stats.silent);
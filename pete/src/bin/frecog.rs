@@ -1,15 +1,26 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 use anyhow::Context;
+use chrono::{DateTime, Utc};
 use clap::Parser;
 use dotenvy::dotenv;
 use pete::{EventBus, init_logging};
 use psyche::{
-    FaceDetector, FaceIdDetector, GraphFaceDetection, GraphFaceMatch, GraphImageFrame, Neo4jClient,
-    QdrantClient, image_content_id,
+    BlobConfig, BoundingBox, DetectedFace, FaceDetector, FaceIdDetector, FaceTrack,
+    FaceTrackAssignment, FaceTracker, FaceTrackerConfig, GraphFaceDetection, GraphFaceMatch,
+    GraphFaceTrack, GraphImageFrame, ImageRunKind, LeaseSettings, Neo4jClient, OptOutAction,
+    PrivacyConfig, QdrantClient, SceneChangeConfig, SceneGate, WorkLease, blur_regions,
+    image_captured_at, image_content_id, parse_observed_at, with_work_lease,
+};
+use tokio::{
+    task::JoinHandle,
+    time::{MissedTickBehavior, interval},
 };
-use tokio::time::{MissedTickBehavior, interval};
-use tracing::{error, info, trace};
+use tracing::{debug, error, info, trace, warn};
 
 #[derive(Parser)]
 #[command(
@@ -39,6 +50,33 @@ struct Cli {
     /// Minimum Qdrant similarity for treating a detected face as a known face.
     #[arg(long, env = "FRECOG_FACE_MATCH_THRESHOLD", default_value_t = 0.86)]
     face_match_threshold: f32,
+    /// Minimum crop quality (sharpness and pose, `0.0..=1.0`) for matching a
+    /// detected face against known faces; poorer crops are stored unmatched.
+    #[arg(long, env = "FRECOG_MIN_QUALITY", default_value_t = 0.35)]
+    min_quality: f32,
+    /// Match every detected face instead of following faces across frames
+    /// (tracking is tuned by FACE_TRACK_*). Each camera or stream is tracked
+    /// by one replica at a time; the others take the remaining sources.
+    #[arg(long, env = "FRECOG_NO_TRACKING")]
+    no_tracking: bool,
+    /// Leave frames captured longer ago than this unrecognized, so a backlog
+    /// never holds up the newest frames; `0` recognizes frames of any age.
+    #[arg(long, env = "FRECOG_MAX_FRAME_AGE_MS", default_value_t = 120_000)]
+    max_frame_age_ms: u64,
+    /// Run detection on every frame instead of skipping frames that repeat
+    /// an already recognized scene (change detection is tuned by
+    /// SCENE_CHANGE_*).
+    #[arg(long, env = "FRECOG_NO_SCENE_GATE")]
+    no_scene_gate: bool,
+    /// How long a claimed frame stays leased to this worker without a heartbeat.
+    #[arg(long, env = "FRECOG_LEASE_MS", default_value_t = 60_000)]
+    lease_ms: u64,
+    /// Lease owner id; defaults to the host name and process id.
+    #[arg(long, env = "WORKER_ID")]
+    worker_id: Option<String>,
+    /// Claims a work item gets before it is marked failed and skipped.
+    #[arg(long, env = "WORK_LEASE_MAX_ATTEMPTS", default_value_t = WorkLease::DEFAULT_MAX_ATTEMPTS)]
+    max_attempts: u64,
     /// Process at most one frame and exit.
     #[arg(long)]
     once: bool,
@@ -51,13 +89,35 @@ async fn main() -> anyhow::Result<()> {
     dotenv().ok();
 
     let cli = Cli::parse();
-    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass);
+    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass)
+        .with_blob_store(BlobConfig::from_env()?.open())
+        .with_max_lease_attempts(cli.max_attempts);
     let qdrant = QdrantClient::new(cli.qdrant_url);
     let detector = Arc::new(
         FaceIdDetector::from_hf()
             .await
             .context("failed to initialize face recognition detector")?,
     );
+    let lease = LeaseSettings::new(cli.worker_id, cli.lease_ms);
+    let tracking = if cli.no_tracking {
+        None
+    } else {
+        Some(FaceTrackerConfig::from_env()?)
+    };
+    let matching = MatchSettings {
+        threshold: cli.face_match_threshold,
+        min_quality: cli.min_quality,
+        max_frame_age: (cli.max_frame_age_ms > 0)
+            .then(|| Duration::from_millis(cli.max_frame_age_ms)),
+        tracking,
+        privacy: PrivacyConfig::from_env()?,
+        sources: Mutex::new(HashMap::new()),
+    };
+    let scene_gate = if cli.no_scene_gate {
+        None
+    } else {
+        Some(SceneGate::new(SceneChangeConfig::from_env()?))
+    };
 
     if cli.once {
         process_next_frame(
@@ -65,7 +125,9 @@ async fn main() -> anyhow::Result<()> {
             &qdrant,
             detector,
             &cli.detector,
-            cli.face_match_threshold,
+            &matching,
+            &lease,
+            scene_gate.as_ref(),
         )
         .await?;
         return Ok(());
@@ -74,7 +136,7 @@ async fn main() -> anyhow::Result<()> {
     let mut ticker = interval(Duration::from_millis(cli.poll_ms.max(100)));
     ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
-    info!("face recognition loop started");
+    info!(owner = %lease.owner, "face recognition loop started");
     loop {
         ticker.tick().await;
         if let Err(err) = process_next_frame(
@@ -82,7 +144,9 @@ async fn main() -> anyhow::Result<()> {
             &qdrant,
             detector.clone(),
             &cli.detector,
-            cli.face_match_threshold,
+            &matching,
+            &lease,
+            scene_gate.as_ref(),
         )
         .await
         {
@@ -91,29 +155,239 @@ async fn main() -> anyhow::Result<()> {
     }
 }
 
+/// How detected faces are matched against known faces.
+struct MatchSettings {
+    threshold: f32,
+    min_quality: f32,
+    /// Frames captured longer ago than this are left unrecognized.
+    max_frame_age: Option<Duration>,
+    /// Tracker settings, when faces are followed across frames.
+    tracking: Option<FaceTrackerConfig>,
+    /// What to do with faces of opted-out identities (set by PRIVACY_*).
+    privacy: PrivacyConfig,
+    /// Sources whose faces this replica tracks, by source name.
+    sources: Mutex<HashMap<String, Arc<SourceTracking>>>,
+}
+
+/// Faces followed across the frames of one camera or stream.
+struct SourceTracking {
+    /// Follows faces across frames so only new or doubtful tracks are matched.
+    tracker: FaceTracker,
+    /// Tracks following an opted-out face, which stay discarded until they end.
+    opted_out_tracks: Mutex<HashSet<String>>,
+    /// Capture time of the newest tracked frame, and when it was tracked.
+    last_frame: Mutex<Option<(DateTime<Utc>, Instant)>>,
+    /// Heartbeat of the lease that makes this replica the one tracking the source.
+    lease: JoinHandle<()>,
+}
+
+impl SourceTracking {
+    /// Note that a frame captured at `at` was tracked.
+    fn saw_frame(&self, at: DateTime<Utc>) {
+        let mut last = self.last_frame.lock().unwrap();
+        if last.is_none_or(|(seen, _)| at >= seen) {
+            *last = Some((at, Instant::now()));
+        }
+    }
+
+    /// Whether a frame captured at `at` can still be tracked. Tracks only
+    /// move forward, so frames older than the newest tracked one are
+    /// recognized without them.
+    fn follows(&self, at: DateTime<Utc>) -> bool {
+        self.last_frame
+            .lock()
+            .unwrap()
+            .is_none_or(|(seen, _)| at >= seen)
+    }
+
+    /// Capture time the camera has likely reached: the newest tracked frame
+    /// plus the time since, or `None` before any frame was tracked.
+    fn frame_clock(&self) -> Option<DateTime<Utc>> {
+        let (at, seen) = (*self.last_frame.lock().unwrap())?;
+        Some(at + chrono::Duration::from_std(seen.elapsed()).unwrap_or_default())
+    }
+}
+
+impl Drop for SourceTracking {
+    fn drop(&mut self) {
+        self.lease.abort();
+    }
+}
+
 async fn process_next_frame(
     graph: &Neo4jClient,
     qdrant: &QdrantClient,
     detector: Arc<dyn FaceDetector>,
     detector_name: &str,
-    face_match_threshold: f32,
+    matching: &MatchSettings,
+    lease_settings: &LeaseSettings,
+    scene_gate: Option<&SceneGate>,
 ) -> anyhow::Result<()> {
+    let not_before = matching
+        .max_frame_age
+        .and_then(|age| chrono::Duration::from_std(age).ok())
+        .map(|age| Utc::now() - age);
+    let tracking_owner = matching
+        .tracking
+        .as_ref()
+        .map(|_| lease_settings.owner.as_str());
     let Some(frame) = graph
-        .latest_unprocessed_image_frame_for_face_recognition()
+        .newest_unprocessed_image_frame_for_face_recognition(not_before, tracking_owner)
         .await
-        .context("failed to load latest unprocessed image frame")?
+        .context("failed to load newest unprocessed image frame")?
     else {
         trace!("no unprocessed image frames found");
+        end_idle_tracks(graph, matching).await?;
         return Ok(());
     };
+    let tracking = match &matching.tracking {
+        Some(config) => {
+            let Some(tracking) =
+                track_source(graph, matching, config, lease_settings, frame.source()).await?
+            else {
+                trace!(
+                    source = frame.source(),
+                    "another replica is tracking faces of this source"
+                );
+                return Ok(());
+            };
+            Some(tracking)
+        }
+        None => None,
+    };
+    let work = async {
+        let tracking = tracking.filter(|tracking| tracking.follows(frame_observed_at(&frame)));
+        if reuse_keyframe(graph, qdrant, scene_gate, tracking.as_deref(), &frame).await {
+            Ok(())
+        } else {
+            recognize_frame(
+                graph,
+                qdrant,
+                detector,
+                detector_name,
+                matching,
+                tracking.as_deref(),
+                &frame,
+            )
+            .await
+        }
+    };
+    with_work_lease(
+        graph,
+        WorkLease::FACE_RECOGNITION,
+        &frame.id,
+        lease_settings,
+        work,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Tracking state for `source` when this replica holds its face tracking
+/// lease, claiming the lease when free. Tracks live in memory, so each
+/// source is followed by one replica at a time.
+async fn track_source(
+    graph: &Neo4jClient,
+    matching: &MatchSettings,
+    config: &FaceTrackerConfig,
+    lease_settings: &LeaseSettings,
+    source: &str,
+) -> anyhow::Result<Option<Arc<SourceTracking>>> {
+    if let Some(tracking) = matching
+        .sources
+        .lock()
+        .unwrap()
+        .get(source)
+        .filter(|tracking| !tracking.lease.is_finished())
+    {
+        return Ok(Some(tracking.clone()));
+    }
+    let Some(lease) = graph
+        .claim_worker_lease(
+            &WorkLease::face_tracking(source),
+            &lease_settings.owner,
+            lease_settings.ttl,
+        )
+        .await
+        .with_context(|| format!("failed to lease face tracking for {source}"))?
+    else {
+        matching.sources.lock().unwrap().remove(source);
+        return Ok(None);
+    };
+    info!(owner = %lease.owner, source, "tracking faces");
+    let tracking = Arc::new(SourceTracking {
+        tracker: FaceTracker::new(config.clone()).with_source(source),
+        opted_out_tracks: Mutex::new(HashSet::new()),
+        last_frame: Mutex::new(None),
+        lease: graph.spawn_lease_heartbeat(lease, lease_settings.ttl),
+    });
+    matching
+        .sources
+        .lock()
+        .unwrap()
+        .insert(source.to_string(), tracking.clone());
+    Ok(Some(tracking))
+}
+
+/// End the tracks of faces that went unseen while no frames came in.
+async fn end_idle_tracks(graph: &Neo4jClient, matching: &MatchSettings) -> anyhow::Result<()> {
+    let sources: Vec<_> = matching.sources.lock().unwrap().values().cloned().collect();
+    for tracking in sources {
+        let Some(now) = tracking.frame_clock() else {
+            continue;
+        };
+        let plan = tracking.tracker.plan(&[], now);
+        end_tracks(graph, &tracking, &plan.update().ended).await?;
+        forget_ended(&tracking, &tracking.tracker.commit(plan).ended);
+    }
+    Ok(())
+}
 
+async fn recognize_frame(
+    graph: &Neo4jClient,
+    qdrant: &QdrantClient,
+    detector: Arc<dyn FaceDetector>,
+    detector_name: &str,
+    matching: &MatchSettings,
+    tracking: Option<&SourceTracking>,
+    frame: &GraphImageFrame,
+) -> anyhow::Result<()> {
     info!(image_id = %frame.id, "recognizing faces in image frame");
     let faces = detector
         .detect_faces(&frame.image)
         .await
         .with_context(|| format!("failed to recognize faces in image {}", frame.id))?;
+    let occurred_at = frame_observed_at(frame);
+    // Tracker state changes only once the frame is stored, so a failed write
+    // is tracked again from the same tracks.
+    let mut plan = tracking.map(|tracking| {
+        let observed: Vec<_> = faces
+            .iter()
+            .map(|face| (face.details.bbox, face.embedding.as_slice()))
+            .collect();
+        tracking.tracker.plan(&observed, occurred_at)
+    });
+    let tracks = match (&plan, tracking) {
+        (Some(plan), Some(tracking)) => {
+            end_tracks(graph, tracking, &plan.update().ended).await?;
+            plan.update().assignments.clone()
+        }
+        _ => Vec::new(),
+    };
     let mut detections = Vec::with_capacity(faces.len());
-    for (index, (mut crop, embedding)) in faces.into_iter().enumerate() {
+    let mut opted_out_regions = Vec::new();
+    for (index, face) in faces.into_iter().enumerate() {
+        let DetectedFace {
+            mut crop,
+            embedding,
+            details,
+        } = face;
+        let assignment = tracks.get(index);
+        if is_opted_out(graph, qdrant, matching, tracking, &embedding, assignment).await? {
+            debug!(image_id = %frame.id, index, "discarding face of an opted-out identity");
+            opted_out_regions.extend(details.bbox);
+            continue;
+        }
         if crop.captured_at.is_none() {
             crop.captured_at = frame
                 .image
@@ -132,15 +406,31 @@ async fn process_next_frame(
             .await
             .with_context(|| format!("failed to store face vector for {face_id}"))?
             .to_string();
-        let recognition = match_face(
-            graph,
-            qdrant,
-            &embedding,
-            &vector_id,
-            face_match_threshold,
-            &face_id,
-        )
-        .await?;
+        let quality = details.quality.map(|quality| quality.score);
+        let (recognition, matched) = match (assignment, quality) {
+            (Some(assignment), _) if !assignment.needs_identity => {
+                (assignment.recognition.clone(), false)
+            }
+            (assignment, Some(quality)) if quality < matching.min_quality => {
+                debug!(%face_id, quality, "skipping identity match for low-quality face crop");
+                (assignment.and_then(|a| a.recognition.clone()), false)
+            }
+            (assignment, _) => {
+                let recognition = match_face(
+                    graph,
+                    qdrant,
+                    &embedding,
+                    &vector_id,
+                    matching.threshold,
+                    &face_id,
+                )
+                .await?;
+                if let (Some(plan), Some(assignment)) = (&mut plan, assignment) {
+                    plan.identified(&assignment.track_id, recognition.clone());
+                }
+                (recognition, true)
+            }
+        };
         detections.push(GraphFaceDetection {
             index,
             face_id,
@@ -148,17 +438,177 @@ async fn process_next_frame(
             vector_id,
             embedding_len: embedding.len(),
             recognition,
+            details,
+            track: assignment.map(|assignment| GraphFaceTrack {
+                track_id: assignment.track_id.clone(),
+                started: assignment.started,
+                matched,
+            }),
         });
     }
 
-    graph
-        .attach_face_recognition(&frame, detector_name, &detections)
-        .await
+    let attached = if tracking.is_some() {
+        graph
+            .attach_tracked_face_recognition(frame, detector_name, &detections)
+            .await
+    } else {
+        graph
+            .attach_face_recognition(frame, detector_name, &detections)
+            .await
+    };
+    attached
         .with_context(|| format!("failed to attach face recognition for image {}", frame.id))?;
-    log_completion(&frame, detections.len());
+    if let (Some(tracking), Some(plan)) = (tracking, plan) {
+        forget_ended(tracking, &tracking.tracker.commit(plan).ended);
+        tracking.saw_frame(occurred_at);
+    }
+    if matching.privacy.opt_out_action == OptOutAction::Blur && !opted_out_regions.is_empty() {
+        blur_frame(graph, frame, &opted_out_regions, &matching.privacy).await?;
+    }
+    log_completion(frame, detections.len());
     Ok(())
 }
 
+/// Whether a face belongs to an opted-out identity. Tracks are checked
+/// until identified, and an opted-out track stays opted out until it ends.
+async fn is_opted_out(
+    graph: &Neo4jClient,
+    qdrant: &QdrantClient,
+    matching: &MatchSettings,
+    tracking: Option<&SourceTracking>,
+    embedding: &[f32],
+    assignment: Option<&FaceTrackAssignment>,
+) -> anyhow::Result<bool> {
+    if let (Some(tracking), Some(assignment)) = (tracking, assignment) {
+        if tracking
+            .opted_out_tracks
+            .lock()
+            .unwrap()
+            .contains(&assignment.track_id)
+        {
+            return Ok(true);
+        }
+        if !assignment.needs_identity {
+            return Ok(false);
+        }
+    }
+    let Some(neighbor) = qdrant
+        .nearest_face_neighbor(embedding, "", matching.threshold)
+        .await
+        .context("failed to search nearest face neighbor for opt-out")?
+    else {
+        return Ok(false);
+    };
+    let Some(identity_id) = graph
+        .opted_out_face_identity(&neighbor.point_id)
+        .await
+        .context("failed to check face opt-out")?
+    else {
+        return Ok(false);
+    };
+    info!(%identity_id, "face of an opted-out identity in view");
+    if let (Some(tracking), Some(assignment)) = (tracking, assignment) {
+        tracking
+            .opted_out_tracks
+            .lock()
+            .unwrap()
+            .insert(assignment.track_id.clone());
+    }
+    Ok(true)
+}
+
+/// Replace the stored frame with one where `regions` are blurred.
+async fn blur_frame(
+    graph: &Neo4jClient,
+    frame: &GraphImageFrame,
+    regions: &[BoundingBox],
+    privacy: &PrivacyConfig,
+) -> anyhow::Result<()> {
+    let image = frame.image.clone();
+    let regions = regions.to_vec();
+    let privacy = privacy.clone();
+    let blurred = tokio::task::spawn_blocking(move || blur_regions(&image, &regions, &privacy))
+        .await
+        .context("face blurring task failed")?
+        .with_context(|| format!("failed to blur image {}", frame.id))?;
+    graph
+        .replace_image_payload(&frame.id, &blurred)
+        .await
+        .with_context(|| format!("failed to store blurred image {}", frame.id))
+}
+
+/// Reuse the recognition run of the keyframe `frame` repeats, returning
+/// whether the frame needs no detection of its own.
+async fn reuse_keyframe(
+    graph: &Neo4jClient,
+    qdrant: &QdrantClient,
+    scene_gate: Option<&SceneGate>,
+    tracking: Option<&SourceTracking>,
+    frame: &GraphImageFrame,
+) -> bool {
+    let Some(gate) = scene_gate else {
+        return false;
+    };
+    match gate
+        .reuse_keyframe_run(graph, qdrant, frame, ImageRunKind::FaceRecognition)
+        .await
+    {
+        Ok(Some(keyframe_id)) => {
+            // The faces of the keyframe are still in view.
+            if let Some(tracking) = tracking {
+                let at = frame_observed_at(frame);
+                tracking.tracker.hold(at);
+                tracking.saw_frame(at);
+            }
+            info!(image_id = %frame.id, %keyframe_id, "reused face recognition of unchanged scene");
+            true
+        }
+        Ok(None) => false,
+        Err(err) => {
+            warn!(image_id = %frame.id, error = %err, "scene change check failed");
+            false
+        }
+    }
+}
+
+fn frame_observed_at(frame: &GraphImageFrame) -> DateTime<Utc> {
+    image_captured_at(&frame.image)
+        .or_else(|| frame.occurred_at.as_deref().and_then(parse_observed_at))
+        .unwrap_or_else(Utc::now)
+}
+
+/// Record tracks that ended as people leaving view.
+async fn end_tracks(
+    graph: &Neo4jClient,
+    tracking: &SourceTracking,
+    ended: &[FaceTrack],
+) -> anyhow::Result<()> {
+    // Tracks of opted-out faces were never stored, so nobody left.
+    let ended: Vec<FaceTrack> = {
+        let opted_out = tracking.opted_out_tracks.lock().unwrap();
+        ended
+            .iter()
+            .filter(|track| !opted_out.contains(&track.id))
+            .cloned()
+            .collect()
+    };
+    for track in &ended {
+        info!(track_id = %track.id, frames = track.frames, "face track ended");
+    }
+    graph
+        .attach_face_track_ends(&ended)
+        .await
+        .context("failed to record ended face tracks")
+}
+
+/// Stop discarding faces of tracks that have ended for good.
+fn forget_ended(tracking: &SourceTracking, ended: &[FaceTrack]) {
+    let mut opted_out = tracking.opted_out_tracks.lock().unwrap();
+    for track in ended {
+        opted_out.remove(&track.id);
+    }
+}
+
 async fn match_face(
     graph: &Neo4jClient,
     qdrant: &QdrantClient,
@@ -0,0 +1,466 @@
+//! Inspect and correct the people Pete has put names to.
+//!
+//! Identities come from cluster labelling and from the Will naming a face or
+//! voice. When one is wrong this tool renames it, merges two identities that
+//! are the same person, splits off a cluster that belongs to someone else, or
+//! deletes it. Qdrant payloads of the affected face and voice vectors follow
+//! along. A face or voice wrongly linked into a person is unlinked, and the
+//! person linker leaves that pairing alone afterwards.
+//!
+//! People who do not want to be remembered are opted out: recognition
+//! workers then discard their new faces and voices. Purging also deletes
+//! their stored crops, audio, vectors and derived sensations, and blurs their
+//! faces in stored frames.
+//!
+//! ```bash
+//! cargo run -p pete --bin identities -- list
+//! cargo run -p pete --bin identities -- --dry-run merge "Jon" "John"
+//! cargo run -p pete --bin identities -- unlink voice-cluster:7
+//! cargo run -p pete --bin identities -- purge "Jon" --confirm
+//! ```
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use dotenvy::dotenv;
+use pete::{EventBus, init_logging};
+use psyche::{
+    BlobConfig, GraphFaceRegion, GraphIdentity, GraphIdentityVector, Neo4jClient, PrivacyConfig,
+    QdrantClient, blur_regions, person_identity_id,
+};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+const IDENTITY_PAYLOAD_KEYS: &[&str] = &["identity_id", "identity_name"];
+
+#[derive(Parser)]
+#[command(
+    author,
+    version,
+    about = "List, rename, merge, split, delete, opt out, and purge face and voice identities, and unlink people"
+)]
+struct Cli {
+    /// Neo4j bolt or HTTP URI.
+    #[arg(long, env = "NEO4J_URI", default_value = "bolt://localhost:7687")]
+    neo4j_uri: String,
+    /// Neo4j username.
+    #[arg(long, env = "NEO4J_USER", default_value = "neo4j")]
+    neo4j_user: String,
+    /// Neo4j password.
+    #[arg(long, env = "NEO4J_PASS", default_value = "password")]
+    neo4j_pass: String,
+    /// Qdrant HTTP endpoint.
+    #[arg(long, env = "QDRANT_URL", default_value = "http://localhost:6333")]
+    qdrant_url: String,
+    /// Print what would change without changing anything.
+    #[arg(long, global = true)]
+    dry_run: bool,
+    #[command(subcommand)]
+    cmd: Cmd,
+}
+
+/// Identities are given by graph id or by name.
+#[derive(Subcommand)]
+enum Cmd {
+    /// List identities with their sample counts and when they were last seen.
+    List,
+    /// Give an identity a new name.
+    Rename { identity: String, name: String },
+    /// Fold `from` into `into`, deleting `from`.
+    Merge { from: String, into: String },
+    /// Detach a face or voice cluster (or single sample) from an identity.
+    Split {
+        identity: String,
+        /// Graph id of the cluster or sample to detach.
+        target: String,
+        /// Name the detached cluster belongs to instead.
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// Delete an identity, leaving its faces and voices unnamed.
+    Delete { identity: String },
+    /// Detach a face or voice cluster from the person it was linked into.
+    Unlink {
+        /// Graph id of the face or voice cluster.
+        member: String,
+    },
+    /// Stop remembering new faces and voices of an identity.
+    OptOut { identity: String },
+    /// Remember an opted-out identity again.
+    OptIn { identity: String },
+    /// Opt an identity out and delete its stored crops, audio and derived
+    /// sensations, blurring its faces in stored frames.
+    Purge {
+        identity: String,
+        /// Also delete the face and voice vectors. They are kept by default
+        /// because new detections are matched to the opt-out through them;
+        /// without them the identity would be remembered again.
+        #[arg(long)]
+        forget_vectors: bool,
+        /// Required to purge; nothing purged can be restored.
+        #[arg(long)]
+        confirm: bool,
+    },
+}
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() -> anyhow::Result<()> {
+    let (bus, _user_rx) = EventBus::new();
+    init_logging(bus.log_sender());
+    dotenv().ok();
+
+    let cli = Cli::parse();
+    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass)
+        .with_blob_store(BlobConfig::from_env()?.open());
+    let qdrant = QdrantClient::new(cli.qdrant_url);
+    let dry_run = cli.dry_run;
+
+    match cli.cmd {
+        Cmd::List => list(&graph).await,
+        Cmd::Rename { identity, name } => {
+            let identity = existing(&graph, &identity).await?;
+            let new_id = person_identity_id(&name);
+            if dry_run {
+                println!(
+                    "would rename {} to {name:?} ({new_id})",
+                    describe(&identity)
+                );
+                return Ok(());
+            }
+            let new_id = graph
+                .rename_identity(&identity.identity_id, &name)
+                .await
+                .context("failed to rename identity")?;
+            let updated = label_vectors(&graph, &qdrant, &new_id, &name).await?;
+            println!(
+                "renamed {} to {name:?} ({new_id}); updated {updated} vectors",
+                identity.identity_id
+            );
+            Ok(())
+        }
+        Cmd::Merge { from, into } => {
+            let from = existing(&graph, &from).await?;
+            let into = existing(&graph, &into).await?;
+            if dry_run {
+                let vectors = graph
+                    .identity_vectors(&from.identity_id, None)
+                    .await
+                    .context("failed to load identity vectors")?;
+                println!(
+                    "would merge {} into {} and relabel {} vectors",
+                    describe(&from),
+                    describe(&into),
+                    vectors.len()
+                );
+                return Ok(());
+            }
+            let moved = graph
+                .merge_identities(&from.identity_id, &into.identity_id)
+                .await
+                .context("failed to merge identities")?;
+            let updated = label_vectors(&graph, &qdrant, &into.identity_id, &into.name).await?;
+            println!(
+                "merged {} into {}: moved {moved} faces and voices, updated {updated} vectors",
+                from.identity_id, into.identity_id
+            );
+            Ok(())
+        }
+        Cmd::Split {
+            identity,
+            target,
+            name,
+        } => {
+            let identity = existing(&graph, &identity).await?;
+            let vectors = graph
+                .identity_vectors(&identity.identity_id, Some(&target))
+                .await
+                .context("failed to load identity vectors")?;
+            let destination = match &name {
+                Some(name) => format!("{name:?} ({})", person_identity_id(name)),
+                None => "no identity".into(),
+            };
+            if dry_run {
+                println!(
+                    "would split {target} ({} vectors) from {} to {destination}",
+                    vectors.len(),
+                    describe(&identity)
+                );
+                return Ok(());
+            }
+            let new_id = graph
+                .split_identity(
+                    &identity.identity_id,
+                    &target,
+                    name.as_deref(),
+                    "identities",
+                )
+                .await
+                .context("failed to split identity")?;
+            match (&new_id, &name) {
+                (Some(new_id), Some(name)) => {
+                    set_identity_payload(&qdrant, &vectors, new_id, name).await?
+                }
+                _ => clear_identity_payload(&qdrant, &vectors).await?,
+            }
+            println!(
+                "split {target} from {} to {destination}; updated {} vectors",
+                identity.identity_id,
+                vectors.len()
+            );
+            Ok(())
+        }
+        Cmd::Delete { identity } => {
+            let identity = existing(&graph, &identity).await?;
+            let vectors = graph
+                .identity_vectors(&identity.identity_id, None)
+                .await
+                .context("failed to load identity vectors")?;
+            if dry_run {
+                println!(
+                    "would delete {} and unlabel {} vectors",
+                    describe(&identity),
+                    vectors.len()
+                );
+                return Ok(());
+            }
+            let detached = graph
+                .delete_identity(&identity.identity_id)
+                .await
+                .context("failed to delete identity")?;
+            clear_identity_payload(&qdrant, &vectors).await?;
+            println!(
+                "deleted {}: detached {detached} faces and voices, unlabelled {} vectors",
+                identity.identity_id,
+                vectors.len()
+            );
+            Ok(())
+        }
+        Cmd::Unlink { member } => {
+            let person = graph
+                .people()
+                .await
+                .context("failed to load people")?
+                .into_iter()
+                .find(|person| {
+                    person.face_ids.contains(&member) || person.voice_ids.contains(&member)
+                })
+                .with_context(|| format!("{member} is not linked to a person"))?;
+            if dry_run {
+                println!("would unlink {member} from {}", person.person_id);
+                return Ok(());
+            }
+            graph
+                .unlink_person_member(&member)
+                .await
+                .context("failed to unlink person member")?
+                .with_context(|| format!("{member} is not linked to a person"))?;
+            println!("unlinked {member} from {}", person.person_id);
+            Ok(())
+        }
+        Cmd::OptOut { identity } => set_opt_out(&graph, &identity, true, dry_run).await,
+        Cmd::OptIn { identity } => set_opt_out(&graph, &identity, false, dry_run).await,
+        Cmd::Purge {
+            identity,
+            forget_vectors,
+            confirm,
+        } => {
+            let identity = existing(&graph, &identity).await?;
+            let mut purge = graph
+                .identity_purge(&identity.identity_id)
+                .await
+                .context("failed to find identity data to purge")?;
+            if !forget_vectors {
+                purge.vectors.clear();
+            }
+            let summary = format!(
+                "{} face crops, {} voice samples, {} audio clips, {} sensations, {} vectors and {} face regions",
+                purge.face_ids.len(),
+                purge.voice_ids.len(),
+                purge.audio_clip_ids.len(),
+                purge.sensation_ids.len(),
+                purge.vectors.len(),
+                purge.face_regions.len()
+            );
+            if dry_run {
+                println!("would purge {}: {summary}", describe(&identity));
+                return Ok(());
+            }
+            anyhow::ensure!(
+                confirm,
+                "refusing to purge without --confirm; use --dry-run to inspect first"
+            );
+            let blurred = blur_frames(&graph, &purge.face_regions).await?;
+            let deleted = graph
+                .purge_identity(&purge)
+                .await
+                .context("failed to purge identity")?;
+            for (collection, point_ids) in by_collection(&purge.vectors) {
+                qdrant
+                    .delete_points(collection, &point_ids)
+                    .await
+                    .with_context(|| format!("failed to delete vectors in {collection}"))?;
+            }
+            println!(
+                "purged {}: {summary}; deleted {deleted} graph nodes, blurred {blurred} frames",
+                identity.identity_id
+            );
+            Ok(())
+        }
+    }
+}
+
+async fn set_opt_out(
+    graph: &Neo4jClient,
+    identity: &str,
+    opted_out: bool,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let identity = existing(graph, identity).await?;
+    let change = if opted_out { "opt out" } else { "opt in" };
+    if dry_run {
+        println!("would {change} {}", describe(&identity));
+        return Ok(());
+    }
+    graph
+        .set_identity_opt_out(&identity.identity_id, opted_out)
+        .await
+        .with_context(|| format!("failed to {change} identity"))?;
+    println!("{}: {change}", identity.identity_id);
+    Ok(())
+}
+
+/// Blur the face regions in each stored frame, returning how many frames
+/// changed.
+async fn blur_frames(graph: &Neo4jClient, regions: &[GraphFaceRegion]) -> anyhow::Result<usize> {
+    let privacy = PrivacyConfig::from_env()?;
+    let mut frames = BTreeMap::<&str, Vec<_>>::new();
+    for region in regions {
+        frames
+            .entry(region.image_id.as_str())
+            .or_default()
+            .push(region.bbox);
+    }
+    let mut blurred = 0;
+    for (image_id, boxes) in frames {
+        let Some(frame) = graph
+            .image_frame(image_id)
+            .await
+            .with_context(|| format!("failed to load image {image_id}"))?
+        else {
+            continue;
+        };
+        let image = blur_regions(&frame.image, &boxes, &privacy)
+            .with_context(|| format!("failed to blur image {image_id}"))?;
+        graph
+            .replace_image_payload(image_id, &image)
+            .await
+            .with_context(|| format!("failed to store blurred image {image_id}"))?;
+        blurred += 1;
+    }
+    Ok(blurred)
+}
+
+async fn list(graph: &Neo4jClient) -> anyhow::Result<()> {
+    let identities = graph
+        .identities()
+        .await
+        .context("failed to list identities")?;
+    if identities.is_empty() {
+        println!("no identities");
+    }
+    for identity in identities {
+        println!(
+            "{}\t{}\tfaces={}\tvoices={}\tlast_seen={}\tclusters={}{}",
+            identity.identity_id,
+            identity.name,
+            identity.face_samples,
+            identity.voice_samples,
+            identity.last_seen_at.as_deref().unwrap_or("never"),
+            identity.clusters.join(","),
+            if identity.opted_out {
+                "\topted_out"
+            } else {
+                ""
+            }
+        );
+    }
+    Ok(())
+}
+
+/// Load the identity given by id or name.
+async fn existing(graph: &Neo4jClient, identity: &str) -> anyhow::Result<GraphIdentity> {
+    let identity_id = if identity.starts_with("identity:") {
+        identity.to_string()
+    } else {
+        person_identity_id(identity)
+    };
+    graph
+        .identity(&identity_id)
+        .await
+        .context("failed to load identity")?
+        .with_context(|| format!("identity {identity_id} not found"))
+}
+
+fn describe(identity: &GraphIdentity) -> String {
+    format!(
+        "{} {:?} ({} faces, {} voices)",
+        identity.identity_id, identity.name, identity.face_samples, identity.voice_samples
+    )
+}
+
+/// Point the payloads of every vector of `identity_id` at it.
+async fn label_vectors(
+    graph: &Neo4jClient,
+    qdrant: &QdrantClient,
+    identity_id: &str,
+    name: &str,
+) -> anyhow::Result<usize> {
+    let vectors = graph
+        .identity_vectors(identity_id, None)
+        .await
+        .context("failed to load identity vectors")?;
+    set_identity_payload(qdrant, &vectors, identity_id, name).await?;
+    Ok(vectors.len())
+}
+
+async fn set_identity_payload(
+    qdrant: &QdrantClient,
+    vectors: &[GraphIdentityVector],
+    identity_id: &str,
+    name: &str,
+) -> anyhow::Result<()> {
+    for (collection, point_ids) in by_collection(vectors) {
+        qdrant
+            .set_payload(
+                collection,
+                &point_ids,
+                json!({ "identity_id": identity_id, "identity_name": name }),
+            )
+            .await
+            .with_context(|| format!("failed to relabel vectors in {collection}"))?;
+    }
+    Ok(())
+}
+
+async fn clear_identity_payload(
+    qdrant: &QdrantClient,
+    vectors: &[GraphIdentityVector],
+) -> anyhow::Result<()> {
+    for (collection, point_ids) in by_collection(vectors) {
+        qdrant
+            .delete_payload_keys(collection, &point_ids, IDENTITY_PAYLOAD_KEYS)
+            .await
+            .with_context(|| format!("failed to unlabel vectors in {collection}"))?;
+    }
+    Ok(())
+}
+
+fn by_collection(vectors: &[GraphIdentityVector]) -> BTreeMap<&str, Vec<String>> {
+    let mut collections = BTreeMap::<&str, Vec<String>>::new();
+    for vector in vectors {
+        collections
+            .entry(vector.collection.as_str())
+            .or_default()
+            .push(vector.point_id.clone());
+    }
+    collections
+}
@@ -5,11 +5,12 @@ use base64::Engine;
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use clap::Parser;
 use dotenvy::dotenv;
-use lingproc::{ImageData as LImageData, LlmInstruction, Vectorizer};
-use pete::{EventBus, init_logging, ollama_provider_from_args};
+use lingproc::{ImageData as LImageData, LlmInstruction, Priority, Vectorizer};
+use pete::{EventBus, init_logging, scheduled_ollama_provider};
 use psyche::{
-    Doer, GraphImageDescription, GraphImageFrame, GraphLatestCombobulation, IMAGE_CAPTION_PROMPT,
-    Neo4jClient, QdrantClient, with_default_system_prompt,
+    BlobConfig, Doer, GraphImageDescription, GraphImageFrame, GraphLatestCombobulation,
+    IMAGE_CAPTION_PROMPT, ImageRunKind, Neo4jClient, QdrantClient, SceneChangeConfig, SceneGate,
+    with_default_system_prompt,
 };
 use tokio::time::{MissedTickBehavior, interval};
 use tracing::{error, info, trace, warn};
@@ -62,6 +63,10 @@ struct Cli {
     /// Delay between graph polling attempts.
     #[arg(long, env = "IMAGE_DESCRIPTION_POLL_MS", default_value_t = 1000)]
     poll_ms: u64,
+    /// Describe every frame instead of reusing the description of an
+    /// unchanged scene (change detection is tuned by SCENE_CHANGE_*).
+    #[arg(long, env = "IMAGE_DESCRIPTION_NO_SCENE_GATE")]
+    no_scene_gate: bool,
     /// Process at most one frame and exit.
     #[arg(long)]
     once: bool,
@@ -74,17 +79,31 @@ async fn main() -> anyhow::Result<()> {
     dotenv().ok();
 
     let cli = Cli::parse();
-    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass);
+    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass)
+        .with_blob_store(BlobConfig::from_env()?.open());
     let qdrant = QdrantClient::new(cli.qdrant_url);
     ensure_vision_model(&cli.image_description_model)?;
-    let describer =
-        ollama_provider_from_args(&cli.image_description_host, &cli.image_description_model)?;
-    let vectorizer = ollama_provider_from_args(&cli.embeddings_host, &cli.embeddings_model)?;
+    let describer = scheduled_ollama_provider(
+        &cli.image_description_host,
+        &cli.image_description_model,
+        Priority::Background,
+    )?;
+    let vectorizer = scheduled_ollama_provider(
+        &cli.embeddings_host,
+        &cli.embeddings_model,
+        Priority::Background,
+    )?;
+    let scene_gate = if cli.no_scene_gate {
+        None
+    } else {
+        Some(SceneGate::new(SceneChangeConfig::from_env()?))
+    };
     let processor = ImageDescriptionProcessor {
         describer,
         vectorizer,
         vision_model: cli.image_description_model,
         embedding_model: cli.embeddings_model,
+        scene_gate,
     };
 
     if cli.once {
@@ -117,6 +136,21 @@ async fn process_next_frame(
         trace!("no undescribed image frames found");
         return Ok(());
     };
+    if let Some(gate) = &processor.scene_gate {
+        match gate
+            .reuse_keyframe_run(graph, qdrant, &frame, ImageRunKind::Description)
+            .await
+        {
+            Ok(Some(keyframe_id)) => {
+                info!(image_id = %frame.id, %keyframe_id, "reused description of unchanged scene");
+                return Ok(());
+            }
+            Ok(None) => {}
+            Err(err) => {
+                warn!(image_id = %frame.id, error = %err, "scene change check failed");
+            }
+        }
+    }
 
     let combobulation = graph.latest_combobulation().await.unwrap_or(None);
 
@@ -145,10 +179,12 @@ async fn process_next_frame(
 }
 
 struct ImageDescriptionProcessor {
-    describer: lingproc::OllamaProvider,
-    vectorizer: lingproc::OllamaProvider,
+    describer: lingproc::Scheduled<lingproc::OllamaProvider>,
+    vectorizer: lingproc::Scheduled<lingproc::OllamaProvider>,
     vision_model: String,
     embedding_model: String,
+    /// Skips frames that repeat an already described scene.
+    scene_gate: Option<SceneGate>,
 }
 
 impl ImageDescriptionProcessor {
@@ -189,6 +225,7 @@ impl ImageDescriptionProcessor {
                         mime: frame.image.mime.clone(),
                         base64,
                         captured_at: frame.image.captured_at.clone(),
+                        source: frame.image.source.clone(),
                     }],
                 })
                 .await?
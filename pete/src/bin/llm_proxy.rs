@@ -0,0 +1,38 @@
+use clap::Parser;
+use dotenvy::dotenv;
+use lingproc::LlmScheduler;
+use pete::{EventBus, init_logging, llm_proxy_router};
+use tracing::info;
+
+#[derive(Parser)]
+#[command(
+    author,
+    version,
+    about = "Queue model requests from every Pete process by priority before they reach Ollama"
+)]
+struct Cli {
+    /// Address to bind the proxy.
+    #[arg(long, env = "LLM_PROXY_LISTEN", default_value = "0.0.0.0:11435")]
+    listen: String,
+    /// Ollama server the proxy forwards to.
+    #[arg(
+        long,
+        env = "LLM_PROXY_UPSTREAM",
+        default_value = "http://localhost:11434"
+    )]
+    upstream: String,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let (bus, _user_rx) = EventBus::new();
+    init_logging(bus.log_sender());
+    dotenv().ok();
+    let cli = Cli::parse();
+
+    let listener = tokio::net::TcpListener::bind(&cli.listen).await?;
+    info!(listen = %cli.listen, upstream = %cli.upstream, "LLM proxy listening");
+    let app = llm_proxy_router(cli.upstream, LlmScheduler::from_env());
+    axum::serve(listener, app.into_make_service()).await?;
+    Ok(())
+}
@@ -8,7 +8,7 @@ use pete::{
         default_movie_path, default_time_range, default_work_dir, parse_time, render_graph_movie,
     },
 };
-use psyche::Neo4jClient;
+use psyche::{BlobConfig, Neo4jClient};
 
 #[derive(Parser)]
 #[command(
@@ -51,7 +51,8 @@ async fn main() -> anyhow::Result<()> {
         cli.neo4j_uri.clone(),
         cli.neo4j_user.clone(),
         cli.neo4j_pass.clone(),
-    );
+    )
+    .with_blob_store(BlobConfig::from_env()?.open());
     let from = cli.from.as_deref().map(parse_time).transpose()?;
     let to = cli.to.as_deref().map(parse_time).transpose()?;
     let (from, to) = default_time_range(&graph, from, to).await?;
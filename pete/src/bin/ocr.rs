@@ -0,0 +1,263 @@
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use dotenvy::dotenv;
+use pete::{EventBus, init_logging};
+use psyche::{
+    BlobConfig, GraphImageFrame, GraphStore, GraphTextReading, ImageRunKind, LeaseSettings,
+    Neo4jClient, OcrConfig, PaddleTextRecognizer, QdrantClient, SceneChangeConfig, SceneGate,
+    Sensation, SensationGraphObserver, SensationObserver, TextRecognizer, WorkLease,
+    distinct_text_blocks, image_captured_at, parse_observed_at, with_work_lease,
+};
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info, trace, warn};
+
+#[derive(Parser)]
+#[command(
+    author,
+    version,
+    about = "Read text in stored Image graph nodes and link what was read"
+)]
+struct Cli {
+    /// Neo4j bolt or HTTP URI.
+    #[arg(long, env = "NEO4J_URI", default_value = "bolt://localhost:7687")]
+    neo4j_uri: String,
+    /// Neo4j username.
+    #[arg(long, env = "NEO4J_USER", default_value = "neo4j")]
+    neo4j_user: String,
+    /// Neo4j password.
+    #[arg(long, env = "NEO4J_PASS", default_value = "password")]
+    neo4j_pass: String,
+    /// Qdrant HTTP endpoint, used to compare scenes.
+    #[arg(long, env = "QDRANT_URL", default_value = "http://localhost:6333")]
+    qdrant_url: String,
+    /// PaddleOCR-style ONNX text detection model.
+    #[arg(long, env = "OCR_DETECTION_MODEL")]
+    detection_model: PathBuf,
+    /// PaddleOCR-style ONNX text recognition model.
+    #[arg(long, env = "OCR_RECOGNITION_MODEL")]
+    recognition_model: PathBuf,
+    /// Character dictionary of the recognition model, one per line.
+    #[arg(long, env = "OCR_DICTIONARY")]
+    dictionary: PathBuf,
+    /// Longest side of the detection model input in pixels.
+    #[arg(long, env = "OCR_MAX_SIDE", default_value_t = 960)]
+    max_side: u32,
+    /// Minimum recognition confidence for reporting text.
+    #[arg(long, env = "OCR_CONFIDENCE", default_value_t = 0.5)]
+    confidence: f32,
+    /// Engine label stored on text-recognition runs.
+    #[arg(long, env = "OCR_ENGINE", default_value = "paddleocr")]
+    engine: String,
+    /// How long after reading a text the same text is linked to new frames
+    /// without being sensed again.
+    #[arg(long, env = "OCR_REPEAT_WINDOW_MS", default_value_t = 600_000)]
+    repeat_window_ms: u64,
+    /// Delay between graph polling attempts.
+    #[arg(long, env = "OCR_POLL_MS", default_value_t = 1000)]
+    poll_ms: u64,
+    /// How long a claimed frame stays leased to this worker without a heartbeat.
+    #[arg(long, env = "OCR_LEASE_MS", default_value_t = 60_000)]
+    lease_ms: u64,
+    /// Lease owner id; defaults to the host name and process id.
+    #[arg(long, env = "WORKER_ID")]
+    worker_id: Option<String>,
+    /// Claims a work item gets before it is marked failed and skipped.
+    #[arg(long, env = "WORK_LEASE_MAX_ATTEMPTS", default_value_t = WorkLease::DEFAULT_MAX_ATTEMPTS)]
+    max_attempts: u64,
+    /// Read every frame instead of reusing the reading of an unchanged
+    /// scene (change detection is tuned by SCENE_CHANGE_*).
+    #[arg(long, env = "OCR_NO_SCENE_GATE")]
+    no_scene_gate: bool,
+    /// Process at most one frame and exit.
+    #[arg(long)]
+    once: bool,
+}
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() -> anyhow::Result<()> {
+    let (bus, _user_rx) = EventBus::new();
+    init_logging(bus.log_sender());
+    dotenv().ok();
+
+    let cli = Cli::parse();
+    let graph = Arc::new(
+        Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass)
+            .with_blob_store(BlobConfig::from_env()?.open())
+            .with_max_lease_attempts(cli.max_attempts),
+    );
+    let observer = SensationGraphObserver::new(graph.clone() as Arc<dyn GraphStore>);
+    let recognizer = PaddleTextRecognizer::from_files(
+        &cli.detection_model,
+        &cli.recognition_model,
+        &cli.dictionary,
+    )
+    .context("failed to initialize text recognizer")?
+    .with_config(OcrConfig {
+        max_side: cli.max_side,
+        confidence: cli.confidence,
+        ..OcrConfig::default()
+    });
+    let scene_gate = if cli.no_scene_gate {
+        None
+    } else {
+        Some(SceneGate::new(SceneChangeConfig::from_env()?))
+    };
+    let worker = Worker {
+        graph,
+        qdrant: QdrantClient::new(cli.qdrant_url),
+        observer,
+        recognizer: Arc::new(recognizer),
+        engine: cli.engine,
+        repeat_window: Duration::from_millis(cli.repeat_window_ms),
+        scene_gate,
+        lease: LeaseSettings::new(cli.worker_id, cli.lease_ms),
+    };
+
+    if cli.once {
+        worker.process_next_frame().await?;
+        return Ok(());
+    }
+
+    let mut ticker = interval(Duration::from_millis(cli.poll_ms.max(100)));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    info!(owner = %worker.lease.owner, "text recognition loop started");
+    loop {
+        ticker.tick().await;
+        if let Err(err) = worker.process_next_frame().await {
+            error!(error = %err, "text recognition loop iteration failed");
+        }
+    }
+}
+
+struct Worker {
+    graph: Arc<Neo4jClient>,
+    qdrant: QdrantClient,
+    observer: SensationGraphObserver,
+    recognizer: Arc<dyn TextRecognizer>,
+    engine: String,
+    /// Text read again within this window is not sensed again.
+    repeat_window: Duration,
+    /// Skips frames that repeat an already read scene.
+    scene_gate: Option<SceneGate>,
+    lease: LeaseSettings,
+}
+
+impl Worker {
+    async fn process_next_frame(&self) -> anyhow::Result<()> {
+        let Some(frame) = self
+            .graph
+            .latest_unprocessed_image_frame_for_text_recognition()
+            .await
+            .context("failed to load latest unprocessed image frame")?
+        else {
+            trace!("no unprocessed image frames found");
+            return Ok(());
+        };
+        let work = async {
+            if self.reuse_keyframe(&frame).await {
+                Ok(())
+            } else {
+                self.read_frame(&frame).await
+            }
+        };
+        with_work_lease(
+            &self.graph,
+            WorkLease::TEXT_RECOGNITION,
+            &frame.id,
+            &self.lease,
+            work,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Reuse the reading of the keyframe `frame` repeats, returning whether
+    /// the frame needs no reading of its own.
+    async fn reuse_keyframe(&self, frame: &GraphImageFrame) -> bool {
+        let Some(gate) = &self.scene_gate else {
+            return false;
+        };
+        match gate
+            .reuse_keyframe_run(
+                &self.graph,
+                &self.qdrant,
+                frame,
+                ImageRunKind::TextRecognition,
+            )
+            .await
+        {
+            Ok(Some(keyframe_id)) => {
+                info!(image_id = %frame.id, %keyframe_id, "reused text recognition of unchanged scene");
+                true
+            }
+            Ok(None) => false,
+            Err(err) => {
+                warn!(image_id = %frame.id, error = %err, "scene change check failed");
+                false
+            }
+        }
+    }
+
+    async fn read_frame(&self, frame: &GraphImageFrame) -> anyhow::Result<()> {
+        info!(image_id = %frame.id, "reading text in image frame");
+        let blocks = self
+            .recognizer
+            .recognize_text(&frame.image)
+            .await
+            .with_context(|| format!("failed to read text in image {}", frame.id))?;
+        let blocks = distinct_text_blocks(blocks);
+        let occurred_at = frame_observed_at(frame);
+        let text_ids: Vec<String> = blocks.iter().map(|block| block.text_id()).collect();
+        let since =
+            occurred_at - chrono::Duration::from_std(self.repeat_window).unwrap_or_default();
+        let recent = self
+            .graph
+            .recently_read_texts(&text_ids, &since.to_rfc3339())
+            .await
+            .context("failed to look up recently read text")?;
+
+        let mut readings = Vec::with_capacity(blocks.len());
+        for ((index, mut block), text_id) in blocks.into_iter().enumerate().zip(text_ids) {
+            block.source_image_id = Some(frame.id.clone());
+            let sensation_id = if recent.contains(&text_id) {
+                trace!(image_id = %frame.id, %text_id, "text was read recently");
+                None
+            } else {
+                let sensation = Sensation::of_at(block.clone(), occurred_at);
+                self.observer.observe_sensation(&sensation).await;
+                Some(sensation.id())
+            };
+            readings.push(GraphTextReading {
+                index,
+                text_id,
+                text: block.text,
+                sensation_id,
+                confidence: block.confidence,
+                bbox: block.bbox,
+            });
+        }
+
+        self.graph
+            .attach_text_recognition(frame, &self.engine, &occurred_at.to_rfc3339(), &readings)
+            .await
+            .with_context(|| format!("failed to attach text recognition for image {}", frame.id))?;
+        info!(
+            image_id = %frame.id,
+            sensation_id = frame.sensation_id.as_deref().unwrap_or(""),
+            text_count = readings.len(),
+            sensed_count = readings.iter().filter(|r| r.sensation_id.is_some()).count(),
+            "attached text recognition"
+        );
+        Ok(())
+    }
+}
+
+fn frame_observed_at(frame: &GraphImageFrame) -> DateTime<Utc> {
+    image_captured_at(&frame.image)
+        .or_else(|| frame.occurred_at.as_deref().and_then(parse_observed_at))
+        .unwrap_or_else(Utc::now)
+}
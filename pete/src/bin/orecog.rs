@@ -0,0 +1,195 @@
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+use anyhow::Context;
+use chrono::Utc;
+use clap::Parser;
+use dotenvy::dotenv;
+use pete::{EventBus, init_logging};
+use psyche::{
+    BlobConfig, GraphImageFrame, GraphObjectDetection, GraphStore, LeaseSettings, Neo4jClient,
+    ObjectDetector, Sensation, SensationGraphObserver, SensationObserver, WorkLease, YoloConfig,
+    YoloObjectDetector, image_captured_at, parse_observed_at, with_work_lease,
+};
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info, trace};
+
+#[derive(Parser)]
+#[command(
+    author,
+    version,
+    about = "Detect objects in stored Image graph nodes and link the results"
+)]
+struct Cli {
+    /// Neo4j bolt or HTTP URI.
+    #[arg(long, env = "NEO4J_URI", default_value = "bolt://localhost:7687")]
+    neo4j_uri: String,
+    /// Neo4j username.
+    #[arg(long, env = "NEO4J_USER", default_value = "neo4j")]
+    neo4j_user: String,
+    /// Neo4j password.
+    #[arg(long, env = "NEO4J_PASS", default_value = "password")]
+    neo4j_pass: String,
+    /// YOLOv8-style ONNX model file.
+    #[arg(long, env = "ORECOG_MODEL")]
+    model: PathBuf,
+    /// File with one class name per line; defaults to the COCO classes.
+    #[arg(long, env = "ORECOG_LABELS")]
+    labels: Option<PathBuf>,
+    /// Side of the square model input in pixels.
+    #[arg(long, env = "ORECOG_INPUT_SIZE", default_value_t = 640)]
+    input_size: u32,
+    /// Minimum class score for reporting an object.
+    #[arg(long, env = "ORECOG_CONFIDENCE", default_value_t = 0.25)]
+    confidence: f32,
+    /// Overlap above which weaker boxes of the same class are dropped.
+    #[arg(long, env = "ORECOG_IOU", default_value_t = 0.45)]
+    iou: f32,
+    /// Detector label stored on object-detection runs.
+    #[arg(long, env = "ORECOG_DETECTOR", default_value = "yolo")]
+    detector: String,
+    /// Delay between graph polling attempts.
+    #[arg(long, env = "ORECOG_POLL_MS", default_value_t = 1000)]
+    poll_ms: u64,
+    /// How long a claimed frame stays leased to this worker without a heartbeat.
+    #[arg(long, env = "ORECOG_LEASE_MS", default_value_t = 60_000)]
+    lease_ms: u64,
+    /// Lease owner id; defaults to the host name and process id.
+    #[arg(long, env = "WORKER_ID")]
+    worker_id: Option<String>,
+    /// Claims a work item gets before it is marked failed and skipped.
+    #[arg(long, env = "WORK_LEASE_MAX_ATTEMPTS", default_value_t = WorkLease::DEFAULT_MAX_ATTEMPTS)]
+    max_attempts: u64,
+    /// Process at most one frame and exit.
+    #[arg(long)]
+    once: bool,
+}
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() -> anyhow::Result<()> {
+    let (bus, _user_rx) = EventBus::new();
+    init_logging(bus.log_sender());
+    dotenv().ok();
+
+    let cli = Cli::parse();
+    let graph = Arc::new(
+        Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass)
+            .with_blob_store(BlobConfig::from_env()?.open())
+            .with_max_lease_attempts(cli.max_attempts),
+    );
+    let observer = SensationGraphObserver::new(graph.clone() as Arc<dyn GraphStore>);
+    let mut detector = YoloObjectDetector::from_file(&cli.model)
+        .context("failed to initialize object detector")?
+        .with_config(YoloConfig {
+            input_size: cli.input_size,
+            confidence: cli.confidence,
+            iou: cli.iou,
+            ..YoloConfig::default()
+        });
+    if let Some(path) = &cli.labels {
+        let labels = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read labels from {}", path.display()))?;
+        detector = detector.with_labels(
+            labels
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_string)
+                .collect(),
+        );
+    }
+    let worker = Worker {
+        graph,
+        observer,
+        detector: Arc::new(detector),
+        detector_name: cli.detector,
+        lease: LeaseSettings::new(cli.worker_id, cli.lease_ms),
+    };
+
+    if cli.once {
+        worker.process_next_frame().await?;
+        return Ok(());
+    }
+
+    let mut ticker = interval(Duration::from_millis(cli.poll_ms.max(100)));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    info!(owner = %worker.lease.owner, "object detection loop started");
+    loop {
+        ticker.tick().await;
+        if let Err(err) = worker.process_next_frame().await {
+            error!(error = %err, "object detection loop iteration failed");
+        }
+    }
+}
+
+struct Worker {
+    graph: Arc<Neo4jClient>,
+    observer: SensationGraphObserver,
+    detector: Arc<dyn ObjectDetector>,
+    detector_name: String,
+    lease: LeaseSettings,
+}
+
+impl Worker {
+    async fn process_next_frame(&self) -> anyhow::Result<()> {
+        let Some(frame) = self
+            .graph
+            .latest_unprocessed_image_frame_for_object_detection()
+            .await
+            .context("failed to load latest unprocessed image frame")?
+        else {
+            trace!("no unprocessed image frames found");
+            return Ok(());
+        };
+        with_work_lease(
+            &self.graph,
+            WorkLease::OBJECT_DETECTION,
+            &frame.id,
+            &self.lease,
+            self.detect_frame(&frame),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn detect_frame(&self, frame: &GraphImageFrame) -> anyhow::Result<()> {
+        info!(image_id = %frame.id, "detecting objects in image frame");
+        let objects = self
+            .detector
+            .detect_objects(&frame.image)
+            .await
+            .with_context(|| format!("failed to detect objects in image {}", frame.id))?;
+        let occurred_at = image_captured_at(&frame.image)
+            .or_else(|| frame.occurred_at.as_deref().and_then(parse_observed_at))
+            .unwrap_or_else(Utc::now);
+
+        let mut detections = Vec::with_capacity(objects.len());
+        for (index, mut object) in objects.into_iter().enumerate() {
+            object.source_image_id = Some(frame.id.clone());
+            let object_id = object.observation_id(&occurred_at.to_rfc3339());
+            let label = object.label.clone();
+            let confidence = object.confidence;
+            let sensation = Sensation::of_at(object, occurred_at);
+            self.observer.observe_sensation(&sensation).await;
+            detections.push(GraphObjectDetection {
+                index,
+                object_id,
+                sensation_id: sensation.id(),
+                label,
+                confidence,
+            });
+        }
+
+        self.graph
+            .attach_object_detection(frame, &self.detector_name, &detections)
+            .await
+            .with_context(|| format!("failed to attach object detection for image {}", frame.id))?;
+        info!(
+            image_id = %frame.id,
+            sensation_id = frame.sensation_id.as_deref().unwrap_or(""),
+            object_count = detections.len(),
+            "attached object detection"
+        );
+        Ok(())
+    }
+}
@@ -8,8 +8,9 @@ use std::{
 
 use axum::{
     Json, Router,
+    body::Body,
     extract::{
-        Path, State,
+        Path, Query, State,
         ws::{Message as WsMessage, WebSocket, WebSocketUpgrade},
     },
     http::{StatusCode, header},
@@ -20,8 +21,11 @@ use base64::{Engine, engine::general_purpose::STANDARD as BASE64_STANDARD};
 use chrono::{DateTime, NaiveDateTime, Utc};
 use clap::Parser;
 use dotenvy::dotenv;
-use pete::{EventBus, init_logging, movie};
-use psyche::{AudioClip, GraphNodeDetails, GraphSnapshot, GraphSpeechSegmentAudio, Neo4jClient};
+use pete::{EventBus, codec, init_logging, movie};
+use psyche::{
+    AudioClip, BlobConfig, BlobStore, GraphNodeDetails, GraphSnapshot, GraphSpeechSegmentAudio,
+    Neo4jClient, is_blob_hash,
+};
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 use tokio::time::interval;
@@ -67,6 +71,7 @@ struct Cli {
 #[derive(Clone)]
 struct PsychicState {
     graph: Arc<Neo4jClient>,
+    blobs: Arc<dyn BlobStore>,
     graph_limit: usize,
     refresh: Duration,
     movie_dir: PathBuf,
@@ -90,6 +95,12 @@ struct MovieAsset {
     duration_ms: i64,
 }
 
+#[derive(Deserialize)]
+struct BlobQuery {
+    /// Content type to serve the blob as, e.g. the `mime` of its node.
+    mime: Option<String>,
+}
+
 #[derive(Deserialize)]
 struct MovieRequest {
     from: String,
@@ -103,12 +114,13 @@ async fn main() -> anyhow::Result<()> {
     dotenv().ok();
 
     let cli = Cli::parse();
+    let blobs = BlobConfig::from_env()?.open();
     let state = PsychicState {
-        graph: Arc::new(Neo4jClient::new(
-            cli.neo4j_uri,
-            cli.neo4j_user,
-            cli.neo4j_pass,
-        )),
+        graph: Arc::new(
+            Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass)
+                .with_blob_store(blobs.clone()),
+        ),
+        blobs,
         graph_limit: cli.graph_limit,
         refresh: Duration::from_millis(cli.refresh_ms.max(250)),
         movie_dir: PathBuf::from(DEFAULT_MOVIE_DIR),
@@ -133,6 +145,7 @@ fn app(state: PsychicState) -> Router {
         .route("/movie", post(request_movie))
         .route("/graph/node/{id}", get(graph_node_details))
         .route("/graph/audio-clip/{id}/audio.wav", get(audio_clip_audio))
+        .route("/blobs/{hash}", get(blob))
         .route(
             "/graph/speech-segment/{id}/audio.wav",
             get(speech_segment_audio),
@@ -345,6 +358,61 @@ async fn graph_node_details(
     }
 }
 
+/// Stream a stored image or audio payload by its content hash.
+async fn blob(
+    Path(hash): Path<String>,
+    Query(query): Query<BlobQuery>,
+    State(state): State<PsychicState>,
+) -> impl IntoResponse {
+    if !is_blob_hash(&hash) {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(PsychicMessage::Error {
+                message: format!("invalid blob hash: {hash}"),
+            }),
+        )
+            .into_response();
+    }
+    match state.blobs.stream(&hash).await {
+        Ok(Some(chunks)) => {
+            let mime = query
+                .mime
+                .filter(|mime| mime.starts_with("image/") || mime.starts_with("audio/"))
+                .unwrap_or_else(|| "application/octet-stream".into());
+            (
+                [
+                    (header::CONTENT_TYPE, mime),
+                    // Content-addressed blobs never change, but purges delete
+                    // them, so keep copies out of shared caches.
+                    (
+                        header::CACHE_CONTROL,
+                        "private, max-age=31536000, immutable".into(),
+                    ),
+                ],
+                Body::from_stream(chunks),
+            )
+                .into_response()
+        }
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(PsychicMessage::Error {
+                message: format!("blob not found: {hash}"),
+            }),
+        )
+            .into_response(),
+        Err(err) => {
+            error!(%err, %hash, "failed to load blob");
+            (
+                StatusCode::BAD_GATEWAY,
+                Json(PsychicMessage::Error {
+                    message: err.to_string(),
+                }),
+            )
+                .into_response()
+        }
+    }
+}
+
 async fn audio_clip_audio(
     Path(id): Path<String>,
     State(state): State<PsychicState>,
@@ -441,9 +509,24 @@ async fn load_audio_clip_by_id(
     state: &PsychicState,
     id: &str,
 ) -> anyhow::Result<Option<AudioClip>> {
-    let Some(details) = state.graph.graph_node_details(id).await? else {
+    let Some(mut details) = state.graph.graph_node_details(id).await? else {
         return Ok(None);
     };
+    let blob_hash = details
+        .properties
+        .get("blob_hash")
+        .and_then(serde_json::Value::as_str)
+        .filter(|_| details.properties.get("base64").is_none())
+        .filter(|_| details.labels.iter().any(|label| label == "AudioClip"))
+        .map(str::to_string);
+    if let Some(hash) = blob_hash {
+        let bytes = state
+            .blobs
+            .get(&hash)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("audio clip blob not found: {hash}"))?;
+        details.properties["base64"] = BASE64_STANDARD.encode(bytes).into();
+    }
     audio_clip_from_details(details)
 }
 
@@ -587,6 +670,14 @@ fn decode_source_audio(
     {
         return decode_wav_pcm_s16le(bytes);
     }
+    if codec::is_compressed_mime(&mime) || codec::starts_stream(bytes) {
+        let samples = codec::decode_compressed_audio(bytes, &mime, codec::OPUS_SAMPLE_RATE)?;
+        return Ok(PcmAudio {
+            bytes: codec::encode_pcm_s16le(&samples),
+            sample_rate: codec::OPUS_SAMPLE_RATE,
+            channels: 1,
+        });
+    }
     if is_pcm_s16_mime(&mime) {
         return Ok(PcmAudio {
             bytes: bytes.to_vec(),
@@ -4,8 +4,8 @@ use std::time::Duration;
 use anyhow::Context;
 use clap::Parser;
 use dotenvy::dotenv;
-use lingproc::{Doer, LlmInstruction, Vectorizer};
-use pete::{EventBus, init_logging, ollama_provider_from_args};
+use lingproc::{Doer, LlmInstruction, Priority, Vectorizer};
+use pete::{EventBus, init_logging, scheduled_ollama_provider};
 use psyche::{
     GraphClusterItem, GraphSensationTimelineItem, GraphSnapshot, Neo4jClient, QdrantClient,
     with_default_system_prompt,
@@ -96,8 +96,16 @@ async fn main() -> anyhow::Result<()> {
         cli.neo4j_pass.clone(),
     );
     let qdrant = QdrantClient::new(cli.qdrant_url.clone());
-    let doer = ollama_provider_from_args(&cli.remember_host, &cli.remember_model)?;
-    let vectorizer = ollama_provider_from_args(&cli.embeddings_host, &cli.embeddings_model)?;
+    let doer = scheduled_ollama_provider(
+        &cli.remember_host,
+        &cli.remember_model,
+        Priority::Background,
+    )?;
+    let vectorizer = scheduled_ollama_provider(
+        &cli.embeddings_host,
+        &cli.embeddings_model,
+        Priority::Background,
+    )?;
     let processor = RememberProcessor {
         doer,
         vectorizer,
@@ -173,8 +181,8 @@ async fn process_next_batch(
 }
 
 struct RememberProcessor {
-    doer: lingproc::OllamaProvider,
-    vectorizer: lingproc::OllamaProvider,
+    doer: lingproc::Scheduled<lingproc::OllamaProvider>,
+    vectorizer: lingproc::Scheduled<lingproc::OllamaProvider>,
     llm_model: String,
 }
 
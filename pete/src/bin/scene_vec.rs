@@ -7,9 +7,12 @@ use clap::Parser;
 use dotenvy::dotenv;
 use open_clip_inference::VisionEmbedder;
 use pete::{EventBus, init_logging};
-use psyche::{GraphImageFrame, GraphSceneVectorization, Neo4jClient, QdrantClient};
+use psyche::{
+    BlobConfig, GraphImageFrame, GraphSceneVectorization, ImageRunKind, Neo4jClient, QdrantClient,
+    SceneChangeConfig, SceneGate,
+};
 use tokio::time::{MissedTickBehavior, interval};
-use tracing::{error, info, trace};
+use tracing::{error, info, trace, warn};
 
 const DEFAULT_SCENE_VEC_MODEL: &str = "RuteNL/MobileCLIP2-S3-OpenCLIP-ONNX";
 
@@ -41,6 +44,10 @@ struct Cli {
     /// Delay between graph polling attempts.
     #[arg(long, env = "SCENE_VEC_POLL_MS", default_value_t = 1000)]
     poll_ms: u64,
+    /// Vectorize every frame instead of reusing the vector of an unchanged
+    /// scene (change detection is tuned by SCENE_CHANGE_*).
+    #[arg(long, env = "SCENE_VEC_NO_SCENE_GATE")]
+    no_scene_gate: bool,
     /// Process at most one frame and exit.
     #[arg(long)]
     once: bool,
@@ -53,12 +60,18 @@ async fn main() -> anyhow::Result<()> {
     dotenv().ok();
 
     let cli = Cli::parse();
-    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass);
+    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass)
+        .with_blob_store(BlobConfig::from_env()?.open());
     let qdrant = QdrantClient::new(cli.qdrant_url);
     let vectorizer = SceneVectorizer::new(cli.model, cli.model_dir).await?;
+    let scene_gate = if cli.no_scene_gate {
+        None
+    } else {
+        Some(SceneGate::new(SceneChangeConfig::from_env()?))
+    };
 
     if cli.once {
-        process_next_frame(&graph, &qdrant, &vectorizer).await?;
+        process_next_frame(&graph, &qdrant, &vectorizer, scene_gate.as_ref()).await?;
         return Ok(());
     }
 
@@ -68,7 +81,9 @@ async fn main() -> anyhow::Result<()> {
     info!("scene vectorization loop started");
     loop {
         ticker.tick().await;
-        if let Err(err) = process_next_frame(&graph, &qdrant, &vectorizer).await {
+        if let Err(err) =
+            process_next_frame(&graph, &qdrant, &vectorizer, scene_gate.as_ref()).await
+        {
             error!(error = %err, "scene vectorization loop iteration failed");
         }
     }
@@ -78,6 +93,7 @@ async fn process_next_frame(
     graph: &Neo4jClient,
     qdrant: &QdrantClient,
     vectorizer: &SceneVectorizer,
+    scene_gate: Option<&SceneGate>,
 ) -> anyhow::Result<()> {
     let Some(frame) = graph
         .latest_unprocessed_image_frame_for_scene_vectorization()
@@ -87,6 +103,21 @@ async fn process_next_frame(
         trace!("no unprocessed image frames found");
         return Ok(());
     };
+    if let Some(gate) = scene_gate {
+        match gate
+            .reuse_keyframe_run(graph, qdrant, &frame, ImageRunKind::SceneVectorization)
+            .await
+        {
+            Ok(Some(keyframe_id)) => {
+                info!(image_id = %frame.id, %keyframe_id, "reused scene vector of unchanged scene");
+                return Ok(());
+            }
+            Ok(None) => {}
+            Err(err) => {
+                warn!(image_id = %frame.id, error = %err, "scene change check failed");
+            }
+        }
+    }
 
     info!(image_id = %frame.id, "vectorizing image scene");
     let scene = vectorizer
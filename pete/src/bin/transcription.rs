@@ -5,7 +5,10 @@ use chrono::{DateTime, Utc};
 use clap::Parser;
 use dotenvy::dotenv;
 use pete::{AsrService, EventBus, SegmentMessage, WordTiming, init_logging};
-use psyche::{GraphAudioClip, GraphSpeechSegment, Neo4jClient, parse_observed_at};
+use psyche::{
+    BlobConfig, GraphAudioClip, GraphSpeechSegment, LeaseSettings, Neo4jClient, WorkLease,
+    parse_observed_at, with_work_lease,
+};
 use tokio::time::{MissedTickBehavior, interval};
 use tracing::{error, info, trace};
 
@@ -28,6 +31,15 @@ struct Cli {
     /// Delay between graph polling attempts.
     #[arg(long, env = "TRANSCRIPTION_POLL_MS", default_value_t = 1000)]
     poll_ms: u64,
+    /// How long a claimed clip stays leased to this worker without a heartbeat.
+    #[arg(long, env = "TRANSCRIPTION_LEASE_MS", default_value_t = 120_000)]
+    lease_ms: u64,
+    /// Lease owner id; defaults to the host name and process id.
+    #[arg(long, env = "WORKER_ID")]
+    worker_id: Option<String>,
+    /// Claims a work item gets before it is marked failed and skipped.
+    #[arg(long, env = "WORK_LEASE_MAX_ATTEMPTS", default_value_t = WorkLease::DEFAULT_MAX_ATTEMPTS)]
+    max_attempts: u64,
 }
 
 #[tokio::main(flavor = "multi_thread")]
@@ -44,14 +56,17 @@ async fn main() -> anyhow::Result<()> {
     if !asr.has_whisper_model() {
         anyhow::bail!("Whisper model not configured; set WHISPER_MODEL or run `just fetch`");
     }
-    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass);
+    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass)
+        .with_blob_store(BlobConfig::from_env()?.open())
+        .with_max_lease_attempts(cli.max_attempts);
+    let lease = LeaseSettings::new(cli.worker_id, cli.lease_ms);
     let mut ticker = interval(Duration::from_millis(cli.poll_ms.max(100)));
     ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
-    info!("transcription loop started");
+    info!(owner = %lease.owner, "transcription loop started");
     loop {
         ticker.tick().await;
-        if let Err(err) = transcribe_next_clip(&graph, &asr).await {
+        if let Err(err) = transcribe_next_clip(&graph, &asr, &lease).await {
             error!(error = %err, "transcription loop iteration failed");
         }
     }
@@ -144,7 +159,11 @@ fn parse_cuda_version(value: &str) -> Option<CudaVersion> {
     })
 }
 
-async fn transcribe_next_clip(graph: &Neo4jClient, asr: &AsrService) -> anyhow::Result<()> {
+async fn transcribe_next_clip(
+    graph: &Neo4jClient,
+    asr: &AsrService,
+    lease: &LeaseSettings,
+) -> anyhow::Result<()> {
     let Some(audio) = graph
         .latest_untranscribed_audio_clip()
         .await
@@ -153,13 +172,28 @@ async fn transcribe_next_clip(graph: &Neo4jClient, asr: &AsrService) -> anyhow::
         trace!("no untranscribed audio clips found");
         return Ok(());
     };
+    with_work_lease(
+        graph,
+        WorkLease::TRANSCRIPTION,
+        &audio.id,
+        lease,
+        transcribe_clip(graph, asr, &audio),
+    )
+    .await?;
+    Ok(())
+}
 
+async fn transcribe_clip(
+    graph: &Neo4jClient,
+    asr: &AsrService,
+    audio: &GraphAudioClip,
+) -> anyhow::Result<()> {
     info!(clip_id = %audio.id, "transcribing audio clip");
     let transcription = asr
         .transcribe_clip(&audio.clip)
         .await
         .with_context(|| format!("failed to transcribe audio clip {}", audio.id))?;
-    let source_started_at = audio_timestamp(&audio);
+    let source_started_at = audio_timestamp(audio);
     let source_captured_at = source_started_at.map(|at| at.to_rfc3339());
     let segments = graph_speech_segments(&transcription.segments, source_started_at);
     graph
@@ -7,10 +7,15 @@ use chrono::{DateTime, Utc};
 use clap::Parser;
 use dotenvy::dotenv;
 use hound::{SampleFormat, WavReader};
+use pete::codec;
+use pete::diarize::{self, DiarizeConfig, SpeakerTurn};
+use pete::vad::VadConfig;
 use pete::{EventBus, init_logging};
 use psyche::{
-    GraphVoiceClip, GraphVoiceMatch, GraphVoiceRecognition, GraphVoiceSample, GraphVoiceSignature,
-    Neo4jClient, QdrantClient, parse_observed_at,
+    BlobConfig, GraphDiarization, GraphDiarizationCandidate, GraphDiarizationSource,
+    GraphDiarizedSpeaker, GraphVoiceClip, GraphVoiceIdentity, GraphVoiceMatch,
+    GraphVoiceRecognition, GraphVoiceSample, GraphVoiceSignature, Neo4jClient, QdrantClient,
+    parse_observed_at,
 };
 use tokio::time::{MissedTickBehavior, interval};
 use tracing::{error, info, trace, warn};
@@ -47,7 +52,10 @@ struct Cli {
     /// Minimum Qdrant similarity for treating a detected voice as a known voice.
     #[arg(long, env = "VRECOG_VOICE_MATCH_THRESHOLD", default_value_t = 0.86)]
     voice_match_threshold: f32,
-    /// Process at most one clip and exit.
+    /// Skip speaker diarization of big transcriptions.
+    #[arg(long, env = "VRECOG_NO_DIARIZE")]
+    no_diarize: bool,
+    /// Process at most one clip and one big transcription, then exit.
     #[arg(long)]
     once: bool,
 }
@@ -67,12 +75,27 @@ async fn main() -> anyhow::Result<()> {
                 "voice embedding model not configured; set VOICE_EMBEDDING_MODEL or run `just fetch`"
             )
         })?;
-    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass);
+    let graph = Neo4jClient::new(cli.neo4j_uri, cli.neo4j_user, cli.neo4j_pass)
+        .with_blob_store(BlobConfig::from_env()?.open());
     let qdrant = QdrantClient::new(cli.qdrant_url);
     let mut recognizer = VoiceRecognizer::new(model_path)?;
+    let diarization = (!cli.no_diarize)
+        .then(|| -> anyhow::Result<_> { Ok((VadConfig::from_env()?, DiarizeConfig::from_env()?)) })
+        .transpose()?;
 
     if cli.once {
         process_next_clip(&graph, &qdrant, &mut recognizer, cli.voice_match_threshold).await?;
+        if let Some((vad_config, config)) = &diarization {
+            process_next_transcription(
+                &graph,
+                &qdrant,
+                &mut recognizer,
+                vad_config,
+                config,
+                cli.voice_match_threshold,
+            )
+            .await?;
+        }
         return Ok(());
     }
 
@@ -87,6 +110,21 @@ async fn main() -> anyhow::Result<()> {
         {
             error!(error = %err, "voice recognition loop iteration failed");
         }
+        let Some((vad_config, config)) = &diarization else {
+            continue;
+        };
+        if let Err(err) = process_next_transcription(
+            &graph,
+            &qdrant,
+            &mut recognizer,
+            vad_config,
+            config,
+            cli.voice_match_threshold,
+        )
+        .await
+        {
+            error!(error = %err, "diarization loop iteration failed");
+        }
     }
 }
 
@@ -144,6 +182,71 @@ async fn process_next_clip(
     Ok(())
 }
 
+async fn process_next_transcription(
+    graph: &Neo4jClient,
+    qdrant: &QdrantClient,
+    recognizer: &mut VoiceRecognizer,
+    vad_config: &VadConfig,
+    config: &DiarizeConfig,
+    voice_match_threshold: f32,
+) -> anyhow::Result<()> {
+    let Some(candidate) = graph
+        .latest_big_transcription_for_diarization()
+        .await
+        .context("failed to load latest undiarized big transcription")?
+    else {
+        trace!("no undiarized big transcriptions found");
+        return Ok(());
+    };
+
+    let transcription_id = &candidate.transcription_id;
+    info!(%transcription_id, sources = candidate.sources.len(), "diarizing big transcription");
+    match recognizer
+        .diarize(
+            &candidate,
+            graph,
+            qdrant,
+            vad_config,
+            config,
+            voice_match_threshold,
+        )
+        .await
+        .with_context(|| format!("failed to diarize transcription {transcription_id}"))?
+    {
+        DiarizationOutcome::Diarized(diarization) => {
+            graph
+                .attach_speaker_diarization(transcription_id, &recognizer.model, &diarization)
+                .await
+                .with_context(|| {
+                    format!("failed to attach diarization for transcription {transcription_id}")
+                })?;
+            info!(
+                %transcription_id,
+                speakers = diarization.speakers.len(),
+                turns = diarization.turns.len(),
+                "attached speaker diarization"
+            );
+        }
+        DiarizationOutcome::Skipped(reason) => {
+            graph
+                .attach_skipped_diarization(transcription_id, &recognizer.model, &reason)
+                .await
+                .with_context(|| {
+                    format!(
+                        "failed to attach skipped diarization for transcription {transcription_id}"
+                    )
+                })?;
+            warn!(%transcription_id, %reason, "skipped diarization for big transcription");
+        }
+    }
+    Ok(())
+}
+
+enum DiarizationOutcome {
+    Diarized(GraphDiarization),
+    Skipped(String),
+}
+
 enum VoiceRecognitionOutcome {
     Recognized(GraphVoiceRecognition),
     Skipped(String),
@@ -204,6 +307,14 @@ impl VoiceRecognizer {
             .next()
             .ok_or_else(|| anyhow!("voice embedding model returned no embeddings"))?
             .to_vec();
+        if let Some(identity_id) =
+            opted_out_voice(graph, qdrant, &embedding, voice_match_threshold).await?
+        {
+            info!(clip_id = %clip.id, %identity_id, "discarding voice of an opted-out identity");
+            return Ok(VoiceRecognitionOutcome::Skipped(
+                "voice belongs to an opted-out identity".into(),
+            ));
+        }
         let user_id = voice_id_from_embedding(&embedding);
         let vector_id = qdrant
             .store_voice_vector_for_sensation(
@@ -219,7 +330,7 @@ impl VoiceRecognizer {
             graph,
             qdrant,
             &embedding,
-            &vector_id,
+            Some(&vector_id),
             voice_match_threshold,
             &clip.id,
         )
@@ -262,16 +373,174 @@ impl VoiceRecognizer {
     }
 }
 
+impl VoiceRecognizer {
+    /// Embed one window of 16 kHz audio, or `None` when it is too short for the model.
+    async fn embed(&mut self, samples: &[f32]) -> anyhow::Result<Option<Vec<f32>>> {
+        let audio_22050 = voxudio::resample::<16000, 22050, f32>(samples, 1, 1)
+            .context("failed to resample audio for voice embedding")?;
+        if audio_22050.len() < MIN_VOICE_EMBEDDING_SAMPLES_22050 {
+            return Ok(None);
+        }
+        match self.extractor.extract(&audio_22050, 1).await {
+            Ok(embeddings) => Ok(embeddings
+                .into_iter()
+                .next()
+                .map(|embedding| embedding.to_vec())),
+            Err(err) if is_short_audio_embedding_error(&err) => Ok(None),
+            Err(err) => Err(err).context("failed to extract voice embedding"),
+        }
+    }
+
+    async fn diarize(
+        &mut self,
+        candidate: &GraphDiarizationCandidate,
+        graph: &Neo4jClient,
+        qdrant: &QdrantClient,
+        vad_config: &VadConfig,
+        config: &DiarizeConfig,
+        voice_match_threshold: f32,
+    ) -> anyhow::Result<DiarizationOutcome> {
+        if candidate.segments.is_empty() {
+            return Ok(DiarizationOutcome::Skipped(
+                "transcription has no speech segments".into(),
+            ));
+        }
+        let audio = match aggregate_source_audio(&candidate.sources) {
+            Ok(audio) if !audio.is_empty() => audio,
+            Ok(_) => {
+                return Ok(DiarizationOutcome::Skipped(
+                    "transcription has no source audio".into(),
+                ));
+            }
+            Err(err) => {
+                return Ok(DiarizationOutcome::Skipped(format!(
+                    "failed to decode source audio: {err:#}"
+                )));
+            }
+        };
+
+        let mut windows = Vec::new();
+        let mut embeddings = Vec::new();
+        for window in diarize::embedding_windows(&audio, ANALYSIS_SAMPLE_RATE, vad_config, config) {
+            if let Some(embedding) = self.embed(&audio[window.clone()]).await? {
+                windows.push(window);
+                embeddings.push(embedding);
+            }
+        }
+        if embeddings.is_empty() {
+            return Ok(DiarizationOutcome::Skipped(
+                "no speech long enough for voice embeddings".into(),
+            ));
+        }
+
+        let labels = diarize::cluster_embeddings(&embeddings, config);
+        let turns = diarize::speaker_turns(&windows, &labels, ANALYSIS_SAMPLE_RATE);
+        let mut speakers = Vec::new();
+        for (index, centroid) in diarize::speaker_centroids(&embeddings, &labels)
+            .iter()
+            .enumerate()
+        {
+            // Centroids are not stored, so there is no vector of their own to skip.
+            let clip_id = speaker_clip_id(&candidate.sources, &turns, index)
+                .unwrap_or(&candidate.transcription_id);
+            let mut matched = match_voice(
+                graph,
+                qdrant,
+                centroid,
+                None,
+                voice_match_threshold,
+                clip_id,
+            )
+            .await?;
+            let opted_out = match &matched {
+                Some(voice) => graph
+                    .opted_out_voice_identity(&voice.nearest_vector_id)
+                    .await
+                    .context("failed to check voice opt-out")?
+                    .is_some(),
+                None => false,
+            };
+            if opted_out {
+                // Opted-out speakers stay anonymous.
+                matched = None;
+            }
+            speakers.push(GraphDiarizedSpeaker {
+                index,
+                score: matched.as_ref().map(|matched| matched.score),
+                voice: matched.map(|matched| GraphVoiceIdentity {
+                    voice_id: matched.voice_id,
+                    identity: matched.identity,
+                    person_id: None,
+                }),
+                window_count: labels.iter().filter(|label| **label == index).count(),
+            });
+        }
+        Ok(DiarizationOutcome::Diarized(diarize::attribute_segments(
+            &candidate.segments,
+            &turns,
+            speakers,
+        )))
+    }
+}
+
+/// Source clip in which `speaker` talks the longest.
+fn speaker_clip_id<'a>(
+    sources: &'a [GraphDiarizationSource],
+    turns: &[SpeakerTurn],
+    speaker: usize,
+) -> Option<&'a str> {
+    sources
+        .iter()
+        .map(|source| {
+            let spoken: u32 = turns
+                .iter()
+                .filter(|turn| turn.speaker == speaker)
+                .map(|turn| {
+                    turn.end_ms
+                        .min(source.end_ms)
+                        .saturating_sub(turn.start_ms.max(source.start_ms))
+                })
+                .sum();
+            (source, spoken)
+        })
+        .filter(|(_, spoken)| *spoken > 0)
+        .max_by_key(|(_, spoken)| *spoken)
+        .map(|(source, _)| source.clip.id.as_str())
+}
+
+/// Lay each source clip at its offset on the transcription's timeline.
+fn aggregate_source_audio(sources: &[GraphDiarizationSource]) -> anyhow::Result<Vec<f32>> {
+    let to_samples = |ms: u32| (u64::from(ms) * u64::from(ANALYSIS_SAMPLE_RATE) / 1000) as usize;
+    let mut audio = Vec::new();
+    for source in sources {
+        let samples = decode_audio_clip_samples(&source.clip.clip, ANALYSIS_SAMPLE_RATE)
+            .with_context(|| format!("audio clip {}", source.clip.id))?;
+        let offset = to_samples(source.start_ms);
+        let len = if source.end_ms > source.start_ms {
+            samples
+                .len()
+                .min(to_samples(source.end_ms - source.start_ms))
+        } else {
+            samples.len()
+        };
+        if audio.len() < offset + len {
+            audio.resize(offset + len, 0.0);
+        }
+        audio[offset..offset + len].copy_from_slice(&samples[..len]);
+    }
+    Ok(audio)
+}
+
 async fn match_voice(
     graph: &Neo4jClient,
     qdrant: &QdrantClient,
     embedding: &[f32],
-    vector_id: &str,
+    vector_id: Option<&str>,
     threshold: f32,
     clip_id: &str,
 ) -> anyhow::Result<Option<GraphVoiceMatch>> {
     let Some(neighbor) = qdrant
-        .nearest_voice_neighbor(embedding, vector_id, threshold)
+        .nearest_voice_neighbor(embedding, vector_id.unwrap_or(""), threshold)
         .await
         .with_context(|| format!("failed to search nearest voice neighbor for {clip_id}"))?
     else {
@@ -297,6 +566,26 @@ async fn match_voice(
     }))
 }
 
+/// Return the opted-out identity a voice embedding belongs to, when any.
+async fn opted_out_voice(
+    graph: &Neo4jClient,
+    qdrant: &QdrantClient,
+    embedding: &[f32],
+    threshold: f32,
+) -> anyhow::Result<Option<String>> {
+    let Some(neighbor) = qdrant
+        .nearest_voice_neighbor(embedding, "", threshold)
+        .await
+        .context("failed to search nearest voice neighbor for opt-out")?
+    else {
+        return Ok(None);
+    };
+    graph
+        .opted_out_voice_identity(&neighbor.point_id)
+        .await
+        .context("failed to check voice opt-out")
+}
+
 fn is_short_audio_embedding_error(error: &impl std::fmt::Display) -> bool {
     let message = error.to_string();
     message.contains("window_size <= signal_size") || message.contains("dft size is smaller")
@@ -563,6 +852,9 @@ fn decode_audio_clip_samples(
     }
 
     let mime = clip.mime.to_ascii_lowercase();
+    if codec::is_compressed_mime(&mime) || codec::starts_stream(&bytes) {
+        return codec::decode_compressed_audio(&bytes, &mime, target_sample_rate);
+    }
     let (sample_rate, channels, samples) = if mime.starts_with("audio/wav")
         || mime.starts_with("audio/x-wav")
         || bytes.starts_with(b"RIFF")
@@ -578,11 +870,11 @@ fn decode_audio_clip_samples(
         bail!("unsupported audio clip MIME type {}", clip.mime);
     };
 
-    if sample_rate != target_sample_rate {
-        bail!("audio clip sample rate {sample_rate} does not match expected {target_sample_rate}");
+    if sample_rate == 0 {
+        bail!("audio clip has no sample rate");
     }
-
-    Ok(downmix_to_mono(samples, channels))
+    let samples = downmix_to_mono(samples, channels);
+    Ok(codec::resample(&samples, sample_rate, target_sample_rate))
 }
 
 fn decode_wav_samples(bytes: &[u8]) -> anyhow::Result<(u32, u16, Vec<f32>)> {
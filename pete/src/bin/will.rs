@@ -5,14 +5,16 @@ use anyhow::Context;
 use chrono::{DateTime, Utc};
 use clap::Parser;
 use dotenvy::dotenv;
-use lingproc::{Doer, LlmInstruction, Vectorizer};
-use pete::{EventBus, init_logging, ollama_provider_from_args};
+use lingproc::{Doer, ImageData as LImageData, LlmInstruction, Priority, Vectorizer};
+use pete::{EventBus, init_logging, scheduled_ollama_provider};
 use psyche::{
-    BasicMemory, CONVERSATION_SPEAKER_NOTE, ConversationEntry, GraphFaceIdentityTarget,
-    GraphLatestCombobulation, GraphNodeDetails, GraphSensationTimelineItem, GraphSnapshot,
-    GraphVoiceIdentityTarget, Impression, Memory, Neo4jClient, QdrantClient, Sensation,
-    SensationGraphObserver, SensationObserver, Stimulus, Thought, WillTypeScriptExecution,
-    WillTypeScriptResult, WitReport, with_default_system_prompt,
+    BasicMemory, BlobConfig, BoundingBox, CONVERSATION_SPEAKER_NOTE, ConversationEntry,
+    GraphFaceIdentityTarget, GraphLatestCombobulation, GraphLookAnswer, GraphNodeDetails,
+    GraphSensationTimelineItem, GraphSnapshot, GraphVoiceIdentityTarget, Impression,
+    LOOK_AT_PROMPT, LOOK_PADDING, LookRegion, Memory, Neo4jClient, PersonNames, QdrantClient,
+    Sensation, SensationGraphObserver, SensationObserver, Stimulus, Thought,
+    WillTypeScriptExecution, WillTypeScriptResult, WitReport, crop_image,
+    with_default_system_prompt,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value, json};
@@ -203,6 +205,16 @@ struct Cli {
         default_value = "gpt-oss"
     )]
     will_model: String,
+    /// URL of the Ollama server answering `lookAt` questions.
+    #[arg(
+        long,
+        env = "IMAGE_DESCRIPTION_HOST",
+        default_value = "http://localhost:11434"
+    )]
+    vision_host: String,
+    /// Vision-capable model answering `lookAt` questions.
+    #[arg(long, env = "IMAGE_DESCRIPTION_MODEL", default_value = "gemma4")]
+    vision_model: String,
     /// Delay between graph polling attempts.
     #[arg(long, env = "WILL_POLL_MS", default_value_t = 1000)]
     poll_ms: u64,
@@ -218,15 +230,20 @@ async fn main() -> anyhow::Result<()> {
     dotenv().ok();
 
     let cli = Cli::parse();
-    let graph = std::sync::Arc::new(Neo4jClient::new(
-        cli.neo4j_uri.clone(),
-        cli.neo4j_user.clone(),
-        cli.neo4j_pass.clone(),
-    ));
+    let graph = std::sync::Arc::new(
+        Neo4jClient::new(
+            cli.neo4j_uri.clone(),
+            cli.neo4j_user.clone(),
+            cli.neo4j_pass.clone(),
+        )
+        .with_blob_store(BlobConfig::from_env()?.open()),
+    );
     let qdrant = QdrantClient::new(cli.qdrant_url.clone());
     let observer = SensationGraphObserver::new(graph.clone());
-    let doer = ollama_provider_from_args(&cli.will_host, &cli.will_model)?;
-    let vectorizer = ollama_provider_from_args(&cli.embeddings_host, &cli.embeddings_model)?;
+    let doer = scheduled_ollama_provider(&cli.will_host, &cli.will_model, Priority::Will)?;
+    let vectorizer =
+        scheduled_ollama_provider(&cli.embeddings_host, &cli.embeddings_model, Priority::Will)?;
+    let looker = scheduled_ollama_provider(&cli.vision_host, &cli.vision_model, Priority::Will)?;
     let memory: std::sync::Arc<dyn Memory> = std::sync::Arc::new(BasicMemory {
         vectorizer: std::sync::Arc::new(vectorizer.clone()),
         qdrant: qdrant.clone(),
@@ -235,6 +252,8 @@ async fn main() -> anyhow::Result<()> {
     let processor = WillProcessor {
         doer,
         vectorizer,
+        looker,
+        vision_model: cli.vision_model,
         graph: graph.clone(),
         qdrant: std::sync::Arc::new(qdrant),
         memory,
@@ -381,8 +400,11 @@ async fn process_latest_combobulation(
 }
 
 struct WillProcessor {
-    doer: lingproc::OllamaProvider,
-    vectorizer: lingproc::OllamaProvider,
+    doer: lingproc::Scheduled<lingproc::OllamaProvider>,
+    vectorizer: lingproc::Scheduled<lingproc::OllamaProvider>,
+    /// Vision model answering `lookAt` questions.
+    looker: lingproc::Scheduled<lingproc::OllamaProvider>,
+    vision_model: String,
     graph: std::sync::Arc<Neo4jClient>,
     qdrant: std::sync::Arc<QdrantClient>,
     memory: std::sync::Arc<dyn Memory>,
@@ -468,6 +490,12 @@ impl WillProcessor {
                 self.neighbors(id, *depth).await.unwrap_or_else(error_text),
             ),
             TypeScriptCommand::Look => ("look", self.look().await.unwrap_or_else(error_text)),
+            TypeScriptCommand::LookAt { region, question } => (
+                "look_at",
+                self.look_at(region, question)
+                    .await
+                    .unwrap_or_else(error_text),
+            ),
             TypeScriptCommand::ListenRecent { limit } => (
                 "listen_recent",
                 self.listen_recent(*limit).await.unwrap_or_else(error_text),
@@ -536,6 +564,66 @@ impl WillProcessor {
             .unwrap_or_else(|| "No recent image description is available.".into()))
     }
 
+    async fn look_at(&self, region: &LookRegion, question: &str) -> anyhow::Result<String> {
+        let Some(frame) = self.graph.latest_image_frame().await? else {
+            return Ok("No image is available to look at.".into());
+        };
+        let regions = match region {
+            LookRegion::Named(_) => self.graph.image_regions(&frame.id).await?,
+            LookRegion::Box(_) => Vec::new(),
+        };
+        let Some((bbox, named)) = region.locate(&regions) else {
+            let seen = regions
+                .iter()
+                .map(|region| region.name.as_deref().unwrap_or(&region.node_id))
+                .collect::<Vec<_>>();
+            return Ok(if seen.is_empty() {
+                format!(
+                    "Could not find {} in the latest frame.",
+                    region.describe(None)
+                )
+            } else {
+                format!(
+                    "Could not find {} in the latest frame. It shows: {}.",
+                    region.describe(None),
+                    seen.join(", ")
+                )
+            });
+        };
+        let image = frame.image.clone();
+        let crop = tokio::task::spawn_blocking(move || crop_image(&image, &bbox, LOOK_PADDING))
+            .await
+            .context("image cropping task failed")??;
+        let answer = self
+            .looker
+            .follow(LlmInstruction {
+                command: with_default_system_prompt(format!(
+                    "{LOOK_AT_PROMPT}\n\nQuestion: {}",
+                    question.trim()
+                )),
+                images: vec![LImageData {
+                    mime: crop.mime,
+                    base64: crop.base64,
+                    captured_at: crop.captured_at,
+                    source: crop.source,
+                }],
+            })
+            .await?;
+        let Some(answer) = common::non_empty_model_text(&answer) else {
+            return Ok("The vision model gave no answer.".into());
+        };
+        let look = GraphLookAnswer {
+            region: region.describe(named),
+            bbox,
+            region_node_id: named.map(|region| region.node_id.clone()),
+            question: question.trim().to_string(),
+            answer: answer.trim().to_string(),
+            model: self.vision_model.clone(),
+        };
+        self.graph.attach_look_answer(&frame, &look).await?;
+        Ok(format!("Looked at {}: {}", look.region, look.answer))
+    }
+
     async fn recall(&self, query: &str, limit: usize) -> anyhow::Result<String> {
         let query = query.trim();
         if query.is_empty() {
@@ -628,7 +716,10 @@ impl WillProcessor {
             .graph
             .recent_face_identity_targets(command_limit(limit).min(10))
             .await?;
-        Ok(format_face_identity_targets(&targets))
+        Ok(format_face_identity_targets(
+            &targets,
+            &self.person_names().await,
+        ))
     }
 
     async fn recent_voices(&self, limit: usize) -> anyhow::Result<String> {
@@ -636,7 +727,14 @@ impl WillProcessor {
             .graph
             .recent_voice_identity_targets(command_limit(limit).min(10))
             .await?;
-        Ok(format_voice_identity_targets(&targets))
+        Ok(format_voice_identity_targets(
+            &targets,
+            &self.person_names().await,
+        ))
+    }
+
+    async fn person_names(&self) -> PersonNames {
+        PersonNames::new(&self.graph.people().await.unwrap_or_default())
     }
 
     async fn recognize_face(&self, index: usize, name: &str) -> anyhow::Result<String> {
@@ -704,7 +802,10 @@ fn format_timeline_items(label: &str, items: &[GraphSensationTimelineItem]) -> S
     format!("{label}:\n{}", lines.join("\n"))
 }
 
-fn format_face_identity_targets(targets: &[GraphFaceIdentityTarget]) -> String {
+fn format_face_identity_targets(
+    targets: &[GraphFaceIdentityTarget],
+    people: &PersonNames,
+) -> String {
     if targets.is_empty() {
         return "Recent faces: no face detections are available.".into();
     }
@@ -712,11 +813,11 @@ fn format_face_identity_targets(targets: &[GraphFaceIdentityTarget]) -> String {
         .iter()
         .enumerate()
         .map(|(index, target)| {
-            let identity = target
-                .identity
-                .as_deref()
-                .map(|name| format!(" identity={name}"))
-                .unwrap_or_default();
+            let who = identity_or_person(
+                target.identity.as_deref(),
+                target.person_id.as_deref(),
+                people,
+            );
             let image = target
                 .source_image_id
                 .as_deref()
@@ -735,7 +836,7 @@ fn format_face_identity_targets(targets: &[GraphFaceIdentityTarget]) -> String {
                 target.occurred_at,
                 image,
                 vector,
-                identity
+                who
             )
         })
         .collect::<Vec<_>>();
@@ -745,7 +846,10 @@ fn format_face_identity_targets(targets: &[GraphFaceIdentityTarget]) -> String {
     )
 }
 
-fn format_voice_identity_targets(targets: &[GraphVoiceIdentityTarget]) -> String {
+fn format_voice_identity_targets(
+    targets: &[GraphVoiceIdentityTarget],
+    people: &PersonNames,
+) -> String {
     if targets.is_empty() {
         return "Recent voices: no voice signatures are available.".into();
     }
@@ -753,11 +857,11 @@ fn format_voice_identity_targets(targets: &[GraphVoiceIdentityTarget]) -> String
         .iter()
         .enumerate()
         .map(|(index, target)| {
-            let identity = target
-                .identity
-                .as_deref()
-                .map(|name| format!(" identity={name}"))
-                .unwrap_or_default();
+            let who = identity_or_person(
+                target.identity.as_deref(),
+                target.person_id.as_deref(),
+                people,
+            );
             let audio = target
                 .audio_clip_id
                 .as_deref()
@@ -776,7 +880,7 @@ fn format_voice_identity_targets(targets: &[GraphVoiceIdentityTarget]) -> String
                 target.occurred_at,
                 audio,
                 vector,
-                identity
+                who
             )
         })
         .collect::<Vec<_>>();
@@ -786,6 +890,20 @@ fn format_voice_identity_targets(targets: &[GraphVoiceIdentityTarget]) -> String
     )
 }
 
+/// Name a linked face or voice after its person, and others by identity.
+fn identity_or_person(
+    identity: Option<&str>,
+    person_id: Option<&str>,
+    people: &PersonNames,
+) -> String {
+    match person_id {
+        Some(person_id) => format!(" person={}", people.name(person_id, identity)),
+        None => identity
+            .map(|name| format!(" identity={name}"))
+            .unwrap_or_default(),
+    }
+}
+
 fn format_graph_node_details(details: GraphNodeDetails) -> String {
     let mut properties = compact_json(details.properties);
     if let Value::Object(object) = &mut properties {
@@ -958,8 +1076,9 @@ fn will_instruction_prompt(
          inspectGraphNode(id: string) - reads one graph node and nearby relationships.\n\
          neighbors(id: string, depth?: number) - reads graph neighbors up to depth 2.\n\
          look() - reads the latest image description.\n\
-         recentFaces(limit?: number) - lists recent face detections by selectable index.\n\
-         recentVoices(limit?: number) - lists recent voice signatures by selectable index.\n\
+         lookAt(region: string | {{x, y, width, height}}, question: string) - looks closer at part of the latest frame and answers the question. region names a person or object in view (by name, object class, or graph id) or is a box given as fractions of the frame.\n\
+         recentFaces(limit?: number) - lists recent face detections by selectable index, with the person each belongs to when Pete has linked it to a voice.\n\
+         recentVoices(limit?: number) - lists recent voice signatures by selectable index, with the person each belongs to when Pete has linked it to a face.\n\
          recognizeFace(index: number, name: string) or recognizeFace(name: string) - assigns a human identity to a recent face. Call recentFaces first if the right index is unclear.\n\
          recognizeVoice(index: number, name: string) or recognizeVoice(name: string) - assigns a human identity to a recent voice. Call recentVoices or listenRecent first if the right index is unclear.\n\
          setFace(emoji: string) - turns your face into an emoji.\n\
@@ -1005,24 +1124,61 @@ struct WillActionPayload {
     typescript: String,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq)]
 enum TypeScriptCommand {
     Say(String),
     ListFiles,
-    ReadSourceFile { file: String, page: usize },
-    SearchSource { query: String, limit: usize },
-    GrepSource { pattern: String, limit: usize },
-    ReadRecentTimeline { limit: usize },
-    ReadRecentConversation { limit: usize },
-    Recall { query: String, limit: usize },
-    InspectGraphNode { id: String },
-    Neighbors { id: String, depth: usize },
+    ReadSourceFile {
+        file: String,
+        page: usize,
+    },
+    SearchSource {
+        query: String,
+        limit: usize,
+    },
+    GrepSource {
+        pattern: String,
+        limit: usize,
+    },
+    ReadRecentTimeline {
+        limit: usize,
+    },
+    ReadRecentConversation {
+        limit: usize,
+    },
+    Recall {
+        query: String,
+        limit: usize,
+    },
+    InspectGraphNode {
+        id: String,
+    },
+    Neighbors {
+        id: String,
+        depth: usize,
+    },
     Look,
-    ListenRecent { limit: usize },
-    RecentFaces { limit: usize },
-    RecentVoices { limit: usize },
-    RecognizeFace { index: usize, name: String },
-    RecognizeVoice { index: usize, name: String },
+    LookAt {
+        region: LookRegion,
+        question: String,
+    },
+    ListenRecent {
+        limit: usize,
+    },
+    RecentFaces {
+        limit: usize,
+    },
+    RecentVoices {
+        limit: usize,
+    },
+    RecognizeFace {
+        index: usize,
+        name: String,
+    },
+    RecognizeVoice {
+        index: usize,
+        name: String,
+    },
     SetFace(String),
     Note(String),
     Remember(String),
@@ -1120,6 +1276,11 @@ enum TypeScriptCommandPayload {
         depth: Option<usize>,
     },
     Look,
+    LookAt {
+        target: Option<String>,
+        region: Option<BoundingBox>,
+        question: Option<String>,
+    },
     ListenRecent {
         limit: Option<usize>,
     },
@@ -1232,6 +1393,28 @@ fn execute_typescript_commands(script: &str) -> anyhow::Result<Vec<TypeScriptCom
                     depth: depth.unwrap_or(1).clamp(1, 2),
                 }),
             TypeScriptCommandPayload::Look => Some(TypeScriptCommand::Look),
+            TypeScriptCommandPayload::LookAt {
+                target,
+                region,
+                question,
+            } => {
+                let region = match (
+                    region,
+                    target.as_deref().and_then(common::non_empty_model_text),
+                ) {
+                    (Some(bbox), _) => LookRegion::Box(bbox),
+                    (None, Some(target)) => LookRegion::Named(target.to_string()),
+                    (None, None) => return None,
+                };
+                let question = question
+                    .as_deref()
+                    .and_then(common::non_empty_model_text)
+                    .unwrap_or("What do you see here?");
+                Some(TypeScriptCommand::LookAt {
+                    region,
+                    question: question.to_string(),
+                })
+            }
             TypeScriptCommandPayload::ListenRecent { limit } => {
                 Some(TypeScriptCommand::ListenRecent {
                     limit: limit.unwrap_or(10).max(1),
@@ -1305,6 +1488,7 @@ fn will_typescript_module() -> InternalModule {
         .with_function("inspectGraphNode", ts_inspect_graph_node, 1)
         .with_function("neighbors", ts_neighbors, 2)
         .with_function("look", ts_look, 0)
+        .with_function("lookAt", ts_look_at, 2)
         .with_function("listenRecent", ts_listen_recent, 1)
         .with_function("recentFaces", ts_recent_faces, 1)
         .with_function("recentVoices", ts_recent_voices, 1)
@@ -1465,6 +1649,43 @@ fn ts_look(
     command_value(interp, json!({ "kind": "look" }))
 }
 
+fn ts_look_at(
+    interp: &mut Interpreter,
+    _this: JsValue,
+    args: &[JsValue],
+) -> Result<Guarded, JsError> {
+    let mut value = json!({ "kind": "look_at", "question": string_arg(args, 1) });
+    match args.first() {
+        Some(target) if target.as_str().is_some() => value["target"] = json!(string_arg(args, 0)),
+        Some(region) => {
+            if let Some(bbox) = bounding_box_json(js_value_to_json(region)?) {
+                value["region"] = bbox;
+            }
+        }
+        None => {}
+    }
+    command_value(interp, value)
+}
+
+/// Normalise a box given as `{x, y, width, height}` or `[x, y, width, height]`.
+fn bounding_box_json(value: Value) -> Option<Value> {
+    let values = match &value {
+        Value::Array(items) => items
+            .iter()
+            .map(Value::as_f64)
+            .collect::<Option<Vec<_>>>()?,
+        Value::Object(fields) => ["x", "y", "width", "height"]
+            .iter()
+            .map(|key| fields.get(*key).and_then(Value::as_f64))
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+    let [x, y, width, height] = values[..] else {
+        return None;
+    };
+    Some(json!({ "x": x, "y": y, "width": width, "height": height }))
+}
+
 fn ts_listen_recent(
     interp: &mut Interpreter,
     _this: JsValue,
@@ -1864,6 +2085,42 @@ mod tests {
         assert!(prompt.contains("(no current conversation)"));
     }
 
+    #[test]
+    fn recent_faces_name_linked_people() {
+        let target =
+            |id: &str, identity: Option<&str>, person: Option<&str>| GraphFaceIdentityTarget {
+                target_id: id.into(),
+                target_label: "Face".into(),
+                face_instance_id: format!("{id}:instance"),
+                source_image_id: None,
+                vector_id: None,
+                identity: identity.map(Into::into),
+                occurred_at: "2026-05-08T09:00:00Z".into(),
+                person_id: person.map(Into::into),
+                pose: None,
+            };
+        let people = PersonNames::new(&[psyche::GraphPerson {
+            person_id: "person:1".into(),
+            name: None,
+            face_ids: vec!["face:1".into()],
+            voice_ids: vec!["voice:1".into()],
+            ordinal: Some(1),
+        }]);
+
+        let text = format_face_identity_targets(
+            &[
+                target("face:1", None, Some("person:1")),
+                target("face:2", Some("Anna"), None),
+            ],
+            &people,
+        );
+
+        assert!(text.contains("target=face:1 [Face]"));
+        assert!(text.contains(" person=unknown person 1"));
+        assert!(!text.contains("person:1"));
+        assert!(text.contains(" identity=Anna"));
+    }
+
     #[test]
     fn parses_structured_action_with_typescript() {
         let action = parse_will_action(
@@ -1898,6 +2155,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parses_look_at_by_name_and_box() {
+        let action = parse_will_action(
+            r#"{"thought":"What is Travis holding?","typescript":"import { lookAt } from \"pete:will\";\n[lookAt('Travis', 'What is in their hands?'), lookAt({ x: 0.5, y: 0.25, width: 0.25, height: 0.5 }, 'What does the screen say?'), lookAt([0, 0, 0.5, 0.5]), lookAt('', 'Who?')]"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            action.commands,
+            vec![
+                TypeScriptCommand::LookAt {
+                    region: LookRegion::Named("Travis".into()),
+                    question: "What is in their hands?".into()
+                },
+                TypeScriptCommand::LookAt {
+                    region: LookRegion::Box(BoundingBox {
+                        x: 0.5,
+                        y: 0.25,
+                        width: 0.25,
+                        height: 0.5
+                    }),
+                    question: "What does the screen say?".into()
+                },
+                TypeScriptCommand::LookAt {
+                    region: LookRegion::Box(BoundingBox {
+                        x: 0.0,
+                        y: 0.0,
+                        width: 0.5,
+                        height: 0.5
+                    }),
+                    question: "What do you see here?".into()
+                },
+            ]
+        );
+    }
+
     #[test]
     fn typescript_errors_are_rejected() {
         let err = parse_will_action(
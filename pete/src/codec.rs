@@ -0,0 +1,727 @@
+//! Decoding compressed browser audio.
+//!
+//! Browsers record with `MediaRecorder` as Opus in WebM (Chrome, Firefox) or
+//! Ogg (Firefox, Safari). [`CompressedAudioDecoder`] demuxes either container
+//! incrementally, so each `Hear` fragment can be decoded as it arrives even
+//! though only the first one carries the stream headers, decodes the Opus
+//! packets at 48 kHz mono and resamples them to the rate the caller needs.
+//! [`decode_compressed_audio`] does the same for a whole stored clip.
+//!
+//! Demuxing, [`Resampler`], [`wav_duration`], which times synthesized
+//! speech for lip-sync, and [`reshape_wav`], which gives synthesized speech
+//! the prosody a TTS backend could not, are plain Rust; the Opus decoder
+//! itself needs the `opus` feature.
+//!
+//! ```
+//! use pete::codec::{Resampler, is_compressed_mime};
+//!
+//! assert!(is_compressed_mime("audio/webm;codecs=opus"));
+//! assert!(!is_compressed_mime("audio/pcm;format=s16le;rate=16000"));
+//!
+//! let mut resampler = Resampler::new(48_000, 16_000);
+//! let mut out = resampler.process(&vec![0.25; 4_800]);
+//! out.extend(resampler.finish());
+//! assert_eq!(out.len(), 1_600);
+//! ```
+
+use anyhow::{Result, bail};
+use std::f64::consts::PI;
+use std::time::Duration;
+
+/// Rate Opus always decodes at here.
+pub const OPUS_SAMPLE_RATE: u32 = 48_000;
+
+/// Whether `mime` names a compressed container this module decodes.
+pub fn is_compressed_mime(mime: &str) -> bool {
+    let mime = mime.to_ascii_lowercase();
+    mime.starts_with("audio/webm")
+        || mime.starts_with("video/webm")
+        || mime.starts_with("audio/ogg")
+        || mime.starts_with("audio/opus")
+        || mime.contains("codecs=opus")
+}
+
+/// Whether `bytes` open a new Ogg or WebM stream, so a streaming decoder
+/// should start over.
+pub fn starts_stream(bytes: &[u8]) -> bool {
+    bytes.starts_with(&EBML_MAGIC) || (bytes.starts_with(b"OggS") && bytes.get(5) == Some(&0x02))
+}
+
+/// Decode a complete Ogg or WebM Opus clip to mono samples at `target_rate`.
+pub fn decode_compressed_audio(bytes: &[u8], mime: &str, target_rate: u32) -> Result<Vec<f32>> {
+    let mut decoder = CompressedAudioDecoder::new(mime, target_rate)?;
+    let mut samples = decoder.push(bytes)?;
+    samples.extend(decoder.finish());
+    Ok(samples)
+}
+
+/// Convert samples in `-1.0..=1.0` to 16-bit little-endian PCM.
+pub fn encode_pcm_s16le(samples: &[f32]) -> Vec<u8> {
+    samples
+        .iter()
+        .flat_map(|sample| ((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16).to_le_bytes())
+        .collect()
+}
+
+/// Playing time of a WAV file, read from its `fmt ` and `data` chunks.
+///
+/// Streaming synthesizers often leave the data size unset (zero or
+/// `0xFFFFFFFF`); the rest of the file is then taken as audio.
+pub fn wav_duration(bytes: &[u8]) -> Option<Duration> {
+    if bytes.len() < 12 || &bytes[..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return None;
+    }
+    let le_u32 = |at: usize| Some(u32::from_le_bytes(bytes.get(at..at + 4)?.try_into().ok()?));
+    let mut byte_rate = None;
+    let mut pos = 12;
+    while pos + 8 <= bytes.len() {
+        let size = le_u32(pos + 4)? as usize;
+        let body = pos + 8;
+        match &bytes[pos..pos + 4] {
+            b"fmt " => byte_rate = le_u32(body + 8).filter(|rate| *rate > 0),
+            b"data" => {
+                let available = bytes.len() - body;
+                let size = if size == 0 || size > available {
+                    available
+                } else {
+                    size
+                };
+                return Some(Duration::from_secs_f64(size as f64 / byte_rate? as f64));
+            }
+            _ => {}
+        }
+        // Chunks are padded to an even length.
+        pos = body.saturating_add(size).saturating_add(size & 1);
+    }
+    None
+}
+
+/// Change the tempo, pitch and loudness of a 16-bit PCM WAV file.
+///
+/// `tempo` speeds the audio up (`2.0` halves its length) without changing
+/// its pitch, `semitones` shifts the pitch without changing the length and
+/// `gain` scales the samples, clipping at full scale. Returns `None` for
+/// anything but 16-bit PCM.
+pub fn reshape_wav(bytes: &[u8], tempo: f32, semitones: f32, gain: f32) -> Option<Vec<u8>> {
+    let (sample_rate, channels, samples) = decode_pcm_s16le_wav(bytes)?;
+    let tempo = if tempo > 0.0 { f64::from(tempo) } else { 1.0 };
+    let pitch = 2f64.powf(f64::from(semitones) / 12.0);
+    // Resampling raises the pitch and the tempo together; stretching the
+    // result in time puts the tempo where it was asked to be.
+    let stretch = pitch / tempo;
+    let width = usize::from(channels.max(1));
+    let mut tracks: Vec<Vec<f32>> = (0..width)
+        .map(|channel| {
+            samples
+                .iter()
+                .skip(channel)
+                .step_by(width)
+                .copied()
+                .collect()
+        })
+        .collect();
+    for track in &mut tracks {
+        if (pitch - 1.0).abs() > 1e-3 {
+            let from = (f64::from(sample_rate) * pitch).round() as u32;
+            *track = resample(track, from, sample_rate);
+        }
+        if (stretch - 1.0).abs() > 1e-3 {
+            *track = time_stretch(track, stretch, sample_rate);
+        }
+        if (gain - 1.0).abs() > 1e-3 {
+            track.iter_mut().for_each(|sample| *sample *= gain);
+        }
+    }
+    let frames = tracks.iter().map(Vec::len).min().unwrap_or(0);
+    let interleaved: Vec<f32> = (0..frames)
+        .flat_map(|frame| tracks.iter().map(move |track| track[frame]))
+        .collect();
+    Some(pcm_s16le_wav(
+        &encode_pcm_s16le(&interleaved),
+        sample_rate,
+        channels,
+    ))
+}
+
+/// Sample rate, channel count and interleaved samples of a 16-bit PCM WAV.
+fn decode_pcm_s16le_wav(bytes: &[u8]) -> Option<(u32, u16, Vec<f32>)> {
+    if bytes.len() < 12 || &bytes[..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return None;
+    }
+    let le_u16 = |at: usize| Some(u16::from_le_bytes(bytes.get(at..at + 2)?.try_into().ok()?));
+    let le_u32 = |at: usize| Some(u32::from_le_bytes(bytes.get(at..at + 4)?.try_into().ok()?));
+    let mut format = None;
+    let mut pos = 12;
+    while pos + 8 <= bytes.len() {
+        let size = le_u32(pos + 4)? as usize;
+        let body = pos + 8;
+        match &bytes[pos..pos + 4] {
+            b"fmt " => {
+                let pcm = le_u16(body)? == 1 && le_u16(body + 14)? == 16;
+                format = pcm.then_some((le_u32(body + 4)?, le_u16(body + 2)?));
+            }
+            b"data" => {
+                let (sample_rate, channels) = format?;
+                let available = bytes.len() - body;
+                let size = if size == 0 || size > available {
+                    available
+                } else {
+                    size
+                };
+                let samples = bytes[body..body + size]
+                    .chunks_exact(2)
+                    .map(|pair| f32::from(i16::from_le_bytes([pair[0], pair[1]])) / i16::MAX as f32)
+                    .collect();
+                return Some((sample_rate, channels, samples));
+            }
+            _ => {}
+        }
+        pos = body.saturating_add(size).saturating_add(size & 1);
+    }
+    None
+}
+
+/// Wrap 16-bit little-endian PCM in a WAV header.
+fn pcm_s16le_wav(pcm: &[u8], sample_rate: u32, channels: u16) -> Vec<u8> {
+    let block_align = channels * 2;
+    let mut wav = Vec::with_capacity(44 + pcm.len());
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + pcm.len() as u32).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&channels.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(sample_rate * u32::from(block_align)).to_le_bytes());
+    wav.extend_from_slice(&block_align.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&(pcm.len() as u32).to_le_bytes());
+    wav.extend_from_slice(pcm);
+    wav
+}
+
+/// Stretch `samples` to `factor` times their length keeping their pitch.
+///
+/// This is WSOLA: 30 ms Hann-windowed frames are overlap-added at half a
+/// frame apart, each read from near where the stretched timeline puts it,
+/// shifted by up to a quarter frame to line up with the natural
+/// continuation of the frame before so the waveform stays in phase.
+fn time_stretch(samples: &[f32], factor: f64, sample_rate: u32) -> Vec<f32> {
+    let frame = (sample_rate as usize * 3 / 100).max(32) & !1;
+    let hop = frame / 2;
+    let tolerance = frame / 4;
+    let out_len = (samples.len() as f64 * factor).round() as usize;
+    if samples.len() < frame + hop {
+        return samples.to_vec();
+    }
+    let window: Vec<f32> = (0..frame)
+        .map(|i| (0.5 - 0.5 * (2.0 * PI * i as f64 / frame as f64).cos()) as f32)
+        .collect();
+    let last_start = samples.len() - frame;
+    let mut out = vec![0.0f32; out_len + frame];
+    let mut weight = vec![0.0f32; out_len + frame];
+    let mut previous: Option<usize> = None;
+    let mut at = 0;
+    while at < out_len {
+        let nominal = ((at as f64 / factor).round() as usize).min(last_start);
+        let start = match previous {
+            Some(previous) if previous + hop + hop <= samples.len() => {
+                let natural = &samples[previous + hop..previous + hop + hop];
+                let candidates =
+                    nominal.saturating_sub(tolerance)..=(nominal + tolerance).min(last_start);
+                candidates
+                    .max_by(|a, b| {
+                        let score = |start: usize| -> f32 {
+                            natural
+                                .iter()
+                                .zip(&samples[start..start + hop])
+                                .map(|(x, y)| x * y)
+                                .sum()
+                        };
+                        score(*a).total_cmp(&score(*b))
+                    })
+                    .unwrap_or(nominal)
+            }
+            _ => nominal,
+        };
+        for (i, w) in window.iter().enumerate() {
+            out[at + i] += samples[start + i] * w;
+            weight[at + i] += w;
+        }
+        previous = Some(start);
+        at += hop;
+    }
+    out.truncate(out_len);
+    for (sample, weight) in out.iter_mut().zip(weight) {
+        if weight > 1e-3 {
+            *sample /= weight;
+        }
+    }
+    out
+}
+
+/// Streaming decoder for one Opus recording in Ogg or WebM.
+pub struct CompressedAudioDecoder {
+    demuxer: OpusDemuxer,
+    opus: OpusFrames,
+    pre_skip: usize,
+    resampler: Resampler,
+}
+
+impl CompressedAudioDecoder {
+    /// Decoder producing mono samples at `target_rate`. `mime` picks the
+    /// container until the first bytes reveal it.
+    ///
+    /// Fails when pete was built without the `opus` feature.
+    pub fn new(mime: &str, target_rate: u32) -> Result<Self> {
+        Ok(Self {
+            demuxer: OpusDemuxer::new(mime),
+            opus: OpusFrames::new()?,
+            pre_skip: 0,
+            resampler: Resampler::new(OPUS_SAMPLE_RATE, target_rate),
+        })
+    }
+
+    /// Feed the next fragment of the recording and return the audio it
+    /// completes.
+    pub fn push(&mut self, bytes: &[u8]) -> Result<Vec<f32>> {
+        let mut decoded = Vec::new();
+        for packet in self.demuxer.push(bytes)? {
+            match packet {
+                OpusPacket::Head { pre_skip, .. } => self.pre_skip = usize::from(pre_skip),
+                OpusPacket::Audio(data) => {
+                    let frame = self.opus.decode(&data)?;
+                    let skip = self.pre_skip.min(frame.len());
+                    self.pre_skip -= skip;
+                    decoded.extend_from_slice(&frame[skip..]);
+                }
+            }
+        }
+        Ok(self.resampler.process(&decoded))
+    }
+
+    /// Flush audio held back by the resampler at the end of the recording.
+    pub fn finish(&mut self) -> Vec<f32> {
+        self.resampler.finish()
+    }
+}
+
+/// Packet found by an [`OpusDemuxer`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OpusPacket {
+    /// Stream header (`OpusHead`).
+    Head { channels: u8, pre_skip: u16 },
+    /// One Opus audio packet.
+    Audio(Vec<u8>),
+}
+
+/// Incremental Ogg or WebM demuxer yielding Opus packets.
+pub struct OpusDemuxer {
+    container: Option<Container>,
+    buffer: Vec<u8>,
+    ogg_packet: Vec<u8>,
+    webm_skip: usize,
+    webm_track_number: Option<u64>,
+    webm_track: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Container {
+    Ogg,
+    WebM,
+}
+
+const EBML_MAGIC: [u8; 4] = [0x1A, 0x45, 0xDF, 0xA3];
+const WEBM_SEGMENT: u32 = 0x1853_8067;
+const WEBM_CLUSTER: u32 = 0x1F43_B675;
+const WEBM_TRACKS: u32 = 0x1654_AE6B;
+const WEBM_TRACK_ENTRY: u32 = 0xAE;
+const WEBM_BLOCK_GROUP: u32 = 0xA0;
+const WEBM_BLOCK: u32 = 0xA1;
+const WEBM_SIMPLE_BLOCK: u32 = 0xA3;
+const WEBM_TRACK_NUMBER: u32 = 0xD7;
+const WEBM_CODEC_ID: u32 = 0x86;
+const WEBM_CODEC_PRIVATE: u32 = 0x63A2;
+
+impl OpusDemuxer {
+    /// Demuxer for a stream labelled `mime`.
+    pub fn new(mime: &str) -> Self {
+        let mime = mime.to_ascii_lowercase();
+        let container = if mime.contains("webm") {
+            Some(Container::WebM)
+        } else if mime.contains("ogg") {
+            Some(Container::Ogg)
+        } else {
+            None
+        };
+        Self {
+            container,
+            buffer: Vec::new(),
+            ogg_packet: Vec::new(),
+            webm_skip: 0,
+            webm_track_number: None,
+            webm_track: None,
+        }
+    }
+
+    /// Feed the next bytes and return every packet they complete.
+    pub fn push(&mut self, bytes: &[u8]) -> Result<Vec<OpusPacket>> {
+        if bytes.starts_with(b"OggS") {
+            self.container = Some(Container::Ogg);
+        } else if bytes.starts_with(&EBML_MAGIC) {
+            self.container = Some(Container::WebM);
+        }
+        self.buffer.extend_from_slice(bytes);
+        match self.container {
+            Some(Container::Ogg) => self.ogg_packets(),
+            Some(Container::WebM) => self.webm_packets(),
+            None if self.buffer.len() < 4 => Ok(Vec::new()),
+            None => bail!("audio is neither Ogg nor WebM"),
+        }
+    }
+
+    fn ogg_packets(&mut self) -> Result<Vec<OpusPacket>> {
+        let mut packets = Vec::new();
+        let mut pos = 0;
+        loop {
+            let Some(start) = find(&self.buffer[pos..], b"OggS") else {
+                // Keep a possible partial capture pattern.
+                pos = self.buffer.len().saturating_sub(3).max(pos);
+                break;
+            };
+            pos += start;
+            let page = &self.buffer[pos..];
+            if page.len() < 27 {
+                break;
+            }
+            let segments = usize::from(page[26]);
+            if page.len() < 27 + segments {
+                break;
+            }
+            let table = &page[27..27 + segments];
+            let body_len: usize = table.iter().map(|len| usize::from(*len)).sum();
+            let page_len = 27 + segments + body_len;
+            if page.len() < page_len {
+                break;
+            }
+            let mut offset = 27 + segments;
+            for &len in table {
+                let len = usize::from(len);
+                self.ogg_packet
+                    .extend_from_slice(&page[offset..offset + len]);
+                offset += len;
+                if len < 255 {
+                    let data = std::mem::take(&mut self.ogg_packet);
+                    packets.extend(opus_packet(data)?);
+                }
+            }
+            pos += page_len;
+        }
+        self.buffer.drain(..pos);
+        Ok(packets)
+    }
+
+    fn webm_packets(&mut self) -> Result<Vec<OpusPacket>> {
+        let mut packets = Vec::new();
+        let mut pos = 0;
+        loop {
+            if self.webm_skip > 0 {
+                let skipped = self.webm_skip.min(self.buffer.len() - pos);
+                self.webm_skip -= skipped;
+                pos += skipped;
+                if self.webm_skip > 0 {
+                    break;
+                }
+            }
+            let Some((id, id_len)) = ebml_id(&self.buffer[pos..]) else {
+                break;
+            };
+            let Some((size, size_len)) = ebml_size(&self.buffer[pos + id_len..]) else {
+                break;
+            };
+            let header = id_len + size_len;
+            match id {
+                // Containers are walked into rather than read whole; live
+                // recordings leave Segment and Cluster sizes unknown.
+                WEBM_SEGMENT | WEBM_CLUSTER | WEBM_TRACKS | WEBM_TRACK_ENTRY | WEBM_BLOCK_GROUP => {
+                    pos += header
+                }
+                WEBM_SIMPLE_BLOCK | WEBM_BLOCK | WEBM_TRACK_NUMBER | WEBM_CODEC_ID
+                | WEBM_CODEC_PRIVATE => {
+                    let Some(size) = size else {
+                        bail!("WebM element {id:#x} has unknown size");
+                    };
+                    if self.buffer.len() - pos < header + size {
+                        break;
+                    }
+                    let body = &self.buffer[pos + header..pos + header + size];
+                    match id {
+                        WEBM_SIMPLE_BLOCK | WEBM_BLOCK => {
+                            if let Some(data) = webm_block_frame(body, self.webm_track)? {
+                                packets.push(OpusPacket::Audio(data));
+                            }
+                        }
+                        WEBM_TRACK_NUMBER => self.webm_track_number = Some(be_uint(body)),
+                        WEBM_CODEC_ID if body == b"A_OPUS" => {
+                            self.webm_track = self.webm_track_number;
+                        }
+                        WEBM_CODEC_ID if body.starts_with(b"A_") => bail!(
+                            "unsupported WebM audio codec {}",
+                            String::from_utf8_lossy(body)
+                        ),
+                        WEBM_CODEC_PRIVATE => packets.extend(opus_packet(body.to_vec())?),
+                        _ => {}
+                    }
+                    pos += header + size;
+                }
+                _ => {
+                    let Some(size) = size else {
+                        bail!("WebM element {id:#x} has unknown size");
+                    };
+                    pos += header;
+                    self.webm_skip = size;
+                }
+            }
+        }
+        self.buffer.drain(..pos);
+        Ok(packets)
+    }
+}
+
+/// Classify an Ogg packet or WebM `CodecPrivate` as header or audio.
+fn opus_packet(data: Vec<u8>) -> Result<Vec<OpusPacket>> {
+    if data.starts_with(b"OpusHead") {
+        if data.len() < 12 {
+            bail!("truncated OpusHead header");
+        }
+        return Ok(vec![OpusPacket::Head {
+            channels: data[9],
+            pre_skip: u16::from_le_bytes([data[10], data[11]]),
+        }]);
+    }
+    if data.starts_with(b"OpusTags") || data.is_empty() {
+        return Ok(Vec::new());
+    }
+    if data.starts_with(b"\x01vorbis") || data.starts_with(b"\x7fFLAC") {
+        bail!("only Opus audio is supported in Ogg");
+    }
+    Ok(vec![OpusPacket::Audio(data)])
+}
+
+/// Frame of an unlaced (Simple)Block on `track`, if it belongs to it.
+fn webm_block_frame(body: &[u8], track: Option<u64>) -> Result<Option<Vec<u8>>> {
+    let Some((number, len)) = ebml_size(body) else {
+        bail!("truncated WebM block");
+    };
+    if body.len() < len + 3 {
+        bail!("truncated WebM block");
+    }
+    if track.is_some_and(|track| number != Some(track as usize)) {
+        return Ok(None);
+    }
+    let flags = body[len + 2];
+    if flags & 0x06 != 0 {
+        bail!("laced WebM blocks are not supported");
+    }
+    Ok(Some(body[len + 3..].to_vec()))
+}
+
+/// EBML element id with its marker bits, and its length.
+fn ebml_id(bytes: &[u8]) -> Option<(u32, usize)> {
+    let first = *bytes.first()?;
+    let len = first.leading_zeros() as usize + 1;
+    if len > 4 || bytes.len() < len {
+        return None;
+    }
+    let id = bytes[..len]
+        .iter()
+        .fold(0u32, |id, byte| (id << 8) | u32::from(*byte));
+    Some((id, len))
+}
+
+/// EBML variable-size integer (`None` when all value bits are set, meaning
+/// unknown size), and its length.
+fn ebml_size(bytes: &[u8]) -> Option<(Option<usize>, usize)> {
+    let first = *bytes.first()?;
+    let len = first.leading_zeros() as usize + 1;
+    if len > 8 || bytes.len() < len {
+        return None;
+    }
+    let mask = if len == 8 { 0 } else { 0xFFu8 >> len };
+    let value = bytes[1..len]
+        .iter()
+        .fold(u64::from(first & mask), |value, byte| {
+            (value << 8) | u64::from(*byte)
+        });
+    let unknown = (1u64 << (7 * len)) - 1;
+    Some(((value != unknown).then_some(value as usize), len))
+}
+
+fn be_uint(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(0, |value, byte| (value << 8) | u64::from(*byte))
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Opus decoder at 48 kHz that downmixes every stream to mono.
+#[cfg(feature = "opus")]
+struct OpusFrames {
+    decoder: opus::Decoder,
+    frame: Vec<f32>,
+}
+
+#[cfg(feature = "opus")]
+impl OpusFrames {
+    /// Longest Opus packet: 120 ms at 48 kHz.
+    const MAX_FRAME: usize = 5_760;
+
+    fn new() -> Result<Self> {
+        Ok(Self {
+            decoder: opus::Decoder::new(OPUS_SAMPLE_RATE, opus::Channels::Mono)?,
+            frame: vec![0.0; Self::MAX_FRAME],
+        })
+    }
+
+    fn decode(&mut self, packet: &[u8]) -> Result<Vec<f32>> {
+        let len = self.decoder.decode_float(packet, &mut self.frame, false)?;
+        Ok(self.frame[..len].to_vec())
+    }
+}
+
+#[cfg(not(feature = "opus"))]
+struct OpusFrames;
+
+#[cfg(not(feature = "opus"))]
+impl OpusFrames {
+    fn new() -> Result<Self> {
+        bail!("compressed audio needs pete built with the `opus` feature")
+    }
+
+    fn decode(&mut self, _packet: &[u8]) -> Result<Vec<f32>> {
+        bail!("compressed audio needs pete built with the `opus` feature")
+    }
+}
+
+/// Streaming band-limited resampler (windowed sinc).
+///
+/// Output sample `n` sits at input position `n * from / to`; each is a
+/// Hann-windowed sinc interpolation with the cutoff at the lower Nyquist
+/// frequency, so downsampling does not alias.
+pub struct Resampler {
+    from: u32,
+    to: u32,
+    step: f64,
+    cutoff: f64,
+    half_width: usize,
+    history: Vec<f32>,
+    /// Input samples dropped from the front of `history`.
+    offset: usize,
+    /// Output samples produced so far.
+    produced: u64,
+    /// Input samples seen so far.
+    consumed: u64,
+}
+
+impl Resampler {
+    /// Zero crossings of the sinc kept on each side.
+    const ZERO_CROSSINGS: f64 = 16.0;
+
+    /// Resampler converting `from` Hz to `to` Hz.
+    pub fn new(from: u32, to: u32) -> Self {
+        let cutoff = (f64::from(to) / f64::from(from)).min(1.0);
+        Self {
+            from,
+            to,
+            step: f64::from(from) / f64::from(to),
+            cutoff,
+            half_width: (Self::ZERO_CROSSINGS / cutoff).ceil() as usize,
+            history: Vec::new(),
+            offset: 0,
+            produced: 0,
+            consumed: 0,
+        }
+    }
+
+    /// Resample the next block of input.
+    pub fn process(&mut self, input: &[f32]) -> Vec<f32> {
+        if self.from == self.to {
+            return input.to_vec();
+        }
+        self.history.extend_from_slice(input);
+        self.consumed += input.len() as u64;
+        self.drain(false)
+    }
+
+    /// Emit the remaining output as if the input were followed by silence.
+    pub fn finish(&mut self) -> Vec<f32> {
+        if self.from == self.to {
+            return Vec::new();
+        }
+        self.drain(true)
+    }
+
+    fn drain(&mut self, flush: bool) -> Vec<f32> {
+        let mut out = Vec::new();
+        let total = (self.consumed as u128 * u128::from(self.to)).div_ceil(u128::from(self.from));
+        loop {
+            if flush && u128::from(self.produced) >= total {
+                break;
+            }
+            let center = self.produced as f64 * self.step;
+            let last_needed = center.floor() as usize + self.half_width;
+            if !flush && last_needed >= self.offset + self.history.len() {
+                break;
+            }
+            out.push(self.interpolate(center));
+            self.produced += 1;
+        }
+        let keep_from = ((self.produced as f64 * self.step).floor() as usize)
+            .saturating_sub(self.half_width)
+            .max(self.offset);
+        self.history.drain(..keep_from - self.offset);
+        self.offset = keep_from;
+        out
+    }
+
+    fn interpolate(&self, center: f64) -> f32 {
+        let base = center.floor() as i64;
+        let half = self.half_width as i64;
+        let mut sum = 0.0;
+        for index in (base - half + 1)..=(base + half) {
+            let Some(sample) = usize::try_from(index)
+                .ok()
+                .and_then(|index| index.checked_sub(self.offset))
+                .and_then(|index| self.history.get(index))
+            else {
+                continue;
+            };
+            let x = center - index as f64;
+            let window = 0.5 + 0.5 * (PI * x / (half as f64)).cos();
+            sum += f64::from(*sample) * self.cutoff * sinc(self.cutoff * x) * window;
+        }
+        sum as f32
+    }
+}
+
+/// Resample a whole clip from `from` Hz to `to` Hz.
+pub fn resample(samples: &[f32], from: u32, to: u32) -> Vec<f32> {
+    let mut resampler = Resampler::new(from, to);
+    let mut out = resampler.process(samples);
+    out.extend(resampler.finish());
+    out
+}
+
+fn sinc(x: f64) -> f64 {
+    if x.abs() < 1e-9 {
+        1.0
+    } else {
+        (PI * x).sin() / (PI * x)
+    }
+}
@@ -0,0 +1,463 @@
+//! Speaker diarization for long audio clips.
+//!
+//! A big transcription often covers several people talking in turn, while a
+//! voice embedding describes a single speaker. Diarization slides an
+//! embedding window over the speech found by [`vad`](crate::vad), groups the
+//! windows by voice with average-linkage clustering on cosine similarity, and
+//! turns the labelled windows into [`SpeakerTurn`]s. Word timings from
+//! Whisper are then attributed to whichever speaker covers most of them with
+//! [`attribute_span`], so a segment that runs across a change of speaker is
+//! split between them at a word boundary.
+//!
+//! Embedding itself is left to the caller so the same logic works with any
+//! speaker model:
+//!
+//! ```
+//! use pete::diarize::{self, DiarizeConfig};
+//!
+//! let embeddings = vec![vec![1.0, 0.0], vec![0.9, 0.1], vec![0.0, 1.0]];
+//! let labels = diarize::cluster_embeddings(&embeddings, &DiarizeConfig::default());
+//! assert_eq!(labels, vec![0, 0, 1]);
+//! ```
+
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration};
+use common::env_or;
+use psyche::{
+    GraphDiarization, GraphDiarizationSegment, GraphDiarizedSpeaker, GraphSpeakerAttribution,
+    GraphSpeakerTurn,
+};
+use serde::Serialize;
+use std::ops::Range;
+use std::time::Duration;
+
+use crate::vad::{self, VadConfig};
+
+/// Tunables for windowing and clustering.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiarizeConfig {
+    /// Audio handed to the embedding model per window.
+    pub window: Duration,
+    /// Step between consecutive windows.
+    pub hop: Duration,
+    /// Shortest speech run that still gets its own window.
+    pub min_window: Duration,
+    /// Cosine similarity above which two clusters are the same speaker.
+    pub similarity: f32,
+    /// Upper bound on distinct speakers per clip.
+    pub max_speakers: usize,
+}
+
+impl Default for DiarizeConfig {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_millis(1_500),
+            hop: Duration::from_millis(750),
+            min_window: Duration::from_millis(500),
+            similarity: 0.75,
+            max_speakers: 8,
+        }
+    }
+}
+
+impl DiarizeConfig {
+    /// Read overrides from `DIARIZE_WINDOW_MS`, `DIARIZE_HOP_MS`,
+    /// `DIARIZE_MIN_WINDOW_MS`, `DIARIZE_SIMILARITY` and
+    /// `DIARIZE_MAX_SPEAKERS`.
+    pub fn from_env() -> Result<Self> {
+        let d = Self::default();
+        let window = env_or("DIARIZE_WINDOW_MS", d.window.as_millis() as u64)?.clamp(250, 10_000);
+        Ok(Self {
+            window: Duration::from_millis(window),
+            hop: Duration::from_millis(
+                env_or("DIARIZE_HOP_MS", d.hop.as_millis() as u64)?.clamp(50, window),
+            ),
+            min_window: Duration::from_millis(
+                env_or("DIARIZE_MIN_WINDOW_MS", d.min_window.as_millis() as u64)?.min(window),
+            ),
+            similarity: env_or("DIARIZE_SIMILARITY", d.similarity)?.clamp(-1.0, 1.0),
+            max_speakers: env_or("DIARIZE_MAX_SPEAKERS", d.max_speakers)?.max(1),
+        })
+    }
+}
+
+/// When one transcribed word was spoken.
+#[derive(Clone, Debug, Serialize)]
+pub struct WordTiming {
+    pub text: String,
+    pub start_ms: u32,
+    pub end_ms: u32,
+}
+
+/// A stretch of audio attributed to one speaker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpeakerTurn {
+    /// Speaker index from [`cluster_embeddings`].
+    pub speaker: usize,
+    /// Start offset from the beginning of the clip.
+    pub start_ms: u32,
+    /// End offset from the beginning of the clip.
+    pub end_ms: u32,
+}
+
+/// Sample ranges to embed, covering every speech run found by the VAD.
+///
+/// Runs longer than a window are covered by overlapping windows `hop` apart,
+/// with the last one pinned to the end of the run. Runs shorter than
+/// `min_window` are skipped because speaker models are unreliable on them.
+pub fn embedding_windows(
+    samples: &[f32],
+    sample_rate: u32,
+    vad_config: &VadConfig,
+    config: &DiarizeConfig,
+) -> Vec<Range<usize>> {
+    let to_samples = |d: Duration| (d.as_secs_f32() * sample_rate as f32) as usize;
+    let window = to_samples(config.window).max(1);
+    let hop = to_samples(config.hop).max(1);
+    let min_window = to_samples(config.min_window);
+    let mut windows = Vec::new();
+    for run in vad::speech_segments(samples, sample_rate, vad_config) {
+        let len = run.end - run.start;
+        if len < min_window.max(1) {
+            continue;
+        }
+        if len <= window {
+            windows.push(run);
+            continue;
+        }
+        let mut start = run.start;
+        while start + window < run.end {
+            windows.push(start..start + window);
+            start += hop;
+        }
+        windows.push(run.end - window..run.end);
+    }
+    windows
+}
+
+/// Group embeddings by speaker.
+///
+/// Starts with one cluster per embedding and repeatedly merges the most
+/// similar pair (average cosine similarity) until no pair reaches
+/// `similarity` and at most `max_speakers` remain. Labels are numbered in
+/// order of first appearance.
+pub fn cluster_embeddings(embeddings: &[Vec<f32>], config: &DiarizeConfig) -> Vec<usize> {
+    let n = embeddings.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let normalized = embeddings.iter().map(|e| normalize(e)).collect::<Vec<_>>();
+    let mut similarity = vec![vec![0.0f32; n]; n];
+    for i in 0..n {
+        for j in i + 1..n {
+            let s = dot(&normalized[i], &normalized[j]);
+            similarity[i][j] = s;
+            similarity[j][i] = s;
+        }
+    }
+
+    let mut clusters = (0..n).map(|i| vec![i]).collect::<Vec<_>>();
+    while clusters.len() > 1 {
+        let mut best = None::<(usize, usize, f32)>;
+        for a in 0..clusters.len() {
+            for b in a + 1..clusters.len() {
+                let mut total = 0.0;
+                for &i in &clusters[a] {
+                    for &j in &clusters[b] {
+                        total += similarity[i][j];
+                    }
+                }
+                let average = total / (clusters[a].len() * clusters[b].len()) as f32;
+                if best.is_none_or(|(_, _, s)| average > s) {
+                    best = Some((a, b, average));
+                }
+            }
+        }
+        let Some((a, b, score)) = best else {
+            break;
+        };
+        if score < config.similarity && clusters.len() <= config.max_speakers.max(1) {
+            break;
+        }
+        let merged = clusters.remove(b);
+        clusters[a].extend(merged);
+    }
+
+    let mut labels = vec![0; n];
+    let mut order = clusters
+        .iter()
+        .map(|members| *members.iter().min().unwrap_or(&0))
+        .enumerate()
+        .collect::<Vec<_>>();
+    order.sort_by_key(|(_, first)| *first);
+    for (label, (cluster, _)) in order.into_iter().enumerate() {
+        for &member in &clusters[cluster] {
+            labels[member] = label;
+        }
+    }
+    labels
+}
+
+/// Mean unit vector of each speaker's embeddings, indexed by label.
+pub fn speaker_centroids(embeddings: &[Vec<f32>], labels: &[usize]) -> Vec<Vec<f32>> {
+    let speakers = labels.iter().copied().max().map_or(0, |max| max + 1);
+    let dim = embeddings.first().map_or(0, Vec::len);
+    let mut sums = vec![vec![0.0f32; dim]; speakers];
+    for (embedding, &label) in embeddings.iter().zip(labels) {
+        for (sum, value) in sums[label].iter_mut().zip(normalize(embedding)) {
+            *sum += value;
+        }
+    }
+    sums.iter().map(|sum| normalize(sum)).collect()
+}
+
+/// Merge labelled windows into contiguous speaker turns.
+///
+/// Where overlapping windows disagree the boundary is placed halfway
+/// through the overlap. Gaps between windows of the same speaker are
+/// bridged; gaps between different speakers are left unattributed.
+pub fn speaker_turns(
+    windows: &[Range<usize>],
+    labels: &[usize],
+    sample_rate: u32,
+) -> Vec<SpeakerTurn> {
+    let mut turns: Vec<SpeakerTurn> = Vec::new();
+    for (i, (window, &speaker)) in windows.iter().zip(labels).enumerate() {
+        let start = match i.checked_sub(1).map(|p| &windows[p]) {
+            Some(previous) if previous.end > window.start => {
+                window.start + (previous.end - window.start) / 2
+            }
+            _ => window.start,
+        };
+        let end = match windows.get(i + 1) {
+            Some(next) if window.end > next.start => next.start + (window.end - next.start) / 2,
+            _ => window.end,
+        };
+        let start_ms = samples_to_ms(start, sample_rate);
+        let end_ms = samples_to_ms(end.max(start), sample_rate);
+        match turns.last_mut() {
+            Some(last) if last.speaker == speaker => last.end_ms = last.end_ms.max(end_ms),
+            _ => turns.push(SpeakerTurn {
+                speaker,
+                start_ms,
+                end_ms,
+            }),
+        }
+    }
+    turns
+}
+
+/// Speaker covering the most of `start_ms..end_ms`, with the covered share.
+///
+/// Spans that fall between turns go to the nearest turn with zero
+/// confidence. Returns `None` only when there are no turns.
+pub fn attribute_span(turns: &[SpeakerTurn], start_ms: u32, end_ms: u32) -> Option<(usize, f32)> {
+    let end_ms = end_ms.max(start_ms);
+    let duration = (end_ms - start_ms).max(1);
+    let mut overlap = Vec::<(usize, u32)>::new();
+    for turn in turns {
+        let covered = end_ms
+            .min(turn.end_ms)
+            .saturating_sub(start_ms.max(turn.start_ms));
+        if covered == 0 {
+            continue;
+        }
+        match overlap
+            .iter_mut()
+            .find(|(speaker, _)| *speaker == turn.speaker)
+        {
+            Some((_, total)) => *total += covered,
+            None => overlap.push((turn.speaker, covered)),
+        }
+    }
+    if let Some((speaker, covered)) = overlap.into_iter().max_by_key(|(_, covered)| *covered) {
+        return Some((speaker, (covered as f32 / duration as f32).min(1.0)));
+    }
+    let middle = start_ms + (end_ms - start_ms) / 2;
+    turns
+        .iter()
+        .min_by_key(|turn| {
+            if middle < turn.start_ms {
+                turn.start_ms - middle
+            } else {
+                middle.saturating_sub(turn.end_ms)
+            }
+        })
+        .map(|turn| (turn.speaker, 0.0))
+}
+
+/// Cosine similarity of two embeddings.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    dot(&normalize(a), &normalize(b))
+}
+
+fn normalize(v: &[f32]) -> Vec<f32> {
+    let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm <= f32::EPSILON {
+        return vec![0.0; v.len()];
+    }
+    v.iter().map(|x| x / norm).collect()
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn samples_to_ms(samples: usize, sample_rate: u32) -> u32 {
+    if sample_rate == 0 {
+        return 0;
+    }
+    ((samples as u64 * 1000) / u64::from(sample_rate)).min(u64::from(u32::MAX)) as u32
+}
+
+/// Timings of the words of `segment`, spread over it in proportion to
+/// their length.
+///
+/// Word-level transcripts store every word as its own segment, which then
+/// keeps its exact timing.
+pub fn segment_words(segment: &GraphDiarizationSegment) -> Vec<WordTiming> {
+    let words = segment.text.split_whitespace().collect::<Vec<_>>();
+    let end_ms = segment.end_ms.max(segment.start_ms);
+    if words.len() <= 1 {
+        return vec![WordTiming {
+            text: segment.text.trim().to_string(),
+            start_ms: segment.start_ms,
+            end_ms,
+        }];
+    }
+    let duration = u64::from(end_ms - segment.start_ms);
+    let total = words.iter().map(|word| word.len()).sum::<usize>() as u64;
+    let at = |chars: u64| segment.start_ms + (duration * chars / total.max(1)) as u32;
+    let mut spoken = 0;
+    words
+        .into_iter()
+        .map(|word| {
+            let start_ms = at(spoken);
+            spoken += word.len() as u64;
+            WordTiming {
+                text: word.to_string(),
+                start_ms,
+                end_ms: at(spoken),
+            }
+        })
+        .collect()
+}
+
+/// Attribute each transcript segment to a speaker and group runs of the
+/// same speaker into turns ready for
+/// [`Neo4jClient::attach_speaker_diarization`](psyche::Neo4jClient::attach_speaker_diarization).
+///
+/// A segment goes to the speaker covering most of it, while turns follow its
+/// [`segment_words`]: words spoken after a change of speaker start the next
+/// turn, and the segment belongs to both.
+pub fn attribute_segments(
+    segments: &[GraphDiarizationSegment],
+    turns: &[SpeakerTurn],
+    speakers: Vec<GraphDiarizedSpeaker>,
+) -> GraphDiarization {
+    let mut attributions = Vec::new();
+    let mut speaker_turns: Vec<GraphSpeakerTurn> = Vec::new();
+    for segment in segments {
+        let Some((speaker, confidence)) = attribute_span(turns, segment.start_ms, segment.end_ms)
+        else {
+            continue;
+        };
+        attributions.push(GraphSpeakerAttribution {
+            segment_id: segment.id.clone(),
+            speaker,
+            confidence,
+        });
+        for piece in split_by_speaker(segment, turns) {
+            match speaker_turns.last_mut() {
+                Some(turn) if turn.speaker == piece.speaker => {
+                    if !piece.text.is_empty() {
+                        if !turn.text.is_empty() {
+                            turn.text.push(' ');
+                        }
+                        turn.text.push_str(&piece.text);
+                    }
+                    turn.end_ms = turn.end_ms.max(piece.end_ms);
+                    if piece.ended_at.is_some() {
+                        turn.ended_at = piece.ended_at;
+                    }
+                    if turn.segment_ids.last() != Some(&segment.id) {
+                        turn.segment_ids.push(segment.id.clone());
+                    }
+                }
+                _ => speaker_turns.push(GraphSpeakerTurn {
+                    speaker: piece.speaker,
+                    text: piece.text,
+                    start_ms: piece.start_ms,
+                    end_ms: piece.end_ms,
+                    occurred_at: piece.occurred_at,
+                    ended_at: piece.ended_at,
+                    segment_ids: vec![segment.id.clone()],
+                }),
+            }
+        }
+    }
+    GraphDiarization {
+        speakers,
+        attributions,
+        turns: speaker_turns,
+    }
+}
+
+/// Consecutive words of one segment spoken by the same speaker.
+struct SegmentPiece {
+    speaker: usize,
+    text: String,
+    start_ms: u32,
+    end_ms: u32,
+    occurred_at: Option<String>,
+    ended_at: Option<String>,
+}
+
+fn split_by_speaker(segment: &GraphDiarizationSegment, turns: &[SpeakerTurn]) -> Vec<SegmentPiece> {
+    let mut pieces: Vec<SegmentPiece> = Vec::new();
+    for word in segment_words(segment) {
+        let Some((speaker, _)) = attribute_span(turns, word.start_ms, word.end_ms) else {
+            continue;
+        };
+        match pieces.last_mut() {
+            Some(piece) if piece.speaker == speaker => {
+                if !piece.text.is_empty() {
+                    piece.text.push(' ');
+                }
+                piece.text.push_str(&word.text);
+                piece.end_ms = word.end_ms;
+            }
+            _ => pieces.push(SegmentPiece {
+                speaker,
+                text: word.text,
+                start_ms: word.start_ms,
+                end_ms: word.end_ms,
+                occurred_at: None,
+                ended_at: None,
+            }),
+        }
+    }
+    // Pieces keep the segment's timestamps at its ends and are offset from
+    // its start in between.
+    let count = pieces.len();
+    for (index, piece) in pieces.iter_mut().enumerate() {
+        piece.occurred_at = if index == 0 {
+            segment.occurred_at.clone()
+        } else {
+            offset_timestamp(segment, piece.start_ms)
+        };
+        piece.ended_at = if index + 1 == count {
+            segment.ended_at.clone()
+        } else {
+            offset_timestamp(segment, piece.end_ms)
+        };
+    }
+    pieces
+}
+
+/// Absolute time of `ms` into the transcription, from the segment's start.
+fn offset_timestamp(segment: &GraphDiarizationSegment, ms: u32) -> Option<String> {
+    let started = DateTime::parse_from_rfc3339(segment.occurred_at.as_deref()?).ok()?;
+    let offset = i64::from(ms) - i64::from(segment.start_ms);
+    Some((started + ChronoDuration::milliseconds(offset)).to_rfc3339())
+}
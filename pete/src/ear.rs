@@ -3,10 +3,10 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use psyche::traits::Ear;
 #[cfg(feature = "ear")]
-use psyche::{Sensation, Voice};
+use psyche::{EchoAction, EchoFilter, HeardWord, PartialUtterance, Sensation, Voice};
 #[cfg(feature = "ear")]
 use std::sync::{
-    Arc,
+    Arc, Mutex,
     atomic::{AtomicBool, Ordering},
 };
 #[cfg(feature = "ear")]
@@ -21,6 +21,8 @@ pub struct ChannelEar {
     forward: mpsc::Sender<Sensation>,
     speaking: Arc<AtomicBool>,
     voice: Arc<Voice>,
+    last_partial: Arc<Mutex<String>>,
+    echo: Option<Arc<EchoFilter>>,
 }
 
 #[cfg(feature = "ear")]
@@ -35,20 +37,40 @@ impl ChannelEar {
             forward,
             speaking,
             voice,
+            last_partial: Arc::default(),
+            echo: None,
         }
     }
 
+    /// Recognise Pete's own voice in transcripts with `filter`.
+    ///
+    /// Playback reports passing through this ear feed the filter; other
+    /// sources of Pete's speech, such as the TTS stream, should call
+    /// [`EchoFilter::queued`] themselves.
+    pub fn with_echo_filter(mut self, filter: Arc<EchoFilter>) -> Self {
+        self.echo = Some(filter);
+        self
+    }
+
     /// Human readable description of this sense.
     pub const DESCRIPTION: &'static str = "You hear audio from the user, transcribed as text. He can respond to spoken questions and converse naturally.";
 
     fn queue_sensation(&self, sensation: Sensation, label: &'static str) {
+        self.queue_sensations(vec![sensation], label);
+    }
+
+    /// Queue `sensations` in order, so later ones may refer to earlier ones.
+    fn queue_sensations(&self, sensations: Vec<Sensation>, label: &'static str) {
         let forward = self.forward.clone();
         tokio::spawn(async move {
-            if forward.send(sensation).await.is_err() {
-                warn!(
-                    label,
-                    "failed to queue heard speech; psyche input is closed"
-                );
+            for sensation in sensations {
+                if forward.send(sensation).await.is_err() {
+                    warn!(
+                        label,
+                        "failed to queue heard speech; psyche input is closed"
+                    );
+                    return;
+                }
             }
         });
     }
@@ -76,12 +98,78 @@ impl Ear for ChannelEar {
     }
 
     async fn hear_user_say_at(&self, text: &str, occurred_at: DateTime<Utc>) {
-        info!(%text, "ear heard user say");
-        trace!("ear heard user say queued");
-        self.voice.permit(None);
+        self.hear_user_words_at(text, &[], occurred_at).await;
+    }
+
+    async fn hear_user_words_at(
+        &self,
+        text: &str,
+        words: &[HeardWord],
+        occurred_at: DateTime<Utc>,
+    ) -> Option<EchoAction> {
+        self.last_partial.lock().unwrap().clear();
+        let verdict = self
+            .echo
+            .as_ref()
+            .map(|echo| echo.check(text, words, occurred_at));
+        let action = verdict.as_ref().and_then(|verdict| verdict.action);
+        let heard = match action {
+            None => {
+                info!(%text, "ear heard user say");
+                self.voice.permit(None);
+                Some(Sensation::heard_user_voice_at(
+                    text.to_string(),
+                    occurred_at,
+                ))
+            }
+            Some(EchoAction::Reclassify) => {
+                info!(%text, "ear heard own voice echo");
+                Some(Sensation::heard_own_voice_at(text.to_string(), occurred_at))
+            }
+            Some(EchoAction::Drop) => {
+                info!(%text, "ear dropped own voice echo");
+                None
+            }
+        };
+        let mut sensations = Vec::from_iter(heard);
+        // Only comparisons with something Pete said are worth auditing.
+        if let Some(verdict) = verdict.filter(|verdict| verdict.spoken.is_some()) {
+            sensations.push(Sensation::of_at(verdict, occurred_at));
+        }
+        self.queue_sensations(sensations, "user");
+        action
+    }
+
+    async fn hear_user_partial_at(&self, text: &str, stable: bool, occurred_at: DateTime<Utc>) {
+        trace!(%text, stable, "ear heard user partial");
+        // Only settled hypotheses are worth a sensation, and only once each.
+        if !stable {
+            return;
+        }
+        if self
+            .echo
+            .as_ref()
+            .is_some_and(|echo| echo.check(text, &[], occurred_at).echo)
+        {
+            trace!(%text, "ear ignored partial own voice echo");
+            return;
+        }
+        {
+            let mut last = self.last_partial.lock().unwrap();
+            if *last == text {
+                return;
+            }
+            *last = text.to_string();
+        }
         self.queue_sensation(
-            Sensation::heard_user_voice_at(text.to_string(), occurred_at),
-            "user",
+            Sensation::of_at(
+                PartialUtterance {
+                    text: text.to_string(),
+                    stable,
+                },
+                occurred_at,
+            ),
+            "partial",
         );
     }
 
@@ -102,6 +190,9 @@ impl Ear for ChannelEar {
     async fn started_speaking(&self, text: &str, occurred_at: DateTime<Utc>) {
         trace!(%text, "ear heard self start speaking");
         self.speaking.store(true, Ordering::SeqCst);
+        if let Some(echo) = &self.echo {
+            echo.speaking(text, occurred_at);
+        }
         self.queue_sensation(
             Sensation::StartedSpeaking {
                 text: text.to_string(),
@@ -114,6 +205,9 @@ impl Ear for ChannelEar {
     async fn finished_speaking(&self, text: &str, occurred_at: DateTime<Utc>) {
         trace!(%text, "ear heard self finish speaking");
         self.speaking.store(false, Ordering::SeqCst);
+        if let Some(echo) = &self.echo {
+            echo.finished(text, occurred_at);
+        }
         self.queue_sensation(
             Sensation::FinishedSpeaking {
                 text: text.to_string(),
@@ -24,47 +24,64 @@
 //! - [`main.rs`]: Pete’s entry point and lifecycle wiring
 //! - [`psyche_factory.rs`]: Assembles the cognitive architecture (Wits, Topics,
 //!   Memory)
-//! - [`tts_mouth.rs`]: TTS backend for generating speech (e.g., via Coqui)
+//! - [`codec.rs`]: Decodes Opus browser audio (WebM or Ogg) and resamples it
+//! - [`diarize.rs`]: Splits long audio into speaker turns
+//! - [`vad.rs`]: Voice activity detection shared by every audio path
+//! - [`tts.rs`]: Pluggable TTS backends (Coqui, Piper, OpenAI-compatible or a
+//!   local command) for generating speech
 //! - [`voice.rs`]: The inner voice agent that turns intention into words
 //!
 //! Pete is not just a chatbot. It is a cognitive agent with evolving memory,
 //! emotion, and embodied presence. This crate provides the scaffolding and
 //! external limbs through which that mind interfaces with the world.
 
+pub mod codec;
+pub mod diarize;
 mod ear;
 mod event_bus;
 mod face_ipc;
+mod llm_proxy;
 mod logging;
 mod motor;
 mod mouth;
 pub mod movie;
 mod ollama;
+mod pipeline;
 mod psyche_factory;
 mod sensor;
+#[cfg(feature = "simulation")]
+mod simulation;
 mod simulator;
 mod tts;
+pub mod vad;
 mod web;
 
 #[cfg(feature = "asr")]
 mod asr;
 #[cfg(feature = "asr")]
 pub use asr::{
-    AsrService, AsrTranscript, ClipTranscription, HIGH_QUALITY_MULTILINGUAL_MODEL_PATH,
-    MultiClipTranscription, SegmentMessage, SourceClipSpan, WordTiming,
+    AsrInterim, AsrService, AsrTranscript, ClipTranscription, HIGH_QUALITY_MULTILINGUAL_MODEL_PATH,
+    MultiClipTranscription, SegmentMessage, SourceClipSpan,
 };
+pub use diarize::WordTiming;
 #[cfg(feature = "ear")]
 pub use ear::ChannelEar;
 pub use ear::NoopEar;
 pub use event_bus::EventBus;
 pub use face_ipc::MediaEvent;
+pub use llm_proxy::llm_proxy_router;
 pub use logging::init_logging;
 pub use motor::LoggingMotor;
 pub use mouth::{ChannelMouth, NoopMouth};
-pub use ollama::ollama_provider_from_args;
+pub use ollama::{ollama_provider_from_args, scheduled_ollama_provider};
+pub use pipeline::{
+    ModelProfile, PipelineConfig, PipelineEdge, PipelineGraph, PipelineNode, WitKind, WitSpec,
+    ollama_doers,
+};
 #[cfg(feature = "face")]
 pub use psyche::FaceSensor;
 #[cfg(feature = "tts")]
-pub use psyche::traits::{Tts, TtsStream};
+pub use psyche::traits::{Tts, TtsAudio, TtsRequest, TtsStream, TtsVoice};
 pub use psyche_factory::{dummy_psyche, ollama_psyche};
 pub use sensor::NoopSensor;
 #[cfg(feature = "eye")]
@@ -74,11 +91,19 @@ pub use sensor::geo::GeoSensor;
 pub use sensor::heartbeat::HeartbeatSensor;
 #[cfg(feature = "motion")]
 pub use sensor::motion::MotionSensor;
+#[cfg(feature = "simulation")]
+pub use simulation::{
+    DEFAULT_SIMULATION_TICK, InMemoryGraph, Scenario, ScenarioEvent, ScenarioStep, ScriptedLlm,
+    SimulatedSensation, SimulationHarness, SimulationTrace,
+};
 pub use simulator::Simulator;
-pub use tts::default_mouth;
 #[cfg(feature = "tts")]
-pub use tts::{CoquiTts, TtsMouth, speech_text_for_tts, synthesize_speech_audio};
+pub use tts::{
+    CommandTts, CoquiTts, OpenAiTts, PiperTts, SpokenAudio, TtsMouth, speech_text_for_tts,
+    synthesize_speech_audio, synthesize_speech_audio_with_prosody, synthesize_speech_with_visemes,
+};
+pub use tts::{TtsBackend, TtsConfig, default_mouth};
 pub use web::{
     Body, WsRequest, app, conversation_log, index, listen_user_input, log_ws_handler,
-    parse_data_url, psyche_debug, toggle_wit_debug, wit_debug_page, ws_handler,
+    parse_data_url, pipeline_graph, psyche_debug, toggle_wit_debug, wit_debug_page, ws_handler,
 };
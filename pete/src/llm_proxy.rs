@@ -0,0 +1,104 @@
+//! Scheduling proxy in front of a shared Ollama host.
+//!
+//! Each Pete binary owns its own [`LlmScheduler`], which only orders that
+//! process's requests. Pointing every `*_HOST` at one proxy gives the model
+//! host a single queue: the proxy reads each request's class from the
+//! [`PRIORITY_HEADER`], waits for a permit on its scheduler and forwards the
+//! request, holding the permit until the response body has streamed back.
+
+use axum::{
+    Router,
+    body::Body,
+    extract::{Request, State},
+    http::StatusCode,
+    response::Response,
+};
+use futures::StreamExt;
+use lingproc::{LlmScheduler, PRIORITY_HEADER, Preempted, Priority};
+use tracing::{debug, warn};
+
+/// Headers describing a single hop, which the proxy never forwards.
+const HOP_HEADERS: [&str; 4] = ["host", "connection", "content-length", "transfer-encoding"];
+
+#[derive(Clone)]
+struct ProxyState {
+    upstream: String,
+    scheduler: LlmScheduler,
+    client: reqwest::Client,
+}
+
+/// Build a router forwarding every request to `upstream` through `scheduler`.
+///
+/// Requests without a recognised [`PRIORITY_HEADER`] queue as
+/// [`Priority::Background`]. Requests dropped from the queue are answered
+/// with `503 Service Unavailable`; upstream failures with `502 Bad Gateway`.
+pub fn llm_proxy_router(upstream: impl Into<String>, scheduler: LlmScheduler) -> Router {
+    let state = ProxyState {
+        upstream: upstream.into().trim_end_matches('/').to_string(),
+        scheduler,
+        client: reqwest::Client::new(),
+    };
+    Router::new().fallback(forward).with_state(state)
+}
+
+async fn forward(State(state): State<ProxyState>, request: Request) -> Response {
+    match proxy(&state, request).await {
+        Ok(response) => response,
+        Err(e) => {
+            let status = if e.downcast_ref::<Preempted>().is_some() {
+                StatusCode::SERVICE_UNAVAILABLE
+            } else {
+                StatusCode::BAD_GATEWAY
+            };
+            warn!(error = %format!("{e:#}"), %status, "LLM proxy request failed");
+            let mut response = Response::new(Body::from(format!("{e:#}")));
+            *response.status_mut() = status;
+            response
+        }
+    }
+}
+
+async fn proxy(state: &ProxyState, request: Request) -> anyhow::Result<Response> {
+    let (parts, body) = request.into_parts();
+    let priority = parts
+        .headers
+        .get(PRIORITY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(Priority::Background);
+    let body = axum::body::to_bytes(body, usize::MAX).await?;
+    let permit = state.scheduler.acquire(&state.upstream, priority).await?;
+    let path = parts
+        .uri
+        .path_and_query()
+        .map(|path| path.as_str())
+        .unwrap_or("/");
+    debug!(priority = priority.as_str(), path, "forwarding LLM request");
+
+    let method = reqwest::Method::from_bytes(parts.method.as_str().as_bytes())?;
+    let mut upstream = state
+        .client
+        .request(method, format!("{}{path}", state.upstream));
+    for (name, value) in &parts.headers {
+        if HOP_HEADERS.contains(&name.as_str()) || name.as_str() == PRIORITY_HEADER {
+            continue;
+        }
+        upstream = upstream.header(name.as_str(), value.as_bytes());
+    }
+    let reply = upstream.body(body.to_vec()).send().await?;
+
+    let mut response = Response::builder().status(reply.status().as_u16());
+    for (name, value) in reply.headers() {
+        if HOP_HEADERS.contains(&name.as_str()) {
+            continue;
+        }
+        response = response.header(name.as_str(), value.as_bytes());
+    }
+    // The permit rides along with the stream so the slot stays taken until
+    // the last chunk of a streamed chat reply has been sent.
+    let stream = reply.bytes_stream().map(move |chunk| {
+        let _ = &permit;
+        chunk
+    });
+    Ok(response.body(Body::from_stream(stream))?)
+}
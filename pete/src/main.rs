@@ -1,6 +1,7 @@
 use axum_server::tls_rustls::RustlsConfig;
 use clap::Parser;
 use dotenvy::dotenv;
+use lingproc::Priority;
 #[cfg(feature = "ear")]
 use pete::ChannelEar;
 #[cfg(feature = "eye")]
@@ -14,19 +15,29 @@ use pete::HeartbeatSensor;
 use pete::MotionSensor;
 #[cfg(any(not(feature = "eye"), not(feature = "geo"), not(feature = "motion")))]
 use pete::NoopSensor;
-use pete::{Body, LoggingMotor, NoopEar, NoopMouth, app, init_logging, listen_user_input};
+use pete::{
+    Body, LoggingMotor, NoopEar, NoopMouth, PipelineConfig, app, init_logging, listen_user_input,
+    ollama_doers,
+};
+use pete::{TtsBackend, TtsConfig, default_mouth};
 // helper for building Ollama providers
-use pete::default_mouth;
-use pete::ollama_provider_from_args;
-use psyche::{BrowserMotion, Ear, GeoLoc, ImageData, Mouth, Sensor, TrimMouth};
+use pete::scheduled_ollama_provider;
+use psyche::{
+    AddresseeConfig, AddresseeDetector, BlobConfig, BrowserMotion, ChatterAddresseeJudge, Ear,
+    GeoLoc, ImageData, Mouth, ProsodyMap, Sensor, Shutdown, TrimMouth,
+};
+#[cfg(feature = "ear")]
+use psyche::{EchoConfig, EchoFilter};
 use std::{
     net::SocketAddr,
+    path::PathBuf,
     sync::{
         Arc, Mutex,
         atomic::{AtomicBool, AtomicUsize},
     },
+    time::Duration,
 };
-use tracing::info;
+use tracing::{info, warn};
 
 #[derive(Parser)]
 #[command(author, version, about)]
@@ -56,19 +67,37 @@ struct Cli {
     /// Model name to use for embeddings
     #[arg(long, env = "EMBEDDINGS_MODEL", default_value = "embeddinggemma")]
     embeddings_model: String,
-    /// URL of the Coqui TTS server
-    #[arg(
-        long,
-        env = "COQUI_URL",
-        default_value = "http://localhost:5002/api/tts"
-    )]
-    tts_url: String,
+    /// TTS backend: coqui, piper, openai or command
+    #[arg(long, env = "TTS_BACKEND", default_value = "coqui")]
+    tts_backend: TtsBackend,
+    /// URL of the TTS server (Coqui, Piper root or OpenAI-compatible speech
+    /// endpoint); defaults to the backend's usual address
+    #[arg(long, env = "TTS_URL")]
+    tts_url: Option<String>,
     /// Speaker ID for the TTS voice
     #[arg(long, env = "SPEAKER", default_value = "p228")]
     tts_speaker_id: String,
     /// Language ID for the TTS voice
     #[arg(long, default_value = "en")]
     tts_language_id: String,
+    /// Model name for an OpenAI-compatible TTS server
+    #[arg(long, env = "TTS_MODEL")]
+    tts_model: Option<String>,
+    /// Bearer token for an OpenAI-compatible TTS server
+    #[arg(long, env = "TTS_API_KEY")]
+    tts_api_key: Option<String>,
+    /// Command that reads text on stdin and writes WAV to stdout
+    #[arg(long, env = "TTS_COMMAND")]
+    tts_command: Option<String>,
+    /// Comma-separated voices the TTS command offers
+    #[arg(long, env = "TTS_VOICES", value_delimiter = ',')]
+    tts_voices: Vec<String>,
+    /// JSON file mapping emotions to speaking rate, pitch, volume and voice
+    #[arg(long, env = "TTS_PROSODY")]
+    tts_prosody: Option<PathBuf>,
+    /// Print the voices offered by the TTS backend and exit
+    #[arg(long)]
+    list_tts_voices: bool,
     /// Path to TLS certificate in PEM format
     #[arg(long)]
     tls_cert: Option<String>,
@@ -81,6 +110,15 @@ struct Cli {
     /// Disable the fallback <take_turn> when no Wit suggests one
     #[arg(long)]
     no_fallback_turn: bool,
+    /// Only respond to speech judged to be directed at Pete (tuned by ADDRESSEE_*)
+    #[arg(long, env = "PETE_ONLY_WHEN_ADDRESSED")]
+    only_when_addressed: bool,
+    /// Ask the chatter model about speech whose addressee is unclear
+    #[arg(long, env = "ADDRESSEE_JUDGE")]
+    addressee_judge: bool,
+    /// Keep transcripts of Pete's own voice from the microphone (tuned by ECHO_*)
+    #[arg(long, env = "PETE_NO_ECHO_FILTER")]
+    no_echo_filter: bool,
     /// URL of the Qdrant service
     #[arg(long, env = "QDRANT_URL", default_value = "http://localhost:6333")]
     qdrant_url: String,
@@ -93,6 +131,53 @@ struct Cli {
     /// Neo4j password
     #[arg(long, env = "NEO4J_PASS", default_value = "password")]
     neo4j_pass: String,
+    /// JSON file describing which wits run and how they are wired
+    #[arg(long, env = "PETE_PIPELINE")]
+    pipeline: Option<PathBuf>,
+    /// File used to save state on shutdown and resume it on start
+    #[arg(long, env = "PSYCHE_CHECKPOINT")]
+    checkpoint: Option<PathBuf>,
+}
+
+/// How long open HTTPS connections may take to finish after shutdown.
+const TLS_SHUTDOWN_GRACE: Duration = Duration::from_secs(10);
+
+/// Trigger `shutdown` on Ctrl-C or SIGTERM.
+fn spawn_signal_handler(shutdown: Shutdown) {
+    tokio::spawn(async move {
+        let ctrl_c = tokio::signal::ctrl_c();
+        #[cfg(unix)]
+        {
+            let mut term =
+                tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                    .expect("install SIGTERM handler");
+            tokio::select! {
+                _ = ctrl_c => {}
+                _ = term.recv() => {}
+            }
+        }
+        #[cfg(not(unix))]
+        let _ = ctrl_c.await;
+        info!("shutdown requested");
+        shutdown.trigger();
+    });
+}
+
+/// Print the voices offered by the configured TTS backend, one per line.
+#[cfg(feature = "tts")]
+async fn list_tts_voices(config: &TtsConfig) -> anyhow::Result<()> {
+    for voice in config.build()?.voices().await? {
+        match voice.language {
+            Some(language) => println!("{}\t{language}", voice.id),
+            None => println!("{}", voice.id),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "tts"))]
+async fn list_tts_voices(_config: &TtsConfig) -> anyhow::Result<()> {
+    anyhow::bail!("pete was built without the `tts` feature")
 }
 
 #[tokio::main(flavor = "multi_thread")]
@@ -102,27 +187,60 @@ async fn main() -> anyhow::Result<()> {
     init_logging(bus.log_sender());
     dotenv().ok();
     let cli = Cli::parse();
+    let tts_config = TtsConfig {
+        backend: cli.tts_backend,
+        url: cli
+            .tts_url
+            .clone()
+            .unwrap_or_else(|| cli.tts_backend.default_url().into()),
+        voice: Some(cli.tts_speaker_id.clone()),
+        language: Some(cli.tts_language_id.clone()),
+        model: cli.tts_model.clone(),
+        api_key: cli.tts_api_key.clone(),
+        command: cli.tts_command.clone(),
+        voices: cli.tts_voices.clone(),
+        prosody: match &cli.tts_prosody {
+            Some(path) => ProsodyMap::load(path)?,
+            None => ProsodyMap::default(),
+        },
+    };
+    if cli.list_tts_voices {
+        return list_tts_voices(&tts_config).await;
+    }
 
     info!(%cli.addr, "starting server");
 
-    use psyche::wits::{
-        BasicMemory, Combobulator, FaceMemoryWit, FondDuCoeur, HeartWit, IdentityWit, MemoryWit,
-        Neo4jClient, QdrantClient, Quick, SensationGraphObserver, VoiceMemoryWit, Will,
-    };
+    use psyche::wits::{BasicMemory, Neo4jClient, QdrantClient, SensationGraphObserver};
 
-    let narrator = ollama_provider_from_args(&cli.chatter_host, &cli.chatter_model)?;
-    let voice_provider = ollama_provider_from_args(&cli.chatter_host, &cli.chatter_model)?;
-    let vectorizer = ollama_provider_from_args(&cli.embeddings_host, &cli.embeddings_model)?;
+    let narrator = scheduled_ollama_provider(
+        &cli.chatter_host,
+        &cli.chatter_model,
+        Priority::Conversation,
+    )?;
+    let voice_provider = scheduled_ollama_provider(
+        &cli.chatter_host,
+        &cli.chatter_model,
+        Priority::Conversation,
+    )?;
+    let vectorizer = scheduled_ollama_provider(
+        &cli.embeddings_host,
+        &cli.embeddings_model,
+        Priority::Background,
+    )?;
 
-    let graph_store = Arc::new(Neo4jClient::new(
-        cli.neo4j_uri.clone(),
-        cli.neo4j_user.clone(),
-        cli.neo4j_pass.clone(),
-    ));
+    let graph_store = Arc::new(
+        Neo4jClient::new(
+            cli.neo4j_uri.clone(),
+            cli.neo4j_user.clone(),
+            cli.neo4j_pass.clone(),
+        )
+        .with_blob_store(BlobConfig::from_env()?.open()),
+    );
     let memory = Arc::new(BasicMemory {
-        vectorizer: Arc::new(ollama_provider_from_args(
+        vectorizer: Arc::new(scheduled_ollama_provider(
             &cli.embeddings_host,
             &cli.embeddings_model,
+            Priority::Background,
         )?),
         qdrant: QdrantClient::new(cli.qdrant_url.clone()),
         neo4j: graph_store.clone(),
@@ -139,62 +257,64 @@ async fn main() -> anyhow::Result<()> {
         ear_placeholder,
     );
     psyche.set_turn_limit(usize::MAX);
+    if let Some(path) = &cli.checkpoint {
+        psyche.set_checkpoint_path(path);
+    }
+    let shutdown = psyche.shutdown_handle();
+    spawn_signal_handler(shutdown.clone());
     psyche
         .voice()
         .set_prompt(psyche::ContextualPrompt::new(psyche.topic_bus()));
 
-    let wit_tx = psyche.wit_sender();
     let latest_image = Arc::new(Mutex::new(None));
-    let graph_observer = Arc::new(SensationGraphObserver::new(graph_store));
+    #[cfg(feature = "face")]
+    let face_detector: Arc<dyn psyche::FaceDetector> =
+        Arc::new(psyche::FaceIdDetector::from_hf().await?);
+    // Opted-out faces, and in strict mode every unnamed face, are kept out
+    // of both stored frames and the face sensor's crops and vectors. Unless
+    // PRIVACY_STRICT is on, the redactor stays idle while nobody is opted out.
+    #[cfg(feature = "face")]
+    let face_redactor = Arc::new(psyche::FaceRedactor::new(
+        face_detector.clone(),
+        graph_store.clone(),
+        psyche::QdrantClient::new(cli.qdrant_url.clone()),
+        psyche::PrivacyConfig::from_env()?,
+    ));
+    let graph_observer = SensationGraphObserver::new(graph_store.clone());
+    #[cfg(feature = "face")]
+    let graph_observer = graph_observer.with_image_redactor(face_redactor.clone());
+    let graph_observer = Arc::new(graph_observer);
     psyche.register_observer(graph_observer.clone());
     graph_observer.spawn_topic_listener(psyche.topic_bus());
-    psyche.register_observing_wit(Arc::new(FaceMemoryWit::with_debug(wit_tx.clone())));
-    psyche.register_observing_wit(Arc::new(VoiceMemoryWit::with_debug(wit_tx.clone())));
-    psyche.register_observing_wit(Arc::new(Quick::with_debug(
-        psyche.topic_bus(),
-        Arc::new(ollama_provider_from_args(&cli.wits_host, &cli.wits_model)?),
-        Some(wit_tx.clone()),
-    )));
-    psyche.register_typed_wit(Arc::new(
-        Combobulator::with_bus_and_debug(
-            psyche.topic_bus(),
-            Arc::new(ollama_provider_from_args(&cli.wits_host, &cli.wits_model)?),
-            Some(wit_tx.clone()),
-        )
-        .with_events(psyche.event_sender()),
-    ));
-    psyche.register_typed_wit(Arc::new(Will::with_debug(
-        psyche.topic_bus(),
-        Arc::new(ollama_provider_from_args(&cli.wits_host, &cli.wits_model)?),
-        Some(wit_tx.clone()),
-    )));
-    psyche.register_typed_wit(Arc::new(MemoryWit::with_debug(
+    let pipeline = match &cli.pipeline {
+        Some(path) => PipelineConfig::load(path)?,
+        None => PipelineConfig::default(),
+    };
+    pipeline.build(
+        &mut psyche,
         memory.clone(),
-        wit_tx.clone(),
-    )));
-    psyche.register_typed_wit(Arc::new(HeartWit::with_debug(
-        Box::new(ollama_provider_from_args(&cli.wits_host, &cli.wits_model)?),
         Arc::new(LoggingMotor),
-        wit_tx.clone(),
-    )));
-    psyche.register_typed_wit(Arc::new(IdentityWit::new(FondDuCoeur::with_debug(
-        Box::new(ollama_provider_from_args(&cli.wits_host, &cli.wits_model)?),
-        wit_tx.clone(),
-    ))));
+        ollama_doers(&cli.wits_host, &cli.wits_model),
+    )?;
+    pipeline.enable_debug().await;
+    let pipeline_graph = Arc::new(pipeline.graph());
     for w in psyche.debug_handle().snapshot().await.active_wits {
         tracing::debug!(%w, "registered wit");
     }
-    psyche.enable_all_debug().await;
     psyche.set_fallback_turn_enabled(!cli.no_fallback_turn);
+    if cli.only_when_addressed {
+        let mut detector = AddresseeDetector::new(AddresseeConfig::from_env()?);
+        if cli.addressee_judge {
+            detector = detector.with_judge(Arc::new(ChatterAddresseeJudge::new(Arc::new(
+                voice_provider.clone(),
+            ))));
+        }
+        psyche.set_addressee_detector(detector);
+        psyche.set_speak_when_spoken_to(true);
+    }
     let speaking = Arc::new(AtomicBool::new(false));
     let connections = Arc::new(AtomicUsize::new(0));
-    let base_mouth: Arc<dyn Mouth> = default_mouth(
-        bus.clone(),
-        speaking.clone(),
-        cli.tts_url,
-        Some(cli.tts_speaker_id.clone()),
-        Some(cli.tts_language_id.clone()),
-    );
+    let base_mouth: Arc<dyn Mouth> = default_mouth(bus.clone(), speaking.clone(), &tts_config)?;
     let mouth = Arc::new(TrimMouth::new(base_mouth)) as Arc<dyn Mouth>;
     psyche.set_mouth(mouth.clone());
     psyche.set_emotion("😐");
@@ -204,11 +324,25 @@ async fn main() -> anyhow::Result<()> {
     #[cfg(feature = "ear")]
     let ear: Arc<dyn Ear> = {
         psyche.add_sense(ChannelEar::DESCRIPTION.into());
-        Arc::new(ChannelEar::new(
-            psyche.input_sender(),
-            speaking.clone(),
-            voice.clone(),
-        )) as Arc<dyn Ear>
+        let mut ear = ChannelEar::new(psyche.input_sender(), speaking.clone(), voice.clone());
+        if !cli.no_echo_filter {
+            let echo = Arc::new(EchoFilter::new(EchoConfig::from_env()?));
+            ear = ear.with_echo_filter(echo.clone());
+            // Lines leave the TTS before the browser reports playing them.
+            let mut events = bus.subscribe_events();
+            tokio::spawn(async move {
+                loop {
+                    match events.recv().await {
+                        Ok(psyche::Event::Speech { text, .. }) => {
+                            echo.queued(&text, chrono::Utc::now());
+                        }
+                        Ok(_) | Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                    }
+                }
+            });
+        }
+        Arc::new(ear) as Arc<dyn Ear>
     };
     #[cfg(not(feature = "ear"))]
     let ear: Arc<dyn Ear> = Arc::new(NoopEar) as Arc<dyn Ear>;
@@ -228,11 +362,14 @@ async fn main() -> anyhow::Result<()> {
     let eye: Arc<dyn Sensor<ImageData>> = Arc::new(NoopSensor) as Arc<dyn Sensor<ImageData>>;
 
     #[cfg(feature = "face")]
-    let face_sensor = Arc::new(FaceSensor::new(
-        Arc::new(psyche::FaceIdDetector::from_hf().await?),
-        psyche::QdrantClient::new(cli.qdrant_url.clone()),
-        psyche.topic_bus(),
-    ));
+    let face_sensor = Arc::new(
+        FaceSensor::new(
+            face_detector.clone(),
+            psyche::QdrantClient::new(cli.qdrant_url.clone()),
+            psyche.topic_bus(),
+        )
+        .with_consent(face_redactor.clone()),
+    );
     #[cfg(feature = "face")]
     {
         psyche.add_sense(face_sensor.describe().into());
@@ -342,7 +479,23 @@ async fn main() -> anyhow::Result<()> {
         }
         asr.map(Arc::new)
     };
-    tokio::spawn(async move {
+    #[cfg(feature = "asr")]
+    if let Some(service) = asr.as_ref() {
+        let mut onsets = service.subscribe_speech_onsets();
+        let barge_in = psyche.barge_in();
+        tokio::spawn(async move {
+            loop {
+                match onsets.recv().await {
+                    Ok(at) => {
+                        barge_in.user_speech_onset(at).await;
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+    let psyche_task = tokio::spawn(async move {
         psyche.run().await;
     });
 
@@ -358,6 +511,7 @@ async fn main() -> anyhow::Result<()> {
         connections,
         system_prompt: Arc::new(tokio::sync::Mutex::new(system_prompt)),
         psyche_debug: debug_handle,
+        pipeline: pipeline_graph,
     };
     let app = app(state);
 
@@ -365,12 +519,29 @@ async fn main() -> anyhow::Result<()> {
     info!(%addr, "listening");
     if let (Some(cert), Some(key)) = (cli.tls_cert.as_deref(), cli.tls_key.as_deref()) {
         let config = RustlsConfig::from_pem_file(cert, key).await?;
+        // Stop accepting connections on shutdown but give open requests time
+        // to finish, like `with_graceful_shutdown` below.
+        let handle = axum_server::Handle::new();
+        let signal = shutdown.clone();
+        let on_shutdown = handle.clone();
+        tokio::spawn(async move {
+            signal.cancelled().await;
+            on_shutdown.graceful_shutdown(Some(TLS_SHUTDOWN_GRACE));
+        });
         axum_server::bind_rustls(addr, config)
+            .handle(handle)
             .serve(app.into_make_service())
             .await?;
     } else {
         let listener = tokio::net::TcpListener::bind(addr).await?;
-        axum::serve(listener, app.into_make_service()).await?;
+        let signal = shutdown.clone();
+        axum::serve(listener, app.into_make_service())
+            .with_graceful_shutdown(async move { signal.cancelled().await })
+            .await?;
+    }
+    shutdown.trigger();
+    if let Err(e) = psyche_task.await {
+        warn!(?e, "psyche task failed during shutdown");
     }
     Ok(())
 }
@@ -37,6 +37,7 @@ impl Mouth for ChannelMouth {
                 self.bus.publish_event(Event::Speech {
                     text: sent.to_string(),
                     audio: None,
+                    visemes: None,
                 });
             }
         }
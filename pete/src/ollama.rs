@@ -1,4 +1,4 @@
-use lingproc::OllamaProvider;
+use lingproc::{OllamaProvider, Priority, Scheduled};
 
 /// Build an [`OllamaProvider`] from command line arguments.
 ///
@@ -21,3 +21,32 @@ use lingproc::OllamaProvider;
 pub fn ollama_provider_from_args(host: &str, model: &str) -> anyhow::Result<OllamaProvider> {
     Ok(OllamaProvider::new_with_defaults(Some(host), Some(model))?)
 }
+
+/// Build an [`OllamaProvider`] whose requests queue on the process-wide
+/// [`LlmScheduler`](lingproc::LlmScheduler) at `priority`.
+///
+/// Providers sharing a `host` share its concurrency limit, so background
+/// enrichment waits behind conversation and Will requests. Requests also
+/// carry `priority` in the [`PRIORITY_HEADER`](lingproc::PRIORITY_HEADER), so
+/// when `host` is the `llm_proxy` binary separate processes queue together.
+///
+/// ```
+/// use lingproc::Priority;
+/// use pete::scheduled_ollama_provider;
+///
+/// let provider =
+///     scheduled_ollama_provider("http://localhost:11434", "gpt-oss", Priority::Conversation)
+///         .expect("valid provider");
+/// assert_eq!(provider.priority(), Priority::Conversation);
+/// ```
+pub fn scheduled_ollama_provider(
+    host: &str,
+    model: &str,
+    priority: Priority,
+) -> anyhow::Result<Scheduled<OllamaProvider>> {
+    Ok(Scheduled::new(
+        ollama_provider_from_args(host, model)?.with_priority_header(priority),
+        host,
+        priority,
+    ))
+}
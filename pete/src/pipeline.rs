@@ -0,0 +1,573 @@
+//! Declarative description of which Wits run and how they are wired.
+//!
+//! A [`PipelineConfig`] lists the Wits to register, the model profile each
+//! one uses, its tick interval and whether debug reports start enabled. The
+//! [`Topic`]s a Wit subscribes to and publishes on are fixed by its kind
+//! ([`WitKind::subscribes`], [`WitKind::publishes`]); the pipeline only
+//! chooses which Wits take part, so a spec cannot rewire them. The host loads
+//! it at startup, [`validate`](PipelineConfig::validate)s it and then
+//! [`build`](PipelineConfig::build)s the Wits into a [`Psyche`]. The resulting
+//! [`PipelineGraph`] is served at `/debug/pipeline`.
+//!
+//! ```
+//! use pete::PipelineConfig;
+//!
+//! let config = PipelineConfig::from_json(
+//!     r#"{
+//!         "profiles": { "fast": { "model": "gemma3", "priority": "combobulation" } },
+//!         "wits": [
+//!             { "kind": "quick", "profile": "fast", "tick_ms": 2000 },
+//!             { "kind": "combobulator" },
+//!             { "kind": "situation" }
+//!         ]
+//!     }"#,
+//! )
+//! .unwrap();
+//! config.validate().unwrap();
+//! assert_eq!(config.graph().edges.len(), 3);
+//! ```
+
+use anyhow::{Context, Result, bail};
+use lingproc::{Doer, Priority};
+use psyche::wits::{
+    Combobulator, EpisodeWit, FaceMemoryWit, FondDuCoeur, HeartWit, IdentityWit, MemoryWit,
+    MomentWit, Quick, SituationWit, VisionWit, VoiceMemoryWit, Will,
+};
+use psyche::{Memory, Motor, Psyche, SensationObserver, Topic, Wit};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Wits that can be named in a pipeline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WitKind {
+    Quick,
+    Combobulator,
+    Moment,
+    Situation,
+    Episode,
+    Will,
+    Memory,
+    Heart,
+    Identity,
+    Vision,
+    FaceMemory,
+    VoiceMemory,
+}
+
+impl WitKind {
+    /// Debug label of the Wit this kind builds.
+    pub fn label(self) -> &'static str {
+        match self {
+            WitKind::Quick => Quick::LABEL,
+            WitKind::Combobulator => Combobulator::LABEL,
+            WitKind::Moment => MomentWit::LABEL,
+            WitKind::Situation => SituationWit::LABEL,
+            WitKind::Episode => EpisodeWit::LABEL,
+            WitKind::Will => Will::LABEL,
+            WitKind::Memory => MemoryWit::LABEL,
+            WitKind::Heart => HeartWit::LABEL,
+            WitKind::Identity => IdentityWit::LABEL,
+            WitKind::Vision => VisionWit::LABEL,
+            WitKind::FaceMemory => FaceMemoryWit::LABEL,
+            WitKind::VoiceMemory => VoiceMemoryWit::LABEL,
+        }
+    }
+
+    /// Topics the Wit listens to on the [`TopicBus`](psyche::TopicBus).
+    pub fn subscribes(self) -> Vec<Topic> {
+        match self {
+            WitKind::Quick => vec![Topic::Sensation],
+            WitKind::Combobulator | WitKind::Moment => vec![Topic::Instant],
+            WitKind::Situation => vec![Topic::Moment],
+            WitKind::Episode => vec![Topic::Situation, Topic::Instruction],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Topics the Wit publishes on the [`TopicBus`](psyche::TopicBus).
+    pub fn publishes(self) -> Vec<Topic> {
+        match self {
+            WitKind::Quick => vec![Topic::Instant],
+            WitKind::Combobulator | WitKind::Moment => vec![Topic::Moment],
+            WitKind::Situation => vec![Topic::Situation],
+            WitKind::Episode => vec![Topic::Episode],
+            WitKind::Will => vec![Topic::Instruction],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Profile used when the spec does not name one.
+    pub fn default_profile(self) -> &'static str {
+        match self {
+            WitKind::Quick | WitKind::Combobulator => "combobulation",
+            WitKind::Will => "will",
+            _ => "background",
+        }
+    }
+
+    /// Whether the Wit needs a language model.
+    pub fn uses_model(self) -> bool {
+        !matches!(
+            self,
+            WitKind::Memory | WitKind::FaceMemory | WitKind::VoiceMemory
+        )
+    }
+}
+
+/// Language model settings shared by one or more Wits.
+///
+/// `host` and `model` fall back to the host's `--wits-host` and
+/// `--wits-model` when omitted.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct ModelProfile {
+    #[serde(default)]
+    pub host: Option<String>,
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(
+        default = "background_priority",
+        deserialize_with = "priority_from_str"
+    )]
+    pub priority: Priority,
+}
+
+impl ModelProfile {
+    /// Profile on the default wits host with `priority`.
+    pub fn with_priority(priority: Priority) -> Self {
+        Self {
+            host: None,
+            model: None,
+            priority,
+        }
+    }
+}
+
+fn background_priority() -> Priority {
+    Priority::Background
+}
+
+fn priority_from_str<'de, D: Deserializer<'de>>(de: D) -> Result<Priority, D::Error> {
+    let s = String::deserialize(de)?;
+    s.parse().map_err(serde::de::Error::custom)
+}
+
+/// One Wit entry in a [`PipelineConfig`].
+///
+/// Unknown fields are rejected, including `subscribes` and `publishes`:
+/// topics come from the [`WitKind`].
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WitSpec {
+    pub kind: WitKind,
+    /// Node name in the graph; defaults to the Wit's debug label.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Key into [`PipelineConfig::profiles`].
+    #[serde(default)]
+    pub profile: Option<String>,
+    /// Idle tick interval; defaults to the psyche's experience tick.
+    #[serde(default)]
+    pub tick_ms: Option<u64>,
+    /// Enable debug reports for this Wit at startup.
+    #[serde(default = "default_true")]
+    pub debug: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl WitSpec {
+    /// Spec for `kind` with every field at its default.
+    pub fn new(kind: WitKind) -> Self {
+        Self {
+            kind,
+            name: None,
+            profile: None,
+            tick_ms: None,
+            debug: true,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(self.kind.label())
+    }
+
+    pub fn profile(&self) -> &str {
+        self.profile
+            .as_deref()
+            .unwrap_or(self.kind.default_profile())
+    }
+}
+
+/// Startup description of Pete's Wits.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineConfig {
+    /// Named model profiles. `combobulation`, `will` and `background` are
+    /// always available and may be overridden.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, ModelProfile>,
+    /// Topics fed from outside the Wits, such as sensors.
+    #[serde(default = "default_sources")]
+    pub sources: Vec<Topic>,
+    pub wits: Vec<WitSpec>,
+}
+
+fn default_sources() -> Vec<Topic> {
+    vec![Topic::Sensation, Topic::FaceInfo]
+}
+
+impl Default for PipelineConfig {
+    /// The Wits Pete runs when no pipeline file is given.
+    fn default() -> Self {
+        Self::with_wits([
+            WitKind::FaceMemory,
+            WitKind::VoiceMemory,
+            WitKind::Quick,
+            WitKind::Combobulator,
+            WitKind::Will,
+            WitKind::Memory,
+            WitKind::Heart,
+            WitKind::Identity,
+        ])
+    }
+}
+
+impl PipelineConfig {
+    /// Pipeline running `kinds` in order with default specs and profiles.
+    pub fn with_wits(kinds: impl IntoIterator<Item = WitKind>) -> Self {
+        Self {
+            profiles: BTreeMap::new(),
+            sources: default_sources(),
+            wits: kinds.into_iter().map(WitSpec::new).collect(),
+        }
+    }
+
+    /// Parse a pipeline from JSON.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).context("invalid pipeline config")
+    }
+
+    /// Read a pipeline from a JSON file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read pipeline {}", path.display()))?;
+        Self::from_json(&json).with_context(|| format!("in {}", path.display()))
+    }
+
+    /// Look up `name`, falling back to the built-in profiles.
+    pub fn profile(&self, name: &str) -> Option<ModelProfile> {
+        if let Some(p) = self.profiles.get(name) {
+            return Some(p.clone());
+        }
+        match name {
+            "combobulation" => Some(ModelProfile::with_priority(Priority::Combobulation)),
+            "will" => Some(ModelProfile::with_priority(Priority::Will)),
+            "background" => Some(ModelProfile::with_priority(Priority::Background)),
+            _ => None,
+        }
+    }
+
+    /// Check names, profiles and that every subscribed topic has a producer.
+    pub fn validate(&self) -> Result<()> {
+        let mut problems = Vec::new();
+        let mut names = BTreeSet::new();
+        let mut produced: BTreeSet<Topic> = self.sources.iter().copied().collect();
+        for spec in &self.wits {
+            produced.extend(spec.kind.publishes());
+        }
+        for spec in &self.wits {
+            if !names.insert(spec.name()) {
+                problems.push(format!("duplicate wit name `{}`", spec.name()));
+            }
+            if spec.kind.uses_model() && self.profile(spec.profile()).is_none() {
+                problems.push(format!(
+                    "wit `{}` uses unknown profile `{}`",
+                    spec.name(),
+                    spec.profile()
+                ));
+            }
+            if spec.tick_ms == Some(0) {
+                problems.push(format!("wit `{}` has a zero tick interval", spec.name()));
+            }
+            for topic in spec.kind.subscribes() {
+                if !produced.contains(&topic) {
+                    problems.push(format!(
+                        "wit `{}` subscribes to `{topic}` but nothing publishes it",
+                        spec.name()
+                    ));
+                }
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            bail!("invalid pipeline:\n- {}", problems.join("\n- "))
+        }
+    }
+
+    /// Describe the Wits and the topics connecting them.
+    pub fn graph(&self) -> PipelineGraph {
+        let nodes: Vec<PipelineNode> = self
+            .wits
+            .iter()
+            .map(|spec| PipelineNode {
+                name: spec.name().to_string(),
+                kind: spec.kind,
+                subscribes: spec.kind.subscribes(),
+                publishes: spec.kind.publishes(),
+                profile: spec.kind.uses_model().then(|| spec.profile().to_string()),
+                tick_ms: spec.tick_ms,
+                debug: spec.debug,
+            })
+            .collect();
+        let mut edges = Vec::new();
+        for consumer in &nodes {
+            for &topic in &consumer.subscribes {
+                if self.sources.contains(&topic) {
+                    edges.push(PipelineEdge {
+                        from: format!("source:{topic}"),
+                        to: consumer.name.clone(),
+                        topic,
+                    });
+                }
+                for producer in nodes.iter().filter(|n| n.publishes.contains(&topic)) {
+                    edges.push(PipelineEdge {
+                        from: producer.name.clone(),
+                        to: consumer.name.clone(),
+                        topic,
+                    });
+                }
+            }
+        }
+        PipelineGraph {
+            sources: self.sources.clone(),
+            nodes,
+            edges,
+        }
+    }
+
+    /// Construct every Wit and register it with `psyche`.
+    ///
+    /// `make_doer` turns a resolved [`ModelProfile`] into a language model;
+    /// see [`ollama_doers`] for the one the host uses.
+    pub fn build<F>(
+        &self,
+        psyche: &mut Psyche,
+        memory: Arc<dyn Memory>,
+        motor: Arc<dyn Motor>,
+        make_doer: F,
+    ) -> Result<()>
+    where
+        F: Fn(&ModelProfile) -> Result<Box<dyn Doer>>,
+    {
+        self.validate()?;
+        let wit_tx = psyche.wit_sender();
+        let bus = psyche.topic_bus();
+        for spec in &self.wits {
+            let doer = || -> Result<Box<dyn Doer>> {
+                let profile = self
+                    .profile(spec.profile())
+                    .with_context(|| format!("unknown profile `{}`", spec.profile()))?;
+                make_doer(&profile)
+            };
+            match spec.kind {
+                WitKind::Quick => {
+                    let wit = Arc::new(Quick::with_debug(
+                        bus.clone(),
+                        Arc::from(doer()?),
+                        Some(wit_tx.clone()),
+                    ));
+                    register_observing(psyche, spec, wit);
+                }
+                WitKind::Combobulator => {
+                    let wit = Combobulator::with_bus_and_debug(
+                        bus.clone(),
+                        Arc::from(doer()?),
+                        Some(wit_tx.clone()),
+                    )
+                    .with_events(psyche.event_sender());
+                    register_typed(psyche, spec, Arc::new(wit));
+                }
+                WitKind::Moment => register_typed(
+                    psyche,
+                    spec,
+                    Arc::new(MomentWit::with_debug(
+                        bus.clone(),
+                        Arc::from(doer()?),
+                        Some(wit_tx.clone()),
+                    )),
+                ),
+                WitKind::Situation => register_typed(
+                    psyche,
+                    spec,
+                    Arc::new(SituationWit::with_debug(
+                        bus.clone(),
+                        Arc::from(doer()?),
+                        Some(wit_tx.clone()),
+                    )),
+                ),
+                WitKind::Episode => register_typed(
+                    psyche,
+                    spec,
+                    Arc::new(EpisodeWit::with_debug(
+                        bus.clone(),
+                        Arc::from(doer()?),
+                        Some(wit_tx.clone()),
+                    )),
+                ),
+                WitKind::Will => register_typed(
+                    psyche,
+                    spec,
+                    Arc::new(Will::with_debug(
+                        bus.clone(),
+                        Arc::from(doer()?),
+                        Some(wit_tx.clone()),
+                    )),
+                ),
+                WitKind::Memory => register_typed(
+                    psyche,
+                    spec,
+                    Arc::new(MemoryWit::with_debug(memory.clone(), wit_tx.clone())),
+                ),
+                WitKind::Heart => register_typed(
+                    psyche,
+                    spec,
+                    Arc::new(HeartWit::with_debug(doer()?, motor.clone(), wit_tx.clone())),
+                ),
+                WitKind::Identity => register_typed(
+                    psyche,
+                    spec,
+                    Arc::new(IdentityWit::new(FondDuCoeur::with_debug(
+                        doer()?,
+                        wit_tx.clone(),
+                    ))),
+                ),
+                WitKind::Vision => register_observing(
+                    psyche,
+                    spec,
+                    Arc::new(VisionWit::with_debug(Arc::from(doer()?), wit_tx.clone())),
+                ),
+                WitKind::FaceMemory => register_observing(
+                    psyche,
+                    spec,
+                    Arc::new(FaceMemoryWit::with_debug(wit_tx.clone())),
+                ),
+                WitKind::VoiceMemory => register_observing(
+                    psyche,
+                    spec,
+                    Arc::new(VoiceMemoryWit::with_debug(wit_tx.clone())),
+                ),
+            }
+            if let Some(ms) = spec.tick_ms {
+                psyche.set_wit_tick(spec.name(), Duration::from_millis(ms));
+            }
+        }
+        Ok(())
+    }
+
+    /// Turn on debug reports for every Wit whose spec sets `debug`.
+    pub async fn enable_debug(&self) {
+        for spec in self.wits.iter().filter(|s| s.debug) {
+            psyche::enable_debug(spec.kind.label()).await;
+        }
+    }
+}
+
+/// Register `wit`, under the spec's name when it sets one.
+fn register_typed<W>(psyche: &mut Psyche, spec: &WitSpec, wit: Arc<W>)
+where
+    W: Wit + Send + Sync + 'static,
+    W::Output: Serialize + Send + Sync + 'static,
+    W::Input: 'static,
+{
+    match &spec.name {
+        Some(name) => psyche.register_named_typed_wit(name.clone(), wit),
+        None => psyche.register_typed_wit(wit),
+    }
+}
+
+/// Register `wit` as a [`SensationObserver`] too, like [`register_typed`].
+fn register_observing<W>(psyche: &mut Psyche, spec: &WitSpec, wit: Arc<W>)
+where
+    W: Wit + SensationObserver + Send + Sync + 'static,
+    W::Output: Serialize + Send + Sync + 'static,
+    W::Input: 'static,
+{
+    match &spec.name {
+        Some(name) => psyche.register_named_observing_wit(name.clone(), wit),
+        None => psyche.register_observing_wit(wit),
+    }
+}
+
+/// Build Wit language models on Ollama, defaulting to `host` and `model`.
+pub fn ollama_doers(
+    host: &str,
+    model: &str,
+) -> impl Fn(&ModelProfile) -> Result<Box<dyn Doer>> + use<> {
+    let host = host.to_string();
+    let model = model.to_string();
+    move |profile: &ModelProfile| -> Result<Box<dyn Doer>> {
+        Ok(Box::new(crate::scheduled_ollama_provider(
+            profile.host.as_deref().unwrap_or(&host),
+            profile.model.as_deref().unwrap_or(&model),
+            profile.priority,
+        )?))
+    }
+}
+
+/// A Wit in the rendered pipeline.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PipelineNode {
+    pub name: String,
+    pub kind: WitKind,
+    pub subscribes: Vec<Topic>,
+    pub publishes: Vec<Topic>,
+    pub profile: Option<String>,
+    pub tick_ms: Option<u64>,
+    pub debug: bool,
+}
+
+/// A topic flowing from a producer to a subscriber.
+///
+/// External producers are named `source:<topic>`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PipelineEdge {
+    pub from: String,
+    pub to: String,
+    pub topic: Topic,
+}
+
+/// Wit/topic graph served by `/debug/pipeline`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct PipelineGraph {
+    pub sources: Vec<Topic>,
+    pub nodes: Vec<PipelineNode>,
+    pub edges: Vec<PipelineEdge>,
+}
+
+impl PipelineGraph {
+    /// Render the graph in Graphviz DOT format.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph pipeline {\n    rankdir=LR;\n");
+        for topic in &self.sources {
+            out.push_str(&format!("    \"source:{topic}\" [shape=box];\n"));
+        }
+        for node in &self.nodes {
+            out.push_str(&format!("    \"{}\";\n", node.name));
+        }
+        for edge in &self.edges {
+            out.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                edge.from, edge.to, edge.topic
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
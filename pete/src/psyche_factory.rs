@@ -6,7 +6,8 @@ use tracing::info;
 
 use crate::ear::NoopEar;
 use crate::mouth::NoopMouth;
-use crate::ollama_provider_from_args;
+use crate::scheduled_ollama_provider;
+use lingproc::Priority;
 use psyche::wits::Quick;
 
 /// Create a psyche with dummy providers for demos/tests.
@@ -67,7 +68,11 @@ pub fn dummy_psyche() -> Psyche {
 /// Create a psyche backed by an Ollama server.
 ///
 /// This uses [`OllamaProvider`](lingproc::OllamaProvider) for all language
-/// capabilities and the no-op ear and mouth implementations.
+/// capabilities and the no-op ear and mouth implementations. Its Wits are the
+/// vision, face memory and core reasoning Wits as a
+/// [`PipelineConfig`](crate::PipelineConfig). Requests are queued
+/// per host by [`Priority`], so conversation replies run ahead of wit and
+/// background work.
 pub fn ollama_psyche(
     chatter_host: &str,
     chatter_model: &str,
@@ -80,30 +85,28 @@ pub fn ollama_psyche(
     neo4j_user: &str,
     neo4j_pass: &str,
 ) -> anyhow::Result<Psyche> {
-    use crate::LoggingMotor;
-    use psyche::wits::{
-        BasicMemory, Combobulator, FondDuCoeur, HeartWit, IdentityWit, MemoryWit, Neo4jClient,
-        QdrantClient, Quick, Will,
-    };
+    use crate::{LoggingMotor, PipelineConfig, WitKind, ollama_doers};
+    use psyche::wits::{BasicMemory, Neo4jClient, QdrantClient};
 
-    let narrator = ollama_provider_from_args(chatter_host, chatter_model)?;
-    let voice = ollama_provider_from_args(chatter_host, chatter_model)?;
-    let vectorizer = ollama_provider_from_args(embeddings_host, embeddings_model)?;
+    let narrator = scheduled_ollama_provider(chatter_host, chatter_model, Priority::Conversation)?;
+    let voice = scheduled_ollama_provider(chatter_host, chatter_model, Priority::Conversation)?;
+    let vectorizer =
+        scheduled_ollama_provider(embeddings_host, embeddings_model, Priority::Background)?;
 
     let mouth = Arc::new(NoopMouth::default());
     let ear = Arc::new(NoopEar);
 
     let memory = Arc::new(BasicMemory {
-        vectorizer: Arc::new(ollama_provider_from_args(
+        vectorizer: Arc::new(scheduled_ollama_provider(
             embeddings_host,
             embeddings_model,
+            Priority::Background,
         )?),
         qdrant: QdrantClient::new(qdrant_url.into()),
-        neo4j: Arc::new(Neo4jClient::new(
-            neo4j_uri.into(),
-            neo4j_user.into(),
-            neo4j_pass.into(),
-        )),
+        neo4j: Arc::new(
+            Neo4jClient::new(neo4j_uri.into(), neo4j_user.into(), neo4j_pass.into())
+                .with_blob_store(psyche::BlobConfig::from_env()?.open()),
+        ),
     });
 
     let mut psyche = Psyche::new(
@@ -114,43 +117,22 @@ pub fn ollama_psyche(
         mouth,
         ear,
     );
-    let wit_tx = psyche.wit_sender();
-    psyche.register_observing_wit(Arc::new(psyche::VisionWit::with_debug(
-        Arc::new(ollama_provider_from_args(wits_host, wits_model)?),
-        wit_tx.clone(),
-    )));
-    psyche.register_observing_wit(Arc::new(psyche::FaceMemoryWit::with_debug(wit_tx.clone())));
-    psyche.register_observing_wit(Arc::new(Quick::with_debug(
-        psyche.topic_bus(),
-        Arc::new(ollama_provider_from_args(wits_host, wits_model)?),
-        Some(wit_tx.clone()),
-    )));
-    psyche.register_typed_wit(Arc::new(
-        Combobulator::with_bus_and_debug(
-            psyche.topic_bus(),
-            Arc::new(ollama_provider_from_args(wits_host, wits_model)?),
-            Some(wit_tx.clone()),
-        )
-        .with_events(psyche.event_sender()),
-    ));
-    psyche.register_typed_wit(Arc::new(Will::with_debug(
-        psyche.topic_bus(),
-        Arc::new(ollama_provider_from_args(wits_host, wits_model)?),
-        Some(wit_tx.clone()),
-    )));
-    psyche.register_typed_wit(Arc::new(MemoryWit::with_debug(
-        memory.clone(),
-        wit_tx.clone(),
-    )));
-    psyche.register_typed_wit(Arc::new(HeartWit::with_debug(
-        Box::new(ollama_provider_from_args(wits_host, wits_model)?),
+    PipelineConfig::with_wits([
+        WitKind::Vision,
+        WitKind::FaceMemory,
+        WitKind::Quick,
+        WitKind::Combobulator,
+        WitKind::Will,
+        WitKind::Memory,
+        WitKind::Heart,
+        WitKind::Identity,
+    ])
+    .build(
+        &mut psyche,
+        memory,
         Arc::new(LoggingMotor),
-        wit_tx.clone(),
-    )));
-    psyche.register_typed_wit(Arc::new(IdentityWit::new(FondDuCoeur::with_debug(
-        Box::new(ollama_provider_from_args(wits_host, wits_model)?),
-        wit_tx.clone(),
-    ))));
+        ollama_doers(wits_host, wits_model),
+    )?;
     psyche.set_turn_limit(usize::MAX);
     psyche
         .voice()
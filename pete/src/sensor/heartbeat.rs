@@ -1,7 +1,6 @@
 use async_trait::async_trait;
-use chrono::Utc;
-use psyche::{Heartbeat, Sensation, Sensor};
-use rand::Rng;
+use psyche::{Clock, Heartbeat, Sensation, Sensor, SystemClock};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tracing::trace;
@@ -13,24 +12,29 @@ pub struct HeartbeatSensor;
 impl HeartbeatSensor {
     /// Spawn a new heartbeat loop forwarding sensations through `forward`.
     pub fn new(forward: mpsc::Sender<Sensation>) -> Self {
-        Self::spawn(forward, Duration::from_secs(55), 10);
+        Self::with_clock(forward, Arc::new(SystemClock))
+    }
+
+    /// Spawn a heartbeat loop that waits and stamps beats using `clock`.
+    pub fn with_clock(forward: mpsc::Sender<Sensation>, clock: Arc<dyn Clock>) -> Self {
+        Self::spawn(forward, clock, Duration::from_secs(55), 10);
         Self
     }
 
     #[cfg(test)]
     pub fn test_interval(forward: mpsc::Sender<Sensation>, secs: u64) -> Self {
-        Self::spawn(forward, Duration::from_secs(secs), 0);
+        Self::spawn(forward, Arc::new(SystemClock), Duration::from_secs(secs), 0);
         Self
     }
 
-    fn spawn(forward: mpsc::Sender<Sensation>, base: Duration, range: u64) {
+    fn spawn(forward: mpsc::Sender<Sensation>, clock: Arc<dyn Clock>, base: Duration, range: u64) {
         tokio::spawn(async move {
             loop {
-                let secs = rand::thread_rng().gen_range(0..=range);
-                let wait = base + Duration::from_secs(secs);
-                tokio::time::sleep(wait).await;
+                let jitter = clock.jitter(Duration::from_secs(range + 1));
+                let wait = base + Duration::from_secs(jitter.as_secs());
+                clock.sleep(wait).await;
                 let beat = Heartbeat {
-                    timestamp: Utc::now(),
+                    timestamp: clock.now(),
                 };
                 let occurred_at = beat.timestamp;
                 trace!("heartbeat");
@@ -0,0 +1,614 @@
+//! Deterministic end-to-end simulation of Pete's cognition.
+//!
+//! A [`Scenario`] scripts timed utterances, images, geolocations and motion.
+//! [`SimulationHarness`] replays it into a [`Psyche`] driven by a
+//! [`VirtualClock`], answers every language-model call from a [`ScriptedLlm`]
+//! and records graph writes in an [`InMemoryGraph`], then returns a
+//! [`SimulationTrace`] that tests can assert against.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use lingproc::{Chatter, Doer, LlmInstruction, Message, TextStream, Vectorizer};
+use psyche::{
+    BrowserMotion, Clock, Combobulator, ContextualPrompt, Event, GeoLoc, GraphStore,
+    HostInstruction, ImageData, Impression, Psyche, Sensation, SensationGraphObserver, Topic,
+    VirtualClock, Will, Wit, wits::Quick,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tokio::sync::broadcast;
+use tracing::{debug, info};
+
+use crate::{ChannelMouth, EventBus, NoopEar};
+
+/// Default virtual time between Wit ticks during a simulation.
+pub const DEFAULT_SIMULATION_TICK: Duration = Duration::from_secs(1);
+
+/// Input delivered to Pete at one step of a [`Scenario`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ScenarioEvent {
+    /// The user says `text` aloud.
+    Say { text: String },
+    /// Someone types `text` into the web interface.
+    Type { text: String },
+    /// The camera captures a frame.
+    Image { mime: String, base64: String },
+    /// The device reports a position.
+    Geolocate { latitude: f64, longitude: f64 },
+    /// The device reports motion.
+    Motion { motion: BrowserMotion },
+}
+
+/// A [`ScenarioEvent`] scheduled at an offset from the start of the scenario.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScenarioStep {
+    /// Milliseconds of virtual time after the scenario starts.
+    pub at_ms: u64,
+    #[serde(flatten)]
+    pub event: ScenarioEvent,
+}
+
+/// Timed script of sensory input for a [`SimulationHarness`].
+///
+/// Scenarios can be built in code or loaded from JSON:
+///
+/// ```
+/// use pete::Scenario;
+///
+/// let scenario = Scenario::from_json(
+///     r#"{"steps": [{"at_ms": 0, "kind": "say", "text": "hello"}], "settle_ms": 5000}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(scenario.steps.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Scenario {
+    /// Scripted inputs. They are replayed in `at_ms` order.
+    pub steps: Vec<ScenarioStep>,
+    /// Virtual time to keep ticking after the last step.
+    #[serde(default)]
+    pub settle_ms: u64,
+}
+
+impl Scenario {
+    /// Create an empty scenario.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a scenario from JSON.
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        serde_json::from_str(json).context("failed to parse simulation scenario")
+    }
+
+    /// Schedule the user saying `text` at `at_ms`.
+    pub fn say(self, at_ms: u64, text: impl Into<String>) -> Self {
+        self.step(at_ms, ScenarioEvent::Say { text: text.into() })
+    }
+
+    /// Schedule typed web-interface text at `at_ms`.
+    pub fn type_text(self, at_ms: u64, text: impl Into<String>) -> Self {
+        self.step(at_ms, ScenarioEvent::Type { text: text.into() })
+    }
+
+    /// Schedule a camera frame with raw `bytes` at `at_ms`.
+    pub fn image(self, at_ms: u64, mime: impl Into<String>, bytes: &[u8]) -> Self {
+        self.step(
+            at_ms,
+            ScenarioEvent::Image {
+                mime: mime.into(),
+                base64: BASE64.encode(bytes),
+            },
+        )
+    }
+
+    /// Schedule a geolocation fix at `at_ms`.
+    pub fn geolocate(self, at_ms: u64, latitude: f64, longitude: f64) -> Self {
+        self.step(
+            at_ms,
+            ScenarioEvent::Geolocate {
+                latitude,
+                longitude,
+            },
+        )
+    }
+
+    /// Schedule a browser motion reading at `at_ms`.
+    pub fn motion(self, at_ms: u64, motion: BrowserMotion) -> Self {
+        self.step(at_ms, ScenarioEvent::Motion { motion })
+    }
+
+    /// Keep ticking for `settle_ms` of virtual time after the last step.
+    pub fn settle(mut self, settle_ms: u64) -> Self {
+        self.settle_ms = settle_ms;
+        self
+    }
+
+    fn step(mut self, at_ms: u64, event: ScenarioEvent) -> Self {
+        self.steps.push(ScenarioStep { at_ms, event });
+        self
+    }
+}
+
+#[derive(Default)]
+struct ScriptState {
+    cassette: Vec<(String, String)>,
+    replies: VecDeque<String>,
+    fallback: String,
+    calls: Vec<String>,
+}
+
+/// Fake language model answering from a cassette and a reply queue.
+///
+/// Each prompt is matched against cassette entries in insertion order; the
+/// first entry whose needle appears in the prompt supplies the response.
+/// Unmatched prompts consume the next queued reply, and fall back to a fixed
+/// response once the queue is empty. Every prompt is recorded.
+#[derive(Clone, Default)]
+pub struct ScriptedLlm {
+    state: Arc<Mutex<ScriptState>>,
+}
+
+impl ScriptedLlm {
+    /// Create a scripted model that answers `fallback` when nothing matches.
+    pub fn new(fallback: impl Into<String>) -> Self {
+        let llm = Self::default();
+        llm.state.lock().unwrap().fallback = fallback.into();
+        llm
+    }
+
+    /// Load cassette entries from a JSON object of `needle -> response`.
+    pub fn from_cassette_json(fallback: impl Into<String>, json: &str) -> anyhow::Result<Self> {
+        let entries: BTreeMap<String, String> =
+            serde_json::from_str(json).context("failed to parse LLM cassette")?;
+        Ok(entries
+            .into_iter()
+            .fold(Self::new(fallback), |llm, (needle, reply)| {
+                llm.on(needle, reply)
+            }))
+    }
+
+    /// Answer prompts containing `needle` with `reply`.
+    pub fn on(self, needle: impl Into<String>, reply: impl Into<String>) -> Self {
+        self.state
+            .lock()
+            .unwrap()
+            .cassette
+            .push((needle.into(), reply.into()));
+        self
+    }
+
+    /// Queue `reply` for the next prompt that no cassette entry matches.
+    pub fn then(self, reply: impl Into<String>) -> Self {
+        self.state.lock().unwrap().replies.push_back(reply.into());
+        self
+    }
+
+    /// Every prompt received so far, in order.
+    pub fn calls(&self) -> Vec<String> {
+        self.state.lock().unwrap().calls.clone()
+    }
+
+    fn respond(&self, prompt: &str) -> String {
+        let mut state = self.state.lock().unwrap();
+        state.calls.push(prompt.to_string());
+        if let Some((_, reply)) = state
+            .cassette
+            .iter()
+            .find(|(needle, _)| prompt.contains(needle.as_str()))
+        {
+            return reply.clone();
+        }
+        let queued = state.replies.pop_front();
+        queued.unwrap_or_else(|| state.fallback.clone())
+    }
+}
+
+#[async_trait]
+impl Doer for ScriptedLlm {
+    async fn follow(&self, instruction: LlmInstruction) -> anyhow::Result<String> {
+        Ok(self.respond(&instruction.command))
+    }
+}
+
+#[async_trait]
+impl Chatter for ScriptedLlm {
+    async fn chat(&self, system_prompt: &str, history: &[Message]) -> anyhow::Result<TextStream> {
+        let mut prompt = system_prompt.to_string();
+        for message in history {
+            prompt.push('\n');
+            prompt.push_str(&message.content);
+        }
+        let reply = self.respond(&prompt);
+        Ok(Box::pin(tokio_stream::once(Ok(reply))))
+    }
+}
+
+#[async_trait]
+impl Vectorizer for ScriptedLlm {
+    async fn vectorize(&self, text: &str) -> anyhow::Result<Vec<f32>> {
+        let digest = Sha256::digest(text.as_bytes());
+        Ok(digest
+            .iter()
+            .take(8)
+            .map(|byte| *byte as f32 / 255.0)
+            .collect())
+    }
+}
+
+/// [`GraphStore`] that keeps merged nodes and relationships in memory.
+#[derive(Clone, Default)]
+pub struct InMemoryGraph {
+    nodes: Arc<Mutex<BTreeMap<String, Value>>>,
+    relationships: Arc<Mutex<Vec<Value>>>,
+}
+
+impl InMemoryGraph {
+    /// Create an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the merged properties of the node with `id`.
+    pub fn node(&self, id: &str) -> Option<Value> {
+        self.nodes.lock().unwrap().get(id).cloned()
+    }
+
+    /// Return every node carrying `label`, either as its primary label or in
+    /// its extra `labels` list.
+    pub fn nodes_labeled(&self, label: &str) -> Vec<Value> {
+        self.nodes
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|node| node_has_label(node, label))
+            .cloned()
+            .collect()
+    }
+
+    /// Return every relationship of type `rel_type`.
+    pub fn relationships_of_type(&self, rel_type: &str) -> Vec<Value> {
+        self.relationships
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|rel| rel.get("type").and_then(Value::as_str) == Some(rel_type))
+            .cloned()
+            .collect()
+    }
+}
+
+fn node_has_label(node: &Value, label: &str) -> bool {
+    node.get("label").and_then(Value::as_str) == Some(label)
+        || node
+            .get("labels")
+            .and_then(Value::as_array)
+            .is_some_and(|labels| labels.iter().any(|l| l.as_str() == Some(label)))
+}
+
+#[async_trait]
+impl GraphStore for InMemoryGraph {
+    async fn store_data(&self, data: &Value) -> anyhow::Result<()> {
+        if data.get("op").and_then(Value::as_str) != Some("merge_graph") {
+            return Ok(());
+        }
+        if let Some(nodes) = data.get("nodes").and_then(Value::as_array) {
+            let mut stored = self.nodes.lock().unwrap();
+            for node in nodes {
+                let id = node
+                    .get("id")
+                    .and_then(Value::as_str)
+                    .context("graph node is missing id")?;
+                let entry = stored
+                    .entry(id.to_string())
+                    .or_insert_with(|| Value::Object(Default::default()));
+                if let (Some(entry), Some(props)) = (entry.as_object_mut(), node.as_object()) {
+                    for (key, value) in props {
+                        entry.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        if let Some(relationships) = data.get("relationships").and_then(Value::as_array) {
+            self.relationships
+                .lock()
+                .unwrap()
+                .extend(relationships.iter().cloned());
+        }
+        Ok(())
+    }
+}
+
+/// A sensation recorded in the graph during a simulation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulatedSensation {
+    /// Sensation kind, e.g. `utterance` or `image`.
+    pub kind: String,
+    /// RFC3339 virtual time the sensation occurred.
+    pub occurred_at: String,
+    /// First-person gloss stored on the sensation node.
+    pub how: String,
+}
+
+/// Everything observable that happened during a simulation run.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimulationTrace {
+    /// Graph sensations ordered by occurrence time.
+    pub timeline: Vec<SimulatedSensation>,
+    /// Situation summaries published by the Combobulator.
+    pub combobulations: Vec<Impression<String>>,
+    /// Text the Will decided Pete ought to say.
+    pub speech_intentions: Vec<String>,
+    /// Sentences the Voice actually spoke.
+    pub spoken: Vec<String>,
+    /// Emoji shown on Pete's face, in order.
+    pub face_changes: Vec<String>,
+}
+
+impl SimulationTrace {
+    /// Return `true` if any spoken sentence contains `needle`.
+    pub fn said(&self, needle: &str) -> bool {
+        self.spoken.iter().any(|s| s.contains(needle))
+    }
+
+    /// Return `true` if the timeline contains a sensation of `kind`.
+    pub fn sensed(&self, kind: &str) -> bool {
+        self.timeline.iter().any(|s| s.kind == kind)
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    combobulations: Vec<Impression<String>>,
+    speech_intentions: Vec<String>,
+    spoken: Vec<String>,
+    face_changes: Vec<String>,
+}
+
+/// Replays [`Scenario`]s through a fully wired [`Psyche`] on virtual time.
+pub struct SimulationHarness {
+    clock: VirtualClock,
+    llm: ScriptedLlm,
+    graph: InMemoryGraph,
+    tick: Duration,
+}
+
+impl SimulationHarness {
+    /// Create a harness answering language-model calls with `llm`.
+    pub fn new(llm: ScriptedLlm) -> Self {
+        Self {
+            clock: VirtualClock::default(),
+            llm,
+            graph: InMemoryGraph::new(),
+            tick: DEFAULT_SIMULATION_TICK,
+        }
+    }
+
+    /// Use `tick` of virtual time between Wit ticks.
+    pub fn with_tick(mut self, tick: Duration) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// The virtual clock driving the simulation.
+    pub fn clock(&self) -> VirtualClock {
+        self.clock.clone()
+    }
+
+    /// The in-memory graph receiving sensation records.
+    pub fn graph(&self) -> InMemoryGraph {
+        self.graph.clone()
+    }
+
+    /// The scripted model, for inspecting prompts after a run.
+    pub fn llm(&self) -> ScriptedLlm {
+        self.llm.clone()
+    }
+
+    /// Replay `scenario` and return what Pete perceived, thought and did.
+    ///
+    /// Call this on a current-thread runtime with Tokio time paused, e.g.
+    /// from `#[tokio::test(start_paused = true)]`. Between steps the harness
+    /// waits until every loop is parked rather than for wall time, so a
+    /// scenario replays identically on every run.
+    pub async fn run(&self, scenario: &Scenario) -> SimulationTrace {
+        let clock: Arc<dyn Clock> = Arc::new(self.clock.clone());
+        let (bus, _user_rx) = EventBus::new();
+        let bus = Arc::new(bus);
+        let mouth = Arc::new(ChannelMouth::new(bus.clone(), Default::default()));
+        let mut psyche = Psyche::new(
+            Box::new(self.llm.clone()),
+            Box::new(self.llm.clone()),
+            Box::new(self.llm.clone()),
+            Arc::new(psyche::NoopMemory),
+            mouth,
+            Arc::new(NoopEar),
+        );
+        psyche.set_clock(clock.clone()).await;
+        psyche.set_turn_limit(usize::MAX);
+        psyche.set_experience_tick(self.tick);
+        psyche.set_active_experience_tick(self.tick);
+
+        let topics = psyche.topic_bus();
+        let observer = Arc::new(
+            SensationGraphObserver::new(Arc::new(self.graph.clone())).with_clock(clock.clone()),
+        );
+        observer.clone().spawn_topic_listener(topics.clone());
+        psyche.register_observer(observer);
+        psyche.register_observing_wit(Arc::new(
+            Quick::new(topics.clone(), Arc::new(self.llm.clone())).with_clock(clock.clone()),
+        ));
+        psyche.register_typed_wit(Arc::new(
+            Combobulator::with_bus(topics.clone(), Arc::new(self.llm.clone()))
+                .with_events(psyche.event_sender())
+                .with_clock(clock.clone()),
+        ));
+        let will = Arc::new(Will::new(topics.clone(), Arc::new(self.llm.clone())));
+        psyche.register_typed_wit(will.clone());
+        psyche
+            .voice()
+            .set_prompt(ContextualPrompt::new(topics.clone()));
+
+        let recorder = Arc::new(Mutex::new(Recorder::default()));
+        let mut listeners = psyche::TaskGroup::new();
+        {
+            let recorder = recorder.clone();
+            let mut rx = topics.subscribe_raw();
+            listeners.spawn(async move {
+                while let Ok(msg) = rx.recv().await {
+                    match msg.topic {
+                        Topic::Moment => {
+                            if let Some(imp) = msg.payload.downcast_ref::<Impression<String>>() {
+                                recorder.lock().unwrap().combobulations.push(imp.clone());
+                                will.observe(imp.clone()).await;
+                            }
+                        }
+                        Topic::Instruction => match msg.payload.downcast_ref::<HostInstruction>() {
+                            Some(HostInstruction::Say { text, .. }) => {
+                                recorder
+                                    .lock()
+                                    .unwrap()
+                                    .speech_intentions
+                                    .push(text.clone());
+                            }
+                            Some(HostInstruction::Emote(emoji)) => {
+                                recorder.lock().unwrap().face_changes.push(emoji.clone());
+                            }
+                            _ => {}
+                        },
+                        _ => {}
+                    }
+                }
+            });
+        }
+        for mut rx in [psyche.subscribe(), bus.subscribe_events()] {
+            let recorder = recorder.clone();
+            listeners.spawn(async move {
+                loop {
+                    match rx.recv().await {
+                        Ok(Event::Speech { text, .. }) => {
+                            recorder.lock().unwrap().spoken.push(text);
+                        }
+                        Ok(Event::EmotionChanged(emoji)) => {
+                            recorder.lock().unwrap().face_changes.push(emoji);
+                        }
+                        Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    }
+                }
+            });
+        }
+
+        let input = psyche.input_sender();
+        let running = tokio::spawn(psyche.run());
+        let start = self.clock.now();
+        let mut steps = scenario.steps.clone();
+        steps.sort_by_key(|step| step.at_ms);
+        info!(steps = steps.len(), "simulation started");
+        for step in &steps {
+            let due = start + chrono::Duration::milliseconds(step.at_ms as i64);
+            self.advance_until(due).await;
+            let occurred_at = self.clock.now();
+            debug!(at_ms = step.at_ms, ?step.event, "simulation step");
+            let sensation = match &step.event {
+                ScenarioEvent::Say { text } => Sensation::heard_user_voice_at(text, occurred_at),
+                ScenarioEvent::Type { text } => Sensation::web_interface_text_at(text, occurred_at),
+                ScenarioEvent::Image { mime, base64 } => Sensation::of_at(
+                    ImageData {
+                        mime: mime.clone(),
+                        base64: base64.clone(),
+                        captured_at: Some(occurred_at.to_rfc3339()),
+                        source: None,
+                    },
+                    occurred_at,
+                ),
+                ScenarioEvent::Geolocate {
+                    latitude,
+                    longitude,
+                } => Sensation::of_at(
+                    GeoLoc {
+                        latitude: *latitude,
+                        longitude: *longitude,
+                        observed_at: Some(occurred_at.to_rfc3339()),
+                    },
+                    occurred_at,
+                ),
+                ScenarioEvent::Motion { motion } => {
+                    let mut motion = motion.clone();
+                    motion.observed_at = Some(occurred_at.to_rfc3339());
+                    Sensation::of_at(motion, occurred_at)
+                }
+            };
+            let _ = input.send(sensation).await;
+            settle().await;
+        }
+        let end = self.clock.now() + chrono::Duration::milliseconds(scenario.settle_ms as i64);
+        self.advance_until(end).await;
+
+        running.abort();
+        let _ = running.await;
+        listeners.shutdown().await;
+        info!("simulation finished");
+
+        let recorder = std::mem::take(&mut *recorder.lock().unwrap());
+        SimulationTrace {
+            timeline: self.timeline(),
+            combobulations: recorder.combobulations,
+            speech_intentions: recorder.speech_intentions,
+            spoken: recorder.spoken,
+            face_changes: recorder.face_changes,
+        }
+    }
+
+    /// Step virtual time towards `due` one tick at a time, letting every
+    /// loop run between steps.
+    async fn advance_until(&self, due: chrono::DateTime<chrono::Utc>) {
+        settle().await;
+        while self.clock.now() < due {
+            let remaining = (due - self.clock.now()).to_std().unwrap_or_default();
+            let step = remaining.min(self.tick);
+            self.clock.advance(step);
+            tokio::time::advance(step).await;
+            settle().await;
+        }
+    }
+
+    fn timeline(&self) -> Vec<SimulatedSensation> {
+        let mut timeline = self
+            .graph
+            .nodes_labeled("Sensation")
+            .into_iter()
+            .map(|node| SimulatedSensation {
+                kind: string_prop(&node, "kind"),
+                occurred_at: string_prop(&node, "occurred_at"),
+                how: string_prop(&node, "how"),
+            })
+            .collect::<Vec<_>>();
+        timeline.sort_by(|a, b| a.occurred_at.cmp(&b.occurred_at));
+        timeline
+    }
+}
+
+fn string_prop(node: &Value, key: &str) -> String {
+    node.get(key)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Wait until every spawned loop has observed the latest virtual time.
+///
+/// With Tokio time paused the runtime only moves its clock forward once no
+/// task can make progress, so this sleep ends exactly when everything is
+/// parked on a channel or on the [`VirtualClock`].
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(1)).await;
+}
@@ -32,6 +32,7 @@ impl Simulator {
             mime: mime.to_string(),
             base64: data,
             captured_at: None,
+            source: None,
         };
         self.eye.sense(img).await;
     }
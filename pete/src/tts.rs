@@ -5,27 +5,136 @@ use crate::EventBus;
 use async_trait::async_trait;
 #[cfg(feature = "tts")]
 use lingproc::segment_text_into_sentences;
+use psyche::ProsodyMap;
 use psyche::traits::Mouth;
 #[cfg(feature = "tts")]
-use psyche::traits::{Tts, TtsStream};
+use psyche::traits::{Tts, TtsAudio, TtsRequest, TtsStream, TtsVoice};
 #[cfg(feature = "tts")]
-use psyche::{Event, PlainMouth};
+use psyche::{
+    Event, PhonemeTiming, PlainMouth, Prosody, VisemeCue, extract_emojis, visemes_from_phonemes,
+    visemes_from_text,
+};
 #[cfg(feature = "tts")]
-use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+#[cfg(feature = "tts")]
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, atomic::AtomicBool};
 #[cfg(feature = "tts")]
 use tokio::sync::broadcast;
 #[cfg(feature = "tts")]
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 #[cfg(feature = "tts")]
-use anyhow::Result;
+use anyhow::{Context, anyhow};
+use anyhow::{Result, bail};
 #[cfg(feature = "tts")]
 use base64::{Engine as _, engine::general_purpose};
 #[cfg(feature = "tts")]
 use futures::StreamExt;
 #[cfg(feature = "tts")]
 use reqwest::{Client, Url};
+#[cfg(feature = "tts")]
+use serde_json::{Value, json};
+use std::str::FromStr;
+#[cfg(feature = "tts")]
+use tokio::io::AsyncWriteExt;
+
+/// Speech synthesizer selected by [`TtsConfig`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TtsBackend {
+    /// Coqui TTS server queried with GET parameters.
+    #[default]
+    Coqui,
+    /// Piper HTTP server taking a JSON body.
+    Piper,
+    /// OpenAI-compatible `/v1/audio/speech` endpoint.
+    OpenAi,
+    /// Local program reading text on stdin and writing WAV to stdout.
+    Command,
+}
+
+impl FromStr for TtsBackend {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "coqui" => Ok(Self::Coqui),
+            "piper" => Ok(Self::Piper),
+            "openai" | "open-ai" => Ok(Self::OpenAi),
+            "command" | "cmd" => Ok(Self::Command),
+            other => {
+                bail!("unknown TTS backend `{other}`; expected coqui, piper, openai or command")
+            }
+        }
+    }
+}
+
+impl TtsBackend {
+    /// Server URL used when none is configured (`TTS_URL`).
+    pub fn default_url(self) -> &'static str {
+        match self {
+            Self::Coqui => "http://localhost:5002/api/tts",
+            Self::Piper => "http://localhost:5000",
+            Self::OpenAi => "https://api.openai.com/v1/audio/speech",
+            Self::Command => "",
+        }
+    }
+}
+
+/// Settings for building the configured [`Tts`] backend.
+#[derive(Clone, Debug, Default)]
+pub struct TtsConfig {
+    /// Which synthesizer to talk to.
+    pub backend: TtsBackend,
+    /// Server URL for HTTP backends.
+    pub url: String,
+    /// Voice, speaker or model voice name, depending on the backend.
+    pub voice: Option<String>,
+    /// Language code for backends that take one.
+    pub language: Option<String>,
+    /// Model name for OpenAI-compatible servers.
+    pub model: Option<String>,
+    /// Bearer token for OpenAI-compatible servers.
+    pub api_key: Option<String>,
+    /// Program and arguments for the command backend; `{voice}` is replaced
+    /// with the configured voice.
+    pub command: Option<String>,
+    /// Voices advertised by the command backend.
+    pub voices: Vec<String>,
+    /// How Pete's emotion shapes his voice.
+    pub prosody: ProsodyMap,
+}
+
+#[cfg(feature = "tts")]
+impl TtsConfig {
+    /// Build the backend described by this configuration.
+    pub fn build(&self) -> Result<Arc<dyn Tts>> {
+        let tts: Arc<dyn Tts> = match self.backend {
+            TtsBackend::Coqui => Arc::new(CoquiTts::new(
+                self.url.clone(),
+                self.voice.clone(),
+                self.language.clone(),
+            )),
+            TtsBackend::Piper => Arc::new(PiperTts::new(self.url.clone(), self.voice.clone())),
+            TtsBackend::OpenAi => Arc::new(
+                OpenAiTts::new(self.url.clone(), self.model.clone(), self.voice.clone())
+                    .with_api_key(self.api_key.clone()),
+            ),
+            TtsBackend::Command => {
+                let command = self
+                    .command
+                    .as_deref()
+                    .filter(|command| !command.trim().is_empty())
+                    .context("the command TTS backend needs a command (TTS_COMMAND)")?;
+                Arc::new(
+                    CommandTts::parse(command, self.voice.clone())?
+                        .with_voices(self.voices.clone()),
+                )
+            }
+        };
+        Ok(tts)
+    }
+}
 
 /// Client for a Coqui TTS server.
 #[cfg_attr(feature = "tts", derive(Clone))]
@@ -63,22 +172,429 @@ impl CoquiTts {
 #[cfg(feature = "tts")]
 impl Tts for CoquiTts {
     async fn stream_wav(&self, text: &str) -> Result<TtsStream> {
+        self.stream_request(&TtsRequest::new(text)).await
+    }
+
+    /// Coqui only takes a speaker, so prosody switches voices there and its
+    /// rate, pitch and volume reshape the returned WAV. The style is ignored.
+    async fn stream_request(&self, request: &TtsRequest) -> Result<TtsStream> {
+        let speaker_id = request
+            .prosody
+            .voice
+            .as_deref()
+            .or(self.speaker_id.as_deref())
+            .unwrap_or("p228");
         let mut url = Url::parse(&self.url)?;
         {
             let mut qp = url.query_pairs_mut();
-            qp.append_pair("text", text);
+            qp.append_pair("text", &request.text);
             // Always include speaker_id, style_wav and language_id parameters
             // providing defaults when values are not configured
-            qp.append_pair("speaker_id", self.speaker_id.as_deref().unwrap_or("p228"));
+            qp.append_pair("speaker_id", speaker_id);
             qp.append_pair("style_wav", "");
             qp.append_pair("language_id", self.language_id.as_deref().unwrap_or(""));
         }
         info!(%url, "requesting TTS");
         let resp = self.client.get(url).send().await?.error_for_status()?;
-        let stream = resp
-            .bytes_stream()
-            .map(|b| b.map(|bytes| bytes.to_vec()).map_err(|e| e.into()));
-        Ok(Box::pin(stream))
+        reshaped(wav_stream(resp), request.prosody.rate, &request.prosody).await
+    }
+
+    /// Coqui's server has no voice listing, so this is the configured speaker.
+    async fn voices(&self) -> Result<Vec<TtsVoice>> {
+        Ok(vec![TtsVoice {
+            id: self.speaker_id.as_deref().unwrap_or("p228").to_string(),
+            language: self.language_id.clone().filter(|id| !id.is_empty()),
+        }])
+    }
+}
+
+#[cfg(feature = "tts")]
+fn wav_stream(resp: reqwest::Response) -> TtsStream {
+    Box::pin(
+        resp.bytes_stream()
+            .map(|b| b.map(|bytes| bytes.to_vec()).map_err(|e| e.into())),
+    )
+}
+
+/// Give a backend's WAV the `tempo` and the pitch and volume of `prosody`
+/// it could not apply itself. Neutral settings pass the stream through.
+#[cfg(feature = "tts")]
+async fn reshaped(mut stream: TtsStream, tempo: f32, prosody: &Prosody) -> Result<TtsStream> {
+    let (pitch, volume) = (prosody.pitch, prosody.volume);
+    if tempo == 1.0 && pitch == 0.0 && volume == 1.0 {
+        return Ok(stream);
+    }
+    let mut wav = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        wav.extend(chunk?);
+    }
+    let wav = tokio::task::spawn_blocking(move || {
+        crate::codec::reshape_wav(&wav, tempo, pitch, volume).unwrap_or_else(|| {
+            warn!("TTS audio is not 16-bit PCM WAV; speaking it without prosody");
+            wav
+        })
+    })
+    .await?;
+    Ok(Box::pin(futures::stream::once(async move { Ok(wav) })))
+}
+
+/// Client for a Piper HTTP server (`python -m piper.http_server`).
+#[derive(Clone)]
+#[cfg(feature = "tts")]
+pub struct PiperTts {
+    url: String,
+    client: Client,
+    voice: Option<String>,
+}
+
+#[cfg(feature = "tts")]
+impl PiperTts {
+    /// Create a new client targeting the server root `url` (e.g.
+    /// `http://localhost:5000`). `voice` picks one of the loaded models.
+    pub fn new(url: impl Into<String>, voice: Option<String>) -> Self {
+        Self {
+            url: url.into(),
+            client: Client::new(),
+            voice,
+        }
+    }
+}
+
+#[async_trait]
+#[cfg(feature = "tts")]
+impl Tts for PiperTts {
+    async fn stream_wav(&self, text: &str) -> Result<TtsStream> {
+        self.stream_request(&TtsRequest::new(text)).await
+    }
+
+    /// Piper controls tempo through `length_scale`, the inverse of rate;
+    /// pitch and volume reshape the returned WAV. The style is ignored.
+    async fn stream_request(&self, request: &TtsRequest) -> Result<TtsStream> {
+        let prosody = &request.prosody;
+        let mut body = json!({ "text": request.text });
+        if let Some(voice) = prosody.voice.as_ref().or(self.voice.as_ref()) {
+            body["voice"] = json!(voice);
+        }
+        if prosody.rate > 0.0 && prosody.rate != 1.0 {
+            body["length_scale"] = json!(1.0 / prosody.rate);
+        }
+        info!(url = %self.url, "requesting Piper TTS");
+        let resp = self
+            .client
+            .post(&self.url)
+            .json(&body)
+            .send()
+            .await?
+            .error_for_status()?;
+        reshaped(wav_stream(resp), 1.0, prosody).await
+    }
+
+    async fn voices(&self) -> Result<Vec<TtsVoice>> {
+        let url = Url::parse(&self.url)?.join("voices")?;
+        let listing: Value = self
+            .client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(parse_voice_listing(&listing))
+    }
+}
+
+/// Voices offered by OpenAI's speech endpoint, used when a compatible server
+/// does not list its own.
+#[cfg(feature = "tts")]
+const OPENAI_VOICES: &[&str] = &[
+    "alloy", "ash", "ballad", "coral", "echo", "fable", "nova", "onyx", "sage", "shimmer",
+];
+
+/// Client for an OpenAI-compatible speech endpoint.
+#[derive(Clone)]
+#[cfg(feature = "tts")]
+pub struct OpenAiTts {
+    url: String,
+    client: Client,
+    model: String,
+    voice: String,
+    api_key: Option<String>,
+}
+
+#[cfg(feature = "tts")]
+impl OpenAiTts {
+    /// Create a new client targeting the full speech `url` (e.g.
+    /// `https://api.openai.com/v1/audio/speech`). `model` defaults to `tts-1`
+    /// and `voice` to `alloy`.
+    pub fn new(url: impl Into<String>, model: Option<String>, voice: Option<String>) -> Self {
+        Self {
+            url: url.into(),
+            client: Client::new(),
+            model: model.unwrap_or_else(|| "tts-1".into()),
+            voice: voice.unwrap_or_else(|| "alloy".into()),
+            api_key: None,
+        }
+    }
+
+    /// Return this client sending `api_key` as a bearer token.
+    pub fn with_api_key(mut self, api_key: Option<String>) -> Self {
+        self.api_key = api_key.filter(|key| !key.is_empty());
+        self
+    }
+
+    fn authorize(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.api_key {
+            Some(key) => request.bearer_auth(key),
+            None => request,
+        }
+    }
+}
+
+#[async_trait]
+#[cfg(feature = "tts")]
+impl Tts for OpenAiTts {
+    async fn stream_wav(&self, text: &str) -> Result<TtsStream> {
+        self.stream_request(&TtsRequest::new(text)).await
+    }
+
+    /// Rate becomes `speed` and the style becomes `instructions` for models
+    /// that follow them.
+    async fn stream_request(&self, request: &TtsRequest) -> Result<TtsStream> {
+        let prosody = &request.prosody;
+        let mut body = json!({
+            "model": self.model,
+            "input": request.text,
+            "voice": prosody.voice.as_ref().unwrap_or(&self.voice),
+            "response_format": "wav",
+        });
+        if prosody.rate != 1.0 {
+            body["speed"] = json!(prosody.rate.clamp(0.25, 4.0));
+        }
+        if let Some(style) = &prosody.style {
+            body["instructions"] = json!(format!("Sound {style}."));
+        }
+        info!(url = %self.url, model = %self.model, "requesting OpenAI-compatible TTS");
+        let resp = self
+            .authorize(self.client.post(&self.url))
+            .json(&body)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(wav_stream(resp))
+    }
+
+    /// Ask the server's `voices` endpoint next to `speech`, falling back to
+    /// OpenAI's own voices when it has none.
+    async fn voices(&self) -> Result<Vec<TtsVoice>> {
+        let url = Url::parse(&self.url)?.join("voices")?;
+        let listed = match self.authorize(self.client.get(url)).send().await {
+            Ok(resp) if resp.status().is_success() => resp
+                .json::<Value>()
+                .await
+                .map(|listing| parse_voice_listing(&listing))
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        if listed.is_empty() {
+            return Ok(OPENAI_VOICES.iter().copied().map(TtsVoice::new).collect());
+        }
+        Ok(listed)
+    }
+}
+
+/// Read voices from the shapes servers commonly return: an array of names or
+/// objects, an object keyed by voice id, or either wrapped in `{"voices": ...}`.
+#[cfg(feature = "tts")]
+fn parse_voice_listing(listing: &Value) -> Vec<TtsVoice> {
+    let language = |value: &Value| {
+        value
+            .get("language")
+            .and_then(|language| language.get("code").or(Some(language)))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    match listing {
+        Value::Object(map) if map.contains_key("voices") => parse_voice_listing(&map["voices"]),
+        Value::Object(map) => map
+            .iter()
+            .map(|(id, info)| TtsVoice {
+                id: id.clone(),
+                language: language(info),
+            })
+            .collect(),
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| match item {
+                Value::String(id) => Some(TtsVoice::new(id.clone())),
+                Value::Object(_) => {
+                    let id = item.get("id").or_else(|| item.get("name"))?.as_str()?;
+                    Some(TtsVoice {
+                        id: id.to_string(),
+                        language: language(item),
+                    })
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Synthesizer that runs a local program, writing the text to its stdin and
+/// reading WAV bytes from its stdout.
+#[derive(Clone)]
+#[cfg(feature = "tts")]
+pub struct CommandTts {
+    program: String,
+    args: Vec<String>,
+    voice: Option<String>,
+    voices: Vec<String>,
+}
+
+#[cfg(feature = "tts")]
+impl CommandTts {
+    /// Create a synthesizer running `program` with `args`. Any `{voice}` in
+    /// the arguments is replaced with `voice`, and `{rate}`, `{pitch}`,
+    /// `{volume}` and `{style}` with the request's prosody. `{alignment}`
+    /// becomes the path of a file where the program may write phoneme
+    /// timings as a JSON array of `{"phoneme", "start_ms", "end_ms"}`.
+    pub fn new(program: impl Into<String>, args: Vec<String>, voice: Option<String>) -> Self {
+        Self {
+            program: program.into(),
+            args,
+            voice,
+            voices: Vec::new(),
+        }
+    }
+
+    /// Split a whitespace-separated command line into program and arguments.
+    pub fn parse(command: &str, voice: Option<String>) -> Result<Self> {
+        let mut words = command.split_whitespace().map(str::to_string);
+        let program = words.next().context("empty TTS command")?;
+        Ok(Self::new(program, words.collect(), voice))
+    }
+
+    /// Return this synthesizer advertising `voices`.
+    pub fn with_voices(mut self, voices: Vec<String>) -> Self {
+        self.voices = voices;
+        self
+    }
+}
+
+#[cfg(feature = "tts")]
+impl CommandTts {
+    /// Run the program for `request`, collecting its WAV output and any
+    /// phoneme timings it wrote to the `{alignment}` file.
+    async fn run(&self, request: &TtsRequest) -> Result<TtsAudio> {
+        static RUNS: AtomicU64 = AtomicU64::new(0);
+        let prosody = &request.prosody;
+        let voice = prosody
+            .voice
+            .as_deref()
+            .or(self.voice.as_deref())
+            .unwrap_or_default();
+        let alignment = self
+            .args
+            .iter()
+            .any(|arg| arg.contains("{alignment}"))
+            .then(|| {
+                std::env::temp_dir().join(format!(
+                    "pete-tts-{}-{}.json",
+                    std::process::id(),
+                    RUNS.fetch_add(1, Ordering::Relaxed)
+                ))
+            });
+        let alignment_arg = alignment
+            .as_deref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+        let fill = |arg: &String| {
+            arg.replace("{voice}", voice)
+                .replace("{rate}", &format!("{:.2}", prosody.rate))
+                .replace("{pitch}", &format!("{:.2}", prosody.pitch))
+                .replace("{volume}", &format!("{:.2}", prosody.volume))
+                .replace("{style}", prosody.style.as_deref().unwrap_or("neutral"))
+                .replace("{alignment}", &alignment_arg)
+        };
+        let mut child = tokio::process::Command::new(&self.program)
+            .args(self.args.iter().map(fill))
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .with_context(|| format!("failed to start TTS command `{}`", self.program))?;
+        let mut stdin = child.stdin.take().context("TTS command has no stdin")?;
+        let text = request.text.as_bytes();
+        // Drain stdout while writing, so a program that starts writing audio
+        // before it has read all the text cannot stall on a full pipe.
+        let write = async move {
+            let written = stdin.write_all(text).await;
+            drop(stdin);
+            written
+        };
+        let (written, output) = tokio::join!(write, child.wait_with_output());
+        let output = output?;
+        let phonemes = match &alignment {
+            Some(path) => read_alignment(path).await,
+            None => None,
+        };
+        if !output.status.success() {
+            return Err(anyhow!(
+                "TTS command `{}` failed with {}: {}",
+                self.program,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        written
+            .with_context(|| format!("failed writing text to TTS command `{}`", self.program))?;
+        Ok(TtsAudio {
+            wav: output.stdout,
+            phonemes,
+        })
+    }
+}
+
+/// Phoneme timings a TTS command wrote to `path`, removing the file.
+///
+/// A missing or malformed file is not an error; the caller falls back to
+/// guessing the timings from the text.
+#[cfg(feature = "tts")]
+async fn read_alignment(path: &std::path::Path) -> Option<Vec<PhonemeTiming>> {
+    let bytes = tokio::fs::read(path).await.ok()?;
+    let _ = tokio::fs::remove_file(path).await;
+    match serde_json::from_slice(&bytes) {
+        Ok(phonemes) => Some(phonemes),
+        Err(e) => {
+            warn!(?e, path = %path.display(), "ignoring malformed TTS alignment");
+            None
+        }
+    }
+}
+
+#[async_trait]
+#[cfg(feature = "tts")]
+impl Tts for CommandTts {
+    async fn stream_wav(&self, text: &str) -> Result<TtsStream> {
+        self.stream_request(&TtsRequest::new(text)).await
+    }
+
+    /// Prosody reaches the program through the `{rate}`, `{pitch}`,
+    /// `{volume}` and `{style}` argument placeholders.
+    async fn stream_request(&self, request: &TtsRequest) -> Result<TtsStream> {
+        let wav = self.run(request).await?.wav;
+        Ok(Box::pin(futures::stream::once(async move { Ok(wav) })))
+    }
+
+    /// Phoneme timings come from the `{alignment}` file when the command
+    /// line has one.
+    async fn synthesize(&self, request: &TtsRequest) -> Result<TtsAudio> {
+        self.run(request).await
+    }
+
+    async fn voices(&self) -> Result<Vec<TtsVoice>> {
+        Ok(self.voices.iter().cloned().map(TtsVoice::new).collect())
     }
 }
 
@@ -91,30 +607,82 @@ pub fn speech_text_for_tts(text: &str) -> Option<String> {
 
 #[cfg(feature = "tts")]
 pub async fn synthesize_speech_audio(tts: &dyn Tts, text: &str) -> Result<Option<String>> {
+    synthesize_speech_audio_with_prosody(tts, text, &Prosody::default()).await
+}
+
+/// Synthesize `text` as base64 WAV spoken with `prosody`.
+#[cfg(feature = "tts")]
+pub async fn synthesize_speech_audio_with_prosody(
+    tts: &dyn Tts,
+    text: &str,
+    prosody: &Prosody,
+) -> Result<Option<String>> {
+    Ok(synthesize_speech_with_visemes(tts, text, prosody)
+        .await?
+        .audio)
+}
+
+/// A synthesized sentence ready for the face.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg(feature = "tts")]
+pub struct SpokenAudio {
+    /// Base64 WAV, or `None` when there was nothing to say.
+    pub audio: Option<String>,
+    /// Mouth shapes timed against the audio.
+    pub visemes: Option<Vec<VisemeCue>>,
+}
+
+/// Synthesize `text` spoken with `prosody` together with its lip-sync
+/// timeline.
+///
+/// The timeline follows the backend's phoneme timings when it reports
+/// them and is otherwise guessed from the text and stretched over the
+/// length of the WAV.
+#[cfg(feature = "tts")]
+pub async fn synthesize_speech_with_visemes(
+    tts: &dyn Tts,
+    text: &str,
+    prosody: &Prosody,
+) -> Result<SpokenAudio> {
     let Some(clean) = speech_text_for_tts(text) else {
-        return Ok(None);
+        return Ok(SpokenAudio::default());
     };
 
-    let mut stream = tts.stream_wav(&clean).await?;
-    let mut buf = Vec::new();
-    while let Some(chunk) = stream.next().await {
-        let bytes = chunk?;
-        if !bytes.is_empty() {
-            buf.extend(bytes);
-        }
+    let request = TtsRequest::new(clean).with_prosody(prosody.clone());
+    let spoken = tts.synthesize(&request).await?;
+    if spoken.wav.is_empty() {
+        return Ok(SpokenAudio::default());
     }
+    let visemes = match spoken.phonemes {
+        Some(phonemes) if !phonemes.is_empty() => visemes_from_phonemes(&phonemes),
+        _ => crate::codec::wav_duration(&spoken.wav)
+            .map(|duration| visemes_from_text(&request.text, duration.as_millis() as u32))
+            .unwrap_or_default(),
+    };
 
-    Ok((!buf.is_empty()).then(|| general_purpose::STANDARD.encode(buf)))
+    Ok(SpokenAudio {
+        audio: Some(general_purpose::STANDARD.encode(spoken.wav)),
+        visemes: (!visemes.is_empty()).then_some(visemes),
+    })
 }
 
 /// [`Mouth`] implementation that streams audio via [`Tts`] and forwards it as
 /// [`Event::Speech`] chunks.
+///
+/// Each sentence is spoken with the [`Prosody`] of an emoji in the sentence
+/// itself or else of Pete's current emotion, the latest
+/// [`Event::EmotionChanged`].
 #[derive(Clone)]
 #[cfg(feature = "tts")]
 pub struct TtsMouth {
     events: broadcast::Sender<Event>,
     speaking: Arc<AtomicBool>,
     tts: Arc<dyn Tts>,
+    /// Bumped by [`Mouth::interrupt`] so in-flight `speak` calls stop early.
+    generation: Arc<AtomicU64>,
+    prosody: Arc<ProsodyMap>,
+    emotions: Arc<Mutex<broadcast::Receiver<Event>>>,
+    mood: Arc<Mutex<Option<String>>>,
 }
 
 #[cfg(feature = "tts")]
@@ -125,10 +693,42 @@ impl TtsMouth {
         tts: Arc<dyn Tts>,
     ) -> Self {
         Self {
+            emotions: Arc::new(Mutex::new(events.subscribe())),
             events,
             speaking,
             tts,
+            generation: Arc::new(AtomicU64::new(0)),
+            prosody: Arc::new(ProsodyMap::default()),
+            mood: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Return this mouth shaping its voice with `prosody`.
+    pub fn with_prosody(mut self, prosody: ProsodyMap) -> Self {
+        self.prosody = Arc::new(prosody);
+        self
+    }
+
+    /// Prosody for `sentence`, updating the mood from emotion events. An
+    /// emoji in the sentence colours that sentence only.
+    fn prosody_for(&self, sentence: &str) -> Prosody {
+        let mut mood = self.mood.lock().unwrap();
+        {
+            let mut emotions = self.emotions.lock().unwrap();
+            loop {
+                match emotions.try_recv() {
+                    Ok(Event::EmotionChanged(emoji)) => *mood = Some(emoji),
+                    Ok(_) | Err(broadcast::error::TryRecvError::Lagged(_)) => {}
+                    Err(_) => break,
+                }
+            }
         }
+        extract_emojis(sentence)
+            .1
+            .pop()
+            .or_else(|| mood.clone())
+            .map(|emoji| self.prosody.for_emoji(&emoji))
+            .unwrap_or_default()
     }
 }
 
@@ -136,19 +736,31 @@ impl TtsMouth {
 #[cfg(feature = "tts")]
 impl Mouth for TtsMouth {
     async fn speak(&self, text: &str) {
+        let generation = self.generation.load(Ordering::SeqCst);
+        let interrupted = || self.generation.load(Ordering::SeqCst) != generation;
         self.speaking.store(true, Ordering::SeqCst);
         for sentence in segment_text_into_sentences(text) {
             let sent = sentence.trim();
             if sent.is_empty() {
                 continue;
             }
-            match synthesize_speech_audio(self.tts.as_ref(), sent).await {
-                Ok(audio) => {
+            if interrupted() {
+                break;
+            }
+            let prosody = self.prosody_for(sent);
+            let result = synthesize_speech_with_visemes(self.tts.as_ref(), sent, &prosody).await;
+            if interrupted() {
+                info!(sentence = %sent, "dropping speech synthesized after interruption");
+                break;
+            }
+            match result {
+                Ok(spoken) => {
                     if self
                         .events
                         .send(Event::Speech {
                             text: sent.to_string(),
-                            audio,
+                            audio: spoken.audio,
+                            visemes: spoken.visemes,
                         })
                         .is_err()
                     {
@@ -160,6 +772,7 @@ impl Mouth for TtsMouth {
                     let _ = self.events.send(Event::Speech {
                         text: sent.to_string(),
                         audio: None,
+                        visemes: None,
                     });
                 }
             }
@@ -168,6 +781,7 @@ impl Mouth for TtsMouth {
     }
 
     async fn interrupt(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
         self.speaking.store(false, Ordering::SeqCst);
     }
 
@@ -178,26 +792,26 @@ impl Mouth for TtsMouth {
 
 /// Create the mouth implementation used by the application.
 ///
-/// When the `tts` feature is enabled this wraps a [`TtsMouth`] with
-/// [`PlainMouth`] so Markdown formatting is stripped before speaking.
-/// Otherwise a [`ChannelMouth`] that emits text-only speech events is returned.
+/// When the `tts` feature is enabled this wraps a [`TtsMouth`] speaking
+/// through the backend chosen by `tts` with [`PlainMouth`] so Markdown
+/// formatting is stripped before speaking. Otherwise a [`ChannelMouth`] that
+/// emits text-only speech events is returned.
 pub fn default_mouth(
     bus: Arc<EventBus>,
     speaking: Arc<AtomicBool>,
-    tts_url: String,
-    speaker_id: Option<String>,
-    language_id: Option<String>,
-) -> Arc<dyn Mouth> {
+    tts: &TtsConfig,
+) -> Result<Arc<dyn Mouth>> {
     #[cfg(feature = "tts")]
     {
-        let tts = Arc::new(CoquiTts::new(tts_url, speaker_id, language_id)) as Arc<dyn Tts>;
-        let mouth =
-            Arc::new(TtsMouth::new(bus.event_sender(), speaking.clone(), tts)) as Arc<dyn Mouth>;
-        return Arc::new(PlainMouth::new(mouth)) as Arc<dyn Mouth>;
+        let mouth = Arc::new(
+            TtsMouth::new(bus.event_sender(), speaking.clone(), tts.build()?)
+                .with_prosody(tts.prosody.clone()),
+        ) as Arc<dyn Mouth>;
+        return Ok(Arc::new(PlainMouth::new(mouth)) as Arc<dyn Mouth>);
     }
     #[cfg(not(feature = "tts"))]
     {
-        let _ = (tts_url, speaker_id, language_id);
-        Arc::new(ChannelMouth::new(bus, speaking)) as Arc<dyn Mouth>
+        let _ = tts;
+        Ok(Arc::new(ChannelMouth::new(bus, speaking)) as Arc<dyn Mouth>)
     }
 }
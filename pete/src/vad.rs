@@ -0,0 +1,469 @@
+//! Voice activity detection shared by every audio path.
+//!
+//! The ASR service, the `face` audio line buffer and `forget_silence` all
+//! decide where speech starts and stops with [`Vad`]. Each 20 ms frame is
+//! classified from its energy relative to an adaptive noise floor and from a
+//! coarse spectrum probed with Goertzel filters: voiced speech keeps most of
+//! its energy below 4 kHz and is far from spectrally flat, while fans and hiss
+//! are flat and steady hums are absorbed into the noise floor over time. The
+//! spectrum is averaged with the previous frame's, so the chance dips in
+//! flatness of broadband noise do not pass for speech.
+//! Onsets need a short run of voiced frames, reach back by a pre-roll so the
+//! first syllable is kept, and speech only ends after a hangover of
+//! non-speech frames.
+//!
+//! All thresholds live in [`VadConfig`], loaded from `VAD_*` environment
+//! variables by [`VadConfig::from_env`].
+//!
+//! ```
+//! use pete::vad::{self, VadConfig};
+//!
+//! let rate = 16_000;
+//! let mut clip = vec![0.0f32; rate as usize / 2];
+//! clip.extend((0..rate as usize / 2).map(|i| {
+//!     0.2 * (2.0 * std::f32::consts::PI * 300.0 * i as f32 / rate as f32).sin()
+//! }));
+//! clip.extend(vec![0.0f32; rate as usize / 2]);
+//!
+//! let config = VadConfig::default();
+//! let segments = vad::speech_segments(&clip, rate, &config);
+//! assert_eq!(segments.len(), 1);
+//! assert!(segments[0].start < rate as usize / 2);
+//! ```
+
+use anyhow::Result;
+use common::env_or;
+use std::ops::Range;
+use std::time::Duration;
+
+/// Tunables for [`Vad`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct VadConfig {
+    /// Analysis frame length.
+    pub frame: Duration,
+    /// Minimum frame RMS for speech regardless of the noise floor.
+    pub min_rms: f32,
+    /// Required level above the adaptive noise floor, in dB.
+    pub snr_db: f32,
+    /// Maximum spectral flatness (0 = pure tone, 1 = white noise) for speech.
+    pub max_flatness: f32,
+    /// Minimum share of probed energy inside the speech band.
+    pub min_speech_band_ratio: f32,
+    /// Voiced audio needed before speech is considered started.
+    pub min_speech: Duration,
+    /// Non-speech needed before speech is considered finished.
+    pub hangover: Duration,
+    /// Audio kept before a detected onset and after the last voiced frame.
+    pub pre_roll: Duration,
+    /// Noise floor adaptation rate per non-speech frame (0–1).
+    pub noise_adapt: f32,
+}
+
+impl Default for VadConfig {
+    fn default() -> Self {
+        Self {
+            frame: Duration::from_millis(20),
+            min_rms: 0.015,
+            snr_db: 6.0,
+            max_flatness: 0.5,
+            min_speech_band_ratio: 0.5,
+            min_speech: Duration::from_millis(60),
+            hangover: Duration::from_millis(1_200),
+            pre_roll: Duration::from_millis(200),
+            noise_adapt: 0.05,
+        }
+    }
+}
+
+impl VadConfig {
+    /// Read overrides from `VAD_FRAME_MS`, `VAD_MIN_RMS`, `VAD_SNR_DB`,
+    /// `VAD_MAX_FLATNESS`, `VAD_MIN_SPEECH_BAND_RATIO`, `VAD_MIN_SPEECH_MS`,
+    /// `VAD_HANGOVER_MS`, `VAD_PRE_ROLL_MS` and `VAD_NOISE_ADAPT`.
+    pub fn from_env() -> Result<Self> {
+        let d = Self::default();
+        Ok(Self {
+            frame: Duration::from_millis(
+                env_or("VAD_FRAME_MS", d.frame.as_millis() as u64)?.clamp(10, 50),
+            ),
+            min_rms: env_or("VAD_MIN_RMS", d.min_rms)?.clamp(0.0, 1.0),
+            snr_db: env_or("VAD_SNR_DB", d.snr_db)?.max(0.0),
+            max_flatness: env_or("VAD_MAX_FLATNESS", d.max_flatness)?.clamp(0.0, 1.0),
+            min_speech_band_ratio: env_or("VAD_MIN_SPEECH_BAND_RATIO", d.min_speech_band_ratio)?
+                .clamp(0.0, 1.0),
+            min_speech: Duration::from_millis(env_or(
+                "VAD_MIN_SPEECH_MS",
+                d.min_speech.as_millis() as u64,
+            )?),
+            hangover: Duration::from_millis(
+                env_or("VAD_HANGOVER_MS", d.hangover.as_millis() as u64)?.clamp(100, 10_000),
+            ),
+            pre_roll: Duration::from_millis(env_or(
+                "VAD_PRE_ROLL_MS",
+                d.pre_roll.as_millis() as u64,
+            )?),
+            noise_adapt: env_or("VAD_NOISE_ADAPT", d.noise_adapt)?.clamp(0.0, 1.0),
+        })
+    }
+}
+
+/// Speech boundary reported by [`Vad::push`].
+///
+/// Sample offsets count mono samples since the last [`Vad::reset`] and
+/// already include pre-roll.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VadEvent {
+    SpeechStart { sample: usize },
+    SpeechEnd { sample: usize },
+}
+
+/// Per-frame measurements used for the speech decision.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameFeatures {
+    pub rms: f32,
+    pub flatness: f32,
+    pub speech_band_ratio: f32,
+}
+
+/// Streaming voice activity detector over mono `f32` samples.
+pub struct Vad {
+    config: VadConfig,
+    sample_rate: u32,
+    frame_len: usize,
+    min_speech_frames: usize,
+    hangover_frames: usize,
+    pre_roll: usize,
+    probes: Vec<Probe>,
+    window: Vec<f32>,
+    /// Probe powers of the previous frame.
+    previous_powers: Vec<f32>,
+    pending: Vec<f32>,
+    position: usize,
+    noise_floor: f32,
+    voiced_run: usize,
+    unvoiced_run: usize,
+    speaking: bool,
+    speech_start: usize,
+    last_voiced_end: usize,
+    boundary: Option<usize>,
+    segments: Vec<Range<usize>>,
+}
+
+struct Probe {
+    coeff: f32,
+    in_speech_band: bool,
+}
+
+impl Vad {
+    pub fn new(config: VadConfig, sample_rate: u32) -> Self {
+        let sample_rate = sample_rate.max(1);
+        let samples_for = |d: Duration| (d.as_secs_f32() * sample_rate as f32).round() as usize;
+        let frame_len = samples_for(config.frame).max(1);
+        let frames_for = |d: Duration| samples_for(d).div_ceil(frame_len).max(1);
+        let nyquist = sample_rate as f32 / 2.0;
+        let top = (nyquist * 0.9).min(7_000.0);
+        let probes = if top > 100.0 {
+            (0..16)
+                .map(|i| {
+                    let freq = 100.0 * (top / 100.0).powf(i as f32 / 15.0);
+                    Probe {
+                        coeff: 2.0 * (2.0 * std::f32::consts::PI * freq / sample_rate as f32).cos(),
+                        in_speech_band: freq <= 4_000.0,
+                    }
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let window = (0..frame_len)
+            .map(|i| {
+                let x = std::f32::consts::PI * 2.0 * i as f32 / frame_len.max(2) as f32;
+                0.5 - 0.5 * x.cos()
+            })
+            .collect();
+        Self {
+            min_speech_frames: frames_for(config.min_speech),
+            hangover_frames: frames_for(config.hangover),
+            pre_roll: samples_for(config.pre_roll),
+            noise_floor: config.min_rms / 2.0,
+            config,
+            sample_rate,
+            frame_len,
+            probes,
+            window,
+            previous_powers: Vec::new(),
+            pending: Vec::new(),
+            position: 0,
+            voiced_run: 0,
+            unvoiced_run: 0,
+            speaking: false,
+            speech_start: 0,
+            last_voiced_end: 0,
+            boundary: None,
+            segments: Vec::new(),
+        }
+    }
+
+    pub fn config(&self) -> &VadConfig {
+        &self.config
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Feed samples and return any speech boundaries they complete.
+    pub fn push(&mut self, samples: &[f32]) -> Vec<VadEvent> {
+        let mut events = Vec::new();
+        self.pending.extend_from_slice(samples);
+        let mut offset = 0;
+        while self.pending.len() - offset >= self.frame_len {
+            let frame = self.pending[offset..offset + self.frame_len].to_vec();
+            offset += self.frame_len;
+            self.step(&frame, &mut events);
+        }
+        self.pending.drain(..offset);
+        events
+    }
+
+    /// Flush a trailing partial frame and close any open speech segment.
+    pub fn finish(&mut self) -> Vec<VadEvent> {
+        let mut events = Vec::new();
+        if self.pending.len() * 2 >= self.frame_len {
+            let mut frame = std::mem::take(&mut self.pending);
+            let real = frame.len();
+            frame.resize(self.frame_len, 0.0);
+            self.step(&frame, &mut events);
+            self.position = self.position + real - self.frame_len;
+            self.last_voiced_end = self.last_voiced_end.min(self.position);
+        }
+        self.pending.clear();
+        if self.speaking {
+            self.end_speech(&mut events);
+        }
+        events
+    }
+
+    /// `true` while inside a speech segment.
+    pub fn is_speaking(&self) -> bool {
+        self.speaking
+    }
+
+    /// Non-speech audio at the end of the input, in samples.
+    pub fn trailing_silence(&self) -> usize {
+        self.unvoiced_run * self.frame_len
+    }
+
+    /// `true` once trailing non-speech has lasted for the hangover.
+    pub fn is_paused(&self) -> bool {
+        self.unvoiced_run >= self.hangover_frames
+    }
+
+    /// Sample at which non-speech first lasted for the hangover since the last
+    /// reset; a natural place to cut an utterance.
+    pub fn boundary_sample(&self) -> Option<usize> {
+        self.boundary
+    }
+
+    /// Speech segments completed so far.
+    pub fn segments(&self) -> &[Range<usize>] {
+        &self.segments
+    }
+
+    /// Forget positions and segments but keep the learned noise floor.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.position = 0;
+        self.voiced_run = 0;
+        self.unvoiced_run = 0;
+        self.speaking = false;
+        self.speech_start = 0;
+        self.last_voiced_end = 0;
+        self.boundary = None;
+        self.segments.clear();
+    }
+
+    /// Measure one frame on its own without updating any state; speech
+    /// decisions also average in the previous frame's spectrum.
+    pub fn features(&self, frame: &[f32]) -> FrameFeatures {
+        self.features_of(rms(frame), &self.probe_powers(frame))
+    }
+
+    fn probe_powers(&self, frame: &[f32]) -> Vec<f32> {
+        self.probes
+            .iter()
+            .map(|probe| {
+                let (mut s1, mut s2) = (0.0f32, 0.0f32);
+                for (x, w) in frame.iter().zip(&self.window) {
+                    let s0 = x * w + probe.coeff * s1 - s2;
+                    s2 = s1;
+                    s1 = s0;
+                }
+                (s1 * s1 + s2 * s2 - probe.coeff * s1 * s2).max(0.0)
+            })
+            .collect()
+    }
+
+    fn features_of(&self, rms: f32, powers: &[f32]) -> FrameFeatures {
+        if powers.is_empty() || rms == 0.0 {
+            return FrameFeatures {
+                rms,
+                flatness: 1.0,
+                speech_band_ratio: 0.0,
+            };
+        }
+        let total: f32 = powers.iter().sum();
+        if total <= f32::EPSILON {
+            return FrameFeatures {
+                rms,
+                flatness: 1.0,
+                speech_band_ratio: 0.0,
+            };
+        }
+        let eps = total * 1e-6 + f32::MIN_POSITIVE;
+        let mean = total / powers.len() as f32;
+        let log_mean = powers.iter().map(|p| (p + eps).ln()).sum::<f32>() / powers.len() as f32;
+        let band: f32 = powers
+            .iter()
+            .zip(&self.probes)
+            .filter(|(_, probe)| probe.in_speech_band)
+            .map(|(p, _)| p)
+            .sum();
+        FrameFeatures {
+            rms,
+            flatness: (log_mean.exp() / mean).clamp(0.0, 1.0),
+            speech_band_ratio: band / total,
+        }
+    }
+
+    fn is_voiced(&mut self, frame: &[f32]) -> bool {
+        let powers = self.probe_powers(frame);
+        let averaged: Vec<f32> = if self.previous_powers.len() == powers.len() {
+            powers
+                .iter()
+                .zip(&self.previous_powers)
+                .map(|(now, before)| (now + before) / 2.0)
+                .collect()
+        } else {
+            powers.clone()
+        };
+        self.previous_powers = powers;
+        let f = self.features_of(rms(frame), &averaged);
+        let floor = self.noise_floor.max(1e-6);
+        let snr_db = 20.0 * (f.rms.max(1e-9) / floor).log10();
+        let voiced = f.rms >= self.config.min_rms
+            && snr_db >= self.config.snr_db
+            && f.flatness <= self.config.max_flatness
+            && f.speech_band_ratio >= self.config.min_speech_band_ratio;
+        // Track quiet quickly, follow persistent sounds slowly so a fan or
+        // background music stops counting as speech.
+        let rate = if voiced {
+            self.config.noise_adapt / 10.0
+        } else {
+            self.config.noise_adapt
+        };
+        if f.rms < self.noise_floor {
+            self.noise_floor = f.rms.max(1e-6);
+        } else {
+            self.noise_floor += rate * (f.rms - self.noise_floor);
+        }
+        voiced
+    }
+
+    fn step(&mut self, frame: &[f32], events: &mut Vec<VadEvent>) {
+        let voiced = self.is_voiced(frame);
+        let start = self.position;
+        self.position += self.frame_len;
+        if voiced {
+            self.voiced_run += 1;
+            self.unvoiced_run = 0;
+            self.last_voiced_end = self.position;
+            if !self.speaking && self.voiced_run >= self.min_speech_frames {
+                let onset = start + self.frame_len - self.voiced_run * self.frame_len;
+                let floor = self.segments.last().map_or(0, |s| s.end);
+                self.speech_start = onset.saturating_sub(self.pre_roll).max(floor);
+                self.speaking = true;
+                events.push(VadEvent::SpeechStart {
+                    sample: self.speech_start,
+                });
+            }
+        } else {
+            self.voiced_run = 0;
+            self.unvoiced_run += 1;
+            if self.unvoiced_run >= self.hangover_frames {
+                if self.speaking {
+                    self.end_speech(events);
+                }
+                if self.boundary.is_none() {
+                    self.boundary = Some(self.position);
+                }
+            }
+        }
+    }
+
+    fn end_speech(&mut self, events: &mut Vec<VadEvent>) {
+        let end = (self.last_voiced_end + self.pre_roll).min(self.position);
+        self.speaking = false;
+        self.segments.push(self.speech_start..end);
+        events.push(VadEvent::SpeechEnd { sample: end });
+    }
+}
+
+/// Speech ranges in a complete mono clip.
+pub fn speech_segments(samples: &[f32], sample_rate: u32, config: &VadConfig) -> Vec<Range<usize>> {
+    let mut vad = Vad::new(config.clone(), sample_rate);
+    vad.push(samples);
+    vad.finish();
+    vad.segments
+        .into_iter()
+        .map(|s| s.start.min(samples.len())..s.end.min(samples.len()))
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// `true` if a complete mono clip contains any speech.
+pub fn contains_speech(samples: &[f32], sample_rate: u32, config: &VadConfig) -> bool {
+    !speech_segments(samples, sample_rate, config).is_empty()
+}
+
+/// Span from the first to the last speech segment, if any.
+pub fn speech_span(samples: &[f32], sample_rate: u32, config: &VadConfig) -> Option<Range<usize>> {
+    let segments = speech_segments(samples, sample_rate, config);
+    Some(segments.first()?.start..segments.last()?.end)
+}
+
+/// Cut leading and trailing non-speech, keeping pre-roll around the speech.
+///
+/// Returns the kept samples and how many were dropped from the front; an
+/// empty result means the clip held no speech.
+pub fn trim_silence(samples: &[f32], sample_rate: u32, config: &VadConfig) -> (Vec<f32>, usize) {
+    match speech_span(samples, sample_rate, config) {
+        Some(span) => (samples[span.clone()].to_vec(), span.start),
+        None => (Vec::new(), samples.len()),
+    }
+}
+
+/// Average interleaved channels into mono.
+pub fn downmix(samples: &[f32], channels: usize) -> Vec<f32> {
+    if channels <= 1 {
+        return samples.to_vec();
+    }
+    samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect()
+}
+
+/// Root mean square level of `samples`.
+pub fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum_sq = samples
+        .iter()
+        .map(|s| {
+            let v = f64::from(*s);
+            v * v
+        })
+        .sum::<f64>();
+    (sum_sq / samples.len() as f64).sqrt() as f32
+}
@@ -1,11 +1,11 @@
 use axum::{
     Json, Router,
     extract::{
-        Path, State,
+        Path, Query, State,
         ws::{Message as WsMessage, WebSocket, WebSocketUpgrade},
     },
-    http::StatusCode,
-    response::{Html, IntoResponse},
+    http::{StatusCode, header},
+    response::{Html, IntoResponse, Response},
     routing::{get, get_service},
 };
 #[cfg(feature = "asr")]
@@ -27,7 +27,7 @@ use tracing::{debug, error, info, trace};
 
 use crate::EventBus;
 use lingproc::Role;
-use psyche::{BrowserMotion, Ear, Event, GeoLoc, ImageData, Sensor};
+use psyche::{BrowserMotion, Ear, EchoAction, Event, GeoLoc, ImageData, Sensor};
 
 /// PETE's interface to the world — his `Body`.
 ///
@@ -60,6 +60,7 @@ pub struct Body {
     pub connections: Arc<AtomicUsize>,
     pub system_prompt: Arc<tokio::sync::Mutex<String>>,
     pub psyche_debug: psyche::DebugHandle,
+    pub pipeline: Arc<crate::PipelineGraph>,
 }
 
 pub type WsRequest = WsPayload;
@@ -90,17 +91,42 @@ async fn handle_socket(mut socket: WebSocket, state: Body) {
     state.connections.fetch_add(1, Ordering::SeqCst);
     let mut events = state.bus.subscribe_events();
     let mut wits = state.bus.subscribe_wits();
-    let (asr_text_tx, mut asr_text_rx) = mpsc::channel::<(String, DateTime<Utc>)>(64);
+    let (asr_text_tx, mut asr_text_rx) =
+        mpsc::channel::<(String, Vec<psyche::HeardWord>, DateTime<Utc>)>(64);
+    let (asr_interim_tx, mut asr_interim_rx) = mpsc::channel::<(String, bool, DateTime<Utc>)>(16);
     let mut asr_open = false;
+    let mut asr_interim_open = false;
     #[cfg(feature = "asr")]
     let asr_pcm_tx = if let Some(asr) = state.asr.clone() {
-        let (pcm_tx, mut transcript_rx) = asr.spawn_connection();
+        let (pcm_tx, mut transcript_rx, mut interim_rx) = asr.spawn_connection();
         let text_tx = asr_text_tx.clone();
         asr_open = true;
+        if asr.interim_enabled() {
+            let interim_tx = asr_interim_tx.clone();
+            asr_interim_open = true;
+            tokio::spawn(async move {
+                while let Some(interim) = interim_rx.recv().await {
+                    let text = interim.text.trim().to_string();
+                    if !text.is_empty()
+                        && interim_tx
+                            .send((text, interim.stable, interim.occurred_at))
+                            .await
+                            .is_err()
+                    {
+                        break;
+                    }
+                }
+            });
+        }
         tokio::spawn(async move {
             while let Some(transcript) = transcript_rx.recv().await {
                 let text = transcript.text.trim().to_string();
-                if !text.is_empty() && text_tx.send((text, transcript.occurred_at)).await.is_err() {
+                if !text.is_empty()
+                    && text_tx
+                        .send((text, transcript.heard_words(), transcript.occurred_at))
+                        .await
+                        .is_err()
+                {
                     break;
                 }
             }
@@ -109,7 +135,10 @@ async fn handle_socket(mut socket: WebSocket, state: Body) {
     } else {
         None
     };
+    #[cfg(feature = "asr")]
+    let mut hear_decoder: Option<crate::codec::CompressedAudioDecoder> = None;
     drop(asr_text_tx);
+    drop(asr_interim_tx);
     let prompt = state.system_prompt.lock().await.clone();
     let _ = socket
         .send(WsMessage::Text(
@@ -139,8 +168,8 @@ async fn handle_socket(mut socket: WebSocket, state: Body) {
         tokio::select! {
             evt = events.recv() => {
                 match evt {
-                    Ok(Event::Speech { text, audio }) => {
-                        let payload = serde_json::to_string(&WsResponse::Say { words: text.clone(), audio }).unwrap();
+                    Ok(Event::Speech { text, audio, visemes }) => {
+                        let payload = serde_json::to_string(&WsResponse::Say { words: text.clone(), audio, visemes }).unwrap();
                         if socket.send(WsMessage::Text(payload.into())).await.is_err() {
                             error!("failed sending speech");
                             break;
@@ -168,17 +197,35 @@ async fn handle_socket(mut socket: WebSocket, state: Body) {
                             break;
                         }
                     }
+                    Ok(Event::SpeechInterrupted { spoken }) => {
+                        let payload = serde_json::to_string(&WsResponse::SpeechPlayback {
+                            text: spoken,
+                            status: shared::SpeechPlaybackStatus::Interrupted,
+                            at: Some(Utc::now().to_rfc3339()),
+                        })
+                        .unwrap();
+                        if socket.send(WsMessage::Text(payload.into())).await.is_err() {
+                            error!("failed sending speech interruption");
+                            break;
+                        }
+                    }
                     Err(broadcast::error::RecvError::Closed) => break,
                     Err(broadcast::error::RecvError::Lagged(_)) => continue,
                 }
             }
             , transcript = asr_text_rx.recv(), if asr_open => {
                 match transcript {
-                    Some((text, occurred_at)) => {
+                    Some((text, words, occurred_at)) => {
                         info!(%text, "asr finalized transcript");
-                        state.ear.hear_user_say_at(&text, occurred_at).await;
+                        let action = state.ear.hear_user_words_at(&text, &words, occurred_at).await;
+                        // Echoes of Pete's voice are shown as his line, if at all.
+                        let role = match action {
+                            None => "user",
+                            Some(EchoAction::Reclassify) => "assistant",
+                            Some(EchoAction::Drop) => continue,
+                        };
                         let entry = ConvEntry {
-                            role: "user".into(),
+                            role: role.into(),
                             content: text,
                             timestamp: occurred_at.to_rfc3339(),
                         };
@@ -192,6 +239,26 @@ async fn handle_socket(mut socket: WebSocket, state: Body) {
                     }
                 }
             }
+            , interim = asr_interim_rx.recv(), if asr_interim_open => {
+                match interim {
+                    Some((text, stable, occurred_at)) => {
+                        trace!(%text, stable, "asr interim transcript");
+                        state.ear.hear_user_partial_at(&text, stable, occurred_at).await;
+                        let msg = serde_json::to_string(&WsResponse::Interim {
+                            text,
+                            stable,
+                            at: Some(occurred_at.to_rfc3339()),
+                        })
+                        .unwrap();
+                        if socket.send(WsMessage::Text(msg.into())).await.is_err() {
+                            break;
+                        }
+                    }
+                    None => {
+                        asr_interim_open = false;
+                    }
+                }
+            }
             , wit = wits.recv() => {
                 if let Ok(report) = wit {
                     let msg = serde_json::to_string(&WsResponse::Think(report)).unwrap();
@@ -225,20 +292,27 @@ async fn handle_socket(mut socket: WebSocket, state: Body) {
                                     trace!(text_len = text.len(), "played ack received");
                                     state.ear.hear_self_say_at(&text, occurred_at).await;
                                 }
-                                WsRequest::See { data, at } => {
+                                WsRequest::See { data, at, source } => {
                                     if let Some((mime, base64)) = parse_data_url(&data) {
                                         if base64.trim().is_empty() {
                                             trace!("blank image ignored");
-                                            state.eye.sense(ImageData { mime, base64: String::new(), captured_at: at }).await;
+                                            state.eye.sense(ImageData { mime, base64: String::new(), captured_at: at, source }).await;
                                         } else {
                                             trace!("image received");
-                                            state.eye.sense(ImageData { mime, base64, captured_at: at }).await;
+                                            state.eye.sense(ImageData { mime, base64, captured_at: at, source }).await;
                                         }
                                     }
                                 }
                                 WsRequest::Hear { data, at } => {
                                     #[cfg(feature = "asr")]
-                                    handle_hear_frame(&data, at.as_deref(), &asr_pcm_tx).await;
+                                    handle_hear_frame(
+                                        &data,
+                                        at.as_deref(),
+                                        &asr_pcm_tx,
+                                        &mut hear_decoder,
+                                        state.asr.as_ref().map_or(16_000, |asr| asr.sample_rate()),
+                                    )
+                                    .await;
                                     #[cfg(not(feature = "asr"))]
                                     {
                                         let _ = at;
@@ -324,7 +398,11 @@ fn parse_flat_ws_request(text: &str) -> Option<WsRequest> {
                 .get("at")
                 .and_then(|at| at.as_str())
                 .map(ToString::to_string);
-            Some(WsRequest::See { data, at })
+            let source = value
+                .get("source")
+                .and_then(|source| source.as_str())
+                .map(ToString::to_string);
+            Some(WsRequest::See { data, at, source })
         }
         "Hear" => {
             let data = serde_json::from_value(value.get("data")?.clone()).ok()?;
@@ -371,6 +449,8 @@ async fn handle_hear_frame(
     data: &shared::AudioData,
     at: Option<&str>,
     asr_pcm_tx: &Option<mpsc::Sender<crate::asr::AudioChunk>>,
+    decoder: &mut Option<crate::codec::CompressedAudioDecoder>,
+    sample_rate: u32,
 ) {
     let Some(tx) = asr_pcm_tx else {
         trace!(
@@ -380,16 +460,17 @@ async fn handle_hear_frame(
         );
         return;
     };
-    if !is_pcm_mime(&data.mime) {
+    let compressed = crate::codec::is_compressed_mime(&data.mime);
+    if !compressed && !is_pcm_mime(&data.mime) {
         debug!(
             mime = %data.mime,
             bytes = data.base64.len(),
-            "audio fragment ignored; expected 16-bit mono PCM"
+            "audio fragment ignored; expected 16-bit mono PCM or Opus"
         );
         return;
     }
     if let Some(channels) = data.channels {
-        if channels != 1 {
+        if channels != 1 && !compressed {
             warn!(channels, "ASR expects mono PCM; forwarding chunk anyway");
         }
     }
@@ -403,6 +484,19 @@ async fn handle_hear_frame(
     if bytes.is_empty() {
         return;
     }
+    let bytes = if compressed {
+        match decode_hear_fragment(&bytes, &data.mime, decoder, sample_rate) {
+            Ok(bytes) if bytes.is_empty() => return,
+            Ok(bytes) => bytes,
+            Err(error) => {
+                warn!(%error, mime = %data.mime, "failed to decode compressed audio fragment");
+                *decoder = None;
+                return;
+            }
+        }
+    } else {
+        bytes
+    };
     let chunk = crate::asr::AudioChunk {
         bytes,
         captured_at: parse_ws_at(at),
@@ -418,6 +512,26 @@ async fn handle_hear_frame(
     }
 }
 
+/// Decode the next fragment of a browser recording to PCM at the ASR rate,
+/// starting a new decoder whenever the fragment opens a new stream.
+#[cfg(feature = "asr")]
+fn decode_hear_fragment(
+    bytes: &[u8],
+    mime: &str,
+    decoder: &mut Option<crate::codec::CompressedAudioDecoder>,
+    sample_rate: u32,
+) -> anyhow::Result<Vec<u8>> {
+    let decoder = match decoder.take() {
+        Some(current) if !crate::codec::starts_stream(bytes) => decoder.insert(current),
+        _ => decoder.insert(crate::codec::CompressedAudioDecoder::new(
+            mime,
+            sample_rate,
+        )?),
+    };
+    let samples = decoder.push(bytes)?;
+    Ok(crate::codec::encode_pcm_s16le(&samples))
+}
+
 #[cfg(feature = "asr")]
 fn is_pcm_mime(mime: &str) -> bool {
     let lower = mime.to_ascii_lowercase();
@@ -484,6 +598,29 @@ pub async fn psyche_debug(State(state): State<Body>) -> impl IntoResponse {
     axum::Json(info)
 }
 
+#[derive(Deserialize)]
+pub struct PipelineQuery {
+    #[serde(default)]
+    format: Option<String>,
+}
+
+/// Render the configured wit/topic graph as JSON, or as Graphviz DOT with
+/// `?format=dot`.
+pub async fn pipeline_graph(
+    State(state): State<Body>,
+    Query(query): Query<PipelineQuery>,
+) -> Response {
+    if query.format.as_deref() == Some("dot") {
+        (
+            [(header::CONTENT_TYPE, "text/vnd.graphviz")],
+            state.pipeline.to_dot(),
+        )
+            .into_response()
+    } else {
+        axum::Json(state.pipeline.as_ref().clone()).into_response()
+    }
+}
+
 #[derive(Deserialize)]
 pub struct ToggleDebug {
     enable: bool,
@@ -571,6 +708,7 @@ pub fn app(state: Body) -> Router {
             get(wit_debug_page).post(toggle_wit_debug),
         )
         .route("/debug/psyche", get(psyche_debug))
+        .route("/debug/pipeline", get(pipeline_graph))
         .route("/conversation", get(conversation_log))
         .fallback_service(
             get_service(ServeDir::new("frontend/dist"))
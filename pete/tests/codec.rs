@@ -0,0 +1,291 @@
+use pete::codec::{
+    OpusDemuxer, OpusPacket, Resampler, encode_pcm_s16le, is_compressed_mime, resample,
+    reshape_wav, starts_stream, wav_duration,
+};
+use std::f32::consts::PI;
+
+fn opus_head(pre_skip: u16) -> Vec<u8> {
+    let mut head = b"OpusHead".to_vec();
+    head.extend([1, 1]);
+    head.extend(pre_skip.to_le_bytes());
+    head.extend(48_000u32.to_le_bytes());
+    head.extend([0, 0, 0]);
+    head
+}
+
+/// One Ogg page holding `packets`; the checksum is left zero because the
+/// demuxer does not verify it.
+fn ogg_page(flags: u8, sequence: u32, packets: &[&[u8]]) -> Vec<u8> {
+    let mut table = Vec::new();
+    let mut body = Vec::new();
+    for packet in packets {
+        table.extend(std::iter::repeat_n(255, packet.len() / 255));
+        table.push((packet.len() % 255) as u8);
+        body.extend_from_slice(packet);
+    }
+    let mut page = b"OggS".to_vec();
+    page.extend([0, flags]);
+    page.extend(0u64.to_le_bytes());
+    page.extend(7u32.to_le_bytes());
+    page.extend(sequence.to_le_bytes());
+    page.extend(0u32.to_le_bytes());
+    page.push(table.len() as u8);
+    page.extend(table);
+    page.extend(body);
+    page
+}
+
+fn ebml(id: &[u8], body: &[u8]) -> Vec<u8> {
+    let mut element = id.to_vec();
+    element.extend([0x01, 0, 0, 0, 0, 0, 0, 0]);
+    element[id.len() + 1..].copy_from_slice(&(body.len() as u64).to_be_bytes()[1..]);
+    element.extend_from_slice(body);
+    element
+}
+
+fn simple_block(track: u8, frame: &[u8]) -> Vec<u8> {
+    let mut body = vec![0x80 | track, 0, 0, 0x80];
+    body.extend_from_slice(frame);
+    ebml(&[0xA3], &body)
+}
+
+#[test]
+fn recognizes_compressed_mime_types() {
+    assert!(is_compressed_mime("audio/webm;codecs=opus"));
+    assert!(is_compressed_mime("audio/ogg; codecs=opus"));
+    assert!(is_compressed_mime("Audio/Ogg"));
+    assert!(!is_compressed_mime("audio/pcm;format=s16le;rate=16000"));
+    assert!(!is_compressed_mime("audio/wav"));
+}
+
+#[test]
+fn ogg_packets_survive_arbitrary_fragment_boundaries() {
+    let big = vec![7u8; 600];
+    let mut stream = ogg_page(0x02, 0, &[&opus_head(312)]);
+    stream.extend(ogg_page(0, 1, &[b"OpusTags\0\0\0\0"]));
+    stream.extend(ogg_page(0, 2, &[&[1, 2, 3], &big, &[4]]));
+    assert!(starts_stream(&stream));
+    assert!(!starts_stream(&stream[27..]));
+
+    let mut demuxer = OpusDemuxer::new("audio/ogg;codecs=opus");
+    let mut packets = Vec::new();
+    for fragment in stream.chunks(50) {
+        packets.extend(demuxer.push(fragment).unwrap());
+    }
+    assert_eq!(
+        packets,
+        vec![
+            OpusPacket::Head {
+                channels: 1,
+                pre_skip: 312,
+            },
+            OpusPacket::Audio(vec![1, 2, 3]),
+            OpusPacket::Audio(big),
+            OpusPacket::Audio(vec![4]),
+        ]
+    );
+}
+
+#[test]
+fn webm_blocks_stream_from_unknown_size_clusters() {
+    let mut header = ebml(&[0x1A, 0x45, 0xDF, 0xA3], &ebml(&[0x42, 0x82], b"webm"));
+    // Segment of unknown size, as MediaRecorder writes it.
+    header.extend([
+        0x18, 0x53, 0x80, 0x67, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    ]);
+    let mut entry = ebml(&[0xD7], &[1]);
+    entry.extend(ebml(&[0x86], b"A_OPUS"));
+    entry.extend(ebml(&[0x63, 0xA2], &opus_head(0)));
+    header.extend(ebml(&[0x16, 0x54, 0xAE, 0x6B], &ebml(&[0xAE], &entry)));
+
+    let mut cluster = vec![0x1F, 0x43, 0xB6, 0x75, 0xFF];
+    cluster.extend(ebml(&[0xE7], &[0]));
+    cluster.extend(simple_block(1, &[9, 9]));
+    cluster.extend(simple_block(2, &[0xEE]));
+    cluster.extend(simple_block(1, &[8]));
+
+    let mut demuxer = OpusDemuxer::new("audio/webm");
+    let mut packets = demuxer.push(&header).unwrap();
+    let (first, second) = cluster.split_at(11);
+    packets.extend(demuxer.push(first).unwrap());
+    packets.extend(demuxer.push(second).unwrap());
+    assert_eq!(
+        packets,
+        vec![
+            OpusPacket::Head {
+                channels: 1,
+                pre_skip: 0,
+            },
+            OpusPacket::Audio(vec![9, 9]),
+            OpusPacket::Audio(vec![8]),
+        ]
+    );
+}
+
+#[test]
+fn webm_rejects_other_audio_codecs() {
+    let mut stream = ebml(&[0x1A, 0x45, 0xDF, 0xA3], &[]);
+    stream.extend(ebml(&[0x86], b"A_VORBIS"));
+    assert!(OpusDemuxer::new("audio/webm").push(&stream).is_err());
+}
+
+#[test]
+fn resampling_keeps_tone_and_length() {
+    let tone = |rate: u32, seconds: f32| -> Vec<f32> {
+        (0..(rate as f32 * seconds) as usize)
+            .map(|i| 0.5 * (2.0 * PI * 440.0 * i as f32 / rate as f32).sin())
+            .collect()
+    };
+    let input = tone(48_000, 0.5);
+    let mut resampler = Resampler::new(48_000, 16_000);
+    let mut streamed = Vec::new();
+    for block in input.chunks(960) {
+        streamed.extend(resampler.process(block));
+    }
+    streamed.extend(resampler.finish());
+    assert_eq!(streamed, resample(&input, 48_000, 16_000));
+    assert_eq!(streamed.len(), 8_000);
+
+    let expected = tone(16_000, 0.5);
+    let error = streamed[200..7_800]
+        .iter()
+        .zip(&expected[200..7_800])
+        .map(|(a, b)| (a - b).abs())
+        .fold(0.0, f32::max);
+    assert!(error < 0.01, "max error {error}");
+}
+
+#[test]
+fn pcm_encoding_clamps() {
+    assert_eq!(
+        encode_pcm_s16le(&[0.0, 2.0, -2.0]),
+        [0, 0, 0xFF, 0x7F, 0x01, 0x80]
+    );
+}
+
+fn wav(rate: u32, data_size: u32, samples: usize) -> Vec<u8> {
+    let mut wav = b"RIFF\0\0\0\0WAVEfmt ".to_vec();
+    wav.extend(16u32.to_le_bytes());
+    wav.extend([1, 0, 1, 0]);
+    wav.extend(rate.to_le_bytes());
+    wav.extend((rate * 2).to_le_bytes());
+    wav.extend([2, 0, 16, 0]);
+    wav.extend(b"LIST");
+    wav.extend(3u32.to_le_bytes());
+    wav.extend(b"abc\0");
+    wav.extend(b"data");
+    wav.extend(data_size.to_le_bytes());
+    wav.extend(vec![0; samples * 2]);
+    wav
+}
+
+#[test]
+fn wav_duration_reads_header_and_tolerates_streamed_sizes() {
+    assert_eq!(
+        wav_duration(&wav(16_000, 16_000, 8_000))
+            .unwrap()
+            .as_millis(),
+        500
+    );
+    assert_eq!(
+        wav_duration(&wav(22_050, u32::MAX, 22_050))
+            .unwrap()
+            .as_millis(),
+        1_000
+    );
+    assert_eq!(
+        wav_duration(&wav(8_000, 0, 2_000)).unwrap().as_millis(),
+        250
+    );
+    assert!(wav_duration(b"not a wav file").is_none());
+}
+
+/// A 16 kHz mono WAV of `seconds` of a `hz` tone at amplitude `level`.
+fn tone_wav(hz: f32, seconds: f32, level: f32) -> Vec<u8> {
+    let samples: Vec<f32> = (0..(16_000.0 * seconds) as usize)
+        .map(|n| level * (2.0 * PI * hz * n as f32 / 16_000.0).sin())
+        .collect();
+    let pcm = encode_pcm_s16le(&samples);
+    let mut wav = wav(16_000, pcm.len() as u32, 0);
+    wav.extend(pcm);
+    wav
+}
+
+/// Samples of a WAV written by `reshape_wav`, whose header is 44 bytes.
+fn wav_samples(wav: &[u8]) -> Vec<f32> {
+    wav[44..]
+        .chunks_exact(2)
+        .map(|pair| f32::from(i16::from_le_bytes([pair[0], pair[1]])) / i16::MAX as f32)
+        .collect()
+}
+
+/// Frequency of a tone from its zero crossings, skipping the edges.
+fn tone_hz(samples: &[f32]) -> f32 {
+    let middle = &samples[samples.len() / 10..samples.len() * 9 / 10];
+    let crossings = middle
+        .windows(2)
+        .filter(|pair| (pair[0] < 0.0) != (pair[1] < 0.0))
+        .count();
+    crossings as f32 / 2.0 / (middle.len() as f32 / 16_000.0)
+}
+
+#[test]
+fn reshaping_speeds_up_speech_without_raising_its_pitch() {
+    let faster = reshape_wav(&tone_wav(220.0, 1.0, 0.5), 2.0, 0.0, 1.0).unwrap();
+
+    let millis = wav_duration(&faster).unwrap().as_millis();
+    assert!((490..=510).contains(&millis), "{millis} ms");
+    let hz = tone_hz(&wav_samples(&faster));
+    assert!((hz - 220.0).abs() < 10.0, "{hz} Hz");
+}
+
+#[test]
+fn reshaping_raises_pitch_without_changing_length() {
+    let higher = reshape_wav(&tone_wav(220.0, 1.0, 0.5), 1.0, 12.0, 1.0).unwrap();
+
+    let millis = wav_duration(&higher).unwrap().as_millis();
+    assert!((990..=1_010).contains(&millis), "{millis} ms");
+    let hz = tone_hz(&wav_samples(&higher));
+    assert!((hz - 440.0).abs() < 15.0, "{hz} Hz");
+}
+
+#[test]
+fn reshaping_scales_loudness_and_clips() {
+    let louder = wav_samples(&reshape_wav(&tone_wav(220.0, 0.5, 0.25), 1.0, 0.0, 2.0).unwrap());
+    let peak = louder.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+    assert!((peak - 0.5).abs() < 0.01, "{peak}");
+
+    let clipped = wav_samples(&reshape_wav(&tone_wav(220.0, 0.5, 0.5), 1.0, 0.0, 4.0).unwrap());
+    assert!(clipped.iter().all(|s| s.abs() <= 1.0));
+    assert!(reshape_wav(b"RIFF", 1.5, 0.0, 1.0).is_none());
+}
+
+#[cfg(feature = "opus")]
+#[test]
+fn decodes_ogg_opus_to_asr_rate() {
+    use pete::codec::decode_compressed_audio;
+
+    let mut encoder =
+        opus::Encoder::new(48_000, opus::Channels::Mono, opus::Application::Voip).unwrap();
+    let mut stream = ogg_page(0x02, 0, &[&opus_head(0)]);
+    for (index, frame) in (0..50)
+        .map(|_| vec![0.1f32; 960])
+        .enumerate()
+        .map(|(index, frame)| (index, encoder.encode_vec_float(&frame, 4_000).unwrap()))
+    {
+        stream.extend(ogg_page(0, index as u32 + 1, &[&frame]));
+    }
+
+    let samples = decode_compressed_audio(&stream, "audio/ogg", 16_000).unwrap();
+    assert_eq!(samples.len(), 16_000);
+}
+
+#[cfg(not(feature = "opus"))]
+#[test]
+fn compressed_audio_needs_the_opus_feature() {
+    use pete::codec::decode_compressed_audio;
+
+    let stream = ogg_page(0x02, 0, &[&opus_head(0)]);
+    let err = decode_compressed_audio(&stream, "audio/ogg", 16_000).unwrap_err();
+    assert!(err.to_string().contains("`opus` feature"), "{err}");
+}
@@ -38,6 +38,7 @@ async fn returns_log_json() {
         connections: Arc::new(AtomicUsize::new(1)),
         system_prompt: Arc::new(tokio::sync::Mutex::new(psyche.system_prompt())),
         psyche_debug: debug,
+        pipeline: Default::default(),
     };
     let resp = conversation_log(State(state)).await.into_response();
     let body = body::to_bytes(resp.into_body(), usize::MAX).await.unwrap();
@@ -81,6 +82,7 @@ async fn debug_mode_includes_timestamps() {
         connections: Arc::new(AtomicUsize::new(1)),
         system_prompt: Arc::new(tokio::sync::Mutex::new(psyche.system_prompt())),
         psyche_debug: debug,
+        pipeline: Default::default(),
     };
     let resp = conversation_log(State(state)).await.into_response();
     let body = body::to_bytes(resp.into_body(), usize::MAX).await.unwrap();
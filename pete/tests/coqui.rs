@@ -68,3 +68,52 @@ async fn coqui_rejects_http_errors() {
     assert!(err.to_string().contains("500"));
     mock.assert_async().await;
 }
+
+/// A 16 kHz mono WAV of a short tone at amplitude `level`.
+fn tone_wav(level: f32) -> Vec<u8> {
+    let samples: Vec<f32> = (0..1_600).map(|n| level * (n as f32 * 0.1).sin()).collect();
+    let pcm = pete::codec::encode_pcm_s16le(&samples);
+    let mut wav = b"RIFF".to_vec();
+    wav.extend((36 + pcm.len() as u32).to_le_bytes());
+    wav.extend(b"WAVEfmt ");
+    wav.extend(16u32.to_le_bytes());
+    wav.extend([1, 0, 1, 0]);
+    wav.extend(16_000u32.to_le_bytes());
+    wav.extend(32_000u32.to_le_bytes());
+    wav.extend([2, 0, 16, 0]);
+    wav.extend(b"data");
+    wav.extend((pcm.len() as u32).to_le_bytes());
+    wav.extend(pcm);
+    wav
+}
+
+#[tokio::test]
+async fn coqui_applies_prosody_volume_to_the_returned_wav() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/api/tts");
+            then.status(200).body(tone_wav(0.25));
+        })
+        .await;
+
+    let prosody = psyche::Prosody {
+        volume: 2.0,
+        ..Default::default()
+    };
+    let tts = CoquiTts::new(server.url("/api/tts"), None, None);
+    let request = pete::TtsRequest::new("hi").with_prosody(prosody);
+    let mut stream = tts.stream_request(&request).await.unwrap();
+    let mut wav = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        wav.extend(chunk.unwrap());
+    }
+
+    assert_eq!(wav.len(), tone_wav(0.5).len());
+    let peak = wav[44..]
+        .chunks_exact(2)
+        .map(|pair| i16::from_le_bytes([pair[0], pair[1]]).unsigned_abs())
+        .max()
+        .unwrap();
+    assert!((16_000..=16_500).contains(&peak), "{peak}");
+}
@@ -0,0 +1,209 @@
+use pete::diarize::{
+    self, DiarizeConfig, SpeakerTurn, attribute_span, cluster_embeddings, speaker_centroids,
+    speaker_turns,
+};
+use pete::vad::VadConfig;
+use psyche::{GraphDiarizationSegment, GraphDiarizedSpeaker, GraphVoiceIdentity};
+use std::f32::consts::PI;
+
+const RATE: u32 = 16_000;
+
+fn voiced(seconds: f32, f0: f32) -> Vec<f32> {
+    (0..(seconds * RATE as f32) as usize)
+        .map(|i| {
+            let t = i as f32 / RATE as f32;
+            (1..=6)
+                .map(|h| 0.08 / h as f32 * (2.0 * PI * f0 * h as f32 * t).sin())
+                .sum()
+        })
+        .collect()
+}
+
+#[test]
+fn windows_cover_each_speech_run() {
+    let mut clip = vec![0.0; RATE as usize];
+    clip.extend(voiced(4.0, 140.0));
+    clip.extend(vec![0.0; 2 * RATE as usize]);
+    clip.extend(voiced(0.2, 220.0));
+    clip.extend(vec![0.0; RATE as usize]);
+
+    let config = DiarizeConfig {
+        min_window: std::time::Duration::from_secs(1),
+        ..DiarizeConfig::default()
+    };
+    let windows = diarize::embedding_windows(&clip, RATE, &VadConfig::default(), &config);
+    assert!(windows.len() >= 4, "{windows:?}");
+    let window = (config.window.as_secs_f32() * RATE as f32) as usize;
+    assert!(windows.iter().all(|w| w.end - w.start == window));
+    assert!(windows.windows(2).all(|w| w[0].start < w[1].start));
+    // The short blip is too brief for a speaker embedding.
+    assert!(
+        windows
+            .iter()
+            .all(|w| w.end <= 5 * RATE as usize + RATE as usize / 2)
+    );
+}
+
+#[test]
+fn clustering_separates_distinct_voices() {
+    let a = vec![1.0, 0.1, 0.0];
+    let b = vec![0.0, 0.2, 1.0];
+    let embeddings = vec![a.clone(), a.clone(), b.clone(), b.clone(), a.clone()];
+    let labels = cluster_embeddings(&embeddings, &DiarizeConfig::default());
+    assert_eq!(labels, vec![0, 0, 1, 1, 0]);
+
+    let centroids = speaker_centroids(&embeddings, &labels);
+    assert_eq!(centroids.len(), 2);
+    assert!(diarize::cosine_similarity(&centroids[0], &a) > 0.99);
+    assert!(diarize::cosine_similarity(&centroids[1], &b) > 0.99);
+}
+
+#[test]
+fn clustering_respects_speaker_limit() {
+    let embeddings = vec![
+        vec![1.0, 0.0, 0.0],
+        vec![0.0, 1.0, 0.0],
+        vec![0.0, 0.0, 1.0],
+    ];
+    let config = DiarizeConfig {
+        max_speakers: 2,
+        ..DiarizeConfig::default()
+    };
+    let labels = cluster_embeddings(&embeddings, &config);
+    assert_eq!(labels.iter().max(), Some(&1));
+}
+
+#[test]
+fn turns_split_overlapping_windows_and_attribute_words() {
+    let second = RATE as usize;
+    let windows = vec![0..second, second / 2..3 * second / 2, second..2 * second];
+    let turns = speaker_turns(&windows, &[0, 0, 1], RATE);
+    assert_eq!(
+        turns,
+        vec![
+            SpeakerTurn {
+                speaker: 0,
+                start_ms: 0,
+                end_ms: 1_250,
+            },
+            SpeakerTurn {
+                speaker: 1,
+                start_ms: 1_250,
+                end_ms: 2_000,
+            },
+        ]
+    );
+
+    assert_eq!(attribute_span(&turns, 100, 400), Some((0, 1.0)));
+    let (speaker, confidence) = attribute_span(&turns, 1_200, 1_500).unwrap();
+    assert_eq!(speaker, 1);
+    assert!(confidence > 0.8);
+    assert_eq!(attribute_span(&turns, 2_500, 2_700), Some((1, 0.0)));
+    assert_eq!(attribute_span(&[], 0, 10), None);
+}
+
+fn segment(index: usize, text: &str, start_ms: u32, end_ms: u32) -> GraphDiarizationSegment {
+    GraphDiarizationSegment {
+        id: format!("big:1:segment:{index}"),
+        index,
+        text: text.into(),
+        start_ms,
+        end_ms,
+        occurred_at: None,
+        ended_at: None,
+    }
+}
+
+#[test]
+fn segments_group_into_named_turns() {
+    let turns = vec![
+        SpeakerTurn {
+            speaker: 0,
+            start_ms: 0,
+            end_ms: 1_000,
+        },
+        SpeakerTurn {
+            speaker: 1,
+            start_ms: 1_000,
+            end_ms: 2_000,
+        },
+    ];
+    let speakers = vec![
+        GraphDiarizedSpeaker {
+            index: 0,
+            voice: Some(GraphVoiceIdentity {
+                voice_id: "voice:1".into(),
+                identity: Some("Alice".into()),
+                person_id: None,
+            }),
+            score: Some(0.9),
+            window_count: 2,
+        },
+        GraphDiarizedSpeaker {
+            index: 1,
+            voice: None,
+            score: None,
+            window_count: 1,
+        },
+    ];
+    let segments = vec![
+        segment(0, "how", 0, 300),
+        segment(1, "are you?", 300, 900),
+        segment(2, "fine", 1_100, 1_500),
+    ];
+
+    let diarization = diarize::attribute_segments(&segments, &turns, speakers);
+    assert_eq!(diarization.attributions.len(), 3);
+    assert_eq!(diarization.turns.len(), 2);
+    assert_eq!(diarization.turns[0].text, "how are you?");
+    assert_eq!(diarization.turns[0].end_ms, 900);
+    assert_eq!(diarization.turns[0].segment_ids.len(), 2);
+    assert_eq!(diarization.turns[1].speaker, 1);
+    assert_eq!(diarization.speakers[0].name(), "Alice");
+    assert_eq!(diarization.speakers[1].name(), "speaker 2");
+}
+
+#[test]
+fn segments_split_between_speakers_at_word_boundaries() {
+    let turns = vec![
+        SpeakerTurn {
+            speaker: 0,
+            start_ms: 0,
+            end_ms: 1_000,
+        },
+        SpeakerTurn {
+            speaker: 1,
+            start_ms: 1_000,
+            end_ms: 2_000,
+        },
+    ];
+    let mut long = segment(0, "okay then yes please", 0, 2_000);
+    long.occurred_at = Some("2026-05-05T12:00:00+00:00".into());
+    long.ended_at = Some("2026-05-05T12:00:02+00:00".into());
+
+    let words = diarize::segment_words(&long);
+    let diarization = diarize::attribute_segments(&[long], &turns, Vec::new());
+
+    assert_eq!(words.len(), 4);
+    assert_eq!(words[1].text, "then");
+    assert_eq!(words[3].end_ms, 2_000);
+    assert_eq!(diarization.attributions.len(), 1);
+    assert_eq!(diarization.turns.len(), 2);
+    assert_eq!(diarization.turns[0].text, "okay then");
+    assert_eq!(diarization.turns[1].text, "yes please");
+    assert_eq!(diarization.turns[1].speaker, 1);
+    assert_eq!(diarization.turns[1].start_ms, diarization.turns[0].end_ms);
+    assert_eq!(
+        diarization.turns[0].occurred_at.as_deref(),
+        Some("2026-05-05T12:00:00+00:00")
+    );
+    assert_eq!(
+        diarization.turns[1].occurred_at,
+        diarization.turns[0].ended_at
+    );
+    assert_eq!(
+        diarization.turns[1].ended_at.as_deref(),
+        Some("2026-05-05T12:00:02+00:00")
+    );
+    assert_eq!(diarization.turns[1].segment_ids, vec!["big:1:segment:0"]);
+}
@@ -1,5 +1,5 @@
 use pete::{ChannelEar, dummy_psyche, listen_user_input};
-use psyche::{Sensation, traits::Ear};
+use psyche::{EchoAction, EchoFilter, EchoVerdict, Sensation, traits::Ear};
 use std::sync::atomic::AtomicBool;
 use tokio::sync::mpsc;
 
@@ -46,3 +46,38 @@ async fn channel_ear_does_not_block_when_psyche_input_is_full() {
     .await
     .expect("ear blocked on a full psyche input queue");
 }
+
+#[tokio::test]
+async fn channel_ear_drops_echoes_of_pete_speech() {
+    let psyche = dummy_psyche();
+    let speaking = std::sync::Arc::new(AtomicBool::new(false));
+    let (tx, mut rx) = mpsc::channel(8);
+    let ear = ChannelEar::new(tx, speaking, psyche.voice())
+        .with_echo_filter(std::sync::Arc::new(EchoFilter::default()));
+    let now = chrono::Utc::now();
+
+    ear.started_speaking("How are you today?", now).await;
+    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    let echo = ear.hear_user_words_at("how are you today", &[], now).await;
+    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    let reply = ear.hear_user_words_at("very well thanks", &[], now).await;
+    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+
+    let mut heard = Vec::new();
+    let mut echoes = Vec::new();
+    while let Ok(sensation) = rx.try_recv() {
+        match sensation {
+            Sensation::HeardUserVoice { text, .. } => heard.push(text),
+            Sensation::Of { payload, .. } => {
+                if let Some(verdict) = payload.downcast_ref::<EchoVerdict>() {
+                    echoes.push(verdict.echo);
+                }
+            }
+            _ => {}
+        }
+    }
+    assert_eq!(echo, Some(EchoAction::Drop));
+    assert_eq!(reply, None);
+    assert_eq!(heard, ["very well thanks"]);
+    assert_eq!(echoes, [true, false]);
+}
@@ -0,0 +1,75 @@
+use httpmock::{Method::POST, MockServer};
+use lingproc::{LlmScheduler, PRIORITY_HEADER, Priority};
+use pete::llm_proxy_router;
+
+async fn spawn_proxy(upstream: String, scheduler: LlmScheduler) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let app = llm_proxy_router(upstream, scheduler);
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service())
+            .await
+            .unwrap();
+    });
+    format!("http://{addr}")
+}
+
+#[tokio::test]
+async fn forwards_requests_and_queues_them_by_header_priority() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/api/chat")
+                .query_param("stream", "false")
+                .matches(|req| {
+                    !req.headers
+                        .iter()
+                        .flatten()
+                        .any(|(name, _)| name.eq_ignore_ascii_case(PRIORITY_HEADER))
+                })
+                .body_contains("\"model\":\"gpt-oss\"");
+            then.status(200).body("{\"done\":true}");
+        })
+        .await;
+    let scheduler = LlmScheduler::new(1);
+    let proxy = spawn_proxy(server.base_url(), scheduler.clone()).await;
+
+    let reply = reqwest::Client::new()
+        .post(format!("{proxy}/api/chat?stream=false"))
+        .header(PRIORITY_HEADER, Priority::Conversation.as_str())
+        .body("{\"model\":\"gpt-oss\"}")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(reply.status(), 200);
+    assert_eq!(reply.text().await.unwrap(), "{\"done\":true}");
+    mock.assert_async().await;
+    let metrics = scheduler.metrics();
+    assert_eq!(metrics[&Priority::Conversation].started, 1);
+    assert_eq!(metrics[&Priority::Background].started, 0);
+}
+
+#[tokio::test]
+async fn requests_without_a_priority_queue_as_background() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/api/embed");
+            then.status(200).body("{}");
+        })
+        .await;
+    let scheduler = LlmScheduler::new(1);
+    let proxy = spawn_proxy(server.base_url(), scheduler.clone()).await;
+
+    let reply = reqwest::Client::new()
+        .post(format!("{proxy}/api/embed"))
+        .body("{}")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(reply.status(), 200);
+    assert_eq!(scheduler.metrics()[&Priority::Background].started, 1);
+}
@@ -13,14 +13,16 @@ async fn sends_sentence_by_sentence() {
         rx.recv().await.unwrap(),
         Event::Speech {
             text: "Hello world.".into(),
-            audio: None
+            audio: None,
+            visemes: None,
         }
     );
     assert_eq!(
         rx.recv().await.unwrap(),
         Event::Speech {
             text: "How are you?".into(),
-            audio: None
+            audio: None,
+            visemes: None,
         }
     );
 }
@@ -0,0 +1,161 @@
+use async_trait::async_trait;
+use lingproc::{Doer, LlmInstruction, Priority};
+use pete::{ModelProfile, PipelineConfig, WitKind, dummy_psyche};
+use psyche::{NoopMotor, Topic};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+struct Dummy;
+
+#[async_trait]
+impl Doer for Dummy {
+    async fn follow(&self, _: LlmInstruction) -> anyhow::Result<String> {
+        Ok("ok".into())
+    }
+}
+
+#[test]
+fn default_pipeline_is_valid() {
+    let config = PipelineConfig::default();
+    config.validate().unwrap();
+    let graph = config.graph();
+    assert!(
+        graph
+            .edges
+            .iter()
+            .any(|e| e.from == "Quick" && e.to == "Combobulator" && e.topic == Topic::Instant)
+    );
+    let kinds: Vec<WitKind> = config.wits.iter().map(|w| w.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            WitKind::FaceMemory,
+            WitKind::VoiceMemory,
+            WitKind::Quick,
+            WitKind::Combobulator,
+            WitKind::Will,
+            WitKind::Memory,
+            WitKind::Heart,
+            WitKind::Identity,
+        ]
+    );
+}
+
+#[test]
+fn rejects_subscriptions_without_producer() {
+    let config = PipelineConfig::from_json(
+        r#"{ "wits": [ { "kind": "situation" }, { "kind": "quick", "profile": "missing" } ] }"#,
+    )
+    .unwrap();
+    let err = config.validate().unwrap_err().to_string();
+    assert!(err.contains("subscribes to `moment`"), "{err}");
+    assert!(err.contains("unknown profile `missing`"), "{err}");
+}
+
+#[test]
+fn rejects_duplicate_names() {
+    let config =
+        PipelineConfig::from_json(r#"{ "wits": [ { "kind": "memory" }, { "kind": "memory" } ] }"#)
+            .unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn rejects_topic_overrides() {
+    let err = PipelineConfig::from_json(
+        r#"{ "wits": [ { "kind": "will", "subscribes": ["instant"] } ] }"#,
+    )
+    .unwrap_err();
+    assert!(
+        format!("{err:#}").contains("unknown field `subscribes`"),
+        "{err:#}"
+    );
+}
+
+#[test]
+fn topics_come_from_the_wit_kind() {
+    let config =
+        PipelineConfig::from_json(r#"{ "wits": [ { "kind": "quick", "name": "eyes" } ] }"#)
+            .unwrap();
+    let graph = config.graph();
+    assert_eq!(graph.nodes[0].subscribes, WitKind::Quick.subscribes());
+    assert_eq!(graph.nodes[0].publishes, vec![Topic::Instant]);
+}
+
+#[test]
+fn renders_dot() {
+    let config =
+        PipelineConfig::from_json(r#"{ "wits": [ { "kind": "quick" }, { "kind": "moment" } ] }"#)
+            .unwrap();
+    let dot = config.graph().to_dot();
+    assert!(dot.contains("\"source:sensation\" -> \"Quick\" [label=\"sensation\"]"));
+    assert!(dot.contains("\"Quick\" -> \"MomentWit\" [label=\"instant\"]"));
+}
+
+#[tokio::test]
+async fn builds_wits_with_resolved_profiles() {
+    let config = PipelineConfig::from_json(
+        r#"{
+            "profiles": { "fast": { "host": "http://gpu:11434", "priority": "will" } },
+            "wits": [
+                { "kind": "quick", "profile": "fast", "tick_ms": 250 },
+                { "kind": "combobulator" },
+                { "kind": "memory", "debug": false }
+            ]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(config.wits[0].kind, WitKind::Quick);
+    let mut psyche = dummy_psyche();
+    let before = psyche.debug_handle().snapshot().await.active_wits.len();
+    let seen: Arc<Mutex<Vec<ModelProfile>>> = Arc::default();
+    let record = seen.clone();
+    config
+        .build(
+            &mut psyche,
+            Arc::new(psyche::NoopMemory),
+            Arc::new(NoopMotor),
+            move |profile: &ModelProfile| -> anyhow::Result<Box<dyn Doer>> {
+                record.lock().unwrap().push(profile.clone());
+                Ok(Box::new(Dummy))
+            },
+        )
+        .unwrap();
+    let after = psyche.debug_handle().snapshot().await.active_wits.len();
+    assert_eq!(after - before, 3);
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 2);
+    assert_eq!(seen[0].host.as_deref(), Some("http://gpu:11434"));
+    assert_eq!(seen[0].priority, Priority::Will);
+    assert_eq!(seen[1].priority, Priority::Combobulation);
+}
+
+#[tokio::test]
+async fn ticks_are_keyed_by_spec_name() {
+    let config = PipelineConfig::from_json(
+        r#"{ "wits": [
+            { "kind": "memory", "name": "short", "tick_ms": 100 },
+            { "kind": "memory", "name": "long", "tick_ms": 5000 },
+            { "kind": "heart", "tick_ms": 750 }
+        ] }"#,
+    )
+    .unwrap();
+    let mut psyche = dummy_psyche();
+    config
+        .build(
+            &mut psyche,
+            Arc::new(psyche::NoopMemory),
+            Arc::new(NoopMotor),
+            |_: &ModelProfile| -> anyhow::Result<Box<dyn Doer>> { Ok(Box::new(Dummy)) },
+        )
+        .unwrap();
+    let names = psyche.wit_names();
+    assert!(names.contains(&"short".to_string()), "{names:?}");
+    assert!(names.contains(&"long".to_string()), "{names:?}");
+    assert_eq!(psyche.wit_tick("short"), Some(Duration::from_millis(100)));
+    assert_eq!(psyche.wit_tick("long"), Some(Duration::from_millis(5000)));
+    assert_eq!(
+        psyche.wit_tick(WitKind::Heart.label()),
+        Some(Duration::from_millis(750))
+    );
+}
@@ -0,0 +1,85 @@
+use pete::{Scenario, ScriptedLlm, SimulationHarness};
+use std::time::Duration;
+
+fn scripted_llm() -> ScriptedLlm {
+    ScriptedLlm::new("ok")
+        .on(
+            "Summarize these recent sensations",
+            "Someone greeted me and I can see a bright room.",
+        )
+        .on(
+            "timestamped timeline of Pete's internal representations",
+            "A visitor is saying hello to me.",
+        )
+        .on(
+            "what should Pete Daringsby do or say next",
+            "<say>Hello there!</say>",
+        )
+}
+
+fn greeting_scenario() -> Scenario {
+    Scenario::new()
+        .image(0, "image/png", b"frame")
+        .say(1_500, "hello Pete")
+        .settle(10_000)
+}
+
+#[tokio::test(start_paused = true)]
+async fn scenario_reaches_graph_and_wits_on_virtual_time() {
+    let scenario = greeting_scenario();
+    let harness = SimulationHarness::new(scripted_llm()).with_tick(Duration::from_millis(500));
+    let start = psyche::Clock::now(&harness.clock());
+
+    let trace = harness.run(&scenario).await;
+
+    assert!(trace.sensed("image"));
+    assert!(trace.sensed("utterance"));
+    let first = chrono::DateTime::parse_from_rfc3339(&trace.timeline[0].occurred_at).unwrap();
+    assert_eq!(first, start);
+    assert!(
+        harness
+            .llm()
+            .calls()
+            .iter()
+            .any(|p| p.starts_with("Summarize these recent sensations"))
+    );
+    assert!(
+        trace
+            .combobulations
+            .iter()
+            .any(|c| c.summary.contains("visitor"))
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn scenario_replays_identically() {
+    let first = SimulationHarness::new(scripted_llm()).with_tick(Duration::from_millis(500));
+    let second = SimulationHarness::new(scripted_llm()).with_tick(Duration::from_millis(500));
+
+    let first_trace = first.run(&greeting_scenario()).await;
+    let second_trace = second.run(&greeting_scenario()).await;
+
+    assert_eq!(first_trace, second_trace);
+    assert_eq!(first.llm().calls(), second.llm().calls());
+    assert_eq!(
+        first.graph().nodes_labeled("Sensation"),
+        second.graph().nodes_labeled("Sensation")
+    );
+}
+
+#[test]
+fn scenario_loads_from_json() {
+    let scenario = Scenario::from_json(
+        r#"{
+            "steps": [
+                {"at_ms": 0, "kind": "say", "text": "hi"},
+                {"at_ms": 2000, "kind": "geolocate", "latitude": 45.5, "longitude": -122.6}
+            ],
+            "settle_ms": 3000
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(scenario.steps.len(), 2);
+    assert_eq!(scenario.steps[1].at_ms, 2000);
+    assert_eq!(scenario.settle_ms, 3000);
+}
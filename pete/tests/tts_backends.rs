@@ -0,0 +1,245 @@
+#![cfg(feature = "tts")]
+use futures::StreamExt;
+use httpmock::{
+    Method::{GET, POST},
+    MockServer,
+};
+use pete::{
+    CommandTts, OpenAiTts, PiperTts, Tts, TtsBackend, TtsConfig, TtsRequest, TtsStream, TtsVoice,
+};
+use psyche::ProsodyMap;
+use serde_json::json;
+
+async fn collect(mut stream: TtsStream) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        bytes.extend(chunk.unwrap());
+    }
+    bytes
+}
+
+#[test]
+fn backend_names_parse() {
+    assert_eq!("Piper".parse::<TtsBackend>().unwrap(), TtsBackend::Piper);
+    assert_eq!("openai".parse::<TtsBackend>().unwrap(), TtsBackend::OpenAi);
+    assert_eq!(
+        "command".parse::<TtsBackend>().unwrap(),
+        TtsBackend::Command
+    );
+    assert!("festival".parse::<TtsBackend>().is_err());
+}
+
+#[test]
+fn backends_default_to_their_own_urls() {
+    assert_eq!(
+        TtsBackend::Coqui.default_url(),
+        "http://localhost:5002/api/tts"
+    );
+    assert_eq!(TtsBackend::Piper.default_url(), "http://localhost:5000");
+    assert!(
+        TtsBackend::OpenAi
+            .default_url()
+            .ends_with("/v1/audio/speech")
+    );
+}
+
+#[tokio::test]
+async fn piper_posts_text_and_voice() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/")
+                .json_body(json!({"text": "hello", "voice": "en_US-lessac-medium"}));
+            then.status(200).body("RIFF");
+        })
+        .await;
+
+    let tts = PiperTts::new(server.url("/"), Some("en_US-lessac-medium".into()));
+    assert_eq!(
+        collect(tts.stream_wav("hello").await.unwrap()).await,
+        b"RIFF"
+    );
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn piper_lists_loaded_voices() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/voices");
+            then.status(200).json_body(json!({
+                "en_US-lessac-medium": {"language": {"code": "en_US"}},
+            }));
+        })
+        .await;
+
+    let voices = PiperTts::new(server.url("/"), None).voices().await.unwrap();
+    assert_eq!(
+        voices,
+        vec![TtsVoice {
+            id: "en_US-lessac-medium".into(),
+            language: Some("en_US".into()),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn openai_requests_wav_with_bearer_token() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/v1/audio/speech")
+                .header("authorization", "Bearer secret")
+                .json_body(json!({
+                    "model": "tts-1",
+                    "input": "hi",
+                    "voice": "nova",
+                    "response_format": "wav",
+                }));
+            then.status(200).body("RIFF");
+        })
+        .await;
+
+    let tts = OpenAiTts::new(server.url("/v1/audio/speech"), None, Some("nova".into()))
+        .with_api_key(Some("secret".into()));
+    assert_eq!(collect(tts.stream_wav("hi").await.unwrap()).await, b"RIFF");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn openai_maps_prosody_to_speed_and_instructions() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/v1/audio/speech").json_body(json!({
+                "model": "gpt-4o-mini-tts",
+                "input": "we won",
+                "voice": "alloy",
+                "response_format": "wav",
+                "speed": 1.5,
+                "instructions": "Sound excited.",
+            }));
+            then.status(200).body("RIFF");
+        })
+        .await;
+
+    let mut prosody = ProsodyMap::default().for_emoji("🤩");
+    prosody.rate = 1.5;
+    let tts = OpenAiTts::new(
+        server.url("/v1/audio/speech"),
+        Some("gpt-4o-mini-tts".into()),
+        None,
+    );
+    let request = TtsRequest::new("we won").with_prosody(prosody);
+    collect(tts.stream_request(&request).await.unwrap()).await;
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn command_fills_prosody_placeholders() {
+    let tts = CommandTts::new("sh", vec!["-c".into(), "echo {rate} {style}".into()], None);
+    let mut prosody = ProsodyMap::default().for_emoji("😢");
+    prosody.rate = 0.8;
+    let request = TtsRequest::new("").with_prosody(prosody);
+    let out = collect(tts.stream_request(&request).await.unwrap()).await;
+    assert_eq!(String::from_utf8(out).unwrap().trim(), "0.80 sad");
+}
+
+#[tokio::test]
+async fn command_reports_phoneme_timings_from_alignment_file() {
+    let script = r#"cat; echo '[{"phoneme":"HH","start_ms":0,"end_ms":60},{"phoneme":"AY1","start_ms":60,"end_ms":240}]' > "$0""#;
+    let tts = CommandTts::new(
+        "sh",
+        vec!["-c".into(), script.into(), "{alignment}".into()],
+        None,
+    );
+
+    let audio = tts.synthesize(&TtsRequest::new("hi")).await.unwrap();
+
+    assert_eq!(audio.wav, b"hi");
+    let phonemes = audio.phonemes.unwrap();
+    assert_eq!(phonemes.len(), 2);
+    assert_eq!(phonemes[1].phoneme, "AY1");
+    assert_eq!(phonemes[1].end_ms, 240);
+
+    let plain = CommandTts::parse("cat", None).unwrap();
+    assert!(
+        plain
+            .synthesize(&TtsRequest::new("hi"))
+            .await
+            .unwrap()
+            .phonemes
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn openai_voices_come_from_server_or_defaults() {
+    let server = MockServer::start_async().await;
+    let listed = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/compatible/v1/audio/voices");
+            then.status(200)
+                .json_body(json!({"voices": ["af_bella", {"id": "bm_george"}]}));
+        })
+        .await;
+
+    let tts = OpenAiTts::new(server.url("/compatible/v1/audio/speech"), None, None);
+    let ids: Vec<_> = tts
+        .voices()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|v| v.id)
+        .collect();
+    assert_eq!(ids, ["af_bella", "bm_george"]);
+    listed.assert_async().await;
+
+    let tts = OpenAiTts::new(server.url("/v1/audio/speech"), None, None);
+    let voices = tts.voices().await.unwrap();
+    assert!(voices.iter().any(|voice| voice.id == "alloy"));
+}
+
+#[tokio::test]
+async fn command_pipes_text_through_program() {
+    let tts = CommandTts::parse("cat", None).unwrap();
+    assert_eq!(
+        collect(tts.stream_wav("hello").await.unwrap()).await,
+        b"hello"
+    );
+
+    // More text than a pipe holds, echoed back before it is all read.
+    let long = "la ".repeat(100_000);
+    assert_eq!(
+        collect(tts.stream_wav(&long).await.unwrap()).await,
+        long.as_bytes()
+    );
+
+    let tts = CommandTts::parse("sh -c {voice}", Some("exit 3".into())).unwrap();
+    let err = match tts.stream_wav("hello").await {
+        Ok(_) => panic!("expected command failure"),
+        Err(err) => err,
+    };
+    assert!(err.to_string().contains("failed"));
+}
+
+#[tokio::test]
+async fn config_builds_command_backend_with_voices() {
+    let config = TtsConfig {
+        backend: TtsBackend::Command,
+        command: Some("cat".into()),
+        voices: vec!["low".into(), "high".into()],
+        ..TtsConfig::default()
+    };
+    let voices = config.build().unwrap().voices().await.unwrap();
+    assert_eq!(voices, vec![TtsVoice::new("low"), TtsVoice::new("high")]);
+
+    let missing = TtsConfig {
+        backend: TtsBackend::Command,
+        ..TtsConfig::default()
+    };
+    assert!(missing.build().is_err());
+}
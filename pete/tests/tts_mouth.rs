@@ -1,9 +1,11 @@
 #![cfg(feature = "tts")]
 use futures::stream;
-use pete::{Tts, TtsMouth, TtsStream, speech_text_for_tts};
-use psyche::Event;
+use pete::{
+    Tts, TtsMouth, TtsRequest, TtsStream, speech_text_for_tts, synthesize_speech_with_visemes,
+};
 use psyche::traits::Mouth;
-use std::sync::{Arc, atomic::AtomicBool};
+use psyche::{Event, PhonemeTiming, Prosody, ProsodyMap, TtsAudio};
+use std::sync::{Arc, Mutex, atomic::AtomicBool};
 use tokio::sync::broadcast;
 
 struct DummyTts;
@@ -15,6 +17,72 @@ impl Tts for DummyTts {
     }
 }
 
+/// Records the prosody of every request.
+#[derive(Default)]
+struct RecordingTts {
+    requests: Mutex<Vec<TtsRequest>>,
+}
+
+#[async_trait::async_trait]
+impl Tts for RecordingTts {
+    async fn stream_wav(&self, text: &str) -> anyhow::Result<TtsStream> {
+        self.stream_request(&TtsRequest::new(text)).await
+    }
+
+    async fn stream_request(&self, request: &TtsRequest) -> anyhow::Result<TtsStream> {
+        self.requests.lock().unwrap().push(request.clone());
+        Ok(Box::pin(stream::once(async { Ok(vec![0u8; 4]) })))
+    }
+}
+
+/// Speaks a second of silence as a 16 kHz mono WAV.
+struct WavTts;
+
+#[async_trait::async_trait]
+impl Tts for WavTts {
+    async fn stream_wav(&self, _text: &str) -> anyhow::Result<TtsStream> {
+        let data = vec![0u8; 32_000];
+        let mut wav = b"RIFF".to_vec();
+        wav.extend((36 + data.len() as u32).to_le_bytes());
+        wav.extend(b"WAVEfmt ");
+        wav.extend(16u32.to_le_bytes());
+        wav.extend([1, 0, 1, 0]);
+        wav.extend(16_000u32.to_le_bytes());
+        wav.extend(32_000u32.to_le_bytes());
+        wav.extend([2, 0, 16, 0]);
+        wav.extend(b"data");
+        wav.extend((data.len() as u32).to_le_bytes());
+        wav.extend(data);
+        Ok(Box::pin(stream::once(async { Ok(wav) })))
+    }
+}
+
+/// Reports phoneme timings alongside its audio.
+struct AlignedTts;
+
+#[async_trait::async_trait]
+impl Tts for AlignedTts {
+    async fn stream_wav(&self, _text: &str) -> anyhow::Result<TtsStream> {
+        Ok(Box::pin(stream::once(async { Ok(vec![0u8; 4]) })))
+    }
+
+    async fn synthesize(&self, _request: &TtsRequest) -> anyhow::Result<TtsAudio> {
+        let phoneme = |phoneme: &str, start_ms, end_ms| PhonemeTiming {
+            phoneme: phoneme.into(),
+            start_ms,
+            end_ms,
+        };
+        Ok(TtsAudio {
+            wav: vec![0u8; 4],
+            phonemes: Some(vec![
+                phoneme("M", 0, 80),
+                phoneme("AA1", 80, 250),
+                phoneme("M", 250, 320),
+            ]),
+        })
+    }
+}
+
 #[tokio::test]
 async fn emits_audio_events() {
     let (tx, mut rx) = broadcast::channel(8);
@@ -28,6 +96,7 @@ async fn emits_audio_events() {
         Ok(Event::Speech {
             text,
             audio: Some(a),
+            ..
         }) => {
             assert_eq!(text, "Hello world.");
             assert!(!a.is_empty());
@@ -44,3 +113,59 @@ fn speech_text_for_tts_strips_emoji() {
     );
     assert_eq!(speech_text_for_tts("🙂"), None);
 }
+
+#[tokio::test]
+async fn speaks_with_prosody_of_current_emotion() {
+    let (tx, _rx) = broadcast::channel(16);
+    let tts = Arc::new(RecordingTts::default());
+    let mouth = TtsMouth::new(tx.clone(), Arc::new(AtomicBool::new(false)), tts.clone());
+
+    mouth.speak("Hello.").await;
+    tx.send(Event::EmotionChanged("🤩".into())).unwrap();
+    mouth.speak("We did it!").await;
+    mouth.speak("😢 Oh no. Anyway, onwards!").await;
+
+    let requests = tts.requests.lock().unwrap();
+    let map = ProsodyMap::default();
+    assert_eq!(requests[0].prosody, Prosody::default());
+    assert_eq!(requests[1].prosody, map.for_emoji("🤩"));
+    assert_eq!(requests[2].text, "Oh no.");
+    assert_eq!(requests[2].prosody, map.for_emoji("😢"));
+    assert!(requests[1].prosody.rate > requests[2].prosody.rate);
+    // An emoji colours its own sentence; the emotion carries on after it.
+    assert_eq!(requests[3].text, "Anyway, onwards!");
+    assert_eq!(requests[3].prosody, map.for_emoji("🤩"));
+}
+
+#[tokio::test]
+async fn guesses_visemes_over_the_length_of_the_audio() {
+    let spoken = synthesize_speech_with_visemes(&WavTts, "Hello, Bob!", &Prosody::default())
+        .await
+        .unwrap();
+
+    let visemes = spoken.visemes.unwrap();
+    assert!(spoken.audio.is_some());
+    assert_eq!(visemes.first().unwrap().start_ms, 0);
+    assert_eq!(visemes.last().unwrap().end_ms, 1_000);
+    assert!(visemes.iter().any(|cue| cue.viseme == "PP"));
+}
+
+#[tokio::test]
+async fn speech_events_follow_backend_phoneme_timings() {
+    let (tx, mut rx) = broadcast::channel(8);
+    let mouth = TtsMouth::new(tx, Arc::new(AtomicBool::new(false)), Arc::new(AlignedTts));
+    mouth.speak("Mom.").await;
+
+    let Ok(Event::Speech {
+        visemes: Some(visemes),
+        ..
+    }) = rx.recv().await
+    else {
+        panic!("expected speech with visemes");
+    };
+    let shapes: Vec<_> = visemes
+        .iter()
+        .map(|cue| (cue.viseme.as_str(), cue.start_ms, cue.end_ms))
+        .collect();
+    assert_eq!(shapes, [("PP", 0, 80), ("aa", 80, 250), ("PP", 250, 320)]);
+}
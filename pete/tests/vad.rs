@@ -0,0 +1,163 @@
+use pete::vad::{self, Vad, VadConfig, VadEvent};
+use std::f32::consts::PI;
+
+const RATE: u32 = 16_000;
+
+fn samples(seconds: f32) -> usize {
+    (seconds * RATE as f32) as usize
+}
+
+fn silence(seconds: f32) -> Vec<f32> {
+    vec![0.0; samples(seconds)]
+}
+
+/// Voiced-speech stand-in: a 150 Hz fundamental with falling harmonics up to
+/// 3 kHz, scaled to `amplitude` peak per harmonic.
+fn voiced(seconds: f32, amplitude: f32) -> Vec<f32> {
+    (0..samples(seconds))
+        .map(|i| {
+            let t = i as f32 / RATE as f32;
+            (1..=20)
+                .map(|h| amplitude / h as f32 * (2.0 * PI * 150.0 * h as f32 * t).sin())
+                .sum()
+        })
+        .collect()
+}
+
+/// Broadband fan noise from a fixed-seed generator.
+fn fan(seconds: f32, amplitude: f32) -> Vec<f32> {
+    let mut state = 0x2545_f491_u32;
+    (0..samples(seconds))
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            amplitude * (state as f32 / u32::MAX as f32 * 2.0 - 1.0)
+        })
+        .collect()
+}
+
+/// A sustained A major chord.
+fn chord(seconds: f32, amplitude: f32) -> Vec<f32> {
+    (0..samples(seconds))
+        .map(|i| {
+            let t = i as f32 / RATE as f32;
+            [440.0, 554.4, 659.3]
+                .iter()
+                .map(|f| amplitude * (2.0 * PI * f * t).sin())
+                .sum()
+        })
+        .collect()
+}
+
+/// Feed `clip` in 20 ms chunks, returning each event with how many samples
+/// had been pushed when it was reported.
+fn stream(vad: &mut Vad, clip: &[f32]) -> Vec<(usize, VadEvent)> {
+    let mut pushed = 0;
+    let mut events = Vec::new();
+    for chunk in clip.chunks(samples(0.02)) {
+        pushed += chunk.len();
+        events.extend(vad.push(chunk).into_iter().map(|event| (pushed, event)));
+    }
+    events
+}
+
+#[test]
+fn fan_noise_is_not_speech() {
+    let config = VadConfig::default();
+    for amplitude in [0.05, 0.1, 0.3] {
+        let clip = fan(5.0, amplitude);
+        assert!(
+            !vad::contains_speech(&clip, RATE, &config),
+            "fan at {amplitude}: {:?}",
+            vad::speech_segments(&clip, RATE, &config)
+        );
+    }
+}
+
+#[test]
+fn noise_floor_adapts_to_tonal_music_and_speech_ends() {
+    let mut clip = silence(0.5);
+    clip.extend(chord(10.0, 0.05));
+
+    let mut vad = Vad::new(VadConfig::default(), RATE);
+    let events = stream(&mut vad, &clip);
+
+    // The chord is tonal and loud enough to start as speech, but the floor
+    // catches up with it and the segment ends while the music plays on.
+    assert_eq!(events.len(), 2, "{events:?}");
+    let (ended_at, VadEvent::SpeechEnd { sample: end }) = events[1] else {
+        panic!("expected the music to stop counting as speech: {events:?}");
+    };
+    assert!(end < samples(5.0), "{events:?}");
+    assert!(ended_at < samples(6.0), "{events:?}");
+    assert!(!vad.is_speaking());
+    assert!(vad.is_paused());
+}
+
+#[test]
+fn quiet_speech_band_signal_is_detected() {
+    let voice = voiced(0.5, 0.02);
+    assert!(vad::rms(&voice) < 0.02);
+    let mut clip = silence(1.0);
+    clip.extend(voice);
+    clip.extend(silence(1.0));
+    let background = fan(clip.len() as f32 / RATE as f32, 0.004);
+    let clip: Vec<f32> = clip.iter().zip(&background).map(|(a, b)| a + b).collect();
+
+    let segments = vad::speech_segments(&clip, RATE, &VadConfig::default());
+
+    assert_eq!(segments.len(), 1, "{segments:?}");
+    assert!(segments[0].start <= samples(1.0));
+    assert!(segments[0].end >= samples(1.5));
+}
+
+#[test]
+fn hangover_and_pre_roll_bound_the_segment() {
+    let config = VadConfig::default();
+    let pre_roll = samples(config.pre_roll.as_secs_f32());
+    let hangover = samples(config.hangover.as_secs_f32());
+    let (onset, offset) = (samples(1.0), samples(1.5));
+    let mut clip = silence(1.0);
+    clip.extend(voiced(0.5, 0.1));
+    clip.extend(silence(2.0));
+
+    let mut vad = Vad::new(config.clone(), RATE);
+    let events = stream(&mut vad, &clip);
+
+    let min_speech = samples(config.min_speech.as_secs_f32());
+    assert_eq!(
+        events,
+        vec![
+            (
+                onset + min_speech,
+                VadEvent::SpeechStart {
+                    sample: onset - pre_roll
+                }
+            ),
+            (
+                offset + hangover,
+                VadEvent::SpeechEnd {
+                    sample: offset + pre_roll
+                }
+            ),
+        ]
+    );
+    assert_eq!(vad.boundary_sample(), Some(offset + hangover));
+    assert_eq!(vad.segments().len(), 1);
+    assert_eq!(vad.segments()[0], onset - pre_roll..offset + pre_roll);
+}
+
+#[test]
+fn pauses_shorter_than_the_hangover_do_not_split_speech() {
+    let mut clip = silence(0.5);
+    clip.extend(voiced(0.5, 0.1));
+    clip.extend(silence(1.0));
+    clip.extend(voiced(0.5, 0.1));
+    clip.extend(silence(2.0));
+
+    let segments = vad::speech_segments(&clip, RATE, &VadConfig::default());
+
+    assert_eq!(segments.len(), 1, "{segments:?}");
+    assert_eq!(segments[0].end, samples(2.5) + samples(0.2));
+}
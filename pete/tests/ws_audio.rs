@@ -3,8 +3,8 @@ use futures::StreamExt;
 use pete::{
     Body, ChannelEar, EventBus, EyeSensor, GeoSensor, MotionSensor, dummy_psyche, ws_handler,
 };
-use psyche::Event;
 use psyche::traits::Sensor;
+use psyche::{Event, VisemeCue};
 use std::sync::{
     Arc,
     atomic::{AtomicBool, AtomicUsize},
@@ -40,6 +40,7 @@ async fn websocket_forwards_audio() {
         connections: Arc::new(AtomicUsize::new(0)),
         system_prompt: Arc::new(tokio::sync::Mutex::new(psyche.system_prompt())),
         psyche_debug: debug,
+        pipeline: Default::default(),
     };
     let app = Router::new()
         .route("/ws", get(ws_handler))
@@ -56,6 +57,11 @@ async fn websocket_forwards_audio() {
     bus.publish_event(Event::Speech {
         text: "hi".into(),
         audio: Some("UklGRg==".into()),
+        visemes: Some(vec![VisemeCue {
+            viseme: "aa".into(),
+            start_ms: 0,
+            end_ms: 120,
+        }]),
     });
     // skip initial system prompt
     let mut msg = socket.next().await.unwrap().unwrap();
@@ -67,5 +73,7 @@ async fn websocket_forwards_audio() {
     assert_eq!(value["type"], "Say");
     assert_eq!(value["data"]["audio"], "UklGRg==");
     assert_eq!(value["data"]["words"], "hi");
+    assert_eq!(value["data"]["visemes"][0]["viseme"], "aa");
+    assert_eq!(value["data"]["visemes"][0]["end_ms"], 120);
     server.abort();
 }
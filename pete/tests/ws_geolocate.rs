@@ -42,6 +42,7 @@ async fn websocket_forwards_geolocation() {
         connections: Arc::new(AtomicUsize::new(0)),
         system_prompt: Arc::new(tokio::sync::Mutex::new(psyche.system_prompt())),
         psyche_debug: debug,
+        pipeline: Default::default(),
     };
     let app = Router::new()
         .route("/ws", get(ws_handler))
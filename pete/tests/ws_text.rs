@@ -81,6 +81,7 @@ async fn websocket_text_is_reported_to_ear() {
         connections: Arc::new(AtomicUsize::new(0)),
         system_prompt: Arc::new(Mutex::new(psyche.system_prompt())),
         psyche_debug: debug,
+        pipeline: Default::default(),
     };
     let app = Router::new()
         .route("/ws", get(ws_handler))
@@ -144,6 +145,7 @@ async fn websocket_text_is_not_blocked_by_latest_eye_state() {
         connections: Arc::new(AtomicUsize::new(0)),
         system_prompt: Arc::new(Mutex::new(psyche.system_prompt())),
         psyche_debug: debug,
+        pipeline: Default::default(),
     };
     let app = Router::new()
         .route("/ws", get(ws_handler))
@@ -239,6 +241,7 @@ async fn websocket_flat_see_is_reported_to_eye() {
         connections: Arc::new(AtomicUsize::new(0)),
         system_prompt: Arc::new(Mutex::new(psyche.system_prompt())),
         psyche_debug: debug,
+        pipeline: Default::default(),
     };
     let app = Router::new()
         .route("/ws", get(ws_handler))
@@ -303,6 +306,7 @@ async fn websocket_motion_is_reported_to_motion_sensor() {
         connections: Arc::new(AtomicUsize::new(0)),
         system_prompt: Arc::new(Mutex::new(psyche.system_prompt())),
         psyche_debug: debug,
+        pipeline: Default::default(),
     };
     let app = Router::new()
         .route("/ws", get(ws_handler))
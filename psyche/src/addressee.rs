@@ -0,0 +1,404 @@
+//! Deciding whether heard speech was meant for Pete.
+//!
+//! Pete hears everything said in the room. An [`AddresseeDetector`] weighs
+//! the cues a person would use — being called by name, speech that answers
+//! something Pete just said, and someone facing the camera — into a
+//! confidence that an utterance was directed at him. Ambiguous cases can be
+//! passed to an [`AddresseeJudge`], usually a language model, whose opinion is
+//! blended in. Verdicts are remembered per utterance, so the judge is asked
+//! at most once. The resulting [`AddresseeVerdict`] is stored on the heard
+//! speech sensation so speaking policies can respond only when addressed.
+//!
+//! ```
+//! use chrono::Utc;
+//! use psyche::{AddresseeCues, AddresseeDetector};
+//!
+//! let detector = AddresseeDetector::default();
+//! let now = Utc::now();
+//! assert!(detector.score(&AddresseeCues::new("Pete, what time is it?", now)).0 > 0.5);
+//! assert!(detector.score(&AddresseeCues::new("pass the salt", now)).0 < 0.5);
+//! ```
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use common::{env_or, env_var};
+use lingproc::{Chatter, Message};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_stream::StreamExt;
+use tracing::warn;
+
+/// What was observed around one utterance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddresseeCues {
+    /// The transcribed words.
+    pub text: String,
+    /// When the utterance occurred.
+    pub occurred_at: DateTime<Utc>,
+    /// When Pete last finished saying something, if he has spoken.
+    pub pete_spoke_at: Option<DateTime<Utc>>,
+    /// What Pete last said, for the judge's context.
+    pub pete_said: Option<String>,
+    /// When a face was last seen, if any.
+    pub face_seen_at: Option<DateTime<Utc>>,
+    /// How squarely that face looked at the camera, in `0.0..=1.0`, when its
+    /// landmarks were known.
+    pub face_pose: Option<f32>,
+}
+
+impl AddresseeCues {
+    /// Cues holding only the words and their time.
+    pub fn new(text: impl Into<String>, occurred_at: DateTime<Utc>) -> Self {
+        Self {
+            text: text.into(),
+            occurred_at,
+            pete_spoke_at: None,
+            pete_said: None,
+            face_seen_at: None,
+            face_pose: None,
+        }
+    }
+
+    /// Record that Pete said `text` at `at`.
+    pub fn with_pete_speech(mut self, text: impl Into<String>, at: DateTime<Utc>) -> Self {
+        self.pete_said = Some(text.into());
+        self.pete_spoke_at = Some(at);
+        self
+    }
+
+    /// Record that a face was seen at `at`, turned toward the camera by
+    /// `pose` as scored by [`FaceQuality::pose_of`](crate::FaceQuality::pose_of).
+    pub fn with_face(mut self, at: DateTime<Utc>, pose: Option<f32>) -> Self {
+        self.face_seen_at = Some(at);
+        self.face_pose = pose;
+        self
+    }
+}
+
+/// Whether an utterance was directed at Pete, and why.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AddresseeVerdict {
+    /// Graph id of the heard speech sensation the verdict belongs to.
+    pub sensation_id: String,
+    /// Whether the speech was judged to be for Pete.
+    pub addressed: bool,
+    /// Confidence in `0.0..=1.0` that the speech was for Pete.
+    pub confidence: f32,
+    /// Short names of the cues that contributed, such as `name` or `reply`.
+    pub reasons: Vec<String>,
+}
+
+/// Weights and windows used by an [`AddresseeDetector`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddresseeConfig {
+    /// Names Pete answers to, matched as whole words ignoring case.
+    pub names: Vec<String>,
+    /// Confidence before any cue is considered.
+    pub prior: f32,
+    /// Added when Pete is called by name at the start or end of the utterance;
+    /// a third of it when he is only mentioned.
+    pub name_weight: f32,
+    /// Added, fading over `reply_window`, when the utterance follows Pete's speech.
+    pub reply_weight: f32,
+    /// How long after Pete speaks an utterance still counts as a reply.
+    pub reply_window: Duration,
+    /// Added when a face looking at the camera was seen within `face_window`
+    /// of the utterance.
+    pub face_weight: f32,
+    /// How recently a face must have been seen.
+    pub face_window: Duration,
+    /// Pose score at or above which a face counts as looking at Pete. Faces
+    /// without landmarks have no pose and never count.
+    pub facing_min: f32,
+    /// Confidence at or above which speech counts as addressed.
+    pub threshold: f32,
+    /// Distance from `threshold` within which the judge is asked.
+    pub judge_margin: f32,
+    /// Share of the final confidence taken from the judge.
+    pub judge_weight: f32,
+}
+
+impl Default for AddresseeConfig {
+    fn default() -> Self {
+        Self {
+            names: vec!["Pete".into(), "Peter".into()],
+            prior: 0.2,
+            name_weight: 0.6,
+            reply_weight: 0.35,
+            reply_window: Duration::from_secs(10),
+            face_weight: 0.15,
+            face_window: Duration::from_secs(5),
+            facing_min: 0.6,
+            threshold: 0.5,
+            judge_margin: 0.2,
+            judge_weight: 0.5,
+        }
+    }
+}
+
+impl AddresseeConfig {
+    /// Read overrides from `ADDRESSEE_*` environment variables.
+    pub fn from_env() -> Result<Self> {
+        let d = Self::default();
+        let names = match env_var("ADDRESSEE_NAMES") {
+            Some(names) => names
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect(),
+            None => d.names,
+        };
+        Ok(Self {
+            names,
+            prior: env_or("ADDRESSEE_PRIOR", d.prior)?.clamp(0.0, 1.0),
+            name_weight: env_or("ADDRESSEE_NAME_WEIGHT", d.name_weight)?,
+            reply_weight: env_or("ADDRESSEE_REPLY_WEIGHT", d.reply_weight)?,
+            reply_window: Duration::from_millis(env_or(
+                "ADDRESSEE_REPLY_WINDOW_MS",
+                d.reply_window.as_millis() as u64,
+            )?),
+            face_weight: env_or("ADDRESSEE_FACE_WEIGHT", d.face_weight)?,
+            face_window: Duration::from_millis(env_or(
+                "ADDRESSEE_FACE_WINDOW_MS",
+                d.face_window.as_millis() as u64,
+            )?),
+            facing_min: env_or("ADDRESSEE_FACING_MIN", d.facing_min)?.clamp(0.0, 1.0),
+            threshold: env_or("ADDRESSEE_THRESHOLD", d.threshold)?.clamp(0.0, 1.0),
+            judge_margin: env_or("ADDRESSEE_JUDGE_MARGIN", d.judge_margin)?.max(0.0),
+            judge_weight: env_or("ADDRESSEE_JUDGE_WEIGHT", d.judge_weight)?.clamp(0.0, 1.0),
+        })
+    }
+}
+
+/// Second opinion on whether speech was meant for Pete.
+#[async_trait]
+pub trait AddresseeJudge: Send + Sync {
+    /// Probability in `0.0..=1.0` that the utterance in `cues` was for Pete.
+    async fn judge(&self, cues: &AddresseeCues) -> Result<f32>;
+}
+
+/// [`AddresseeJudge`] that asks a language model for a yes or no answer.
+pub struct ChatterAddresseeJudge {
+    chatter: Arc<dyn Chatter>,
+}
+
+impl ChatterAddresseeJudge {
+    pub fn new(chatter: Arc<dyn Chatter>) -> Self {
+        Self { chatter }
+    }
+}
+
+#[async_trait]
+impl AddresseeJudge for ChatterAddresseeJudge {
+    async fn judge(&self, cues: &AddresseeCues) -> Result<f32> {
+        let context = cues
+            .pete_said
+            .as_deref()
+            .map(|said| format!("Pete last said: \"{}\"\n", said.trim()))
+            .unwrap_or_default();
+        let system_prompt = "You decide whether speech heard in a room was directed at Pete, \
+             an embodied AI, or at someone else. Answer with only YES or NO.";
+        let message = Message::user(format!(
+            "{context}Someone in the room said: \"{}\"\nWas this said to Pete?",
+            cues.text.trim()
+        ));
+        let mut stream = self.chatter.chat(system_prompt, &[message]).await?;
+        let mut answer = String::new();
+        while let Some(chunk) = stream.next().await {
+            answer.push_str(&chunk?);
+        }
+        parse_judgement(&answer).with_context(|| format!("unclear addressee judgement {answer:?}"))
+    }
+}
+
+/// Probability from a YES/NO style answer.
+fn parse_judgement(answer: &str) -> Option<f32> {
+    let answer = answer.trim().to_ascii_lowercase();
+    let first = answer
+        .split(|c: char| !c.is_alphabetic())
+        .find(|word| !word.is_empty())?;
+    match first {
+        "yes" => Some(1.0),
+        "no" => Some(0.0),
+        "maybe" | "unsure" => Some(0.5),
+        _ => None,
+    }
+}
+
+/// How many recent verdicts an [`AddresseeDetector`] remembers.
+const REMEMBERED_VERDICTS: usize = 64;
+
+/// Combines addressee cues into an [`AddresseeVerdict`].
+///
+/// Clones share the verdicts already reached.
+#[derive(Clone, Default)]
+pub struct AddresseeDetector {
+    config: AddresseeConfig,
+    judge: Option<Arc<dyn AddresseeJudge>>,
+    verdicts: Arc<Mutex<VecDeque<AddresseeVerdict>>>,
+}
+
+impl AddresseeDetector {
+    pub fn new(config: AddresseeConfig) -> Self {
+        Self {
+            config,
+            judge: None,
+            verdicts: Arc::default(),
+        }
+    }
+
+    /// Ask `judge` about utterances the cues leave ambiguous.
+    pub fn with_judge(mut self, judge: Arc<dyn AddresseeJudge>) -> Self {
+        self.judge = Some(judge);
+        self
+    }
+
+    pub fn config(&self) -> &AddresseeConfig {
+        &self.config
+    }
+
+    /// Confidence from the cues alone, with the names of those that applied.
+    pub fn score(&self, cues: &AddresseeCues) -> (f32, Vec<String>) {
+        let config = &self.config;
+        let mut score = config.prior;
+        let mut reasons = Vec::new();
+
+        match name_mention(&cues.text, &config.names) {
+            Some(NameMention::Vocative) => {
+                score += config.name_weight;
+                reasons.push("name".to_string());
+            }
+            Some(NameMention::Mentioned) => {
+                score += config.name_weight / 3.0;
+                reasons.push("mention".to_string());
+            }
+            None => {}
+        }
+
+        if let Some(spoke_at) = cues.pete_spoke_at {
+            let window = config.reply_window.as_secs_f32();
+            let since = (cues.occurred_at - spoke_at).num_milliseconds() as f32 / 1000.0;
+            if window > 0.0 && (0.0..window).contains(&since) {
+                score += config.reply_weight * (1.0 - since / window);
+                reasons.push("reply".to_string());
+            }
+        }
+
+        if let (Some(face_at), Some(pose)) = (cues.face_seen_at, cues.face_pose) {
+            let age = (cues.occurred_at - face_at).num_milliseconds().abs() as f32 / 1000.0;
+            if age <= config.face_window.as_secs_f32() && pose >= config.facing_min {
+                score += config.face_weight;
+                reasons.push("facing".to_string());
+            }
+        }
+
+        (score.clamp(0.0, 1.0), reasons)
+    }
+
+    /// The verdict already reached for `sensation_id`, if any.
+    pub fn verdict(&self, sensation_id: &str) -> Option<AddresseeVerdict> {
+        self.verdicts
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|verdict| verdict.sensation_id == sensation_id)
+            .cloned()
+    }
+
+    /// Decide from the cues alone when they are clear enough.
+    ///
+    /// Returns `None` when the judge should be asked, which
+    /// [`detect`](Self::detect) does; callers on a busy loop can spawn it.
+    pub fn quick_verdict(
+        &self,
+        sensation_id: &str,
+        cues: &AddresseeCues,
+    ) -> Option<AddresseeVerdict> {
+        if let Some(verdict) = self.verdict(sensation_id) {
+            return Some(verdict);
+        }
+        let (confidence, reasons) = self.score(cues);
+        if self.wants_judge(confidence) {
+            return None;
+        }
+        Some(self.remember(sensation_id, confidence, reasons))
+    }
+
+    /// Decide whether the utterance behind `sensation_id` was for Pete,
+    /// asking the judge when the cues are ambiguous.
+    ///
+    /// An utterance already decided keeps its verdict without asking again.
+    pub async fn detect(&self, sensation_id: &str, cues: &AddresseeCues) -> AddresseeVerdict {
+        if let Some(verdict) = self.verdict(sensation_id) {
+            return verdict;
+        }
+        let (mut confidence, mut reasons) = self.score(cues);
+        let judge = self.judge.as_ref().filter(|_| self.wants_judge(confidence));
+        if let Some(judge) = judge {
+            match judge.judge(cues).await {
+                Ok(probability) => {
+                    let weight = self.config.judge_weight;
+                    confidence = confidence * (1.0 - weight) + probability.clamp(0.0, 1.0) * weight;
+                    reasons.push("judge".to_string());
+                }
+                Err(e) => warn!(error = %format!("{e:#}"), "addressee judge failed"),
+            }
+        }
+        self.remember(sensation_id, confidence, reasons)
+    }
+
+    fn wants_judge(&self, confidence: f32) -> bool {
+        self.judge.is_some()
+            && (confidence - self.config.threshold).abs() < self.config.judge_margin
+    }
+
+    fn remember(
+        &self,
+        sensation_id: &str,
+        confidence: f32,
+        reasons: Vec<String>,
+    ) -> AddresseeVerdict {
+        let verdict = AddresseeVerdict {
+            sensation_id: sensation_id.to_string(),
+            addressed: confidence >= self.config.threshold,
+            confidence,
+            reasons,
+        };
+        let mut verdicts = self.verdicts.lock().unwrap();
+        if verdicts.len() >= REMEMBERED_VERDICTS {
+            verdicts.pop_front();
+        }
+        verdicts.push_back(verdict.clone());
+        verdict
+    }
+}
+
+enum NameMention {
+    /// Called by name at the start or end: "Pete, look" or "right, Pete?".
+    Vocative,
+    /// Named somewhere else, possibly talked about rather than to.
+    Mentioned,
+}
+
+fn name_mention(text: &str, names: &[String]) -> Option<NameMention> {
+    let words: Vec<String> = text
+        .split(|c: char| !(c.is_alphanumeric() || c == '\''))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    let is_name = |word: &str| names.iter().any(|name| name.to_lowercase() == word);
+    let position = words.iter().position(|word| is_name(word))?;
+    // Allow a greeting before the name, as in "hey Pete".
+    let greeting = ["hey", "hi", "hello", "ok", "okay", "oh", "yo"];
+    let opens = position == 0 || (position == 1 && greeting.contains(&words[0].as_str()));
+    let closes = words.last().is_some_and(|word| is_name(word));
+    if opens || closes {
+        Some(NameMention::Vocative)
+    } else {
+        Some(NameMention::Mentioned)
+    }
+}
@@ -0,0 +1,77 @@
+//! Cutting Pete off when the user talks over him.
+//!
+//! Audio front-ends hold a [`BargeIn`] from
+//! [`Psyche::barge_in`](crate::Psyche::barge_in) and call
+//! [`BargeIn::user_speech_onset`] whenever voice activity begins. If Pete is
+//! speaking at that moment the current turn stops, the mouth is interrupted,
+//! clients receive [`Event::SpeechInterrupted`] so they can cut playback, and
+//! an [`InterruptedSpeaking`](Sensation::InterruptedSpeaking) sensation
+//! records what was said and what was left unsaid.
+
+use crate::voice::Voice;
+use crate::{Event, Sensation};
+use chrono::{DateTime, Utc};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+use tokio::sync::{broadcast, mpsc};
+use tracing::{info, warn};
+
+/// Cloneable handle that interrupts Pete's speech on user speech onset.
+#[derive(Clone)]
+pub struct BargeIn {
+    voice: Arc<Voice>,
+    is_speaking: Arc<AtomicBool>,
+    events: broadcast::Sender<Event>,
+    input: mpsc::Sender<Sensation>,
+}
+
+impl BargeIn {
+    pub(crate) fn new(
+        voice: Arc<Voice>,
+        is_speaking: Arc<AtomicBool>,
+        events: broadcast::Sender<Event>,
+        input: mpsc::Sender<Sensation>,
+    ) -> Self {
+        Self {
+            voice,
+            is_speaking,
+            events,
+            input,
+        }
+    }
+
+    /// Returns `true` while a turn is generating or the mouth is speaking.
+    pub fn pete_speaking(&self) -> bool {
+        self.is_speaking.load(Ordering::SeqCst) || self.voice.mouth().speaking()
+    }
+
+    /// Report that the user started talking at `occurred_at`.
+    ///
+    /// Returns `true` when this interrupted Pete.
+    pub async fn user_speech_onset(&self, occurred_at: DateTime<Utc>) -> bool {
+        if !self.pete_speaking() {
+            return false;
+        }
+        let (spoken, unsaid) = self.voice.interrupt().unwrap_or_default();
+        self.voice.mouth().interrupt().await;
+        info!(%spoken, unsaid_len = unsaid.len(), "user barged in; speech interrupted");
+        let _ = self.events.send(Event::SpeechInterrupted {
+            spoken: spoken.clone(),
+        });
+        if self
+            .input
+            .send(Sensation::InterruptedSpeaking {
+                text: spoken,
+                unsaid,
+                occurred_at,
+            })
+            .await
+            .is_err()
+        {
+            warn!("failed to record interrupted speech; psyche input is closed");
+        }
+        true
+    }
+}
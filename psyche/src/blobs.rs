@@ -0,0 +1,498 @@
+//! Content-addressed storage for media payloads.
+//!
+//! Camera frames and audio clips are kept out of the graph: a [`BlobStore`]
+//! holds their bytes under the hex SHA-256 of the content, and `Image` and
+//! `AudioClip` nodes only carry `blob_hash`, `blob_size` and `mime`. Equal
+//! payloads are stored once. [`BlobConfig::from_env`] picks a local directory
+//! by default or an S3-compatible bucket.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use common::env_var;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use sha2::{Digest, Sha256};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::io::AsyncReadExt;
+use uuid::Uuid;
+
+/// Bytes read from the local store per streamed chunk.
+const LOCAL_CHUNK_SIZE: usize = 64 * 1024;
+
+/// How long a freshly stored local blob is safe from garbage collection, so a
+/// writer has time to commit the node that refers to it.
+pub const DEFAULT_BLOB_GC_GRACE: Duration = Duration::from_secs(300);
+
+/// Chunks of a stored blob.
+pub type BlobStream = BoxStream<'static, std::io::Result<Vec<u8>>>;
+
+/// Storage for payloads addressed by their [`blob_hash`].
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Store `bytes` under `hash` unless they are already stored.
+    async fn put(&self, hash: &str, bytes: &[u8]) -> Result<()>;
+    /// Return the bytes stored under `hash`, if any.
+    async fn get(&self, hash: &str) -> Result<Option<Vec<u8>>>;
+    /// Stream the bytes stored under `hash`, if any.
+    async fn stream(&self, hash: &str) -> Result<Option<BlobStream>>;
+    /// Remove the bytes stored under `hash`. Removing a missing blob is not
+    /// an error.
+    async fn delete(&self, hash: &str) -> Result<()>;
+
+    /// Delete a blob found unreferenced by garbage collection, returning
+    /// whether it was removed. Stores may keep blobs written too recently
+    /// for the node that refers to them to have been committed.
+    async fn collect(&self, hash: &str) -> Result<bool> {
+        self.delete(hash).await?;
+        Ok(true)
+    }
+
+    /// Store `bytes`, returning their hash.
+    async fn insert(&self, bytes: &[u8]) -> Result<String> {
+        let hash = blob_hash(bytes);
+        self.put(&hash, bytes).await?;
+        Ok(hash)
+    }
+}
+
+/// Hex SHA-256 of `bytes`, the address of a blob.
+pub fn blob_hash(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Whether `value` has the form of a [`blob_hash`].
+pub fn is_blob_hash(value: &str) -> bool {
+    value.len() == 64
+        && value
+            .bytes()
+            .all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+fn ensure_blob_hash(hash: &str) -> Result<()> {
+    anyhow::ensure!(is_blob_hash(hash), "invalid blob hash {hash:?}");
+    Ok(())
+}
+
+fn verified(hash: &str, bytes: Vec<u8>) -> Result<Vec<u8>> {
+    anyhow::ensure!(blob_hash(&bytes) == hash, "blob {hash} is corrupt");
+    Ok(bytes)
+}
+
+/// Where blobs are kept.
+#[derive(Clone, PartialEq)]
+pub enum BlobConfig {
+    /// Files under a local directory, sharded by the first two hash digits.
+    Local { dir: PathBuf },
+    /// Objects in an S3-compatible bucket.
+    S3(S3BlobConfig),
+}
+
+impl Default for BlobConfig {
+    fn default() -> Self {
+        Self::Local {
+            dir: PathBuf::from("blobs"),
+        }
+    }
+}
+
+impl BlobConfig {
+    /// Read the store from `BLOB_*` environment variables.
+    pub fn from_env() -> Result<Self> {
+        let kind = env_var("BLOB_STORE").unwrap_or_else(|| "local".into());
+        match kind.to_ascii_lowercase().as_str() {
+            "local" => Ok(match env_var("BLOB_DIR") {
+                Some(dir) => Self::Local {
+                    dir: PathBuf::from(dir),
+                },
+                None => Self::default(),
+            }),
+            "s3" => Ok(Self::S3(S3BlobConfig {
+                endpoint: env_var("BLOB_S3_ENDPOINT").context("missing BLOB_S3_ENDPOINT")?,
+                bucket: env_var("BLOB_S3_BUCKET").context("missing BLOB_S3_BUCKET")?,
+                region: env_var("BLOB_S3_REGION").unwrap_or_else(|| "us-east-1".into()),
+                access_key: env_var("BLOB_S3_ACCESS_KEY")
+                    .or_else(|| env_var("AWS_ACCESS_KEY_ID"))
+                    .context("missing BLOB_S3_ACCESS_KEY")?,
+                secret_key: env_var("BLOB_S3_SECRET_KEY")
+                    .or_else(|| env_var("AWS_SECRET_ACCESS_KEY"))
+                    .context("missing BLOB_S3_SECRET_KEY")?,
+                prefix: env_var("BLOB_S3_PREFIX").unwrap_or_default(),
+            })),
+            other => anyhow::bail!("invalid BLOB_STORE {other:?}; expected local or s3"),
+        }
+    }
+
+    /// Open the configured store.
+    pub fn open(&self) -> Arc<dyn BlobStore> {
+        match self {
+            Self::Local { dir } => Arc::new(LocalBlobStore::new(dir.clone())),
+            Self::S3(config) => Arc::new(S3BlobStore::new(config.clone())),
+        }
+    }
+}
+
+/// Blobs stored as files under a directory.
+#[derive(Clone, Debug)]
+pub struct LocalBlobStore {
+    root: PathBuf,
+    gc_grace: Duration,
+}
+
+impl LocalBlobStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            gc_grace: DEFAULT_BLOB_GC_GRACE,
+        }
+    }
+
+    /// Keep blobs written or re-written within `grace` when collecting.
+    pub fn with_gc_grace(mut self, grace: Duration) -> Self {
+        self.gc_grace = grace;
+        self
+    }
+
+    fn path(&self, hash: &str) -> Result<PathBuf> {
+        ensure_blob_hash(hash)?;
+        Ok(self.root.join(&hash[..2]).join(hash))
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put(&self, hash: &str, bytes: &[u8]) -> Result<()> {
+        let path = self.path(hash)?;
+        if tokio::fs::try_exists(&path).await.unwrap_or(false) {
+            // Restart the grace period so a concurrent collection of an
+            // older copy cannot delete the blob this writer is about to use.
+            let touched = path.clone();
+            let refreshed = tokio::task::spawn_blocking(move || {
+                std::fs::File::options()
+                    .append(true)
+                    .open(&touched)?
+                    .set_modified(SystemTime::now())
+            })
+            .await?;
+            match refreshed {
+                Ok(()) => return Ok(()),
+                // Collected in the meantime; store it again below.
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => {
+                    return Err(err).with_context(|| format!("failed to refresh blob {hash}"));
+                }
+            }
+        }
+        let dir = path.parent().context("blob path has no directory")?;
+        tokio::fs::create_dir_all(dir)
+            .await
+            .with_context(|| format!("failed to create blob directory {}", dir.display()))?;
+        // Write beside the blob and rename so readers never see a partial file.
+        let partial = dir.join(format!(".{hash}.{}", Uuid::new_v4()));
+        tokio::fs::write(&partial, bytes)
+            .await
+            .with_context(|| format!("failed to write blob {hash}"))?;
+        tokio::fs::rename(&partial, &path)
+            .await
+            .with_context(|| format!("failed to store blob {hash}"))
+    }
+
+    async fn get(&self, hash: &str) -> Result<Option<Vec<u8>>> {
+        match tokio::fs::read(self.path(hash)?).await {
+            Ok(bytes) => verified(hash, bytes).map(Some),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err).with_context(|| format!("failed to read blob {hash}")),
+        }
+    }
+
+    async fn stream(&self, hash: &str) -> Result<Option<BlobStream>> {
+        let file = match tokio::fs::File::open(self.path(hash)?).await {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err).with_context(|| format!("failed to open blob {hash}")),
+        };
+        let chunks = futures::stream::unfold(Some(file), |file| async move {
+            let mut file = file?;
+            let mut chunk = vec![0; LOCAL_CHUNK_SIZE];
+            match file.read(&mut chunk).await {
+                Ok(0) => None,
+                Ok(read) => {
+                    chunk.truncate(read);
+                    Some((Ok(chunk), Some(file)))
+                }
+                Err(err) => Some((Err(err), None)),
+            }
+        });
+        Ok(Some(chunks.boxed()))
+    }
+
+    async fn delete(&self, hash: &str) -> Result<()> {
+        match tokio::fs::remove_file(self.path(hash)?).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err).with_context(|| format!("failed to delete blob {hash}")),
+        }
+    }
+
+    async fn collect(&self, hash: &str) -> Result<bool> {
+        let modified = match tokio::fs::metadata(self.path(hash)?).await {
+            Ok(metadata) => metadata.modified().ok(),
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(false),
+            Err(err) => return Err(err).with_context(|| format!("failed to inspect blob {hash}")),
+        };
+        let age = match modified {
+            Some(modified) => SystemTime::now()
+                .duration_since(modified)
+                .unwrap_or_default(),
+            None => Duration::MAX,
+        };
+        if age < self.gc_grace {
+            return Ok(false);
+        }
+        self.delete(hash).await?;
+        Ok(true)
+    }
+}
+
+/// Connection settings for an S3-compatible bucket.
+#[derive(Clone, PartialEq)]
+pub struct S3BlobConfig {
+    /// Base URL of the service, e.g. `http://localhost:9000` for MinIO.
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key: String,
+    pub secret_key: String,
+    /// Prepended to each hash to form the object key.
+    pub prefix: String,
+}
+
+/// Blobs stored as objects in an S3-compatible bucket, addressed path-style
+/// and signed with AWS Signature Version 4.
+pub struct S3BlobStore {
+    config: S3BlobConfig,
+    client: reqwest::Client,
+}
+
+impl S3BlobStore {
+    pub fn new(config: S3BlobConfig) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    fn request(
+        &self,
+        method: reqwest::Method,
+        hash: &str,
+        body: &[u8],
+    ) -> Result<reqwest::RequestBuilder> {
+        ensure_blob_hash(hash)?;
+        let url = reqwest::Url::parse(&format!(
+            "{}/{}/{}{hash}",
+            self.config.endpoint.trim_end_matches('/'),
+            self.config.bucket,
+            self.config.prefix
+        ))
+        .context("invalid BLOB_S3_ENDPOINT")?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => anyhow::bail!("BLOB_S3_ENDPOINT has no host"),
+        };
+        let now = chrono::Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let payload_hash = blob_hash(body);
+        let signing = SigV4Request {
+            method: method.as_str(),
+            path: url.path(),
+            query: "",
+            headers: &[
+                ("host", host.as_str()),
+                ("x-amz-content-sha256", payload_hash.as_str()),
+                ("x-amz-date", amz_date.as_str()),
+            ],
+            payload_hash: &payload_hash,
+            amz_date: &amz_date,
+            region: &self.config.region,
+            service: "s3",
+        };
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.config.access_key,
+            signing.scope(),
+            signing.signed_headers(),
+            signing.signature(&self.config.secret_key)
+        );
+        Ok(self
+            .client
+            .request(method, url)
+            .header("x-amz-date", amz_date)
+            .header("x-amz-content-sha256", payload_hash)
+            .header("authorization", authorization))
+    }
+
+    async fn fetch(&self, hash: &str) -> Result<Option<reqwest::Response>> {
+        let response = self
+            .request(reqwest::Method::GET, hash, &[])?
+            .send()
+            .await
+            .with_context(|| format!("failed to request blob {hash}"))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        response
+            .error_for_status()
+            .map(Some)
+            .with_context(|| format!("failed to fetch blob {hash}"))
+    }
+}
+
+#[async_trait]
+impl BlobStore for S3BlobStore {
+    async fn put(&self, hash: &str, bytes: &[u8]) -> Result<()> {
+        self.request(reqwest::Method::PUT, hash, bytes)?
+            .body(bytes.to_vec())
+            .send()
+            .await
+            .with_context(|| format!("failed to upload blob {hash}"))?
+            .error_for_status()
+            .with_context(|| format!("failed to store blob {hash}"))?;
+        Ok(())
+    }
+
+    async fn get(&self, hash: &str) -> Result<Option<Vec<u8>>> {
+        let Some(response) = self.fetch(hash).await? else {
+            return Ok(None);
+        };
+        let bytes = response
+            .bytes()
+            .await
+            .with_context(|| format!("failed to read blob {hash}"))?;
+        verified(hash, bytes.to_vec()).map(Some)
+    }
+
+    async fn stream(&self, hash: &str) -> Result<Option<BlobStream>> {
+        Ok(self.fetch(hash).await?.map(|response| {
+            response
+                .bytes_stream()
+                .map(|chunk| {
+                    chunk
+                        .map(|bytes| bytes.to_vec())
+                        .map_err(std::io::Error::other)
+                })
+                .boxed()
+        }))
+    }
+
+    async fn delete(&self, hash: &str) -> Result<()> {
+        let response = self
+            .request(reqwest::Method::DELETE, hash, &[])?
+            .send()
+            .await
+            .with_context(|| format!("failed to request deleting blob {hash}"))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+        response
+            .error_for_status()
+            .with_context(|| format!("failed to delete blob {hash}"))?;
+        Ok(())
+    }
+}
+
+/// A request to sign with AWS Signature Version 4.
+pub struct SigV4Request<'a> {
+    pub method: &'a str,
+    /// URI-encoded absolute path.
+    pub path: &'a str,
+    /// Canonical query string, e.g. `lifecycle=`.
+    pub query: &'a str,
+    /// Signed headers with lower-case names, sorted by name.
+    pub headers: &'a [(&'a str, &'a str)],
+    /// Hex SHA-256 of the body.
+    pub payload_hash: &'a str,
+    /// Request time as `YYYYMMDDTHHMMSSZ`.
+    pub amz_date: &'a str,
+    pub region: &'a str,
+    pub service: &'a str,
+}
+
+impl SigV4Request<'_> {
+    /// Credential scope, e.g. `20130524/us-east-1/s3/aws4_request`.
+    pub fn scope(&self) -> String {
+        format!(
+            "{}/{}/{}/aws4_request",
+            self.date(),
+            self.region,
+            self.service
+        )
+    }
+
+    /// Names of the signed headers joined by `;`.
+    pub fn signed_headers(&self) -> String {
+        self.headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+
+    /// Hex signature of the request made with `secret_key`.
+    pub fn signature(&self, secret_key: &str) -> String {
+        let headers: String = self
+            .headers
+            .iter()
+            .map(|(name, value)| format!("{name}:{}\n", value.trim()))
+            .collect();
+        let canonical_request = format!(
+            "{}\n{}\n{}\n{headers}\n{}\n{}",
+            self.method,
+            self.path,
+            self.query,
+            self.signed_headers(),
+            self.payload_hash
+        );
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            self.amz_date,
+            self.scope(),
+            blob_hash(canonical_request.as_bytes())
+        );
+        let signing_key = [self.region, self.service, "aws4_request"].iter().fold(
+            hmac_sha256(
+                format!("AWS4{secret_key}").as_bytes(),
+                self.date().as_bytes(),
+            ),
+            |key, part| hmac_sha256(&key, part.as_bytes()),
+        );
+        hmac_sha256(&signing_key, string_to_sign.as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    fn date(&self) -> &str {
+        self.amz_date.get(..8).unwrap_or(self.amz_date)
+    }
+}
+
+/// HMAC-SHA256 of `message` under `key` (RFC 2104).
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut block = [0u8; 64];
+    if key.len() > block.len() {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let mut inner = Sha256::new();
+    inner.update(block.map(|byte| byte ^ 0x36));
+    inner.update(message);
+    let mut outer = Sha256::new();
+    outer.update(block.map(|byte| byte ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
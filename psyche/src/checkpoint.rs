@@ -0,0 +1,92 @@
+//! On-disk snapshot of Pete's conversational state.
+//!
+//! [`Psyche`](crate::Psyche) writes a [`PsycheCheckpoint`] after draining on
+//! shutdown and restores it on the next start, so a restart resumes the same
+//! conversation, pending turn, prompt notes and self-story.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// One conversation entry stored in a checkpoint.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CheckpointMessage {
+    /// `"user"` or `"assistant"`.
+    pub role: String,
+    /// Message text.
+    pub content: String,
+    /// When the message was heard or spoken.
+    pub at: DateTime<Utc>,
+}
+
+/// Serializable state needed to resume a [`Psyche`](crate::Psyche).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PsycheCheckpoint {
+    /// Format version, currently [`PsycheCheckpoint::VERSION`].
+    pub version: u32,
+    /// When the checkpoint was written.
+    #[serde(default)]
+    pub saved_at: Option<DateTime<Utc>>,
+    /// Conversation log in order.
+    #[serde(default)]
+    pub conversation: Vec<CheckpointMessage>,
+    /// Turn that was queued but not yet spoken.
+    #[serde(default)]
+    pub pending_turn: Option<String>,
+    /// Emoji Pete was expressing.
+    #[serde(default)]
+    pub emotion: Option<String>,
+    /// Context notes gathered for the next Voice prompt.
+    #[serde(default)]
+    pub prompt_notes: Vec<String>,
+    /// Extra prompt granted to the Voice with its last permit.
+    #[serde(default)]
+    pub voice_extra_prompt: Option<String>,
+    /// Per-wit state keyed by wit name.
+    #[serde(default)]
+    pub wits: BTreeMap<String, Value>,
+}
+
+impl PsycheCheckpoint {
+    /// Current checkpoint format version.
+    pub const VERSION: u32 = 1;
+
+    /// Read a checkpoint from `path`, returning `None` if the file is missing.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        let data = match std::fs::read(path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("failed to read checkpoint {}", path.display()));
+            }
+        };
+        let checkpoint: Self = serde_json::from_slice(&data)
+            .with_context(|| format!("failed to parse checkpoint {}", path.display()))?;
+        anyhow::ensure!(
+            checkpoint.version <= Self::VERSION,
+            "checkpoint {} has unsupported version {}",
+            path.display(),
+            checkpoint.version
+        );
+        Ok(Some(checkpoint))
+    }
+
+    /// Atomically write the checkpoint to `path`.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let tmp = path.with_extension("tmp");
+        let data = serde_json::to_vec_pretty(self).context("failed to serialize checkpoint")?;
+        std::fs::write(&tmp, data)
+            .with_context(|| format!("failed to write checkpoint {}", tmp.display()))?;
+        std::fs::rename(&tmp, path)
+            .with_context(|| format!("failed to move checkpoint into {}", path.display()))?;
+        Ok(())
+    }
+}
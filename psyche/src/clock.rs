@@ -0,0 +1,125 @@
+//! Time sources for cognition loops.
+//!
+//! [`Psyche`](crate::Psyche) and timing-sensitive Wits read the current time
+//! and wait between ticks through a [`Clock`] so simulations can replace wall
+//! time with a [`VirtualClock`] that only moves when the test advances it.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// Source of "now" and of delays for Pete's loops.
+#[async_trait]
+pub trait Clock: Send + Sync {
+    /// Current wall-clock time as seen by cognition.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Wait until `dur` has elapsed on this clock.
+    async fn sleep(&self, dur: Duration);
+
+    /// Random delay up to `max` used to stagger periodic ticks.
+    fn jitter(&self, max: Duration) -> Duration {
+        let max_ms = max.as_millis() as u64;
+        if max_ms == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_millis(rand::thread_rng().gen_range(0..max_ms))
+    }
+}
+
+/// [`Clock`] backed by [`Utc::now`] and [`tokio::time::sleep`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+#[async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    async fn sleep(&self, dur: Duration) {
+        tokio::time::sleep(dur).await;
+    }
+}
+
+/// Manually driven [`Clock`] for deterministic simulations.
+///
+/// Time stands still until [`advance`](Self::advance) or
+/// [`set`](Self::set) is called; sleepers wake once the virtual time reaches
+/// their deadline. Jitter is always zero so tick schedules are reproducible.
+///
+/// ```
+/// use psyche::{Clock, VirtualClock};
+/// use std::time::Duration;
+///
+/// let clock = VirtualClock::default();
+/// let start = clock.now();
+/// clock.advance(Duration::from_secs(5));
+/// assert_eq!((clock.now() - start).num_seconds(), 5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct VirtualClock {
+    now: Arc<watch::Sender<DateTime<Utc>>>,
+}
+
+impl VirtualClock {
+    /// Create a virtual clock starting at `start`.
+    pub fn new(start: DateTime<Utc>) -> Self {
+        let (now, _rx) = watch::channel(start);
+        Self { now: Arc::new(now) }
+    }
+
+    /// Move virtual time forward by `dur`, waking any sleepers that are due.
+    pub fn advance(&self, dur: Duration) {
+        let step = chrono::Duration::from_std(dur).unwrap_or(chrono::Duration::zero());
+        self.now.send_modify(|now| *now += step);
+    }
+
+    /// Jump virtual time to `at`. Moving backwards is ignored.
+    pub fn set(&self, at: DateTime<Utc>) {
+        self.now.send_if_modified(|now| {
+            if at > *now {
+                *now = at;
+                true
+            } else {
+                false
+            }
+        });
+    }
+}
+
+impl Default for VirtualClock {
+    /// Start at a fixed instant so timestamps are identical across runs.
+    fn default() -> Self {
+        Self::new(
+            DateTime::parse_from_rfc3339("2024-01-01T12:00:00Z")
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or_default(),
+        )
+    }
+}
+
+#[async_trait]
+impl Clock for VirtualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.borrow()
+    }
+
+    async fn sleep(&self, dur: Duration) {
+        let step = chrono::Duration::from_std(dur).unwrap_or(chrono::Duration::zero());
+        let deadline = self.now() + step;
+        let mut rx = self.now.subscribe();
+        while *rx.borrow_and_update() < deadline {
+            if rx.changed().await.is_err() {
+                break;
+            }
+        }
+    }
+
+    fn jitter(&self, _max: Duration) -> Duration {
+        Duration::ZERO
+    }
+}
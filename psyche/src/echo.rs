@@ -0,0 +1,352 @@
+//! Recognising Pete's own voice in microphone transcripts.
+//!
+//! When Pete talks through a loudspeaker the microphone hears him too, and
+//! the speech recogniser reports his words as if someone else had said them.
+//! An [`EchoFilter`] remembers what Pete is saying or has just said — from
+//! the TTS stream and from playback start and finish reports — and compares
+//! each incoming transcript with it. Words heard while a line was playing
+//! that follow that line closely are an echo; the caller then drops the
+//! transcript or passes it on as [`Sensation::HeardOwnVoice`]. Every
+//! comparison with a candidate line yields an [`EchoVerdict`], which the
+//! graph observer stores so suppressed speech can be audited.
+//!
+//! ```
+//! use chrono::{Duration, Utc};
+//! use psyche::EchoFilter;
+//!
+//! let filter = EchoFilter::default();
+//! let now = Utc::now();
+//! filter.speaking("The kettle is boiling.", now);
+//! let verdict = filter.check("the kettle is boiling", &[], now + Duration::seconds(1));
+//! assert!(verdict.echo);
+//! assert!(!filter.check("is it?", &[], now + Duration::seconds(1)).echo);
+//! ```
+
+use crate::Sensation;
+use crate::wits::sensation_graph_observer::{sensation_id, utterance_id};
+use anyhow::{Result, bail};
+use chrono::{DateTime, Utc};
+use common::env_or;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// What to do with a transcript recognised as Pete's own voice.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EchoAction {
+    /// Discard it.
+    #[default]
+    Drop,
+    /// Pass it on as [`Sensation::HeardOwnVoice`].
+    Reclassify,
+}
+
+impl std::str::FromStr for EchoAction {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "drop" => Ok(Self::Drop),
+            "reclassify" | "own_voice" => Ok(Self::Reclassify),
+            other => bail!("unknown echo action {other:?}; expected drop or reclassify"),
+        }
+    }
+}
+
+/// Settings for an [`EchoFilter`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct EchoConfig {
+    /// Share of heard words that must follow Pete's line for an echo.
+    pub threshold: f32,
+    /// How long around a line's playback its words may still be heard,
+    /// covering room reverb, recogniser latency and clock skew.
+    pub tail: Duration,
+    /// Number of recent lines remembered.
+    pub history: usize,
+    /// What callers should do with an echo.
+    pub action: EchoAction,
+}
+
+impl Default for EchoConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.6,
+            tail: Duration::from_secs(2),
+            history: 16,
+            action: EchoAction::Drop,
+        }
+    }
+}
+
+impl EchoConfig {
+    /// Read overrides from `ECHO_*` environment variables.
+    pub fn from_env() -> Result<Self> {
+        let d = Self::default();
+        Ok(Self {
+            threshold: env_or("ECHO_THRESHOLD", d.threshold)?.clamp(0.0, 1.0),
+            tail: Duration::from_millis(env_or("ECHO_TAIL_MS", d.tail.as_millis() as u64)?),
+            history: env_or("ECHO_HISTORY", d.history)?,
+            action: env_or("ECHO_ACTION", d.action)?,
+        })
+    }
+}
+
+/// A recognised word and when it was spoken.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeardWord {
+    pub text: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// Outcome of comparing a transcript with what Pete was saying.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EchoVerdict {
+    /// Id the transcript has as heard user speech.
+    pub sensation_id: String,
+    /// The transcript.
+    pub text: String,
+    /// Whether the transcript is Pete's own voice.
+    pub echo: bool,
+    /// Share of heard words that followed Pete's line, in `0.0..=1.0`.
+    pub similarity: f32,
+    /// The line of Pete's it was compared with, if any.
+    pub spoken: Option<String>,
+    /// Graph id of the sensation for that line, when it came from playback.
+    pub spoken_id: Option<String>,
+    /// What was done with an echo.
+    pub action: Option<EchoAction>,
+}
+
+#[derive(Clone, Debug)]
+struct SpokenLine {
+    text: String,
+    words: Vec<String>,
+    started_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+    sensation_id: Option<String>,
+}
+
+impl SpokenLine {
+    /// When the line stops being audible, guessing from its length while
+    /// no finish has been reported.
+    fn end(&self) -> DateTime<Utc> {
+        self.finished_at
+            .unwrap_or_else(|| self.started_at + estimated_duration(self.words.len()))
+    }
+}
+
+/// Rough playback time for `words` at a conversational 150 words a minute.
+fn estimated_duration(words: usize) -> chrono::Duration {
+    chrono::Duration::milliseconds(400 * words as i64)
+}
+
+/// Short history of Pete's speech for spotting it in transcripts.
+#[derive(Debug, Default)]
+pub struct EchoFilter {
+    config: EchoConfig,
+    lines: Mutex<VecDeque<SpokenLine>>,
+}
+
+impl EchoFilter {
+    pub fn new(config: EchoConfig) -> Self {
+        Self {
+            config,
+            lines: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn config(&self) -> &EchoConfig {
+        &self.config
+    }
+
+    /// Note that `text` was synthesised and is about to be played.
+    pub fn queued(&self, text: &str, at: DateTime<Utc>) {
+        self.start(text, at, None);
+    }
+
+    /// Note that playback of `text` started at `at`.
+    pub fn speaking(&self, text: &str, at: DateTime<Utc>) {
+        self.start(
+            text,
+            at,
+            Some(spoken_sensation_id("started_speaking", text, at)),
+        );
+    }
+
+    /// Note that playback of `text` finished at `at`.
+    pub fn finished(&self, text: &str, at: DateTime<Utc>) {
+        let words = words(text);
+        if words.is_empty() {
+            return;
+        }
+        let mut lines = self.lines.lock().unwrap();
+        if let Some(line) = lines
+            .iter_mut()
+            .rev()
+            .find(|line| line.finished_at.is_none() && line.words == words)
+        {
+            line.finished_at = Some(at);
+            return;
+        }
+        let id = spoken_sensation_id("finished_speaking", text, at);
+        let line = SpokenLine {
+            text: text.to_string(),
+            started_at: at - estimated_duration(words.len()),
+            words,
+            finished_at: Some(at),
+            sensation_id: Some(id),
+        };
+        self.remember(&mut lines, line);
+    }
+
+    fn start(&self, text: &str, at: DateTime<Utc>, sensation_id: Option<String>) {
+        let words = words(text);
+        if words.is_empty() {
+            return;
+        }
+        let mut lines = self.lines.lock().unwrap();
+        // Playback of a queued line moves its start to when it is heard.
+        if let Some(line) = lines
+            .iter_mut()
+            .rev()
+            .find(|line| line.finished_at.is_none() && line.words == words)
+        {
+            line.started_at = at;
+            if sensation_id.is_some() {
+                line.sensation_id = sensation_id;
+            }
+            return;
+        }
+        let line = SpokenLine {
+            text: text.to_string(),
+            words,
+            started_at: at,
+            finished_at: None,
+            sensation_id,
+        };
+        self.remember(&mut lines, line);
+    }
+
+    fn remember(&self, lines: &mut VecDeque<SpokenLine>, line: SpokenLine) {
+        lines.push_back(line);
+        while lines.len() > self.config.history.max(1) {
+            lines.pop_front();
+        }
+    }
+
+    /// Compare a transcript with Pete's recent lines.
+    ///
+    /// `words` carries per-word timings when the recogniser has them; only
+    /// words spoken while a line was audible count towards matching it.
+    /// Without them the whole transcript is placed at `occurred_at`.
+    pub fn check(
+        &self,
+        text: &str,
+        words: &[HeardWord],
+        occurred_at: DateTime<Utc>,
+    ) -> EchoVerdict {
+        let heard = if words.is_empty() {
+            self::words(text)
+                .into_iter()
+                .map(|word| (word, occurred_at, occurred_at))
+                .collect::<Vec<_>>()
+        } else {
+            words
+                .iter()
+                .flat_map(|word| {
+                    self::words(&word.text)
+                        .into_iter()
+                        .map(|text| (text, word.start, word.end))
+                })
+                .collect()
+        };
+        let tail = chrono::Duration::from_std(self.config.tail).unwrap_or_default();
+        let mut best: Option<(f32, &SpokenLine)> = None;
+        let lines = self.lines.lock().unwrap();
+        for line in lines.iter() {
+            let from = line.started_at - tail;
+            let to = line.end() + tail;
+            let audible = heard
+                .iter()
+                .filter(|(_, start, end)| *end >= from && *start <= to)
+                .map(|(word, _, _)| word.as_str())
+                .collect::<Vec<_>>();
+            if audible.is_empty() {
+                continue;
+            }
+            let similarity = common_subsequence(&audible, &line.words) as f32 / heard.len() as f32;
+            if best.is_none_or(|(score, _)| similarity > score) {
+                best = Some((similarity, line));
+            }
+        }
+
+        let sensation_id = Sensation::heard_user_voice_at(text, occurred_at).id();
+        let Some((similarity, line)) = best else {
+            return EchoVerdict {
+                sensation_id,
+                text: text.to_string(),
+                echo: false,
+                similarity: 0.0,
+                spoken: None,
+                spoken_id: None,
+                action: None,
+            };
+        };
+        // A word or two matches too easily by chance, so short transcripts
+        // must match completely.
+        let threshold = if heard.len() < 3 {
+            1.0
+        } else {
+            self.config.threshold
+        };
+        let echo = similarity >= threshold;
+        EchoVerdict {
+            sensation_id,
+            text: text.to_string(),
+            echo,
+            similarity,
+            spoken: Some(line.text.clone()),
+            spoken_id: line.sensation_id.clone(),
+            action: echo.then_some(self.config.action),
+        }
+    }
+}
+
+/// Id of the utterance sensation the graph observer stores for Pete's
+/// playback reports.
+fn spoken_sensation_id(speaker: &str, text: &str, at: DateTime<Utc>) -> String {
+    sensation_id(
+        "utterance",
+        &utterance_id(speaker, text, &at),
+        at.to_rfc3339(),
+    )
+}
+
+/// Lower-case words without punctuation.
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '\''))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Length of the longest common subsequence of `heard` and `spoken`.
+fn common_subsequence(heard: &[&str], spoken: &[String]) -> usize {
+    let mut row = vec![0usize; spoken.len() + 1];
+    for word in heard {
+        let mut diagonal = 0;
+        for (j, candidate) in spoken.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if word == candidate {
+                diagonal + 1
+            } else {
+                above.max(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[spoken.len()]
+}
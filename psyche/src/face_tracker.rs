@@ -0,0 +1,354 @@
+//! Following faces from one camera frame to the next.
+//!
+//! Someone sitting in front of the camera shows up in every frame. A
+//! [`FaceTracker`] links each detection to the track it most likely
+//! continues — boxes that overlap and embeddings that look alike — so
+//! identity matching runs once when a track starts, and again only when the
+//! tracker loses confidence that it is still following the same face. Tracks
+//! not seen for a while end, letting callers report people entering and
+//! leaving view instead of repeating themselves every frame. Frames should
+//! arrive roughly in capture order. Callers that store each frame's tracks
+//! elsewhere can [`plan`](FaceTracker::plan) a frame and
+//! [`commit`](FaceTracker::commit) it once stored, so a failed write is
+//! tracked again from the same state.
+//!
+//! ```
+//! use chrono::{Duration, Utc};
+//! use psyche::{BoundingBox, FaceTracker};
+//!
+//! let tracker = FaceTracker::default();
+//! let bbox = BoundingBox { x: 0.4, y: 0.3, width: 0.2, height: 0.3 };
+//! let now = Utc::now();
+//! let first = tracker.update(&[(Some(bbox), &[1.0, 0.0][..])], now);
+//! let second = tracker.update(&[(Some(bbox), &[1.0, 0.0][..])], now + Duration::seconds(1));
+//! assert!(first.assignments[0].started && first.assignments[0].needs_identity);
+//! assert_eq!(second.assignments[0].track_id, first.assignments[0].track_id);
+//! assert!(!second.assignments[0].needs_identity);
+//! ```
+
+use crate::{BoundingBox, GraphFaceMatch};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use common::env_var;
+use lingproc::math::cosine_similarity;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Settings for a [`FaceTracker`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaceTrackerConfig {
+    /// Box overlap at which a detection may continue a track.
+    pub min_iou: f32,
+    /// Embedding similarity at which a detection may continue a track.
+    pub min_similarity: f32,
+    /// Track confidence below which the identity is matched again.
+    pub rematch_below: f32,
+    /// How long a track survives without a detection.
+    pub lost_after: Duration,
+}
+
+impl Default for FaceTrackerConfig {
+    fn default() -> Self {
+        Self {
+            min_iou: 0.3,
+            min_similarity: 0.6,
+            rematch_below: 0.5,
+            lost_after: Duration::from_secs(5),
+        }
+    }
+}
+
+impl FaceTrackerConfig {
+    /// Read overrides from `FACE_TRACK_*` environment variables.
+    pub fn from_env() -> Result<Self> {
+        let d = Self::default();
+        let fraction = |key: &str, default: f32| -> Result<f32> {
+            Ok(match env_var(key) {
+                Some(value) => value
+                    .parse::<f32>()
+                    .with_context(|| format!("invalid {key}"))?
+                    .clamp(0.0, 1.0),
+                None => default,
+            })
+        };
+        Ok(Self {
+            min_iou: fraction("FACE_TRACK_MIN_IOU", d.min_iou)?,
+            min_similarity: fraction("FACE_TRACK_MIN_SIMILARITY", d.min_similarity)?,
+            rematch_below: fraction("FACE_TRACK_REMATCH_BELOW", d.rematch_below)?,
+            lost_after: match env_var("FACE_TRACK_LOST_MS") {
+                Some(value) => {
+                    Duration::from_millis(value.parse().context("invalid FACE_TRACK_LOST_MS")?)
+                }
+                None => d.lost_after,
+            },
+        })
+    }
+}
+
+/// A face followed across frames.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaceTrack {
+    /// Graph id of the track.
+    pub id: String,
+    pub started_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    /// Number of frames the face was detected in.
+    pub frames: usize,
+    /// Latest box of the face.
+    pub bbox: Option<BoundingBox>,
+    /// Latest embedding of the face.
+    pub embedding: Vec<f32>,
+    /// How sure the tracker is that it still follows the face it matched:
+    /// the weakest association since then, in `0.0..=1.0`.
+    pub confidence: f32,
+    /// Known face the track was last matched to.
+    pub recognition: Option<GraphFaceMatch>,
+}
+
+/// Track a detection was assigned to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaceTrackAssignment {
+    pub track_id: String,
+    /// Whether the detection starts a new track.
+    pub started: bool,
+    /// Whether the identity should be matched for this detection; otherwise
+    /// `recognition` carries the track's earlier match.
+    pub needs_identity: bool,
+    /// Known face the track was last matched to.
+    pub recognition: Option<GraphFaceMatch>,
+}
+
+/// Outcome of tracking one frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FaceTrackUpdate {
+    /// One assignment per detection, in detection order.
+    pub assignments: Vec<FaceTrackAssignment>,
+    /// Tracks that ended before the frame.
+    pub ended: Vec<FaceTrack>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct TrackerState {
+    tracks: Vec<FaceTrack>,
+    started: u64,
+}
+
+impl TrackerState {
+    fn identified(&mut self, track_id: &str, recognition: Option<GraphFaceMatch>) {
+        if let Some(track) = self.tracks.iter_mut().find(|track| track.id == track_id) {
+            track.recognition = recognition;
+            track.confidence = 1.0;
+        }
+    }
+}
+
+/// Tracking of one frame that takes effect once
+/// [`commit`](FaceTracker::commit)ted.
+#[derive(Debug)]
+pub struct FaceTrackPlan {
+    update: FaceTrackUpdate,
+    state: TrackerState,
+}
+
+impl FaceTrackPlan {
+    /// Assignments and ended tracks of the frame.
+    pub fn update(&self) -> &FaceTrackUpdate {
+        &self.update
+    }
+
+    /// Record the identity matched for `track_id`, restoring full confidence.
+    pub fn identified(&mut self, track_id: &str, recognition: Option<GraphFaceMatch>) {
+        self.state.identified(track_id, recognition);
+    }
+}
+
+/// Associates face detections across frames.
+#[derive(Debug, Default)]
+pub struct FaceTracker {
+    config: FaceTrackerConfig,
+    source: Option<String>,
+    state: Mutex<TrackerState>,
+}
+
+impl FaceTracker {
+    pub fn new(config: FaceTrackerConfig) -> Self {
+        Self {
+            config,
+            source: None,
+            state: Mutex::new(TrackerState::default()),
+        }
+    }
+
+    /// Follow the frames of one camera or stream, naming its tracks after it
+    /// so they stay distinct from other sources' tracks.
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn config(&self) -> &FaceTrackerConfig {
+        &self.config
+    }
+
+    /// Tracks currently followed.
+    pub fn tracks(&self) -> Vec<FaceTrack> {
+        self.state.lock().unwrap().tracks.clone()
+    }
+
+    /// Assign the faces of a frame captured at `at` to tracks.
+    ///
+    /// Each face is its box, when known, and embedding. Pairs are taken
+    /// best first, scoring each by box overlap and embedding similarity;
+    /// faces left over start new tracks.
+    pub fn update(
+        &self,
+        faces: &[(Option<BoundingBox>, &[f32])],
+        at: DateTime<Utc>,
+    ) -> FaceTrackUpdate {
+        let mut state = self.state.lock().unwrap();
+        self.track(&mut state, faces, at)
+    }
+
+    /// Like [`update`](Self::update), but leave the tracker unchanged until
+    /// the plan is [`commit`](Self::commit)ted. Planning no faces ends lost
+    /// tracks. A plan must be committed or dropped before the next is made.
+    pub fn plan(
+        &self,
+        faces: &[(Option<BoundingBox>, &[f32])],
+        at: DateTime<Utc>,
+    ) -> FaceTrackPlan {
+        let mut state = self.state.lock().unwrap().clone();
+        let update = self.track(&mut state, faces, at);
+        FaceTrackPlan { update, state }
+    }
+
+    /// Apply a plan, returning its update.
+    pub fn commit(&self, plan: FaceTrackPlan) -> FaceTrackUpdate {
+        *self.state.lock().unwrap() = plan.state;
+        plan.update
+    }
+
+    fn track(
+        &self,
+        state: &mut TrackerState,
+        faces: &[(Option<BoundingBox>, &[f32])],
+        at: DateTime<Utc>,
+    ) -> FaceTrackUpdate {
+        let ended = self.expire_locked(state, at);
+
+        let mut pairs = Vec::new();
+        for (face, (bbox, embedding)) in faces.iter().enumerate() {
+            for (track, tracked) in state.tracks.iter().enumerate() {
+                let similarity = cosine_similarity(&tracked.embedding, embedding).max(0.0);
+                let score = match (bbox, &tracked.bbox) {
+                    (Some(bbox), Some(last)) => {
+                        let iou = bbox.iou(last);
+                        if iou < self.config.min_iou && similarity < self.config.min_similarity {
+                            continue;
+                        }
+                        (iou + similarity) / 2.0
+                    }
+                    _ if similarity >= self.config.min_similarity => similarity,
+                    _ => continue,
+                };
+                pairs.push((score, face, track));
+            }
+        }
+        pairs.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        let mut assigned: Vec<Option<usize>> = vec![None; faces.len()];
+        let mut taken = vec![false; state.tracks.len()];
+        for (score, face, track) in pairs {
+            if assigned[face].is_some() || taken[track] {
+                continue;
+            }
+            assigned[face] = Some(track);
+            taken[track] = true;
+            let (bbox, embedding) = faces[face];
+            let tracked = &mut state.tracks[track];
+            tracked.confidence = tracked.confidence.min(score);
+            tracked.frames += 1;
+            if at >= tracked.last_seen_at {
+                tracked.last_seen_at = at;
+                tracked.bbox = bbox;
+                tracked.embedding = embedding.to_vec();
+            }
+        }
+
+        let mut assignments = Vec::with_capacity(faces.len());
+        for (face, track) in assigned.into_iter().enumerate() {
+            let assignment = match track {
+                Some(track) => {
+                    let tracked = &state.tracks[track];
+                    FaceTrackAssignment {
+                        track_id: tracked.id.clone(),
+                        started: false,
+                        needs_identity: tracked.confidence < self.config.rematch_below,
+                        recognition: tracked.recognition.clone(),
+                    }
+                }
+                None => {
+                    state.started += 1;
+                    let (bbox, embedding) = faces[face];
+                    let id = match &self.source {
+                        Some(source) => format!(
+                            "face-track:{source}:{}:{}",
+                            at.timestamp_millis(),
+                            state.started
+                        ),
+                        None => format!("face-track:{}:{}", at.timestamp_millis(), state.started),
+                    };
+                    state.tracks.push(FaceTrack {
+                        id: id.clone(),
+                        started_at: at,
+                        last_seen_at: at,
+                        frames: 1,
+                        bbox,
+                        embedding: embedding.to_vec(),
+                        confidence: 1.0,
+                        recognition: None,
+                    });
+                    FaceTrackAssignment {
+                        track_id: id,
+                        started: true,
+                        needs_identity: true,
+                        recognition: None,
+                    }
+                }
+            };
+            assignments.push(assignment);
+        }
+        FaceTrackUpdate { assignments, ended }
+    }
+
+    /// Record the identity matched for `track_id`, restoring full confidence.
+    pub fn identified(&self, track_id: &str, recognition: Option<GraphFaceMatch>) {
+        self.state.lock().unwrap().identified(track_id, recognition);
+    }
+
+    /// Count every track as seen at `at`, for a frame that repeats the last
+    /// one and so was not run through the detector.
+    pub fn hold(&self, at: DateTime<Utc>) {
+        let mut state = self.state.lock().unwrap();
+        for track in &mut state.tracks {
+            if at > track.last_seen_at {
+                track.last_seen_at = at;
+            }
+        }
+    }
+
+    /// End and return tracks not seen for `lost_after` before `now`.
+    pub fn expire(&self, now: DateTime<Utc>) -> Vec<FaceTrack> {
+        let mut state = self.state.lock().unwrap();
+        self.expire_locked(&mut state, now)
+    }
+
+    fn expire_locked(&self, state: &mut TrackerState, now: DateTime<Utc>) -> Vec<FaceTrack> {
+        let lost_after = chrono::Duration::from_std(self.config.lost_after).unwrap_or_default();
+        let (ended, live) = std::mem::take(&mut state.tracks)
+            .into_iter()
+            .partition(|track| now - track.last_seen_at > lost_after);
+        state.tracks = live;
+        ended
+    }
+}
@@ -1,9 +1,20 @@
 //! Core cognitive engine powering Pete.
 
+mod addressee;
+mod blobs;
+pub mod checkpoint;
+pub mod clock;
 mod default_prompt;
+mod echo;
+mod face_tracker;
 mod instruction;
+mod look;
+mod person_link;
+mod privacy;
 pub mod psyche;
+mod scene_change;
 pub mod sensation;
+pub mod shutdown;
 pub mod topics;
 pub mod util;
 mod voice;
@@ -29,7 +40,7 @@ pub mod traits {
     pub use mouth::Mouth;
     pub use observer::SensationObserver;
     pub use sensor::Sensor;
-    pub use tts::{Tts, TtsStream};
+    pub use tts::{Tts, TtsAudio, TtsRequest, TtsStream, TtsVoice};
     pub use wit::{ErasedWit, Wit, WitAdapter};
 }
 
@@ -62,18 +73,25 @@ pub mod wits {
     pub use memory::{
         BasicMemory, GraphAudioClip, GraphAudioClipWindow, GraphAudioSourceSpan, GraphAwareness,
         GraphClusterItem, GraphClusterTheme, GraphCombobulationEmotion,
-        GraphConsolidatedSpeechCandidate, GraphConsolidatedSpeechSource, GraphFaceDetection,
-        GraphFaceIdentity, GraphFaceIdentityLabel, GraphFaceIdentityTarget, GraphFaceMatch,
-        GraphGeolocation, GraphImageDescription, GraphImageFrame, GraphImpressionTimelineItem,
-        GraphLatestCombobulation, GraphMovieImageFrame, GraphMovieSpeechSegment, GraphNodeDetails,
-        GraphNodeSnapshot, GraphRelationshipSnapshot, GraphSceneVectorization,
-        GraphSensationTimelineItem, GraphSnapshot, GraphSpeechConsolidationReport,
-        GraphSpeechIntention, GraphSpeechSegment, GraphSpeechSegmentAudio, GraphStore,
-        GraphTimelineItem, GraphTimelineWindow, GraphVoiceClip, GraphVoiceIdentity,
-        GraphVoiceIdentityLabel, GraphVoiceIdentityTarget, GraphVoiceMatch, GraphVoiceRecognition,
-        GraphVoiceSample, GraphVoiceSignature, Memory, Neo4jClient, NoopMemory, QdrantClient,
-        QdrantNearestNeighbor, QdrantVectorPoint, VectorCluster, VectorClusterMember,
-        find_vector_clusters, qdrant_vector_collections,
+        GraphConsolidatedSpeechCandidate, GraphConsolidatedSpeechSource, GraphDiarization,
+        GraphDiarizationCandidate, GraphDiarizationSegment, GraphDiarizationSource,
+        GraphDiarizedSpeaker, GraphFaceDetection, GraphFaceIdentity, GraphFaceIdentityLabel,
+        GraphFaceIdentityTarget, GraphFaceMatch, GraphFaceRegion, GraphFaceTrack, GraphGeolocation,
+        GraphIdentity, GraphIdentityPurge, GraphIdentityVector, GraphImageDescription,
+        GraphImageFrame, GraphImageRegion, GraphImpressionTimelineItem, GraphLatestCombobulation,
+        GraphLookAnswer, GraphMediaPayload, GraphMovieImageFrame, GraphMovieSpeechSegment,
+        GraphNodeDetails, GraphNodeSnapshot, GraphObjectDetection, GraphPerson,
+        GraphPersonCandidate, GraphPersonCoOccurrence, GraphPersonRecognition,
+        GraphRelationshipSnapshot, GraphSceneDuplicate, GraphSceneFrame, GraphSceneVectorization,
+        GraphSensationTimelineItem, GraphSnapshot, GraphSpeakerAttribution, GraphSpeakerTurn,
+        GraphSpeechConsolidationReport, GraphSpeechIntention, GraphSpeechSegment,
+        GraphSpeechSegmentAudio, GraphStore, GraphTextReading, GraphTimelineItem,
+        GraphTimelineWindow, GraphVoiceClip, GraphVoiceIdentity, GraphVoiceIdentityLabel,
+        GraphVoiceIdentityTarget, GraphVoiceMatch, GraphVoiceRecognition, GraphVoiceSample,
+        GraphVoiceSignature, ImageRunKind, LeaseSettings, Memory, Neo4jClient, NoopMemory,
+        QdrantClient, QdrantNearestNeighbor, QdrantVectorPoint, VectorCluster, VectorClusterMember,
+        WorkLease, find_vector_clusters, person_identity_id, qdrant_vector_collections,
+        with_work_lease,
     };
     pub use memory_wit::MemoryWit;
     pub use moment_wit::MomentWit;
@@ -86,6 +104,7 @@ pub mod wits {
 }
 
 mod and_mouth;
+mod barge_in;
 
 mod debug;
 
@@ -98,6 +117,7 @@ mod plain_mouth;
 /// The `prompt` module is kept public so callers may use `psyche::prompt::*`.
 /// Key prompt types are also re-exported at the crate root for convenience.
 pub mod prompt;
+mod prosody;
 mod task_group;
 pub use task_group::TaskGroup;
 pub mod sensors {
@@ -108,29 +128,81 @@ pub mod sensors {
     #[cfg(feature = "face")]
     pub mod face;
     #[cfg(feature = "face")]
-    pub use face::{DummyDetector, FaceDetector, FaceIdDetector, FaceInfo, FaceSensor};
+    pub use face::{
+        DetectedFace, DummyDetector, FaceDetector, FaceIdDetector, FaceInfo, FaceSensor,
+    };
+    pub mod object;
+    #[cfg(feature = "objects")]
+    pub use object::YoloObjectDetector;
+    pub use object::{
+        COCO_LABELS, DummyObjectDetector, Letterbox, ObjectDetector, YoloConfig, decode_yolo,
+    };
+    pub mod text;
+    #[cfg(feature = "ocr")]
+    pub use text::PaddleTextRecognizer;
+    pub use text::{
+        DummyTextRecognizer, OcrConfig, TextRecognizer, decode_ctc, distinct_text_blocks,
+        find_text_regions, parse_ocr_dictionary,
+    };
 }
 mod pending_turn;
 mod trim_mouth;
 mod types;
+mod viseme;
 
+pub use addressee::{
+    AddresseeConfig, AddresseeCues, AddresseeDetector, AddresseeJudge, AddresseeVerdict,
+    ChatterAddresseeJudge,
+};
 pub use and_mouth::AndMouth;
+pub use barge_in::BargeIn;
+pub use blobs::{
+    BlobConfig, BlobStore, BlobStream, DEFAULT_BLOB_GC_GRACE, LocalBlobStore, S3BlobConfig,
+    S3BlobStore, SigV4Request, blob_hash, hmac_sha256, is_blob_hash,
+};
+pub use checkpoint::{CheckpointMessage, PsycheCheckpoint};
+pub use clock::{Clock, SystemClock, VirtualClock};
 pub use debug::{DebugHandle, DebugInfo, debug_enabled, disable_debug, enable_debug};
 pub use default_prompt::{DEFAULT_SYSTEM_PROMPT, with_default_system_prompt};
+pub use echo::{EchoAction, EchoConfig, EchoFilter, EchoVerdict, HeardWord};
+pub use face_tracker::{
+    FaceTrack, FaceTrackAssignment, FaceTrackPlan, FaceTrackUpdate, FaceTracker, FaceTrackerConfig,
+};
 pub use instruction::{HostInstruction, parse_instructions};
+#[cfg(feature = "look")]
+pub use look::crop_image;
+pub use look::{LOOK_PADDING, LookRegion};
 pub use model::{Experience, Impression, Stimulus};
 pub use pending_turn::PendingTurn;
+pub use person_link::{
+    PersonLink, PersonLinkConfig, PersonLinker, PersonNames, person_co_occurrences,
+    strongest_person_pairs,
+};
 pub use plain_mouth::PlainMouth;
+#[cfg(all(feature = "face", feature = "privacy"))]
+pub use privacy::FaceRedactor;
+#[cfg(feature = "privacy")]
+pub use privacy::blur_regions;
+pub use privacy::{
+    FaceConsent, FaceConsentCheck, ImageRedactor, OptOutAction, PrivacyConfig, Redaction, pixelate,
+};
 pub use prompt::{
     CONVERSATION_SPEAKER_NOTE, CombobulatorPrompt, ContextualPrompt, IMAGE_CAPTION_PROMPT,
-    IMAGE_SENSATION_TEXT, PromptFragment, SENSOR_GROUNDING_RULES, VoicePrompt, WillPrompt,
-    face_count_sensation_text, face_familiarity_sensation_text, face_identity_sensation_text,
+    IMAGE_SENSATION_TEXT, LOOK_AT_PROMPT, PromptFragment, SENSOR_GROUNDING_RULES, VoicePrompt,
+    WillPrompt, face_count_sensation_text, face_familiarity_sensation_text,
+    face_identity_sensation_text, face_track_entered_sensation_text,
+    face_track_left_sensation_text, look_at_sensation_text,
 };
+pub use prosody::{Affect, Prosody, ProsodyMap};
+#[cfg(feature = "scene-change")]
+pub use scene_change::SceneGate;
+pub use scene_change::{SceneChangeConfig, SceneComparison, SceneFingerprint};
 pub use topics::{Topic, TopicBus, TopicMessage};
 pub use trim_mouth::TrimMouth;
 pub use types::{
-    AudioClip, BrowserMotion, CombobulationSummary, ConversationEntry, Decision, DeviceOrientation,
-    GeoEmbedding, GeoLoc, Heartbeat, ImageData, ImageEmbedding, MotionVector, ObjectInfo, Thought,
+    AudioClip, BoundingBox, BrowserMotion, CombobulationSummary, ConversationEntry, Decision,
+    DeviceOrientation, FaceDetails, FaceLandmark, FaceQuality, GeoEmbedding, GeoLoc, Heartbeat,
+    ImageData, ImageEmbedding, MotionVector, ObjectInfo, PartialUtterance, TextBlock, Thought,
     VoiceInfo, WillTypeScriptExecution, WillTypeScriptResult, audio_captured_at, audio_clip_id,
     browser_motion_content_id, browser_motion_observed_at, geoloc_content_id, geoloc_observed_at,
     geoloc_vector, image_captured_at, image_content_id, parse_observed_at,
@@ -140,29 +212,52 @@ pub use ling::{Feeling, PromptBuilder};
 pub use psyche::extract_tag as test_extract_tag;
 pub use psyche::{Conversation, Psyche};
 pub use sensation::{Event, Sensation, WitReport};
+#[cfg(feature = "ocr")]
+pub use sensors::PaddleTextRecognizer;
+#[cfg(feature = "objects")]
+pub use sensors::YoloObjectDetector;
+pub use sensors::{
+    COCO_LABELS, DummyObjectDetector, Letterbox, ObjectDetector, YoloConfig, decode_yolo,
+};
 #[cfg(feature = "face")]
-pub use sensors::{DummyDetector, FaceDetector, FaceIdDetector, FaceInfo, FaceSensor};
+pub use sensors::{
+    DetectedFace, DummyDetector, FaceDetector, FaceIdDetector, FaceInfo, FaceSensor,
+};
+pub use sensors::{
+    DummyTextRecognizer, OcrConfig, TextRecognizer, decode_ctc, distinct_text_blocks,
+    find_text_regions, parse_ocr_dictionary,
+};
 #[cfg(feature = "image-vector")]
 pub use sensors::{ImageVectorSensor, RuVectorCnnImageVectorizer, WholeImageVectorizer};
+pub use shutdown::Shutdown;
 pub use traits::{
     BufferedWit, Doer, Ear, ErasedWit, Motor, Mouth, NoopMotor, SensationObserver, Sensor, Tts,
-    TtsStream, Wit, WitAdapter,
+    TtsAudio, TtsRequest, TtsStream, TtsVoice, Wit, WitAdapter,
+};
+pub use viseme::{
+    PhonemeTiming, VisemeCue, phoneme_viseme, visemes_from_phonemes, visemes_from_text,
 };
 pub use voice::{Voice, extract_emojis};
 pub use wits::{
     BasicMemory, Combobulator, EntityWit, EpisodeWit, FaceMemoryWit, FondDuCoeur, GraphAudioClip,
     GraphAudioClipWindow, GraphAudioSourceSpan, GraphAwareness, GraphClusterItem,
     GraphClusterTheme, GraphCombobulationEmotion, GraphConsolidatedSpeechCandidate,
-    GraphConsolidatedSpeechSource, GraphFaceDetection, GraphFaceIdentity, GraphFaceIdentityLabel,
-    GraphFaceIdentityTarget, GraphFaceMatch, GraphGeolocation, GraphImageDescription,
-    GraphImageFrame, GraphImpressionTimelineItem, GraphLatestCombobulation, GraphMovieImageFrame,
-    GraphMovieSpeechSegment, GraphNodeDetails, GraphNodeSnapshot, GraphRelationshipSnapshot,
-    GraphSceneVectorization, GraphSensationTimelineItem, GraphSnapshot,
-    GraphSpeechConsolidationReport, GraphSpeechIntention, GraphSpeechSegment,
-    GraphSpeechSegmentAudio, GraphStore, GraphTimelineItem, GraphTimelineWindow, GraphVoiceClip,
-    GraphVoiceIdentity, GraphVoiceIdentityLabel, GraphVoiceIdentityTarget, GraphVoiceMatch,
-    GraphVoiceRecognition, GraphVoiceSample, GraphVoiceSignature, HeartWit, IdentityWit, Memory,
-    MemoryWit, Neo4jClient, NoopMemory, QdrantClient, QdrantNearestNeighbor, QdrantVectorPoint,
-    SensationGraphObserver, VectorCluster, VectorClusterMember, VisionWit, VoiceMemoryWit, Will,
-    find_vector_clusters, qdrant_vector_collections,
+    GraphConsolidatedSpeechSource, GraphDiarization, GraphDiarizationCandidate,
+    GraphDiarizationSegment, GraphDiarizationSource, GraphDiarizedSpeaker, GraphFaceDetection,
+    GraphFaceIdentity, GraphFaceIdentityLabel, GraphFaceIdentityTarget, GraphFaceMatch,
+    GraphFaceRegion, GraphFaceTrack, GraphGeolocation, GraphIdentity, GraphIdentityPurge,
+    GraphIdentityVector, GraphImageDescription, GraphImageFrame, GraphImageRegion,
+    GraphImpressionTimelineItem, GraphLatestCombobulation, GraphLookAnswer, GraphMediaPayload,
+    GraphMovieImageFrame, GraphMovieSpeechSegment, GraphNodeDetails, GraphNodeSnapshot,
+    GraphObjectDetection, GraphPerson, GraphPersonCandidate, GraphPersonCoOccurrence,
+    GraphPersonRecognition, GraphRelationshipSnapshot, GraphSceneDuplicate, GraphSceneFrame,
+    GraphSceneVectorization, GraphSensationTimelineItem, GraphSnapshot, GraphSpeakerAttribution,
+    GraphSpeakerTurn, GraphSpeechConsolidationReport, GraphSpeechIntention, GraphSpeechSegment,
+    GraphSpeechSegmentAudio, GraphStore, GraphTextReading, GraphTimelineItem, GraphTimelineWindow,
+    GraphVoiceClip, GraphVoiceIdentity, GraphVoiceIdentityLabel, GraphVoiceIdentityTarget,
+    GraphVoiceMatch, GraphVoiceRecognition, GraphVoiceSample, GraphVoiceSignature, HeartWit,
+    IdentityWit, ImageRunKind, LeaseSettings, Memory, MemoryWit, Neo4jClient, NoopMemory,
+    QdrantClient, QdrantNearestNeighbor, QdrantVectorPoint, SensationGraphObserver, VectorCluster,
+    VectorClusterMember, VisionWit, VoiceMemoryWit, Will, WorkLease, find_vector_clusters,
+    person_identity_id, qdrant_vector_collections, with_work_lease,
 };
@@ -115,4 +115,14 @@ impl PromptBuilder {
     pub fn flush(&mut self) {
         self.notes.clear();
     }
+
+    /// Return the temporary notes gathered since the last turn.
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    /// Replace the temporary notes, e.g. when resuming from a checkpoint.
+    pub fn restore_notes(&mut self, notes: Vec<String>) {
+        self.notes = notes;
+    }
 }
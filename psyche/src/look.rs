@@ -0,0 +1,146 @@
+//! Looking closer at part of a camera frame.
+//!
+//! The Will can ask a question about one region of the latest frame, named by
+//! the person or object seen there or given as a normalised box. The region is
+//! cropped with a margin of context around it, and the vision model sees only
+//! the crop, so small things fill its view.
+//!
+//! ```
+//! use psyche::{BoundingBox, GraphImageRegion, LookRegion};
+//!
+//! let regions = [GraphImageRegion {
+//!     node_id: "face:1".into(),
+//!     kind: "face".into(),
+//!     name: Some("Travis".into()),
+//!     track_id: None,
+//!     bbox: BoundingBox { x: 0.5, y: 0.1, width: 0.2, height: 0.3 },
+//! }];
+//! let (bbox, region) = LookRegion::Named("travis".into()).locate(&regions).unwrap();
+//! assert_eq!(region.unwrap().node_id, "face:1");
+//! assert_eq!(bbox.x, 0.5);
+//! ```
+
+use crate::{BoundingBox, GraphImageRegion};
+
+/// Margin added around a looked-at region, as a fraction of its size.
+pub const LOOK_PADDING: f32 = 0.15;
+
+/// Part of a frame to look at.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LookRegion {
+    /// A face or object in the frame, by identity name, object class, or the
+    /// graph id of its node or face track.
+    Named(String),
+    /// Box within the frame, as fractions of its width and height.
+    Box(BoundingBox),
+}
+
+impl LookRegion {
+    /// Box of this region within a frame showing `regions`, and the region
+    /// it names. The largest match wins when a name fits several.
+    pub fn locate<'a>(
+        &self,
+        regions: &'a [GraphImageRegion],
+    ) -> Option<(BoundingBox, Option<&'a GraphImageRegion>)> {
+        match self {
+            Self::Box(bbox) => clamped(bbox).map(|bbox| (bbox, None)),
+            Self::Named(name) => {
+                let name = name.trim();
+                regions
+                    .iter()
+                    .filter(|region| {
+                        region.node_id == name
+                            || region.track_id.as_deref() == Some(name)
+                            || region
+                                .name
+                                .as_deref()
+                                .is_some_and(|known| known.trim().eq_ignore_ascii_case(name))
+                    })
+                    .filter_map(|region| clamped(&region.bbox).map(|bbox| (bbox, region)))
+                    .max_by(|(left, _), (right, _)| area(left).total_cmp(&area(right)))
+                    .map(|(bbox, region)| (bbox, Some(region)))
+            }
+        }
+    }
+
+    /// How the region reads in a sensation, e.g. "Travis".
+    pub fn describe(&self, region: Option<&GraphImageRegion>) -> String {
+        match (self, region.and_then(|region| region.name.as_deref())) {
+            (_, Some(name)) => name.trim().to_string(),
+            (Self::Named(name), None) => name.trim().to_string(),
+            (Self::Box(bbox), None) => format!(
+                "the area at x {:.2}, y {:.2}, {:.2} wide and {:.2} high",
+                bbox.x, bbox.y, bbox.width, bbox.height
+            ),
+        }
+    }
+}
+
+/// `bbox` cut to the frame, or `None` when nothing of it is inside.
+fn clamped(bbox: &BoundingBox) -> Option<BoundingBox> {
+    let values = [bbox.x, bbox.y, bbox.width, bbox.height];
+    if values.iter().any(|value| !value.is_finite()) {
+        return None;
+    }
+    let x = bbox.x.clamp(0.0, 1.0);
+    let y = bbox.y.clamp(0.0, 1.0);
+    let width = (bbox.x + bbox.width).clamp(0.0, 1.0) - x;
+    let height = (bbox.y + bbox.height).clamp(0.0, 1.0) - y;
+    (width > 0.0 && height > 0.0).then_some(BoundingBox {
+        x,
+        y,
+        width,
+        height,
+    })
+}
+
+fn area(bbox: &BoundingBox) -> f32 {
+    bbox.width * bbox.height
+}
+
+/// Decode `image`, cut out `region` grown by `padding` of its size on every
+/// side, and encode the crop in the image's own format.
+#[cfg(feature = "look")]
+pub fn crop_image(
+    image: &crate::ImageData,
+    region: &BoundingBox,
+    padding: f32,
+) -> anyhow::Result<crate::ImageData> {
+    use anyhow::Context;
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+
+    let region = clamped(region).context("region is outside the frame")?;
+    let region = crate::privacy::padded(&region, padding);
+    let bytes = BASE64_STANDARD
+        .decode(image.base64.trim().as_bytes())
+        .context("failed to decode image payload")?;
+    let rgb = image::load_from_memory(&bytes)
+        .context("failed to decode image")?
+        .to_rgb8();
+    let (width, height) = rgb.dimensions();
+    let x0 = (region.x * width as f32).floor() as u32;
+    let y0 = (region.y * height as f32).floor() as u32;
+    let x1 = ((region.x + region.width) * width as f32)
+        .ceil()
+        .min(width as f32) as u32;
+    let y1 = ((region.y + region.height) * height as f32)
+        .ceil()
+        .min(height as f32) as u32;
+    anyhow::ensure!(x1 > x0 && y1 > y0, "region is smaller than a pixel");
+    let crop = image::imageops::crop_imm(&rgb, x0, y0, x1 - x0, y1 - y0).to_image();
+    let format = if image.mime == "image/png" {
+        image::ImageFormat::Png
+    } else {
+        image::ImageFormat::Jpeg
+    };
+    let mut encoded = std::io::Cursor::new(Vec::new());
+    crop.write_to(&mut encoded, format)
+        .context("failed to encode cropped image")?;
+    Ok(crate::ImageData {
+        mime: format.to_mime_type().to_string(),
+        base64: BASE64_STANDARD.encode(encoded.into_inner()),
+        captured_at: image.captured_at.clone(),
+        source: image.source.clone(),
+    })
+}
@@ -117,6 +117,19 @@ impl<T> Impression<T> {
         }
     }
 
+    /// Create an impression that was made at `timestamp`.
+    pub fn at(
+        stimuli: Vec<Stimulus<T>>,
+        summary: impl Into<String>,
+        emoji: Option<impl Into<String>>,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            timestamp,
+            ..Self::new(stimuli, summary, emoji)
+        }
+    }
+
     /// Return this impression timestamp in the host's local timezone.
     pub fn localized_timestamp(&self) -> String {
         localized_timestamp(self.timestamp)
@@ -47,6 +47,11 @@ impl PendingTurn {
         self.inner.lock().unwrap().take()
     }
 
+    /// Return a copy of the pending prompt without taking it.
+    pub fn peek(&self) -> Option<String> {
+        self.inner.lock().unwrap().clone()
+    }
+
     /// Return `true` when no turn is pending.
     pub fn is_empty(&self) -> bool {
         self.inner.lock().unwrap().is_none()
@@ -0,0 +1,286 @@
+//! Deciding which face and which voice belong to the same person.
+//!
+//! Faces and voices are clustered and named separately, so nothing says the
+//! face Pete calls Anna speaks with the voice he also calls Anna — or with an
+//! unnamed voice heard every time that face is in view. A [`PersonLinker`]
+//! counts the time windows in which a recognised face and a recognised voice
+//! show up together, and once a pair has co-occurred often enough attaches
+//! both clusters to one persisted `Person`. Recognition then names a face or
+//! voice after its person when it has no name of its own.
+//!
+//! Each run only reads the recognitions since the last one: window tallies
+//! accumulate on `CO_OCCURS_WITH` relationships, and a progress node records
+//! how far they reach.
+//!
+//! ```
+//! use psyche::{GraphPersonCandidate, strongest_person_pairs};
+//!
+//! let pair = |face: &str, voice: &str, windows| GraphPersonCandidate {
+//!     face_id: face.into(),
+//!     voice_id: voice.into(),
+//!     windows,
+//! };
+//! let pairs = strongest_person_pairs(vec![
+//!     pair("face:anna", "voice:1", 9),
+//!     pair("face:bob", "voice:1", 4),
+//!     pair("face:bob", "voice:2", 3),
+//! ]);
+//! assert_eq!(pairs, vec![pair("face:anna", "voice:1", 9), pair("face:bob", "voice:2", 3)]);
+//! ```
+
+use crate::{
+    GraphPerson, GraphPersonCandidate, GraphPersonCoOccurrence, GraphPersonRecognition, Neo4jClient,
+};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use common::env_or;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::time::Duration;
+use tracing::debug;
+
+/// Settings for a [`PersonLinker`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PersonLinkConfig {
+    /// How close a face and a voice must be recognised to co-occur.
+    pub window: Duration,
+    /// Distinct windows a pair must co-occur in before it is linked.
+    pub min_windows: u32,
+    /// How far back recognitions are counted on the first run, before any
+    /// progress has been recorded.
+    pub lookback: Duration,
+}
+
+impl Default for PersonLinkConfig {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(10),
+            min_windows: 3,
+            lookback: Duration::from_secs(7 * 24 * 60 * 60),
+        }
+    }
+}
+
+impl PersonLinkConfig {
+    /// Read overrides from `PERSON_LINK_*` environment variables.
+    pub fn from_env() -> Result<Self> {
+        let d = Self::default();
+        let millis = |key: &str, default: Duration| -> Result<Duration> {
+            Ok(Duration::from_millis(env_or(
+                key,
+                default.as_millis() as u64,
+            )?))
+        };
+        Ok(Self {
+            window: millis("PERSON_LINK_WINDOW_MS", d.window)?,
+            min_windows: env_or("PERSON_LINK_MIN_WINDOWS", d.min_windows)?.max(1),
+            lookback: millis("PERSON_LINK_LOOKBACK_MS", d.lookback)?,
+        })
+    }
+}
+
+/// Face and voice attached to a person.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PersonLink {
+    pub person_id: String,
+    pub face_id: String,
+    pub voice_id: String,
+    /// Windows the pair co-occurred in.
+    pub windows: u32,
+}
+
+/// How prompts refer to people.
+///
+/// Named people go by their name; the others are `unknown person N` after
+/// the ordinal stored on their person when it was first linked, so the same
+/// person keeps the same name across prompts and as people come and go.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PersonNames {
+    names: HashMap<String, String>,
+}
+
+impl PersonNames {
+    pub fn new(people: &[GraphPerson]) -> Self {
+        // People linked before ordinals were stored follow the numbered ones.
+        let mut last = people
+            .iter()
+            .filter_map(|person| person.ordinal)
+            .max()
+            .unwrap_or(0);
+        let names = people
+            .iter()
+            .map(|person| {
+                let name = match person.name.as_deref().map(str::trim) {
+                    Some(name) if !name.is_empty() => name.to_string(),
+                    _ => {
+                        let ordinal = person.ordinal.unwrap_or_else(|| {
+                            last += 1;
+                            last
+                        });
+                        format!("unknown person {ordinal}")
+                    }
+                };
+                (person.person_id.clone(), name)
+            })
+            .collect();
+        Self { names }
+    }
+
+    /// What to call the person `person_id`, preferring `identity` when the
+    /// face or voice it was found through is already named.
+    pub fn name(&self, person_id: &str, identity: Option<&str>) -> String {
+        match identity.map(str::trim) {
+            Some(identity) if !identity.is_empty() => identity.to_string(),
+            _ => self
+                .names
+                .get(person_id)
+                .cloned()
+                .unwrap_or_else(|| "unknown person".into()),
+        }
+    }
+}
+
+/// Windows in which a face and a voice were recognised within `window_ms` of
+/// each other, counting only pairs whose later recognition is at or after
+/// `from_ms`. A pair's window is the one its voice recognition falls in.
+///
+/// Recognitions are bucketed by window, so each voice is only compared with
+/// the faces of its own and the neighbouring windows.
+pub fn person_co_occurrences(
+    recognitions: &[GraphPersonRecognition],
+    window_ms: i64,
+    from_ms: i64,
+) -> Vec<GraphPersonCoOccurrence> {
+    let window_ms = window_ms.max(1);
+    let mut faces: HashMap<i64, Vec<&GraphPersonRecognition>> = HashMap::new();
+    for face in recognitions.iter().filter(|r| !r.voice) {
+        faces
+            .entry(face.at_ms.div_euclid(window_ms))
+            .or_default()
+            .push(face);
+    }
+    let mut pairs: BTreeMap<(&str, &str), BTreeSet<i64>> = BTreeMap::new();
+    for voice in recognitions.iter().filter(|r| r.voice) {
+        let window = voice.at_ms.div_euclid(window_ms);
+        for face in (window - 1..=window + 1).flat_map(|w| faces.get(&w).into_iter().flatten()) {
+            if (face.at_ms - voice.at_ms).abs() <= window_ms
+                && face.at_ms.max(voice.at_ms) >= from_ms
+            {
+                pairs
+                    .entry((face.cluster_id.as_str(), voice.cluster_id.as_str()))
+                    .or_default()
+                    .insert(window);
+            }
+        }
+    }
+    pairs
+        .into_iter()
+        .map(|((face_id, voice_id), windows)| GraphPersonCoOccurrence {
+            face_id: face_id.to_string(),
+            voice_id: voice_id.to_string(),
+            windows: windows.into_iter().collect(),
+        })
+        .collect()
+}
+
+/// Keep the most frequent pairing of each face and each voice.
+///
+/// Pairs are taken best first; a pair is dropped once its face or its voice
+/// has been paired, so a voice heard while two faces are in view goes to the
+/// face it is heard with most.
+pub fn strongest_person_pairs(
+    mut candidates: Vec<GraphPersonCandidate>,
+) -> Vec<GraphPersonCandidate> {
+    candidates.sort_by(|a, b| b.windows.cmp(&a.windows));
+    let mut pairs: Vec<GraphPersonCandidate> = Vec::new();
+    for candidate in candidates {
+        if pairs
+            .iter()
+            .any(|pair| pair.face_id == candidate.face_id || pair.voice_id == candidate.voice_id)
+        {
+            continue;
+        }
+        pairs.push(candidate);
+    }
+    pairs
+}
+
+/// Links faces and voices that keep showing up together into people.
+#[derive(Clone, Debug, Default)]
+pub struct PersonLinker {
+    config: PersonLinkConfig,
+}
+
+impl PersonLinker {
+    pub fn new(config: PersonLinkConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &PersonLinkConfig {
+        &self.config
+    }
+
+    /// Tally the co-occurrences of recognitions since the last run, up to a
+    /// window before `now` so late partners of the newest ones still count,
+    /// returning how many face and voice pairs gained windows.
+    pub async fn tally(&self, graph: &Neo4jClient, now: DateTime<Utc>) -> Result<usize> {
+        let window = chrono::Duration::from_std(self.config.window).unwrap_or_default();
+        let through = match graph.person_link_progress().await? {
+            Some(through) => DateTime::parse_from_rfc3339(&through)
+                .with_context(|| format!("invalid person link progress {through:?}"))?
+                .with_timezone(&Utc),
+            None => now - chrono::Duration::from_std(self.config.lookback).unwrap_or_default(),
+        };
+        let until = now - window;
+        if until <= through {
+            return Ok(0);
+        }
+        // Recognitions just before `through` may pair with new ones after it.
+        let recognitions = graph
+            .person_recognitions(&(through - window).to_rfc3339(), &until.to_rfc3339())
+            .await?;
+        let co_occurrences = person_co_occurrences(
+            &recognitions,
+            window.num_milliseconds(),
+            through.timestamp_millis(),
+        );
+        graph
+            .record_person_co_occurrences(&co_occurrences, &until.to_rfc3339())
+            .await?;
+        Ok(co_occurrences.len())
+    }
+
+    /// Pairs that would be linked from the windows tallied so far.
+    pub async fn candidates(&self, graph: &Neo4jClient) -> Result<Vec<GraphPersonCandidate>> {
+        let candidates = graph
+            .person_link_candidates(self.config.min_windows)
+            .await?;
+        Ok(strongest_person_pairs(candidates))
+    }
+
+    /// Tally recognitions up to `now`, then attach every pair that
+    /// co-occurred often enough to a person.
+    ///
+    /// Pairs whose face and voice already belong to different people are
+    /// left alone.
+    pub async fn link(&self, graph: &Neo4jClient, now: DateTime<Utc>) -> Result<Vec<PersonLink>> {
+        self.tally(graph, now).await?;
+        let mut links = Vec::new();
+        for candidate in self.candidates(graph).await? {
+            let Some(person_id) = graph.link_person(&candidate).await? else {
+                debug!(
+                    face_id = %candidate.face_id,
+                    voice_id = %candidate.voice_id,
+                    "face and voice belong to different people"
+                );
+                continue;
+            };
+            links.push(PersonLink {
+                person_id,
+                face_id: candidate.face_id,
+                voice_id: candidate.voice_id,
+                windows: candidate.windows,
+            });
+        }
+        Ok(links)
+    }
+}
@@ -0,0 +1,418 @@
+//! Keeping people who did not consent out of what Pete remembers.
+//!
+//! Identities can be opted out: vision and voice workers then discard new
+//! detections of them, or blur their faces in the stored frame, and the
+//! `identities` tool can purge what was stored before. In strict mode an
+//! [`ImageRedactor`] also runs before camera frames are persisted, blurring
+//! every face Pete cannot put a consenting name to.
+//!
+//! Blurring pixelates each face into a few coarse blocks of its average
+//! colour, which unlike a gaussian blur cannot be sharpened back.
+//!
+//! ```
+//! use psyche::{BoundingBox, pixelate};
+//!
+//! let mut pixels: Vec<u8> = (0..16).collect();
+//! let left_half = BoundingBox { x: 0.0, y: 0.0, width: 0.5, height: 1.0 };
+//! pixelate(&mut pixels, 4, 4, 1, &left_half, 1);
+//! assert_eq!(&pixels[..4], &[6, 6, 2, 3]);
+//! ```
+
+use crate::{BoundingBox, ImageData};
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use common::env_or;
+use std::str::FromStr;
+
+/// What happens to a new detection of an opted-out identity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OptOutAction {
+    /// Store nothing of the detection, and no frame showing it.
+    #[default]
+    Discard,
+    /// Store the frame with the face blurred, but no crop or vector.
+    Blur,
+}
+
+impl FromStr for OptOutAction {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "discard" => Ok(Self::Discard),
+            "blur" => Ok(Self::Blur),
+            other => bail!("unknown opt-out action {other:?}; expected discard or blur"),
+        }
+    }
+}
+
+/// Settings for opt-outs and frame redaction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrivacyConfig {
+    /// Blur every face not matching a named, consenting identity before a
+    /// frame is stored.
+    pub strict: bool,
+    pub opt_out_action: OptOutAction,
+    /// Blocks across the longer side of a blurred face.
+    pub blur_cells: u32,
+    /// Margin added around each face before blurring, as a fraction of its
+    /// size, to cover hair and ears.
+    pub blur_padding: f32,
+    /// Least face-vector similarity for treating a face as a known identity.
+    pub known_face_threshold: f32,
+}
+
+impl Default for PrivacyConfig {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            opt_out_action: OptOutAction::Discard,
+            blur_cells: 6,
+            blur_padding: 0.2,
+            known_face_threshold: 0.86,
+        }
+    }
+}
+
+impl PrivacyConfig {
+    /// Read overrides from `PRIVACY_*` environment variables.
+    pub fn from_env() -> Result<Self> {
+        let d = Self::default();
+        Ok(Self {
+            strict: env_or("PRIVACY_STRICT", d.strict)?,
+            opt_out_action: env_or("PRIVACY_OPT_OUT_ACTION", d.opt_out_action)?,
+            blur_cells: env_or("PRIVACY_BLUR_CELLS", d.blur_cells)?.max(1),
+            blur_padding: env_or("PRIVACY_BLUR_PADDING", d.blur_padding)?.max(0.0),
+            known_face_threshold: env_or("PRIVACY_KNOWN_FACE_THRESHOLD", d.known_face_threshold)?
+                .clamp(0.0, 1.0),
+        })
+    }
+}
+
+/// What of a frame may be stored.
+#[derive(Clone, Debug)]
+pub enum Redaction {
+    /// Store the frame as it is.
+    Keep,
+    /// Store this redacted copy instead.
+    Redacted(ImageData),
+    /// Store nothing of the frame.
+    Discard,
+}
+
+/// Decides what of a camera frame may be persisted.
+#[async_trait]
+pub trait ImageRedactor: Send + Sync {
+    async fn redact(&self, image: &ImageData) -> Result<Redaction>;
+
+    /// Whether a frame that could not be redacted must not be stored at
+    /// all. When false such frames are stored as they are.
+    fn strict(&self) -> bool {
+        true
+    }
+}
+
+/// Whether the person behind a detected face agreed to be remembered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FaceConsent {
+    /// The face matches a named identity that has not opted out.
+    Known,
+    /// The face matches no named identity.
+    Unknown,
+    /// The face matches an identity that opted out.
+    OptedOut,
+}
+
+impl FaceConsent {
+    /// Whether a crop and vector of the face may be stored. In `strict` mode
+    /// only faces with a named identity may.
+    pub fn may_remember(self, strict: bool) -> bool {
+        match self {
+            Self::Known => true,
+            Self::Unknown => !strict,
+            Self::OptedOut => false,
+        }
+    }
+}
+
+/// Looks up whether a detected face may be remembered.
+#[async_trait]
+pub trait FaceConsentCheck: Send + Sync {
+    /// Consent of the person whose face has `embedding`.
+    async fn consent(&self, embedding: &[f32]) -> Result<FaceConsent>;
+
+    /// Whether faces without a named identity are kept out of memory.
+    fn strict(&self) -> bool;
+}
+
+/// Replace `region`, in normalised coordinates, of an interleaved image of
+/// `channels` bytes per pixel with blocks of its average colour, `cells`
+/// blocks across its longer side.
+pub fn pixelate(
+    pixels: &mut [u8],
+    width: u32,
+    height: u32,
+    channels: usize,
+    region: &BoundingBox,
+    cells: u32,
+) {
+    let (width, height) = (width as usize, height as usize);
+    if channels == 0 || pixels.len() < width * height * channels {
+        return;
+    }
+    let span = |start: f32, extent: f32, size: usize| {
+        let from = (start * size as f32).floor().clamp(0.0, size as f32) as usize;
+        let to = ((start + extent) * size as f32)
+            .ceil()
+            .clamp(0.0, size as f32) as usize;
+        (from, to)
+    };
+    let (x0, x1) = span(region.x, region.width, width);
+    let (y0, y1) = span(region.y, region.height, height);
+    if x1 <= x0 || y1 <= y0 {
+        return;
+    }
+    let block = (x1 - x0).max(y1 - y0).div_ceil(cells.max(1) as usize);
+    let mut sums = vec![0u32; channels];
+    for block_y in (y0..y1).step_by(block) {
+        let block_y1 = (block_y + block).min(y1);
+        for block_x in (x0..x1).step_by(block) {
+            let block_x1 = (block_x + block).min(x1);
+            sums.fill(0);
+            for y in block_y..block_y1 {
+                let row =
+                    &pixels[(y * width + block_x) * channels..(y * width + block_x1) * channels];
+                for pixel in row.chunks_exact(channels) {
+                    for (sum, &value) in sums.iter_mut().zip(pixel) {
+                        *sum += u32::from(value);
+                    }
+                }
+            }
+            let count = ((block_x1 - block_x) * (block_y1 - block_y)) as u32;
+            let average: Vec<u8> = sums.iter().map(|sum| (sum / count) as u8).collect();
+            for y in block_y..block_y1 {
+                let row = &mut pixels
+                    [(y * width + block_x) * channels..(y * width + block_x1) * channels];
+                for pixel in row.chunks_exact_mut(channels) {
+                    pixel.copy_from_slice(&average);
+                }
+            }
+        }
+    }
+}
+
+/// Grow `region` by `padding` of its size on every side, within the frame.
+#[cfg(any(feature = "privacy", feature = "look"))]
+pub(crate) fn padded(region: &BoundingBox, padding: f32) -> BoundingBox {
+    let (dx, dy) = (region.width * padding, region.height * padding);
+    let x = (region.x - dx).max(0.0);
+    let y = (region.y - dy).max(0.0);
+    BoundingBox {
+        x,
+        y,
+        width: (region.x + region.width + dx).min(1.0) - x,
+        height: (region.y + region.height + dy).min(1.0) - y,
+    }
+}
+
+/// Decode `image`, blur `regions` and encode it again in its own format.
+#[cfg(feature = "privacy")]
+pub fn blur_regions(
+    image: &ImageData,
+    regions: &[BoundingBox],
+    config: &PrivacyConfig,
+) -> Result<ImageData> {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+
+    let bytes = BASE64_STANDARD
+        .decode(image.base64.trim().as_bytes())
+        .context("failed to decode image payload")?;
+    let mut rgb = image::load_from_memory(&bytes)
+        .context("failed to decode image")?
+        .to_rgb8();
+    let (width, height) = rgb.dimensions();
+    for region in regions {
+        pixelate(
+            &mut rgb,
+            width,
+            height,
+            3,
+            &padded(region, config.blur_padding),
+            config.blur_cells,
+        );
+    }
+    let format = if image.mime == "image/png" {
+        image::ImageFormat::Png
+    } else {
+        image::ImageFormat::Jpeg
+    };
+    let mut encoded = std::io::Cursor::new(Vec::new());
+    rgb.write_to(&mut encoded, format)
+        .context("failed to encode blurred image")?;
+    Ok(ImageData {
+        mime: format.to_mime_type().to_string(),
+        base64: BASE64_STANDARD.encode(encoded.into_inner()),
+        captured_at: image.captured_at.clone(),
+        source: image.source.clone(),
+    })
+}
+
+#[cfg(all(feature = "face", feature = "privacy"))]
+pub use face::FaceRedactor;
+
+#[cfg(all(feature = "face", feature = "privacy"))]
+mod face {
+    use super::{
+        FaceConsent, FaceConsentCheck, ImageRedactor, OptOutAction, PrivacyConfig, Redaction,
+        blur_regions,
+    };
+    use crate::{FaceDetector, ImageData, Neo4jClient, QdrantClient};
+    use anyhow::{Context, Result};
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+    use tracing::debug;
+
+    /// How long the answer to whether anyone has opted out is reused.
+    const OPT_OUT_RECHECK: Duration = Duration::from_secs(30);
+
+    /// Blurs faces of opted-out identities and, in strict mode, of anyone
+    /// without a named identity.
+    ///
+    /// Outside strict mode nothing needs blurring until someone opts out, so
+    /// frames are then kept without running face detection.
+    pub struct FaceRedactor {
+        detector: Arc<dyn FaceDetector>,
+        graph: Arc<Neo4jClient>,
+        qdrant: QdrantClient,
+        config: PrivacyConfig,
+        opt_outs: Mutex<Option<(Instant, bool)>>,
+    }
+
+    impl FaceRedactor {
+        pub fn new(
+            detector: Arc<dyn FaceDetector>,
+            graph: Arc<Neo4jClient>,
+            qdrant: QdrantClient,
+            config: PrivacyConfig,
+        ) -> Self {
+            Self {
+                detector,
+                graph,
+                qdrant,
+                config,
+                opt_outs: Mutex::new(None),
+            }
+        }
+
+        /// Whether any face may have to be kept out of memory: always in
+        /// strict mode, otherwise while some identity is opted out.
+        async fn active(&self) -> Result<bool> {
+            if self.config.strict {
+                return Ok(true);
+            }
+            let cached = *self.opt_outs.lock().unwrap();
+            if let Some((_, any)) =
+                cached.filter(|(checked, _)| checked.elapsed() < OPT_OUT_RECHECK)
+            {
+                return Ok(any);
+            }
+            let any = self
+                .graph
+                .has_opted_out_identities()
+                .await
+                .context("failed to check for opted-out identities")?;
+            *self.opt_outs.lock().unwrap() = Some((Instant::now(), any));
+            Ok(any)
+        }
+    }
+
+    #[async_trait]
+    impl FaceConsentCheck for FaceRedactor {
+        async fn consent(&self, embedding: &[f32]) -> Result<FaceConsent> {
+            if !self.active().await? {
+                return Ok(FaceConsent::Unknown);
+            }
+            let Some(neighbor) = self
+                .qdrant
+                .nearest_face_neighbor(embedding, "", self.config.known_face_threshold)
+                .await
+                .context("failed to search nearest face neighbor")?
+            else {
+                return Ok(FaceConsent::Unknown);
+            };
+            if let Some(identity_id) = self
+                .graph
+                .opted_out_face_identity(&neighbor.point_id)
+                .await
+                .context("failed to check face opt-out")?
+            {
+                debug!(%identity_id, "frame shows an opted-out face");
+                return Ok(FaceConsent::OptedOut);
+            }
+            let named = self
+                .graph
+                .face_identity_for_vector_neighbor(&neighbor.point_id)
+                .await
+                .context("failed to load face identity")?
+                .is_some_and(|face| face.identity.is_some());
+            Ok(if named {
+                FaceConsent::Known
+            } else {
+                FaceConsent::Unknown
+            })
+        }
+
+        fn strict(&self) -> bool {
+            self.config.strict
+        }
+    }
+
+    #[async_trait]
+    impl ImageRedactor for FaceRedactor {
+        async fn redact(&self, image: &ImageData) -> Result<Redaction> {
+            if !self.active().await? {
+                return Ok(Redaction::Keep);
+            }
+            let faces = self
+                .detector
+                .detect_faces(image)
+                .await
+                .context("failed to detect faces to redact")?;
+            let mut regions = Vec::new();
+            for face in faces {
+                let blur = match self.consent(&face.embedding).await? {
+                    FaceConsent::Known => false,
+                    FaceConsent::Unknown => self.config.strict,
+                    FaceConsent::OptedOut => match self.config.opt_out_action {
+                        OptOutAction::Discard => return Ok(Redaction::Discard),
+                        OptOutAction::Blur => true,
+                    },
+                };
+                if !blur {
+                    continue;
+                }
+                // A face that cannot be located cannot be blurred.
+                let Some(bbox) = face.details.bbox else {
+                    return Ok(Redaction::Discard);
+                };
+                regions.push(bbox);
+            }
+            if regions.is_empty() {
+                return Ok(Redaction::Keep);
+            }
+            let image = image.clone();
+            let config = self.config.clone();
+            let redacted =
+                tokio::task::spawn_blocking(move || blur_regions(&image, &regions, &config))
+                    .await
+                    .context("face blurring task failed")??;
+            Ok(Redaction::Redacted(redacted))
+        }
+
+        fn strict(&self) -> bool {
+            self.config.strict
+        }
+    }
+}
@@ -16,6 +16,8 @@ pub const SENSOR_GROUNDING_RULES: &str = "Describe the real-world scene or event
 
 pub const CONVERSATION_SPEAKER_NOTE: &str = "Conversation entries whose role or field is `user` may contain multiple human voices. Do not assume there is only one person speaking; use context, names, recognized faces or voices, and recent events to infer who is speaking when it matters.";
 
+pub const LOOK_AT_PROMPT: &str = "This is a close-up of part of your own live view, cropped so you can look closer. Answer the question from what is visible in it, briefly and in the first person, as in \"I see...\". If the answer cannot be seen, say so instead of guessing.";
+
 pub const IMAGE_SENSATION_TEXT: &str = "I'm looking.";
 
 pub fn face_count_sensation_text(face_count: usize) -> String {
@@ -46,6 +48,24 @@ pub fn face_identity_sensation_text(identity: Option<&str>, seen_before: bool) -
     }
 }
 
+pub fn face_track_entered_sensation_text(identity: Option<&str>) -> String {
+    match identity.map(str::trim).filter(|name| !name.is_empty()) {
+        Some(name) => format!("{name} came into view."),
+        None => "Someone came into view.".into(),
+    }
+}
+
+pub fn look_at_sensation_text(region: &str, answer: &str) -> String {
+    format!("I look closer at {}: {}", region.trim(), answer.trim())
+}
+
+pub fn face_track_left_sensation_text(identity: Option<&str>) -> String {
+    match identity.map(str::trim).filter(|name| !name.is_empty()) {
+        Some(name) => format!("{name} left my view."),
+        None => "Someone left my view.".into(),
+    }
+}
+
 /// Prompt builder for the `Voice` subagent.
 #[derive(Clone, Default)]
 pub struct VoicePrompt;
@@ -0,0 +1,205 @@
+//! Turning Pete's emotion into how his voice sounds.
+//!
+//! Emotions arrive as emoji (from [`Event::EmotionChanged`](crate::Event) or
+//! inline in spoken text). A [`ProsodyMap`] places each emoji on a
+//! valence/arousal plane ([`Affect`]) and derives [`Prosody`] controls from
+//! it: aroused speech is faster, higher and louder; positive speech is a
+//! little higher; and each quadrant names a speaking style that backends may
+//! map to a voice or an instruction.
+//!
+//! ```
+//! use psyche::ProsodyMap;
+//!
+//! let map = ProsodyMap::default();
+//! let excited = map.for_emoji("🤩");
+//! let sad = map.for_emoji("😢");
+//! assert!(excited.rate > 1.0 && sad.rate < 1.0);
+//! assert_eq!(excited.style.as_deref(), Some("excited"));
+//! ```
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Where an emotion sits on the valence (unpleasant..pleasant) and arousal
+/// (calm..energetic) axes, each in `-1.0..=1.0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Affect {
+    pub valence: f32,
+    pub arousal: f32,
+}
+
+impl Affect {
+    /// Affect at `valence` and `arousal`, clamped to the unit square.
+    pub fn new(valence: f32, arousal: f32) -> Self {
+        Self {
+            valence: valence.clamp(-1.0, 1.0),
+            arousal: arousal.clamp(-1.0, 1.0),
+        }
+    }
+}
+
+/// Speaking controls passed to a [`Tts`](crate::Tts) backend.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Prosody {
+    /// Speaking rate multiplier; `1.0` is the voice's normal speed.
+    pub rate: f32,
+    /// Pitch shift in semitones.
+    pub pitch: f32,
+    /// Loudness multiplier; `1.0` is unchanged.
+    pub volume: f32,
+    /// Voice to use instead of the configured one.
+    pub voice: Option<String>,
+    /// Speaking style name such as `excited` or `sad`.
+    pub style: Option<String>,
+}
+
+impl Default for Prosody {
+    fn default() -> Self {
+        Self {
+            rate: 1.0,
+            pitch: 0.0,
+            volume: 1.0,
+            voice: None,
+            style: None,
+        }
+    }
+}
+
+impl Prosody {
+    /// Whether these controls leave the voice unchanged.
+    pub fn is_neutral(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Configurable mapping from emotion to [`Prosody`].
+///
+/// Load one from JSON with [`ProsodyMap::load`]; missing fields keep their
+/// defaults, so `{"rate_gain": 0.4}` only makes Pete's tempo more expressive.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProsodyMap {
+    /// Rate change at full arousal (`0.25` means 25% faster).
+    pub rate_gain: f32,
+    /// Pitch change in semitones at full arousal.
+    pub pitch_gain: f32,
+    /// Pitch change in semitones at full valence.
+    pub valence_pitch_gain: f32,
+    /// Volume change at full arousal.
+    pub volume_gain: f32,
+    /// How far from neutral an axis must be before a style is named.
+    pub style_threshold: f32,
+    /// Affect of emoji, added to or replacing the built-in table.
+    pub emojis: BTreeMap<String, Affect>,
+    /// Exact prosody for particular emoji, bypassing the affect mapping.
+    pub overrides: BTreeMap<String, Prosody>,
+    /// Voice to use for each style, for backends with per-emotion voices.
+    pub voices: BTreeMap<String, String>,
+}
+
+impl Default for ProsodyMap {
+    fn default() -> Self {
+        Self {
+            rate_gain: 0.2,
+            pitch_gain: 2.0,
+            valence_pitch_gain: 1.0,
+            volume_gain: 0.15,
+            style_threshold: 0.3,
+            emojis: BTreeMap::new(),
+            overrides: BTreeMap::new(),
+            voices: BTreeMap::new(),
+        }
+    }
+}
+
+impl ProsodyMap {
+    /// Parse a mapping from JSON.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).context("invalid prosody map")
+    }
+
+    /// Read a mapping from a JSON file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read prosody map {}", path.display()))?;
+        Self::from_json(&json).with_context(|| format!("in {}", path.display()))
+    }
+
+    /// Affect of `emoji`, from the configured table or the built-in one.
+    pub fn affect(&self, emoji: &str) -> Option<Affect> {
+        let emoji = normalize(emoji);
+        self.emojis
+            .iter()
+            .find(|(key, _)| normalize(key) == emoji)
+            .map(|(_, affect)| *affect)
+            .or_else(|| builtin_affect(&emoji))
+    }
+
+    /// Prosody for `emoji`; unknown emoji sound neutral.
+    pub fn for_emoji(&self, emoji: &str) -> Prosody {
+        let key = normalize(emoji);
+        if let Some((_, prosody)) = self.overrides.iter().find(|(k, _)| normalize(k) == key) {
+            return prosody.clone();
+        }
+        self.affect(emoji)
+            .map(|affect| self.for_affect(affect))
+            .unwrap_or_default()
+    }
+
+    /// Prosody for a point on the valence/arousal plane.
+    pub fn for_affect(&self, affect: Affect) -> Prosody {
+        let Affect { valence, arousal } = Affect::new(affect.valence, affect.arousal);
+        let style = self.style(valence, arousal).map(str::to_string);
+        Prosody {
+            rate: 1.0 + arousal * self.rate_gain,
+            pitch: arousal * self.pitch_gain + valence * self.valence_pitch_gain,
+            volume: 1.0 + arousal * self.volume_gain,
+            voice: style
+                .as_ref()
+                .and_then(|style| self.voices.get(style).cloned()),
+            style,
+        }
+    }
+
+    fn style(&self, valence: f32, arousal: f32) -> Option<&'static str> {
+        let t = self.style_threshold;
+        match (valence, arousal) {
+            (v, a) if v >= t && a >= t => Some("excited"),
+            (v, _) if v >= t => Some("cheerful"),
+            (v, a) if v <= -t && a >= t => Some("angry"),
+            (v, _) if v <= -t => Some("sad"),
+            (_, a) if a >= t => Some("surprised"),
+            (_, a) if a <= -t => Some("calm"),
+            _ => None,
+        }
+    }
+}
+
+/// Drop variation selectors so `❤` and `❤️` match.
+fn normalize(emoji: &str) -> String {
+    emoji.chars().filter(|c| *c != '\u{fe0f}').collect()
+}
+
+fn builtin_affect(emoji: &str) -> Option<Affect> {
+    let (valence, arousal) = match emoji {
+        "🤩" | "🥳" | "😆" | "😁" | "😄" => (0.8, 0.7),
+        "😀" | "😃" | "😂" | "🤣" => (0.7, 0.6),
+        "🙂" | "😊" | "☺" | "😉" => (0.5, 0.1),
+        "😍" | "🥰" | "❤" | "💖" => (0.8, 0.4),
+        "😌" | "😇" => (0.4, -0.5),
+        "😐" | "😶" | "😑" => (0.0, 0.0),
+        "🤔" | "🧐" => (0.0, -0.2),
+        "😴" | "🥱" => (0.0, -0.8),
+        "😮" | "😲" | "😯" => (0.1, 0.7),
+        "😱" | "😨" | "😰" => (-0.6, 0.9),
+        "😠" | "😡" | "🤬" => (-0.7, 0.8),
+        "😢" | "😞" | "😔" | "🙁" | "☹" => (-0.6, -0.4),
+        "😭" => (-0.8, 0.3),
+        "😕" | "😟" => (-0.3, 0.0),
+        _ => return None,
+    };
+    Some(Affect::new(valence, arousal))
+}
@@ -1,5 +1,9 @@
+use crate::addressee::{AddresseeCues, AddresseeDetector, AddresseeVerdict};
+use crate::checkpoint::{CheckpointMessage, PsycheCheckpoint};
+use crate::clock::{Clock, SystemClock};
 use crate::default_prompt::DEFAULT_SYSTEM_PROMPT;
 use crate::sensation::{Event, Sensation, WitReport};
+use crate::shutdown::Shutdown;
 use crate::traits::Doer;
 use crate::traits::wit;
 use crate::traits::wit::{ErasedWit, Wit};
@@ -8,6 +12,7 @@ use crate::wits::memory::Memory;
 use lingproc::{Chatter, Message, Role, Vectorizer};
 use serde::Serialize;
 use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::time::Duration;
@@ -23,12 +28,13 @@ const DEFAULT_ACTIVE_EXPERIENCE_TICK: Duration = Duration::from_millis(5);
 use crate::pending_turn::PendingTurn;
 /// Default size for internal broadcast channels.
 pub const DEFAULT_CHANNEL_CAPACITY: usize = 16;
+/// Default time allowed for loops and wits to drain after shutdown.
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(5);
 
 use crate::task_group::TaskGroup;
 use chrono::{DateTime, Utc};
 use futures::FutureExt;
 use quick_xml::{Reader, events::Event as XmlEvent};
-use rand::Rng;
 use std::any::Any;
 use std::panic::AssertUnwindSafe;
 use tokio::sync::{Mutex, broadcast, mpsc, watch};
@@ -44,31 +50,58 @@ pub struct TimedMessage {
 }
 
 impl TimedMessage {
-    fn new(role: Role, content: String) -> Self {
+    fn new(role: Role, content: String, at: DateTime<Utc>) -> Self {
         Self {
-            at: Utc::now(),
+            at,
             message: Message { role, content },
         }
     }
 }
 
-#[derive(Default, Clone)]
+#[derive(Clone)]
 pub struct Conversation {
     log: Vec<TimedMessage>,
+    clock: Arc<dyn Clock>,
+}
+
+impl Default for Conversation {
+    fn default() -> Self {
+        Self {
+            log: Vec::new(),
+            clock: Arc::new(SystemClock),
+        }
+    }
 }
 
 impl Conversation {
+    /// Stamp messages added without an explicit time with `clock`.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
     /// Append a user message to the log, merging with the previous user entry when possible.
     pub fn add_message_from_user(&mut self, content: String) {
-        self.append_or_new(Role::User, content);
+        let now = self.clock.now();
+        self.append_or_new(Role::User, content, now);
+    }
+
+    /// Append a user message that was heard at `at`.
+    pub fn add_message_from_user_at(&mut self, content: String, at: DateTime<Utc>) {
+        self.append_or_new(Role::User, content, at);
     }
 
     /// Append an AI generated message to the log, merging consecutive assistant entries.
     pub fn add_message_from_ai(&mut self, content: String) {
-        self.append_or_new(Role::Assistant, content);
+        let now = self.clock.now();
+        self.append_or_new(Role::Assistant, content, now);
     }
 
-    fn append_or_new(&mut self, role: Role, content: String) {
+    /// Append an AI generated message that was spoken at `at`.
+    pub fn add_message_from_ai_at(&mut self, content: String, at: DateTime<Utc>) {
+        self.append_or_new(Role::Assistant, content, at);
+    }
+
+    fn append_or_new(&mut self, role: Role, content: String, at: DateTime<Utc>) {
         if let Some(last) = self.log.last_mut() {
             if last.message.role == role {
                 if !last.message.content.is_empty() && !content.is_empty() {
@@ -79,7 +112,7 @@ impl Conversation {
                 return;
             }
         }
-        self.log.push(TimedMessage::new(role, content));
+        self.log.push(TimedMessage::new(role, content, at));
     }
 
     /// Return the last `n` messages from the conversation.
@@ -111,6 +144,35 @@ impl Conversation {
         let len = self.log.len();
         self.log[len.saturating_sub(n)..].to_vec()
     }
+
+    fn to_checkpoint(&self) -> Vec<CheckpointMessage> {
+        self.log
+            .iter()
+            .map(|tm| CheckpointMessage {
+                role: match tm.message.role {
+                    Role::User => "user",
+                    Role::Assistant => "assistant",
+                }
+                .to_string(),
+                content: tm.message.content.clone(),
+                at: tm.at,
+            })
+            .collect()
+    }
+
+    fn restore_checkpoint(&mut self, messages: &[CheckpointMessage]) {
+        self.log = messages
+            .iter()
+            .map(|m| {
+                let role = if m.role == "assistant" {
+                    Role::Assistant
+                } else {
+                    Role::User
+                };
+                TimedMessage::new(role, m.content.clone(), m.at)
+            })
+            .collect();
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -179,6 +241,8 @@ pub struct Psyche {
     speak_policy: SpeakPolicy,
     connections: Option<Arc<AtomicUsize>>,
     wits: Vec<Arc<dyn wit::ErasedWit + Send + Sync>>,
+    /// Idle tick overrides keyed by Wit debug label.
+    wit_ticks: HashMap<String, Duration>,
     wit_tx: broadcast::Sender<WitReport>,
     prompt_builder: Arc<Mutex<crate::PromptBuilder>>,
     observers: Vec<Arc<dyn crate::traits::observer::SensationObserver + Send + Sync>>,
@@ -188,6 +252,16 @@ pub struct Psyche {
     topic_bus: crate::topics::TopicBus,
     activity_tx: watch::Sender<u64>,
     fallback_turn: bool,
+    clock: Arc<dyn Clock>,
+    shutdown: Shutdown,
+    checkpoint_path: Option<PathBuf>,
+    drain_timeout: Duration,
+    addressee: Option<Arc<AddresseeDetector>>,
+    /// What Pete last said and when, for addressee detection.
+    last_spoke: Option<(String, DateTime<Utc>)>,
+    /// When a face was last seen and how squarely it faced the camera, for
+    /// addressee detection.
+    last_face: Option<(DateTime<Utc>, Option<f32>)>,
 }
 
 #[doc(hidden)]
@@ -302,6 +376,7 @@ impl Psyche {
             speak_policy: SpeakPolicy::Always,
             connections: None,
             wits: Vec::new(),
+            wit_ticks: HashMap::new(),
             prompt_builder,
             observers: Vec::new(),
             sensation_buffer: Arc::new(Mutex::new(VecDeque::<Arc<Sensation>>::new())),
@@ -310,6 +385,13 @@ impl Psyche {
             topic_bus: crate::topics::TopicBus::new(capacity),
             activity_tx,
             fallback_turn: true,
+            clock: Arc::new(SystemClock),
+            shutdown: Shutdown::new(),
+            checkpoint_path: None,
+            drain_timeout: DEFAULT_DRAIN_TIMEOUT,
+            addressee: None,
+            last_spoke: None,
+            last_face: None,
         }
     }
 
@@ -367,6 +449,119 @@ impl Psyche {
         self.active_experience_tick
     }
 
+    /// Replace the [`Clock`] used for tick scheduling and timestamps.
+    ///
+    /// Simulations install a [`VirtualClock`](crate::VirtualClock) here so the
+    /// conversation, experience and Wit loops only advance when told to.
+    pub async fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.conversation.lock().await.set_clock(clock.clone());
+        self.clock = clock;
+    }
+
+    /// Tick the Wit registered as `name`, or failing that the Wits whose
+    /// debug label is `name`, every `dur` instead of the shared experience
+    /// tick.
+    pub fn set_wit_tick(&mut self, name: impl Into<String>, dur: Duration) {
+        self.wit_ticks.insert(name.into(), dur);
+    }
+
+    /// Tick interval set for `name` with [`set_wit_tick`](Self::set_wit_tick).
+    pub fn wit_tick(&self, name: &str) -> Option<Duration> {
+        self.wit_ticks.get(name).copied()
+    }
+
+    /// Names of the registered Wits, in registration order.
+    pub fn wit_names(&self) -> Vec<String> {
+        self.wits.iter().map(|w| w.name().to_string()).collect()
+    }
+
+    /// Get a handle to the [`Clock`] driving this psyche.
+    pub fn clock(&self) -> Arc<dyn Clock> {
+        self.clock.clone()
+    }
+
+    /// Get a handle that stops [`run`](Self::run) gracefully when triggered.
+    ///
+    /// After triggering, the loops finish their current step, Wits drain
+    /// their buffers through [`Memory`], and the checkpoint (if any) is saved.
+    pub fn shutdown_handle(&self) -> Shutdown {
+        self.shutdown.clone()
+    }
+
+    /// Get a handle that cuts Pete off when the user starts talking over him.
+    pub fn barge_in(&self) -> crate::BargeIn {
+        crate::BargeIn::new(
+            self.voice.clone(),
+            self.is_speaking.clone(),
+            self.events_tx.clone(),
+            self.input_tx.clone(),
+        )
+    }
+
+    /// Persist state to `path` on shutdown and restore it when [`run`](Self::run) starts.
+    pub fn set_checkpoint_path(&mut self, path: impl Into<PathBuf>) {
+        self.checkpoint_path = Some(path.into());
+    }
+
+    /// Location of the checkpoint file, if configured.
+    pub fn checkpoint_path(&self) -> Option<&Path> {
+        self.checkpoint_path.as_deref()
+    }
+
+    /// Limit how long loops and Wits may take to drain after shutdown.
+    pub fn set_drain_timeout(&mut self, dur: Duration) {
+        self.drain_timeout = dur;
+    }
+
+    /// Capture the state needed to resume this psyche later.
+    pub async fn checkpoint(&self) -> PsycheCheckpoint {
+        let conversation = self.conversation.lock().await.to_checkpoint();
+        let prompt_notes = self.prompt_builder.lock().await.notes().to_vec();
+        let wits = self
+            .wits
+            .iter()
+            .filter_map(|w| w.checkpoint_state().map(|v| (w.name().to_string(), v)))
+            .collect();
+        PsycheCheckpoint {
+            version: PsycheCheckpoint::VERSION,
+            saved_at: Some(self.clock.now()),
+            conversation,
+            pending_turn: self.pending_turn.peek(),
+            emotion: Some(self.emotion.clone()),
+            prompt_notes,
+            voice_extra_prompt: self.voice.extra_prompt(),
+            wits,
+        }
+    }
+
+    /// Restore state previously captured with [`checkpoint`](Self::checkpoint).
+    ///
+    /// Wits are matched by name; state for Wits that are no longer
+    /// registered is ignored.
+    pub async fn restore(&mut self, checkpoint: PsycheCheckpoint) {
+        self.conversation
+            .lock()
+            .await
+            .restore_checkpoint(&checkpoint.conversation);
+        self.prompt_builder
+            .lock()
+            .await
+            .restore_notes(checkpoint.prompt_notes);
+        if let Some(turn) = checkpoint.pending_turn {
+            self.pending_turn.set(turn);
+        }
+        if let Some(emoji) = checkpoint.emotion {
+            self.set_emotion(emoji);
+        }
+        self.voice.set_extra_prompt(checkpoint.voice_extra_prompt);
+        let mut states = checkpoint.wits;
+        for wit in &self.wits {
+            if let Some(state) = states.remove(wit.name()) {
+                wit.restore_state(state);
+            }
+        }
+    }
+
     /// Attach an atomic counter tracking active WebSocket connections.
     pub fn set_connection_counter(&mut self, counter: Arc<AtomicUsize>) {
         self.connections = Some(counter);
@@ -492,6 +687,18 @@ impl Psyche {
             .push(Arc::new(wit::WitAdapter::new(wit)) as Arc<dyn ErasedWit + Send + Sync>);
     }
 
+    /// Register `wit` as `name`, so Wits of the same type can be told apart
+    /// by [`set_wit_tick`](Self::set_wit_tick) and checkpoints.
+    pub fn register_named_typed_wit<W>(&mut self, name: impl Into<String>, wit: Arc<W>)
+    where
+        W: Wit + Send + Sync + 'static,
+        W::Output: Serialize + Send + Sync + 'static,
+        W::Input: 'static,
+    {
+        self.wits
+            .push(Arc::new(wit::WitAdapter::named(wit, name)) as Arc<dyn ErasedWit + Send + Sync>);
+    }
+
     /// Register a component that listens for [`Sensation`]s.
     pub fn register_observer(
         &mut self,
@@ -512,6 +719,20 @@ impl Psyche {
             .push(Arc::new(wit::WitAdapter::new(wit)) as Arc<dyn ErasedWit + Send + Sync>);
     }
 
+    /// Register `wit` as `name`, like
+    /// [`register_named_typed_wit`](Self::register_named_typed_wit), and
+    /// feed it [`Sensation`]s.
+    pub fn register_named_observing_wit<W>(&mut self, name: impl Into<String>, wit: Arc<W>)
+    where
+        W: Wit + crate::traits::observer::SensationObserver + Send + Sync + 'static,
+        W::Output: Serialize + Send + Sync + 'static,
+        W::Input: 'static,
+    {
+        self.register_observer(wit.clone());
+        self.wits
+            .push(Arc::new(wit::WitAdapter::named(wit, name)) as Arc<dyn ErasedWit + Send + Sync>);
+    }
+
     fn still_conversing(&self, turns: usize) -> bool {
         turns < self.max_turns
     }
@@ -551,6 +772,17 @@ impl Psyche {
         matches!(self.speak_policy, SpeakPolicy::WhenSpokenTo { .. })
     }
 
+    /// Judge whether heard speech is directed at Pete before responding.
+    ///
+    /// Speech the detector decides was meant for someone else still enters
+    /// the conversation, but does not satisfy the speak policy or queue a
+    /// fallback turn. Typed web interface text always counts as addressed.
+    /// When the detector needs its judge, the judge runs in the background
+    /// and Pete responds once its verdict arrives.
+    pub fn set_addressee_detector(&mut self, detector: AddresseeDetector) {
+        self.addressee = Some(Arc::new(detector));
+    }
+
     /// Enable or disable the default fallback turn when no Wit sets a turn.
     pub fn set_fallback_turn_enabled(&mut self, enabled: bool) {
         self.fallback_turn = enabled;
@@ -583,6 +815,104 @@ impl Psyche {
             )));
     }
 
+    /// Remember when Pete spoke and when a face was seen.
+    fn note_addressee_context(&mut self, sensation: &Sensation) {
+        match sensation {
+            Sensation::HeardOwnVoice { text, occurred_at }
+            | Sensation::StartedSpeaking { text, occurred_at }
+            | Sensation::FinishedSpeaking { text, occurred_at } => {
+                self.last_spoke = Some((text.clone(), *occurred_at));
+            }
+            #[cfg(feature = "face")]
+            Sensation::Of {
+                payload,
+                occurred_at,
+            } if payload.is::<crate::FaceInfo>() => {
+                let pose = payload
+                    .downcast_ref::<crate::FaceInfo>()
+                    .and_then(|face| face.details.quality)
+                    .and_then(|quality| quality.pose);
+                // Several faces from one frame share a time; keep the one
+                // looking most directly at Pete.
+                let pose = match self.last_face {
+                    Some((at, seen)) if at == *occurred_at => seen.max(pose),
+                    _ => pose,
+                };
+                self.last_face = Some((*occurred_at, pose));
+            }
+            _ => {}
+        }
+    }
+
+    /// Respond to heard speech once a background addressee verdict says it
+    /// was meant for Pete.
+    fn heed_addressee_verdict(&mut self, sensation: &Sensation) {
+        let Sensation::Of { payload, .. } = sensation else {
+            return;
+        };
+        let Some(verdict) = payload.downcast_ref::<AddresseeVerdict>() else {
+            return;
+        };
+        debug!(
+            addressed = verdict.addressed,
+            confidence = verdict.confidence,
+            reasons = ?verdict.reasons,
+            "judged addressee verdict"
+        );
+        if verdict.addressed {
+            if self.pending_turn.is_empty() && self.fallback_turn {
+                self.pending_turn.set("I'm listening.".to_string());
+            }
+            self.speak_policy.received_user_message();
+        }
+    }
+
+    /// Whether the user's `text` was meant for Pete.
+    ///
+    /// Without a detector all speech counts as addressed. The verdict is
+    /// passed to observers so it is stored on the heard speech sensation.
+    /// Speech that needs the judge counts as unaddressed for now; the judge
+    /// runs in a spawned task whose verdict comes back as a sensation and is
+    /// handled by [`heed_addressee_verdict`](Self::heed_addressee_verdict).
+    async fn addressed(&self, text: &str, occurred_at: DateTime<Utc>) -> bool {
+        let Some(detector) = &self.addressee else {
+            return true;
+        };
+        let mut cues = AddresseeCues::new(text, occurred_at);
+        if let Some((said, at)) = &self.last_spoke {
+            cues = cues.with_pete_speech(said.clone(), *at);
+        }
+        if let Some((at, pose)) = self.last_face {
+            cues = cues.with_face(at, pose);
+        }
+        let sensation_id = Sensation::heard_user_voice_at(text, occurred_at).id();
+        let Some(verdict) = detector.quick_verdict(&sensation_id, &cues) else {
+            let detector = Arc::clone(detector);
+            let input_tx = self.input_tx.clone();
+            tokio::spawn(async move {
+                let verdict = detector.detect(&sensation_id, &cues).await;
+                if input_tx
+                    .send(Sensation::of_at(verdict, occurred_at))
+                    .await
+                    .is_err()
+                {
+                    debug!("psyche stopped before the addressee verdict arrived");
+                }
+            });
+            return false;
+        };
+        debug!(
+            addressed = verdict.addressed,
+            confidence = verdict.confidence,
+            reasons = ?verdict.reasons,
+            "addressee verdict"
+        );
+        let addressed = verdict.addressed;
+        self.notify_observers(&Sensation::of_at(verdict, occurred_at))
+            .await;
+        addressed
+    }
+
     async fn notify_observers(&self, sensation: &Sensation) {
         for obs in &self.observers {
             obs.observe_sensation(sensation as &(dyn Any + Send + Sync))
@@ -594,22 +924,28 @@ impl Psyche {
     /// Main loop that handles the conversation with the assistant.
     async fn converse(mut self) -> Self {
         info!("psyche conversation started");
+        let shutdown = self.shutdown.clone();
         let mut turns = 0;
-        while self.still_conversing(turns) {
+        while self.still_conversing(turns) && !shutdown.is_triggered() {
             if let Some(counter) = &self.connections {
-                while counter.load(Ordering::SeqCst) == 0 {
-                    tokio::time::sleep(Duration::from_millis(100)).await;
+                while counter.load(Ordering::SeqCst) == 0 && !shutdown.is_triggered() {
+                    tokio::select! {
+                        _ = self.clock.sleep(Duration::from_millis(100)) => {}
+                        _ = shutdown.cancelled() => {}
+                    }
                 }
             }
             while let Ok(s) = self.input_rx.try_recv() {
                 let arc = Arc::new(s);
+                self.note_addressee_context(&arc);
+                self.heed_addressee_verdict(&arc);
                 match &*arc {
                     Sensation::HeardOwnVoice {
                         text: msg,
                         occurred_at,
                     } => {
                         let mut conv = self.conversation.lock().await;
-                        conv.add_message_from_ai(msg.clone());
+                        conv.add_message_from_ai_at(msg.clone(), *occurred_at);
                         self.buffer_self_speech_at(msg, *occurred_at).await;
                     }
                     Sensation::HeardUserVoice {
@@ -621,23 +957,32 @@ impl Psyche {
                         occurred_at,
                     } => {
                         let mut conv = self.conversation.lock().await;
-                        conv.add_message_from_user(msg.clone());
+                        conv.add_message_from_user_at(msg.clone(), *occurred_at);
                         self.buffer_user_speech_at(msg, *occurred_at).await;
-                        if self.pending_turn.is_empty() && self.fallback_turn {
-                            self.pending_turn.set("I'm listening.".to_string());
+                        let addressed = !matches!(&*arc, Sensation::HeardUserVoice { .. })
+                            || self.addressed(msg, *occurred_at).await;
+                        if addressed {
+                            if self.pending_turn.is_empty() && self.fallback_turn {
+                                self.pending_turn.set("I'm listening.".to_string());
+                            }
+                            self.speak_policy.received_user_message();
                         }
-                        self.speak_policy.received_user_message();
                     }
                     Sensation::Of { .. }
                     | Sensation::StartedSpeaking { .. }
-                    | Sensation::FinishedSpeaking { .. } => {
+                    | Sensation::FinishedSpeaking { .. }
+                    | Sensation::InterruptedSpeaking { .. } => {
                         self.sensation_buffer.lock().await.push_back(arc.clone());
                     }
                 }
                 self.notify_observers(arc.as_ref()).await;
             }
             if self.speak_policy.waiting_for_user() {
-                match self.input_rx.recv().await {
+                let input = tokio::select! {
+                    input = self.input_rx.recv() => input,
+                    _ = shutdown.cancelled() => break,
+                };
+                match input {
                     Some(Sensation::HeardUserVoice {
                         text: msg,
                         occurred_at,
@@ -645,15 +990,17 @@ impl Psyche {
                         trace!("heard user voice: {}", msg);
                         self.ear.hear_user_say_at(&msg, occurred_at).await;
                         self.buffer_user_speech_at(&msg, occurred_at).await;
-                        if self.pending_turn.is_empty() && self.fallback_turn {
-                            self.pending_turn.set("I'm listening.".to_string());
-                        }
                         self.notify_observers(&Sensation::heard_user_voice_at(
                             msg.clone(),
                             occurred_at,
                         ))
                         .await;
-                        self.speak_policy.received_user_message();
+                        if self.addressed(&msg, occurred_at).await {
+                            if self.pending_turn.is_empty() && self.fallback_turn {
+                                self.pending_turn.set("I'm listening.".to_string());
+                            }
+                            self.speak_policy.received_user_message();
+                        }
                         continue;
                     }
                     Some(Sensation::WebInterfaceText {
@@ -678,6 +1025,7 @@ impl Psyche {
                         occurred_at,
                     }) => {
                         trace!("received HeardOwnVoice: '{}'", msg);
+                        self.last_spoke = Some((msg.clone(), occurred_at));
                         self.ear.hear_self_say_at(&msg, occurred_at).await;
                         self.buffer_self_speech_at(&msg, occurred_at).await;
                         self.notify_observers(&Sensation::heard_own_voice_at(
@@ -689,8 +1037,11 @@ impl Psyche {
                     }
                     Some(s @ Sensation::StartedSpeaking { .. })
                     | Some(s @ Sensation::FinishedSpeaking { .. })
+                    | Some(s @ Sensation::InterruptedSpeaking { .. })
                     | Some(s @ Sensation::Of { .. }) => {
                         trace!("received non-voice sensation while waiting");
+                        self.note_addressee_context(&s);
+                        self.heed_addressee_verdict(&s);
                         self.notify_observers(&s).await;
                         self.sensation_buffer.lock().await.push_back(Arc::new(s));
                         continue;
@@ -703,7 +1054,7 @@ impl Psyche {
                 if self.speaking() {
                     trace!("still speaking; deferring turn");
                     self.pending_turn.set(extra);
-                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    self.clock.sleep(Duration::from_millis(100)).await;
                     continue;
                 }
                 debug!(extra_len = extra.len(), "pending_turn being processed");
@@ -732,18 +1083,21 @@ impl Psyche {
                 let pending_turn = Arc::clone(&self.pending_turn);
                 tokio::select! {
                     _ = pending_turn.notified() => {}
+                    _ = shutdown.cancelled() => break,
                     input = self.input_rx.recv() => {
                         let Some(s) = input else {
                             break;
                         };
                         let arc = Arc::new(s);
+                        self.note_addressee_context(&arc);
+                        self.heed_addressee_verdict(&arc);
                         match &*arc {
                             Sensation::HeardOwnVoice {
                                 text: msg,
                                 occurred_at,
                             } => {
                                 let mut conv = self.conversation.lock().await;
-                                conv.add_message_from_ai(msg.clone());
+                                conv.add_message_from_ai_at(msg.clone(), *occurred_at);
                                 self.buffer_self_speech_at(msg, *occurred_at).await;
                             }
                             Sensation::HeardUserVoice {
@@ -755,16 +1109,21 @@ impl Psyche {
                                 occurred_at,
                             } => {
                                 let mut conv = self.conversation.lock().await;
-                                conv.add_message_from_user(msg.clone());
+                                conv.add_message_from_user_at(msg.clone(), *occurred_at);
                                 self.buffer_user_speech_at(msg, *occurred_at).await;
-                                if self.pending_turn.is_empty() && self.fallback_turn {
-                                    self.pending_turn.set("I'm listening.".to_string());
+                                let addressed = !matches!(&*arc, Sensation::HeardUserVoice { .. })
+                                    || self.addressed(msg, *occurred_at).await;
+                                if addressed {
+                                    if self.pending_turn.is_empty() && self.fallback_turn {
+                                        self.pending_turn.set("I'm listening.".to_string());
+                                    }
+                                    self.speak_policy.received_user_message();
                                 }
-                                self.speak_policy.received_user_message();
                             }
                             Sensation::Of { .. }
                             | Sensation::StartedSpeaking { .. }
-                            | Sensation::FinishedSpeaking { .. } => {
+                            | Sensation::FinishedSpeaking { .. }
+                            | Sensation::InterruptedSpeaking { .. } => {
                                 self.sensation_buffer.lock().await.push_back(arc.clone());
                             }
                         }
@@ -786,8 +1145,10 @@ impl Psyche {
         idle_tick: Duration,
         active_tick: Duration,
         speaking: Arc<AtomicBool>,
+        clock: Arc<dyn Clock>,
+        shutdown: Shutdown,
     ) {
-        loop {
+        while !shutdown.is_triggered() {
             let batch: Vec<Arc<Sensation>> = buffer.lock().await.drain(..).collect();
             for s in &batch {
                 for obs in &observers {
@@ -799,13 +1160,16 @@ impl Psyche {
             if !batch.is_empty() {
                 notify_activity(&activity_tx);
             }
-            let jitter = rand::thread_rng().gen_range(0..50);
+            let jitter = clock.jitter(Duration::from_millis(50));
             let tick = if speaking.load(Ordering::SeqCst) || !batch.is_empty() {
                 active_tick
             } else {
                 idle_tick
             };
-            tokio::time::sleep(tick + Duration::from_millis(jitter)).await;
+            tokio::select! {
+                _ = clock.sleep(tick + jitter) => {}
+                _ = shutdown.cancelled() => {}
+            }
         }
     }
 
@@ -820,13 +1184,15 @@ impl Psyche {
         idle_tick: Duration,
         active_tick: Duration,
         speaking: Arc<AtomicBool>,
+        clock: Arc<dyn Clock>,
+        shutdown: Shutdown,
     ) {
-        loop {
+        while !shutdown.is_triggered() {
             let name = wit.name();
             trace!(%name, "tick start");
             let imps = wit.tick_erased().await;
             trace!(%name, count = imps.len(), "tick finished");
-            let now = Utc::now();
+            let now = clock.now();
             {
                 let mut map = ticks.lock().await;
                 map.insert(name.to_string(), now);
@@ -844,14 +1210,15 @@ impl Psyche {
                 error!(?e, "memory store failed");
             }
             prompt_builder.lock().await.add_impressions(&imps).await;
-            let jitter = rand::thread_rng().gen_range(0..50);
+            let jitter = clock.jitter(Duration::from_millis(50));
             let tick = if speaking.load(Ordering::SeqCst) {
                 active_tick
             } else {
                 idle_tick
             };
             tokio::select! {
-                _ = tokio::time::sleep(tick + Duration::from_millis(jitter)) => {}
+                _ = clock.sleep(tick + jitter) => {}
+                _ = shutdown.cancelled() => {}
                 changed = activity_rx.changed() => {
                     if changed.is_err() {
                         break;
@@ -860,13 +1227,49 @@ impl Psyche {
             }
         }
     }
+    /// Flush buffered sensations and ask each Wit for its remaining impressions.
+    async fn drain(&self) {
+        let pending: Vec<Arc<Sensation>> = self.sensation_buffer.lock().await.drain(..).collect();
+        for s in &pending {
+            for obs in &self.observers {
+                obs.observe_sensation(s.as_ref() as &(dyn Any + Send + Sync))
+                    .await;
+            }
+        }
+        for wit in &self.wits {
+            let name = wit.name();
+            match tokio::time::timeout(self.drain_timeout, wit.drain_erased()).await {
+                Ok(imps) => {
+                    debug!(%name, count = imps.len(), "wit drained");
+                    if let Err(e) = self.memory.store_all(&imps).await {
+                        error!(%name, ?e, "memory store failed while draining");
+                    }
+                }
+                Err(_) => warn!(%name, "wit drain timed out"),
+            }
+        }
+    }
+
     /// Start the conversation and background tasks.
     ///
     /// All spawned tasks are tracked and aborted on drop. This ensures
     /// background loops do not outlive the [`Psyche`] if `run` is cancelled.
+    /// When a checkpoint path is set, saved state is restored before the
+    /// loops start. Triggering the [`Shutdown`] handle stops the loops, drains
+    /// Wits through [`Memory`] and writes a fresh checkpoint.
     /// Returns the updated [`Psyche`] when finished.
-    pub async fn run(self) -> Self {
+    pub async fn run(mut self) -> Self {
         info!("psyche run started");
+        if let Some(path) = self.checkpoint_path.clone() {
+            match PsycheCheckpoint::load(&path) {
+                Ok(Some(cp)) => {
+                    info!(path = %path.display(), "restoring checkpoint");
+                    self.restore(cp).await;
+                }
+                Ok(None) => {}
+                Err(e) => warn!(?e, "failed to load checkpoint"),
+            }
+        }
         let buf = Arc::clone(&self.sensation_buffer);
         let observers = self.observers.clone();
         let bus = self.topic_bus.clone();
@@ -886,6 +1289,13 @@ impl Psyche {
             let pending_turn = Arc::clone(&pending);
             let name = wit.name().to_string();
             let activity_rx = activity_tx.subscribe();
+            let idle_tick = self
+                .wit_ticks
+                .get(wit.name())
+                .or_else(|| self.wit_ticks.get(wit.debug_label()))
+                .copied()
+                .unwrap_or(self.experience_tick);
+            let active_tick = self.active_experience_tick.min(idle_tick);
 
             let fut = AssertUnwindSafe(Self::wit_loop(
                 wit,
@@ -894,9 +1304,11 @@ impl Psyche {
                 prompt_builder,
                 pending_turn,
                 activity_rx,
-                self.experience_tick,
-                self.active_experience_tick,
+                idle_tick,
+                active_tick,
                 Arc::clone(&self.is_speaking),
+                Arc::clone(&self.clock),
+                self.shutdown.clone(),
             ))
             .catch_unwind()
             .map(move |res| {
@@ -915,6 +1327,8 @@ impl Psyche {
             self.experience_tick,
             self.active_experience_tick,
             Arc::clone(&self.is_speaking),
+            Arc::clone(&self.clock),
+            self.shutdown.clone(),
         ));
         let converse_handle = tokio::spawn(self.converse());
 
@@ -927,7 +1341,19 @@ impl Psyche {
             }
         };
 
-        tasks.shutdown().await;
+        if psyche.shutdown.is_triggered() {
+            tasks.shutdown_within(psyche.drain_timeout).await;
+            psyche.drain().await;
+        } else {
+            tasks.shutdown().await;
+        }
+        if let Some(path) = &psyche.checkpoint_path {
+            let checkpoint = psyche.checkpoint().await;
+            match checkpoint.save(path) {
+                Ok(()) => info!(path = %path.display(), "checkpoint saved"),
+                Err(e) => error!(?e, "failed to save checkpoint"),
+            }
+        }
         info!("psyche run finished");
         psyche
     }
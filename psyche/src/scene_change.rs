@@ -0,0 +1,355 @@
+//! Telling new camera frames from near-duplicates of earlier ones.
+//!
+//! Most consecutive webcam frames show the same scene. A [`SceneFingerprint`]
+//! summarises a frame cheaply — a 64-bit difference hash and a 16×16
+//! grayscale thumbnail — so [`SceneChangeConfig::compare`] can tell whether a
+//! frame shows anything its keyframe, the last frame that did, did not. With
+//! the `scene-change` feature a [`SceneGate`] runs the comparison against the
+//! graph: it stores each frame's fingerprint, links duplicates to their
+//! keyframe with `DUPLICATE_OF` and lets vision workers reuse the keyframe's
+//! results instead of running again.
+//!
+//! ```
+//! use psyche::{SceneChangeConfig, SceneFingerprint};
+//!
+//! let left_lit: Vec<u8> = (0..64 * 48).map(|i| 255 - (i % 64 * 4) as u8).collect();
+//! let right_lit: Vec<u8> = left_lit.iter().map(|v| 255 - v).collect();
+//! let a = SceneFingerprint::from_luma(&left_lit, 64, 48).unwrap();
+//! let b = SceneFingerprint::from_luma(&right_lit, 64, 48).unwrap();
+//! let config = SceneChangeConfig::default();
+//! assert!(config.compare(&a, &a, None).duplicate);
+//! assert!(!config.compare(&b, &a, None).duplicate);
+//! ```
+
+use anyhow::{Context, Result};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use common::env_or;
+use std::time::Duration;
+
+const HASH_WIDTH: usize = 9;
+const HASH_HEIGHT: usize = 8;
+const THUMBNAIL_SIZE: usize = 16;
+
+/// Cheap summary of what a frame looks like.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SceneFingerprint {
+    /// Difference hash: one bit per horizontally adjacent pair of cells in a
+    /// 9×8 grayscale thumbnail, set where the left cell is brighter.
+    pub hash: u64,
+    /// 16×16 grayscale thumbnail, row by row.
+    pub thumbnail: Vec<u8>,
+}
+
+impl SceneFingerprint {
+    /// Fingerprint a grayscale image of `width` × `height` pixels.
+    pub fn from_luma(gray: &[u8], width: u32, height: u32) -> Option<Self> {
+        let (width, height) = (width as usize, height as usize);
+        if width == 0 || height == 0 || gray.len() < width * height {
+            return None;
+        }
+        let cells = shrink(gray, width, height, HASH_WIDTH, HASH_HEIGHT);
+        let mut hash = 0u64;
+        for row in cells.chunks(HASH_WIDTH) {
+            for pair in row.windows(2) {
+                hash = (hash << 1) | u64::from(pair[0] > pair[1]);
+            }
+        }
+        let thumbnail = shrink(gray, width, height, THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+            .into_iter()
+            .map(|value| value.round() as u8)
+            .collect();
+        Some(Self { hash, thumbnail })
+    }
+
+    /// Decode and fingerprint an image payload.
+    #[cfg(feature = "scene-change")]
+    pub fn from_image(image: &crate::ImageData) -> Result<Self> {
+        let bytes = BASE64_STANDARD
+            .decode(image.base64.trim().as_bytes())
+            .context("failed to decode image payload")?;
+        let gray = image::load_from_memory(&bytes)
+            .context("failed to decode image")?
+            .to_luma8();
+        Self::from_luma(gray.as_raw(), gray.width(), gray.height()).context("image had no pixels")
+    }
+
+    /// Number of hash bits that differ from `other`.
+    pub fn hash_distance(&self, other: &Self) -> u32 {
+        (self.hash ^ other.hash).count_ones()
+    }
+
+    /// Mean absolute thumbnail difference from `other`, in `0.0..=1.0`.
+    pub fn difference(&self, other: &Self) -> f32 {
+        if self.thumbnail.is_empty() || self.thumbnail.len() != other.thumbnail.len() {
+            return 1.0;
+        }
+        let total: u32 = self
+            .thumbnail
+            .iter()
+            .zip(&other.thumbnail)
+            .map(|(a, b)| u32::from(a.abs_diff(*b)))
+            .sum();
+        total as f32 / (self.thumbnail.len() as f32 * 255.0)
+    }
+
+    /// Hash as stored on `Image` nodes.
+    pub fn hash_hex(&self) -> String {
+        format!("{:016x}", self.hash)
+    }
+
+    /// Thumbnail as stored on `Image` nodes.
+    pub fn thumbnail_base64(&self) -> String {
+        BASE64_STANDARD.encode(&self.thumbnail)
+    }
+
+    /// Rebuild a fingerprint from its stored [`hash_hex`](Self::hash_hex) and
+    /// [`thumbnail_base64`](Self::thumbnail_base64).
+    pub fn from_stored(hash: &str, thumbnail: &str) -> Option<Self> {
+        let hash = u64::from_str_radix(hash, 16).ok()?;
+        let thumbnail = BASE64_STANDARD.decode(thumbnail.as_bytes()).ok()?;
+        (thumbnail.len() == THUMBNAIL_SIZE * THUMBNAIL_SIZE).then_some(Self { hash, thumbnail })
+    }
+}
+
+/// Average `gray` down to `out_width` × `out_height` cells.
+fn shrink(
+    gray: &[u8],
+    width: usize,
+    height: usize,
+    out_width: usize,
+    out_height: usize,
+) -> Vec<f32> {
+    let mut cells = Vec::with_capacity(out_width * out_height);
+    for cell_y in 0..out_height {
+        let y0 = cell_y * height / out_height;
+        let y1 = ((cell_y + 1) * height / out_height).max(y0 + 1);
+        for cell_x in 0..out_width {
+            let x0 = cell_x * width / out_width;
+            let x1 = ((cell_x + 1) * width / out_width).max(x0 + 1);
+            let mut sum = 0u32;
+            for y in y0..y1 {
+                sum += gray[y * width + x0..y * width + x1]
+                    .iter()
+                    .map(|&v| u32::from(v))
+                    .sum::<u32>();
+            }
+            cells.push(sum as f32 / ((x1 - x0) * (y1 - y0)) as f32);
+        }
+    }
+    cells
+}
+
+/// Settings for deciding when a frame repeats its keyframe.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SceneChangeConfig {
+    /// Most hash bits that may differ in a duplicate.
+    pub max_hash_distance: u32,
+    /// Largest mean thumbnail difference, in `0.0..=1.0`, of a duplicate.
+    pub max_difference: f32,
+    /// Least scene-vector similarity of a duplicate, when both frames have
+    /// a stored scene vector.
+    pub min_scene_similarity: f32,
+    /// How long a keyframe stands in for the frames after it; a later frame
+    /// becomes a keyframe however little changed.
+    pub max_age: Duration,
+}
+
+impl Default for SceneChangeConfig {
+    fn default() -> Self {
+        Self {
+            max_hash_distance: 6,
+            max_difference: 0.04,
+            min_scene_similarity: 0.95,
+            max_age: Duration::from_secs(60),
+        }
+    }
+}
+
+impl SceneChangeConfig {
+    /// Read overrides from `SCENE_CHANGE_*` environment variables.
+    pub fn from_env() -> Result<Self> {
+        let d = Self::default();
+        let fraction =
+            |key: &str, default: f32| -> Result<f32> { Ok(env_or(key, default)?.clamp(0.0, 1.0)) };
+        Ok(Self {
+            max_hash_distance: env_or("SCENE_CHANGE_MAX_HASH_DISTANCE", d.max_hash_distance)?,
+            max_difference: fraction("SCENE_CHANGE_MAX_DIFFERENCE", d.max_difference)?,
+            min_scene_similarity: fraction("SCENE_CHANGE_MIN_SIMILARITY", d.min_scene_similarity)?,
+            max_age: Duration::from_millis(env_or(
+                "SCENE_CHANGE_MAX_AGE_MS",
+                d.max_age.as_millis() as u64,
+            )?),
+        })
+    }
+
+    /// Compare `frame` with its candidate `keyframe`, using the similarity of
+    /// their scene vectors too when known.
+    pub fn compare(
+        &self,
+        frame: &SceneFingerprint,
+        keyframe: &SceneFingerprint,
+        scene_similarity: Option<f32>,
+    ) -> SceneComparison {
+        let hash_distance = frame.hash_distance(keyframe);
+        let difference = frame.difference(keyframe);
+        SceneComparison {
+            hash_distance,
+            difference,
+            scene_similarity,
+            duplicate: hash_distance <= self.max_hash_distance
+                && difference <= self.max_difference
+                && scene_similarity
+                    .is_none_or(|similarity| similarity >= self.min_scene_similarity),
+        }
+    }
+}
+
+/// How a frame compares with a keyframe.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SceneComparison {
+    pub hash_distance: u32,
+    pub difference: f32,
+    pub scene_similarity: Option<f32>,
+    /// Whether the frame shows nothing its keyframe did not.
+    pub duplicate: bool,
+}
+
+#[cfg(feature = "scene-change")]
+pub use gate::SceneGate;
+
+#[cfg(feature = "scene-change")]
+mod gate {
+    use super::{SceneChangeConfig, SceneFingerprint};
+    use crate::{
+        GraphImageFrame, GraphSceneDuplicate, GraphSceneFrame, ImageRunKind, Neo4jClient,
+        QdrantClient, parse_observed_at,
+    };
+    use anyhow::{Context, Result};
+    use lingproc::math::cosine_similarity;
+    use tracing::debug;
+
+    /// Marks frames that repeat their keyframe and reuses the keyframe's
+    /// vision results for them.
+    #[derive(Debug, Default)]
+    pub struct SceneGate {
+        config: SceneChangeConfig,
+    }
+
+    impl SceneGate {
+        pub fn new(config: SceneChangeConfig) -> Self {
+            Self { config }
+        }
+
+        pub fn config(&self) -> &SceneChangeConfig {
+            &self.config
+        }
+
+        /// Return the keyframe `frame` duplicates, fingerprinting the frame
+        /// first when no worker has yet.
+        pub async fn keyframe_for(
+            &self,
+            graph: &Neo4jClient,
+            qdrant: &QdrantClient,
+            frame: &GraphImageFrame,
+        ) -> Result<Option<String>> {
+            let Some(scene) = graph.scene_frame(&frame.id).await? else {
+                return Ok(None);
+            };
+            if scene.fingerprint.is_some() {
+                return Ok(scene.duplicate_of);
+            }
+            let image = frame.image.clone();
+            let fingerprint =
+                tokio::task::spawn_blocking(move || SceneFingerprint::from_image(&image))
+                    .await
+                    .context("scene fingerprint task failed")?
+                    .with_context(|| format!("failed to fingerprint image {}", frame.id))?;
+
+            let keyframe = match frame.observed_at() {
+                Some(at) => graph.previous_scene_keyframe(&frame.id, at).await?,
+                None => None,
+            };
+            let duplicate = match keyframe {
+                Some(keyframe) => {
+                    self.compare_with_keyframe(qdrant, frame, &scene, &fingerprint, keyframe)
+                        .await?
+                }
+                None => None,
+            };
+
+            graph
+                .attach_scene_fingerprint(frame, &fingerprint, duplicate.as_ref())
+                .await?;
+            Ok(duplicate.map(|duplicate| duplicate.keyframe_id))
+        }
+
+        async fn compare_with_keyframe(
+            &self,
+            qdrant: &QdrantClient,
+            frame: &GraphImageFrame,
+            scene: &GraphSceneFrame,
+            fingerprint: &SceneFingerprint,
+            keyframe: GraphSceneFrame,
+        ) -> Result<Option<GraphSceneDuplicate>> {
+            let Some(keyframe_fingerprint) = &keyframe.fingerprint else {
+                return Ok(None);
+            };
+            let observed_at = frame.observed_at();
+            let keyframe_at = keyframe.observed_at.as_deref().and_then(parse_observed_at);
+            let fresh = match (observed_at, keyframe_at) {
+                (Some(observed_at), Some(keyframe_at)) => (observed_at - keyframe_at)
+                    .to_std()
+                    .is_ok_and(|age| age <= self.config.max_age),
+                _ => false,
+            };
+            if !fresh {
+                return Ok(None);
+            }
+            let similarity = match (&scene.scene_vector_id, &keyframe.scene_vector_id) {
+                (Some(vector_id), Some(keyframe_vector_id)) => {
+                    let vector = qdrant.scene_vector(vector_id).await?;
+                    let keyframe_vector = qdrant.scene_vector(keyframe_vector_id).await?;
+                    vector
+                        .zip(keyframe_vector)
+                        .map(|(a, b)| cosine_similarity(&a, &b))
+                }
+                _ => None,
+            };
+            let comparison = self
+                .config
+                .compare(fingerprint, keyframe_fingerprint, similarity);
+            debug!(
+                image_id = %frame.id,
+                keyframe_id = %keyframe.image_id,
+                hash_distance = comparison.hash_distance,
+                difference = comparison.difference,
+                duplicate = comparison.duplicate,
+                "compared frame with keyframe"
+            );
+            Ok(comparison.duplicate.then(|| GraphSceneDuplicate {
+                keyframe_id: keyframe.image_id,
+                hash_distance: comparison.hash_distance,
+                difference: comparison.difference,
+                scene_similarity: comparison.scene_similarity,
+            }))
+        }
+
+        /// Record `kind` as done for `frame` by reusing the run of its
+        /// keyframe, returning the keyframe when the frame is a duplicate and
+        /// some frame of its scene has such a run.
+        pub async fn reuse_keyframe_run(
+            &self,
+            graph: &Neo4jClient,
+            qdrant: &QdrantClient,
+            frame: &GraphImageFrame,
+            kind: ImageRunKind,
+        ) -> Result<Option<String>> {
+            let Some(keyframe_id) = self.keyframe_for(graph, qdrant, frame).await? else {
+                return Ok(None);
+            };
+            let reused = graph.reuse_image_run(frame, kind, &keyframe_id).await?;
+            Ok(reused.then_some(keyframe_id))
+        }
+    }
+}
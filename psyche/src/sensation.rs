@@ -1,3 +1,4 @@
+use crate::VisemeCue;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -9,10 +10,18 @@ use ts_rs::TS;
 pub enum Event {
     /// A partial chunk of the assistant's response.
     StreamChunk(String),
-    /// The assistant spoke a line of dialogue. Optional base64-encoded WAV audio accompanies the text.
-    Speech { text: String, audio: Option<String> },
+    /// The assistant spoke a line of dialogue. Optional base64-encoded WAV audio accompanies the text,
+    /// along with the mouth shapes to show while it plays.
+    Speech {
+        text: String,
+        audio: Option<String>,
+        visemes: Option<Vec<VisemeCue>>,
+    },
     /// The psyche's emotional expression changed.
     EmotionChanged(String),
+    /// The user talked over the assistant; clients should cut playback.
+    /// `spoken` holds the text delivered before the interruption.
+    SpeechInterrupted { spoken: String },
 }
 
 /// Debug information emitted by a [`Wit`].
@@ -64,6 +73,14 @@ pub enum Sensation {
         text: String,
         occurred_at: DateTime<Utc>,
     },
+    /// The user started talking while Pete was speaking, cutting him off.
+    InterruptedSpeaking {
+        /// What Pete had said before being interrupted.
+        text: String,
+        /// The rest of the reply, which was never spoken.
+        unsaid: String,
+        occurred_at: DateTime<Utc>,
+    },
 }
 
 impl Sensation {
@@ -133,7 +150,8 @@ impl Sensation {
             | Self::WebInterfaceText { occurred_at, .. }
             | Self::Of { occurred_at, .. }
             | Self::StartedSpeaking { occurred_at, .. }
-            | Self::FinishedSpeaking { occurred_at, .. } => *occurred_at,
+            | Self::FinishedSpeaking { occurred_at, .. }
+            | Self::InterruptedSpeaking { occurred_at, .. } => *occurred_at,
         }
     }
 
@@ -160,6 +178,12 @@ impl Sensation {
                 let id = format!("finished-speaking:{}:{text}", occurred_at.to_rfc3339());
                 sensation_id("finished_speaking", &id, occurred_at)
             }
+            Self::InterruptedSpeaking {
+                text, occurred_at, ..
+            } => {
+                let id = format!("interrupted-speaking:{}:{text}", occurred_at.to_rfc3339());
+                sensation_id("interrupted_speaking", &id, occurred_at)
+            }
             Self::Of {
                 payload,
                 occurred_at,
@@ -202,8 +226,15 @@ impl Sensation {
                     } else if let Some(heartbeat) = payload.downcast_ref::<crate::Heartbeat>() {
                         let id = format!("heartbeat:{}", heartbeat.timestamp.to_rfc3339());
                         sensation_id("heartbeat", &id, occurred_at)
+                    } else if let Some(verdict) = payload.downcast_ref::<crate::AddresseeVerdict>()
+                    {
+                        sensation_id("addressee", &verdict.sensation_id, occurred_at)
+                    } else if let Some(verdict) = payload.downcast_ref::<crate::EchoVerdict>() {
+                        sensation_id("echo", &verdict.sensation_id, occurred_at)
                     } else if let Some(object) = payload.downcast_ref::<crate::ObjectInfo>() {
                         sensation_id("object", &object_info_id(object, occurred_at), occurred_at)
+                    } else if let Some(text) = payload.downcast_ref::<crate::TextBlock>() {
+                        sensation_id("text", &text.text_id(), occurred_at)
                     } else if let Some(value) = payload.downcast_ref::<Value>() {
                         sensation_id("json", &json_sensation_id(value, occurred_at), occurred_at)
                     } else if let Some(summary) =
@@ -261,6 +292,15 @@ impl Clone for Sensation {
                 text: text.clone(),
                 occurred_at: *occurred_at,
             },
+            Self::InterruptedSpeaking {
+                text,
+                unsaid,
+                occurred_at,
+            } => Self::InterruptedSpeaking {
+                text: text.clone(),
+                unsaid: unsaid.clone(),
+                occurred_at: *occurred_at,
+            },
         }
     }
 }
@@ -270,12 +310,7 @@ fn sensation_id(kind: &str, content_id: &str, occurred_at: &DateTime<Utc>) -> St
 }
 
 fn object_info_id(object: &crate::ObjectInfo, occurred_at: &DateTime<Utc>) -> String {
-    format!(
-        "object:{}:{}:{}",
-        object.label.clone().unwrap_or_else(|| "unknown".into()),
-        object.embedding.len(),
-        occurred_at.to_rfc3339()
-    )
+    object.observation_id(&occurred_at.to_rfc3339())
 }
 
 fn json_sensation_id(value: &Value, occurred_at: &DateTime<Utc>) -> String {
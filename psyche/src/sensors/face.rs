@@ -1,7 +1,10 @@
 use crate::topics::TopicBus;
 use crate::traits::Sensor;
 use crate::wits::memory::QdrantClient;
-use crate::{ImageData, Sensation, image_captured_at, image_content_id};
+use crate::{
+    BoundingBox, FaceConsentCheck, FaceDetails, FaceLandmark, FaceQuality, ImageData, Sensation,
+    image_captured_at, image_content_id,
+};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use base64::Engine;
@@ -27,13 +30,38 @@ pub struct FaceInfo {
     /// Qdrant vector id for the face embedding.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vector_id: Option<String>,
+    /// Location, landmarks and quality reported by the detector.
+    #[serde(default, flatten)]
+    pub details: FaceDetails,
+}
+
+/// One face found by a [`FaceDetector`].
+#[derive(Clone, Debug)]
+pub struct DetectedFace {
+    /// Cropped face image.
+    pub crop: ImageData,
+    /// Embedding vector describing the face.
+    pub embedding: Vec<f32>,
+    /// Location, landmarks and quality of the face.
+    pub details: FaceDetails,
+}
+
+impl DetectedFace {
+    /// Face without location or quality details.
+    pub fn new(crop: ImageData, embedding: Vec<f32>) -> Self {
+        Self {
+            crop,
+            embedding,
+            details: FaceDetails::default(),
+        }
+    }
 }
 
 /// Trait for extracting embeddings from images.
 #[async_trait]
 pub trait FaceDetector: Send + Sync {
-    /// Return cropped faces paired with vector embeddings.
-    async fn detect_faces(&self, image: &ImageData) -> Result<Vec<(ImageData, Vec<f32>)>>;
+    /// Return cropped faces with vector embeddings and detection details.
+    async fn detect_faces(&self, image: &ImageData) -> Result<Vec<DetectedFace>>;
 }
 
 /// Dummy detector returning the entire image as one face for offline tests.
@@ -42,8 +70,11 @@ pub struct DummyDetector;
 
 #[async_trait]
 impl FaceDetector for DummyDetector {
-    async fn detect_faces(&self, image: &ImageData) -> Result<Vec<(ImageData, Vec<f32>)>> {
-        Ok(vec![(image.clone(), vec![0.0])])
+    async fn detect_faces(&self, image: &ImageData) -> Result<Vec<DetectedFace>> {
+        let mut face = DetectedFace::new(image.clone(), vec![0.0]);
+        face.details.bbox = Some(BoundingBox::FULL);
+        face.details.confidence = Some(1.0);
+        Ok(vec![face])
     }
 }
 
@@ -67,7 +98,7 @@ impl FaceIdDetector {
 
 #[async_trait]
 impl FaceDetector for FaceIdDetector {
-    async fn detect_faces(&self, image: &ImageData) -> Result<Vec<(ImageData, Vec<f32>)>> {
+    async fn detect_faces(&self, image: &ImageData) -> Result<Vec<DetectedFace>> {
         let image = image.clone();
         let analyzer = Arc::clone(&self.analyzer);
         tokio::task::spawn_blocking(move || {
@@ -88,8 +119,12 @@ impl FaceDetector for FaceIdDetector {
             faces
                 .into_iter()
                 .map(|face| {
-                    let crop = crop_face(&img, &face.detection)?;
-                    Ok((crop, face.embedding))
+                    let (crop, details) = crop_face(&img, &face.detection)?;
+                    Ok(DetectedFace {
+                        crop,
+                        embedding: face.embedding,
+                        details,
+                    })
                 })
                 .collect()
         })
@@ -98,13 +133,21 @@ impl FaceDetector for FaceIdDetector {
     }
 }
 
+/// Crop `detection` out of `img` and measure where it is and how sharp it is.
 fn crop_face(
     img: &image::DynamicImage,
     detection: &face_id::detector::DetectedFace,
-) -> Result<ImageData> {
+) -> Result<(ImageData, FaceDetails)> {
     let width = img.width();
     let height = img.height();
-    let bbox = detection.to_absolute(width, height).bbox;
+    let absolute = detection.to_absolute(width, height);
+    let bbox = &absolute.bbox;
+    let landmarks: Vec<FaceLandmark> = absolute
+        .landmarks
+        .iter()
+        .flatten()
+        .map(|&(x, y)| FaceLandmark { x, y })
+        .collect();
 
     let x1 = bbox.x1.floor().clamp(0.0, width.saturating_sub(1) as f32) as u32;
     let y1 = bbox.y1.floor().clamp(0.0, height.saturating_sub(1) as f32) as u32;
@@ -117,15 +160,40 @@ fn crop_face(
         img.clone()
     };
 
+    let gray = crop.to_luma8();
+    let sharpness = FaceQuality::sharpness_of(gray.as_raw(), gray.width(), gray.height());
+    let details = FaceDetails {
+        bbox: Some(BoundingBox {
+            x: x1 as f32 / width.max(1) as f32,
+            y: y1 as f32 / height.max(1) as f32,
+            width: x2.saturating_sub(x1) as f32 / width.max(1) as f32,
+            height: y2.saturating_sub(y1) as f32 / height.max(1) as f32,
+        }),
+        quality: Some(FaceQuality::new(
+            sharpness,
+            FaceQuality::pose_of(&landmarks),
+        )),
+        landmarks: landmarks
+            .iter()
+            .map(|point| FaceLandmark {
+                x: point.x / width.max(1) as f32,
+                y: point.y / height.max(1) as f32,
+            })
+            .collect(),
+        confidence: Some(absolute.score),
+    };
+
     let mut bytes = Cursor::new(Vec::new());
     crop.write_to(&mut bytes, image::ImageFormat::Jpeg)
         .context("failed to encode face crop")?;
 
-    Ok(ImageData {
+    let crop = ImageData {
         mime: "image/jpeg".to_string(),
         base64: BASE64_STANDARD.encode(bytes.into_inner()),
         captured_at: None,
-    })
+        source: None,
+    };
+    Ok((crop, details))
 }
 
 /// Sensor that emits [`FaceInfo`] sensations.
@@ -134,6 +202,7 @@ pub struct FaceSensor {
     qdrant: QdrantClient,
     bus: TopicBus,
     last_face: Mutex<Option<Vec<f32>>>,
+    consent: Option<Arc<dyn FaceConsentCheck>>,
 }
 
 impl FaceSensor {
@@ -144,6 +213,34 @@ impl FaceSensor {
             qdrant,
             bus,
             last_face: Mutex::new(None),
+            consent: None,
+        }
+    }
+
+    /// Only remember faces `consent` allows: opted-out faces, and in strict
+    /// mode faces without a named identity, get no crop, vector or sensation.
+    pub fn with_consent(mut self, consent: Arc<dyn FaceConsentCheck>) -> Self {
+        self.consent = Some(consent);
+        self
+    }
+
+    /// Whether the face with `embedding` may be remembered. Fails closed.
+    async fn may_remember(&self, embedding: &[f32]) -> bool {
+        let Some(check) = &self.consent else {
+            return true;
+        };
+        match check.consent(embedding).await {
+            Ok(consent) => {
+                let allowed = consent.may_remember(check.strict());
+                if !allowed {
+                    debug!(?consent, "not remembering face without consent");
+                }
+                allowed
+            }
+            Err(e) => {
+                error!(?e, "face consent check failed; not remembering face");
+                false
+            }
         }
     }
 }
@@ -155,7 +252,15 @@ impl Sensor<ImageData> for FaceSensor {
         match self.detector.detect_faces(&input).await {
             Ok(faces) => {
                 debug!(count = faces.len(), "face detector completed");
-                for (mut crop, embed) in faces {
+                for DetectedFace {
+                    mut crop,
+                    embedding: embed,
+                    details,
+                } in faces
+                {
+                    if !self.may_remember(&embed).await {
+                        continue;
+                    }
                     if crop.captured_at.is_none() {
                         crop.captured_at = Some(occurred_at.to_rfc3339());
                     }
@@ -192,6 +297,7 @@ impl Sensor<ImageData> for FaceSensor {
                         source_image_id,
                         embedding: embed,
                         vector_id,
+                        details,
                     };
                     self.bus.publish(
                         crate::topics::Topic::Sensation,
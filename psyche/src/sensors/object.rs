@@ -0,0 +1,426 @@
+//! Object detection in still images.
+//!
+//! An [`ObjectDetector`] turns an image into [`ObjectInfo`]s with labels,
+//! confidences and boxes. [`YoloObjectDetector`] (feature `objects`) runs a
+//! YOLOv8-style ONNX model on the CPU; [`DummyObjectDetector`] stands in for
+//! it in tests. Decoding the model output is plain Rust in [`decode_yolo`].
+//!
+//! ```
+//! use psyche::{Letterbox, YoloConfig, decode_yolo};
+//!
+//! // One candidate of a one-class model: a box centred in a 640x640 input.
+//! let output = [320.0, 320.0, 64.0, 64.0, 0.9];
+//! let letterbox = Letterbox::new(640, 640, 640);
+//! let labels = ["cat".to_string()];
+//! let objects =
+//!     decode_yolo(&output, &[1, 5, 1], &letterbox, &labels, &YoloConfig::default()).unwrap();
+//! assert_eq!(objects[0].label.as_deref(), Some("cat"));
+//! assert_eq!(objects[0].bbox.unwrap().x, 0.45);
+//! ```
+
+use crate::{BoundingBox, ImageData, ObjectInfo};
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+
+/// Trait for finding objects in images.
+#[async_trait]
+pub trait ObjectDetector: Send + Sync {
+    /// Return the objects seen in `image` with labels, confidences and boxes.
+    async fn detect_objects(&self, image: &ImageData) -> Result<Vec<ObjectInfo>>;
+}
+
+/// Dummy detector reporting the entire image as one object for offline tests.
+#[derive(Clone, Default)]
+pub struct DummyObjectDetector;
+
+#[async_trait]
+impl ObjectDetector for DummyObjectDetector {
+    async fn detect_objects(&self, _image: &ImageData) -> Result<Vec<ObjectInfo>> {
+        Ok(vec![ObjectInfo {
+            label: Some("object".into()),
+            confidence: Some(1.0),
+            bbox: Some(BoundingBox::FULL),
+            ..ObjectInfo::default()
+        }])
+    }
+}
+
+/// Settings for decoding YOLO output.
+#[derive(Clone, Debug, PartialEq)]
+pub struct YoloConfig {
+    /// Side of the square model input in pixels.
+    pub input_size: u32,
+    /// Minimum class score for a detection.
+    pub confidence: f32,
+    /// Overlap above which a weaker box of the same class is dropped.
+    pub iou: f32,
+    /// Most detections reported per image.
+    pub max_detections: usize,
+}
+
+impl Default for YoloConfig {
+    fn default() -> Self {
+        Self {
+            input_size: 640,
+            confidence: 0.25,
+            iou: 0.45,
+            max_detections: 100,
+        }
+    }
+}
+
+/// Placement of an image scaled into a square model input with its aspect
+/// ratio kept and the rest padded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Letterbox {
+    pub width: u32,
+    pub height: u32,
+    pub scale: f32,
+    pub pad_x: u32,
+    pub pad_y: u32,
+}
+
+impl Letterbox {
+    /// Letterbox a `width` by `height` image into a `size` square.
+    pub fn new(width: u32, height: u32, size: u32) -> Self {
+        let scale = (size as f32 / width.max(1) as f32).min(size as f32 / height.max(1) as f32);
+        let (scaled_width, scaled_height) = Self::scaled(width, height, scale);
+        Self {
+            width,
+            height,
+            scale,
+            pad_x: size.saturating_sub(scaled_width) / 2,
+            pad_y: size.saturating_sub(scaled_height) / 2,
+        }
+    }
+
+    /// Size of the image inside the input.
+    pub fn scaled_size(&self) -> (u32, u32) {
+        Self::scaled(self.width, self.height, self.scale)
+    }
+
+    fn scaled(width: u32, height: u32, scale: f32) -> (u32, u32) {
+        (
+            ((width as f32 * scale).round() as u32).max(1),
+            ((height as f32 * scale).round() as u32).max(1),
+        )
+    }
+
+    /// Map a box centred at `cx`,`cy` in input pixels back onto the image.
+    fn to_image(&self, cx: f32, cy: f32, w: f32, h: f32) -> BoundingBox {
+        let x0 = ((cx - w / 2.0 - self.pad_x as f32) / self.scale).clamp(0.0, self.width as f32);
+        let y0 = ((cy - h / 2.0 - self.pad_y as f32) / self.scale).clamp(0.0, self.height as f32);
+        let x1 = ((cx + w / 2.0 - self.pad_x as f32) / self.scale).clamp(0.0, self.width as f32);
+        let y1 = ((cy + h / 2.0 - self.pad_y as f32) / self.scale).clamp(0.0, self.height as f32);
+        BoundingBox {
+            x: x0 / self.width.max(1) as f32,
+            y: y0 / self.height.max(1) as f32,
+            width: (x1 - x0) / self.width.max(1) as f32,
+            height: (y1 - y0) / self.height.max(1) as f32,
+        }
+    }
+}
+
+/// Objects in a YOLOv8-style output tensor.
+///
+/// `output` holds, for every candidate, a box (`cx`, `cy`, `w`, `h` in input
+/// pixels) followed by one score per class, laid out either channel-major
+/// (`[1, 4 + classes, candidates]`, as exported by Ultralytics) or
+/// candidate-major. Boxes of the same class that overlap a stronger one are
+/// dropped, and each object's class scores become its embedding.
+pub fn decode_yolo(
+    output: &[f32],
+    shape: &[usize],
+    letterbox: &Letterbox,
+    labels: &[String],
+    config: &YoloConfig,
+) -> Result<Vec<ObjectInfo>> {
+    let (rows, cols) = match shape {
+        [1, rows, cols] | [rows, cols] => (*rows, *cols),
+        _ => bail!("unexpected YOLO output shape {shape:?}"),
+    };
+    if rows * cols != output.len() {
+        bail!(
+            "YOLO output has {} values for shape {shape:?}",
+            output.len()
+        );
+    }
+    // The label count settles the layout; otherwise there are far more
+    // candidates than channels.
+    let channel_major = match labels.len() + 4 {
+        channels if channels == rows => true,
+        channels if channels == cols => false,
+        _ => rows <= cols,
+    };
+    let (channels, candidates) = if channel_major {
+        (rows, cols)
+    } else {
+        (cols, rows)
+    };
+    if channels <= 4 {
+        bail!("YOLO output shape {shape:?} has no class scores");
+    }
+    let value = |candidate: usize, channel: usize| {
+        if channel_major {
+            output[channel * candidates + candidate]
+        } else {
+            output[candidate * channels + channel]
+        }
+    };
+
+    let mut found = Vec::new();
+    for candidate in 0..candidates {
+        let scores: Vec<f32> = (4..channels).map(|c| value(candidate, c)).collect();
+        let Some((class, &score)) = scores.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1))
+        else {
+            continue;
+        };
+        if score < config.confidence {
+            continue;
+        }
+        let bbox = letterbox.to_image(
+            value(candidate, 0),
+            value(candidate, 1),
+            value(candidate, 2),
+            value(candidate, 3),
+        );
+        if bbox.area() <= 0.0 {
+            continue;
+        }
+        found.push((class, score, bbox, scores));
+    }
+    found.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let mut kept: Vec<(usize, f32, BoundingBox, Vec<f32>)> = Vec::new();
+    for detection in found {
+        if kept.len() >= config.max_detections {
+            break;
+        }
+        let suppressed = kept
+            .iter()
+            .any(|other| other.0 == detection.0 && other.2.iou(&detection.2) > config.iou);
+        if !suppressed {
+            kept.push(detection);
+        }
+    }
+
+    Ok(kept
+        .into_iter()
+        .map(|(class, score, bbox, scores)| ObjectInfo {
+            label: Some(
+                labels
+                    .get(class)
+                    .cloned()
+                    .unwrap_or_else(|| format!("class {class}")),
+            ),
+            embedding: scores,
+            confidence: Some(score),
+            bbox: Some(bbox),
+            source_image_id: None,
+        })
+        .collect())
+}
+
+/// Class names of the COCO dataset, in the order YOLO models trained on it
+/// report them.
+pub const COCO_LABELS: [&str; 80] = [
+    "person",
+    "bicycle",
+    "car",
+    "motorcycle",
+    "airplane",
+    "bus",
+    "train",
+    "truck",
+    "boat",
+    "traffic light",
+    "fire hydrant",
+    "stop sign",
+    "parking meter",
+    "bench",
+    "bird",
+    "cat",
+    "dog",
+    "horse",
+    "sheep",
+    "cow",
+    "elephant",
+    "bear",
+    "zebra",
+    "giraffe",
+    "backpack",
+    "umbrella",
+    "handbag",
+    "tie",
+    "suitcase",
+    "frisbee",
+    "skis",
+    "snowboard",
+    "sports ball",
+    "kite",
+    "baseball bat",
+    "baseball glove",
+    "skateboard",
+    "surfboard",
+    "tennis racket",
+    "bottle",
+    "wine glass",
+    "cup",
+    "fork",
+    "knife",
+    "spoon",
+    "bowl",
+    "banana",
+    "apple",
+    "sandwich",
+    "orange",
+    "broccoli",
+    "carrot",
+    "hot dog",
+    "pizza",
+    "donut",
+    "cake",
+    "chair",
+    "couch",
+    "potted plant",
+    "bed",
+    "dining table",
+    "toilet",
+    "tv",
+    "laptop",
+    "mouse",
+    "remote",
+    "keyboard",
+    "cell phone",
+    "microwave",
+    "oven",
+    "toaster",
+    "sink",
+    "refrigerator",
+    "book",
+    "clock",
+    "vase",
+    "scissors",
+    "teddy bear",
+    "hair drier",
+    "toothbrush",
+];
+
+#[cfg(feature = "objects")]
+pub use yolo::YoloObjectDetector;
+
+#[cfg(feature = "objects")]
+mod yolo {
+    use super::{COCO_LABELS, Letterbox, ObjectDetector, YoloConfig, decode_yolo};
+    use crate::{ImageData, ObjectInfo};
+    use anyhow::{Context, Result, anyhow};
+    use async_trait::async_trait;
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+    use image::imageops::FilterType;
+    use ort::session::Session;
+    use ort::value::Tensor;
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+
+    /// Detector running a YOLOv8-style ONNX model on the CPU.
+    ///
+    /// Export a model with `yolo export model=yolov8n.pt format=onnx`; the
+    /// COCO class names are assumed unless [`with_labels`](Self::with_labels)
+    /// supplies others.
+    pub struct YoloObjectDetector {
+        session: Arc<Mutex<Session>>,
+        labels: Arc<Vec<String>>,
+        config: YoloConfig,
+    }
+
+    impl YoloObjectDetector {
+        /// Load the ONNX model at `path`.
+        pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+            let path = path.as_ref();
+            let session = Session::builder()
+                .and_then(|builder| builder.commit_from_file(path))
+                .with_context(|| format!("failed to load object model {}", path.display()))?;
+            Ok(Self {
+                session: Arc::new(Mutex::new(session)),
+                labels: Arc::new(COCO_LABELS.iter().map(|l| l.to_string()).collect()),
+                config: YoloConfig::default(),
+            })
+        }
+
+        /// Return this detector naming classes with `labels`.
+        pub fn with_labels(mut self, labels: Vec<String>) -> Self {
+            self.labels = Arc::new(labels);
+            self
+        }
+
+        /// Return this detector decoding with `config`.
+        pub fn with_config(mut self, config: YoloConfig) -> Self {
+            self.config = config;
+            self
+        }
+    }
+
+    #[async_trait]
+    impl ObjectDetector for YoloObjectDetector {
+        async fn detect_objects(&self, image: &ImageData) -> Result<Vec<ObjectInfo>> {
+            let image = image.clone();
+            let session = Arc::clone(&self.session);
+            let labels = Arc::clone(&self.labels);
+            let config = self.config.clone();
+            tokio::task::spawn_blocking(move || {
+                if image.base64.trim().is_empty() {
+                    return Ok(Vec::new());
+                }
+                let bytes = BASE64_STANDARD
+                    .decode(image.base64.trim().as_bytes())
+                    .context("failed to decode image payload")?;
+                let img = image::load_from_memory(&bytes).context("failed to decode image")?;
+                let letterbox = Letterbox::new(img.width(), img.height(), config.input_size);
+                let pixels = letterboxed_pixels(&img, &letterbox, config.input_size);
+                let size = config.input_size as usize;
+                let input = Tensor::from_array(([1usize, 3, size, size], pixels))
+                    .context("failed to build object model input")?;
+
+                let mut session = session
+                    .lock()
+                    .map_err(|_| anyhow!("object model lock poisoned"))?;
+                let outputs = session
+                    .run(ort::inputs![input])
+                    .context("object model inference failed")?;
+                let (shape, output) = outputs[0]
+                    .try_extract_tensor::<f32>()
+                    .context("object model returned no float tensor")?;
+                let shape: Vec<usize> = shape.iter().map(|dim| *dim as usize).collect();
+                decode_yolo(output, &shape, &letterbox, &labels, &config)
+            })
+            .await
+            .context("object detection task failed")?
+        }
+    }
+
+    /// RGB planes scaled to `0.0..=1.0`, with the image letterboxed into a
+    /// grey `size` square.
+    fn letterboxed_pixels(img: &image::DynamicImage, letterbox: &Letterbox, size: u32) -> Vec<f32> {
+        let (width, height) = letterbox.scaled_size();
+        let resized = img
+            .resize_exact(width, height, FilterType::Triangle)
+            .to_rgb8();
+        let size = size as usize;
+        let plane = size * size;
+        let mut pixels = vec![114.0 / 255.0; 3 * plane];
+        for (x, y, pixel) in resized.enumerate_pixels() {
+            let (x, y) = (
+                (x + letterbox.pad_x) as usize,
+                (y + letterbox.pad_y) as usize,
+            );
+            if x >= size || y >= size {
+                continue;
+            }
+            for (channel, value) in pixel.0.iter().enumerate() {
+                pixels[channel * plane + y * size + x] = *value as f32 / 255.0;
+            }
+        }
+        pixels
+    }
+}
@@ -0,0 +1,461 @@
+//! Reading text in still images.
+//!
+//! A [`TextRecognizer`] turns an image into [`TextBlock`]s with their text,
+//! confidence and box. [`PaddleTextRecognizer`] (feature `ocr`) runs
+//! PaddleOCR-style ONNX detection and recognition models on the CPU;
+//! [`DummyTextRecognizer`] stands in for it in tests. Turning the model
+//! outputs into boxes and text is plain Rust in [`find_text_regions`] and
+//! [`decode_ctc`].
+//!
+//! ```
+//! use psyche::{OcrConfig, find_text_regions};
+//!
+//! // A 16x8 detection map with one bright line of text across the middle.
+//! let mut map = vec![0.0; 16 * 8];
+//! for x in 2..14 {
+//!     for y in 3..5 {
+//!         map[y * 16 + x] = 0.9;
+//!     }
+//! }
+//! let config = OcrConfig { min_size: 2, ..OcrConfig::default() };
+//! let regions = find_text_regions(&map, 16, 8, &config);
+//! assert_eq!(regions.len(), 1);
+//! assert!(regions[0].0.width > 0.75);
+//! ```
+
+use crate::{BoundingBox, ImageData, TextBlock};
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+
+/// Trait for reading text in images.
+#[async_trait]
+pub trait TextRecognizer: Send + Sync {
+    /// Return the blocks of text in `image` with confidences and boxes.
+    async fn recognize_text(&self, image: &ImageData) -> Result<Vec<TextBlock>>;
+}
+
+/// Dummy recognizer reading the same lines in every image, stacked top to
+/// bottom, for offline tests.
+#[derive(Clone, Default)]
+pub struct DummyTextRecognizer {
+    lines: Vec<String>,
+}
+
+impl DummyTextRecognizer {
+    pub fn new(lines: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            lines: lines.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl TextRecognizer for DummyTextRecognizer {
+    async fn recognize_text(&self, _image: &ImageData) -> Result<Vec<TextBlock>> {
+        let height = 1.0 / self.lines.len().max(1) as f32;
+        Ok(self
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| TextBlock {
+                text: line.clone(),
+                confidence: Some(1.0),
+                bbox: Some(BoundingBox {
+                    x: 0.0,
+                    y: index as f32 * height,
+                    width: 1.0,
+                    height,
+                }),
+                source_image_id: None,
+            })
+            .collect())
+    }
+}
+
+/// Settings for finding and reading text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OcrConfig {
+    /// Longest side of the detection model input in pixels.
+    pub max_side: u32,
+    /// Detection probability above which a pixel belongs to text.
+    pub pixel_threshold: f32,
+    /// Mean detection probability a text region needs to be read.
+    pub box_threshold: f32,
+    /// How far regions are grown back out, relative to their area over
+    /// their perimeter; detection models are trained on shrunken regions.
+    pub unclip_ratio: f32,
+    /// Shortest side, in detection map pixels, of a region worth reading.
+    pub min_size: u32,
+    /// Height of the line crops given to the recognition model.
+    pub line_height: u32,
+    /// Widest line crop given to the recognition model.
+    pub max_line_width: u32,
+    /// Minimum recognition confidence for reporting text.
+    pub confidence: f32,
+    /// Most text blocks reported per image.
+    pub max_blocks: usize,
+}
+
+impl Default for OcrConfig {
+    fn default() -> Self {
+        Self {
+            max_side: 960,
+            pixel_threshold: 0.3,
+            box_threshold: 0.6,
+            unclip_ratio: 1.5,
+            min_size: 3,
+            line_height: 48,
+            max_line_width: 1280,
+            confidence: 0.5,
+            max_blocks: 64,
+        }
+    }
+}
+
+/// Text regions in a `width` by `height` detection probability map, in
+/// reading order, with their mean probability.
+///
+/// Connected pixels above [`OcrConfig::pixel_threshold`] form a region; its
+/// box is grown by [`OcrConfig::unclip_ratio`] and given as fractions of the
+/// map, which are also fractions of the image it was computed from.
+pub fn find_text_regions(
+    map: &[f32],
+    width: usize,
+    height: usize,
+    config: &OcrConfig,
+) -> Vec<(BoundingBox, f32)> {
+    if width == 0 || height == 0 || map.len() < width * height {
+        return Vec::new();
+    }
+    let mut visited = vec![false; width * height];
+    let mut regions = Vec::new();
+    let mut stack = Vec::new();
+    for start in 0..width * height {
+        if visited[start] || map[start] <= config.pixel_threshold {
+            continue;
+        }
+        visited[start] = true;
+        stack.push(start);
+        let (mut x0, mut y0, mut x1, mut y1) = (width, height, 0, 0);
+        let (mut sum, mut count) = (0.0f32, 0usize);
+        while let Some(pixel) = stack.pop() {
+            let (x, y) = (pixel % width, pixel / width);
+            (x0, y0, x1, y1) = (x0.min(x), y0.min(y), x1.max(x), y1.max(y));
+            sum += map[pixel];
+            count += 1;
+            let neighbours = [
+                (x > 0).then(|| pixel - 1),
+                (x + 1 < width).then(|| pixel + 1),
+                (y > 0).then(|| pixel - width),
+                (y + 1 < height).then(|| pixel + width),
+            ];
+            for next in neighbours.into_iter().flatten() {
+                if !visited[next] && map[next] > config.pixel_threshold {
+                    visited[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+
+        let (box_width, box_height) = ((x1 - x0 + 1) as f32, (y1 - y0 + 1) as f32);
+        let score = sum / count as f32;
+        if box_width.min(box_height) < config.min_size as f32 || score < config.box_threshold {
+            continue;
+        }
+        let grow = box_width * box_height * config.unclip_ratio / (2.0 * (box_width + box_height));
+        let left = (x0 as f32 - grow).max(0.0);
+        let top = (y0 as f32 - grow).max(0.0);
+        let right = ((x1 + 1) as f32 + grow).min(width as f32);
+        let bottom = ((y1 + 1) as f32 + grow).min(height as f32);
+        regions.push((
+            BoundingBox {
+                x: left / width as f32,
+                y: top / height as f32,
+                width: (right - left) / width as f32,
+                height: (bottom - top) / height as f32,
+            },
+            score,
+        ));
+    }
+    regions.sort_by(|a, b| a.0.y.total_cmp(&b.0.y).then(a.0.x.total_cmp(&b.0.x)));
+    regions
+}
+
+/// Text and confidence from a CTC recognition output.
+///
+/// `output` holds one probability per class for each time step (shape
+/// `[1, steps, classes]` or `[steps, classes]`); class 0 is the CTC blank and
+/// class `i` is `dictionary[i - 1]`. The best class of each step is taken,
+/// repeats are collapsed and blanks dropped. The confidence is the mean
+/// probability of the characters kept.
+pub fn decode_ctc(output: &[f32], shape: &[usize], dictionary: &[String]) -> Result<(String, f32)> {
+    let (steps, classes) = match shape {
+        [1, steps, classes] | [steps, classes] => (*steps, *classes),
+        _ => bail!("unexpected recognition output shape {shape:?}"),
+    };
+    if steps * classes != output.len() {
+        bail!(
+            "recognition output has {} values for shape {shape:?}",
+            output.len()
+        );
+    }
+    if classes != dictionary.len() + 1 {
+        bail!(
+            "recognition output has {classes} classes for a dictionary of {} characters",
+            dictionary.len()
+        );
+    }
+
+    let mut text = String::new();
+    let mut probabilities = Vec::new();
+    let mut previous = 0;
+    for step in output.chunks(classes) {
+        let Some((class, &probability)) = step.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1))
+        else {
+            continue;
+        };
+        if class != 0 && class != previous {
+            text.push_str(&dictionary[class - 1]);
+            probabilities.push(probability);
+        }
+        previous = class;
+    }
+    let confidence = if probabilities.is_empty() {
+        0.0
+    } else {
+        probabilities.iter().sum::<f32>() / probabilities.len() as f32
+    };
+    Ok((text.trim().to_string(), confidence))
+}
+
+/// Characters of a recognition dictionary file, one per line.
+///
+/// PaddleOCR models reserve their last class for a space, which dictionary
+/// files leave out; it is added when missing.
+pub fn parse_ocr_dictionary(text: &str) -> Vec<String> {
+    let mut dictionary: Vec<String> = text
+        .lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+    if !dictionary.iter().any(|entry| entry == " ") {
+        dictionary.push(" ".into());
+    }
+    dictionary
+}
+
+/// The most confident reading of each distinct text, in the order first read.
+///
+/// Blocks whose text is only whitespace are dropped; readings of the same
+/// text differing only in case or spacing count as one.
+pub fn distinct_text_blocks(blocks: Vec<TextBlock>) -> Vec<TextBlock> {
+    let mut kept: Vec<TextBlock> = Vec::new();
+    for block in blocks {
+        let normalized = block.normalized_text();
+        if normalized.is_empty() {
+            continue;
+        }
+        match kept
+            .iter_mut()
+            .find(|other| other.normalized_text() == normalized)
+        {
+            Some(other) => {
+                if block.confidence.unwrap_or(0.0) > other.confidence.unwrap_or(0.0) {
+                    *other = block;
+                }
+            }
+            None => kept.push(block),
+        }
+    }
+    kept
+}
+
+#[cfg(feature = "ocr")]
+pub use paddle::PaddleTextRecognizer;
+
+#[cfg(feature = "ocr")]
+mod paddle {
+    use super::{OcrConfig, TextRecognizer, decode_ctc, find_text_regions, parse_ocr_dictionary};
+    use crate::{ImageData, TextBlock};
+    use anyhow::{Context, Result, anyhow};
+    use async_trait::async_trait;
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+    use image::imageops::FilterType;
+    use image::{DynamicImage, RgbImage};
+    use ort::session::Session;
+    use ort::value::Tensor;
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+
+    const DETECTION_MEAN: [f32; 3] = [0.485, 0.456, 0.406];
+    const DETECTION_STD: [f32; 3] = [0.229, 0.224, 0.225];
+
+    /// Recognizer running PaddleOCR-style ONNX models on the CPU: a DBNet
+    /// text detector and a CTC line recognizer.
+    ///
+    /// Convert the PP-OCR detection and recognition models with
+    /// `paddle2onnx` and pass the recognizer's character dictionary file.
+    pub struct PaddleTextRecognizer {
+        detection: Arc<Mutex<Session>>,
+        recognition: Arc<Mutex<Session>>,
+        dictionary: Arc<Vec<String>>,
+        config: OcrConfig,
+    }
+
+    impl PaddleTextRecognizer {
+        /// Load the detection and recognition models and the dictionary.
+        pub fn from_files(
+            detection_model: impl AsRef<Path>,
+            recognition_model: impl AsRef<Path>,
+            dictionary: impl AsRef<Path>,
+        ) -> Result<Self> {
+            let dictionary = dictionary.as_ref();
+            let text = std::fs::read_to_string(dictionary).with_context(|| {
+                format!("failed to read OCR dictionary {}", dictionary.display())
+            })?;
+            Ok(Self {
+                detection: Arc::new(Mutex::new(load_session(detection_model.as_ref())?)),
+                recognition: Arc::new(Mutex::new(load_session(recognition_model.as_ref())?)),
+                dictionary: Arc::new(parse_ocr_dictionary(&text)),
+                config: OcrConfig::default(),
+            })
+        }
+
+        /// Return this recognizer using `config`.
+        pub fn with_config(mut self, config: OcrConfig) -> Self {
+            self.config = config;
+            self
+        }
+    }
+
+    fn load_session(path: &Path) -> Result<Session> {
+        Session::builder()
+            .and_then(|builder| builder.commit_from_file(path))
+            .with_context(|| format!("failed to load OCR model {}", path.display()))
+    }
+
+    #[async_trait]
+    impl TextRecognizer for PaddleTextRecognizer {
+        async fn recognize_text(&self, image: &ImageData) -> Result<Vec<TextBlock>> {
+            let image = image.clone();
+            let detection = Arc::clone(&self.detection);
+            let recognition = Arc::clone(&self.recognition);
+            let dictionary = Arc::clone(&self.dictionary);
+            let config = self.config.clone();
+            tokio::task::spawn_blocking(move || {
+                if image.base64.trim().is_empty() {
+                    return Ok(Vec::new());
+                }
+                let bytes = BASE64_STANDARD
+                    .decode(image.base64.trim().as_bytes())
+                    .context("failed to decode image payload")?;
+                let img = image::load_from_memory(&bytes).context("failed to decode image")?;
+                let rgb = img.to_rgb8();
+
+                let regions = {
+                    let (input, width, height) = detection_input(&img, config.max_side)?;
+                    let mut session = detection
+                        .lock()
+                        .map_err(|_| anyhow!("text detection model lock poisoned"))?;
+                    let outputs = session
+                        .run(ort::inputs![input])
+                        .context("text detection inference failed")?;
+                    let (shape, map) = outputs[0]
+                        .try_extract_tensor::<f32>()
+                        .context("text detection model returned no float tensor")?;
+                    let (map_height, map_width) = match shape.len() {
+                        n if n >= 2 => (shape[n - 2] as usize, shape[n - 1] as usize),
+                        _ => (height, width),
+                    };
+                    find_text_regions(map, map_width, map_height, &config)
+                };
+
+                let mut session = recognition
+                    .lock()
+                    .map_err(|_| anyhow!("text recognition model lock poisoned"))?;
+                let mut blocks = Vec::new();
+                for (bbox, _) in regions.into_iter().take(config.max_blocks) {
+                    let input = line_input(&rgb, &bbox, &config)?;
+                    let outputs = session
+                        .run(ort::inputs![input])
+                        .context("text recognition inference failed")?;
+                    let (shape, output) = outputs[0]
+                        .try_extract_tensor::<f32>()
+                        .context("text recognition model returned no float tensor")?;
+                    let shape: Vec<usize> = shape.iter().map(|dim| *dim as usize).collect();
+                    let (text, confidence) = decode_ctc(output, &shape, &dictionary)?;
+                    if text.is_empty() || confidence < config.confidence {
+                        continue;
+                    }
+                    blocks.push(TextBlock {
+                        text,
+                        confidence: Some(confidence),
+                        bbox: Some(bbox),
+                        source_image_id: None,
+                    });
+                }
+                Ok(blocks)
+            })
+            .await
+            .context("text recognition task failed")?
+        }
+    }
+
+    /// The image scaled so its longest side is at most `max_side` and both
+    /// sides are multiples of 32, as normalized BGR planes.
+    fn detection_input(img: &DynamicImage, max_side: u32) -> Result<(Tensor<f32>, usize, usize)> {
+        let scale = (max_side as f32 / img.width().max(img.height()).max(1) as f32).min(1.0);
+        let round = |side: u32| (((side as f32 * scale) / 32.0).round() as u32).max(1) * 32;
+        let (width, height) = (round(img.width()), round(img.height()));
+        let resized = img
+            .resize_exact(width, height, FilterType::Triangle)
+            .to_rgb8();
+        let (width, height) = (width as usize, height as usize);
+        let plane = width * height;
+        let mut pixels = vec![0.0; 3 * plane];
+        for (x, y, pixel) in resized.enumerate_pixels() {
+            let offset = y as usize * width + x as usize;
+            for channel in 0..3 {
+                let value = pixel.0[2 - channel] as f32 / 255.0;
+                pixels[channel * plane + offset] =
+                    (value - DETECTION_MEAN[channel]) / DETECTION_STD[channel];
+            }
+        }
+        let input = Tensor::from_array(([1usize, 3, height, width], pixels))
+            .context("failed to build text detection input")?;
+        Ok((input, width, height))
+    }
+
+    /// The part of `rgb` inside `bbox` scaled to the line height, as BGR
+    /// planes in `-1.0..=1.0`.
+    fn line_input(
+        rgb: &RgbImage,
+        bbox: &crate::BoundingBox,
+        config: &OcrConfig,
+    ) -> Result<Tensor<f32>> {
+        let (image_width, image_height) = (rgb.width() as f32, rgb.height() as f32);
+        let x = ((bbox.x * image_width) as u32).min(rgb.width().saturating_sub(1));
+        let y = ((bbox.y * image_height) as u32).min(rgb.height().saturating_sub(1));
+        let width = ((bbox.width * image_width).round() as u32).clamp(1, rgb.width() - x);
+        let height = ((bbox.height * image_height).round() as u32).clamp(1, rgb.height() - y);
+        let crop = image::imageops::crop_imm(rgb, x, y, width, height).to_image();
+        let line_width = ((width as f32 * config.line_height as f32 / height as f32).round()
+            as u32)
+            .clamp(1, config.max_line_width);
+        let resized =
+            image::imageops::resize(&crop, line_width, config.line_height, FilterType::Triangle);
+        let (line_width, line_height) = (line_width as usize, config.line_height as usize);
+        let plane = line_width * line_height;
+        let mut pixels = vec![0.0; 3 * plane];
+        for (x, y, pixel) in resized.enumerate_pixels() {
+            let offset = y as usize * line_width + x as usize;
+            for channel in 0..3 {
+                pixels[channel * plane + offset] = pixel.0[2 - channel] as f32 / 127.5 - 1.0;
+            }
+        }
+        Tensor::from_array(([1usize, 3, line_height, line_width], pixels))
+            .context("failed to build text recognition input")
+    }
+}
@@ -0,0 +1,59 @@
+//! Cooperative cancellation for Pete's background loops.
+//!
+//! ```
+//! use psyche::Shutdown;
+//!
+//! let shutdown = Shutdown::new();
+//! let observer = shutdown.clone();
+//! assert!(!observer.is_triggered());
+//! shutdown.trigger();
+//! assert!(observer.is_triggered());
+//! ```
+
+use std::sync::Arc;
+use tokio::sync::watch;
+
+/// Cloneable cancellation token shared by [`Psyche`](crate::Psyche) and its
+/// loops.
+///
+/// Triggering it lets in-flight work finish, then stops the conversation,
+/// experience and wit loops so the psyche can drain and checkpoint.
+#[derive(Clone, Debug)]
+pub struct Shutdown {
+    tx: Arc<watch::Sender<bool>>,
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shutdown {
+    /// Create an untriggered token.
+    pub fn new() -> Self {
+        let (tx, _rx) = watch::channel(false);
+        Self { tx: Arc::new(tx) }
+    }
+
+    /// Request an orderly shutdown. Later calls have no effect.
+    pub fn trigger(&self) {
+        self.tx
+            .send_if_modified(|triggered| !std::mem::replace(triggered, true));
+    }
+
+    /// Return `true` once [`trigger`](Self::trigger) has been called.
+    pub fn is_triggered(&self) -> bool {
+        *self.tx.borrow()
+    }
+
+    /// Wait until shutdown is requested.
+    pub async fn cancelled(&self) {
+        let mut rx = self.tx.subscribe();
+        while !*rx.borrow_and_update() {
+            if rx.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+}
@@ -42,6 +42,20 @@ impl TaskGroup {
             let _ = h.await;
         }
     }
+
+    /// Wait up to `grace` for tasks to finish on their own, then abort the rest.
+    ///
+    /// Used after a [`Shutdown`](crate::Shutdown) has been triggered so loops
+    /// can complete their current tick before being cancelled.
+    pub async fn shutdown_within(mut self, grace: std::time::Duration) {
+        let deadline = tokio::time::Instant::now() + grace;
+        for mut h in self.handles.drain(..) {
+            if tokio::time::timeout_at(deadline, &mut h).await.is_err() {
+                h.abort();
+                let _ = h.await;
+            }
+        }
+    }
 }
 
 impl Drop for TaskGroup {
@@ -71,4 +85,17 @@ mod tests {
         });
         drop(group); // should abort without waiting a second
     }
+
+    #[tokio::test]
+    async fn shutdown_within_aborts_stragglers() {
+        let mut group = TaskGroup::new();
+        group.spawn(async {
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+        });
+        let start = std::time::Instant::now();
+        group
+            .shutdown_within(std::time::Duration::from_millis(10))
+            .await;
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    }
 }
@@ -1,5 +1,6 @@
 use futures::Stream;
 use futures::StreamExt;
+use serde::{Deserialize, Serialize};
 use std::any::Any;
 use std::sync::Arc;
 use tokio::sync::broadcast;
@@ -13,7 +14,8 @@ use tokio_stream::wrappers::BroadcastStream;
 ///
 /// Topics are implemented as typed channels using [`Topic<T>`] and consumed via
 /// the [`TopicBus`] for decoupled publish/subscribe communication.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Topic {
     /// 🧿 Raw sensory input from the environment.
     ///
@@ -73,6 +75,51 @@ pub enum Topic {
     FaceInfo,
 }
 
+impl Topic {
+    /// Every topic, in cognitive order.
+    pub const ALL: [Topic; 8] = [
+        Topic::Sensation,
+        Topic::Instant,
+        Topic::Moment,
+        Topic::Situation,
+        Topic::Episode,
+        Topic::Identity,
+        Topic::Instruction,
+        Topic::FaceInfo,
+    ];
+
+    /// Stable snake_case name used in configuration files.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Topic::Sensation => "sensation",
+            Topic::Instant => "instant",
+            Topic::Moment => "moment",
+            Topic::Situation => "situation",
+            Topic::Episode => "episode",
+            Topic::Identity => "identity",
+            Topic::Instruction => "instruction",
+            Topic::FaceInfo => "face_info",
+        }
+    }
+}
+
+impl std::fmt::Display for Topic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Topic {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Topic::ALL
+            .into_iter()
+            .find(|t| t.as_str() == s)
+            .ok_or_else(|| anyhow::anyhow!("unknown topic: {s}"))
+    }
+}
+
 /// Envelope for messages exchanged on the [`TopicBus`].
 ///
 /// A `TopicMessage` is usually created by [`TopicBus::publish`] and then
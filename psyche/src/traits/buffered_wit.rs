@@ -1,5 +1,6 @@
 use crate::Impression;
 use async_trait::async_trait;
+use serde_json::Value;
 use std::sync::Mutex;
 
 /// Trait for wits that simply buffer inputs and process them on `tick`.
@@ -16,8 +17,27 @@ pub trait BufferedWit: Send + Sync {
     /// Convert drained items into impressions.
     async fn process_buffer(&self, items: Vec<Self::Input>) -> Vec<Impression<Self::Output>>;
 
+    /// Convert what is left at shutdown into impressions.
+    ///
+    /// Defaults to [`process_buffer`](Self::process_buffer); wits that hold
+    /// input back between ticks override this to emit it anyway.
+    async fn flush(&self, items: Vec<Self::Input>) -> Vec<Impression<Self::Output>> {
+        if items.is_empty() {
+            return Vec::new();
+        }
+        self.process_buffer(items).await
+    }
+
     /// Short static label used for debug reporting.
     fn label(&self) -> &'static str;
+
+    /// State to persist across restarts, if any.
+    fn checkpoint(&self) -> Option<Value> {
+        None
+    }
+
+    /// Restore state previously returned by [`checkpoint`](Self::checkpoint).
+    fn restore(&self, _state: Value) {}
 }
 
 #[async_trait]
@@ -43,6 +63,19 @@ where
         self.process_buffer(items).await
     }
 
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        let items = std::mem::take(&mut *self.buffer().lock().unwrap());
+        self.flush(items).await
+    }
+
+    fn checkpoint(&self) -> Option<Value> {
+        BufferedWit::checkpoint(self)
+    }
+
+    fn restore(&self, state: Value) {
+        BufferedWit::restore(self, state);
+    }
+
     fn debug_label(&self) -> &'static str {
         self.label()
     }
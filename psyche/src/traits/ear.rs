@@ -1,3 +1,4 @@
+use crate::{EchoAction, HeardWord};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 /// All callbacks are made from the conversation loop.
@@ -15,6 +16,23 @@ pub trait Ear: Send + Sync {
     async fn hear_user_say_at(&self, text: &str, _occurred_at: DateTime<Utc>) {
         self.hear_user_say(text).await;
     }
+    /// Notifies the ear that the user said `text`, with the recogniser's word timings.
+    ///
+    /// Returns what was done with the words when they turned out to be an
+    /// echo of Pete's own voice.
+    async fn hear_user_words_at(
+        &self,
+        text: &str,
+        _words: &[HeardWord],
+        occurred_at: DateTime<Utc>,
+    ) -> Option<EchoAction> {
+        self.hear_user_say_at(text, occurred_at).await;
+        None
+    }
+    /// Notifies the ear of an interim hypothesis for speech still in progress.
+    ///
+    /// The final wording follows through [`hear_user_say_at`](Self::hear_user_say_at).
+    async fn hear_user_partial_at(&self, _text: &str, _stable: bool, _occurred_at: DateTime<Utc>) {}
     /// Notifies the ear that someone typed `text` into the web interface.
     async fn hear_web_interface_type(&self, text: &str) {
         self.hear_user_say(text).await;
@@ -1,14 +1,100 @@
+use crate::{PhonemeTiming, Prosody};
 use anyhow::Result;
 use async_trait::async_trait;
-use futures::Stream;
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
 use std::pin::Pin;
 
 /// Stream of raw WAV data chunks.
 pub type TtsStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>>> + Send>>;
 
+/// A voice offered by a [`Tts`] backend.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TtsVoice {
+    /// Backend-specific identifier passed back when synthesizing.
+    pub id: String,
+    /// Language code the voice speaks, when the backend reports one.
+    pub language: Option<String>,
+}
+
+impl TtsVoice {
+    /// Voice `id` with no known language.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            language: None,
+        }
+    }
+}
+
+/// Text to synthesize together with how it should sound.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TtsRequest {
+    /// Plain text to speak.
+    pub text: String,
+    /// Rate, pitch, volume and voice controls; backends apply what they
+    /// support and ignore the rest.
+    pub prosody: Prosody,
+}
+
+impl TtsRequest {
+    /// Request speaking `text` with neutral prosody.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            prosody: Prosody::default(),
+        }
+    }
+
+    /// Return this request spoken with `prosody`.
+    pub fn with_prosody(mut self, prosody: Prosody) -> Self {
+        self.prosody = prosody;
+        self
+    }
+}
+
+/// Complete synthesized audio for one request.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TtsAudio {
+    /// WAV bytes.
+    pub wav: Vec<u8>,
+    /// When each phoneme is spoken, for engines that report alignment.
+    pub phonemes: Option<Vec<PhonemeTiming>>,
+}
+
 /// Text-to-speech engine interface.
 #[async_trait]
 pub trait Tts: Send + Sync {
     /// Return a stream of WAV bytes for `text`.
     async fn stream_wav(&self, text: &str) -> Result<TtsStream>;
+
+    /// Return a stream of WAV bytes for `request`.
+    ///
+    /// The default ignores the prosody and speaks the text neutrally.
+    async fn stream_request(&self, request: &TtsRequest) -> Result<TtsStream> {
+        self.stream_wav(&request.text).await
+    }
+
+    /// Synthesize all of `request` at once.
+    ///
+    /// The default collects [`stream_request`](Self::stream_request) and
+    /// reports no phoneme timings; backends with alignment data override it.
+    async fn synthesize(&self, request: &TtsRequest) -> Result<TtsAudio> {
+        let mut stream = self.stream_request(request).await?;
+        let mut wav = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            wav.extend(chunk?);
+        }
+        Ok(TtsAudio {
+            wav,
+            phonemes: None,
+        })
+    }
+
+    /// List the voices this backend can speak with.
+    ///
+    /// Backends that cannot enumerate voices return an empty list.
+    async fn voices(&self) -> Result<Vec<TtsVoice>> {
+        Ok(Vec::new())
+    }
 }
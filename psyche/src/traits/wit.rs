@@ -22,6 +22,22 @@ pub trait Wit: Send + Sync {
     /// Periodically called to emit zero or more summarized [`Impression`]s.
     async fn tick(&self) -> Vec<Impression<Self::Output>>;
 
+    /// Flush buffered input during shutdown.
+    ///
+    /// The default discards nothing and emits nothing; wits that hold input
+    /// between ticks override this to summarize what is left.
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        Vec::new()
+    }
+
+    /// State to persist across restarts, if any.
+    fn checkpoint(&self) -> Option<Value> {
+        None
+    }
+
+    /// Restore state previously returned by [`checkpoint`](Self::checkpoint).
+    fn restore(&self, _state: Value) {}
+
     /// Human readable name used for logging.
     fn name(&self) -> &'static str {
         std::any::type_name::<Self>()
@@ -39,8 +55,22 @@ pub trait ErasedWit: Send + Sync {
     /// Execute a tick and return serialized [`Impression`]s.
     async fn tick_erased(&self) -> Vec<Impression<Value>>;
 
-    /// Name of this [`Wit`]. Used for debugging.
-    fn name(&self) -> &'static str;
+    /// Flush buffered input and return serialized [`Impression`]s.
+    async fn drain_erased(&self) -> Vec<Impression<Value>> {
+        Vec::new()
+    }
+
+    /// State to persist across restarts, if any.
+    fn checkpoint_state(&self) -> Option<Value> {
+        None
+    }
+
+    /// Restore state previously returned by [`checkpoint_state`](Self::checkpoint_state).
+    fn restore_state(&self, _state: Value) {}
+
+    /// Name of this [`Wit`]. Used for debugging, tick overrides and
+    /// checkpoints.
+    fn name(&self) -> &str;
 
     /// Debug label of this [`Wit`].
     fn debug_label(&self) -> &'static str;
@@ -49,12 +79,24 @@ pub trait ErasedWit: Send + Sync {
 /// Adapter allowing any [`Wit`] to be used as an [`ErasedWit`].
 pub struct WitAdapter<W: Wit> {
     inner: Arc<W>,
+    name: Option<String>,
 }
 
 impl<W: Wit> WitAdapter<W> {
     /// Wrap `wit` so it can be stored as an [`ErasedWit`].
     pub fn new(wit: Arc<W>) -> Self {
-        Self { inner: wit }
+        Self {
+            inner: wit,
+            name: None,
+        }
+    }
+
+    /// Wrap `wit` under `name` instead of [`Wit::name`].
+    pub fn named(wit: Arc<W>, name: impl Into<String>) -> Self {
+        Self {
+            inner: wit,
+            name: Some(name.into()),
+        }
     }
 }
 
@@ -65,35 +107,23 @@ where
     W::Output: Serialize + Send + Sync + 'static,
 {
     async fn tick_erased(&self) -> Vec<Impression<Value>> {
-        self.inner
-            .tick()
-            .await
-            .into_iter()
-            .map(|imp| {
-                let stimuli = imp
-                    .stimuli
-                    .into_iter()
-                    .filter_map(|s| {
-                        serde_json::to_value(&s.what).ok().map(|what| Stimulus {
-                            what,
-                            timestamp: s.timestamp,
-                            source_sensation_ids: s.source_sensation_ids,
-                        })
-                    })
-                    .collect();
-                Impression {
-                    stimuli,
-                    source_sensation_ids: imp.source_sensation_ids,
-                    summary: imp.summary,
-                    emoji: imp.emoji,
-                    timestamp: imp.timestamp,
-                }
-            })
-            .collect()
+        erase_impressions(self.inner.tick().await)
     }
 
-    fn name(&self) -> &'static str {
-        self.inner.name()
+    async fn drain_erased(&self) -> Vec<Impression<Value>> {
+        erase_impressions(self.inner.drain().await)
+    }
+
+    fn checkpoint_state(&self) -> Option<Value> {
+        self.inner.checkpoint()
+    }
+
+    fn restore_state(&self, state: Value) {
+        self.inner.restore(state);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or_else(|| self.inner.name())
     }
 
     fn debug_label(&self) -> &'static str {
@@ -101,6 +131,32 @@ where
     }
 }
 
+fn erase_impressions<T: Serialize>(impressions: Vec<Impression<T>>) -> Vec<Impression<Value>> {
+    impressions
+        .into_iter()
+        .map(|imp| {
+            let stimuli = imp
+                .stimuli
+                .into_iter()
+                .filter_map(|s| {
+                    serde_json::to_value(&s.what).ok().map(|what| Stimulus {
+                        what,
+                        timestamp: s.timestamp,
+                        source_sensation_ids: s.source_sensation_ids,
+                    })
+                })
+                .collect();
+            Impression {
+                stimuli,
+                source_sensation_ids: imp.source_sensation_ids,
+                summary: imp.summary,
+                emoji: imp.emoji,
+                timestamp: imp.timestamp,
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
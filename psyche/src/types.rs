@@ -123,10 +123,228 @@ pub fn audio_clip_id(audio: &AudioClip) -> String {
     )
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// An object seen in an image.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ObjectInfo {
     pub label: Option<String>,
     pub embedding: Vec<f32>,
+    /// Detector confidence in `0.0..=1.0`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    /// Where the object is in the image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<BoundingBox>,
+    /// Graph id of the `Image` the object was detected in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_image_id: Option<String>,
+}
+
+impl ObjectInfo {
+    /// Graph id of this observation made at `occurred_at`.
+    ///
+    /// Detections include their box, so several objects with the same label
+    /// in one frame stay distinct.
+    pub fn observation_id(&self, occurred_at: &str) -> String {
+        let label = self.label.as_deref().unwrap_or("unknown");
+        let len = self.embedding.len();
+        match &self.bbox {
+            Some(b) => format!(
+                "object:{label}:{len}:{occurred_at}:{:.4},{:.4},{:.4},{:.4}",
+                b.x, b.y, b.width, b.height
+            ),
+            None => format!("object:{label}:{len}:{occurred_at}"),
+        }
+    }
+}
+
+/// Axis-aligned box in an image, as fractions of its width and height
+/// measured from the top-left corner.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BoundingBox {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl BoundingBox {
+    /// Box covering the whole image.
+    pub const FULL: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        width: 1.0,
+        height: 1.0,
+    };
+
+    /// Area as a fraction of the image.
+    pub fn area(&self) -> f32 {
+        self.width.max(0.0) * self.height.max(0.0)
+    }
+
+    /// Intersection over union with `other`, in `0.0..=1.0`.
+    pub fn iou(&self, other: &Self) -> f32 {
+        let width = (self.x + self.width).min(other.x + other.width) - self.x.max(other.x);
+        let height = (self.y + self.height).min(other.y + other.height) - self.y.max(other.y);
+        let overlap = width.max(0.0) * height.max(0.0);
+        let union = self.area() + other.area() - overlap;
+        if union <= 0.0 { 0.0 } else { overlap / union }
+    }
+}
+
+/// A block of text read in an image.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TextBlock {
+    pub text: String,
+    /// Recognizer confidence in `0.0..=1.0`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    /// Where the text is in the image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<BoundingBox>,
+    /// Graph id of the `Image` the text was read in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_image_id: Option<String>,
+}
+
+impl TextBlock {
+    /// The text with case folded and runs of whitespace collapsed, so small
+    /// recognition differences between frames read as the same text.
+    pub fn normalized_text(&self) -> String {
+        self.text
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    }
+
+    /// Graph id of the `TextBlock` node, shared by every reading of the same
+    /// normalized text.
+    pub fn text_id(&self) -> String {
+        stable_content_id("text-block", [&self.normalized_text()])
+    }
+}
+
+/// Where a detected face is in its source image and how far to trust it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FaceDetails {
+    /// Face box in the source image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<BoundingBox>,
+    /// Facial landmarks as fractions of the source image, in the usual
+    /// five-point order: left eye, right eye, nose, left and right mouth
+    /// corners.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub landmarks: Vec<FaceLandmark>,
+    /// Detector confidence in `0.0..=1.0`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    /// How usable the crop is for recognition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality: Option<FaceQuality>,
+}
+
+impl FaceDetails {
+    /// Add these details to a face graph `node` as flat properties.
+    pub(crate) fn add_graph_properties(&self, node: &mut serde_json::Value) {
+        let bbox = self.bbox.as_ref();
+        let quality = self.quality.as_ref();
+        let landmarks = (!self.landmarks.is_empty()).then(|| {
+            self.landmarks
+                .iter()
+                .flat_map(|point| [point.x, point.y])
+                .collect::<Vec<_>>()
+        });
+        node["detection_confidence"] = serde_json::json!(self.confidence);
+        node["bbox_x"] = serde_json::json!(bbox.map(|b| b.x));
+        node["bbox_y"] = serde_json::json!(bbox.map(|b| b.y));
+        node["bbox_width"] = serde_json::json!(bbox.map(|b| b.width));
+        node["bbox_height"] = serde_json::json!(bbox.map(|b| b.height));
+        node["landmarks"] = serde_json::json!(landmarks);
+        node["quality"] = serde_json::json!(quality.map(|q| q.score));
+        node["sharpness"] = serde_json::json!(quality.map(|q| q.sharpness));
+        node["pose"] = serde_json::json!(quality.and_then(|q| q.pose));
+    }
+}
+
+/// A facial landmark position.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FaceLandmark {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Sharpness and pose of a face crop, each in `0.0..=1.0`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FaceQuality {
+    /// Focus of the crop; blurry crops score low.
+    pub sharpness: f32,
+    /// How squarely the face looks at the camera, when landmarks are known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pose: Option<f32>,
+    /// Overall quality: sharpness scaled by pose.
+    pub score: f32,
+}
+
+/// Laplacian variance at which a crop counts as half sharp.
+const SHARPNESS_MIDPOINT: f32 = 100.0;
+
+impl FaceQuality {
+    /// Combine `sharpness` and an optional `pose` score.
+    pub fn new(sharpness: f32, pose: Option<f32>) -> Self {
+        let sharpness = sharpness.clamp(0.0, 1.0);
+        let pose = pose.map(|pose| pose.clamp(0.0, 1.0));
+        Self {
+            sharpness,
+            pose,
+            score: sharpness * pose.unwrap_or(1.0),
+        }
+    }
+
+    /// Sharpness of a `width` by `height` greyscale image from the variance
+    /// of its Laplacian: flat or blurred crops have little edge detail.
+    pub fn sharpness_of(gray: &[u8], width: u32, height: u32) -> f32 {
+        let (width, height) = (width as usize, height as usize);
+        if width < 3 || height < 3 || gray.len() < width * height {
+            return 0.0;
+        }
+        let at = |x: usize, y: usize| gray[y * width + x] as f32;
+        let mut responses = Vec::with_capacity((width - 2) * (height - 2));
+        for y in 1..height - 1 {
+            for x in 1..width - 1 {
+                responses.push(
+                    at(x - 1, y) + at(x + 1, y) + at(x, y - 1) + at(x, y + 1) - 4.0 * at(x, y),
+                );
+            }
+        }
+        let mean = responses.iter().sum::<f32>() / responses.len() as f32;
+        let variance = responses
+            .iter()
+            .map(|response| (response - mean).powi(2))
+            .sum::<f32>()
+            / responses.len() as f32;
+        variance / (variance + SHARPNESS_MIDPOINT)
+    }
+
+    /// Frontal pose score from five-point landmarks in pixels.
+    ///
+    /// A nose drifting from between the eyes means the head is turned, and a
+    /// tilted eye line means it is rolled; a frontal, upright face scores 1.
+    pub fn pose_of(landmarks: &[FaceLandmark]) -> Option<f32> {
+        let [left_eye, right_eye, nose, ..] = landmarks else {
+            return None;
+        };
+        let (dx, dy) = (right_eye.x - left_eye.x, right_eye.y - left_eye.y);
+        let eye_distance = dx.hypot(dy);
+        if eye_distance <= f32::EPSILON {
+            return None;
+        }
+        let mid_x = (left_eye.x + right_eye.x) / 2.0;
+        let mid_y = (left_eye.y + right_eye.y) / 2.0;
+        let yaw = ((nose.x - mid_x) * dx + (nose.y - mid_y) * dy) / (eye_distance * eye_distance);
+        let yaw_score = (1.0 - 2.0 * yaw.abs()).clamp(0.0, 1.0);
+        let roll_score = (dx / eye_distance).max(0.0);
+        Some(yaw_score * roll_score)
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -141,6 +359,17 @@ pub struct AudioClip {
     pub captured_at: Option<String>,
 }
 
+/// Provisional words of an utterance the user is still speaking.
+///
+/// Streaming recognizers emit these before the final transcript so wits can
+/// start anticipating a reply. `stable` marks a hypothesis that has stopped
+/// changing between re-decodes.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PartialUtterance {
+    pub text: String,
+    pub stable: bool,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CombobulationSummary {
     pub text: String,
@@ -0,0 +1,217 @@
+//! Mouth shapes for lip-syncing Pete's face to his speech.
+//!
+//! A viseme is the visible mouth shape of a group of sounds: `p`, `b` and
+//! `m` all close the lips, so they share the `PP` viseme. Each synthesized
+//! sentence gets a timeline of [`VisemeCue`]s using the fifteen common
+//! shapes (`sil`, `PP`, `FF`, `TH`, `DD`, `kk`, `CH`, `SS`, `nn`, `RR`, `aa`,
+//! `E`, `I`, `O`, `U`). When the speech engine reports phoneme timings the
+//! timeline follows them exactly ([`visemes_from_phonemes`]); otherwise the
+//! spelling is turned into approximate sounds whose durations are scaled to
+//! the length of the audio ([`visemes_from_text`]).
+//!
+//! ```
+//! use psyche::visemes_from_text;
+//!
+//! let cues = visemes_from_text("Hi, Bob.", 1_000);
+//! assert_eq!(cues.first().unwrap().start_ms, 0);
+//! assert_eq!(cues.last().unwrap().end_ms, 1_000);
+//! assert!(cues.iter().any(|cue| cue.viseme == "PP"));
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Mouth shape held from `start_ms` until `end_ms`, measured from the start
+/// of the sentence's audio.
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VisemeCue {
+    pub viseme: String,
+    pub start_ms: u32,
+    pub end_ms: u32,
+}
+
+/// A phoneme and when the speech engine spoke it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhonemeTiming {
+    /// ARPAbet (`AH0`, `B`) or IPA (`ə`, `b`) symbol.
+    pub phoneme: String,
+    pub start_ms: u32,
+    pub end_ms: u32,
+}
+
+/// Viseme for an ARPAbet or IPA phoneme, or `None` when it has no shape of
+/// its own (such as `h`).
+pub fn phoneme_viseme(phoneme: &str) -> Option<&'static str> {
+    let symbol: String = phoneme
+        .chars()
+        .filter(|c| !c.is_ascii_digit() && !matches!(c, 'ˈ' | 'ˌ' | 'ː' | '\u{0361}'))
+        .collect();
+    let viseme = match symbol.to_lowercase().as_str() {
+        "" | "_" | "#" | "sil" | "sp" | "spn" | "pau" | "^" | "$" => "sil",
+        "p" | "b" | "m" => "PP",
+        "f" | "v" => "FF",
+        "th" | "dh" | "θ" | "ð" => "TH",
+        "t" | "d" | "dx" | "ɾ" => "DD",
+        "k" | "g" | "ng" | "ŋ" | "ɡ" => "kk",
+        "ch" | "jh" | "sh" | "zh" | "tʃ" | "dʒ" | "ʃ" | "ʒ" => "CH",
+        "s" | "z" => "SS",
+        "n" | "l" | "el" | "en" | "ɫ" => "nn",
+        "r" | "er" | "ɹ" | "ɚ" | "ɝ" | "ɜ" => "RR",
+        "aa" | "ae" | "ah" | "ay" | "aw" | "a" | "ɑ" | "æ" | "ʌ" | "ə" | "ɐ" | "aɪ" | "aʊ" => {
+            "aa"
+        }
+        "eh" | "ey" | "e" | "ɛ" | "eɪ" => "E",
+        "ih" | "iy" | "y" | "i" | "ɪ" | "j" => "I",
+        "ao" | "ow" | "oy" | "o" | "ɔ" | "oʊ" | "ɔɪ" => "O",
+        "uh" | "uw" | "w" | "u" | "ʊ" => "U",
+        _ => return None,
+    };
+    Some(viseme)
+}
+
+/// Timeline following phoneme timings from the speech engine.
+///
+/// Phonemes without a shape of their own extend the previous cue, and
+/// neighbouring cues with the same shape are merged.
+pub fn visemes_from_phonemes(phonemes: &[PhonemeTiming]) -> Vec<VisemeCue> {
+    let mut cues = Vec::new();
+    for phoneme in phonemes {
+        let viseme = phoneme_viseme(&phoneme.phoneme);
+        push_cue(&mut cues, viseme, phoneme.start_ms, phoneme.end_ms);
+    }
+    cues
+}
+
+/// Timeline guessed from the spelling of `text`, stretched over
+/// `duration_ms` of audio.
+///
+/// Vowels are held longer than consonants and punctuation becomes a closed
+/// mouth, so the guess roughly tracks the rhythm of the sentence.
+pub fn visemes_from_text(text: &str, duration_ms: u32) -> Vec<VisemeCue> {
+    let sounds = spelled_sounds(text);
+    let total: f32 = sounds.iter().map(|(_, weight)| weight).sum();
+    if total <= 0.0 || duration_ms == 0 {
+        return Vec::new();
+    }
+    let mut cues = Vec::new();
+    let mut elapsed = 0.0;
+    for (viseme, weight) in sounds {
+        let start = (elapsed / total * duration_ms as f32).round() as u32;
+        elapsed += weight;
+        let end = (elapsed / total * duration_ms as f32).round() as u32;
+        push_cue(&mut cues, viseme, start, end);
+    }
+    cues
+}
+
+fn push_cue(cues: &mut Vec<VisemeCue>, viseme: Option<&'static str>, start_ms: u32, end_ms: u32) {
+    let viseme = match (viseme, cues.last_mut()) {
+        (Some(viseme), _) => viseme,
+        (None, Some(last)) => {
+            last.end_ms = end_ms;
+            return;
+        }
+        (None, None) => "sil",
+    };
+    match cues.last_mut() {
+        Some(last) if last.viseme == viseme => last.end_ms = end_ms,
+        _ if end_ms > start_ms => cues.push(VisemeCue {
+            viseme: viseme.to_string(),
+            start_ms,
+            end_ms,
+        }),
+        _ => {}
+    }
+}
+
+const VOWEL: f32 = 1.0;
+const CONSONANT: f32 = 0.6;
+const CLAUSE_PAUSE: f32 = 2.0;
+const SENTENCE_PAUSE: f32 = 3.0;
+
+/// Approximate sounds of `text` as visemes with relative durations.
+fn spelled_sounds(text: &str) -> Vec<(Option<&'static str>, f32)> {
+    let mut sounds = Vec::new();
+    for token in text.split_inclusive(|c: char| !c.is_alphanumeric() && c != '\'') {
+        let word: Vec<char> = token
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect();
+        word_sounds(&word, &mut sounds);
+        match token.chars().last() {
+            Some(',' | ';' | ':' | '-' | '—') => sounds.push((Some("sil"), CLAUSE_PAUSE)),
+            Some('.' | '?' | '!') => sounds.push((Some("sil"), SENTENCE_PAUSE)),
+            _ => {}
+        }
+    }
+    // Trailing silence is left to the end of the audio.
+    while matches!(sounds.last(), Some((Some("sil"), _))) {
+        sounds.pop();
+    }
+    sounds
+}
+
+fn word_sounds(word: &[char], sounds: &mut Vec<(Option<&'static str>, f32)>) {
+    let mut i = 0;
+    while i < word.len() {
+        let c = word[i];
+        let next = word.get(i + 1).copied();
+        // A final `e` after a consonant is usually silent, as in "make".
+        if c == 'e' && i + 1 == word.len() && i >= 3 && !is_vowel(word[i - 1]) {
+            break;
+        }
+        let (sound, width) = match (c, next) {
+            ('t', Some('h')) => ((Some("TH"), CONSONANT), 2),
+            ('s' | 'c', Some('h')) => ((Some("CH"), CONSONANT), 2),
+            ('p', Some('h')) => ((Some("FF"), CONSONANT), 2),
+            ('w', Some('h')) => ((Some("U"), CONSONANT), 2),
+            ('c', Some('k')) => ((Some("kk"), CONSONANT), 2),
+            ('n', Some('g')) => ((Some("nn"), CONSONANT), 2),
+            ('o', Some('o')) => ((Some("U"), VOWEL), 2),
+            ('e', Some('e' | 'a')) => ((Some("I"), VOWEL), 2),
+            ('o', Some('u' | 'w')) => ((Some("aa"), VOWEL), 2),
+            ('a', Some('i' | 'y')) => ((Some("E"), VOWEL), 2),
+            ('q', Some('u')) => {
+                sounds.push((Some("kk"), CONSONANT));
+                ((Some("U"), CONSONANT), 2)
+            }
+            ('x', _) => {
+                sounds.push((Some("kk"), CONSONANT));
+                ((Some("SS"), CONSONANT), 1)
+            }
+            (c, Some(n)) if c == n && !is_vowel(c) => (letter_sound(c), 2),
+            (c, _) => (letter_sound(c), 1),
+        };
+        sounds.push(sound);
+        i += width;
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+fn letter_sound(c: char) -> (Option<&'static str>, f32) {
+    let viseme = match c {
+        'a' => "aa",
+        'e' => "E",
+        'i' | 'y' => "I",
+        'o' => "O",
+        'u' | 'w' => "U",
+        'b' | 'p' | 'm' => "PP",
+        'f' | 'v' => "FF",
+        't' | 'd' => "DD",
+        'c' | 'k' | 'g' | 'q' => "kk",
+        'j' => "CH",
+        's' | 'z' => "SS",
+        'n' | 'l' => "nn",
+        'r' => "RR",
+        // Digits are read aloud as words; hold an open mouth for them.
+        '0'..='9' => return (Some("aa"), 2.0 * VOWEL),
+        // `h` and letters outside English keep the previous shape.
+        _ => return (None, CONSONANT),
+    };
+    let weight = if is_vowel(c) { VOWEL } else { CONSONANT };
+    (Some(viseme), weight)
+}
@@ -17,6 +17,29 @@ pub struct Voice {
     extra_prompt: Arc<Mutex<Option<String>>>,
     will: Arc<Mutex<Option<Arc<crate::wits::Will>>>>,
     prompt: Arc<Mutex<Box<dyn crate::prompt::PromptFragment + Send + Sync>>>,
+    interrupted: Arc<AtomicBool>,
+    progress: Arc<Mutex<TurnProgress>>,
+}
+
+/// What the current turn has generated and handed to the mouth so far.
+#[derive(Default)]
+struct TurnProgress {
+    active: bool,
+    generated: String,
+    said: Vec<String>,
+}
+
+impl TurnProgress {
+    /// Generated text that follows the last sentence given to the mouth.
+    fn unsaid(&self) -> String {
+        let mut pos = 0;
+        for sentence in &self.said {
+            if let Some(i) = self.generated[pos..].find(sentence.as_str()) {
+                pos += i + sentence.len();
+            }
+        }
+        self.generated[pos..].trim().to_string()
+    }
 }
 
 impl Clone for Voice {
@@ -29,6 +52,8 @@ impl Clone for Voice {
             extra_prompt: self.extra_prompt.clone(),
             will: self.will.clone(),
             prompt: self.prompt.clone(),
+            interrupted: self.interrupted.clone(),
+            progress: self.progress.clone(),
         }
     }
 }
@@ -48,6 +73,8 @@ impl Voice {
             will: Arc::new(Mutex::new(None)),
             prompt: Arc::new(Mutex::new(Box::new(crate::prompt::VoicePrompt)
                 as Box<dyn crate::prompt::PromptFragment + Send + Sync>)),
+            interrupted: Arc::new(AtomicBool::new(false)),
+            progress: Arc::new(Mutex::new(TurnProgress::default())),
         }
     }
 
@@ -77,11 +104,34 @@ impl Voice {
         *self.extra_prompt.lock().unwrap() = prompt;
     }
 
+    /// Extra prompt waiting to be used on the next turn.
+    pub fn extra_prompt(&self) -> Option<String> {
+        self.extra_prompt.lock().unwrap().clone()
+    }
+
+    /// Replace the extra prompt used on the next turn.
+    pub fn set_extra_prompt(&self, prompt: Option<String>) {
+        *self.extra_prompt.lock().unwrap() = prompt;
+    }
+
     /// Returns `true` if the voice is currently permitted to speak.
     pub fn ready(&self) -> bool {
         self.ready.load(Ordering::SeqCst)
     }
 
+    /// Stop the turn in progress after the sentence currently being spoken.
+    ///
+    /// Returns the text already handed to the mouth and the generated
+    /// remainder that will now go unsaid, or `None` when no turn is running.
+    pub fn interrupt(&self) -> Option<(String, String)> {
+        let progress = self.progress.lock().unwrap();
+        if !progress.active {
+            return None;
+        }
+        self.interrupted.store(true, Ordering::SeqCst);
+        Some((progress.said.join(" "), progress.unsaid()))
+    }
+
     pub async fn update_prompt_context(&self, ctx: &str) {
         self.chatter.update_prompt_context(ctx).await;
     }
@@ -101,10 +151,18 @@ impl Voice {
             base
         };
         trace!(%prompt, "voice prompt");
+        self.interrupted.store(false, Ordering::SeqCst);
+        *self.progress.lock().unwrap() = TurnProgress {
+            active: true,
+            ..TurnProgress::default()
+        };
         if let Ok(mut stream) = self.chatter.chat(&prompt, history).await {
             let mut full = String::new();
             let mut segmenter = SentenceSegmenter::new();
-            while let Some(chunk_res) = stream.next().await {
+            'stream: while let Some(chunk_res) = stream.next().await {
+                if self.is_interrupted() {
+                    break;
+                }
                 match chunk_res {
                     Ok(chunk) => {
                         trace!("chunk received: {}", chunk);
@@ -112,7 +170,11 @@ impl Voice {
                             let _ = self.events.send(Event::StreamChunk(chunk.clone()));
                         }
                         full.push_str(&chunk);
+                        self.progress.lock().unwrap().generated.push_str(&chunk);
                         for sentence in segmenter.push_str(&chunk) {
+                            if self.is_interrupted() {
+                                break 'stream;
+                            }
                             self.emit_sentence(&sentence).await;
                         }
                     }
@@ -122,8 +184,18 @@ impl Voice {
                     }
                 }
             }
-            for sentence in segmenter.finish() {
-                self.emit_sentence(&sentence).await;
+            if !self.is_interrupted() {
+                for sentence in segmenter.finish() {
+                    if self.is_interrupted() {
+                        break;
+                    }
+                    self.emit_sentence(&sentence).await;
+                }
+            }
+            if self.is_interrupted() {
+                // Whatever was not handed to the mouth counts as unsaid.
+                full = self.progress.lock().unwrap().said.join(" ");
+                debug!(spoken_len = full.len(), "voice turn interrupted");
             }
             debug!(response_len = full.len(), "voice full response");
             trace!(%full, "voice full response body");
@@ -134,9 +206,14 @@ impl Voice {
                 trace!("Will not set; skipping output handling");
             }
         }
+        self.progress.lock().unwrap().active = false;
         Ok(())
     }
 
+    fn is_interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::SeqCst)
+    }
+
     async fn emit_sentence(&self, sentence: &str) {
         let trimmed = sentence.trim();
         if trimmed.is_empty() {
@@ -149,6 +226,7 @@ impl Voice {
         }
         if !text.trim().is_empty() {
             tokio::time::sleep(Duration::from_millis(5)).await;
+            self.progress.lock().unwrap().said.push(trimmed.to_string());
             let mouth = { self.mouth.lock().unwrap().clone() };
             mouth.speak(trimmed).await;
         }
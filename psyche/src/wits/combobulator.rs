@@ -1,3 +1,4 @@
+use crate::clock::{Clock, SystemClock};
 use crate::prompt::PromptFragment;
 use crate::topics::{Topic, TopicBus};
 use crate::traits::Doer;
@@ -27,6 +28,7 @@ pub struct Combobulator {
     last_caption_time: Mutex<Instant>,
     latest_image: Arc<Mutex<Option<ImageData>>>,
     llm_semaphore: Arc<Semaphore>,
+    clock: Arc<dyn Clock>,
 }
 
 impl Combobulator {
@@ -88,6 +90,7 @@ impl Combobulator {
             last_caption_time: Mutex::new(Instant::now() - Duration::from_secs(30)),
             latest_image: Arc::new(Mutex::new(None)),
             llm_semaphore: Arc::new(Semaphore::new(2)),
+            clock: Arc::new(SystemClock),
         }
     }
 
@@ -97,6 +100,12 @@ impl Combobulator {
         self
     }
 
+    /// Return this combobulator stamping summaries with `clock`.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Replace the prompt builder.
     pub fn set_prompt(&mut self, prompt: crate::prompt::CombobulatorPrompt) {
         self.prompt = prompt;
@@ -131,10 +140,12 @@ impl Wit for Combobulator {
             debug!(elapsed=?start.elapsed(), "combobulator image captioned");
             drop(permit);
             if let Ok(caption) = result {
-                self.buffer.lock().unwrap().push(Impression::new(
-                    vec![Stimulus::new(caption.clone())],
+                let now = self.clock.now();
+                self.buffer.lock().unwrap().push(Impression::at(
+                    vec![Stimulus::at(caption.clone(), now)],
                     caption,
                     None::<String>,
+                    now,
                 ));
             }
         }
@@ -154,6 +165,18 @@ impl Wit for Combobulator {
         }
     }
 
+    /// Digest whatever is buffered without captioning another image.
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        let inputs = std::mem::take(&mut *self.buffer.lock().unwrap());
+        if inputs.is_empty() {
+            return Vec::new();
+        }
+        match self.digest(&inputs).await {
+            Ok(i) => vec![i],
+            Err(_) => Vec::new(),
+        }
+    }
+
     fn debug_label(&self) -> &'static str {
         Self::LABEL
     }
@@ -198,15 +221,13 @@ impl Combobulator {
             }
         }
         let source_sensation_ids = crate::model::source_sensation_ids_from(inputs);
-        let source_occurred_at = latest_input_timestamp(inputs).unwrap_or_else(Utc::now);
-        let imp = Impression::new(
-            vec![Stimulus::from_impressions(summary.clone(), inputs)],
-            summary.clone(),
-            emoji.clone(),
-        );
+        let source_occurred_at = latest_input_timestamp(inputs).unwrap_or_else(|| self.clock.now());
+        let now = self.clock.now();
+        let mut stimulus = Stimulus::from_impressions(summary.clone(), inputs);
+        stimulus.timestamp = now;
+        let imp = Impression::at(vec![stimulus], summary.clone(), emoji.clone(), now);
         if let Some(bus) = &self.bus {
             bus.publish(Topic::Moment, imp.clone());
-            let now = Utc::now();
             bus.publish(
                 Topic::Sensation,
                 Sensation::of_at(
@@ -234,6 +255,7 @@ impl Combobulator {
                     mime: image.mime.clone(),
                     base64: image.base64.clone(),
                     captured_at: image.captured_at.clone(),
+                    source: image.source.clone(),
                 }],
             })
             .await?;
@@ -162,7 +162,9 @@ impl crate::traits::wit::Wit for EntityWit {
                     });
                 }
             }
-            Sensation::StartedSpeaking { .. } | Sensation::FinishedSpeaking { .. } => {}
+            Sensation::StartedSpeaking { .. }
+            | Sensation::FinishedSpeaking { .. }
+            | Sensation::InterruptedSpeaking { .. } => {}
         }
     }
 
@@ -276,6 +278,10 @@ impl crate::traits::wit::Wit for EntityWit {
         out
     }
 
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        self.tick().await
+    }
+
     fn debug_label(&self) -> &'static str {
         Self::LABEL
     }
@@ -310,7 +316,9 @@ impl crate::traits::observer::SensationObserver for EntityWit {
                             .await;
                     }
                 }
-                Sensation::StartedSpeaking { .. } | Sensation::FinishedSpeaking { .. } => {}
+                Sensation::StartedSpeaking { .. }
+                | Sensation::FinishedSpeaking { .. }
+                | Sensation::InterruptedSpeaking { .. } => {}
             }
         }
     }
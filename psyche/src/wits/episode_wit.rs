@@ -72,32 +72,9 @@ impl EpisodeWit {
             tx,
         }
     }
-}
-
-#[async_trait]
-impl crate::wit::Wit for EpisodeWit {
-    type Input = ();
-    type Output = String;
-
-    async fn observe(&self, _: Self::Input) {
-        // EpisodeWit gathers input via [`TopicBus`] subscriptions at
-        // construction time. This method is required by the [`Wit`] trait
-        // but intentionally does nothing.
-    }
 
-    async fn tick(&self) -> Vec<Impression<Self::Output>> {
-        const MIN_ITEMS: usize = 3;
-        let should_break = self.break_flag.swap(false, Ordering::SeqCst);
-        let items = {
-            let mut buf = self.buffer.lock().unwrap();
-            if buf.is_empty() {
-                return Vec::new();
-            }
-            if buf.len() < MIN_ITEMS && !should_break {
-                return Vec::new();
-            }
-            buf.drain(..).collect::<Vec<_>>()
-        };
+    /// Summarize `items` into an episode and publish it.
+    async fn summarize(&self, items: Vec<Impression<String>>) -> Vec<Impression<String>> {
         debug!(count = items.len(), "episode wit summarizing situations");
         let bullets = items
             .iter()
@@ -142,6 +119,42 @@ impl crate::wit::Wit for EpisodeWit {
         self.bus.publish(Topic::Episode, imp.clone());
         vec![imp]
     }
+}
+
+#[async_trait]
+impl crate::wit::Wit for EpisodeWit {
+    type Input = ();
+    type Output = String;
+
+    async fn observe(&self, _: Self::Input) {
+        // EpisodeWit gathers input via [`TopicBus`] subscriptions at
+        // construction time. This method is required by the [`Wit`] trait
+        // but intentionally does nothing.
+    }
+
+    async fn tick(&self) -> Vec<Impression<Self::Output>> {
+        const MIN_ITEMS: usize = 3;
+        let should_break = self.break_flag.swap(false, Ordering::SeqCst);
+        let items = {
+            let mut buf = self.buffer.lock().unwrap();
+            if buf.is_empty() {
+                return Vec::new();
+            }
+            if buf.len() < MIN_ITEMS && !should_break {
+                return Vec::new();
+            }
+            buf.drain(..).collect::<Vec<_>>()
+        };
+        self.summarize(items).await
+    }
+
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        let items = std::mem::take(&mut *self.buffer.lock().unwrap());
+        if items.is_empty() {
+            return Vec::new();
+        }
+        self.summarize(items).await
+    }
 
     fn debug_label(&self) -> &'static str {
         Self::LABEL
@@ -39,6 +39,48 @@ impl FaceMemoryWit {
             ..Self::new()
         }
     }
+
+    /// Note each buffered face as familiar or new.
+    async fn remember(&self, items: Vec<Stimulus<FaceInfo>>) -> Vec<Impression<FaceInfo>> {
+        let mut out = Vec::new();
+        for item in items {
+            let info = item.what;
+            let summary;
+            {
+                let mut last = self.last_face.lock().unwrap();
+                summary = if let Some(prev) = last.as_ref() {
+                    if similarity(prev, &info.embedding) > 0.9 {
+                        "I saw the same person again.".to_string()
+                    } else {
+                        "I think someone new just showed up.".to_string()
+                    }
+                } else {
+                    "I think someone new just showed up.".to_string()
+                };
+                *last = Some(info.embedding.clone());
+            }
+            info!(%summary, "face memory observation");
+            if let Some(tx) = &self.tx {
+                if crate::debug::debug_enabled(Self::LABEL).await {
+                    let _ = tx.send(crate::WitReport {
+                        name: Self::LABEL.into(),
+                        prompt: "face memory".into(),
+                        output: summary.clone(),
+                    });
+                }
+            }
+            out.push(Impression::new(
+                vec![Stimulus {
+                    what: info.clone(),
+                    timestamp: item.timestamp,
+                    source_sensation_ids: item.source_sensation_ids,
+                }],
+                summary,
+                None::<String>,
+            ));
+        }
+        out
+    }
 }
 
 fn similarity(a: &[f32], b: &[f32]) -> f32 {
@@ -84,45 +126,12 @@ impl Wit for FaceMemoryWit {
             self.ticks_without_face.store(0, Ordering::SeqCst);
             buf.drain(..).collect::<Vec<_>>()
         };
+        self.remember(items).await
+    }
 
-        let mut out = Vec::new();
-        for item in items {
-            let info = item.what;
-            let summary;
-            {
-                let mut last = self.last_face.lock().unwrap();
-                summary = if let Some(prev) = last.as_ref() {
-                    if similarity(prev, &info.embedding) > 0.9 {
-                        "I saw the same person again.".to_string()
-                    } else {
-                        "I think someone new just showed up.".to_string()
-                    }
-                } else {
-                    "I think someone new just showed up.".to_string()
-                };
-                *last = Some(info.embedding.clone());
-            }
-            info!(%summary, "face memory observation");
-            if let Some(tx) = &self.tx {
-                if crate::debug::debug_enabled(Self::LABEL).await {
-                    let _ = tx.send(crate::WitReport {
-                        name: Self::LABEL.into(),
-                        prompt: "face memory".into(),
-                        output: summary.clone(),
-                    });
-                }
-            }
-            out.push(Impression::new(
-                vec![Stimulus {
-                    what: info.clone(),
-                    timestamp: item.timestamp,
-                    source_sensation_ids: item.source_sensation_ids,
-                }],
-                summary,
-                None::<String>,
-            ));
-        }
-        out
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        let items = std::mem::take(&mut *self.buffer.lock().unwrap());
+        self.remember(items).await
     }
 
     fn debug_label(&self) -> &'static str {
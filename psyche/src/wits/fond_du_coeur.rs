@@ -37,6 +37,11 @@ impl FondDuCoeur {
     pub fn story(&self) -> String {
         self.story.lock().unwrap().clone()
     }
+
+    /// Replace the story, e.g. when resuming from a checkpoint.
+    pub fn set_story(&self, story: impl Into<String>) {
+        *self.story.lock().unwrap() = story.into();
+    }
 }
 
 impl FondDuCoeur {
@@ -1,6 +1,7 @@
 use crate::traits::BufferedWit;
 use crate::{Impression, WitReport, wits::FondDuCoeur};
 use async_trait::async_trait;
+use serde_json::{Value, json};
 use std::sync::Mutex;
 use tokio::sync::broadcast;
 
@@ -59,4 +60,14 @@ impl BufferedWit for IdentityWit {
     fn label(&self) -> &'static str {
         Self::LABEL
     }
+
+    fn checkpoint(&self) -> Option<Value> {
+        Some(json!({ "story": self.summarizer.story() }))
+    }
+
+    fn restore(&self, state: Value) {
+        if let Some(story) = state.get("story").and_then(Value::as_str) {
+            self.summarizer.set_story(story);
+        }
+    }
 }
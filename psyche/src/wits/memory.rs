@@ -1,15 +1,19 @@
 use crate::{
-    AudioClip, BrowserMotion, GeoLoc, Heartbeat, ImageData, Impression, ObjectInfo, Stimulus,
-    Thought, audio_clip_id, browser_motion_content_id, geoloc_content_id, image_content_id,
+    AudioClip, BlobStore, BoundingBox, BrowserMotion, FaceDetails, FaceTrack, GeoLoc, Heartbeat,
+    ImageData, Impression, ObjectInfo, SceneFingerprint, Stimulus, Thought, audio_clip_id,
+    browser_motion_content_id, geoloc_content_id, image_content_id,
 };
 use anyhow::{Context, Result, anyhow, bail};
 use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use lingproc::Vectorizer;
 use lingproc::math::cosine_similarity;
 use reqwest::{StatusCode, Url};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value, json};
 use sha2::{Digest, Sha256};
+use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 use std::sync::{
     Arc,
@@ -36,6 +40,13 @@ const QDRANT_VECTOR_COLLECTIONS: &[&str] = &[
     VOICE_COLLECTION,
 ];
 const QDRANT_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// Labels of nodes whose `base64` payload is kept in the blob store.
+const BLOB_PAYLOAD_LABELS: &[&str] = &["Image", "AudioClip"];
+/// Marks a payload read back from Cypher as a blob hash to load, as in
+/// `coalesce(i.base64, 'blob:' + i.blob_hash)`.
+const BLOB_PAYLOAD_PREFIX: &str = "blob:";
+/// Node recording how far face and voice recognitions have been tallied.
+const PERSON_LINK_PROGRESS_ID: &str = "person_link:progress";
 const NEO4J_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -453,6 +464,119 @@ impl QdrantClient {
         Ok(id)
     }
 
+    /// Load a stored CLIP scene embedding, or `None` when the point is absent.
+    pub async fn scene_vector(&self, point_id: &str) -> Result<Option<Vec<f32>>> {
+        self.vector_point(SCENE_VECTOR_COLLECTION, point_id).await
+    }
+
+    /// Load the vector of one Qdrant point, or `None` when it is absent.
+    pub async fn vector_point(&self, collection: &str, point_id: &str) -> Result<Option<Vec<f32>>> {
+        let response = reqwest::Client::new()
+            .get(self.endpoint(&format!("collections/{collection}/points/{point_id}"))?)
+            .timeout(QDRANT_REQUEST_TIMEOUT)
+            .send()
+            .await
+            .with_context(|| format!("failed to load Qdrant point {point_id} from {collection}"))?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(unexpected_qdrant_response(
+                response,
+                &format!("loading point {point_id} from {collection}"),
+            )
+            .await);
+        }
+        let body: Value = response
+            .json()
+            .await
+            .with_context(|| format!("failed to decode Qdrant point {point_id}"))?;
+        let vector = body
+            .pointer("/result/vector")
+            .and_then(Value::as_array)
+            .with_context(|| format!("Qdrant point {point_id} had no vector"))?
+            .iter()
+            .map(|value| value.as_f64().map(|value| value as f32))
+            .collect::<Option<Vec<_>>>()
+            .with_context(|| format!("Qdrant point {point_id} vector was not numeric"))?;
+        Ok(Some(vector))
+    }
+
+    /// Merge `payload` into the payloads of `point_ids`.
+    pub async fn set_payload(
+        &self,
+        collection: &str,
+        point_ids: &[String],
+        payload: Value,
+    ) -> Result<()> {
+        self.post_points(
+            collection,
+            "points/payload",
+            json!({ "payload": payload, "points": point_ids }),
+            "setting payload",
+        )
+        .await
+    }
+
+    /// Remove `keys` from the payloads of `point_ids`.
+    pub async fn delete_payload_keys(
+        &self,
+        collection: &str,
+        point_ids: &[String],
+        keys: &[&str],
+    ) -> Result<()> {
+        self.post_points(
+            collection,
+            "points/payload/delete",
+            json!({ "keys": keys, "points": point_ids }),
+            "deleting payload keys",
+        )
+        .await
+    }
+
+    /// Delete `point_ids` and their payloads.
+    pub async fn delete_points(&self, collection: &str, point_ids: &[String]) -> Result<()> {
+        self.post_points(
+            collection,
+            "points/delete",
+            json!({ "points": point_ids }),
+            "deleting points",
+        )
+        .await
+    }
+
+    async fn post_points(
+        &self,
+        collection: &str,
+        path: &str,
+        body: Value,
+        action: &str,
+    ) -> Result<()> {
+        if body
+            .get("points")
+            .and_then(Value::as_array)
+            .is_none_or(Vec::is_empty)
+        {
+            return Ok(());
+        }
+        let response = reqwest::Client::new()
+            .post(self.endpoint(&format!("collections/{collection}/{path}?wait=true"))?)
+            .json(&body)
+            .timeout(QDRANT_REQUEST_TIMEOUT)
+            .send()
+            .await
+            .with_context(|| format!("failed while {action} in Qdrant collection {collection}"))?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(unexpected_qdrant_response(
+                response,
+                &format!("{action} in collection {collection}"),
+            )
+            .await)
+        }
+    }
+
     /// Store a geolocation embedding in the geolocation collection.
     pub async fn store_geolocation_vector_for(
         &self,
@@ -1138,6 +1262,10 @@ pub struct Neo4jClient {
     pub user: String,
     pub pass: String,
     constraint_ensured: Arc<AtomicBool>,
+    /// Store for media payloads; without one they stay in node properties.
+    blobs: Option<Arc<dyn BlobStore>>,
+    /// Claims a work item may have before it is marked failed.
+    max_lease_attempts: u64,
 }
 
 impl Default for Neo4jClient {
@@ -1147,10 +1275,128 @@ impl Default for Neo4jClient {
             user: "neo4j".into(),
             pass: "password".into(),
             constraint_ensured: Arc::new(AtomicBool::new(false)),
+            blobs: None,
+            max_lease_attempts: WorkLease::DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+}
+
+/// Time-limited claim by one worker replica on a graph node.
+///
+/// Leases live on `WorkLease` nodes linked to the claimed node with `LEASES`.
+/// Expiry is measured on the Neo4j server clock so replicas on different hosts
+/// agree on when an abandoned lease can be reclaimed. A released lease's node
+/// is deleted; an abandoned one stays to count attempts, and once a node has
+/// used up its attempts the lease is marked failed and the work is skipped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkLease {
+    /// Stable id of the `WorkLease` node, derived from `kind` and `node_id`.
+    pub id: String,
+    /// Kind of work being leased, e.g. [`WorkLease::TRANSCRIPTION`].
+    pub kind: String,
+    /// Id of the claimed graph node.
+    pub node_id: String,
+    /// Worker replica holding the lease.
+    pub owner: String,
+    /// Token identifying this particular claim.
+    pub token: String,
+    /// Server time in epoch milliseconds when the lease lapses.
+    pub expires_at_ms: i64,
+    /// Number of times the node has been claimed for this kind of work.
+    pub attempts: u64,
+}
+
+impl WorkLease {
+    /// Claims a work item gets by default before it is marked failed.
+    pub const DEFAULT_MAX_ATTEMPTS: u64 = 3;
+    /// Lease kind used by the Whisper transcription worker.
+    pub const TRANSCRIPTION: &'static str = "transcription";
+    /// Lease kind used by the face-recognition worker.
+    pub const FACE_RECOGNITION: &'static str = "face_recognition";
+    /// Lease kind used by the object-detection worker.
+    pub const OBJECT_DETECTION: &'static str = "object_detection";
+    /// Lease kind used by the text-recognition worker.
+    pub const TEXT_RECOGNITION: &'static str = "text_recognition";
+    /// Prefix of the lease kinds held by the face-recognition replica that
+    /// follows faces across the frames of one source; see
+    /// [`face_tracking`](Self::face_tracking).
+    pub const FACE_TRACKING: &'static str = "face_tracking";
+
+    /// Lease kind for following faces across the frames of `source`.
+    pub fn face_tracking(source: &str) -> String {
+        format!("{}:{source}", Self::FACE_TRACKING)
+    }
+
+    /// Return the `WorkLease` node id for `kind` work on `node_id`.
+    pub fn lease_id(kind: &str, node_id: &str) -> String {
+        format!("lease:{kind}:{node_id}")
+    }
+
+    /// Default owner id for this process, from `WORKER_ID` or the host name
+    /// and process id.
+    pub fn default_owner() -> String {
+        std::env::var("WORKER_ID").unwrap_or_else(|_| {
+            let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "worker".into());
+            format!("{host}:{}", std::process::id())
+        })
+    }
+}
+
+/// Lease owner and duration a worker uses when claiming graph nodes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeaseSettings {
+    /// Worker replica claiming the leases.
+    pub owner: String,
+    /// How long a lease lasts without a heartbeat.
+    pub ttl: Duration,
+}
+
+impl LeaseSettings {
+    /// Settings for `owner`, falling back to [`WorkLease::default_owner`],
+    /// with leases of `ttl_ms` but at least one second.
+    pub fn new(owner: Option<String>, ttl_ms: u64) -> Self {
+        Self {
+            owner: owner.unwrap_or_else(WorkLease::default_owner),
+            ttl: Duration::from_millis(ttl_ms.max(1000)),
         }
     }
 }
 
+/// Run `work` while holding the `kind` lease on `node_id`.
+///
+/// Returns `Ok(None)` without running `work` when another replica holds the
+/// lease or the node's work has failed. Otherwise the lease is renewed until
+/// `work` finishes, then released if it succeeded or abandoned for a retry if
+/// it failed, and `work`'s result is returned.
+pub async fn with_work_lease<T>(
+    graph: &Neo4jClient,
+    kind: &str,
+    node_id: &str,
+    settings: &LeaseSettings,
+    work: impl Future<Output = Result<T>>,
+) -> Result<Option<T>> {
+    let Some(lease) = graph
+        .claim_lease(kind, node_id, &settings.owner, settings.ttl)
+        .await
+        .with_context(|| format!("failed to lease {kind} work on {node_id}"))?
+    else {
+        trace!(kind, node_id, "node is leased by another worker");
+        return Ok(None);
+    };
+    let heartbeat = graph.spawn_lease_heartbeat(lease.clone(), settings.ttl);
+    let result = work.await;
+    heartbeat.abort();
+    let released = if result.is_ok() {
+        graph.release_lease(&lease).await
+    } else {
+        graph.abandon_lease(&lease).await
+    };
+    if let Err(err) = released {
+        warn!(lease_id = %lease.id, error = %err, "failed to release work lease");
+    }
+    result.map(Some)
+}
+
 /// Audio clip loaded directly from the graph store.
 #[derive(Clone, Debug)]
 pub struct GraphAudioClip {
@@ -1164,6 +1410,14 @@ pub struct GraphAudioClip {
     pub sensation_id: Option<String>,
 }
 
+/// Media payload still stored inline on an `Image` or `AudioClip` node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphMediaPayload {
+    pub id: String,
+    pub mime: String,
+    pub base64: String,
+}
+
 /// Ordered audio clips selected for aggregate transcription.
 #[derive(Clone, Debug)]
 pub struct GraphAudioClipWindow {
@@ -1203,6 +1457,113 @@ pub struct GraphConsolidatedSpeechCandidate {
     pub sources: Vec<GraphConsolidatedSpeechSource>,
 }
 
+/// Source clip placed on the timeline of a big transcription being diarized.
+#[derive(Clone, Debug)]
+pub struct GraphDiarizationSource {
+    /// Source order within the aggregate transcription.
+    pub index: usize,
+    /// Source clip and graph metadata.
+    pub clip: GraphAudioClip,
+    /// Start offset from the beginning of the aggregate audio.
+    pub start_ms: u32,
+    /// End offset from the beginning of the aggregate audio.
+    pub end_ms: u32,
+}
+
+/// Existing `SpeechSegment` of a big transcription awaiting a speaker.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphDiarizationSegment {
+    /// Stable graph node id for the speech segment.
+    pub id: String,
+    /// Zero-based segment order in the transcription.
+    pub index: usize,
+    /// Segment text.
+    pub text: String,
+    /// Start offset from the beginning of the aggregate audio.
+    pub start_ms: u32,
+    /// End offset from the beginning of the aggregate audio.
+    pub end_ms: u32,
+    /// Absolute segment start timestamp, when known.
+    pub occurred_at: Option<String>,
+    /// Absolute segment end timestamp, when known.
+    pub ended_at: Option<String>,
+}
+
+/// Big transcription whose speakers have not been separated yet.
+#[derive(Clone, Debug)]
+pub struct GraphDiarizationCandidate {
+    /// Existing big transcription node id.
+    pub transcription_id: String,
+    /// Source clips in playback order.
+    pub sources: Vec<GraphDiarizationSource>,
+    /// Speech segments in transcript order.
+    pub segments: Vec<GraphDiarizationSegment>,
+}
+
+/// One voice found while diarizing a transcription.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphDiarizedSpeaker {
+    /// Speaker index referenced by attributions and turns.
+    pub index: usize,
+    /// Known voice this speaker matched, when any.
+    pub voice: Option<GraphVoiceIdentity>,
+    /// Similarity to the matched voice.
+    pub score: Option<f32>,
+    /// Embedding windows assigned to this speaker.
+    pub window_count: usize,
+}
+
+impl GraphDiarizedSpeaker {
+    /// Identity of the matched voice, or a label unique within the transcription.
+    pub fn name(&self) -> String {
+        self.voice
+            .as_ref()
+            .and_then(|voice| voice.identity.clone())
+            .unwrap_or_else(|| format!("speaker {}", self.index + 1))
+    }
+}
+
+/// Speaker attributed to one `SpeechSegment`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphSpeakerAttribution {
+    /// Stable graph node id for the speech segment.
+    pub segment_id: String,
+    /// Index of the attributed [`GraphDiarizedSpeaker`].
+    pub speaker: usize,
+    /// Share of the segment covered by that speaker's turns.
+    pub confidence: f32,
+}
+
+/// Consecutive speech segments spoken by the same speaker.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphSpeakerTurn {
+    /// Index of the speaking [`GraphDiarizedSpeaker`].
+    pub speaker: usize,
+    /// Joined segment text.
+    pub text: String,
+    /// Start offset from the beginning of the aggregate audio.
+    pub start_ms: u32,
+    /// End offset from the beginning of the aggregate audio.
+    pub end_ms: u32,
+    /// Absolute turn start timestamp, when known.
+    pub occurred_at: Option<String>,
+    /// Absolute turn end timestamp, when known.
+    pub ended_at: Option<String>,
+    /// Segments making up the turn, in order.
+    pub segment_ids: Vec<String>,
+}
+
+/// Speakers and per-segment attributions for one big transcription.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GraphDiarization {
+    /// Distinct voices heard in the transcription.
+    pub speakers: Vec<GraphDiarizedSpeaker>,
+    /// Speaker of each attributed segment.
+    pub attributions: Vec<GraphSpeakerAttribution>,
+    /// Segments grouped into consecutive turns.
+    pub turns: Vec<GraphSpeakerTurn>,
+}
+
 /// Result of consolidating a big transcription into one audio clip.
 #[derive(Clone, Debug, PartialEq)]
 pub struct GraphSpeechConsolidationReport {
@@ -1242,6 +1603,25 @@ pub struct GraphImageFrame {
     pub sensation_id: Option<String>,
 }
 
+impl GraphImageFrame {
+    /// Source assumed for frames that do not name their camera or stream.
+    pub const DEFAULT_SOURCE: &'static str = "camera";
+
+    /// Camera or stream that produced the frame.
+    pub fn source(&self) -> &str {
+        self.image.source.as_deref().unwrap_or(Self::DEFAULT_SOURCE)
+    }
+
+    /// When the frame was captured, falling back to when it was observed.
+    pub fn observed_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::image_captured_at(&self.image).or_else(|| {
+            self.occurred_at
+                .as_deref()
+                .and_then(crate::parse_observed_at)
+        })
+    }
+}
+
 /// Image frame selected for a movie export.
 #[derive(Clone, Debug)]
 pub struct GraphMovieImageFrame {
@@ -1296,6 +1676,22 @@ pub struct GraphFaceDetection {
     pub embedding_len: usize,
     /// Existing face cluster matched through vector similarity, when any.
     pub recognition: Option<GraphFaceMatch>,
+    /// Location, landmarks and quality reported by the detector.
+    pub details: FaceDetails,
+    /// Cross-frame track the face belongs to, when tracking.
+    pub track: Option<GraphFaceTrack>,
+}
+
+/// Cross-frame track a detected face was assigned to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphFaceTrack {
+    /// Stable graph node id for the `FaceTrack`.
+    pub track_id: String,
+    /// Whether the track starts with this face.
+    pub started: bool,
+    /// Whether the identity was matched for this face rather than carried
+    /// over from the track.
+    pub matched: bool,
 }
 
 /// Existing face cluster matched by a detected face vector.
@@ -1316,8 +1712,11 @@ pub struct GraphFaceMatch {
 pub struct GraphFaceIdentity {
     /// Stable graph node id for the matched `Face` cluster.
     pub face_id: String,
-    /// Human identity attached to the face cluster, when known.
+    /// Human identity attached to the face cluster, or to a voice of the
+    /// same person, when known.
     pub identity: Option<String>,
+    /// Person the face cluster belongs to, when linked.
+    pub person_id: Option<String>,
 }
 
 /// A recent face-like graph node that can receive a manually assigned identity.
@@ -1334,10 +1733,48 @@ pub struct GraphFaceIdentityTarget {
     pub source_image_id: Option<String>,
     /// Qdrant vector node connected to the face instance, when known.
     pub vector_id: Option<String>,
-    /// Existing identity already attached to the target, when any.
+    /// Existing identity already attached to the target, or to its person,
+    /// when any.
     pub identity: Option<String>,
     /// When this face was observed or processed.
     pub occurred_at: String,
+    /// Person the target belongs to, when linked.
+    pub person_id: Option<String>,
+    /// How squarely the face looked at the camera, when landmarks were known.
+    pub pose: Option<f32>,
+}
+
+/// Detected object ready to be linked to its object-detection run.
+#[derive(Clone, Debug)]
+pub struct GraphObjectDetection {
+    /// Zero-based detection order within the source frame.
+    pub index: usize,
+    /// Graph id of the `ObjectObservation` node.
+    pub object_id: String,
+    /// Graph id of the object `Sensation` that observed it.
+    pub sensation_id: String,
+    /// Detected class name, when known.
+    pub label: Option<String>,
+    /// Detector confidence in `0.0..=1.0`.
+    pub confidence: Option<f32>,
+}
+
+/// Text read in a frame, ready to be linked to its text-recognition run.
+#[derive(Clone, Debug)]
+pub struct GraphTextReading {
+    /// Zero-based reading order within the source frame.
+    pub index: usize,
+    /// Graph id of the `TextBlock` node.
+    pub text_id: String,
+    /// The text as read in this frame.
+    pub text: String,
+    /// Graph id of the text `Sensation`, or `None` when the text was read
+    /// recently enough not to be sensed again.
+    pub sensation_id: Option<String>,
+    /// Recognizer confidence in `0.0..=1.0`.
+    pub confidence: Option<f32>,
+    /// Where the text is in the frame.
+    pub bbox: Option<BoundingBox>,
 }
 
 /// Scene-level image vector ready to be linked into the graph.
@@ -1349,6 +1786,83 @@ pub struct GraphSceneVectorization {
     pub embedding_len: usize,
 }
 
+/// Scene-change state of an `Image` graph node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphSceneFrame {
+    /// Stable graph node id for the `Image`.
+    pub image_id: String,
+    /// Stored fingerprint, once the frame has been compared with its keyframe.
+    pub fingerprint: Option<SceneFingerprint>,
+    /// Keyframe the frame duplicates, when it does.
+    pub duplicate_of: Option<String>,
+    /// Qdrant point id of the frame's scene vector, when vectorized.
+    pub scene_vector_id: Option<String>,
+    /// Capture or observation timestamp.
+    pub observed_at: Option<String>,
+}
+
+/// Frame found to repeat an earlier keyframe.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphSceneDuplicate {
+    /// `Image` node id of the keyframe.
+    pub keyframe_id: String,
+    /// Differing fingerprint hash bits.
+    pub hash_distance: u32,
+    /// Mean thumbnail difference.
+    pub difference: f32,
+    /// Scene-vector similarity, when both frames had one.
+    pub scene_similarity: Option<f32>,
+}
+
+/// Per-image vision run that a duplicate frame can reuse from its keyframe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageRunKind {
+    Description,
+    SceneVectorization,
+    FaceRecognition,
+    TextRecognition,
+}
+
+impl ImageRunKind {
+    fn run_label(self) -> &'static str {
+        match self {
+            Self::Description => "ImageDescriptionRun",
+            Self::SceneVectorization => "SceneVectorizationRun",
+            Self::FaceRecognition => "FaceRecognitionRun",
+            Self::TextRecognition => "TextRecognitionRun",
+        }
+    }
+
+    fn run_relationship(self) -> &'static str {
+        match self {
+            Self::Description => "HAS_IMAGE_DESCRIPTION_RUN",
+            Self::SceneVectorization => "HAS_SCENE_VECTORIZATION_RUN",
+            Self::FaceRecognition => "HAS_FACE_RECOGNITION_RUN",
+            Self::TextRecognition => "HAS_TEXT_RECOGNITION_RUN",
+        }
+    }
+
+    fn run_id(self, image_id: &str) -> String {
+        match self {
+            Self::Description => format!("image-description:{image_id}"),
+            Self::SceneVectorization => format!("scene-vectorization:{image_id}"),
+            Self::FaceRecognition => format!("face-recognition:{image_id}"),
+            Self::TextRecognition => format!("text-recognition:{image_id}"),
+        }
+    }
+
+    /// Relationships from the source image to run results that a duplicate
+    /// shares. Faces are instances of their own frame and are not shared.
+    fn shared_results(self) -> &'static [&'static str] {
+        match self {
+            Self::Description => &["HAS_IMAGE_DESCRIPTION", "HAS_IMAGE_DESCRIPTION_VECTOR"],
+            Self::SceneVectorization => &["HAS_SCENE_VECTOR"],
+            Self::FaceRecognition => &[],
+            Self::TextRecognition => &["CONTAINS_TEXT"],
+        }
+    }
+}
+
 /// Voice signature extracted from an audio clip.
 #[derive(Clone, Debug)]
 pub struct GraphVoiceSignature {
@@ -1413,8 +1927,11 @@ pub struct GraphVoiceMatch {
 pub struct GraphVoiceIdentity {
     /// Stable graph node id for the matched `Voice` cluster.
     pub voice_id: String,
-    /// Human identity attached to the voice cluster, when known.
+    /// Human identity attached to the voice cluster, or to a face of the
+    /// same person, when known.
     pub identity: Option<String>,
+    /// Person the voice cluster belongs to, when linked.
+    pub person_id: Option<String>,
 }
 
 /// A recent voice-like graph node that can receive a manually assigned identity.
@@ -1431,10 +1948,13 @@ pub struct GraphVoiceIdentityTarget {
     pub audio_clip_id: Option<String>,
     /// Qdrant vector node connected to the voice signature, when known.
     pub vector_id: Option<String>,
-    /// Existing identity already attached to the target, when any.
+    /// Existing identity already attached to the target, or to its person,
+    /// when any.
     pub identity: Option<String>,
     /// When this voice was observed or processed.
     pub occurred_at: String,
+    /// Person the target belongs to, when linked.
+    pub person_id: Option<String>,
 }
 
 /// Speech segment produced by transcribing an `AudioClip`.
@@ -1711,6 +2231,147 @@ pub struct GraphVoiceIdentityLabel {
     pub name: String,
 }
 
+/// Person identity attached to faces or voices, with its evidence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphIdentity {
+    /// Stable graph node id for the identity.
+    pub identity_id: String,
+    pub name: String,
+    /// Face instances linked directly, through a matched face, or through a
+    /// face cluster.
+    pub face_samples: u32,
+    /// Voice signatures linked directly, through a matched voice, or through
+    /// a voice cluster.
+    pub voice_samples: u32,
+    /// Face and voice clusters the identity is attached to.
+    pub clusters: Vec<String>,
+    /// Latest time any sample was observed.
+    pub last_seen_at: Option<String>,
+    /// Whether the person asked not to be remembered.
+    pub opted_out: bool,
+}
+
+/// Qdrant point backing one sample of an identity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphIdentityVector {
+    pub collection: String,
+    pub point_id: String,
+}
+
+/// Where a face of an identity appears in a stored frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphFaceRegion {
+    pub image_id: String,
+    pub bbox: BoundingBox,
+}
+
+/// Face or object located in a stored frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphImageRegion {
+    /// Graph id of the `FaceInstance` or `ObjectObservation`.
+    pub node_id: String,
+    /// `"face"` or `"object"`.
+    pub kind: String,
+    /// Identity name of a face or class of an object, when known.
+    pub name: Option<String>,
+    /// `FaceTrack` a face belongs to, when tracked.
+    pub track_id: Option<String>,
+    pub bbox: BoundingBox,
+}
+
+/// Answer to a question the Will asked about part of a frame.
+#[derive(Clone, Debug)]
+pub struct GraphLookAnswer {
+    /// How the region reads in the sensation, e.g. a name.
+    pub region: String,
+    /// Where the region is in the frame.
+    pub bbox: BoundingBox,
+    /// `FaceInstance` or `ObjectObservation` the region was named by.
+    pub region_node_id: Option<String>,
+    pub question: String,
+    pub answer: String,
+    /// Vision model that answered.
+    pub model: String,
+}
+
+/// Everything stored about an opted-out identity that a purge deletes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GraphIdentityPurge {
+    pub identity_id: String,
+    /// `FaceInstance` nodes and their crops.
+    pub face_ids: Vec<String>,
+    /// `VoiceSignature` and `VoiceSample` nodes.
+    pub voice_ids: Vec<String>,
+    /// `AudioClip` nodes the voices were recognised in.
+    pub audio_clip_ids: Vec<String>,
+    /// Sensations that observed any of the above.
+    pub sensation_ids: Vec<String>,
+    /// Qdrant points behind the faces and voices, with their `Vector` nodes.
+    pub vectors: Vec<GraphIdentityVector>,
+    /// Frames the faces were cropped from, to be blurred rather than deleted.
+    pub face_regions: Vec<GraphFaceRegion>,
+}
+
+impl GraphIdentityPurge {
+    /// Graph ids of every node the purge deletes.
+    pub fn node_ids(&self) -> Vec<String> {
+        self.face_ids
+            .iter()
+            .chain(&self.voice_ids)
+            .chain(&self.audio_clip_ids)
+            .chain(&self.sensation_ids)
+            .cloned()
+            .chain(
+                self.vectors
+                    .iter()
+                    .map(|vector| qdrant_vector_node_id(&vector.collection, &vector.point_id)),
+            )
+            .collect()
+    }
+}
+
+/// A face cluster and a voice cluster recognised close together in time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphPersonCandidate {
+    pub face_id: String,
+    pub voice_id: String,
+    /// Number of distinct time windows in which both were recognised.
+    pub windows: u32,
+}
+
+/// A face or voice cluster recognised at one moment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphPersonRecognition {
+    pub cluster_id: String,
+    /// Whether the cluster is a voice rather than a face.
+    pub voice: bool,
+    /// When the recognised sensation occurred, in Unix milliseconds.
+    pub at_ms: i64,
+}
+
+/// New time windows in which a face and a voice were recognised together.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphPersonCoOccurrence {
+    pub face_id: String,
+    pub voice_id: String,
+    /// Indexes of the windows, in ascending order.
+    pub windows: Vec<i64>,
+}
+
+/// Person whose face and voice clusters Pete has linked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphPerson {
+    /// Stable graph node id for the person.
+    pub person_id: String,
+    /// Name of the first named face or voice identity of the person.
+    pub name: Option<String>,
+    pub face_ids: Vec<String>,
+    pub voice_ids: Vec<String>,
+    /// Number the person goes by while unnamed, assigned once when first
+    /// linked.
+    pub ordinal: Option<u32>,
+}
+
 impl Neo4jClient {
     pub fn new(uri: String, user: String, pass: String) -> Self {
         Self {
@@ -1718,21 +2379,172 @@ impl Neo4jClient {
             user,
             pass,
             constraint_ensured: Arc::new(AtomicBool::new(false)),
+            blobs: None,
+            max_lease_attempts: WorkLease::DEFAULT_MAX_ATTEMPTS,
         }
     }
 
-    /// Count graph nodes that are not raw sensations, audio clips, or image frames.
-    pub async fn count_non_raw_graph_nodes(&self) -> Result<u64> {
-        let endpoint = self.http_endpoint()?;
-        let rows = query_neo4j_rows(
-            &reqwest::Client::new(),
-            &endpoint,
-            &self.user,
-            &self.pass,
-            CypherStatement {
-                statement: raw_retention_match("RETURN count(n)").into(),
-                parameters: json!({}),
-            },
+    /// Keep `Image` and `AudioClip` payloads in `blobs`, leaving only their
+    /// `blob_hash`, `blob_size` and `mime` on the nodes.
+    pub fn with_blob_store(mut self, blobs: Arc<dyn BlobStore>) -> Self {
+        self.blobs = Some(blobs);
+        self
+    }
+
+    /// Mark work items failed after `attempts` claims instead of
+    /// [`WorkLease::DEFAULT_MAX_ATTEMPTS`].
+    pub fn with_max_lease_attempts(mut self, attempts: u64) -> Self {
+        self.max_lease_attempts = attempts.max(1);
+        self
+    }
+
+    /// Move the base64 payloads of media nodes in a `merge_graph` record into
+    /// the blob store.
+    async fn offload_payloads<'a>(&self, data: &'a Value) -> Result<Cow<'a, Value>> {
+        let Some(blobs) = &self.blobs else {
+            return Ok(Cow::Borrowed(data));
+        };
+        let has_payload = |node: &Value| {
+            node.get("base64").is_some_and(Value::is_string)
+                && node_labels(node).is_ok_and(|labels| {
+                    labels
+                        .iter()
+                        .any(|label| BLOB_PAYLOAD_LABELS.contains(&label.as_str()))
+                })
+        };
+        let nodes = data.get("nodes").and_then(Value::as_array);
+        if data.get("op").and_then(Value::as_str) != Some("merge_graph")
+            || !nodes.is_some_and(|nodes| nodes.iter().any(has_payload))
+        {
+            return Ok(Cow::Borrowed(data));
+        }
+        let mut data = data.clone();
+        for node in data
+            .get_mut("nodes")
+            .and_then(Value::as_array_mut)
+            .into_iter()
+            .flatten()
+        {
+            if !has_payload(node) {
+                continue;
+            }
+            let Some(Value::String(base64)) = node
+                .as_object_mut()
+                .and_then(|props| props.remove("base64"))
+            else {
+                continue;
+            };
+            let bytes = BASE64_STANDARD
+                .decode(base64.trim().as_bytes())
+                .context("failed to decode media payload")?;
+            let hash = blobs.insert(&bytes).await?;
+            node["blob_hash"] = json!(hash);
+            node["blob_size"] = json!(bytes.len());
+        }
+        Ok(Cow::Owned(data))
+    }
+
+    /// Payload properties of a media node, keeping the bytes in the blob store
+    /// when one is set.
+    async fn payload_properties(&self, mime: &str, base64: &str) -> Result<Value> {
+        let Some(blobs) = &self.blobs else {
+            return Ok(json!({ "mime": mime, "base64": base64 }));
+        };
+        let bytes = BASE64_STANDARD
+            .decode(base64.trim().as_bytes())
+            .context("failed to decode media payload")?;
+        let hash = blobs.insert(&bytes).await?;
+        Ok(json!({
+            "mime": mime,
+            "base64": null,
+            "blob_hash": hash,
+            "blob_size": bytes.len(),
+        }))
+    }
+
+    /// Replace a payload read back as a blob reference with its contents.
+    async fn load_payload(&self, base64: &mut String) -> Result<()> {
+        let Some(hash) = base64.strip_prefix(BLOB_PAYLOAD_PREFIX) else {
+            return Ok(());
+        };
+        let blobs = self
+            .blobs
+            .as_ref()
+            .with_context(|| format!("payload is in blob {hash} but no blob store is set"))?;
+        let bytes = blobs
+            .get(hash)
+            .await?
+            .with_context(|| format!("blob {hash} not found"))?;
+        *base64 = BASE64_STANDARD.encode(bytes);
+        Ok(())
+    }
+
+    /// Delete the blobs among `hashes` that no graph node refers to any more,
+    /// returning how many were deleted. The store keeps blobs written too
+    /// recently for their referring node to have been committed yet.
+    async fn release_blobs(&self, hashes: Vec<String>) -> Result<usize> {
+        let Some(blobs) = &self.blobs else {
+            return Ok(0);
+        };
+        let mut hashes = hashes;
+        hashes.sort();
+        hashes.dedup();
+        if hashes.is_empty() {
+            return Ok(0);
+        }
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    OPTIONAL MATCH (n:GraphNode)
+                    WHERE n.blob_hash IN $hashes
+                    RETURN collect(DISTINCT n.blob_hash)
+                "#
+                .into(),
+                parameters: json!({ "hashes": hashes }),
+            },
+            "finding referenced blobs",
+        )
+        .await?;
+        let referenced = rows
+            .first()
+            .and_then(Value::as_array)
+            .map(|values| row_string_vec(values, 0))
+            .unwrap_or_default();
+        let mut deleted = 0;
+        for hash in hashes.iter().filter(|hash| !referenced.contains(hash)) {
+            if blobs.collect(hash).await? {
+                deleted += 1;
+            }
+        }
+        Ok(deleted)
+    }
+
+    /// Parse the first row as an image frame, loading its payload.
+    async fn first_image_frame(&self, rows: &[Value]) -> Result<Option<GraphImageFrame>> {
+        let Some(mut frame) = rows.first().map(graph_image_frame_from_row).transpose()? else {
+            return Ok(None);
+        };
+        self.load_payload(&mut frame.image.base64).await?;
+        Ok(Some(frame))
+    }
+
+    /// Count graph nodes that are not raw sensations, audio clips, or image frames.
+    pub async fn count_non_raw_graph_nodes(&self) -> Result<u64> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: raw_retention_match("RETURN count(n)").into(),
+                parameters: json!({}),
+            },
             "counting non-raw graph nodes",
         )
         .await?;
@@ -1845,6 +2657,125 @@ impl Neo4jClient {
             .context("Neo4j cleared audio transcript property count was missing")
     }
 
+    /// Count `Image` and `AudioClip` nodes whose payload is still inline.
+    pub async fn count_inline_media_payloads(&self) -> Result<u64> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (n:GraphNode)
+                    WHERE (n:Image OR n:AudioClip) AND n.base64 IS NOT NULL
+                    RETURN count(n)
+                "#
+                .into(),
+                parameters: json!({}),
+            },
+            "counting inline media payloads",
+        )
+        .await?;
+        rows.first()
+            .and_then(Value::as_array)
+            .and_then(|values| values.first())
+            .and_then(Value::as_u64)
+            .context("Neo4j inline media payload count was missing")
+    }
+
+    /// Return up to `limit` `Image` and `AudioClip` nodes whose payload is
+    /// still inline.
+    pub async fn inline_media_payloads(&self, limit: usize) -> Result<Vec<GraphMediaPayload>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (n:GraphNode)
+                    WHERE (n:Image OR n:AudioClip) AND n.base64 IS NOT NULL
+                    RETURN n.id, coalesce(n.mime, ""), n.base64
+                    ORDER BY n.id
+                    LIMIT $limit
+                "#
+                .into(),
+                parameters: json!({
+                    "limit": i64::try_from(limit.max(1)).unwrap_or(i64::MAX),
+                }),
+            },
+            "finding inline media payloads",
+        )
+        .await?;
+        rows.iter()
+            .map(|row| {
+                let values = row
+                    .as_array()
+                    .context("Neo4j media payload row was not an array")?;
+                Ok(GraphMediaPayload {
+                    id: row_string(values, 0, "id")?,
+                    mime: row_string(values, 1, "mime")?,
+                    base64: row_string(values, 2, "base64")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Move inline `payloads` into the blob store, leaving their nodes with
+    /// `blob_hash` and `blob_size`. Returns how many nodes were updated.
+    pub async fn move_media_payloads_to_blobs(
+        &self,
+        payloads: &[GraphMediaPayload],
+    ) -> Result<u64> {
+        let blobs = self
+            .blobs
+            .as_ref()
+            .context("moving media payloads requires a blob store")?;
+        let mut moved = Vec::with_capacity(payloads.len());
+        for payload in payloads {
+            let bytes = BASE64_STANDARD
+                .decode(payload.base64.trim().as_bytes())
+                .with_context(|| format!("failed to decode payload of {}", payload.id))?;
+            let hash = blobs
+                .insert(&bytes)
+                .await
+                .with_context(|| format!("failed to store payload of {}", payload.id))?;
+            moved.push(json!({ "id": payload.id, "hash": hash, "size": bytes.len() }));
+        }
+        if moved.is_empty() {
+            return Ok(0);
+        }
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    UNWIND $moved AS blob
+                    MATCH (n:GraphNode {id: blob.id})
+                    WHERE n.base64 IS NOT NULL
+                    SET n.blob_hash = blob.hash,
+                        n.blob_size = blob.size
+                    REMOVE n.base64
+                    RETURN count(n)
+                "#
+                .into(),
+                parameters: json!({ "moved": moved }),
+            },
+            "moving media payloads to blobs",
+        )
+        .await?;
+        rows.first()
+            .and_then(Value::as_array)
+            .and_then(|values| values.first())
+            .and_then(Value::as_u64)
+            .context("Neo4j moved media payload count was missing")
+    }
+
     /// Return the latest `AudioClip` graph node that has no transcript property.
     pub async fn latest_untranscribed_audio_clip(&self) -> Result<Option<GraphAudioClip>> {
         let endpoint = self.http_endpoint()?;
@@ -1856,12 +2787,17 @@ impl Neo4jClient {
             CypherStatement {
                 statement: r#"
                     MATCH (a:GraphNode:AudioClip)
-                    WHERE a.base64 IS NOT NULL
+                    WHERE (a.base64 IS NOT NULL OR a.blob_hash IS NOT NULL)
                       AND a.transcript IS NULL
                       AND NOT (a)-[:HAS_TRANSCRIPTION]->(:GraphNode:Transcription)
+                      AND NOT EXISTS {
+                          MATCH (lease:WorkLease {kind: "transcription"})-[:LEASES]->(a)
+                          WHERE lease.failed_at_ms IS NOT NULL
+                             OR (lease.owner IS NOT NULL AND lease.expires_at_ms > timestamp())
+                      }
                     OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(a)
                     WITH a, s, coalesce(a.captured_at, a.occurred_at, s.occurred_at, "") AS observed_at
-                    RETURN a.id, a.mime, a.base64, a.sample_rate, a.channels, a.captured_at, a.occurred_at, s.id
+                    RETURN a.id, a.mime, coalesce(a.base64, 'blob:' + a.blob_hash), a.sample_rate, a.channels, a.captured_at, a.occurred_at, s.id
                     ORDER BY observed_at DESC
                     LIMIT 1
                 "#
@@ -1871,7 +2807,11 @@ impl Neo4jClient {
             "finding latest untranscribed audio clip",
         )
         .await?;
-        rows.first().map(graph_audio_clip_from_row).transpose()
+        let Some(mut clip) = rows.first().map(graph_audio_clip_from_row).transpose()? else {
+            return Ok(None);
+        };
+        self.load_payload(&mut clip.clip.base64).await?;
+        Ok(Some(clip))
     }
 
     /// Return recent `AudioClip` nodes for aggregate transcription.
@@ -1894,13 +2834,13 @@ impl Neo4jClient {
             CypherStatement {
                 statement: r#"
                     MATCH (anchor:GraphNode:AudioClip)
-                    WHERE anchor.base64 IS NOT NULL
+                    WHERE (anchor.base64 IS NOT NULL OR anchor.blob_hash IS NOT NULL)
                       AND NOT (anchor)-[:HAS_BIG_TRANSCRIPTION]->(:GraphNode:Transcription)
                     WITH anchor, coalesce(anchor.captured_at, anchor.occurred_at, "") AS anchor_observed_at
                     ORDER BY anchor_observed_at DESC
                     LIMIT 1
                     MATCH (a:GraphNode:AudioClip)
-                    WHERE a.base64 IS NOT NULL
+                    WHERE (a.base64 IS NOT NULL OR a.blob_hash IS NOT NULL)
                     OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(a)
                     WITH anchor, anchor_observed_at, a, s, coalesce(a.captured_at, a.occurred_at, s.occurred_at, "") AS observed_at
                     WHERE observed_at <= anchor_observed_at
@@ -1910,7 +2850,7 @@ impl Neo4jClient {
                     WITH anchor, collect({
                         id: a.id,
                         mime: a.mime,
-                        base64: a.base64,
+                        base64: coalesce(a.base64, 'blob:' + a.blob_hash),
                         sample_rate: a.sample_rate,
                         channels: a.channels,
                         captured_at: a.captured_at,
@@ -1929,7 +2869,13 @@ impl Neo4jClient {
             "finding latest audio clip window for big transcription",
         )
         .await?;
-        graph_audio_clip_window_from_rows(&rows)
+        let Some(mut window) = graph_audio_clip_window_from_rows(&rows)? else {
+            return Ok(None);
+        };
+        for clip in &mut window.clips {
+            self.load_payload(&mut clip.clip.base64).await?;
+        }
+        Ok(Some(window))
     }
 
     /// Return the latest big transcription whose source audio has not been consolidated.
@@ -1950,7 +2896,7 @@ impl Neo4jClient {
                       AND NOT (t)-[:HAS_CONSOLIDATED_AUDIO]->(:GraphNode:AudioClip)
                       AND toInteger(coalesce(t.source_count, 0)) >= $min_source_count
                     MATCH (a:GraphNode:AudioClip)-[source:HAS_BIG_TRANSCRIPTION]->(t)
-                    WHERE a.base64 IS NOT NULL
+                    WHERE (a.base64 IS NOT NULL OR a.blob_hash IS NOT NULL)
                     OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(a)
                     OPTIONAL MATCH (a)-[:HAS_TRANSCRIPTION]->(old:GraphNode:Transcription)
                     WITH t, a, source, s, collect(DISTINCT old.id) AS old_transcription_ids,
@@ -1960,7 +2906,7 @@ impl Neo4jClient {
                         index: toInteger(coalesce(source.source_index, 0)),
                         id: a.id,
                         mime: a.mime,
-                        base64: a.base64,
+                        base64: coalesce(a.base64, 'blob:' + a.blob_hash),
                         sample_rate: a.sample_rate,
                         channels: a.channels,
                         transcript: a.transcript,
@@ -1989,9 +2935,87 @@ impl Neo4jClient {
             "finding latest big transcription for speech consolidation",
         )
         .await?;
-        rows.first()
+        let Some(mut candidate) = rows
+            .first()
             .map(graph_consolidated_speech_candidate_from_row)
-            .transpose()
+            .transpose()?
+        else {
+            return Ok(None);
+        };
+        for source in &mut candidate.sources {
+            self.load_payload(&mut source.clip.clip.base64).await?;
+        }
+        Ok(Some(candidate))
+    }
+
+    /// Return the latest big transcription that has no diarization run.
+    pub async fn latest_big_transcription_for_diarization(
+        &self,
+    ) -> Result<Option<GraphDiarizationCandidate>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (t:GraphNode:Transcription)
+                    WHERE coalesce(t.kind, "") = "big"
+                      AND NOT (t)-[:HAS_DIARIZATION_RUN]->(:GraphNode:DiarizationRun)
+                    WITH t, coalesce(t.transcribed_at, t.source_ended_at, t.source_started_at, "") AS candidate_at
+                    ORDER BY candidate_at DESC, t.id
+                    LIMIT 1
+                    OPTIONAL MATCH (t)-[:HAS_SEGMENT]->(segment:GraphNode:SpeechSegment)
+                    WITH t, segment
+                    ORDER BY toInteger(coalesce(segment.segment_index, 0)) ASC, segment.id
+                    WITH t, [item IN collect({
+                        id: segment.id,
+                        index: toInteger(coalesce(segment.segment_index, 0)),
+                        text: coalesce(segment.text, ""),
+                        start_ms: toInteger(coalesce(segment.start_ms, 0)),
+                        end_ms: toInteger(coalesce(segment.end_ms, segment.start_ms, 0)),
+                        occurred_at: segment.occurred_at,
+                        ended_at: segment.ended_at
+                    }) WHERE item.id IS NOT NULL] AS segments
+                    OPTIONAL MATCH (a:GraphNode:AudioClip)-[source:HAS_BIG_TRANSCRIPTION]->(t)
+                    WHERE (a.base64 IS NOT NULL OR a.blob_hash IS NOT NULL)
+                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(a)
+                    WITH t, segments, a, source, s
+                    ORDER BY toInteger(coalesce(source.source_index, 0)) ASC, a.id
+                    WITH t, segments, [item IN collect({
+                        index: toInteger(coalesce(source.source_index, 0)),
+                        id: a.id,
+                        mime: a.mime,
+                        base64: coalesce(a.base64, 'blob:' + a.blob_hash),
+                        sample_rate: a.sample_rate,
+                        channels: a.channels,
+                        transcript: a.transcript,
+                        captured_at: a.captured_at,
+                        occurred_at: a.occurred_at,
+                        sensation_id: s.id,
+                        start_ms: toInteger(coalesce(source.start_ms, 0)),
+                        end_ms: toInteger(coalesce(source.end_ms, 0))
+                    }) WHERE item.id IS NOT NULL] AS sources
+                    RETURN t.id, sources, segments
+                "#
+                .into(),
+                parameters: json!({}),
+            },
+            "finding latest big transcription for diarization",
+        )
+        .await?;
+        let Some(mut candidate) = rows
+            .first()
+            .map(graph_diarization_candidate_from_row)
+            .transpose()?
+        else {
+            return Ok(None);
+        };
+        for source in &mut candidate.sources {
+            self.load_payload(&mut source.clip.clip.base64).await?;
+        }
+        Ok(Some(candidate))
     }
 
     /// Return the latest `AudioClip` graph node that has no voice-recognition run.
@@ -2007,11 +3031,11 @@ impl Neo4jClient {
             CypherStatement {
                 statement: r#"
                     MATCH (a:GraphNode:AudioClip)
-                    WHERE a.base64 IS NOT NULL
+                    WHERE (a.base64 IS NOT NULL OR a.blob_hash IS NOT NULL)
                       AND NOT (a)-[:HAS_VOICE_RECOGNITION_RUN]->(:GraphNode:VoiceRecognitionRun)
                     OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(a)
                     WITH a, s, coalesce(a.captured_at, a.occurred_at, s.occurred_at, "") AS observed_at
-                    RETURN a.id, a.mime, a.base64, a.sample_rate, a.channels, a.captured_at, a.occurred_at, s.id
+                    RETURN a.id, a.mime, coalesce(a.base64, 'blob:' + a.blob_hash), a.sample_rate, a.channels, a.captured_at, a.occurred_at, s.id
                     ORDER BY observed_at DESC
                     LIMIT 1
                 "#
@@ -2021,12 +3045,22 @@ impl Neo4jClient {
             "finding latest unprocessed audio clip for voice recognition",
         )
         .await?;
-        rows.first().map(graph_voice_clip_from_row).transpose()
+        let Some(mut clip) = rows.first().map(graph_voice_clip_from_row).transpose()? else {
+            return Ok(None);
+        };
+        self.load_payload(&mut clip.clip.base64).await?;
+        Ok(Some(clip))
     }
 
-    /// Return the latest `Image` graph node that has no face-recognition run.
-    pub async fn latest_unprocessed_image_frame_for_face_recognition(
+    /// Return the newest image frame still waiting for face recognition.
+    ///
+    /// Frames observed before `not_before` are stale and left alone. When
+    /// `tracking_owner` is set, frames from sources whose faces another
+    /// replica is tracking are left to that replica.
+    pub async fn newest_unprocessed_image_frame_for_face_recognition(
         &self,
+        not_before: Option<chrono::DateTime<chrono::Utc>>,
+        tracking_owner: Option<&str>,
     ) -> Result<Option<GraphImageFrame>> {
         let endpoint = self.http_endpoint()?;
         let rows = query_neo4j_rows(
@@ -2037,21 +3071,39 @@ impl Neo4jClient {
             CypherStatement {
                 statement: r#"
                     MATCH (i:GraphNode:Image)
-                    WHERE i.base64 IS NOT NULL
+                    WHERE (i.base64 IS NOT NULL OR i.blob_hash IS NOT NULL)
                       AND NOT (i)-[:HAS_FACE_RECOGNITION_RUN]->(:GraphNode:FaceRecognitionRun)
+                      AND NOT EXISTS {
+                          MATCH (lease:WorkLease {kind: "face_recognition"})-[:LEASES]->(i)
+                          WHERE lease.failed_at_ms IS NOT NULL
+                             OR (lease.owner IS NOT NULL AND lease.expires_at_ms > timestamp())
+                      }
+                      AND ($tracking_owner IS NULL OR NOT EXISTS {
+                          MATCH (tracking:WorkLease {kind: $tracking_kind + ":" + coalesce(i.source, $default_source)})
+                          WHERE tracking.owner IS NOT NULL
+                            AND tracking.owner <> $tracking_owner
+                            AND tracking.expires_at_ms > timestamp()
+                      })
                     OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
                     WITH i, s, coalesce(i.captured_at, i.occurred_at, s.occurred_at, "") AS observed_at
-                    RETURN i.id, i.mime, i.base64, i.captured_at, i.occurred_at, s.id
+                    WHERE $not_before IS NULL
+                       OR (observed_at <> "" AND datetime(observed_at) >= datetime($not_before))
+                    RETURN i.id, i.mime, coalesce(i.base64, 'blob:' + i.blob_hash), i.captured_at, i.occurred_at, s.id, i.source
                     ORDER BY observed_at DESC
                     LIMIT 1
                 "#
                 .into(),
-                parameters: json!({}),
+                parameters: json!({
+                    "not_before": not_before.map(|at| at.to_rfc3339()),
+                    "tracking_owner": tracking_owner,
+                    "tracking_kind": WorkLease::FACE_TRACKING,
+                    "default_source": GraphImageFrame::DEFAULT_SOURCE,
+                }),
             },
-            "finding latest unprocessed image frame for face recognition",
+            "finding newest unprocessed image frame for face recognition",
         )
         .await?;
-        rows.first().map(graph_image_frame_from_row).transpose()
+        self.first_image_frame(&rows).await
     }
 
     /// Return the face cluster and optional identity containing a face vector point.
@@ -2092,7 +3144,10 @@ impl Neo4jClient {
                         )
                     ] AS identity_names
                     WITH face, rank, head([name IN identity_names WHERE name IS NOT NULL AND name <> ""]) AS identity_name
-                    RETURN face.id, identity_name
+                    OPTIONAL MATCH (face)-[:PART_OF_PERSON]->(person:GraphNode:Person)
+                    WITH face, rank, person,
+                         coalesce(identity_name, head([(person)-[:HAS_FACE|HAS_VOICE]->(:GraphNode)-[:HAS_IDENTITY]->(known:GraphNode:Identity) WHERE coalesce(known.name, "") <> "" | known.name])) AS identity_name
+                    RETURN face.id, identity_name, person.id
                     ORDER BY CASE WHEN identity_name IS NULL THEN 1 ELSE 0 END, rank, identity_name
                     LIMIT 1
                 "#
@@ -2145,7 +3200,10 @@ impl Neo4jClient {
                         )
                     ] AS identity_names
                     WITH voice, rank, head([name IN identity_names WHERE name IS NOT NULL AND name <> ""]) AS identity_name
-                    RETURN voice.id, identity_name
+                    OPTIONAL MATCH (voice)-[:PART_OF_PERSON]->(person:GraphNode:Person)
+                    WITH voice, rank, person,
+                         coalesce(identity_name, head([(person)-[:HAS_FACE|HAS_VOICE]->(:GraphNode)-[:HAS_IDENTITY]->(known:GraphNode:Identity) WHERE coalesce(known.name, "") <> "" | known.name])) AS identity_name
+                    RETURN voice.id, identity_name, person.id
                     ORDER BY CASE WHEN identity_name IS NULL THEN 1 ELSE 0 END, rank, identity_name
                     LIMIT 1
                 "#
@@ -2200,7 +3258,10 @@ impl Neo4jClient {
                          ] AS identity_names
                     WITH face, image, vector, target, target_label, observed_at,
                          head([name IN identity_names WHERE name IS NOT NULL AND name <> ""]) AS identity_name
-                    RETURN target.id, target_label, face.id, image.id, vector.id, identity_name, observed_at
+                    OPTIONAL MATCH (target)-[:PART_OF_PERSON]->(person:GraphNode:Person)
+                    WITH face, image, vector, target, target_label, observed_at, person,
+                         coalesce(identity_name, head([(person)-[:HAS_FACE|HAS_VOICE]->(:GraphNode)-[:HAS_IDENTITY]->(known:GraphNode:Identity) WHERE coalesce(known.name, "") <> "" | known.name])) AS identity_name
+                    RETURN target.id, target_label, face.id, image.id, vector.id, identity_name, observed_at, person.id, face.pose
                     ORDER BY observed_at DESC, face.id DESC
                     LIMIT $limit
                 "#
@@ -2257,7 +3318,10 @@ impl Neo4jClient {
                          ] AS identity_names
                     WITH signature, clip, vector, target, target_label, observed_at,
                          head([name IN identity_names WHERE name IS NOT NULL AND name <> ""]) AS identity_name
-                    RETURN target.id, target_label, signature.id, clip.id, vector.id, identity_name, observed_at
+                    OPTIONAL MATCH (target)-[:PART_OF_PERSON]->(person:GraphNode:Person)
+                    WITH signature, clip, vector, target, target_label, observed_at, person,
+                         coalesce(identity_name, head([(person)-[:HAS_FACE|HAS_VOICE]->(:GraphNode)-[:HAS_IDENTITY]->(known:GraphNode:Identity) WHERE coalesce(known.name, "") <> "" | known.name])) AS identity_name
+                    RETURN target.id, target_label, signature.id, clip.id, vector.id, identity_name, observed_at, person.id
                     ORDER BY observed_at DESC, signature.id DESC
                     LIMIT $limit
                 "#
@@ -2312,10 +3376,17 @@ impl Neo4jClient {
         .await
     }
 
-    /// Return the latest `Image` graph node that has no scene-vectorization run.
-    pub async fn latest_unprocessed_image_frame_for_scene_vectorization(
-        &self,
-    ) -> Result<Option<GraphImageFrame>> {
+    /// Return every person identity with its face and voice evidence.
+    pub async fn identities(&self) -> Result<Vec<GraphIdentity>> {
+        self.query_identities(None).await
+    }
+
+    /// Return one person identity, or `None` when it does not exist.
+    pub async fn identity(&self, identity_id: &str) -> Result<Option<GraphIdentity>> {
+        Ok(self.query_identities(Some(identity_id)).await?.pop())
+    }
+
+    async fn query_identities(&self, identity_id: Option<&str>) -> Result<Vec<GraphIdentity>> {
         let endpoint = self.http_endpoint()?;
         let rows = query_neo4j_rows(
             &reqwest::Client::new(),
@@ -2324,28 +3395,51 @@ impl Neo4jClient {
             &self.pass,
             CypherStatement {
                 statement: r#"
-                    MATCH (i:GraphNode:Image)
-                    WHERE i.base64 IS NOT NULL
-                      AND NOT (i)-[:HAS_SCENE_VECTORIZATION_RUN]->(:GraphNode:SceneVectorizationRun)
-                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
-                    WITH i, s, coalesce(i.captured_at, i.occurred_at, s.occurred_at, "") AS observed_at
-                    RETURN i.id, i.mime, i.base64, i.captured_at, i.occurred_at, s.id
-                    ORDER BY observed_at DESC
-                    LIMIT 1
+                    MATCH (identity:GraphNode:Identity)
+                    WHERE $identity_id IS NULL OR identity.id = $identity_id
+                    OPTIONAL MATCH (identity)-[:IDENTITY_OF]->(target:GraphNode)
+                    WITH identity, collect(DISTINCT target) AS targets
+                    WITH identity,
+                         [target IN targets WHERE target:Cluster | target.id] AS clusters,
+                         reduce(samples = [], target IN targets |
+                             samples
+                             + [target]
+                             + [(sample:GraphNode)-[:MATCHED_FACE|MATCHED_VOICE]->(target) | sample]
+                             + [(sample:GraphNode)-[:HAS_FACE_VECTOR|HAS_VOICE_VECTOR]->(:GraphNode:Vector)-[:MEMBER_OF_CLUSTER]->(target) | sample]
+                         ) AS candidates
+                    WITH identity, clusters,
+                         [sample IN candidates WHERE sample:FaceInstance OR sample:VoiceSignature] AS candidates
+                    UNWIND (CASE WHEN candidates = [] THEN [null] ELSE candidates END) AS sample
+                    WITH identity, clusters, collect(DISTINCT sample) AS samples
+                    WITH identity, clusters, samples,
+                         [sample IN samples | coalesce(sample.occurred_at, sample.captured_at, sample.last_updated, "")] AS seen
+                    RETURN identity.id,
+                           coalesce(identity.name, identity.summary, ""),
+                           size([sample IN samples WHERE sample:FaceInstance]),
+                           size([sample IN samples WHERE sample:VoiceSignature]),
+                           clusters,
+                           reduce(latest = "", at IN seen | CASE WHEN at > latest THEN at ELSE latest END),
+                           coalesce(identity.opted_out, false)
+                    ORDER BY toLower(coalesce(identity.name, identity.id))
                 "#
                 .into(),
-                parameters: json!({}),
+                parameters: json!({
+                    "identity_id": identity_id,
+                }),
             },
-            "finding latest unprocessed image frame for scene vectorization",
+            "finding person identities",
         )
         .await?;
-        rows.first().map(graph_image_frame_from_row).transpose()
+        rows.iter().map(graph_identity_from_row).collect()
     }
 
-    /// Return the latest `Image` graph node that has no image-description run.
-    pub async fn latest_unprocessed_image_frame_for_description(
+    /// Return the Qdrant points behind an identity's samples, optionally only
+    /// those reached through `target_id`.
+    pub async fn identity_vectors(
         &self,
-    ) -> Result<Option<GraphImageFrame>> {
+        identity_id: &str,
+        target_id: Option<&str>,
+    ) -> Result<Vec<GraphIdentityVector>> {
         let endpoint = self.http_endpoint()?;
         let rows = query_neo4j_rows(
             &reqwest::Client::new(),
@@ -2354,27 +3448,52 @@ impl Neo4jClient {
             &self.pass,
             CypherStatement {
                 statement: r#"
-                    MATCH (i:GraphNode:Image)
-                    WHERE i.base64 IS NOT NULL
-                      AND NOT (i)-[:HAS_IMAGE_DESCRIPTION_RUN]->(:GraphNode:ImageDescriptionRun)
-                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
-                    WITH i, s, coalesce(i.captured_at, i.occurred_at, s.occurred_at, "") AS observed_at
-                    RETURN i.id, i.mime, i.base64, i.captured_at, i.occurred_at, s.id
-                    ORDER BY observed_at DESC
-                    LIMIT 1
+                    MATCH (identity:GraphNode:Identity {id: $identity_id})-[:IDENTITY_OF]->(target:GraphNode)
+                    WHERE $target_id IS NULL OR target.id = $target_id
+                    WITH collect(DISTINCT target) AS targets
+                    UNWIND reduce(vectors = [], target IN targets |
+                        vectors
+                        + [(target)-[:HAS_FACE_VECTOR|HAS_VOICE_VECTOR]->(vector:GraphNode:Vector) | vector]
+                        + [(vector:GraphNode:Vector)<-[:HAS_FACE_VECTOR|HAS_VOICE_VECTOR]-(:GraphNode)-[:MATCHED_FACE|MATCHED_VOICE]->(target) | vector]
+                        + [(vector:GraphNode:Vector)-[:MEMBER_OF_CLUSTER]->(target) | vector]
+                    ) AS vector
+                    WITH DISTINCT vector
+                    WHERE vector.collection IS NOT NULL AND vector.point_id IS NOT NULL
+                    RETURN vector.collection, vector.point_id
+                    ORDER BY vector.collection, vector.point_id
                 "#
                 .into(),
-                parameters: json!({}),
+                parameters: json!({
+                    "identity_id": identity_id,
+                    "target_id": target_id,
+                }),
             },
-            "finding latest unprocessed image frame for description",
+            "finding identity vectors",
         )
         .await?;
-        rows.first().map(graph_image_frame_from_row).transpose()
+        rows.iter()
+            .map(|row| {
+                let values = row
+                    .as_array()
+                    .context("Neo4j identity vector row was not an array")?;
+                Ok(GraphIdentityVector {
+                    collection: row_string(values, 0, "collection")?,
+                    point_id: row_string(values, 1, "point_id")?,
+                })
+            })
+            .collect()
     }
 
-    /// Return the latest `Image` graph node, whether or not it has already
-    /// been processed by derived vision pipelines.
-    pub async fn latest_image_frame(&self) -> Result<Option<GraphImageFrame>> {
+    /// Rename an identity, returning its new id.
+    ///
+    /// The id follows the name, so renaming to the name of another identity
+    /// fails; merge the two instead.
+    pub async fn rename_identity(&self, identity_id: &str, name: &str) -> Result<String> {
+        let name = common::non_empty_model_text(name).context("identity name was empty")?;
+        let new_id = person_identity_id(name);
+        if new_id != identity_id && self.identity(&new_id).await?.is_some() {
+            bail!("identity {new_id} already exists; merge {identity_id} into it instead");
+        }
         let endpoint = self.http_endpoint()?;
         let rows = query_neo4j_rows(
             &reqwest::Client::new(),
@@ -2383,21 +3502,1364 @@ impl Neo4jClient {
             &self.pass,
             CypherStatement {
                 statement: r#"
-                    MATCH (i:GraphNode:Image)
-                    WHERE i.base64 IS NOT NULL
-                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
-                    WITH i, s, coalesce(i.captured_at, i.occurred_at, s.occurred_at, "") AS observed_at
-                    RETURN i.id, i.mime, i.base64, i.captured_at, i.occurred_at, s.id
-                    ORDER BY observed_at DESC
-                    LIMIT 1
+                    MATCH (identity:GraphNode:Identity {id: $identity_id})
+                    SET identity.id = $new_id,
+                        identity.name = $name,
+                        identity.summary = $name,
+                        identity.renamed_at = $renamed_at
+                    RETURN identity.id
                 "#
                 .into(),
-                parameters: json!({}),
+                parameters: json!({
+                    "identity_id": identity_id,
+                    "new_id": new_id,
+                    "name": name,
+                    "renamed_at": chrono::Utc::now().to_rfc3339(),
+                }),
+            },
+            "renaming identity",
+        )
+        .await?;
+        anyhow::ensure!(!rows.is_empty(), "identity {identity_id} not found");
+        Ok(new_id)
+    }
+
+    /// Move every face, voice and run of `from_id` onto `into_id` and delete
+    /// `from_id`, returning how many faces and voices moved.
+    pub async fn merge_identities(&self, from_id: &str, into_id: &str) -> Result<u32> {
+        anyhow::ensure!(
+            from_id != into_id,
+            "cannot merge identity {from_id} into itself"
+        );
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (from:GraphNode:Identity {id: $from_id})
+                    MATCH (into:GraphNode:Identity {id: $into_id})
+                    OPTIONAL MATCH (from)-[:IDENTITY_OF|HAS_IDENTITY]-(target:GraphNode)
+                    WITH from, into, collect(DISTINCT target) AS targets
+                    OPTIONAL MATCH (run:GraphNode)-[:PRODUCED]->(from)
+                    WITH from, into, targets, collect(DISTINCT run) AS runs
+                    FOREACH (target IN targets |
+                        MERGE (target)-[:HAS_IDENTITY]->(into)
+                        MERGE (into)-[:IDENTITY_OF]->(target))
+                    FOREACH (run IN runs | MERGE (run)-[:PRODUCED]->(into))
+                    SET into.merged_from = coalesce(into.merged_from, []) + from.id,
+                        into.merged_at = $merged_at
+                    WITH from, size(targets) AS moved
+                    DETACH DELETE from
+                    RETURN moved
+                "#
+                .into(),
+                parameters: json!({
+                    "from_id": from_id,
+                    "into_id": into_id,
+                    "merged_at": chrono::Utc::now().to_rfc3339(),
+                }),
+            },
+            "merging identities",
+        )
+        .await?;
+        let values = rows
+            .first()
+            .and_then(Value::as_array)
+            .with_context(|| format!("identity {from_id} or {into_id} not found"))?;
+        row_u32(values, 0, "moved")
+    }
+
+    /// Detach `target_id`, a face or voice cluster or sample, from an
+    /// identity, optionally giving it a new identity named `new_name`.
+    ///
+    /// Returns the new identity id.
+    pub async fn split_identity(
+        &self,
+        identity_id: &str,
+        target_id: &str,
+        new_name: Option<&str>,
+        source: &str,
+    ) -> Result<Option<String>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (identity:GraphNode:Identity {id: $identity_id})-[link:IDENTITY_OF|HAS_IDENTITY]-(target:GraphNode {id: $target_id})
+                    WITH target, collect(link) AS links
+                    FOREACH (link IN links | DELETE link)
+                    RETURN head([label IN labels(target) WHERE label IN ["Face", "FaceInstance", "Voice", "VoiceSignature"]])
+                "#
+                .into(),
+                parameters: json!({
+                    "identity_id": identity_id,
+                    "target_id": target_id,
+                }),
+            },
+            "splitting identity",
+        )
+        .await?;
+        let values = rows
+            .first()
+            .and_then(Value::as_array)
+            .with_context(|| format!("{target_id} is not attached to identity {identity_id}"))?;
+        let Some(name) = new_name else {
+            return Ok(None);
+        };
+        let target_label = row_string(values, 0, "target label")?;
+        let kind = if matches!(target_label.as_str(), "Voice" | "VoiceSignature") {
+            ManualIdentityKind::Voice
+        } else {
+            ManualIdentityKind::Face
+        };
+        attach_manual_identity(self, kind, target_id, &target_label, name, source, None).await?;
+        Ok(Some(person_identity_id(name)))
+    }
+
+    /// Return when person recognitions were last tallied up to, if ever.
+    pub async fn person_link_progress(&self) -> Result<Option<String>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (progress:GraphNode:PersonLinkProgress {id: $progress_id})
+                    RETURN progress.through
+                "#
+                .into(),
+                parameters: json!({ "progress_id": PERSON_LINK_PROGRESS_ID }),
+            },
+            "finding person link progress",
+        )
+        .await?;
+        Ok(rows
+            .first()
+            .and_then(Value::as_array)
+            .and_then(|values| row_optional_string(values, 0)))
+    }
+
+    /// Return every face and voice recognition whose sensation occurred in
+    /// `[since, until)`, oldest first.
+    pub async fn person_recognitions(
+        &self,
+        since: &str,
+        until: &str,
+    ) -> Result<Vec<GraphPersonRecognition>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (sensation:GraphNode:Sensation)
+                    WHERE sensation.occurred_at >= $since AND sensation.occurred_at < $until
+                    MATCH (sensation)-[:RECOGNIZED_AS]->(cluster:GraphNode)
+                    WHERE cluster:Face OR cluster:Voice
+                    WITH cluster, cluster:Voice AS voice,
+                         datetime(sensation.occurred_at).epochMillis AS at_ms
+                    RETURN cluster.id, voice, at_ms
+                    ORDER BY at_ms
+                "#
+                .into(),
+                parameters: json!({
+                    "since": since,
+                    "until": until,
+                }),
+            },
+            "finding face and voice recognitions",
+        )
+        .await?;
+        rows.iter()
+            .map(|row| {
+                let values = row
+                    .as_array()
+                    .context("Neo4j recognition row was not an array")?;
+                Ok(GraphPersonRecognition {
+                    cluster_id: row_string(values, 0, "cluster id")?,
+                    voice: values.get(1).and_then(Value::as_bool).unwrap_or(false),
+                    at_ms: values
+                        .get(2)
+                        .and_then(Value::as_i64)
+                        .context("Neo4j recognition row is missing its time")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Add `co_occurrences` to the window tallies on each face's
+    /// `CO_OCCURS_WITH` relationship and mark recognitions up to `through` as
+    /// counted. Windows at or before a pair's last counted window are
+    /// skipped, so a window straddling two runs counts once.
+    pub async fn record_person_co_occurrences(
+        &self,
+        co_occurrences: &[GraphPersonCoOccurrence],
+        through: &str,
+    ) -> Result<()> {
+        let endpoint = self.http_endpoint()?;
+        let client = reqwest::Client::new();
+        self.ensure_constraint(&client, &endpoint).await?;
+        let pairs: Vec<Value> = co_occurrences
+            .iter()
+            .map(|pair| {
+                json!({
+                    "face_id": pair.face_id,
+                    "voice_id": pair.voice_id,
+                    "windows": pair.windows,
+                })
+            })
+            .collect();
+        let statements = [
+            CypherStatement {
+                statement: r#"
+                    UNWIND $pairs AS pair
+                    MATCH (face:GraphNode:Face {id: pair.face_id})
+                    MATCH (voice:GraphNode:Voice {id: pair.voice_id})
+                    MERGE (face)-[link:CO_OCCURS_WITH]->(voice)
+                    WITH link, [window IN pair.windows WHERE window > coalesce(link.last_window, -1)] AS added
+                    SET link.windows = coalesce(link.windows, 0) + size(added),
+                        link.last_window = reduce(last = coalesce(link.last_window, -1), window IN added |
+                            CASE WHEN window > last THEN window ELSE last END)
+                "#
+                .into(),
+                parameters: json!({ "pairs": pairs }),
+            },
+            CypherStatement {
+                statement: r#"
+                    MERGE (progress:GraphNode:PersonLinkProgress {id: $progress_id})
+                    SET progress.through = $through
+                "#
+                .into(),
+                parameters: json!({
+                    "progress_id": PERSON_LINK_PROGRESS_ID,
+                    "through": through,
+                }),
+            },
+        ];
+        commit_neo4j_statements(
+            &client,
+            &endpoint,
+            &self.user,
+            &self.pass,
+            &statements,
+            "recording face and voice co-occurrences",
+        )
+        .await
+    }
+
+    /// Return face and voice clusters tallied together in at least
+    /// `min_windows` distinct windows and not unlinked by hand, most frequent
+    /// first.
+    pub async fn person_link_candidates(
+        &self,
+        min_windows: u32,
+    ) -> Result<Vec<GraphPersonCandidate>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (face:GraphNode:Face)-[link:CO_OCCURS_WITH]->(voice:GraphNode:Voice)
+                    WHERE link.windows >= $min_windows AND link.unlinked_at IS NULL
+                    RETURN face.id, voice.id, link.windows
+                    ORDER BY link.windows DESC, face.id, voice.id
+                "#
+                .into(),
+                parameters: json!({ "min_windows": min_windows }),
+            },
+            "finding co-occurring faces and voices",
+        )
+        .await?;
+        rows.iter()
+            .map(|row| {
+                let values = row
+                    .as_array()
+                    .context("Neo4j person candidate row was not an array")?;
+                Ok(GraphPersonCandidate {
+                    face_id: row_string(values, 0, "face id")?,
+                    voice_id: row_string(values, 1, "voice id")?,
+                    windows: row_u32(values, 2, "windows")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Attach the face and voice of `candidate` to one `Person`, returning its
+    /// id.
+    ///
+    /// A person already holding the face or the voice takes the other; a new
+    /// person is created when neither belongs to one. The person is named
+    /// after the face's identity, or else the voice's. When they belong to
+    /// different people nothing is linked and `None` is returned.
+    pub async fn link_person(&self, candidate: &GraphPersonCandidate) -> Result<Option<String>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    OPTIONAL MATCH (:GraphNode:Face {id: $face_id})-[:PART_OF_PERSON]->(face_person:GraphNode:Person)
+                    OPTIONAL MATCH (:GraphNode:Voice {id: $voice_id})-[:PART_OF_PERSON]->(voice_person:GraphNode:Person)
+                    OPTIONAL MATCH (:GraphNode:Face {id: $face_id})-[:HAS_IDENTITY]->(face_identity:GraphNode:Identity)
+                    OPTIONAL MATCH (:GraphNode:Voice {id: $voice_id})-[:HAS_IDENTITY]->(voice_identity:GraphNode:Identity)
+                    RETURN face_person.id, voice_person.id,
+                           head([name IN [face_identity.name, voice_identity.name] WHERE coalesce(name, "") <> ""])
+                    LIMIT 1
+                "#
+                .into(),
+                parameters: json!({
+                    "face_id": candidate.face_id,
+                    "voice_id": candidate.voice_id,
+                }),
+            },
+            "finding people of a face and voice",
+        )
+        .await?;
+        let values = rows.first().and_then(Value::as_array);
+        let face_person = values.and_then(|values| row_optional_string(values, 0));
+        let voice_person = values.and_then(|values| row_optional_string(values, 1));
+        let name = values.and_then(|values| row_optional_string(values, 2));
+        let person_id = match (face_person, voice_person) {
+            (Some(face_person), Some(voice_person)) if face_person != voice_person => {
+                return Ok(None);
+            }
+            (Some(person), _) | (None, Some(person)) => person,
+            (None, None) => stable_string_id(
+                "person",
+                &format!("{}:{}", candidate.face_id, candidate.voice_id),
+            ),
+        };
+        let linked_at = chrono::Utc::now().to_rfc3339();
+        let mut person = json!({
+            "label": "Person",
+            "id": person_id,
+            "kind": "person",
+            "linked_at": linked_at,
+        });
+        // Keep a name the person already has when neither member is named.
+        if let Some(name) = name {
+            person["name"] = json!(name);
+        }
+        self.store_data(&json!({
+            "op": "merge_graph",
+            "nodes": [
+                person,
+                {
+                    "label": "Face",
+                    "labels": ["Cluster"],
+                    "id": candidate.face_id,
+                },
+                {
+                    "label": "Voice",
+                    "labels": ["Cluster"],
+                    "id": candidate.voice_id,
+                },
+            ],
+            "relationships": [
+                {
+                    "from": candidate.face_id,
+                    "to": person_id,
+                    "type": "PART_OF_PERSON",
+                },
+                {
+                    "from": person_id,
+                    "to": candidate.face_id,
+                    "type": "HAS_FACE",
+                },
+                {
+                    "from": candidate.voice_id,
+                    "to": person_id,
+                    "type": "PART_OF_PERSON",
+                },
+                {
+                    "from": person_id,
+                    "to": candidate.voice_id,
+                    "type": "HAS_VOICE",
+                },
+                {
+                    "from": candidate.face_id,
+                    "to": candidate.voice_id,
+                    "type": "CO_OCCURS_WITH",
+                    "windows": candidate.windows,
+                    "linked_at": linked_at,
+                },
+            ],
+        }))
+        .await?;
+        self.number_people().await?;
+        Ok(Some(person_id))
+    }
+
+    /// Give every person without an ordinal the next free one, oldest link
+    /// first, so unnamed people keep the number they were first called by.
+    async fn number_people(&self) -> Result<()> {
+        let endpoint = self.http_endpoint()?;
+        query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    OPTIONAL MATCH (numbered:GraphNode:Person)
+                    WHERE numbered.ordinal IS NOT NULL
+                    WITH coalesce(max(numbered.ordinal), 0) AS last
+                    MATCH (person:GraphNode:Person)
+                    WHERE person.ordinal IS NULL
+                    WITH last, person
+                    ORDER BY person.linked_at, person.id
+                    WITH last, collect(person) AS unnumbered
+                    UNWIND range(0, size(unnumbered) - 1) AS index
+                    WITH unnumbered[index] AS person, last + index + 1 AS ordinal
+                    SET person.ordinal = ordinal
+                "#
+                .into(),
+                parameters: json!({}),
+            },
+            "numbering people",
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Detach the face or voice cluster `member_id` from the person it was
+    /// linked into, returning the person's id. The co-occurrences that led
+    /// to the link are marked so the linker does not link them again.
+    pub async fn unlink_person_member(&self, member_id: &str) -> Result<Option<String>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (member:GraphNode {id: $member_id})-[part:PART_OF_PERSON]->(person:GraphNode:Person)
+                    OPTIONAL MATCH (person)-[has:HAS_FACE|HAS_VOICE]->(member)
+                    WITH member, person, collect(DISTINCT part) + collect(DISTINCT has) AS links
+                    OPTIONAL MATCH (person)-[:HAS_FACE|HAS_VOICE]->(other:GraphNode)
+                    WHERE other <> member
+                    OPTIONAL MATCH (member)-[pair:CO_OCCURS_WITH]-(other)
+                    WITH person, links, collect(DISTINCT pair) AS pairs
+                    FOREACH (link IN links | DELETE link)
+                    FOREACH (pair IN pairs | SET pair.unlinked_at = $unlinked_at)
+                    RETURN person.id
+                "#
+                .into(),
+                parameters: json!({
+                    "member_id": member_id,
+                    "unlinked_at": chrono::Utc::now().to_rfc3339(),
+                }),
+            },
+            "unlinking person member",
+        )
+        .await?;
+        Ok(rows
+            .first()
+            .and_then(Value::as_array)
+            .and_then(|values| row_optional_string(values, 0)))
+    }
+
+    /// Return every linked person with their face and voice clusters.
+    pub async fn people(&self) -> Result<Vec<GraphPerson>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (person:GraphNode:Person)-[:HAS_FACE|HAS_VOICE]->(member:GraphNode)
+                    OPTIONAL MATCH (member)-[:HAS_IDENTITY]->(identity:GraphNode:Identity)
+                    WITH person,
+                         collect(DISTINCT CASE WHEN member:Face THEN member.id END) AS face_ids,
+                         collect(DISTINCT CASE WHEN member:Voice THEN member.id END) AS voice_ids,
+                         collect(CASE WHEN member:Face THEN identity.name END) AS face_names,
+                         collect(CASE WHEN member:Voice THEN identity.name END) AS voice_names
+                    RETURN person.id,
+                           coalesce(person.name, head([name IN face_names + voice_names WHERE name <> ""])),
+                           face_ids,
+                           voice_ids,
+                           person.ordinal
+                    ORDER BY person.id
+                "#
+                .into(),
+                parameters: json!({}),
+            },
+            "finding people",
+        )
+        .await?;
+        rows.iter()
+            .map(|row| {
+                let values = row
+                    .as_array()
+                    .context("Neo4j person row was not an array")?;
+                Ok(GraphPerson {
+                    person_id: row_string(values, 0, "person id")?,
+                    name: row_optional_string(values, 1).and_then(non_empty_identity_name),
+                    face_ids: row_string_vec(values, 2),
+                    voice_ids: row_string_vec(values, 3),
+                    ordinal: values
+                        .get(4)
+                        .and_then(Value::as_u64)
+                        .and_then(|ordinal| u32::try_from(ordinal).ok()),
+                })
+            })
+            .collect()
+    }
+
+    /// Delete an identity, leaving its faces and voices unnamed, and return
+    /// how many were detached.
+    pub async fn delete_identity(&self, identity_id: &str) -> Result<u32> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (identity:GraphNode:Identity {id: $identity_id})
+                    OPTIONAL MATCH (identity)-[:IDENTITY_OF|HAS_IDENTITY]-(target:GraphNode)
+                    WITH identity, count(DISTINCT target) AS detached
+                    DETACH DELETE identity
+                    RETURN detached
+                "#
+                .into(),
+                parameters: json!({
+                    "identity_id": identity_id,
+                }),
+            },
+            "deleting identity",
+        )
+        .await?;
+        let values = rows
+            .first()
+            .and_then(Value::as_array)
+            .with_context(|| format!("identity {identity_id} not found"))?;
+        row_u32(values, 0, "detached")
+    }
+
+    /// Mark an identity as opted out of being remembered, or opt it back in.
+    pub async fn set_identity_opt_out(&self, identity_id: &str, opted_out: bool) -> Result<()> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (identity:GraphNode:Identity {id: $identity_id})
+                    SET identity.opted_out = $opted_out,
+                        identity.opted_out_at = CASE WHEN $opted_out THEN coalesce(identity.opted_out_at, $changed_at) END
+                    RETURN identity.id
+                "#
+                .into(),
+                parameters: json!({
+                    "identity_id": identity_id,
+                    "opted_out": opted_out,
+                    "changed_at": chrono::Utc::now().to_rfc3339(),
+                }),
+            },
+            "setting identity opt-out",
+        )
+        .await?;
+        anyhow::ensure!(!rows.is_empty(), "identity {identity_id} not found");
+        Ok(())
+    }
+
+    /// Whether any identity is currently opted out.
+    pub async fn has_opted_out_identities(&self) -> Result<bool> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (identity:GraphNode:Identity)
+                    WHERE identity.opted_out = true
+                    RETURN identity.id
+                    LIMIT 1
+                "#
+                .into(),
+                parameters: json!({}),
+            },
+            "checking for opted-out identities",
+        )
+        .await?;
+        Ok(!rows.is_empty())
+    }
+
+    /// Return the opted-out identity a face vector point belongs to, through
+    /// its face, cluster or person, when any.
+    pub async fn opted_out_face_identity(&self, point_id: &str) -> Result<Option<String>> {
+        self.opted_out_identity_for_vector(FACE_COLLECTION, point_id)
+            .await
+    }
+
+    /// Return the opted-out identity a voice vector point belongs to, through
+    /// its voice, cluster or person, when any.
+    pub async fn opted_out_voice_identity(&self, point_id: &str) -> Result<Option<String>> {
+        self.opted_out_identity_for_vector(VOICE_COLLECTION, point_id)
+            .await
+    }
+
+    async fn opted_out_identity_for_vector(
+        &self,
+        collection: &str,
+        point_id: &str,
+    ) -> Result<Option<String>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (v:GraphNode:Vector {id: $vector_id})
+                    OPTIONAL MATCH (v)-[:MEMBER_OF_CLUSTER|HAS_CLUSTER_MEMBER|HAS_FACE_VECTOR|HAS_VOICE_VECTOR]-(member:GraphNode)
+                    OPTIONAL MATCH (member)-[:MATCHED_FACE|MATCHED_VOICE]->(matched:GraphNode)
+                    WITH collect(DISTINCT member) + collect(DISTINCT matched) AS members
+                    UNWIND members AS member
+                    OPTIONAL MATCH (member)-[:PART_OF_PERSON]->(:GraphNode:Person)-[:HAS_FACE|HAS_VOICE]->(sibling:GraphNode)
+                    WITH collect(DISTINCT member) + collect(DISTINCT sibling) AS members
+                    UNWIND members AS member
+                    MATCH (member)-[:HAS_IDENTITY]->(identity:GraphNode:Identity)
+                    WHERE identity.opted_out = true
+                    RETURN DISTINCT identity.id
+                    ORDER BY identity.id
+                    LIMIT 1
+                "#
+                .into(),
+                parameters: json!({
+                    "vector_id": qdrant_vector_node_id(collection, point_id),
+                }),
+            },
+            "finding opted-out identity for vector",
+        )
+        .await?;
+        Ok(rows
+            .first()
+            .and_then(Value::as_array)
+            .and_then(|values| row_optional_string(values, 0)))
+    }
+
+    /// Find the crops, audio, vectors and derived sensations stored for an
+    /// identity's faces and voices, and the frames its faces appear in.
+    pub async fn identity_purge(&self, identity_id: &str) -> Result<GraphIdentityPurge> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (identity:GraphNode:Identity {id: $identity_id})
+                    OPTIONAL MATCH (identity)-[:IDENTITY_OF]->(target:GraphNode)
+                    WITH collect(DISTINCT target) AS targets
+                    WITH targets,
+                         reduce(samples = [], target IN targets |
+                             samples
+                             + [target]
+                             + [(sample:GraphNode)-[:MATCHED_FACE|MATCHED_VOICE]->(target) | sample]
+                             + [(sample:GraphNode)-[:HAS_FACE_VECTOR|HAS_VOICE_VECTOR]->(:GraphNode:Vector)-[:MEMBER_OF_CLUSTER]->(target) | sample]
+                         ) AS candidates
+                    WITH targets,
+                         [sample IN candidates WHERE sample:FaceInstance OR sample:VoiceSignature] AS samples
+                    WITH targets, samples,
+                         reduce(found = [], sample IN samples |
+                             found + [(sample)-[:HAS_VOICE_SAMPLE]->(voice_sample:GraphNode) | voice_sample]
+                         ) AS voice_samples
+                    WITH targets, samples, voice_samples,
+                         reduce(found = [], sample IN voice_samples |
+                             found + [(sample)-[:DERIVED_FROM]->(clip:GraphNode:AudioClip) | clip]
+                         ) AS clips
+                    WITH samples, voice_samples, clips,
+                         reduce(found = [], node IN samples + clips |
+                             found + [(sensation:GraphNode:Sensation)-[:OBSERVED]->(node) | sensation]
+                         ) AS sensations,
+                         reduce(found = [], node IN targets + samples + voice_samples |
+                             found
+                             + [(node)-[:HAS_FACE_VECTOR|HAS_VOICE_VECTOR]->(vector:GraphNode:Vector) | vector]
+                             + [(vector:GraphNode:Vector)-[:MEMBER_OF_CLUSTER]->(node) | vector]
+                         ) AS vectors
+                    RETURN [sample IN samples WHERE sample:FaceInstance | sample.id],
+                           [sample IN samples WHERE sample:VoiceSignature | sample.id]
+                               + [sample IN voice_samples | sample.id],
+                           [clip IN clips | clip.id],
+                           [sensation IN sensations | sensation.id],
+                           [vector IN vectors WHERE vector.collection IS NOT NULL AND vector.point_id IS NOT NULL |
+                               [vector.collection, vector.point_id]],
+                           [sample IN samples WHERE sample:FaceInstance AND sample.source_image_id IS NOT NULL AND sample.bbox_x IS NOT NULL |
+                               [sample.source_image_id, sample.bbox_x, sample.bbox_y, sample.bbox_width, sample.bbox_height]]
+                "#
+                .into(),
+                parameters: json!({
+                    "identity_id": identity_id,
+                }),
+            },
+            "finding identity data to purge",
+        )
+        .await?;
+        let row = rows
+            .first()
+            .with_context(|| format!("identity {identity_id} not found"))?;
+        graph_identity_purge_from_row(identity_id, row)
+    }
+
+    /// Delete the nodes `purge` found and mark its identity opted out,
+    /// returning how many nodes were deleted.
+    pub async fn purge_identity(&self, purge: &GraphIdentityPurge) -> Result<u32> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (identity:GraphNode:Identity {id: $identity_id})
+                    SET identity.opted_out = true,
+                        identity.opted_out_at = coalesce(identity.opted_out_at, $purged_at),
+                        identity.purged_at = $purged_at
+                    WITH identity
+                    OPTIONAL MATCH (node:GraphNode)
+                    WHERE node.id IN $node_ids
+                    WITH collect(DISTINCT node) AS nodes
+                    WITH nodes,
+                         [node IN nodes WHERE node.blob_hash IS NOT NULL | node.blob_hash] AS hashes
+                    FOREACH (node IN nodes | DETACH DELETE node)
+                    RETURN size(nodes), hashes
+                "#
+                .into(),
+                parameters: json!({
+                    "identity_id": purge.identity_id,
+                    "node_ids": purge.node_ids(),
+                    "purged_at": chrono::Utc::now().to_rfc3339(),
+                }),
+            },
+            "purging identity",
+        )
+        .await?;
+        let values = rows
+            .first()
+            .and_then(Value::as_array)
+            .with_context(|| format!("identity {} not found", purge.identity_id))?;
+        let deleted = row_u32(values, 0, "deleted")?;
+        self.release_blobs(row_string_vec(values, 1)).await?;
+        Ok(deleted)
+    }
+
+    /// Replace the stored payload of an `Image` node with a redacted one,
+    /// keeping its id.
+    pub async fn replace_image_payload(&self, image_id: &str, image: &ImageData) -> Result<()> {
+        let payload = self.payload_properties(&image.mime, &image.base64).await?;
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (image:GraphNode:Image {id: $image_id})
+                    WITH image, image.blob_hash AS previous
+                    SET image += $payload,
+                        image.redacted_at = $redacted_at
+                    RETURN image.id, previous
+                "#
+                .into(),
+                parameters: json!({
+                    "image_id": image_id,
+                    "payload": payload,
+                    "redacted_at": chrono::Utc::now().to_rfc3339(),
+                }),
+            },
+            "replacing image payload",
+        )
+        .await?;
+        let values = rows
+            .first()
+            .and_then(Value::as_array)
+            .with_context(|| format!("image {image_id} not found"))?;
+        if let Some(previous) = row_optional_string(values, 1) {
+            self.release_blobs(vec![previous]).await?;
+        }
+        Ok(())
+    }
+
+    /// Return the latest `Image` graph node that has no object-detection run.
+    pub async fn latest_unprocessed_image_frame_for_object_detection(
+        &self,
+    ) -> Result<Option<GraphImageFrame>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (i:GraphNode:Image)
+                    WHERE (i.base64 IS NOT NULL OR i.blob_hash IS NOT NULL)
+                      AND NOT (i)-[:HAS_OBJECT_DETECTION_RUN]->(:GraphNode:ObjectDetectionRun)
+                      AND NOT EXISTS {
+                          MATCH (lease:WorkLease {kind: "object_detection"})-[:LEASES]->(i)
+                          WHERE lease.failed_at_ms IS NOT NULL
+                             OR (lease.owner IS NOT NULL AND lease.expires_at_ms > timestamp())
+                      }
+                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
+                    WITH i, s, coalesce(i.captured_at, i.occurred_at, s.occurred_at, "") AS observed_at
+                    RETURN i.id, i.mime, coalesce(i.base64, 'blob:' + i.blob_hash), i.captured_at, i.occurred_at, s.id
+                    ORDER BY observed_at DESC
+                    LIMIT 1
+                "#
+                .into(),
+                parameters: json!({}),
+            },
+            "finding latest unprocessed image frame for object detection",
+        )
+        .await?;
+        self.first_image_frame(&rows).await
+    }
+
+    /// Return the latest `Image` graph node that has no text-recognition run.
+    pub async fn latest_unprocessed_image_frame_for_text_recognition(
+        &self,
+    ) -> Result<Option<GraphImageFrame>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (i:GraphNode:Image)
+                    WHERE (i.base64 IS NOT NULL OR i.blob_hash IS NOT NULL)
+                      AND NOT (i)-[:HAS_TEXT_RECOGNITION_RUN]->(:GraphNode:TextRecognitionRun)
+                      AND NOT EXISTS {
+                          MATCH (lease:WorkLease {kind: "text_recognition"})-[:LEASES]->(i)
+                          WHERE lease.failed_at_ms IS NOT NULL
+                             OR (lease.owner IS NOT NULL AND lease.expires_at_ms > timestamp())
+                      }
+                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
+                    WITH i, s, coalesce(i.captured_at, i.occurred_at, s.occurred_at, "") AS observed_at
+                    RETURN i.id, i.mime, coalesce(i.base64, 'blob:' + i.blob_hash), i.captured_at, i.occurred_at, s.id
+                    ORDER BY observed_at DESC
+                    LIMIT 1
+                "#
+                .into(),
+                parameters: json!({}),
+            },
+            "finding latest unprocessed image frame for text recognition",
+        )
+        .await?;
+        self.first_image_frame(&rows).await
+    }
+
+    /// Return those of `text_ids` that a text sensation read at or after
+    /// `since`.
+    pub async fn recently_read_texts(
+        &self,
+        text_ids: &[String],
+        since: &str,
+    ) -> Result<Vec<String>> {
+        if text_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    UNWIND $text_ids AS text_id
+                    MATCH (s:GraphNode:Sensation {kind: "text"})-[:OBSERVED]->(t:GraphNode:TextBlock {id: text_id})
+                    WHERE s.occurred_at >= $since
+                    RETURN DISTINCT t.id
+                "#
+                .into(),
+                parameters: json!({
+                    "text_ids": text_ids,
+                    "since": since,
+                }),
+            },
+            "finding recently read texts",
+        )
+        .await?;
+        rows.iter()
+            .filter_map(Value::as_array)
+            .map(|values| row_string(values, 0, "text id"))
+            .collect()
+    }
+
+    /// Return the latest `Image` graph node that has no scene-vectorization run.
+    pub async fn latest_unprocessed_image_frame_for_scene_vectorization(
+        &self,
+    ) -> Result<Option<GraphImageFrame>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (i:GraphNode:Image)
+                    WHERE (i.base64 IS NOT NULL OR i.blob_hash IS NOT NULL)
+                      AND NOT (i)-[:HAS_SCENE_VECTORIZATION_RUN]->(:GraphNode:SceneVectorizationRun)
+                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
+                    WITH i, s, coalesce(i.captured_at, i.occurred_at, s.occurred_at, "") AS observed_at
+                    RETURN i.id, i.mime, coalesce(i.base64, 'blob:' + i.blob_hash), i.captured_at, i.occurred_at, s.id
+                    ORDER BY observed_at DESC
+                    LIMIT 1
+                "#
+                .into(),
+                parameters: json!({}),
+            },
+            "finding latest unprocessed image frame for scene vectorization",
+        )
+        .await?;
+        self.first_image_frame(&rows).await
+    }
+
+    /// Return the latest `Image` graph node that has no image-description run.
+    pub async fn latest_unprocessed_image_frame_for_description(
+        &self,
+    ) -> Result<Option<GraphImageFrame>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (i:GraphNode:Image)
+                    WHERE (i.base64 IS NOT NULL OR i.blob_hash IS NOT NULL)
+                      AND NOT (i)-[:HAS_IMAGE_DESCRIPTION_RUN]->(:GraphNode:ImageDescriptionRun)
+                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
+                    WITH i, s, coalesce(i.captured_at, i.occurred_at, s.occurred_at, "") AS observed_at
+                    RETURN i.id, i.mime, coalesce(i.base64, 'blob:' + i.blob_hash), i.captured_at, i.occurred_at, s.id
+                    ORDER BY observed_at DESC
+                    LIMIT 1
+                "#
+                .into(),
+                parameters: json!({}),
+            },
+            "finding latest unprocessed image frame for description",
+        )
+        .await?;
+        self.first_image_frame(&rows).await
+    }
+
+    /// Return the latest `Image` graph node, whether or not it has already
+    /// been processed by derived vision pipelines.
+    pub async fn latest_image_frame(&self) -> Result<Option<GraphImageFrame>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (i:GraphNode:Image)
+                    WHERE (i.base64 IS NOT NULL OR i.blob_hash IS NOT NULL)
+                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
+                    WITH i, s, coalesce(i.captured_at, i.occurred_at, s.occurred_at, "") AS observed_at
+                    RETURN i.id, i.mime, coalesce(i.base64, 'blob:' + i.blob_hash), i.captured_at, i.occurred_at, s.id
+                    ORDER BY observed_at DESC
+                    LIMIT 1
+                "#
+                .into(),
+                parameters: json!({}),
+            },
+            "finding latest image frame",
+        )
+        .await?;
+        self.first_image_frame(&rows).await
+    }
+
+    /// Return the stored `Image` graph node with `image_id`, if it still has
+    /// a payload.
+    pub async fn image_frame(&self, image_id: &str) -> Result<Option<GraphImageFrame>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (i:GraphNode:Image {id: $image_id})
+                    WHERE (i.base64 IS NOT NULL OR i.blob_hash IS NOT NULL)
+                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
+                    RETURN i.id, i.mime, coalesce(i.base64, 'blob:' + i.blob_hash), i.captured_at, i.occurred_at, s.id
+                    LIMIT 1
+                "#
+                .into(),
+                parameters: json!({ "image_id": image_id }),
+            },
+            "loading image frame",
+        )
+        .await?;
+        self.first_image_frame(&rows).await
+    }
+
+    /// Return the faces and objects located in the `Image` with `image_id`.
+    pub async fn image_regions(&self, image_id: &str) -> Result<Vec<GraphImageRegion>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (i:GraphNode:Image {id: $image_id})-[:CONTAINS_FACE|CONTAINS_OBJECT]->(region:GraphNode)
+                    WHERE region.bbox_x IS NOT NULL
+                    OPTIONAL MATCH (region)-[:PART_OF_TRACK]->(track:GraphNode:FaceTrack)
+                    OPTIONAL MATCH (region)-[:MATCHED_FACE]->(:GraphNode:Face)-[:HAS_IDENTITY]->(identity:GraphNode:Identity)
+                    WITH region, track, head(collect(identity.name)) AS identity_name
+                    RETURN region.id,
+                           CASE WHEN region:FaceInstance THEN "face" ELSE "object" END,
+                           coalesce(identity_name, track.identity_name, region.object_label),
+                           track.id,
+                           region.bbox_x, region.bbox_y, region.bbox_width, region.bbox_height
+                    ORDER BY region.id
+                "#
+                .into(),
+                parameters: json!({ "image_id": image_id }),
+            },
+            "finding image regions",
+        )
+        .await?;
+        rows.iter().map(graph_image_region_from_row).collect()
+    }
+
+    /// Store the answer to a question about part of `frame` as a sensation
+    /// derived from it, returning the sensation id.
+    pub async fn attach_look_answer(
+        &self,
+        frame: &GraphImageFrame,
+        look: &GraphLookAnswer,
+    ) -> Result<String> {
+        let occurred_at = chrono::Utc::now().to_rfc3339();
+        let sensation_id = stable_bytes_id(
+            "sensation:look_at",
+            format!("{}:{occurred_at}:{}", frame.id, look.question).as_bytes(),
+        );
+        let mut nodes = vec![
+            json!({
+                "label": "Image",
+                "id": frame.id,
+            }),
+            json!({
+                "label": "Sensation",
+                "id": sensation_id,
+                "kind": "look_at",
+                "derived": true,
+                "occurred_at": occurred_at,
+                "how": crate::prompt::look_at_sensation_text(&look.region, &look.answer),
+                "question": look.question,
+                "answer": look.answer,
+                "model": look.model,
+                "region": look.region,
+                "bbox_x": look.bbox.x,
+                "bbox_y": look.bbox.y,
+                "bbox_width": look.bbox.width,
+                "bbox_height": look.bbox.height,
+                "source_image_id": frame.id,
+                "source_sensation_ids": frame.sensation_id.clone().into_iter().collect::<Vec<_>>(),
+            }),
+        ];
+        let mut relationships = vec![json!({
+            "from": sensation_id,
+            "to": frame.id,
+            "type": "DERIVED_FROM",
+        })];
+        if let Some(region_node_id) = &look.region_node_id {
+            relationships.push(json!({
+                "from": sensation_id,
+                "to": region_node_id,
+                "type": "OBSERVED",
+            }));
+        }
+        if let Some(source_id) = &frame.sensation_id {
+            nodes.push(json!({
+                "label": "Sensation",
+                "id": source_id,
+            }));
+            relationships.push(json!({
+                "from": sensation_id,
+                "to": source_id,
+                "type": "DERIVED_FROM",
+            }));
+        }
+        self.store_data(&json!({
+            "op": "merge_graph",
+            "nodes": nodes,
+            "relationships": relationships,
+        }))
+        .await?;
+        Ok(sensation_id)
+    }
+
+    /// Return the scene-change state of an `Image` graph node.
+    pub async fn scene_frame(&self, image_id: &str) -> Result<Option<GraphSceneFrame>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (i:GraphNode:Image {id: $image_id})
+                    OPTIONAL MATCH (i)-[:DUPLICATE_OF]->(k:GraphNode:Image)
+                    OPTIONAL MATCH (i)-[:HAS_SCENE_VECTOR]->(v:GraphNode:Vector)
+                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
+                    RETURN i.id, i.scene_hash, i.scene_thumbnail, k.id, v.point_id,
+                           coalesce(i.captured_at, i.occurred_at, s.occurred_at)
+                    LIMIT 1
+                "#
+                .into(),
+                parameters: json!({
+                    "image_id": image_id,
+                }),
+            },
+            "loading image scene state",
+        )
+        .await?;
+        rows.first().map(graph_scene_frame_from_row).transpose()
+    }
+
+    /// Return the latest keyframe other than the `Image` node `image_id`
+    /// observed no later than `before`.
+    ///
+    /// Keyframes are found through their indexed `keyframe_at_ms`, set by
+    /// [`attach_scene_fingerprint`](Self::attach_scene_fingerprint).
+    pub async fn previous_scene_keyframe(
+        &self,
+        image_id: &str,
+        before: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<GraphSceneFrame>> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (i:GraphNode:Image)
+                    WHERE i.keyframe_at_ms <= $before_ms
+                      AND i.id <> $image_id
+                    WITH i
+                    ORDER BY i.keyframe_at_ms DESC
+                    LIMIT 1
+                    OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
+                    OPTIONAL MATCH (i)-[:HAS_SCENE_VECTOR]->(v:GraphNode:Vector)
+                    RETURN i.id, i.scene_hash, i.scene_thumbnail, null, v.point_id,
+                           coalesce(i.captured_at, i.occurred_at, s.occurred_at, "")
+                    LIMIT 1
+                "#
+                .into(),
+                parameters: json!({
+                    "image_id": image_id,
+                    "before_ms": before.timestamp_millis(),
+                }),
+            },
+            "finding previous scene keyframe",
+        )
+        .await?;
+        rows.first().map(graph_scene_frame_from_row).transpose()
+    }
+
+    /// Store the scene fingerprint of an `Image` node and, for a duplicate,
+    /// link it to its keyframe with `DUPLICATE_OF`. A keyframe with a known
+    /// observation time gets `keyframe_at_ms` so later frames can find it.
+    pub async fn attach_scene_fingerprint(
+        &self,
+        frame: &GraphImageFrame,
+        fingerprint: &SceneFingerprint,
+        duplicate: Option<&GraphSceneDuplicate>,
+    ) -> Result<()> {
+        let mut nodes = vec![json!({
+            "label": "Image",
+            "id": frame.id,
+            "scene_hash": fingerprint.hash_hex(),
+            "scene_thumbnail": fingerprint.thumbnail_base64(),
+            "scene_fingerprinted_at": chrono::Utc::now().to_rfc3339(),
+        })];
+        if let (None, Some(at)) = (duplicate, frame.observed_at()) {
+            nodes[0]["keyframe_at_ms"] = json!(at.timestamp_millis());
+        }
+        let mut relationships = Vec::new();
+        if let Some(duplicate) = duplicate {
+            nodes.push(json!({
+                "label": "Image",
+                "id": duplicate.keyframe_id,
+            }));
+            relationships.push(json!({
+                "from": frame.id,
+                "to": duplicate.keyframe_id,
+                "type": "DUPLICATE_OF",
+                "hash_distance": duplicate.hash_distance,
+                "difference": duplicate.difference,
+                "scene_similarity": duplicate.scene_similarity,
+            }));
+        }
+
+        self.store_data(&json!({
+            "op": "merge_graph",
+            "nodes": nodes,
+            "relationships": relationships,
+        }))
+        .await
+    }
+
+    /// Record a `kind` run for a duplicate frame that reuses the run of the
+    /// keyframe, or of another duplicate of it, sharing the run's results.
+    ///
+    /// Returns `false` when no frame of the scene has such a run yet.
+    pub async fn reuse_image_run(
+        &self,
+        frame: &GraphImageFrame,
+        kind: ImageRunKind,
+        keyframe_id: &str,
+    ) -> Result<bool> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: format!(
+                    r#"
+                    MATCH (keyframe:GraphNode:Image {{id: $keyframe_id}})
+                    MATCH (source:GraphNode:Image)-[:{relationship}]->(run:GraphNode:{label})
+                    WHERE (source = keyframe OR (source)-[:DUPLICATE_OF]->(keyframe))
+                      AND run.reused_run_id IS NULL
+                    WITH source, run
+                    ORDER BY run.processed_at DESC
+                    LIMIT 1
+                    OPTIONAL MATCH (source)-[result]->(product:GraphNode)
+                    WHERE type(result) IN $shared_results
+                    RETURN run.id, collect(CASE WHEN product IS NULL THEN NULL ELSE [type(result), product.id] END)
+                "#,
+                    relationship = kind.run_relationship(),
+                    label = kind.run_label(),
+                ),
+                parameters: json!({
+                    "keyframe_id": keyframe_id,
+                    "shared_results": kind.shared_results(),
+                }),
             },
-            "finding latest image frame",
+            "finding reusable image run",
         )
         .await?;
-        rows.first().map(graph_image_frame_from_row).transpose()
+        let Some(values) = rows.first().and_then(Value::as_array) else {
+            return Ok(false);
+        };
+        let source_run_id = row_string(values, 0, "run id")?;
+
+        let run_id = kind.run_id(&frame.id);
+        let mut nodes = vec![
+            json!({
+                "label": "Image",
+                "id": frame.id,
+            }),
+            json!({
+                "label": kind.run_label(),
+                "id": run_id,
+                "image_id": frame.id,
+                "processed_at": chrono::Utc::now().to_rfc3339(),
+                "reused_run_id": source_run_id,
+                "duplicate_of": keyframe_id,
+            }),
+        ];
+        let mut relationships = vec![
+            json!({
+                "from": frame.id,
+                "to": run_id,
+                "type": kind.run_relationship(),
+            }),
+            json!({
+                "from": run_id,
+                "to": frame.id,
+                "type": "PROCESSED_IMAGE",
+            }),
+            json!({
+                "from": run_id,
+                "to": source_run_id,
+                "type": "REUSED",
+            }),
+        ];
+        for result in values
+            .get(1)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let (Some(relationship), Some(product_id)) = (
+                result.get(0).and_then(Value::as_str),
+                result.get(1).and_then(Value::as_str),
+            ) else {
+                continue;
+            };
+            relationships.push(json!({
+                "from": frame.id,
+                "to": product_id,
+                "type": relationship,
+                "reused_run_id": source_run_id,
+            }));
+        }
+        if let Some(sensation_id) = &frame.sensation_id {
+            nodes.push(json!({
+                "label": "Sensation",
+                "id": sensation_id,
+            }));
+            relationships.push(json!({
+                "from": sensation_id,
+                "to": run_id,
+                "type": "PRODUCED",
+            }));
+        }
+
+        self.store_data(&json!({
+            "op": "merge_graph",
+            "nodes": nodes,
+            "relationships": relationships,
+        }))
+        .await?;
+        Ok(true)
     }
 
     /// Return the latest `Geolocation` graph node that has no geolocation vector.
@@ -2490,13 +4952,13 @@ impl Neo4jClient {
             CypherStatement {
                 statement: r#"
                     MATCH (i:GraphNode:Image)
-                    WHERE i.base64 IS NOT NULL
+                    WHERE (i.base64 IS NOT NULL OR i.blob_hash IS NOT NULL)
                     OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
                     WITH i, s, coalesce(i.captured_at, i.occurred_at, s.occurred_at, "") AS observed_at
                     WHERE observed_at <> ""
                       AND datetime(observed_at) >= datetime($from)
                       AND datetime(observed_at) <= datetime($to)
-                    RETURN i.id, i.mime, i.base64, i.captured_at, observed_at, s.id
+                    RETURN i.id, i.mime, coalesce(i.base64, 'blob:' + i.blob_hash), i.captured_at, observed_at, s.id
                     ORDER BY datetime(observed_at) ASC, i.id
                 "#
                 .into(),
@@ -2508,7 +4970,14 @@ impl Neo4jClient {
             "loading movie image frames",
         )
         .await?;
-        rows.iter().map(graph_movie_image_frame_from_row).collect()
+        let mut frames = rows
+            .iter()
+            .map(graph_movie_image_frame_from_row)
+            .collect::<Result<Vec<_>>>()?;
+        for frame in &mut frames {
+            self.load_payload(&mut frame.image.base64).await?;
+        }
+        Ok(frames)
     }
 
     /// Return the latest image frame before the requested movie export range.
@@ -2525,12 +4994,12 @@ impl Neo4jClient {
             CypherStatement {
                 statement: r#"
                     MATCH (i:GraphNode:Image)
-                    WHERE i.base64 IS NOT NULL
+                    WHERE (i.base64 IS NOT NULL OR i.blob_hash IS NOT NULL)
                     OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)
                     WITH i, s, coalesce(i.captured_at, i.occurred_at, s.occurred_at, "") AS observed_at
                     WHERE observed_at <> ""
                       AND datetime(observed_at) < datetime($before)
-                    RETURN i.id, i.mime, i.base64, i.captured_at, observed_at, s.id
+                    RETURN i.id, i.mime, coalesce(i.base64, 'blob:' + i.blob_hash), i.captured_at, observed_at, s.id
                     ORDER BY datetime(observed_at) DESC, i.id
                     LIMIT 1
                 "#
@@ -2542,9 +5011,15 @@ impl Neo4jClient {
             "loading latest movie image frame before range",
         )
         .await?;
-        rows.first()
+        let Some(mut frame) = rows
+            .first()
             .map(graph_movie_image_frame_from_row)
-            .transpose()
+            .transpose()?
+        else {
+            return Ok(None);
+        };
+        self.load_payload(&mut frame.image.base64).await?;
+        Ok(Some(frame))
     }
 
     /// Return speech segments overlapping the requested movie export range.
@@ -2871,7 +5346,7 @@ impl Neo4jClient {
                         coalesce(s.text, ""),
                         a.id,
                         a.mime,
-                        a.base64,
+                        coalesce(a.base64, 'blob:' + a.blob_hash),
                         a.sample_rate,
                         a.channels,
                         clip_start_ms,
@@ -2887,9 +5362,15 @@ impl Neo4jClient {
             "loading graph speech segment audio",
         )
         .await?;
-        rows.first()
+        let Some(mut segment) = rows
+            .first()
             .map(graph_speech_segment_audio_from_row)
-            .transpose()
+            .transpose()?
+        else {
+            return Ok(None);
+        };
+        self.load_payload(&mut segment.base64).await?;
+        Ok(Some(segment))
     }
 
     /// Return human-readable graph items represented by vector points.
@@ -3771,7 +6252,8 @@ impl Neo4jClient {
             &self.pass,
             CypherStatement {
                 statement: r#"
-                    MATCH (n:GraphNode:Sensation)
+                    MATCH (n:GraphNode)
+                    WHERE n:Sensation OR n:SpeakerTurn
                     WITH n,
                         coalesce(n.how, "") AS text,
                         coalesce(n.source_ended_at, n.source_started_at, n.source_captured_at, n.observed_at, n.captured_at, n.occurred_at, n.timestamp, "") AS occurred_at,
@@ -3781,6 +6263,7 @@ impl Neo4jClient {
                       AND ($start IS NULL OR datetime(occurred_at) >= datetime($start))
                       AND datetime(occurred_at) <= datetime($end)
                       AND (
+                          (n:SpeakerTurn AND text STARTS WITH "I heard ") OR
                           text = "I hear silence." OR
                           text STARTS WITH "I heard: " OR
                           text STARTS WITH "I hear someone on my web interface type: " OR
@@ -4354,7 +6837,7 @@ impl Neo4jClient {
                 }
             ],
         });
-        let mut statements = graph_statements(&graph)?;
+        let mut statements = graph_statements(&*self.offload_payloads(&graph).await?)?;
         statements.push(CypherStatement {
             statement: r#"
                 MATCH (t:GraphNode:Transcription {id: $transcription_id})-[:HAS_SEGMENT]->(segment:GraphNode:SpeechSegment)
@@ -4453,6 +6936,80 @@ impl Neo4jClient {
         frame: &GraphImageFrame,
         detector: &str,
         detections: &[GraphFaceDetection],
+    ) -> Result<()> {
+        self.attach_face_recognition_run(frame, detector, detections, false)
+            .await
+    }
+
+    /// Attach face recognition results for tracked faces.
+    ///
+    /// Faces continuing a track are linked to it without new sensations;
+    /// a track starting produces a `face_track_entered` sensation, and the
+    /// frame's face-count sensation is only written when some face starts
+    /// a track or had its identity matched again.
+    pub async fn attach_tracked_face_recognition(
+        &self,
+        frame: &GraphImageFrame,
+        detector: &str,
+        detections: &[GraphFaceDetection],
+    ) -> Result<()> {
+        self.attach_face_recognition_run(frame, detector, detections, true)
+            .await
+    }
+
+    /// Record face tracks that ended with `face_track_left` sensations.
+    pub async fn attach_face_track_ends(&self, tracks: &[FaceTrack]) -> Result<()> {
+        if tracks.is_empty() {
+            return Ok(());
+        }
+        let mut nodes = Vec::new();
+        let mut relationships = Vec::new();
+        for track in tracks {
+            let ended_at = track.last_seen_at.to_rfc3339();
+            let sensation_id = stable_bytes_id("sensation:face_track_left", track.id.as_bytes());
+            let identity = track
+                .recognition
+                .as_ref()
+                .and_then(|matched| matched.identity.as_deref());
+            nodes.push(json!({
+                "label": "FaceTrack",
+                "id": track.id,
+                "started_at": track.started_at.to_rfc3339(),
+                "ended_at": ended_at,
+                "frames": track.frames,
+                "matched_face_id": track.recognition.as_ref().map(|matched| matched.face_id.clone()),
+                "identity_name": identity,
+            }));
+            nodes.push(json!({
+                "label": "Sensation",
+                "id": sensation_id,
+                "kind": "face_track_left",
+                "derived": true,
+                "occurred_at": ended_at,
+                "how": crate::prompt::face_track_left_sensation_text(identity),
+                "face_track_id": track.id,
+            }));
+            relationships.push(json!({
+                "from": sensation_id,
+                "to": track.id,
+                "type": "OBSERVED",
+            }));
+        }
+
+        self.store_data(&json!({
+            "op": "merge_graph",
+            "nodes": nodes,
+            "relationships": relationships,
+        }))
+        .await
+    }
+
+    async fn attach_face_recognition_run(
+        &self,
+        frame: &GraphImageFrame,
+        detector: &str,
+        detections: &[GraphFaceDetection],
+        tracked: bool,
     ) -> Result<()> {
         let processed_at = chrono::Utc::now().to_rfc3339();
         let run_id = format!("face-recognition:{}", frame.id);
@@ -4468,6 +7025,14 @@ impl Neo4jClient {
             .or_else(|| frame.occurred_at.clone())
             .unwrap_or_else(|| processed_at.clone());
         let source_sensation_ids = frame.sensation_id.clone().into_iter().collect::<Vec<_>>();
+        // Tracked faces continuing a track add nothing new to say.
+        let announced = |detection: &GraphFaceDetection| {
+            detection
+                .track
+                .as_ref()
+                .is_none_or(|track| track.started || track.matched)
+        };
+        let count_announced = !tracked || detections.iter().any(announced);
         let mut nodes = vec![
             json!({
                 "label": "Image",
@@ -4481,19 +7046,6 @@ impl Neo4jClient {
                 "processed_at": processed_at,
                 "face_count": face_count,
             }),
-            json!({
-                "label": "Sensation",
-                "id": recognition_sensation_id,
-                "kind": "face_recognition",
-                "derived": true,
-                "occurred_at": recognition_occurred_at,
-                "how": face_recognition_how(face_count),
-                "how_formed_at": processed_at,
-                "face_count": face_count,
-                "source_image_id": frame.id,
-                "face_recognition_run_id": run_id,
-                "source_sensation_ids": source_sensation_ids,
-            }),
         ];
         let mut relationships = vec![
             json!({
@@ -4506,22 +7058,37 @@ impl Neo4jClient {
                 "to": frame.id,
                 "type": "PROCESSED_IMAGE",
             }),
-            json!({
+        ];
+        if count_announced {
+            nodes.push(json!({
+                "label": "Sensation",
+                "id": recognition_sensation_id,
+                "kind": "face_recognition",
+                "derived": true,
+                "occurred_at": recognition_occurred_at,
+                "how": face_recognition_how(face_count),
+                "how_formed_at": processed_at,
+                "face_count": face_count,
+                "source_image_id": frame.id,
+                "face_recognition_run_id": run_id,
+                "source_sensation_ids": source_sensation_ids,
+            }));
+            relationships.push(json!({
                 "from": run_id,
                 "to": recognition_sensation_id,
                 "type": "PRODUCED",
-            }),
-            json!({
+            }));
+            relationships.push(json!({
                 "from": recognition_sensation_id,
                 "to": run_id,
                 "type": "OBSERVED",
-            }),
-            json!({
+            }));
+            relationships.push(json!({
                 "from": recognition_sensation_id,
                 "to": frame.id,
                 "type": "DERIVED_FROM",
-            }),
-        ];
+            }));
+        }
 
         if let Some(sensation_id) = &frame.sensation_id {
             nodes.push(json!({
@@ -4533,76 +7100,47 @@ impl Neo4jClient {
                 "to": run_id,
                 "type": "PRODUCED",
             }));
-            relationships.push(json!({
-                "from": sensation_id,
-                "to": recognition_sensation_id,
-                "type": "PRODUCED",
-            }));
-            relationships.push(json!({
-                "from": recognition_sensation_id,
-                "to": sensation_id,
-                "type": "DERIVED_FROM",
-            }));
+            if count_announced {
+                relationships.push(json!({
+                    "from": sensation_id,
+                    "to": recognition_sensation_id,
+                    "type": "PRODUCED",
+                }));
+                relationships.push(json!({
+                    "from": recognition_sensation_id,
+                    "to": sensation_id,
+                    "type": "DERIVED_FROM",
+                }));
+            }
         }
 
         for detection in detections {
             let vector_id = qdrant_vector_node_id(FACE_COLLECTION, &detection.vector_id);
-            let identity_sensation_id = stable_bytes_id(
-                "sensation:face_identity",
-                format!(
-                    "{}:{}:{}",
-                    run_id,
-                    detection.face_id,
-                    face_match_key(detection.recognition.as_ref())
-                )
-                .as_bytes(),
-            );
-            let identity_how = face_identity_how(detection.recognition.as_ref());
-            nodes.push(json!({
+            let occurred_at = detection
+                .crop
+                .captured_at
+                .clone()
+                .or_else(|| frame.occurred_at.clone());
+            let mut face_node = json!({
                 "label": "FaceInstance",
                 "id": detection.face_id,
                 "source_image_id": frame.id,
                 "crop_mime": detection.crop.mime.clone(),
                 "crop_base64": detection.crop.base64.clone(),
                 "captured_at": detection.crop.captured_at.clone(),
-                "occurred_at": detection
-                    .crop
-                    .captured_at
-                    .clone()
-                    .or_else(|| frame.occurred_at.clone()),
+                "occurred_at": occurred_at,
                 "detection_index": detection.index,
                 "embedding_len": detection.embedding_len,
                 "recognized_at": processed_at,
-            }));
+            });
+            detection.details.add_graph_properties(&mut face_node);
+            nodes.push(face_node);
             nodes.push(qdrant_vector_node(
                 FACE_COLLECTION,
                 &detection.vector_id,
                 "face_instance",
                 Some(detector),
             ));
-            nodes.push(json!({
-                "label": "Sensation",
-                "id": identity_sensation_id,
-                "kind": "face_identity",
-                "derived": true,
-                "occurred_at": detection
-                    .crop
-                    .captured_at
-                    .clone()
-                    .or_else(|| frame.occurred_at.clone())
-                    .unwrap_or_else(|| processed_at.clone()),
-                "how": identity_how,
-                "how_formed_at": processed_at,
-                "source_image_id": frame.id,
-                "face_instance_id": detection.face_id,
-                "face_recognition_run_id": run_id,
-                "matched": detection.recognition.is_some(),
-                "matched_face_id": detection.recognition.as_ref().map(|matched| matched.face_id.clone()),
-                "identity_name": detection.recognition.as_ref().and_then(|matched| matched.identity.clone()),
-                "nearest_face_vector_id": detection.recognition.as_ref().map(|matched| matched.nearest_vector_id.clone()),
-                "nearest_face_score": detection.recognition.as_ref().map(|matched| matched.score),
-                "source_sensation_ids": source_sensation_ids.clone(),
-            }));
             relationships.push(json!({
                 "from": run_id,
                 "to": detection.face_id,
@@ -4629,6 +7167,75 @@ impl Neo4jClient {
                 "to": vector_id,
                 "type": "PRODUCED",
             }));
+            if let Some(matched) = &detection.recognition {
+                nodes.push(json!({
+                    "label": "Face",
+                    "labels": ["Cluster"],
+                    "id": matched.face_id,
+                }));
+                relationships.push(json!({
+                    "from": detection.face_id,
+                    "to": matched.face_id,
+                    "type": "MATCHED_FACE",
+                    "score": matched.score,
+                }));
+            }
+            if let Some(sensation_id) = &frame.sensation_id {
+                relationships.push(json!({
+                    "from": sensation_id,
+                    "to": detection.face_id,
+                    "type": "PRODUCED",
+                }));
+                relationships.push(json!({
+                    "from": sensation_id,
+                    "to": vector_id,
+                    "type": "PRODUCED",
+                }));
+            }
+            if let Some(track) = &detection.track {
+                face_track_graph(
+                    frame,
+                    &run_id,
+                    detection,
+                    track,
+                    occurred_at.as_deref().unwrap_or(&processed_at),
+                    &mut nodes,
+                    &mut relationships,
+                );
+            }
+            if !announced(detection) {
+                continue;
+            }
+
+            let identity_sensation_id = stable_bytes_id(
+                "sensation:face_identity",
+                format!(
+                    "{}:{}:{}",
+                    run_id,
+                    detection.face_id,
+                    face_match_key(detection.recognition.as_ref())
+                )
+                .as_bytes(),
+            );
+            let identity_how = face_identity_how(detection.recognition.as_ref());
+            nodes.push(json!({
+                "label": "Sensation",
+                "id": identity_sensation_id,
+                "kind": "face_identity",
+                "derived": true,
+                "occurred_at": occurred_at.clone().unwrap_or_else(|| processed_at.clone()),
+                "how": identity_how,
+                "how_formed_at": processed_at,
+                "source_image_id": frame.id,
+                "face_instance_id": detection.face_id,
+                "face_recognition_run_id": run_id,
+                "matched": detection.recognition.is_some(),
+                "matched_face_id": detection.recognition.as_ref().map(|matched| matched.face_id.clone()),
+                "identity_name": detection.recognition.as_ref().and_then(|matched| matched.identity.clone()),
+                "nearest_face_vector_id": detection.recognition.as_ref().map(|matched| matched.nearest_vector_id.clone()),
+                "nearest_face_score": detection.recognition.as_ref().map(|matched| matched.score),
+                "source_sensation_ids": source_sensation_ids.clone(),
+            }));
             relationships.push(json!({
                 "from": run_id,
                 "to": identity_sensation_id,
@@ -4647,23 +7254,12 @@ impl Neo4jClient {
             if let Some(matched) = &detection.recognition {
                 let nearest_vector_id =
                     qdrant_vector_node_id(FACE_COLLECTION, &matched.nearest_vector_id);
-                nodes.push(json!({
-                    "label": "Face",
-                    "labels": ["Cluster"],
-                    "id": matched.face_id,
-                }));
                 nodes.push(qdrant_vector_node(
                     FACE_COLLECTION,
                     &matched.nearest_vector_id,
                     "face_instance",
                     None,
                 ));
-                relationships.push(json!({
-                    "from": detection.face_id,
-                    "to": matched.face_id,
-                    "type": "MATCHED_FACE",
-                    "score": matched.score,
-                }));
                 relationships.push(json!({
                     "from": identity_sensation_id,
                     "to": matched.face_id,
@@ -4678,16 +7274,6 @@ impl Neo4jClient {
                 }));
             }
             if let Some(sensation_id) = &frame.sensation_id {
-                relationships.push(json!({
-                    "from": sensation_id,
-                    "to": detection.face_id,
-                    "type": "PRODUCED",
-                }));
-                relationships.push(json!({
-                    "from": sensation_id,
-                    "to": vector_id,
-                    "type": "PRODUCED",
-                }));
                 relationships.push(json!({
                     "from": sensation_id,
                     "to": identity_sensation_id,
@@ -4792,6 +7378,236 @@ impl Neo4jClient {
         .await
     }
 
+    /// Record an object-detection run over an existing `Image` graph node.
+    ///
+    /// The detected objects are stored beforehand as object sensations; this
+    /// links them to the run and to the sensation that observed the frame.
+    pub async fn attach_object_detection(
+        &self,
+        frame: &GraphImageFrame,
+        detector: &str,
+        detections: &[GraphObjectDetection],
+    ) -> Result<()> {
+        let processed_at = chrono::Utc::now().to_rfc3339();
+        let run_id = format!("object-detection:{}", frame.id);
+        let labels = detections
+            .iter()
+            .filter_map(|detection| detection.label.clone())
+            .collect::<Vec<_>>();
+        let mut nodes = vec![
+            json!({
+                "label": "Image",
+                "id": frame.id,
+            }),
+            json!({
+                "label": "ObjectDetectionRun",
+                "id": run_id,
+                "image_id": frame.id,
+                "detector": detector,
+                "processed_at": processed_at,
+                "object_count": detections.len(),
+                "object_labels": labels,
+            }),
+        ];
+        let mut relationships = vec![
+            json!({
+                "from": frame.id,
+                "to": run_id,
+                "type": "HAS_OBJECT_DETECTION_RUN",
+            }),
+            json!({
+                "from": run_id,
+                "to": frame.id,
+                "type": "PROCESSED_IMAGE",
+            }),
+        ];
+        if let Some(sensation_id) = &frame.sensation_id {
+            nodes.push(json!({
+                "label": "Sensation",
+                "id": sensation_id,
+            }));
+            relationships.push(json!({
+                "from": sensation_id,
+                "to": run_id,
+                "type": "PRODUCED",
+            }));
+        }
+
+        for detection in detections {
+            relationships.push(json!({
+                "from": run_id,
+                "to": detection.object_id,
+                "type": "DETECTED_OBJECT",
+                "detection_index": detection.index,
+                "confidence": detection.confidence,
+            }));
+            relationships.push(json!({
+                "from": run_id,
+                "to": detection.sensation_id,
+                "type": "PRODUCED",
+            }));
+            if let Some(sensation_id) = &frame.sensation_id {
+                relationships.push(json!({
+                    "from": sensation_id,
+                    "to": detection.sensation_id,
+                    "type": "PRODUCED",
+                }));
+                relationships.push(json!({
+                    "from": detection.sensation_id,
+                    "to": sensation_id,
+                    "type": "DERIVED_FROM",
+                }));
+            }
+        }
+
+        self.store_data(&json!({
+            "op": "merge_graph",
+            "nodes": nodes,
+            "relationships": relationships,
+        }))
+        .await
+    }
+
+    /// Record a text-recognition run over an existing `Image` graph node.
+    ///
+    /// Newly sensed text is stored beforehand as text sensations; this links
+    /// every reading to the run and the frame, including repeats of text read
+    /// recently, and moves each `TextBlock`'s `last_read_at` forward to
+    /// `read_at`; a frame processed late never moves it back.
+    pub async fn attach_text_recognition(
+        &self,
+        frame: &GraphImageFrame,
+        engine: &str,
+        read_at: &str,
+        readings: &[GraphTextReading],
+    ) -> Result<()> {
+        let endpoint = self.http_endpoint()?;
+        let client = reqwest::Client::new();
+        self.ensure_constraint(&client, &endpoint).await?;
+        let processed_at = chrono::Utc::now().to_rfc3339();
+        let run_id = ImageRunKind::TextRecognition.run_id(&frame.id);
+        let texts = readings
+            .iter()
+            .map(|reading| reading.text.clone())
+            .collect::<Vec<_>>();
+        let mut nodes = vec![
+            json!({
+                "label": "Image",
+                "id": frame.id,
+            }),
+            json!({
+                "label": "TextRecognitionRun",
+                "id": run_id,
+                "image_id": frame.id,
+                "engine": engine,
+                "processed_at": processed_at,
+                "text_count": readings.len(),
+                "texts": texts,
+            }),
+        ];
+        let mut relationships = vec![
+            json!({
+                "from": frame.id,
+                "to": run_id,
+                "type": "HAS_TEXT_RECOGNITION_RUN",
+            }),
+            json!({
+                "from": run_id,
+                "to": frame.id,
+                "type": "PROCESSED_IMAGE",
+            }),
+        ];
+        if let Some(sensation_id) = &frame.sensation_id {
+            nodes.push(json!({
+                "label": "Sensation",
+                "id": sensation_id,
+            }));
+            relationships.push(json!({
+                "from": sensation_id,
+                "to": run_id,
+                "type": "PRODUCED",
+            }));
+        }
+
+        for reading in readings {
+            nodes.push(json!({
+                "label": "TextBlock",
+                "id": reading.text_id,
+            }));
+            relationships.push(json!({
+                "from": run_id,
+                "to": reading.text_id,
+                "type": "READ_TEXT",
+                "reading_index": reading.index,
+                "confidence": reading.confidence,
+                "repeat": reading.sensation_id.is_none(),
+            }));
+            relationships.push(json!({
+                "from": frame.id,
+                "to": reading.text_id,
+                "type": "CONTAINS_TEXT",
+                "confidence": reading.confidence,
+                "bbox_x": reading.bbox.map(|b| b.x),
+                "bbox_y": reading.bbox.map(|b| b.y),
+                "bbox_width": reading.bbox.map(|b| b.width),
+                "bbox_height": reading.bbox.map(|b| b.height),
+            }));
+            let Some(text_sensation_id) = &reading.sensation_id else {
+                continue;
+            };
+            relationships.push(json!({
+                "from": run_id,
+                "to": text_sensation_id,
+                "type": "PRODUCED",
+            }));
+            if let Some(sensation_id) = &frame.sensation_id {
+                relationships.push(json!({
+                    "from": sensation_id,
+                    "to": text_sensation_id,
+                    "type": "PRODUCED",
+                }));
+                relationships.push(json!({
+                    "from": text_sensation_id,
+                    "to": sensation_id,
+                    "type": "DERIVED_FROM",
+                }));
+            }
+        }
+
+        let mut statements = graph_statements(&json!({
+            "op": "merge_graph",
+            "nodes": nodes,
+            "relationships": relationships,
+        }))?;
+        statements.push(CypherStatement {
+            statement: r#"
+                UNWIND $text_ids AS text_id
+                MATCH (t:GraphNode:TextBlock {id: text_id})
+                SET t.last_read_at = CASE
+                    WHEN coalesce(t.last_read_at, '') < $read_at THEN $read_at
+                    ELSE t.last_read_at
+                END
+            "#
+            .into(),
+            parameters: json!({
+                "text_ids": readings
+                    .iter()
+                    .map(|reading| reading.text_id.clone())
+                    .collect::<Vec<_>>(),
+                "read_at": read_at,
+            }),
+        });
+        commit_neo4j_statements(
+            &client,
+            &endpoint,
+            &self.user,
+            &self.pass,
+            &statements,
+            "attaching text recognition",
+        )
+        .await
+    }
+
     /// Attach an LLM image description and its text embedding to an existing `Image`.
     pub async fn attach_image_description(
         &self,
@@ -5734,14 +8550,153 @@ impl Neo4jClient {
 
         if let Some(sensation_id) = &clip.sensation_id {
             nodes.push(json!({
-                "label": "Sensation",
-                "id": sensation_id,
+                "label": "Sensation",
+                "id": sensation_id,
+            }));
+            relationships.push(json!({
+                "from": sensation_id,
+                "to": run_id,
+                "type": "PRODUCED",
+            }));
+        }
+
+        self.store_data(&json!({
+            "op": "merge_graph",
+            "nodes": nodes,
+            "relationships": relationships,
+        }))
+        .await
+    }
+
+    /// Attach speaker diarization results to a big transcription.
+    ///
+    /// Each speaker becomes a `Speaker` node linked to its matched voice with
+    /// `SAME_VOICE_AS`. Segments get `SPOKEN_BY` relationships and a `speaker`
+    /// property, and each turn becomes a `SpeakerTurn` whose `how` text lets
+    /// conversation timelines say who spoke.
+    pub async fn attach_speaker_diarization(
+        &self,
+        transcription_id: &str,
+        model: &str,
+        diarization: &GraphDiarization,
+    ) -> Result<()> {
+        let processed_at = chrono::Utc::now().to_rfc3339();
+        let run_id = format!("diarization:{transcription_id}");
+        let speaker_id = |index: usize| format!("{run_id}:speaker:{index}");
+        let mut nodes = vec![
+            json!({
+                "label": "Transcription",
+                "id": transcription_id,
+            }),
+            json!({
+                "label": "DiarizationRun",
+                "id": run_id,
+                "transcription_id": transcription_id,
+                "model": model,
+                "processed_at": processed_at,
+                "status": "diarized",
+                "speaker_count": diarization.speakers.len(),
+            }),
+        ];
+        let mut relationships = vec![
+            json!({
+                "from": transcription_id,
+                "to": run_id,
+                "type": "HAS_DIARIZATION_RUN",
+            }),
+            json!({
+                "from": run_id,
+                "to": transcription_id,
+                "type": "PROCESSED_TRANSCRIPTION",
+            }),
+        ];
+        for speaker in &diarization.speakers {
+            let id = speaker_id(speaker.index);
+            nodes.push(json!({
+                "label": "Speaker",
+                "id": id,
+                "transcription_id": transcription_id,
+                "speaker_index": speaker.index,
+                "name": speaker.name(),
+                "voice_id": speaker.voice.as_ref().map(|voice| &voice.voice_id),
+                "identity": speaker.voice.as_ref().and_then(|voice| voice.identity.as_ref()),
+                "window_count": speaker.window_count,
+            }));
+            relationships.push(json!({
+                "from": run_id,
+                "to": id,
+                "type": "FOUND_SPEAKER",
+            }));
+            if let Some(voice) = &speaker.voice {
+                relationships.push(json!({
+                    "from": id,
+                    "to": voice.voice_id,
+                    "type": "SAME_VOICE_AS",
+                    "score": speaker.score,
+                }));
+            }
+        }
+        let speaker_name = |index: usize| {
+            diarization
+                .speakers
+                .iter()
+                .find(|speaker| speaker.index == index)
+                .map(GraphDiarizedSpeaker::name)
+        };
+        for attribution in &diarization.attributions {
+            let Some(name) = speaker_name(attribution.speaker) else {
+                continue;
+            };
+            nodes.push(json!({
+                "label": "SpeechSegment",
+                "id": attribution.segment_id,
+                "speaker": name,
+                "speaker_id": speaker_id(attribution.speaker),
+                "speaker_confidence": attribution.confidence,
             }));
             relationships.push(json!({
-                "from": sensation_id,
-                "to": run_id,
-                "type": "PRODUCED",
+                "from": attribution.segment_id,
+                "to": speaker_id(attribution.speaker),
+                "type": "SPOKEN_BY",
+                "confidence": attribution.confidence,
+            }));
+        }
+        for (index, turn) in diarization.turns.iter().enumerate() {
+            let Some(name) = speaker_name(turn.speaker) else {
+                continue;
+            };
+            let turn_id = format!("{run_id}:turn:{index}");
+            nodes.push(json!({
+                "label": "SpeakerTurn",
+                "id": turn_id,
+                "transcription_id": transcription_id,
+                "turn_index": index,
+                "speaker": name,
+                "text": turn.text,
+                "how": format!("I heard {name} say: {}", turn.text),
+                "start_ms": turn.start_ms,
+                "end_ms": turn.end_ms,
+                "occurred_at": turn.occurred_at,
+                "ended_at": turn.ended_at,
+            }));
+            relationships.push(json!({
+                "from": transcription_id,
+                "to": turn_id,
+                "type": "HAS_TURN",
+                "turn_index": index,
+            }));
+            relationships.push(json!({
+                "from": turn_id,
+                "to": speaker_id(turn.speaker),
+                "type": "SPOKEN_BY",
             }));
+            for segment_id in &turn.segment_ids {
+                relationships.push(json!({
+                    "from": turn_id,
+                    "to": segment_id,
+                    "type": "INCLUDES_SEGMENT",
+                }));
+            }
         }
 
         self.store_data(&json!({
@@ -5752,6 +8707,48 @@ impl Neo4jClient {
         .await
     }
 
+    /// Mark a big transcription as diarized without attributions.
+    pub async fn attach_skipped_diarization(
+        &self,
+        transcription_id: &str,
+        model: &str,
+        reason: &str,
+    ) -> Result<()> {
+        let processed_at = chrono::Utc::now().to_rfc3339();
+        let run_id = format!("diarization:{transcription_id}");
+        self.store_data(&json!({
+            "op": "merge_graph",
+            "nodes": [
+                {
+                    "label": "Transcription",
+                    "id": transcription_id,
+                },
+                {
+                    "label": "DiarizationRun",
+                    "id": run_id,
+                    "transcription_id": transcription_id,
+                    "model": model,
+                    "processed_at": processed_at,
+                    "status": "skipped",
+                    "reason": reason,
+                },
+            ],
+            "relationships": [
+                {
+                    "from": transcription_id,
+                    "to": run_id,
+                    "type": "HAS_DIARIZATION_RUN",
+                },
+                {
+                    "from": run_id,
+                    "to": transcription_id,
+                    "type": "PROCESSED_TRANSCRIPTION",
+                },
+            ],
+        }))
+        .await
+    }
+
     /// Attach geolocation vectorization results to an existing `Geolocation` graph node.
     pub async fn attach_geolocation_vectorization(
         &self,
@@ -6054,7 +9051,8 @@ impl Neo4jClient {
 
     /// Store `data` in the graph database.
     pub async fn store_data(&self, data: &Value) -> Result<()> {
-        let statements = graph_statements(data)?;
+        let data = self.offload_payloads(data).await?;
+        let statements = graph_statements(&data)?;
         if statements.is_empty() {
             return Ok(());
         }
@@ -6080,14 +9078,224 @@ impl Neo4jClient {
         Ok(())
     }
 
+    /// Try to claim `kind` work on `node_id` for `owner` for `ttl`.
+    ///
+    /// Returns `None` when another owner holds an unexpired lease or the work
+    /// has failed. Expired or abandoned leases are reclaimed, incrementing
+    /// [`WorkLease::attempts`]; a lease that has already been claimed
+    /// [`with_max_lease_attempts`](Self::with_max_lease_attempts) times is
+    /// marked failed instead.
+    pub async fn claim_lease(
+        &self,
+        kind: &str,
+        node_id: &str,
+        owner: &str,
+        ttl: Duration,
+    ) -> Result<Option<WorkLease>> {
+        self.claim_lease_within(kind, node_id, owner, ttl, Some(self.max_lease_attempts))
+            .await
+    }
+
+    async fn claim_lease_within(
+        &self,
+        kind: &str,
+        node_id: &str,
+        owner: &str,
+        ttl: Duration,
+        max_attempts: Option<u64>,
+    ) -> Result<Option<WorkLease>> {
+        let client = reqwest::Client::new();
+        let endpoint = self.http_endpoint()?;
+        self.ensure_constraint(&client, &endpoint).await?;
+        let rows = query_neo4j_rows(
+            &client,
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: r#"
+                    MATCH (n:GraphNode {id: $node_id})
+                    MERGE (l:WorkLease {id: $lease_id})
+                    ON CREATE SET l.kind = $kind, l.node_id = $node_id, l.attempts = 0
+                    MERGE (l)-[:LEASES]->(n)
+                    SET l.checked_at_ms = timestamp()
+                    WITH l
+                    WHERE l.failed_at_ms IS NULL
+                      AND (l.owner IS NULL OR l.expires_at_ms <= timestamp())
+                    FOREACH (_ IN CASE
+                        WHEN $max_attempts IS NOT NULL AND coalesce(l.attempts, 0) >= $max_attempts
+                        THEN [1] ELSE [] END |
+                        SET l.owner = null, l.token = null, l.failed_at_ms = timestamp())
+                    WITH l
+                    WHERE l.failed_at_ms IS NULL
+                    SET l.owner = $owner,
+                        l.token = $token,
+                        l.claimed_at_ms = timestamp(),
+                        l.heartbeat_at_ms = timestamp(),
+                        l.expires_at_ms = timestamp() + $ttl_ms,
+                        l.released_at_ms = null,
+                        l.attempts = coalesce(l.attempts, 0) + 1
+                    RETURN l.id, l.kind, l.node_id, l.owner, l.token, l.expires_at_ms, l.attempts
+                "#
+                .into(),
+                parameters: json!({
+                    "lease_id": WorkLease::lease_id(kind, node_id),
+                    "kind": kind,
+                    "node_id": node_id,
+                    "owner": owner,
+                    "token": Uuid::new_v4().to_string(),
+                    "ttl_ms": duration_ms(ttl),
+                    "max_attempts": max_attempts,
+                }),
+            },
+            "claiming work lease",
+        )
+        .await?;
+        rows.first().map(work_lease_from_row).transpose()
+    }
+
+    /// Try to claim `kind` work that only one replica may do at a time.
+    ///
+    /// The lease is held on a `Worker` node for `kind`, created on first use,
+    /// and is never marked failed.
+    pub async fn claim_worker_lease(
+        &self,
+        kind: &str,
+        owner: &str,
+        ttl: Duration,
+    ) -> Result<Option<WorkLease>> {
+        let node_id = format!("worker:{kind}");
+        self.store_data(&json!({
+            "op": "merge_graph",
+            "nodes": [{"label": "Worker", "id": node_id, "kind": kind}],
+            "relationships": [],
+        }))
+        .await?;
+        self.claim_lease_within(kind, &node_id, owner, ttl, None)
+            .await
+    }
+
+    /// Extend `lease` by `ttl` from now. Returns `false` if it was lost.
+    pub async fn heartbeat_lease(&self, lease: &WorkLease, ttl: Duration) -> Result<bool> {
+        self.update_lease(
+            lease,
+            "SET l.heartbeat_at_ms = timestamp(), l.expires_at_ms = timestamp() + $ttl_ms",
+            duration_ms(ttl),
+            "renewing work lease",
+        )
+        .await
+    }
+
+    /// Release `lease` once its work is done, deleting the lease node.
+    ///
+    /// Returns `false` if the lease had already been reclaimed by another owner.
+    pub async fn release_lease(&self, lease: &WorkLease) -> Result<bool> {
+        self.update_lease(lease, "DETACH DELETE l", 0, "releasing work lease")
+            .await
+    }
+
+    /// Give up `lease` after its work failed so the node can be claimed again
+    /// immediately. The lease node stays to count [`WorkLease::attempts`].
+    ///
+    /// Returns `false` if the lease had already been reclaimed by another owner.
+    pub async fn abandon_lease(&self, lease: &WorkLease) -> Result<bool> {
+        self.update_lease(
+            lease,
+            "SET l.owner = null, l.token = null, l.released_at_ms = timestamp(), l.expires_at_ms = timestamp()",
+            0,
+            "abandoning work lease",
+        )
+        .await
+    }
+
+    async fn update_lease(
+        &self,
+        lease: &WorkLease,
+        set_clause: &str,
+        ttl_ms: i64,
+        action: &str,
+    ) -> Result<bool> {
+        let endpoint = self.http_endpoint()?;
+        let rows = query_neo4j_rows(
+            &reqwest::Client::new(),
+            &endpoint,
+            &self.user,
+            &self.pass,
+            CypherStatement {
+                statement: format!(
+                    r#"
+                    MATCH (l:WorkLease {{id: $lease_id}})
+                    WHERE l.owner = $owner AND l.token = $token
+                    {set_clause}
+                    RETURN count(*)
+                "#
+                ),
+                parameters: json!({
+                    "lease_id": lease.id,
+                    "owner": lease.owner,
+                    "token": lease.token,
+                    "ttl_ms": ttl_ms,
+                }),
+            },
+            action,
+        )
+        .await?;
+        Ok(rows
+            .first()
+            .and_then(Value::as_array)
+            .and_then(|values| values.first())
+            .and_then(Value::as_u64)
+            .unwrap_or(0)
+            > 0)
+    }
+
+    /// Renew `lease` every third of `ttl` until the returned task is aborted.
+    pub fn spawn_lease_heartbeat(
+        &self,
+        lease: WorkLease,
+        ttl: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let graph = self.clone();
+        tokio::spawn(async move {
+            let period = (ttl / 3).max(Duration::from_millis(100));
+            loop {
+                tokio::time::sleep(period).await;
+                match graph.heartbeat_lease(&lease, ttl).await {
+                    Ok(true) => trace!(lease_id = %lease.id, "renewed work lease"),
+                    Ok(false) => {
+                        warn!(lease_id = %lease.id, owner = %lease.owner, "work lease was lost");
+                        return;
+                    }
+                    Err(err) => {
+                        warn!(lease_id = %lease.id, error = %err, "work lease renewal failed")
+                    }
+                }
+            }
+        })
+    }
+
     async fn ensure_constraint(&self, client: &reqwest::Client, endpoint: &Url) -> Result<()> {
         if self.constraint_ensured.load(Ordering::SeqCst) {
             return Ok(());
         }
-        let statements = [CypherStatement {
-            statement: "CREATE CONSTRAINT pete_graph_node_id IF NOT EXISTS FOR (n:GraphNode) REQUIRE n.id IS UNIQUE".into(),
-            parameters: json!({}),
-        }];
+        let statements = [
+            CypherStatement {
+                statement: "CREATE CONSTRAINT pete_graph_node_id IF NOT EXISTS FOR (n:GraphNode) REQUIRE n.id IS UNIQUE".into(),
+                parameters: json!({}),
+            },
+            CypherStatement {
+                statement: "CREATE CONSTRAINT pete_work_lease_id IF NOT EXISTS FOR (l:WorkLease) REQUIRE l.id IS UNIQUE".into(),
+                parameters: json!({}),
+            },
+            CypherStatement {
+                statement: "CREATE INDEX pete_sensation_occurred_at IF NOT EXISTS FOR (n:Sensation) ON (n.occurred_at)".into(),
+                parameters: json!({}),
+            },
+            CypherStatement {
+                statement: "CREATE INDEX pete_image_keyframe_at IF NOT EXISTS FOR (n:Image) ON (n.keyframe_at_ms)".into(),
+                parameters: json!({}),
+            },
+        ];
         commit_neo4j_statements(
             client,
             endpoint,
@@ -6172,7 +9380,7 @@ async fn attach_manual_identity(
         "manual identity target label {target_label} is not valid for this identity kind"
     );
     let name = common::non_empty_model_text(name).context("manual identity name was empty")?;
-    let identity_id = format!("identity:person:{}", identity_key(name));
+    let identity_id = person_identity_id(name);
     let assigned_at = chrono::Utc::now().to_rfc3339();
     let run_id = stable_bytes_id(
         kind.run_prefix(),
@@ -6250,6 +9458,101 @@ async fn attach_manual_identity(
         .await
 }
 
+fn graph_identity_from_row(row: &Value) -> Result<GraphIdentity> {
+    let values = row
+        .as_array()
+        .context("Neo4j identity row was not an array")?;
+    Ok(GraphIdentity {
+        identity_id: row_string(values, 0, "identity id")?,
+        name: row_string(values, 1, "identity name")?,
+        face_samples: row_u32(values, 2, "face samples")?,
+        voice_samples: row_u32(values, 3, "voice samples")?,
+        clusters: row_string_vec(values, 4),
+        last_seen_at: row_optional_string(values, 5).filter(|at| !at.is_empty()),
+        opted_out: values.get(6).and_then(Value::as_bool).unwrap_or(false),
+    })
+}
+
+fn graph_image_region_from_row(row: &Value) -> Result<GraphImageRegion> {
+    let values = row
+        .as_array()
+        .context("Neo4j image region row was not an array")?;
+    let coordinate = |index, name| row_f64(values, index, name).map(|value| value as f32);
+    Ok(GraphImageRegion {
+        node_id: row_string(values, 0, "region id")?,
+        kind: row_string(values, 1, "region kind")?,
+        name: row_optional_string(values, 2).filter(|name| !name.trim().is_empty()),
+        track_id: row_optional_string(values, 3),
+        bbox: BoundingBox {
+            x: coordinate(4, "bbox_x")?,
+            y: coordinate(5, "bbox_y")?,
+            width: coordinate(6, "bbox_width")?,
+            height: coordinate(7, "bbox_height")?,
+        },
+    })
+}
+
+fn graph_identity_purge_from_row(identity_id: &str, row: &Value) -> Result<GraphIdentityPurge> {
+    let values = row
+        .as_array()
+        .context("Neo4j identity purge row was not an array")?;
+    let ids = |index| {
+        row_string_vec(values, index)
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    };
+    let nested = |index| {
+        values
+            .get(index)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(|item| {
+                item.as_array()
+                    .context("Neo4j identity purge item was not an array")
+            })
+    };
+    let mut vectors = Vec::new();
+    for pair in nested(4) {
+        let pair = pair?;
+        let vector = GraphIdentityVector {
+            collection: row_string(pair, 0, "collection")?,
+            point_id: row_string(pair, 1, "point_id")?,
+        };
+        if !vectors.contains(&vector) {
+            vectors.push(vector);
+        }
+    }
+    let mut face_regions = Vec::new();
+    for region in nested(5) {
+        let region = region?;
+        let coordinate = |index, name| row_f64(region, index, name).map(|value| value as f32);
+        let region = GraphFaceRegion {
+            image_id: row_string(region, 0, "image id")?,
+            bbox: BoundingBox {
+                x: coordinate(1, "bbox_x")?,
+                y: coordinate(2, "bbox_y")?,
+                width: coordinate(3, "bbox_width")?,
+                height: coordinate(4, "bbox_height")?,
+            },
+        };
+        if !face_regions.contains(&region) {
+            face_regions.push(region);
+        }
+    }
+    Ok(GraphIdentityPurge {
+        identity_id: identity_id.to_string(),
+        face_ids: ids(0),
+        voice_ids: ids(1),
+        audio_clip_ids: ids(2),
+        sensation_ids: ids(3),
+        vectors,
+        face_regions,
+    })
+}
+
 fn graph_snapshot_from_row(row: &Value) -> Result<GraphSnapshot> {
     let values = row
         .as_array()
@@ -6536,6 +9839,71 @@ fn graph_consolidated_speech_source_from_value(
     })
 }
 
+fn graph_diarization_candidate_from_row(row: &Value) -> Result<GraphDiarizationCandidate> {
+    let values = row
+        .as_array()
+        .context("Neo4j diarization row was not an array")?;
+    let sources = values
+        .get(1)
+        .and_then(Value::as_array)
+        .context("Neo4j diarization row is missing sources")?
+        .iter()
+        .map(graph_diarization_source_from_value)
+        .collect::<Result<Vec<_>>>()?;
+    let segments = values
+        .get(2)
+        .and_then(Value::as_array)
+        .context("Neo4j diarization row is missing segments")?
+        .iter()
+        .map(graph_diarization_segment_from_value)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(GraphDiarizationCandidate {
+        transcription_id: row_string(values, 0, "transcription_id")?,
+        sources,
+        segments,
+    })
+}
+
+fn graph_diarization_source_from_value(value: &Value) -> Result<GraphDiarizationSource> {
+    let object = value
+        .as_object()
+        .context("Neo4j diarization source was not an object")?;
+    let clip = GraphAudioClip {
+        id: object_string(object, "id")?,
+        clip: AudioClip {
+            mime: object_string(object, "mime")?,
+            base64: object_string(object, "base64")?,
+            sample_rate: object_u32(object, "sample_rate")?,
+            channels: object_u16(object, "channels")?,
+            transcript: object_optional_string(object, "transcript"),
+            captured_at: object_optional_string(object, "captured_at"),
+        },
+        occurred_at: object_optional_string(object, "occurred_at"),
+        sensation_id: object_optional_string(object, "sensation_id"),
+    };
+    Ok(GraphDiarizationSource {
+        index: object_usize(object, "index")?,
+        clip,
+        start_ms: object_u32(object, "start_ms")?,
+        end_ms: object_u32(object, "end_ms")?,
+    })
+}
+
+fn graph_diarization_segment_from_value(value: &Value) -> Result<GraphDiarizationSegment> {
+    let object = value
+        .as_object()
+        .context("Neo4j diarization segment was not an object")?;
+    Ok(GraphDiarizationSegment {
+        id: object_string(object, "id")?,
+        index: object_usize(object, "index")?,
+        text: object_string(object, "text")?,
+        start_ms: object_u32(object, "start_ms")?,
+        end_ms: object_u32(object, "end_ms")?,
+        occurred_at: object_optional_string(object, "occurred_at"),
+        ended_at: object_optional_string(object, "ended_at"),
+    })
+}
+
 fn graph_timeline_window_from_rows(rows: &[Value]) -> Result<Option<GraphTimelineWindow>> {
     let Some(first) = rows.first() else {
         return Ok(None);
@@ -6688,6 +10056,7 @@ fn graph_image_frame_from_row(row: &Value) -> Result<GraphImageFrame> {
         mime: row_string(values, 1, "mime")?,
         base64: row_string(values, 2, "base64")?,
         captured_at: row_optional_string(values, 3),
+        source: row_optional_string(values, 6),
     };
     Ok(GraphImageFrame {
         id,
@@ -6697,6 +10066,48 @@ fn graph_image_frame_from_row(row: &Value) -> Result<GraphImageFrame> {
     })
 }
 
+fn graph_scene_frame_from_row(row: &Value) -> Result<GraphSceneFrame> {
+    let values = row
+        .as_array()
+        .context("Neo4j scene frame row was not an array")?;
+    let fingerprint = match (
+        row_optional_string(values, 1),
+        row_optional_string(values, 2),
+    ) {
+        (Some(hash), Some(thumbnail)) => SceneFingerprint::from_stored(&hash, &thumbnail),
+        _ => None,
+    };
+    Ok(GraphSceneFrame {
+        image_id: row_string(values, 0, "id")?,
+        fingerprint,
+        duplicate_of: row_optional_string(values, 3),
+        scene_vector_id: row_optional_string(values, 4),
+        observed_at: row_optional_string(values, 5).filter(|at| !at.is_empty()),
+    })
+}
+
+fn work_lease_from_row(row: &Value) -> Result<WorkLease> {
+    let values = row
+        .as_array()
+        .context("Neo4j work lease row was not an array")?;
+    Ok(WorkLease {
+        id: row_string(values, 0, "id")?,
+        kind: row_string(values, 1, "kind")?,
+        node_id: row_string(values, 2, "node id")?,
+        owner: row_string(values, 3, "owner")?,
+        token: row_string(values, 4, "token")?,
+        expires_at_ms: values
+            .get(5)
+            .and_then(Value::as_i64)
+            .context("Neo4j work lease row is missing expires_at_ms")?,
+        attempts: values.get(6).and_then(Value::as_u64).unwrap_or(1),
+    })
+}
+
+fn duration_ms(duration: Duration) -> i64 {
+    i64::try_from(duration.as_millis()).unwrap_or(i64::MAX)
+}
+
 fn graph_face_identity_from_row(row: &Value) -> Result<GraphFaceIdentity> {
     let values = row
         .as_array()
@@ -6711,6 +10122,7 @@ fn graph_face_identity_from_row(row: &Value) -> Result<GraphFaceIdentity> {
                 Some(trimmed.to_string())
             }
         }),
+        person_id: row_optional_string(values, 2),
     })
 }
 
@@ -6728,6 +10140,7 @@ fn graph_voice_identity_from_row(row: &Value) -> Result<GraphVoiceIdentity> {
                 Some(trimmed.to_string())
             }
         }),
+        person_id: row_optional_string(values, 2),
     })
 }
 
@@ -6743,6 +10156,11 @@ fn graph_face_identity_target_from_row(row: &Value) -> Result<GraphFaceIdentityT
         vector_id: row_optional_string(values, 4),
         identity: row_optional_string(values, 5).and_then(non_empty_identity_name),
         occurred_at: row_string(values, 6, "face identity target occurred_at")?,
+        person_id: row_optional_string(values, 7),
+        pose: values
+            .get(8)
+            .and_then(Value::as_f64)
+            .map(|pose| pose as f32),
     })
 }
 
@@ -6758,6 +10176,7 @@ fn graph_voice_identity_target_from_row(row: &Value) -> Result<GraphVoiceIdentit
         vector_id: row_optional_string(values, 4),
         identity: row_optional_string(values, 5).and_then(non_empty_identity_name),
         occurred_at: row_string(values, 6, "voice identity target occurred_at")?,
+        person_id: row_optional_string(values, 7),
     })
 }
 
@@ -6779,6 +10198,7 @@ fn graph_movie_image_frame_from_row(row: &Value) -> Result<GraphMovieImageFrame>
         mime: row_string(values, 1, "mime")?,
         base64: row_string(values, 2, "base64")?,
         captured_at: row_optional_string(values, 3),
+        source: None,
     };
     Ok(GraphMovieImageFrame {
         id,
@@ -7540,17 +10960,18 @@ fn object_info_node(object: &ObjectInfo, id: &str, occurred_at: String) -> Value
         "embedding": object.embedding,
         "embedding_len": object.embedding.len(),
         "embedding_kind": "object",
+        "confidence": object.confidence,
+        "bbox_x": object.bbox.map(|b| b.x),
+        "bbox_y": object.bbox.map(|b| b.y),
+        "bbox_width": object.bbox.map(|b| b.width),
+        "bbox_height": object.bbox.map(|b| b.height),
+        "source_image_id": object.source_image_id.clone(),
         "occurred_at": occurred_at,
     })
 }
 
 fn object_info_id(object: &ObjectInfo, occurred_at: String) -> String {
-    format!(
-        "object:{}:{}:{}",
-        object.label.clone().unwrap_or_else(|| "unknown".into()),
-        object.embedding.len(),
-        occurred_at
-    )
+    object.observation_id(&occurred_at)
 }
 
 fn stored_payload_json(value: &Value) -> Value {
@@ -7591,6 +11012,11 @@ fn stable_bytes_id(prefix: &str, bytes: &[u8]) -> String {
     format!("{prefix}:sha256:{:x}", hasher.finalize())
 }
 
+/// Graph node id of the person identity called `name`.
+pub fn person_identity_id(name: &str) -> String {
+    format!("identity:person:{}", identity_key(name))
+}
+
 fn identity_key(name: &str) -> String {
     let mut key = String::new();
     let mut last_dash = false;
@@ -7617,6 +11043,84 @@ fn face_recognition_how(face_count: usize) -> String {
     crate::prompt::face_count_sensation_text(face_count)
 }
 
+/// Link a tracked face to its `FaceTrack`, announcing tracks that start.
+fn face_track_graph(
+    frame: &GraphImageFrame,
+    run_id: &str,
+    detection: &GraphFaceDetection,
+    track: &GraphFaceTrack,
+    occurred_at: &str,
+    nodes: &mut Vec<Value>,
+    relationships: &mut Vec<Value>,
+) {
+    let identity = detection
+        .recognition
+        .as_ref()
+        .and_then(|matched| matched.identity.as_deref());
+    let mut track_node = json!({
+        "label": "FaceTrack",
+        "id": track.track_id,
+        "last_seen_at": occurred_at,
+    });
+    if track.started {
+        track_node["started_at"] = json!(occurred_at);
+    }
+    if let Some(matched) = &detection.recognition {
+        track_node["matched_face_id"] = json!(matched.face_id);
+        track_node["identity_name"] = json!(identity);
+    }
+    nodes.push(track_node);
+    relationships.push(json!({
+        "from": detection.face_id,
+        "to": track.track_id,
+        "type": "PART_OF_TRACK",
+        "detection_index": detection.index,
+    }));
+    if !track.started {
+        return;
+    }
+
+    let sensation_id = stable_bytes_id("sensation:face_track_entered", track.track_id.as_bytes());
+    nodes.push(json!({
+        "label": "Sensation",
+        "id": sensation_id,
+        "kind": "face_track_entered",
+        "derived": true,
+        "occurred_at": occurred_at,
+        "how": crate::prompt::face_track_entered_sensation_text(identity),
+        "source_image_id": frame.id,
+        "face_instance_id": detection.face_id,
+        "face_track_id": track.track_id,
+    }));
+    relationships.push(json!({
+        "from": run_id,
+        "to": sensation_id,
+        "type": "PRODUCED",
+    }));
+    relationships.push(json!({
+        "from": sensation_id,
+        "to": track.track_id,
+        "type": "OBSERVED",
+    }));
+    relationships.push(json!({
+        "from": sensation_id,
+        "to": detection.face_id,
+        "type": "DERIVED_FROM",
+    }));
+    if let Some(source_id) = &frame.sensation_id {
+        relationships.push(json!({
+            "from": source_id,
+            "to": sensation_id,
+            "type": "PRODUCED",
+        }));
+        relationships.push(json!({
+            "from": sensation_id,
+            "to": source_id,
+            "type": "DERIVED_FROM",
+        }));
+    }
+}
+
 fn face_identity_how(recognition: Option<&GraphFaceMatch>) -> String {
     match recognition {
         Some(matched) => {
@@ -44,30 +44,9 @@ impl MemoryWit {
             ..Self::new(memory)
         }
     }
-}
 
-#[async_trait]
-impl BufferedWit for MemoryWit {
-    type Input = Impression<String>;
-    type Output = String;
-
-    fn buffer(&self) -> &Mutex<Vec<Self::Input>> {
-        &self.buffer
-    }
-
-    async fn process_buffer(&self, new_items: Vec<Self::Input>) -> Vec<Impression<Self::Output>> {
-        {
-            let mut collected = self.collected.lock().unwrap();
-            collected.extend(new_items);
-        }
-        let count = self.ticks.fetch_add(1, Ordering::SeqCst) + 1;
-        let should_summarize = {
-            let c = self.collected.lock().unwrap();
-            !c.is_empty() && (c.len() >= self.threshold || count >= self.threshold)
-        };
-        if !should_summarize {
-            return Vec::new();
-        }
+    /// Store and return one impression concatenating everything collected.
+    async fn summarize(&self) -> Vec<Impression<String>> {
         self.ticks.store(0, Ordering::SeqCst);
         let items = {
             let mut coll = self.collected.lock().unwrap();
@@ -101,6 +80,41 @@ impl BufferedWit for MemoryWit {
         debug!("memory summarized {} impressions", items.len());
         vec![impression]
     }
+}
+
+#[async_trait]
+impl BufferedWit for MemoryWit {
+    type Input = Impression<String>;
+    type Output = String;
+
+    fn buffer(&self) -> &Mutex<Vec<Self::Input>> {
+        &self.buffer
+    }
+
+    async fn process_buffer(&self, new_items: Vec<Self::Input>) -> Vec<Impression<Self::Output>> {
+        {
+            let mut collected = self.collected.lock().unwrap();
+            collected.extend(new_items);
+        }
+        let count = self.ticks.fetch_add(1, Ordering::SeqCst) + 1;
+        let should_summarize = {
+            let c = self.collected.lock().unwrap();
+            !c.is_empty() && (c.len() >= self.threshold || count >= self.threshold)
+        };
+        if !should_summarize {
+            return Vec::new();
+        }
+        self.summarize().await
+    }
+
+    /// Summarize everything collected, even below the threshold.
+    async fn flush(&self, new_items: Vec<Self::Input>) -> Vec<Impression<Self::Output>> {
+        self.collected.lock().unwrap().extend(new_items);
+        if self.collected.lock().unwrap().is_empty() {
+            return Vec::new();
+        }
+        self.summarize().await
+    }
 
     fn label(&self) -> &'static str {
         Self::LABEL
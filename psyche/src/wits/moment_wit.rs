@@ -50,27 +50,9 @@ impl MomentWit {
             tx,
         }
     }
-}
-
-#[async_trait]
-impl crate::wit::Wit for MomentWit {
-    type Input = ();
-    type Output = String;
 
-    async fn observe(&self, _: Self::Input) {
-        // MomentWit also pulls data from the [`TopicBus`].
-        // No direct observations are expected, so this is left empty.
-    }
-
-    async fn tick(&self) -> Vec<Impression<Self::Output>> {
-        const MIN_ITEMS: usize = 3;
-        let items = {
-            let mut buf = self.buffer.lock().unwrap();
-            if buf.len() < MIN_ITEMS {
-                return Vec::new();
-            }
-            buf.drain(..).collect::<Vec<_>>()
-        };
+    /// Summarize `items` into a moment and publish it.
+    async fn summarize(&self, items: Vec<Impression<String>>) -> Vec<Impression<String>> {
         debug!(count = items.len(), "moment wit summarizing instants");
         let bullets = items
             .iter()
@@ -116,6 +98,37 @@ impl crate::wit::Wit for MomentWit {
         self.bus.publish(Topic::Moment, imp.clone());
         vec![imp]
     }
+}
+
+#[async_trait]
+impl crate::wit::Wit for MomentWit {
+    type Input = ();
+    type Output = String;
+
+    async fn observe(&self, _: Self::Input) {
+        // MomentWit also pulls data from the [`TopicBus`].
+        // No direct observations are expected, so this is left empty.
+    }
+
+    async fn tick(&self) -> Vec<Impression<Self::Output>> {
+        const MIN_ITEMS: usize = 3;
+        let items = {
+            let mut buf = self.buffer.lock().unwrap();
+            if buf.len() < MIN_ITEMS {
+                return Vec::new();
+            }
+            buf.drain(..).collect::<Vec<_>>()
+        };
+        self.summarize(items).await
+    }
+
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        let items = std::mem::take(&mut *self.buffer.lock().unwrap());
+        if items.is_empty() {
+            return Vec::new();
+        }
+        self.summarize(items).await
+    }
 
     fn debug_label(&self) -> &'static str {
         Self::LABEL
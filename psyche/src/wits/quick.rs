@@ -11,11 +11,12 @@
 //! window. On [`tick`], it condenses the recent sensations into a single
 //! impression and publishes it on [`Topic::Instant`].
 
+use crate::clock::{Clock, SystemClock};
 use crate::topics::{Topic, TopicBus};
 use crate::traits::Doer;
 use crate::{Impression, Sensation, Stimulus};
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use futures::StreamExt;
 use lingproc::LlmInstruction;
 use std::collections::VecDeque;
@@ -28,6 +29,7 @@ pub struct Quick {
     doer: Arc<dyn Doer>,
     window: Duration,
     tx: Option<broadcast::Sender<crate::WitReport>>, // optional debug
+    clock: Arc<dyn Clock>,
 }
 
 impl Quick {
@@ -70,9 +72,16 @@ impl Quick {
             doer,
             window: Duration::seconds(8),
             tx,
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// Return this `Quick` measuring its window against `clock`.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Describe a sensation for the summarization prompt.
     fn describe(s: &Sensation) -> Option<String> {
         match s {
@@ -88,6 +97,10 @@ impl Quick {
             Sensation::FinishedSpeaking { text, .. } => {
                 Some(format!("I finish saying \"{}\"", text.trim()))
             }
+            Sensation::InterruptedSpeaking { text, .. } => Some(format!(
+                "I am cut off after saying \"{}\" because they start talking",
+                text.trim()
+            )),
             Sensation::Of { payload, .. } => {
                 if let Some(_f) = payload.downcast_ref::<crate::sensors::face::FaceInfo>() {
                     Some(crate::prompt::face_count_sensation_text(1))
@@ -106,6 +119,11 @@ impl Quick {
                 } else if let Some(impression) = payload.downcast_ref::<crate::Impression<String>>()
                 {
                     Some(impression.summary.clone())
+                } else if let Some(partial) = payload.downcast_ref::<crate::PartialUtterance>() {
+                    Some(format!(
+                        "I hear them starting to say \"{}\"",
+                        partial.text.trim()
+                    ))
                 } else if payload.downcast_ref::<crate::AudioClip>().is_some() {
                     None
                 } else if payload.downcast_ref::<crate::ImageData>().is_some() {
@@ -147,9 +165,58 @@ impl Quick {
         }
     }
 
+    /// Condense `stimuli` into one instant and publish it.
+    async fn summarize(&self, stimuli: Vec<Stimulus<String>>) -> Vec<Impression<String>> {
+        trace!(count = stimuli.len(), "quick summarizing sensations");
+        let prompt_bullets: Vec<String> = stimuli.iter().map(Stimulus::prompt_list_item).collect();
+        let fallback_bullets: Vec<String> = stimuli.iter().map(|s| s.what.clone()).collect();
+        let grounding = crate::prompt::SENSOR_GROUNDING_RULES;
+        let prompt = format!(
+            "Summarize these recent sensations in one short sentence, in the first person, using I/my/me. Try to infer what is happening in the real world from fragmentary, possibly contradictory, fleeting sensory data. Some sensations may be consecutive frames from the same sensor stream; repeated similar camera or face observations usually mean one thing persisted across frames, not multiple simultaneous things. {grounding} Compress repeated low-level detections into the real-world gist; do not list ids, hashes, timestamps, or detection-by-detection details. Do not refer to Pete, the individual, the observer, or the person. Return only the summary sentence.\n- {}",
+            prompt_bullets.join("\n- ")
+        );
+        let command = crate::with_default_system_prompt(prompt);
+        let out = match self
+            .doer
+            .follow(LlmInstruction {
+                command: command.clone(),
+                images: Vec::new(),
+            })
+            .await
+        {
+            Ok(s) => {
+                let summary = s.trim();
+                if summary.is_empty() {
+                    fallback_bullets.join("; ")
+                } else {
+                    summary.to_string()
+                }
+            }
+            Err(_) => fallback_bullets.join("; "),
+        };
+        debug!(count = stimuli.len(), summary = %out, "quick emitting instant impression");
+        trace!(
+            "quick: emitting instant impression from {} sensations: \"{}\"",
+            stimuli.len(),
+            out
+        );
+        let imp = Impression::at(stimuli, out.clone(), None::<String>, self.clock.now());
+        if let Some(tx) = &self.tx {
+            if crate::debug::debug_enabled(Self::LABEL).await {
+                let _ = tx.send(crate::WitReport {
+                    name: Self::LABEL.into(),
+                    prompt: command,
+                    output: out.clone(),
+                });
+            }
+        }
+        self.bus.publish(Topic::Instant, imp.clone());
+        vec![imp]
+    }
+
     /// Remove sensations older than the window from `buf`.
-    fn trim_old(buf: &mut VecDeque<Stimulus<String>>, window: Duration) {
-        let cutoff = Utc::now() - window;
+    fn trim_old(buf: &mut VecDeque<Stimulus<String>>, window: Duration, now: DateTime<Utc>) {
+        let cutoff = now - window;
         while let Some(stimulus) = buf.front() {
             if stimulus.timestamp < cutoff {
                 buf.pop_front();
@@ -171,7 +238,7 @@ impl crate::traits::observer::SensationObserver for Quick {
                     s.occurred_at(),
                     [s.id()],
                 ));
-                Self::trim_old(&mut buf, self.window);
+                Self::trim_old(&mut buf, self.window, self.clock.now());
             }
         }
     }
@@ -190,65 +257,28 @@ impl crate::traits::wit::Wit for Quick {
                 input.occurred_at(),
                 [input.id()],
             ));
-            Self::trim_old(&mut buf, self.window);
+            Self::trim_old(&mut buf, self.window, self.clock.now());
         }
     }
 
     async fn tick(&self) -> Vec<Impression<Self::Output>> {
         let items = {
             let mut buf = self.buffer.lock().unwrap();
-            Self::trim_old(&mut buf, self.window);
+            Self::trim_old(&mut buf, self.window, self.clock.now());
             if buf.is_empty() {
                 return Vec::new();
             }
             buf.drain(..).collect::<Vec<_>>()
         };
-        trace!(count = items.len(), "quick summarizing sensations");
-        let stimuli = items;
-        let prompt_bullets: Vec<String> = stimuli.iter().map(Stimulus::prompt_list_item).collect();
-        let fallback_bullets: Vec<String> = stimuli.iter().map(|s| s.what.clone()).collect();
-        let grounding = crate::prompt::SENSOR_GROUNDING_RULES;
-        let prompt = format!(
-            "Summarize these recent sensations in one short sentence, in the first person, using I/my/me. Try to infer what is happening in the real world from fragmentary, possibly contradictory, fleeting sensory data. Some sensations may be consecutive frames from the same sensor stream; repeated similar camera or face observations usually mean one thing persisted across frames, not multiple simultaneous things. {grounding} Compress repeated low-level detections into the real-world gist; do not list ids, hashes, timestamps, or detection-by-detection details. Do not refer to Pete, the individual, the observer, or the person. Return only the summary sentence.\n- {}",
-            prompt_bullets.join("\n- ")
-        );
-        let command = crate::with_default_system_prompt(prompt);
-        let out = match self
-            .doer
-            .follow(LlmInstruction {
-                command: command.clone(),
-                images: Vec::new(),
-            })
-            .await
-        {
-            Ok(s) => {
-                let summary = s.trim();
-                if summary.is_empty() {
-                    fallback_bullets.join("; ")
-                } else {
-                    summary.to_string()
-                }
-            }
-            Err(_) => fallback_bullets.join("; "),
-        };
-        debug!(count = stimuli.len(), summary = %out, "quick emitting instant impression");
-        trace!(
-            "quick: emitting instant impression from {} sensations: \"{}\"",
-            stimuli.len(),
-            out
-        );
-        let imp = Impression::new(stimuli, out.clone(), None::<String>);
-        if let Some(tx) = &self.tx {
-            if crate::debug::debug_enabled(Self::LABEL).await {
-                let _ = tx.send(crate::WitReport {
-                    name: Self::LABEL.into(),
-                    prompt: command,
-                    output: out.clone(),
-                });
-            }
+        self.summarize(items).await
+    }
+
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        let items = self.buffer.lock().unwrap().drain(..).collect::<Vec<_>>();
+        if items.is_empty() {
+            return Vec::new();
         }
-        self.bus.publish(Topic::Instant, imp.clone());
-        vec![imp]
+        self.summarize(items).await
     }
 
     fn debug_label(&self) -> &'static str {
@@ -1,16 +1,19 @@
 #[cfg(feature = "face")]
+use crate::clock::{Clock, SystemClock};
 use crate::sensors::face::FaceInfo;
 use crate::traits::observer::SensationObserver;
 use crate::wits::memory::GraphStore;
 use crate::{
-    AudioClip, BrowserMotion, CombobulationSummary, GeoEmbedding, GeoLoc, Heartbeat, ImageData,
-    ImageEmbedding, Impression, ObjectInfo, Sensation, Thought, Topic, TopicBus, VoiceInfo,
+    AddresseeVerdict, AudioClip, BrowserMotion, CombobulationSummary, EchoAction, EchoVerdict,
+    GeoEmbedding, GeoLoc, Heartbeat, ImageData, ImageEmbedding, ImageRedactor, Impression,
+    ObjectInfo, Redaction, Sensation, TextBlock, Thought, Topic, TopicBus, VoiceInfo,
     audio_clip_id, browser_motion_content_id, geoloc_content_id, image_content_id,
 };
 use async_trait::async_trait;
 use futures::StreamExt;
 use serde_json::{Value, json};
 use sha2::{Digest, Sha256};
+use std::borrow::Cow;
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 use tracing::warn;
@@ -19,6 +22,8 @@ use tracing::warn;
 pub struct SensationGraphObserver {
     graph: Arc<dyn GraphStore>,
     seen: Mutex<HashSet<String>>,
+    redactor: Option<Arc<dyn ImageRedactor>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl SensationGraphObserver {
@@ -26,6 +31,43 @@ impl SensationGraphObserver {
         Self {
             graph,
             seen: Mutex::new(HashSet::new()),
+            redactor: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Stamp when each sensation's description was formed with `clock`.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Pass camera frames through `redactor` before storing them. Frames keep
+    /// the id of what was seen. Frames the redactor fails on are not stored
+    /// when it is [strict](ImageRedactor::strict), and stored as they are
+    /// otherwise.
+    pub fn with_image_redactor(mut self, redactor: Arc<dyn ImageRedactor>) -> Self {
+        self.redactor = Some(redactor);
+        self
+    }
+
+    /// Return what of `image` may be stored, if anything.
+    async fn storable_image<'a>(&self, image: &'a ImageData) -> Option<Cow<'a, ImageData>> {
+        let Some(redactor) = &self.redactor else {
+            return Some(Cow::Borrowed(image));
+        };
+        match redactor.redact(image).await {
+            Ok(Redaction::Keep) => Some(Cow::Borrowed(image)),
+            Ok(Redaction::Redacted(redacted)) => Some(Cow::Owned(redacted)),
+            Ok(Redaction::Discard) => None,
+            Err(e) if redactor.strict() => {
+                warn!(?e, "image redaction failed; not storing frame");
+                None
+            }
+            Err(e) => {
+                warn!(?e, "image redaction failed; storing frame unredacted");
+                Some(Cow::Borrowed(image))
+            }
         }
     }
 
@@ -44,13 +86,21 @@ impl SensationGraphObserver {
         });
     }
 
-    async fn store_once(&self, key: String, record: Value) {
+    async fn store_once(&self, key: String, mut record: Value) {
         {
             let mut seen = self.seen.lock().unwrap();
             if !seen.insert(key) {
                 return;
             }
         }
+        let formed_at = self.clock.now().to_rfc3339();
+        if let Some(nodes) = record.get_mut("nodes").and_then(Value::as_array_mut) {
+            for node in nodes {
+                if node["label"] == "Sensation" && node.get("how_formed_at").is_none() {
+                    node["how_formed_at"] = json!(formed_at);
+                }
+            }
+        }
         if let Err(e) = self.graph.store_data(&record).await {
             warn!(?e, "graph sensation store failed");
         }
@@ -76,6 +126,9 @@ impl SensationObserver for SensationGraphObserver {
         if let Some(image) = payload.downcast_ref::<ImageData>() {
             let id = image_content_id(image);
             let sensation_id = sensation_id("image", &id, occurred_at.to_rfc3339());
+            let Some(image) = self.storable_image(image).await else {
+                return;
+            };
             self.store_once(
                 format!("image:{id}"),
                 json!({
@@ -87,7 +140,7 @@ impl SensationObserver for SensationGraphObserver {
                             occurred_at.to_rfc3339(),
                             crate::prompt::IMAGE_SENSATION_TEXT,
                         ),
-                        image_node(image, &id, occurred_at.to_rfc3339()),
+                        image_node(&image, &id, occurred_at.to_rfc3339()),
                     ],
                     "relationships": [{
                         "from": sensation_id,
@@ -187,6 +240,19 @@ impl SensationObserver for SensationGraphObserver {
                 &image_embedding.image_id,
                 occurred_at.to_rfc3339(),
             );
+            let mut embedding_node = image_embedding_node(
+                &image_embedding.image,
+                &image_embedding.image_id,
+                occurred_at.to_rfc3339(),
+                &image_embedding.embedding,
+                image_embedding.vector_id.as_deref(),
+                image_embedding.model.as_deref(),
+            );
+            if let (Some(_), Some(node)) = (&self.redactor, embedding_node.as_object_mut()) {
+                // The frame itself is stored, redacted, as an image sensation.
+                node.remove("mime");
+                node.remove("base64");
+            }
             self.store_once(
                 format!("image_embedding:{}", image_embedding.image_id),
                 json!({
@@ -198,14 +264,7 @@ impl SensationObserver for SensationGraphObserver {
                             occurred_at.to_rfc3339(),
                             "I recognize the current camera frame visually.",
                         ),
-                        image_embedding_node(
-                            &image_embedding.image,
-                            &image_embedding.image_id,
-                            occurred_at.to_rfc3339(),
-                            &image_embedding.embedding,
-                            image_embedding.vector_id.as_deref(),
-                            image_embedding.model.as_deref(),
-                        )
+                        embedding_node,
                     ],
                     "relationships": [
                         {
@@ -310,27 +369,160 @@ impl SensationObserver for SensationGraphObserver {
                     }),
                 )
                 .await;
+            } else if let Some(verdict) = payload.downcast_ref::<AddresseeVerdict>() {
+                // The verdict annotates the heard speech node, whatever its
+                // kind, rather than forming a sensation of its own.
+                self.store_once(
+                    format!("addressee:{}", verdict.sensation_id),
+                    json!({
+                        "op": "merge_graph",
+                        "nodes": [{
+                            "label": "GraphNode",
+                            "id": verdict.sensation_id,
+                            "addressed": verdict.addressed,
+                            "addressee_confidence": verdict.confidence,
+                            "addressee_reasons": verdict.reasons,
+                            "addressee_judged_at": occurred_at.to_rfc3339(),
+                        }],
+                        "relationships": [],
+                    }),
+                )
+                .await;
+            } else if let Some(verdict) = payload.downcast_ref::<EchoVerdict>() {
+                let id = format!("echo:{}", verdict.sensation_id);
+                let mut relationships = Vec::new();
+                if let Some(spoken_id) = &verdict.spoken_id {
+                    relationships.push(json!({
+                        "from": id,
+                        "to": spoken_id,
+                        "type": "COMPARED_WITH",
+                    }));
+                }
+                match verdict.action {
+                    None => relationships.push(json!({
+                        "from": verdict.sensation_id,
+                        "to": id,
+                        "type": "ECHO_CHECKED",
+                    })),
+                    Some(EchoAction::Reclassify) => {
+                        let utterance_id = utterance_id("self", &verdict.text, occurred_at);
+                        relationships.push(json!({
+                            "from": id,
+                            "to": sensation_id("utterance", &utterance_id, occurred_at.to_rfc3339()),
+                            "type": "RECLASSIFIED_AS",
+                        }));
+                    }
+                    Some(EchoAction::Drop) => {}
+                }
+                self.store_once(
+                    id.clone(),
+                    json!({
+                        "op": "merge_graph",
+                        "nodes": [{
+                            "label": "EchoDecision",
+                            "id": id,
+                            "text": verdict.text,
+                            "echo": verdict.echo,
+                            "similarity": verdict.similarity,
+                            "action": verdict.action,
+                            "spoken": verdict.spoken,
+                            "decided_at": occurred_at.to_rfc3339(),
+                        }],
+                        "relationships": relationships,
+                    }),
+                )
+                .await;
             } else if let Some(object) = payload.downcast_ref::<ObjectInfo>() {
                 let id = object_info_id(object, occurred_at.to_rfc3339());
                 let sensation_id = sensation_id("object", &id, occurred_at.to_rfc3339());
+                let mut nodes = vec![
+                    sensation_node(
+                        &sensation_id,
+                        "object",
+                        occurred_at.to_rfc3339(),
+                        &object_how(object),
+                    ),
+                    object_info_node(object, &id, occurred_at.to_rfc3339()),
+                ];
+                let mut relationships = vec![json!({
+                    "from": sensation_id,
+                    "to": id,
+                    "type": "OBSERVED",
+                })];
+                if let Some(image_id) = &object.source_image_id {
+                    nodes.push(json!({
+                        "label": "Image",
+                        "id": image_id,
+                    }));
+                    relationships.push(json!({
+                        "from": image_id,
+                        "to": id,
+                        "type": "CONTAINS_OBJECT",
+                    }));
+                    relationships.push(json!({
+                        "from": id,
+                        "to": image_id,
+                        "type": "DERIVED_FROM",
+                    }));
+                    relationships.push(json!({
+                        "from": sensation_id,
+                        "to": image_id,
+                        "type": "DERIVED_FROM",
+                    }));
+                }
                 self.store_once(
                     id.clone(),
                     json!({
-                    "op": "merge_graph",
-                    "nodes": [
-                            sensation_node(
-                                &sensation_id,
-                                "object",
-                                occurred_at.to_rfc3339(),
-                                &object_how(object),
-                            ),
-                            object_info_node(object, &id, occurred_at.to_rfc3339()),
-                        ],
-                        "relationships": [{
-                            "from": sensation_id,
-                            "to": id,
-                            "type": "OBSERVED",
-                        }],
+                        "op": "merge_graph",
+                        "nodes": nodes,
+                        "relationships": relationships,
+                    }),
+                )
+                .await;
+            } else if let Some(text) = payload.downcast_ref::<TextBlock>() {
+                let id = text.text_id();
+                let sensation_id = sensation_id("text", &id, occurred_at.to_rfc3339());
+                let mut nodes = vec![
+                    sensation_node(
+                        &sensation_id,
+                        "text",
+                        occurred_at.to_rfc3339(),
+                        &text_how(text),
+                    ),
+                    text_block_node(text, &id, occurred_at.to_rfc3339()),
+                ];
+                let mut relationships = vec![json!({
+                    "from": sensation_id,
+                    "to": id,
+                    "type": "OBSERVED",
+                })];
+                if let Some(image_id) = &text.source_image_id {
+                    nodes.push(json!({
+                        "label": "Image",
+                        "id": image_id,
+                    }));
+                    relationships.push(json!({
+                        "from": image_id,
+                        "to": id,
+                        "type": "CONTAINS_TEXT",
+                        "confidence": text.confidence,
+                        "bbox_x": text.bbox.map(|b| b.x),
+                        "bbox_y": text.bbox.map(|b| b.y),
+                        "bbox_width": text.bbox.map(|b| b.width),
+                        "bbox_height": text.bbox.map(|b| b.height),
+                    }));
+                    relationships.push(json!({
+                        "from": sensation_id,
+                        "to": image_id,
+                        "type": "DERIVED_FROM",
+                    }));
+                }
+                self.store_once(
+                    sensation_id.clone(),
+                    json!({
+                        "op": "merge_graph",
+                        "nodes": nodes,
+                        "relationships": relationships,
                     }),
                 )
                 .await;
@@ -527,6 +719,12 @@ async fn store_textual_sensation(observer: &SensationGraphObserver, sensation: &
         Sensation::FinishedSpeaking { text, occurred_at } => {
             return store_spoken_sensation(observer, "finished_speaking", text, occurred_at).await;
         }
+        Sensation::InterruptedSpeaking {
+            text, occurred_at, ..
+        } => {
+            return store_spoken_sensation(observer, "interrupted_speaking", text, occurred_at)
+                .await;
+        }
         Sensation::Of { .. } => return,
     };
     let id = format!("web-interface-text:{}:{text}", occurred_at.to_rfc3339());
@@ -567,14 +765,16 @@ async fn store_spoken_sensation(
     occurred_at: &chrono::DateTime<chrono::Utc>,
 ) {
     let (speaker, text, occurred_at) = match speaker {
-        "self" | "user" | "started_speaking" | "finished_speaking" => (speaker, text, occurred_at),
+        "self" | "user" | "started_speaking" | "finished_speaking" | "interrupted_speaking" => {
+            (speaker, text, occurred_at)
+        }
         _ => return,
     };
-    let utterance_id = format!("utterance:{speaker}:{}:{text}", occurred_at.to_rfc3339());
+    let utterance_id = utterance_id(speaker, text, occurred_at);
     let sensation_id = sensation_id("utterance", &utterance_id, occurred_at.to_rfc3339());
     observer
         .store_once(
-            format!("utterance:{speaker}:{}:{text}", occurred_at.to_rfc3339()),
+            utterance_id.clone(),
             json!({
                 "op": "merge_graph",
                 "nodes": [
@@ -609,7 +809,6 @@ fn sensation_node(id: &str, kind: &str, occurred_at: String, how: &str) -> Value
         "kind": kind,
         "occurred_at": occurred_at,
         "how": first_person_present(how),
-        "how_formed_at": chrono::Utc::now().to_rfc3339(),
     })
 }
 
@@ -673,17 +872,29 @@ fn object_how(object: &ObjectInfo) -> String {
         .as_deref()
         .filter(|label| !label.trim().is_empty())
     {
-        Some(label) => format!("I see a {label}."),
+        Some(label) => match object.confidence {
+            Some(confidence) if confidence < 0.5 => format!("I think I see a {label}."),
+            _ => format!("I see a {label}."),
+        },
         None => "I see an object.".into(),
     }
 }
 
+fn text_how(text: &TextBlock) -> String {
+    let quoted = text.text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match text.confidence {
+        Some(confidence) if confidence < 0.5 => format!("I think I read \"{quoted}\"."),
+        _ => format!("I read \"{quoted}\"."),
+    }
+}
+
 fn utterance_how(speaker: &str, text: &str) -> String {
     match speaker {
         "self" => format!("I hear myself saying \"{}\".", text.trim()),
         "user" => format!("I hear the user saying \"{}\".", text.trim()),
         "started_speaking" => format!("I start saying \"{}\".", text.trim()),
         "finished_speaking" => format!("I finish saying \"{}\".", text.trim()),
+        "interrupted_speaking" => format!("I am cut off after saying \"{}\".", text.trim()),
         _ => format!("I hear someone saying \"{}\".", text.trim()),
     }
 }
@@ -709,17 +920,30 @@ fn object_info_node(object: &ObjectInfo, id: &str, occurred_at: String) -> Value
         "embedding": object.embedding,
         "embedding_len": object.embedding.len(),
         "embedding_kind": "object",
+        "confidence": object.confidence,
+        "bbox_x": object.bbox.map(|b| b.x),
+        "bbox_y": object.bbox.map(|b| b.y),
+        "bbox_width": object.bbox.map(|b| b.width),
+        "bbox_height": object.bbox.map(|b| b.height),
+        "source_image_id": object.source_image_id.clone(),
         "occurred_at": occurred_at,
     })
 }
 
+/// The node is shared by every reading of the same text; `last_read_at`
+/// moves with each one.
+fn text_block_node(text: &TextBlock, id: &str, occurred_at: String) -> Value {
+    json!({
+        "label": "TextBlock",
+        "id": id,
+        "text": text.text.trim(),
+        "normalized_text": text.normalized_text(),
+        "last_read_at": occurred_at,
+    })
+}
+
 fn object_info_id(object: &ObjectInfo, occurred_at: String) -> String {
-    format!(
-        "object:{}:{}:{}",
-        object.label.clone().unwrap_or_else(|| "unknown".into()),
-        object.embedding.len(),
-        occurred_at
-    )
+    object.observation_id(&occurred_at)
 }
 
 fn json_sensation_id(value: &Value, occurred_at: String) -> String {
@@ -821,7 +1045,7 @@ async fn store_unknown_sensation(
 
 #[cfg(feature = "face")]
 fn face_node(face: &FaceInfo, occurred_at: String) -> Value {
-    json!({
+    let mut node = json!({
         "label": "FaceInstance",
         "id": face.face_id,
         "source_image_id": face.source_image_id,
@@ -833,13 +1057,25 @@ fn face_node(face: &FaceInfo, occurred_at: String) -> Value {
         "embedding_len": face.embedding.len(),
         "embedding_kind": "face_instance",
         "embedding_point_id": face.vector_id,
-    })
+    });
+    face.details.add_graph_properties(&mut node);
+    node
 }
 
-fn sensation_id(kind: &str, content_id: &str, occurred_at: String) -> String {
+/// Graph id of the sensation node for content of `kind` at `occurred_at`.
+pub(crate) fn sensation_id(kind: &str, content_id: &str, occurred_at: String) -> String {
     format!("sensation:{kind}:{content_id}:{occurred_at}")
 }
 
+/// Graph id of the `Utterance` node for `text` said by `speaker`.
+pub(crate) fn utterance_id(
+    speaker: &str,
+    text: &str,
+    occurred_at: &chrono::DateTime<chrono::Utc>,
+) -> String {
+    format!("utterance:{speaker}:{}:{text}", occurred_at.to_rfc3339())
+}
+
 fn image_node(image: &ImageData, id: &str, occurred_at: String) -> Value {
     json!({
         "label": "Image",
@@ -848,6 +1084,7 @@ fn image_node(image: &ImageData, id: &str, occurred_at: String) -> Value {
         "mime": image.mime.clone(),
         "base64": image.base64.clone(),
         "captured_at": image.captured_at.clone(),
+        "source": image.source.clone(),
         "occurred_at": occurred_at,
     })
 }
@@ -53,27 +53,9 @@ impl SituationWit {
             tx,
         }
     }
-}
-
-#[async_trait]
-impl crate::wit::Wit for SituationWit {
-    type Input = ();
-    type Output = String;
 
-    async fn observe(&self, _: Self::Input) {
-        // SituationWit receives moments via a [`TopicBus`] subscription.
-        // Nothing is expected through `observe`, so this is a no-op.
-    }
-
-    async fn tick(&self) -> Vec<Impression<Self::Output>> {
-        const MIN_ITEMS: usize = 3;
-        let items = {
-            let mut buf = self.buffer.lock().unwrap();
-            if buf.len() < MIN_ITEMS {
-                return Vec::new();
-            }
-            buf.drain(..).collect::<Vec<_>>()
-        };
+    /// Summarize `items` into a situation and publish it.
+    async fn summarize(&self, items: Vec<Impression<String>>) -> Vec<Impression<String>> {
         debug!(count = items.len(), "situation wit summarizing moments");
         let previous = self.last.lock().unwrap().clone();
         let mut prompt = String::new();
@@ -126,6 +108,37 @@ impl crate::wit::Wit for SituationWit {
         self.bus.publish(Topic::Situation, imp.clone());
         vec![imp]
     }
+}
+
+#[async_trait]
+impl crate::wit::Wit for SituationWit {
+    type Input = ();
+    type Output = String;
+
+    async fn observe(&self, _: Self::Input) {
+        // SituationWit receives moments via a [`TopicBus`] subscription.
+        // Nothing is expected through `observe`, so this is a no-op.
+    }
+
+    async fn tick(&self) -> Vec<Impression<Self::Output>> {
+        const MIN_ITEMS: usize = 3;
+        let items = {
+            let mut buf = self.buffer.lock().unwrap();
+            if buf.len() < MIN_ITEMS {
+                return Vec::new();
+            }
+            buf.drain(..).collect::<Vec<_>>()
+        };
+        self.summarize(items).await
+    }
+
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        let items = std::mem::take(&mut *self.buffer.lock().unwrap());
+        if items.is_empty() {
+            return Vec::new();
+        }
+        self.summarize(items).await
+    }
 
     fn debug_label(&self) -> &'static str {
         Self::LABEL
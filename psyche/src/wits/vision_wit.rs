@@ -106,6 +106,7 @@ impl Wit for VisionWit {
                         mime: img.mime.clone(),
                         base64: img.base64.clone(),
                         captured_at: img.captured_at.clone(),
+                        source: img.source.clone(),
                     }],
                 })
                 .await
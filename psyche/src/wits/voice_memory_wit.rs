@@ -38,43 +38,9 @@ impl VoiceMemoryWit {
             ..Self::new()
         }
     }
-}
-
-fn similarity(a: &[f32], b: &[f32]) -> f32 {
-    if a.is_empty() || b.is_empty() {
-        return 0.0;
-    }
-    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
-    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
-    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
-    dot / (norm_a * norm_b + 1e-5)
-}
-
-#[async_trait]
-impl Wit for VoiceMemoryWit {
-    type Input = VoiceInfo;
-    type Output = VoiceInfo;
-
-    async fn observe(&self, info: Self::Input) {
-        let timestamp = audio_captured_at(&info.clip).unwrap_or_else(chrono::Utc::now);
-        self.buffer.lock().unwrap().push(Stimulus {
-            what: info,
-            timestamp,
-            source_sensation_ids: Vec::new(),
-        });
-    }
-
-    async fn tick(&self) -> Vec<Impression<Self::Output>> {
-        let items = {
-            let mut buf = self.buffer.lock().unwrap();
-            if buf.is_empty() {
-                self.ticks_without_voice.fetch_add(1, Ordering::SeqCst);
-                return Vec::new();
-            }
-            self.ticks_without_voice.store(0, Ordering::SeqCst);
-            buf.drain(..).collect::<Vec<_>>()
-        };
 
+    /// Note each buffered voice as familiar or new.
+    async fn remember(&self, items: Vec<Stimulus<VoiceInfo>>) -> Vec<Impression<VoiceInfo>> {
         let mut out = Vec::new();
         for item in items {
             let info = item.what;
@@ -114,6 +80,49 @@ impl Wit for VoiceMemoryWit {
         }
         out
     }
+}
+
+fn similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    dot / (norm_a * norm_b + 1e-5)
+}
+
+#[async_trait]
+impl Wit for VoiceMemoryWit {
+    type Input = VoiceInfo;
+    type Output = VoiceInfo;
+
+    async fn observe(&self, info: Self::Input) {
+        let timestamp = audio_captured_at(&info.clip).unwrap_or_else(chrono::Utc::now);
+        self.buffer.lock().unwrap().push(Stimulus {
+            what: info,
+            timestamp,
+            source_sensation_ids: Vec::new(),
+        });
+    }
+
+    async fn tick(&self) -> Vec<Impression<Self::Output>> {
+        let items = {
+            let mut buf = self.buffer.lock().unwrap();
+            if buf.is_empty() {
+                self.ticks_without_voice.fetch_add(1, Ordering::SeqCst);
+                return Vec::new();
+            }
+            self.ticks_without_voice.store(0, Ordering::SeqCst);
+            buf.drain(..).collect::<Vec<_>>()
+        };
+        self.remember(items).await
+    }
+
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        let items = std::mem::take(&mut *self.buffer.lock().unwrap());
+        self.remember(items).await
+    }
 
     fn debug_label(&self) -> &'static str {
         Self::LABEL
@@ -175,6 +175,12 @@ impl crate::wit::Wit for Will {
         )]
     }
 
+    /// Decisions act on the world, so none are made while shutting down.
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        self.buffer.lock().unwrap().clear();
+        Vec::new()
+    }
+
     fn debug_label(&self) -> &'static str {
         Self::LABEL
     }
@@ -0,0 +1,117 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{Duration, TimeZone, Utc};
+use psyche::{AddresseeConfig, AddresseeCues, AddresseeDetector, AddresseeJudge};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct StubJudge {
+    answer: f32,
+    calls: AtomicUsize,
+}
+
+#[async_trait]
+impl AddresseeJudge for StubJudge {
+    async fn judge(&self, _cues: &AddresseeCues) -> Result<f32> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(self.answer)
+    }
+}
+
+fn at(seconds: i64) -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 5, 7, 12, 0, 0).unwrap() + Duration::seconds(seconds)
+}
+
+#[test]
+fn calling_pete_by_name_outweighs_mentioning_him() {
+    let detector = AddresseeDetector::default();
+
+    let (called, reasons) = detector.score(&AddresseeCues::new("Hey Pete, look at this", at(0)));
+    let (mentioned, _) = detector.score(&AddresseeCues::new("I think pete is asleep", at(0)));
+    let (closing, _) = detector.score(&AddresseeCues::new("isn't that right, Peter?", at(0)));
+
+    assert_eq!(reasons, ["name"]);
+    assert!(called >= 0.5 && closing >= 0.5);
+    assert!(mentioned < 0.5 && mentioned > detector.config().prior);
+}
+
+#[test]
+fn replies_fade_after_pete_speaks() {
+    let detector = AddresseeDetector::default();
+    let cues = |seconds| {
+        AddresseeCues::new("sure, go ahead", at(seconds)).with_pete_speech("Shall I?", at(0))
+    };
+
+    let (prompt, reasons) = detector.score(&cues(1));
+    let (late, _) = detector.score(&cues(8));
+    let (stale, stale_reasons) = detector.score(&cues(30));
+
+    assert_eq!(reasons, ["reply"]);
+    assert!(prompt >= 0.5);
+    assert!(late < prompt && late > stale);
+    assert!(stale_reasons.is_empty());
+}
+
+#[test]
+fn only_a_recent_face_looking_at_pete_adds_confidence() {
+    let detector = AddresseeDetector::default();
+    let score = |seconds, pose| {
+        detector.score(&AddresseeCues::new("hello", at(seconds)).with_face(at(0), pose))
+    };
+
+    let (facing, reasons) = score(2, Some(0.9));
+    let (turned_away, away_reasons) = score(2, Some(0.2));
+    let (unknown, _) = score(2, None);
+    let (stale, _) = score(60, Some(0.9));
+
+    assert_eq!(reasons, ["facing"]);
+    assert!(away_reasons.is_empty());
+    assert!(facing > turned_away);
+    assert_eq!(turned_away, unknown);
+    assert_eq!(turned_away, stale);
+}
+
+#[tokio::test]
+async fn judge_settles_only_ambiguous_speech() {
+    let judge = Arc::new(StubJudge {
+        answer: 1.0,
+        calls: AtomicUsize::new(0),
+    });
+    let detector = AddresseeDetector::new(AddresseeConfig::default()).with_judge(judge.clone());
+
+    let clear = detector
+        .detect("s:1", &AddresseeCues::new("Pete, what time is it?", at(0)))
+        .await;
+    assert!(clear.addressed);
+    assert_eq!(judge.calls.load(Ordering::SeqCst), 0);
+
+    let unclear = AddresseeCues::new("what time is it?", at(2)).with_face(at(0), Some(1.0));
+    let (before, _) = detector.score(&unclear);
+    let verdict = detector.detect("s:2", &unclear).await;
+
+    assert_eq!(judge.calls.load(Ordering::SeqCst), 1);
+    assert!(before < 0.5);
+    assert!(verdict.addressed);
+    assert_eq!(verdict.sensation_id, "s:2");
+    assert_eq!(verdict.reasons, ["facing", "judge"]);
+}
+
+#[tokio::test]
+async fn each_utterance_is_judged_once() {
+    let judge = Arc::new(StubJudge {
+        answer: 1.0,
+        calls: AtomicUsize::new(0),
+    });
+    let detector = AddresseeDetector::new(AddresseeConfig::default()).with_judge(judge.clone());
+    let unclear = AddresseeCues::new("what time is it?", at(2)).with_face(at(0), Some(1.0));
+
+    assert!(detector.quick_verdict("s:1", &unclear).is_none());
+    let first = detector.detect("s:1", &unclear).await;
+    let again = detector.detect("s:1", &unclear).await;
+    let shared = detector.clone().quick_verdict("s:1", &unclear);
+
+    assert_eq!(judge.calls.load(Ordering::SeqCst), 1);
+    assert_eq!(again, first);
+    assert_eq!(shared, Some(first));
+    assert!(detector.verdict("s:2").is_none());
+}
@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use futures::StreamExt;
+use lingproc::{Chatter, Doer, LlmInstruction, Message, TextStream, Vectorizer};
+use psyche::{Ear, Event, Mouth, Psyche, Sensation, SensationObserver};
+use std::any::Any;
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+use std::time::Duration;
+
+#[derive(Clone, Default)]
+struct Dummy;
+
+#[async_trait]
+impl Ear for Dummy {
+    async fn hear_self_say(&self, _t: &str) {}
+    async fn hear_user_say(&self, _t: &str) {}
+}
+
+#[async_trait]
+impl Doer for Dummy {
+    async fn follow(&self, _i: LlmInstruction) -> anyhow::Result<String> {
+        Ok("ok".into())
+    }
+}
+
+#[async_trait]
+impl Vectorizer for Dummy {
+    async fn vectorize(&self, _t: &str) -> anyhow::Result<Vec<f32>> {
+        Ok(vec![0.0])
+    }
+}
+
+/// Chatter that streams one sentence at a time.
+struct SlowChatter;
+
+#[async_trait]
+impl Chatter for SlowChatter {
+    async fn chat(&self, _s: &str, _h: &[Message]) -> anyhow::Result<TextStream> {
+        let chunks = ["One. ", "Two. ", "Three. ", "Four. ", "Five."];
+        Ok(Box::pin(futures::stream::iter(chunks).then(
+            |chunk| async move {
+                tokio::time::sleep(Duration::from_millis(40)).await;
+                Ok(chunk.to_string())
+            },
+        )))
+    }
+    async fn update_prompt_context(&self, _c: &str) {}
+}
+
+#[derive(Default)]
+struct RecMouth {
+    spoken: Mutex<Vec<String>>,
+    interrupts: AtomicUsize,
+}
+
+#[async_trait]
+impl Mouth for RecMouth {
+    async fn speak(&self, t: &str) {
+        self.spoken.lock().unwrap().push(t.to_string());
+    }
+    async fn interrupt(&self) {
+        self.interrupts.fetch_add(1, Ordering::SeqCst);
+    }
+    fn speaking(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Default)]
+struct Interruptions(Mutex<Vec<(String, String)>>);
+
+#[async_trait]
+impl SensationObserver for Interruptions {
+    async fn observe_sensation(&self, payload: &(dyn Any + Send + Sync)) {
+        if let Some(Sensation::InterruptedSpeaking { text, unsaid, .. }) =
+            payload.downcast_ref::<Sensation>()
+        {
+            self.0.lock().unwrap().push((text.clone(), unsaid.clone()));
+        }
+    }
+}
+
+fn psyche_with(mouth: Arc<RecMouth>) -> Psyche {
+    let mut psyche = Psyche::new(
+        Box::new(SlowChatter),
+        Box::new(Dummy),
+        Box::new(Dummy),
+        Arc::new(psyche::NoopMemory),
+        mouth,
+        Arc::new(Dummy),
+    );
+    psyche.set_turn_limit(usize::MAX);
+    psyche
+}
+
+#[tokio::test]
+async fn user_speech_cuts_off_the_rest_of_the_turn() {
+    let mouth = Arc::new(RecMouth::default());
+    let interruptions = Arc::new(Interruptions::default());
+    let mut psyche = psyche_with(mouth.clone());
+    psyche.register_observer(interruptions.clone());
+    let barge_in = psyche.barge_in();
+    let shutdown = psyche.shutdown_handle();
+    let mut events = psyche.subscribe();
+    let input = psyche.input_sender();
+    let handle = tokio::spawn(psyche.run());
+    input
+        .send(Sensation::web_interface_text("tell me a story"))
+        .await
+        .unwrap();
+
+    tokio::time::timeout(Duration::from_secs(2), async {
+        while mouth.spoken.lock().unwrap().is_empty() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("Pete never started speaking");
+    assert!(barge_in.user_speech_onset(chrono::Utc::now()).await);
+
+    let spoken = tokio::time::timeout(Duration::from_secs(2), async {
+        loop {
+            if let Ok(Event::SpeechInterrupted { spoken }) = events.recv().await {
+                return spoken;
+            }
+        }
+    })
+    .await
+    .expect("no interruption event");
+    assert!(spoken.starts_with("One."), "{spoken}");
+
+    tokio::time::timeout(Duration::from_secs(2), async {
+        while interruptions.0.lock().unwrap().is_empty() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("interruption was not recorded");
+    shutdown.trigger();
+    let _ = tokio::time::timeout(Duration::from_secs(5), handle).await;
+    assert_eq!(mouth.interrupts.load(Ordering::SeqCst), 1);
+    assert!(!mouth.spoken.lock().unwrap().iter().any(|s| s == "Five."));
+    let recorded = interruptions.0.lock().unwrap();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].0, spoken);
+}
+
+#[tokio::test]
+async fn onset_while_quiet_is_ignored() {
+    let mouth = Arc::new(RecMouth::default());
+    let psyche = psyche_with(mouth.clone());
+    let barge_in = psyche.barge_in();
+    assert!(!barge_in.pete_speaking());
+    assert!(!barge_in.user_speech_onset(chrono::Utc::now()).await);
+    assert_eq!(mouth.interrupts.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,232 @@
+use futures::TryStreamExt;
+use httpmock::{
+    Method::{DELETE, GET, PUT},
+    MockServer,
+};
+use psyche::{
+    BlobStore, LocalBlobStore, S3BlobConfig, S3BlobStore, SigV4Request, blob_hash, hmac_sha256,
+    is_blob_hash,
+};
+
+use std::time::{Duration, SystemTime};
+
+const HELLO_HASH: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+fn hex(bytes: [u8; 32]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn s3_store(server: &MockServer) -> S3BlobStore {
+    S3BlobStore::new(S3BlobConfig {
+        endpoint: server.base_url(),
+        bucket: "pete".into(),
+        region: "us-east-1".into(),
+        access_key: "access".into(),
+        secret_key: "secret".into(),
+        prefix: "media/".into(),
+    })
+}
+
+#[test]
+fn hashes_blobs_by_content() {
+    assert_eq!(blob_hash(b"hello"), HELLO_HASH);
+    assert!(is_blob_hash(HELLO_HASH));
+    assert!(!is_blob_hash(&HELLO_HASH.to_uppercase()));
+    assert!(!is_blob_hash(&HELLO_HASH[1..]));
+    assert!(!is_blob_hash("../etc/passwd"));
+}
+
+#[test]
+fn hmac_matches_rfc_4231() {
+    // Test cases 1, 2 and 6 of RFC 4231.
+    assert_eq!(
+        hex(hmac_sha256(&[0x0b; 20], b"Hi There")),
+        "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"
+    );
+    assert_eq!(
+        hex(hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+    assert_eq!(
+        hex(hmac_sha256(
+            &[0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First"
+        )),
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+    );
+}
+
+#[test]
+fn sigv4_matches_aws_examples() {
+    let empty = blob_hash(b"");
+    // `get-vanilla` from the AWS Signature Version 4 test suite.
+    let vanilla = SigV4Request {
+        method: "GET",
+        path: "/",
+        query: "",
+        headers: &[
+            ("host", "example.amazonaws.com"),
+            ("x-amz-date", "20150830T123600Z"),
+        ],
+        payload_hash: &empty,
+        amz_date: "20150830T123600Z",
+        region: "us-east-1",
+        service: "service",
+    };
+    assert_eq!(
+        vanilla.signature("wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY"),
+        "5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+    );
+    // The S3 "GET Bucket Lifecycle" header-signing example.
+    let lifecycle = SigV4Request {
+        method: "GET",
+        path: "/",
+        query: "lifecycle=",
+        headers: &[
+            ("host", "examplebucket.s3.amazonaws.com"),
+            ("x-amz-content-sha256", &empty),
+            ("x-amz-date", "20130524T000000Z"),
+        ],
+        payload_hash: &empty,
+        amz_date: "20130524T000000Z",
+        region: "us-east-1",
+        service: "s3",
+    };
+    assert_eq!(lifecycle.scope(), "20130524/us-east-1/s3/aws4_request");
+    assert_eq!(
+        lifecycle.signed_headers(),
+        "host;x-amz-content-sha256;x-amz-date"
+    );
+    assert_eq!(
+        lifecycle.signature("wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY"),
+        "fea454ca298b7da1c68078a5d1bdbfbbe0d65c699e0f91ac7a200a0136783543"
+    );
+}
+
+#[tokio::test]
+async fn local_store_round_trips_and_streams_blobs() {
+    let root = std::env::temp_dir().join(format!("psyche-blobs-{}", uuid::Uuid::new_v4()));
+    let store = LocalBlobStore::new(&root);
+
+    let hash = store.insert(b"hello").await.unwrap();
+    store.insert(b"hello").await.unwrap();
+
+    assert_eq!(hash, HELLO_HASH);
+    assert!(root.join("2c").join(HELLO_HASH).is_file());
+    assert_eq!(store.get(&hash).await.unwrap().unwrap(), b"hello");
+    let chunks: Vec<Vec<u8>> = store
+        .stream(&hash)
+        .await
+        .unwrap()
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(chunks.concat(), b"hello");
+    let missing = blob_hash(b"missing");
+    assert!(store.get(&missing).await.unwrap().is_none());
+    assert!(store.stream(&missing).await.unwrap().is_none());
+    assert!(store.get("../hello").await.is_err());
+
+    store.delete(&hash).await.unwrap();
+    store.delete(&hash).await.unwrap();
+    assert!(store.get(&hash).await.unwrap().is_none());
+    assert!(store.delete("../hello").await.is_err());
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[tokio::test]
+async fn local_store_collects_only_blobs_older_than_the_grace_period() {
+    let root = std::env::temp_dir().join(format!("psyche-blobs-{}", uuid::Uuid::new_v4()));
+    let store = LocalBlobStore::new(&root).with_gc_grace(Duration::from_secs(60));
+    let path = root.join("2c").join(HELLO_HASH);
+
+    let hash = store.insert(b"hello").await.unwrap();
+    assert!(!store.collect(&hash).await.unwrap());
+    assert!(path.is_file());
+
+    // A writer re-storing an old blob restarts its grace period.
+    let old = SystemTime::now() - Duration::from_secs(3600);
+    std::fs::File::options()
+        .append(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(old)
+        .unwrap();
+    store.insert(b"hello").await.unwrap();
+    assert!(!store.collect(&hash).await.unwrap());
+
+    std::fs::File::options()
+        .append(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(old)
+        .unwrap();
+    assert!(store.collect(&hash).await.unwrap());
+    assert!(store.get(&hash).await.unwrap().is_none());
+    assert!(!store.collect(&hash).await.unwrap());
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[tokio::test]
+async fn local_store_rejects_corrupt_blobs() {
+    let root = std::env::temp_dir().join(format!("psyche-blobs-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(root.join("2c")).unwrap();
+    std::fs::write(root.join("2c").join(HELLO_HASH), b"jello").unwrap();
+
+    assert!(LocalBlobStore::new(&root).get(HELLO_HASH).await.is_err());
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[tokio::test]
+async fn s3_store_signs_uploads_and_downloads() {
+    let server = MockServer::start_async().await;
+    let path = format!("/pete/media/{HELLO_HASH}");
+    let upload = server
+        .mock_async(|when, then| {
+            when.method(PUT)
+                .path(path.as_str())
+                .header("x-amz-content-sha256", HELLO_HASH)
+                .header_exists("x-amz-date")
+                .header_exists("authorization")
+                .body("hello");
+            then.status(200);
+        })
+        .await;
+    let download = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path(path.as_str())
+                .header_exists("authorization");
+            then.status(200).body("hello");
+        })
+        .await;
+    let missing = server
+        .mock_async(|when, then| {
+            when.method(GET).path_contains(blob_hash(b"missing"));
+            then.status(404);
+        })
+        .await;
+    let remove = server
+        .mock_async(|when, then| {
+            when.method(DELETE)
+                .path(path.as_str())
+                .header_exists("authorization");
+            then.status(204);
+        })
+        .await;
+    let store = s3_store(&server);
+
+    assert_eq!(store.insert(b"hello").await.unwrap(), HELLO_HASH);
+    assert_eq!(store.get(HELLO_HASH).await.unwrap().unwrap(), b"hello");
+    assert!(store.get(&blob_hash(b"missing")).await.unwrap().is_none());
+    store.delete(HELLO_HASH).await.unwrap();
+
+    upload.assert_async().await;
+    download.assert_async().await;
+    missing.assert_async().await;
+    remove.assert_async().await;
+}
@@ -0,0 +1,199 @@
+use async_trait::async_trait;
+use lingproc::{Chatter, Doer, LlmInstruction, Message, TextStream, Vectorizer};
+use psyche::wits::{FondDuCoeur, IdentityWit};
+use psyche::{Ear, Impression, Memory, Mouth, Psyche, PsycheCheckpoint, Sensation, Stimulus, Wit};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_stream::once;
+
+#[derive(Clone, Default)]
+struct Dummy;
+
+#[async_trait]
+impl Mouth for Dummy {
+    async fn speak(&self, _t: &str) {}
+    async fn interrupt(&self) {}
+    fn speaking(&self) -> bool {
+        false
+    }
+}
+
+#[async_trait]
+impl Ear for Dummy {
+    async fn hear_self_say(&self, _t: &str) {}
+    async fn hear_user_say(&self, _t: &str) {}
+}
+
+#[async_trait]
+impl Chatter for Dummy {
+    async fn chat(&self, _s: &str, _h: &[Message]) -> anyhow::Result<TextStream> {
+        Ok(Box::pin(once(Ok("ok".into()))))
+    }
+    async fn update_prompt_context(&self, _c: &str) {}
+}
+
+#[async_trait]
+impl Doer for Dummy {
+    async fn follow(&self, _i: LlmInstruction) -> anyhow::Result<String> {
+        Ok("ok".into())
+    }
+}
+
+#[async_trait]
+impl Vectorizer for Dummy {
+    async fn vectorize(&self, _t: &str) -> anyhow::Result<Vec<f32>> {
+        Ok(vec![0.0])
+    }
+}
+
+#[derive(Default)]
+struct RecordingMemory {
+    summaries: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl Memory for RecordingMemory {
+    async fn store(&self, impression: &Impression<Value>) -> anyhow::Result<()> {
+        self.summaries
+            .lock()
+            .unwrap()
+            .push(impression.summary.clone());
+        Ok(())
+    }
+}
+
+/// Wit that only has something to say when drained.
+struct LeftoverWit;
+
+#[async_trait]
+impl Wit for LeftoverWit {
+    type Input = ();
+    type Output = String;
+
+    async fn observe(&self, _: Self::Input) {}
+
+    async fn tick(&self) -> Vec<Impression<Self::Output>> {
+        Vec::new()
+    }
+
+    async fn drain(&self) -> Vec<Impression<Self::Output>> {
+        vec![Impression::new(
+            vec![Stimulus::new("unfinished thought".to_string())],
+            "leftover",
+            None::<String>,
+        )]
+    }
+}
+
+fn checkpoint_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("psyche-{name}-{}.json", std::process::id()))
+}
+
+fn psyche_with(memory: Arc<dyn Memory>) -> Psyche {
+    let mouth = Arc::new(Dummy);
+    let ear = mouth.clone();
+    let mut psyche = Psyche::new(
+        Box::new(Dummy),
+        Box::new(Dummy),
+        Box::new(Dummy),
+        memory,
+        mouth,
+        ear,
+    );
+    psyche.set_turn_limit(usize::MAX);
+    psyche.set_fallback_turn_enabled(false);
+    psyche.set_drain_timeout(Duration::from_secs(1));
+    psyche
+}
+
+async fn run_briefly(psyche: Psyche, input: Option<&str>) -> Psyche {
+    let shutdown = psyche.shutdown_handle();
+    let tx = psyche.input_sender();
+    let handle = tokio::spawn(psyche.run());
+    if let Some(text) = input {
+        tx.send(Sensation::web_interface_text(text)).await.unwrap();
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    shutdown.trigger();
+    tokio::time::timeout(Duration::from_secs(5), handle)
+        .await
+        .expect("psyche did not stop after shutdown")
+        .unwrap()
+}
+
+#[tokio::test]
+async fn restart_resumes_conversation_and_story() {
+    let path = checkpoint_path("resume");
+    let _ = std::fs::remove_file(&path);
+
+    let story = FondDuCoeur::new(Box::new(Dummy));
+    story.set_story("Pete met a friendly visitor.");
+    let mut first = psyche_with(Arc::new(psyche::NoopMemory));
+    first.set_checkpoint_path(&path);
+    first.register_typed_wit(Arc::new(IdentityWit::new(story)));
+    first.update_prompt_context("It is raining.").await;
+    let _ = run_briefly(first, Some("hello Pete")).await;
+
+    let saved = PsycheCheckpoint::load(&path)
+        .unwrap()
+        .expect("checkpoint saved");
+    assert_eq!(saved.version, PsycheCheckpoint::VERSION);
+    assert!(saved.conversation.iter().any(|m| m.content == "hello Pete"));
+
+    let story = FondDuCoeur::new(Box::new(Dummy));
+    let mut second = psyche_with(Arc::new(psyche::NoopMemory));
+    second.set_checkpoint_path(&path);
+    second.register_typed_wit(Arc::new(IdentityWit::new(story.clone())));
+    let second = run_briefly(second, None).await;
+
+    assert_eq!(story.story(), "Pete met a friendly visitor.");
+    let conversation = second.conversation();
+    let messages = conversation.lock().await.all();
+    assert!(messages.iter().any(|m| m.content == "hello Pete"));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn set_clock_waits_for_a_busy_conversation() {
+    let mut psyche = psyche_with(Arc::new(RecordingMemory::default()));
+    let clock = psyche::VirtualClock::default();
+    let conversation = psyche.conversation();
+    let busy = conversation.clone().lock_owned().await;
+    let release = tokio::spawn(async move {
+        tokio::task::yield_now().await;
+        drop(busy);
+    });
+
+    psyche.set_clock(Arc::new(clock.clone())).await;
+    release.await.unwrap();
+    clock.advance(Duration::from_secs(60));
+    conversation.lock().await.add_message_from_user("hi".into());
+
+    let log = conversation.lock().await.all_with_timestamps();
+    assert_eq!(log[0].at, psyche::Clock::now(&clock));
+}
+
+#[tokio::test]
+async fn shutdown_drains_wits_into_memory() {
+    let memory = Arc::new(RecordingMemory::default());
+    let mut psyche = psyche_with(memory.clone());
+    psyche.register_typed_wit(Arc::new(LeftoverWit));
+    let _ = run_briefly(psyche, None).await;
+    assert!(
+        memory
+            .summaries
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|s| s == "leftover")
+    );
+}
+
+#[test]
+fn missing_checkpoint_loads_as_none() {
+    let path = checkpoint_path("missing");
+    let _ = std::fs::remove_file(&path);
+    assert!(PsycheCheckpoint::load(&path).unwrap().is_none());
+}
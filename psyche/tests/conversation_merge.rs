@@ -15,3 +15,17 @@ fn consecutive_ai_messages_are_spaced_and_trimmed() {
     c.add_message_from_ai("world  ".into());
     assert_eq!(c.all()[0].content, "hello world");
 }
+
+#[test]
+fn messages_are_stamped_by_the_conversation_clock() {
+    let clock = psyche::VirtualClock::default();
+    let mut c = Conversation::default();
+    c.set_clock(std::sync::Arc::new(clock.clone()));
+    c.add_message_from_user("hi".into());
+    clock.advance(std::time::Duration::from_secs(3));
+    c.add_message_from_ai("hello".into());
+
+    let log = c.all_with_timestamps();
+    assert_eq!((log[1].at - log[0].at).num_seconds(), 3);
+    assert_eq!(log[1].at, psyche::Clock::now(&clock));
+}
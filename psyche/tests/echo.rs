@@ -0,0 +1,142 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use psyche::{EchoAction, EchoConfig, EchoFilter, HeardWord};
+
+fn at(ms: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 5, 7, 12, 0, 0).unwrap() + Duration::milliseconds(ms)
+}
+
+fn timed(words: &[(&str, i64)]) -> Vec<HeardWord> {
+    words
+        .iter()
+        .map(|(text, ms)| HeardWord {
+            text: text.to_string(),
+            start: at(*ms),
+            end: at(*ms + 300),
+        })
+        .collect()
+}
+
+#[test]
+fn recognises_a_garbled_echo_of_the_current_line() {
+    let filter = EchoFilter::default();
+    filter.speaking("I think it will rain later today.", at(0));
+
+    let verdict = filter.check("I think it'll rain later today", &[], at(1_500));
+
+    assert!(verdict.echo);
+    assert!(verdict.similarity >= 0.6);
+    assert_eq!(verdict.action, Some(EchoAction::Drop));
+    assert_eq!(
+        verdict.spoken.as_deref(),
+        Some("I think it will rain later today.")
+    );
+    assert!(
+        verdict
+            .spoken_id
+            .unwrap()
+            .starts_with("sensation:utterance:utterance:started_speaking:")
+    );
+}
+
+#[test]
+fn speech_after_pete_stops_is_not_an_echo() {
+    let filter = EchoFilter::default();
+    filter.speaking("Shall I read the news?", at(0));
+    filter.finished("Shall I read the news?", at(2_000));
+
+    let late = filter.check("shall I read the news", &[], at(10_000));
+    let words = timed(&[
+        ("yes", 4_500),
+        ("read", 4_800),
+        ("the", 5_100),
+        ("news", 5_400),
+    ]);
+    let answer = filter.check("yes read the news", &words, at(4_500));
+
+    assert!(!late.echo);
+    assert!(late.spoken.is_none());
+    assert!(!answer.echo);
+}
+
+#[test]
+fn word_timings_limit_matching_to_words_heard_during_playback() {
+    let filter = EchoFilter::default();
+    filter.finished("The kettle is boiling", at(2_000));
+
+    let echoed = timed(&[
+        ("the", 400),
+        ("kettle", 700),
+        ("is", 1_000),
+        ("boiling", 1_300),
+    ]);
+    let mut answered = timed(&[("the", 400), ("kettle", 700)]);
+    answered.extend(timed(&[
+        ("is", 6_000),
+        ("boiling", 6_300),
+        ("already", 6_600),
+    ]));
+
+    assert!(filter.check("The kettle is boiling", &echoed, at(400)).echo);
+    assert!(
+        !filter
+            .check("the kettle is boiling already", &answered, at(400))
+            .echo
+    );
+}
+
+#[test]
+fn short_transcripts_must_match_completely() {
+    let filter = EchoFilter::default();
+    filter.speaking("Yes, I can see you.", at(0));
+
+    assert!(filter.check("see you", &[], at(1_000)).echo);
+    assert!(!filter.check("see me", &[], at(1_000)).echo);
+}
+
+#[test]
+fn queued_lines_move_to_their_playback_time() {
+    let filter = EchoFilter::new(EchoConfig {
+        action: EchoAction::Reclassify,
+        ..EchoConfig::default()
+    });
+    filter.queued("Good morning to you all", at(0));
+    assert!(filter.check("good morning to you all", &[], at(1_000)).echo);
+    assert!(
+        !filter
+            .check("good morning to you all", &[], at(20_000))
+            .echo
+    );
+
+    filter.speaking("Good morning to you all", at(19_000));
+    let verdict = filter.check("good morning to you all", &[], at(20_000));
+
+    assert!(verdict.echo);
+    assert_eq!(verdict.action, Some(EchoAction::Reclassify));
+    assert!(verdict.spoken_id.is_some());
+}
+
+#[test]
+fn forgets_lines_beyond_its_history() {
+    let filter = EchoFilter::new(EchoConfig {
+        history: 2,
+        ..EchoConfig::default()
+    });
+    filter.speaking("Good morning.", at(0));
+    filter.speaking("How did you sleep?", at(0));
+    filter.speaking("The sun is out.", at(0));
+
+    let verdict = filter.check("good morning", &[], at(500));
+
+    assert!(!verdict.echo);
+    assert_eq!(verdict.similarity, 0.0);
+}
+
+#[test]
+fn parses_echo_actions() {
+    assert_eq!("drop".parse::<EchoAction>().unwrap(), EchoAction::Drop);
+    assert_eq!(
+        " Reclassify ".parse::<EchoAction>().unwrap(),
+        EchoAction::Reclassify
+    );
+    assert!("ignore".parse::<EchoAction>().is_err());
+}
@@ -23,6 +23,7 @@ fn dummy_face(v: f32) -> FaceInfo {
         mime: "m".into(),
         base64: "b".into(),
         captured_at: None,
+        source: None,
     };
     FaceInfo {
         face_id: image_content_id(&crop),
@@ -30,6 +31,7 @@ fn dummy_face(v: f32) -> FaceInfo {
         crop,
         embedding: vec![v],
         vector_id: None,
+        details: Default::default(),
     }
 }
 
@@ -37,6 +39,7 @@ fn dummy_object(v: f32) -> ObjectInfo {
     ObjectInfo {
         label: None,
         embedding: vec![v],
+        ..ObjectInfo::default()
     }
 }
 
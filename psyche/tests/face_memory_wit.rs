@@ -9,6 +9,7 @@ fn dummy_info(val: f32) -> FaceInfo {
         mime: "image/png".into(),
         base64: "".into(),
         captured_at: None,
+        source: None,
     };
     FaceInfo {
         face_id: image_content_id(&crop),
@@ -16,6 +17,7 @@ fn dummy_info(val: f32) -> FaceInfo {
         crop,
         embedding: vec![val],
         vector_id: None,
+        details: Default::default(),
     }
 }
 
@@ -0,0 +1,44 @@
+use psyche::{FaceLandmark, FaceQuality};
+
+fn points(points: &[(f32, f32)]) -> Vec<FaceLandmark> {
+    points.iter().map(|&(x, y)| FaceLandmark { x, y }).collect()
+}
+
+#[test]
+fn blurry_crops_score_below_sharp_ones() {
+    let flat = vec![128u8; 16 * 16];
+    let checkered: Vec<u8> = (0..16 * 16)
+        .map(|i| if (i % 16 + i / 16) % 2 == 0 { 0 } else { 255 })
+        .collect();
+
+    assert_eq!(FaceQuality::sharpness_of(&flat, 16, 16), 0.0);
+    assert!(FaceQuality::sharpness_of(&checkered, 16, 16) > 0.99);
+    assert_eq!(FaceQuality::sharpness_of(&checkered, 2, 2), 0.0);
+}
+
+#[test]
+fn turned_and_tilted_faces_score_below_frontal_ones() {
+    let frontal = points(&[
+        (30.0, 40.0),
+        (70.0, 40.0),
+        (50.0, 60.0),
+        (35.0, 80.0),
+        (65.0, 80.0),
+    ]);
+    let turned = points(&[(30.0, 40.0), (70.0, 40.0), (66.0, 60.0)]);
+    let tilted = points(&[(30.0, 30.0), (70.0, 50.0), (50.0, 60.0)]);
+
+    assert_eq!(FaceQuality::pose_of(&frontal), Some(1.0));
+    let turned = FaceQuality::pose_of(&turned).unwrap();
+    assert!((turned - 0.2).abs() < 1e-4);
+    assert!(FaceQuality::pose_of(&tilted).unwrap() < 0.95);
+    assert_eq!(FaceQuality::pose_of(&frontal[..2]), None);
+}
+
+#[test]
+fn overall_quality_scales_sharpness_by_pose() {
+    let quality = FaceQuality::new(0.8, Some(0.5));
+
+    assert!((quality.score - 0.4).abs() < 1e-6);
+    assert_eq!(FaceQuality::new(1.5, None).score, 1.0);
+}
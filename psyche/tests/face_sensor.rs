@@ -3,8 +3,9 @@ use futures::{StreamExt, pin_mut};
 use httpmock::{Method::GET, Method::PUT, MockServer};
 use lingproc::{Chatter, Doer, LlmInstruction, Message, Vectorizer};
 use psyche::{
-    Ear, ImageData, Mouth, Psyche, Sensation, Sensor, Topic,
-    sensors::face::{DummyDetector, FaceDetector, FaceInfo, FaceSensor},
+    BoundingBox, Ear, FaceConsent, FaceConsentCheck, ImageData, Mouth, Psyche, Sensation, Sensor,
+    Topic,
+    sensors::face::{DetectedFace, DummyDetector, FaceDetector, FaceInfo, FaceSensor},
     wits::memory::QdrantClient,
 };
 use std::sync::Arc;
@@ -101,6 +102,7 @@ async fn emits_face_info() {
             mime: "image/png".into(),
             base64: "AA==".into(),
             captured_at: None,
+            source: None,
         })
         .await;
     let sensed = sub.next().await.unwrap();
@@ -109,6 +111,8 @@ async fn emits_face_info() {
             let info = payload.downcast_ref::<FaceInfo>().unwrap();
             assert_eq!(info.crop.mime, "image/png");
             assert_eq!(info.embedding, vec![0.0]);
+            assert_eq!(info.details.bbox, Some(BoundingBox::FULL));
+            assert_eq!(info.details.confidence, Some(1.0));
         } else {
             panic!("wrong sensation")
         }
@@ -122,9 +126,9 @@ struct SeqDetector {
 
 #[async_trait]
 impl FaceDetector for SeqDetector {
-    async fn detect_faces(&self, image: &ImageData) -> anyhow::Result<Vec<(ImageData, Vec<f32>)>> {
+    async fn detect_faces(&self, image: &ImageData) -> anyhow::Result<Vec<DetectedFace>> {
         let e = self.embeddings.lock().unwrap().remove(0);
-        Ok(vec![(image.clone(), e)])
+        Ok(vec![DetectedFace::new(image.clone(), e)])
     }
 }
 
@@ -142,6 +146,7 @@ async fn skips_identical_face() {
         mime: "image/png".into(),
         base64: "AA==".into(),
         captured_at: None,
+        source: None,
     };
     sensor.sense(img.clone()).await;
     assert!(sub.next().await.is_some());
@@ -164,6 +169,7 @@ async fn stores_distinct_faces() {
         mime: "image/png".into(),
         base64: "AA==".into(),
         captured_at: None,
+        source: None,
     };
     sensor.sense(img.clone()).await;
     assert!(sub.next().await.is_some());
@@ -187,6 +193,7 @@ async fn skips_similar_face_detection_without_emitting() {
             mime: "image/png".into(),
             base64: "AA==".into(),
             captured_at: None,
+            source: None,
         })
         .await;
     assert!(sub.next().await.is_some());
@@ -195,8 +202,80 @@ async fn skips_similar_face_detection_without_emitting() {
             mime: "image/png".into(),
             base64: "AA==".into(),
             captured_at: None,
+            source: None,
         })
         .await;
     let second = tokio::time::timeout(std::time::Duration::from_millis(50), sub.next()).await;
     assert!(second.is_err());
 }
+
+/// Consent keyed by the first embedding value: 1 is named, 2 opted out.
+struct FirstValueConsent {
+    strict: bool,
+}
+
+#[async_trait]
+impl FaceConsentCheck for FirstValueConsent {
+    async fn consent(&self, embedding: &[f32]) -> anyhow::Result<FaceConsent> {
+        Ok(match embedding.first() {
+            Some(value) if *value == 1.0 => FaceConsent::Known,
+            Some(value) if *value == 2.0 => FaceConsent::OptedOut,
+            _ => FaceConsent::Unknown,
+        })
+    }
+
+    fn strict(&self) -> bool {
+        self.strict
+    }
+}
+
+async fn remembered_faces(strict: bool, embeddings: Vec<Vec<f32>>) -> Vec<Vec<f32>> {
+    let bus = psyche::TopicBus::new(16);
+    let count = embeddings.len();
+    let detector = Arc::new(SeqDetector {
+        embeddings: std::sync::Mutex::new(embeddings),
+    });
+    let (_server, qdrant) = qdrant_with_faces_collection(2).await;
+    let sensor = FaceSensor::new(detector, qdrant, bus.clone())
+        .with_consent(Arc::new(FirstValueConsent { strict }));
+    let sub = bus.subscribe(Topic::Sensation);
+    pin_mut!(sub);
+    let img = ImageData {
+        mime: "image/png".into(),
+        base64: "AA==".into(),
+        captured_at: None,
+        source: None,
+    };
+    for _ in 0..count {
+        sensor.sense(img.clone()).await;
+    }
+    let mut remembered = Vec::new();
+    while let Ok(Some(sensed)) =
+        tokio::time::timeout(std::time::Duration::from_millis(50), sub.next()).await
+    {
+        if let Some(Sensation::Of { payload, .. }) = sensed.downcast_ref::<Sensation>() {
+            remembered.push(
+                payload
+                    .downcast_ref::<FaceInfo>()
+                    .unwrap()
+                    .embedding
+                    .clone(),
+            );
+        }
+    }
+    remembered
+}
+
+#[tokio::test]
+async fn forgets_faces_without_consent() {
+    let faces = vec![vec![1.0, 0.0], vec![2.0, 1.0], vec![0.0, 1.0]];
+
+    assert_eq!(
+        remembered_faces(true, faces.clone()).await,
+        vec![vec![1.0, 0.0]]
+    );
+    assert_eq!(
+        remembered_faces(false, faces).await,
+        vec![vec![1.0, 0.0], vec![0.0, 1.0]]
+    );
+}
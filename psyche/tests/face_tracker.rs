@@ -0,0 +1,171 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use psyche::{BoundingBox, FaceTracker, FaceTrackerConfig, GraphFaceMatch};
+
+fn at(ms: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 5, 8, 9, 0, 0).unwrap() + Duration::milliseconds(ms)
+}
+
+fn bbox(x: f32) -> Option<BoundingBox> {
+    Some(BoundingBox {
+        x,
+        y: 0.2,
+        width: 0.2,
+        height: 0.3,
+    })
+}
+
+fn anna() -> GraphFaceMatch {
+    GraphFaceMatch {
+        face_id: "cluster:face:anna".into(),
+        identity: Some("Anna".into()),
+        nearest_vector_id: "point-anna".into(),
+        score: 0.93,
+    }
+}
+
+#[test]
+fn follows_a_face_that_stays_in_view() {
+    let tracker = FaceTracker::default();
+    let first = tracker.update(&[(bbox(0.40), &[1.0, 0.0][..])], at(0));
+    tracker.identified(&first.assignments[0].track_id, Some(anna()));
+
+    let second = tracker.update(&[(bbox(0.42), &[0.98, 0.1][..])], at(1_000));
+
+    let assignment = &second.assignments[0];
+    assert!(first.assignments[0].started);
+    assert!(first.assignments[0].needs_identity);
+    assert_eq!(assignment.track_id, first.assignments[0].track_id);
+    assert!(!assignment.started);
+    assert!(!assignment.needs_identity);
+    assert_eq!(assignment.recognition, Some(anna()));
+    assert_eq!(tracker.tracks()[0].frames, 2);
+}
+
+#[test]
+fn pairs_each_face_with_its_own_track() {
+    let tracker = FaceTracker::default();
+    let first = tracker.update(
+        &[(bbox(0.1), &[1.0, 0.0][..]), (bbox(0.6), &[0.0, 1.0][..])],
+        at(0),
+    );
+
+    // The two people appear in the other order in the next frame.
+    let second = tracker.update(
+        &[(bbox(0.62), &[0.0, 1.0][..]), (bbox(0.12), &[1.0, 0.0][..])],
+        at(500),
+    );
+
+    assert_eq!(
+        second.assignments[0].track_id,
+        first.assignments[1].track_id
+    );
+    assert_eq!(
+        second.assignments[1].track_id,
+        first.assignments[0].track_id
+    );
+    assert!(second.assignments.iter().all(|a| !a.started));
+}
+
+#[test]
+fn a_different_face_in_the_same_place_is_matched_again() {
+    let tracker = FaceTracker::default();
+    let first = tracker.update(&[(bbox(0.4), &[1.0, 0.0][..])], at(0));
+    tracker.identified(&first.assignments[0].track_id, Some(anna()));
+
+    let second = tracker.update(&[(bbox(0.42), &[0.0, 1.0][..])], at(1_000));
+
+    assert_eq!(
+        second.assignments[0].track_id,
+        first.assignments[0].track_id
+    );
+    assert!(second.assignments[0].needs_identity);
+}
+
+#[test]
+fn faces_far_away_and_unalike_start_new_tracks() {
+    let tracker = FaceTracker::default();
+    let first = tracker.update(&[(bbox(0.0), &[1.0, 0.0][..])], at(0));
+
+    let second = tracker.update(&[(bbox(0.7), &[0.0, 1.0][..])], at(1_000));
+
+    assert!(second.assignments[0].started);
+    assert_ne!(
+        second.assignments[0].track_id,
+        first.assignments[0].track_id
+    );
+    assert_eq!(tracker.tracks().len(), 2);
+}
+
+#[test]
+fn tracks_end_after_going_unseen() {
+    let tracker = FaceTracker::new(FaceTrackerConfig {
+        lost_after: std::time::Duration::from_secs(2),
+        ..FaceTrackerConfig::default()
+    });
+    let first = tracker.update(&[(bbox(0.4), &[1.0, 0.0][..])], at(0));
+
+    let empty = tracker.update(&[], at(1_500));
+    let ended = tracker.expire(at(3_000));
+
+    assert!(empty.ended.is_empty());
+    assert_eq!(ended.len(), 1);
+    assert_eq!(ended[0].id, first.assignments[0].track_id);
+    assert_eq!(ended[0].last_seen_at, at(0));
+    assert!(tracker.tracks().is_empty());
+}
+
+#[test]
+fn held_tracks_outlast_skipped_frames() {
+    let tracker = FaceTracker::new(FaceTrackerConfig {
+        lost_after: std::time::Duration::from_secs(2),
+        ..FaceTrackerConfig::default()
+    });
+    tracker.update(&[(bbox(0.4), &[1.0, 0.0][..])], at(0));
+
+    tracker.hold(at(1_500));
+
+    assert!(tracker.expire(at(3_000)).is_empty());
+    assert_eq!(tracker.tracks()[0].frames, 1);
+    assert_eq!(tracker.expire(at(4_000)).len(), 1);
+}
+
+#[test]
+fn uncommitted_plans_leave_the_tracker_unchanged() {
+    let tracker = FaceTracker::default();
+    let face = [(bbox(0.4), &[1.0, 0.0][..])];
+
+    // Storing the first frame failed, so it is tracked again.
+    let mut dropped = tracker.plan(&face, at(0));
+    dropped.identified(
+        &dropped.update().assignments[0].track_id.clone(),
+        Some(anna()),
+    );
+    drop(dropped);
+    assert!(tracker.tracks().is_empty());
+
+    let retried = tracker.plan(&face, at(0));
+    assert!(retried.update().assignments[0].started);
+    let first = tracker.commit(retried);
+    let second = tracker.update(&face, at(500));
+
+    assert_eq!(
+        second.assignments[0].track_id,
+        first.assignments[0].track_id
+    );
+    assert!(!second.assignments[0].started);
+}
+
+#[test]
+fn trackers_of_different_sources_name_tracks_apart() {
+    let door = FaceTracker::default().with_source("door");
+    let desk = FaceTracker::default().with_source("desk");
+
+    let at_door = door.update(&[(bbox(0.40), &[1.0, 0.0][..])], at(0));
+    let at_desk = desk.update(&[(bbox(0.40), &[1.0, 0.0][..])], at(0));
+
+    assert!(at_door.assignments[0].track_id.contains(":door:"));
+    assert_ne!(
+        at_door.assignments[0].track_id,
+        at_desk.assignments[0].track_id
+    );
+}
@@ -0,0 +1,109 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use psyche::{BoundingBox, GraphImageRegion, ImageData, LookRegion, crop_image};
+
+fn region(
+    node_id: &str,
+    name: Option<&str>,
+    track_id: Option<&str>,
+    width: f32,
+) -> GraphImageRegion {
+    GraphImageRegion {
+        node_id: node_id.into(),
+        kind: "face".into(),
+        name: name.map(Into::into),
+        track_id: track_id.map(Into::into),
+        bbox: BoundingBox {
+            x: 0.1,
+            y: 0.1,
+            width,
+            height: 0.2,
+        },
+    }
+}
+
+#[test]
+fn locates_named_regions_preferring_the_largest() {
+    let regions = [
+        region("face:1", Some("Travis"), Some("face-track:1"), 0.1),
+        region("face:2", Some("travis "), None, 0.3),
+        region("object:1", Some("cup"), None, 0.2),
+    ];
+
+    let (bbox, named) = LookRegion::Named(" TRAVIS".into())
+        .locate(&regions)
+        .unwrap();
+    assert_eq!(named.unwrap().node_id, "face:2");
+    assert!((bbox.width - 0.3).abs() < 1e-6);
+    let by_track = LookRegion::Named("face-track:1".into());
+    assert_eq!(
+        by_track.locate(&regions).unwrap().1.unwrap().node_id,
+        "face:1"
+    );
+    assert_eq!(by_track.describe(Some(&regions[0])), "Travis");
+    assert!(LookRegion::Named("dog".into()).locate(&regions).is_none());
+}
+
+#[test]
+fn clamps_boxes_to_the_frame() {
+    let look = LookRegion::Box(BoundingBox {
+        x: 0.75,
+        y: -0.5,
+        width: 0.5,
+        height: 1.0,
+    });
+
+    let (bbox, named) = look.locate(&[]).unwrap();
+
+    assert!(named.is_none());
+    assert_eq!(
+        bbox,
+        BoundingBox {
+            x: 0.75,
+            y: 0.0,
+            width: 0.25,
+            height: 0.5,
+        }
+    );
+    assert!(
+        LookRegion::Box(BoundingBox {
+            x: 1.5,
+            y: 0.0,
+            width: 0.5,
+            height: 1.0,
+        })
+        .locate(&[])
+        .is_none()
+    );
+}
+
+#[test]
+fn crops_the_region_with_padding() {
+    let mut pixels = image::RgbImage::new(16, 8);
+    pixels.put_pixel(10, 5, image::Rgb([255, 0, 0]));
+    let mut png = std::io::Cursor::new(Vec::new());
+    pixels.write_to(&mut png, image::ImageFormat::Png).unwrap();
+    let frame = ImageData {
+        mime: "image/png".into(),
+        base64: BASE64_STANDARD.encode(png.into_inner()),
+        captured_at: Some("2026-05-05T12:34:56Z".into()),
+        source: None,
+    };
+    let third_quarter = BoundingBox {
+        x: 0.5,
+        y: 0.0,
+        width: 0.25,
+        height: 1.0,
+    };
+
+    let crop = crop_image(&frame, &third_quarter, 0.5).unwrap();
+
+    let decoded = image::load_from_memory(&BASE64_STANDARD.decode(crop.base64).unwrap())
+        .unwrap()
+        .to_rgb8();
+    // Two pixels of padding each side; the frame edges stop the rest.
+    assert_eq!(decoded.dimensions(), (8, 8));
+    assert_eq!(decoded.get_pixel(4, 5), &image::Rgb([255, 0, 0]));
+    assert_eq!(crop.mime, "image/png");
+    assert_eq!(crop.captured_at, frame.captured_at);
+}
@@ -79,6 +79,7 @@ async fn memory_graph_stores_reconstructable_image_payload() {
         mime: "image/png".into(),
         base64: "abc123".into(),
         captured_at: Some("2026-05-05T12:34:56Z".into()),
+        source: None,
     };
     let image_id = image_content_id(&image);
 
@@ -42,3 +42,27 @@ async fn summarizes_and_emits_report() {
     assert!(out[0].stimuli[0].what.contains("h1"));
     psyche::disable_debug("Memory").await;
 }
+
+#[tokio::test]
+async fn drain_stores_collection_below_threshold() {
+    let mem = Arc::new(DummyMemory::default());
+    let wit = MemoryWit::new(mem.clone());
+    wit.observe(Impression::new(
+        vec![Stimulus::new("d0".to_string())],
+        "h0",
+        None::<String>,
+    ))
+    .await;
+    assert!(wit.tick().await.is_empty());
+    wit.observe(Impression::new(
+        vec![Stimulus::new("d1".to_string())],
+        "h1",
+        None::<String>,
+    ))
+    .await;
+
+    let out = wit.drain().await;
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].summary, "h0 h1");
+    assert_eq!(*mem.0.lock().unwrap(), vec!["h0 h1".to_string()]);
+}
@@ -54,6 +54,19 @@ async fn does_nothing_with_fewer_instants() {
     assert!(out.is_empty());
 }
 
+#[tokio::test]
+async fn drain_summarizes_fewer_instants() {
+    let bus = TopicBus::new(8);
+    let wit = MomentWit::new(bus.clone(), Arc::new(DummyDoer));
+    sleep(Duration::from_millis(20)).await;
+    publish_instants(&bus, 2);
+    sleep(Duration::from_millis(50)).await;
+    let out = wit.drain().await;
+    assert_eq!(out.len(), 1);
+    assert!(out[0].summary.contains("i1"));
+    assert!(wit.drain().await.is_empty());
+}
+
 #[tokio::test]
 async fn debug_report_contains_prompt_and_summary() {
     let bus = TopicBus::new(8);
@@ -1,13 +1,19 @@
 use chrono::Utc;
 use httpmock::{Method::POST, MockServer};
 use psyche::{
-    AudioClip, GeoLoc, GraphAudioClip, GraphAudioSourceSpan, GraphAwareness, GraphClusterItem,
-    GraphClusterTheme, GraphConsolidatedSpeechCandidate, GraphConsolidatedSpeechSource,
-    GraphFaceDetection, GraphFaceIdentityLabel, GraphFaceIdentityTarget, GraphGeolocation,
-    GraphImageDescription, GraphImageFrame, GraphSceneVectorization, GraphSpeechSegment,
-    GraphTimelineItem, GraphTimelineWindow, GraphVoiceClip, GraphVoiceIdentityLabel,
-    GraphVoiceIdentityTarget, GraphVoiceRecognition, GraphVoiceSample, GraphVoiceSignature,
-    ImageData, Neo4jClient, VectorCluster, VectorClusterMember,
+    AudioClip, BlobStore, BoundingBox, FaceDetails, FaceLandmark, FaceQuality, FaceTrack, GeoLoc,
+    GraphAudioClip, GraphAudioSourceSpan, GraphAwareness, GraphClusterItem, GraphClusterTheme,
+    GraphConsolidatedSpeechCandidate, GraphConsolidatedSpeechSource, GraphDiarization,
+    GraphDiarizedSpeaker, GraphFaceDetection, GraphFaceIdentityLabel, GraphFaceIdentityTarget,
+    GraphFaceRegion, GraphFaceTrack, GraphGeolocation, GraphIdentity, GraphIdentityPurge,
+    GraphIdentityVector, GraphImageDescription, GraphImageFrame, GraphLookAnswer,
+    GraphMediaPayload, GraphObjectDetection, GraphPerson, GraphPersonCandidate,
+    GraphSceneDuplicate, GraphSceneVectorization, GraphSpeakerAttribution, GraphSpeakerTurn,
+    GraphSpeechSegment, GraphTextReading, GraphTimelineItem, GraphTimelineWindow, GraphVoiceClip,
+    GraphVoiceIdentity, GraphVoiceIdentityLabel, GraphVoiceIdentityTarget, GraphVoiceRecognition,
+    GraphVoiceSample, GraphVoiceSignature, ImageData, ImageRunKind, LeaseSettings, LocalBlobStore,
+    Neo4jClient, SceneFingerprint, VectorCluster, VectorClusterMember, WorkLease, blob_hash,
+    with_work_lease,
 };
 use serde_json::{Value, json};
 
@@ -320,6 +326,7 @@ async fn neo4j_client_loads_latest_untranscribed_audio_clip() {
                 .body_contains("MATCH (a:GraphNode:AudioClip)")
                 .body_contains("a.transcript IS NULL")
                 .body_contains("NOT (a)-[:HAS_TRANSCRIPTION]->(:GraphNode:Transcription)")
+                .body_contains("MATCH (lease:WorkLease")
                 .body_contains("OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(a)")
                 .body_contains("ORDER BY observed_at DESC");
             then.status(200).json_body(json!({
@@ -593,7 +600,7 @@ async fn neo4j_client_loads_latest_unprocessed_audio_clip_for_voice_recognition(
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_latest_unprocessed_image_frame_for_face_recognition() {
+async fn neo4j_client_loads_newest_unprocessed_image_frame_for_face_recognition() {
     let server = MockServer::start_async().await;
     let query = server
         .mock_async(|when, then| {
@@ -602,7 +609,11 @@ async fn neo4j_client_loads_latest_unprocessed_image_frame_for_face_recognition(
                 .body_contains("MATCH (i:GraphNode:Image)")
                 .body_contains("HAS_FACE_RECOGNITION_RUN")
                 .body_contains("OPTIONAL MATCH (s:GraphNode:Sensation)-[:OBSERVED]->(i)")
-                .body_contains("ORDER BY observed_at DESC");
+                .body_contains("ORDER BY observed_at DESC")
+                .body_contains("\"not_before\":\"2026-05-05T12:34:00+00:00\"")
+                .body_contains("\"tracking_owner\":\"worker-a\"")
+                .body_contains("\"tracking_kind\":\"face_tracking\"")
+                .body_contains("\"default_source\":\"camera\"");
             then.status(200).json_body(json!({
                 "results": [{
                     "columns": [
@@ -611,7 +622,8 @@ async fn neo4j_client_loads_latest_unprocessed_image_frame_for_face_recognition(
                         "i.base64",
                         "i.captured_at",
                         "i.occurred_at",
-                        "s.id"
+                        "s.id",
+                        "i.source"
                     ],
                     "data": [{
                         "row": [
@@ -620,7 +632,8 @@ async fn neo4j_client_loads_latest_unprocessed_image_frame_for_face_recognition(
                             "/9j/AA==",
                             "2026-05-05T12:34:56Z",
                             "2026-05-05T12:34:57Z",
-                            "sensation:image:image:1:2026-05-05T12:34:56Z"
+                            "sensation:image:image:1:2026-05-05T12:34:56Z",
+                            "door"
                         ]
                     }]
                 }],
@@ -629,8 +642,9 @@ async fn neo4j_client_loads_latest_unprocessed_image_frame_for_face_recognition(
         })
         .await;
 
+    let not_before = "2026-05-05T12:34:00Z".parse().unwrap();
     let frame = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .latest_unprocessed_image_frame_for_face_recognition()
+        .newest_unprocessed_image_frame_for_face_recognition(Some(not_before), Some("worker-a"))
         .await
         .unwrap()
         .unwrap();
@@ -647,6 +661,7 @@ async fn neo4j_client_loads_latest_unprocessed_image_frame_for_face_recognition(
         frame.sensation_id.as_deref(),
         Some("sensation:image:image:1:2026-05-05T12:34:56Z")
     );
+    assert_eq!(frame.source(), "door");
     query.assert_async().await;
 }
 
@@ -709,6 +724,138 @@ async fn neo4j_client_loads_latest_unprocessed_image_frame_for_scene_vectorizati
     query.assert_async().await;
 }
 
+#[tokio::test]
+async fn neo4j_client_loads_latest_unprocessed_image_frame_for_object_detection() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (i:GraphNode:Image)")
+                .body_contains("HAS_OBJECT_DETECTION_RUN")
+                .body_contains("ObjectDetectionRun")
+                .body_contains("kind: \\\"object_detection\\\"")
+                .body_contains("ORDER BY observed_at DESC");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [
+                        "i.id",
+                        "i.mime",
+                        "i.base64",
+                        "i.captured_at",
+                        "i.occurred_at",
+                        "s.id"
+                    ],
+                    "data": [{
+                        "row": [
+                            "image:1",
+                            "image/jpeg",
+                            "/9j/AA==",
+                            "2026-05-05T12:34:56Z",
+                            null,
+                            null
+                        ]
+                    }]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let frame = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .latest_unprocessed_image_frame_for_object_detection()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(frame.id, "image:1");
+    assert_eq!(frame.image.base64, "/9j/AA==");
+    assert_eq!(frame.occurred_at, None);
+    assert_eq!(frame.sensation_id, None);
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_latest_unprocessed_image_frame_for_text_recognition() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("HAS_TEXT_RECOGNITION_RUN")
+                .body_contains("TextRecognitionRun")
+                .body_contains("kind: \\\"text_recognition\\\"");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [],
+                    "data": [{
+                        "row": [
+                            "image:1",
+                            "image/png",
+                            "iVBORw0KGgo=",
+                            null,
+                            "2026-05-05T12:34:56Z",
+                            "sensation:image:1"
+                        ]
+                    }]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let frame = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .latest_unprocessed_image_frame_for_text_recognition()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(frame.id, "image:1");
+    assert_eq!(frame.occurred_at.as_deref(), Some("2026-05-05T12:34:56Z"));
+    assert_eq!(frame.sensation_id.as_deref(), Some("sensation:image:1"));
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_finds_recently_read_texts() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("TextBlock")
+                .body_contains(r#""text_ids":["text-block:a","text-block:b"]"#)
+                .body_contains(r#""since":"2026-05-05T12:00:00+00:00""#);
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": ["t.id"],
+                    "data": [{"row": ["text-block:b"]}]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+    let graph = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into());
+
+    let recent = graph
+        .recently_read_texts(
+            &["text-block:a".into(), "text-block:b".into()],
+            "2026-05-05T12:00:00+00:00",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(recent, ["text-block:b"]);
+    assert!(
+        graph
+            .recently_read_texts(&[], "2026-05-05T12:00:00+00:00")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    query.assert_hits_async(1).await;
+}
+
 #[tokio::test]
 async fn neo4j_client_loads_latest_unprocessed_image_frame_for_description() {
     let server = MockServer::start_async().await;
@@ -1460,6 +1607,126 @@ async fn neo4j_client_consolidates_big_audio_transcription() {
     update.assert_async().await;
 }
 
+#[tokio::test]
+async fn neo4j_client_loads_latest_big_transcription_for_diarization() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (t:GraphNode:Transcription)")
+                .body_contains("HAS_DIARIZATION_RUN")
+                .body_contains("HAS_SEGMENT");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": ["t.id", "sources", "segments"],
+                    "data": [{
+                        "row": [
+                            "big:1",
+                            [{
+                                "index": 0,
+                                "id": "audio:1",
+                                "mime": "audio/pcm;format=s16le;rate=16000",
+                                "base64": "AAA=",
+                                "sample_rate": 16000,
+                                "channels": 1,
+                                "start_ms": 0,
+                                "end_ms": 2000
+                            }],
+                            [{
+                                "id": "big:1:segment:0",
+                                "index": 0,
+                                "text": "hello there",
+                                "start_ms": 120,
+                                "end_ms": 900,
+                                "occurred_at": "2026-05-05T12:34:56Z"
+                            }]
+                        ]
+                    }]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let candidate = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .latest_big_transcription_for_diarization()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(candidate.transcription_id, "big:1");
+    assert_eq!(candidate.sources[0].clip.id, "audio:1");
+    assert_eq!(candidate.sources[0].end_ms, 2000);
+    assert_eq!(candidate.segments[0].text, "hello there");
+    assert_eq!(candidate.segments[0].start_ms, 120);
+    assert_eq!(candidate.segments[0].ended_at, None);
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_attaches_speaker_diarization() {
+    let server = MockServer::start_async().await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let merge = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("\"id\":\"diarization:big:1\"")
+                .body_contains("DiarizationRun")
+                .body_contains("HAS_DIARIZATION_RUN")
+                .body_contains("\"id\":\"diarization:big:1:speaker:0\"")
+                .body_contains("SAME_VOICE_AS")
+                .body_contains("voice:alice")
+                .body_contains("SPOKEN_BY")
+                .body_contains("SpeakerTurn")
+                .body_contains("I heard Alice say: hello there");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let diarization = GraphDiarization {
+        speakers: vec![GraphDiarizedSpeaker {
+            index: 0,
+            voice: Some(GraphVoiceIdentity {
+                voice_id: "voice:alice".into(),
+                identity: Some("Alice".into()),
+                person_id: None,
+            }),
+            score: Some(0.91),
+            window_count: 3,
+        }],
+        attributions: vec![GraphSpeakerAttribution {
+            segment_id: "big:1:segment:0".into(),
+            speaker: 0,
+            confidence: 1.0,
+        }],
+        turns: vec![GraphSpeakerTurn {
+            speaker: 0,
+            text: "hello there".into(),
+            start_ms: 120,
+            end_ms: 900,
+            occurred_at: Some("2026-05-05T12:34:56Z".into()),
+            ended_at: None,
+            segment_ids: vec!["big:1:segment:0".into()],
+        }],
+    };
+
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_speaker_diarization("big:1", "speaker_embedding_extractor.onnx", &diarization)
+        .await
+        .unwrap();
+
+    constraint.assert_async().await;
+    merge.assert_async().await;
+}
+
 #[tokio::test]
 async fn neo4j_client_attaches_face_recognition() {
     let server = MockServer::start_async().await;
@@ -1495,6 +1762,10 @@ async fn neo4j_client_attaches_face_recognition() {
                     psyche::face_familiarity_sensation_text(false)
                 ))
                 .body_contains("\"embedding_len\":512")
+                .body_contains("\"bbox_x\":0.25")
+                .body_contains("\"landmarks\":[0.375,0.5]")
+                .body_contains("\"quality\":0.5")
+                .body_contains("\"detection_confidence\":0.75")
                 .body_contains("\"detector\":\"face_id\"");
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
@@ -1508,6 +1779,7 @@ async fn neo4j_client_attaches_face_recognition() {
                     mime: "image/jpeg".into(),
                     base64: "/9j/AA==".into(),
                     captured_at: Some("2026-05-05T12:34:56Z".into()),
+                    source: None,
                 },
                 occurred_at: Some("2026-05-05T12:34:57Z".into()),
                 sensation_id: Some("sensation:image:1".into()),
@@ -1520,10 +1792,23 @@ async fn neo4j_client_attaches_face_recognition() {
                     mime: "image/jpeg".into(),
                     base64: "/9j/crop==".into(),
                     captured_at: Some("2026-05-05T12:34:56Z".into()),
+                    source: None,
                 },
                 vector_id: "point-1".into(),
                 embedding_len: 512,
                 recognition: None,
+                details: FaceDetails {
+                    bbox: Some(BoundingBox {
+                        x: 0.25,
+                        y: 0.25,
+                        width: 0.5,
+                        height: 0.5,
+                    }),
+                    landmarks: vec![FaceLandmark { x: 0.375, y: 0.5 }],
+                    confidence: Some(0.75),
+                    quality: Some(FaceQuality::new(0.5, None)),
+                },
+                track: None,
             }],
         )
         .await
@@ -1570,6 +1855,7 @@ async fn neo4j_client_attaches_face_recognition_sensation_for_zero_faces() {
                     mime: "image/jpeg".into(),
                     base64: "/9j/AA==".into(),
                     captured_at: Some("2026-05-05T12:34:56Z".into()),
+                    source: None,
                 },
                 occurred_at: Some("2026-05-05T12:34:57Z".into()),
                 sensation_id: Some("sensation:image:1".into()),
@@ -1626,6 +1912,7 @@ async fn neo4j_client_attaches_per_face_identity_sensation() {
                     mime: "image/jpeg".into(),
                     base64: "/9j/AA==".into(),
                     captured_at: Some("2026-05-05T12:34:56Z".into()),
+                    source: None,
                 },
                 occurred_at: Some("2026-05-05T12:34:57Z".into()),
                 sensation_id: Some("sensation:image:1".into()),
@@ -1639,6 +1926,7 @@ async fn neo4j_client_attaches_per_face_identity_sensation() {
                         mime: "image/jpeg".into(),
                         base64: "/9j/crop==".into(),
                         captured_at: Some("2026-05-05T12:34:56Z".into()),
+                        source: None,
                     },
                     vector_id: "point-1".into(),
                     embedding_len: 512,
@@ -1648,6 +1936,8 @@ async fn neo4j_client_attaches_per_face_identity_sensation() {
                         nearest_vector_id: "known-point".into(),
                         score: 0.93,
                     }),
+                    details: FaceDetails::default(),
+                    track: None,
                 },
                 GraphFaceDetection {
                     index: 1,
@@ -1656,6 +1946,7 @@ async fn neo4j_client_attaches_per_face_identity_sensation() {
                         mime: "image/jpeg".into(),
                         base64: "/9j/crop2==".into(),
                         captured_at: Some("2026-05-05T12:34:56Z".into()),
+                        source: None,
                     },
                     vector_id: "point-2".into(),
                     embedding_len: 512,
@@ -1665,6 +1956,8 @@ async fn neo4j_client_attaches_per_face_identity_sensation() {
                         nearest_vector_id: "known-point-2".into(),
                         score: 0.91,
                     }),
+                    details: FaceDetails::default(),
+                    track: None,
                 },
             ],
         )
@@ -1675,8 +1968,22 @@ async fn neo4j_client_attaches_per_face_identity_sensation() {
     update.assert_async().await;
 }
 
+fn scene_frame() -> GraphImageFrame {
+    GraphImageFrame {
+        id: "image:2".into(),
+        image: ImageData {
+            mime: "image/jpeg".into(),
+            base64: "/9j/AA==".into(),
+            captured_at: Some("2026-05-05T12:34:57Z".into()),
+            source: None,
+        },
+        occurred_at: None,
+        sensation_id: Some("sensation:image:2".into()),
+    }
+}
+
 #[tokio::test]
-async fn neo4j_client_attaches_scene_vectorization() {
+async fn neo4j_client_links_duplicate_frames_to_their_keyframe() {
     let server = MockServer::start_async().await;
     let constraint = server
         .mock_async(|when, then| {
@@ -1690,35 +1997,29 @@ async fn neo4j_client_attaches_scene_vectorization() {
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("SceneVectorizationRun")
-                .body_contains("HAS_SCENE_VECTORIZATION_RUN")
-                .body_contains("HAS_SCENE_VECTOR")
-                .body_contains("DERIVED_FROM")
-                .body_contains("qdrant:scene_vectors:point-1")
-                .body_contains("sensation:image:1")
-                .body_contains("\"embedding_len\":512")
-                .body_contains("\"model\":\"clip-test\"");
+                .body_contains("\"scene_hash\":\"00000000000000ff\"")
+                .body_contains("scene_thumbnail")
+                .body_contains("DUPLICATE_OF")
+                .body_contains("\"to\":\"image:1\"")
+                .body_contains("\"hash_distance\":2");
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
+    let fingerprint = SceneFingerprint {
+        hash: 0xff,
+        thumbnail: vec![0; 256],
+    };
 
     Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_scene_vectorization(
-            &GraphImageFrame {
-                id: "image:1".into(),
-                image: ImageData {
-                    mime: "image/jpeg".into(),
-                    base64: "/9j/AA==".into(),
-                    captured_at: Some("2026-05-05T12:34:56Z".into()),
-                },
-                occurred_at: Some("2026-05-05T12:34:57Z".into()),
-                sensation_id: Some("sensation:image:1".into()),
-            },
-            "clip-test",
-            &GraphSceneVectorization {
-                vector_id: "point-1".into(),
-                embedding_len: 512,
-            },
+        .attach_scene_fingerprint(
+            &scene_frame(),
+            &fingerprint,
+            Some(&GraphSceneDuplicate {
+                keyframe_id: "image:1".into(),
+                hash_distance: 2,
+                difference: 0.01,
+                scene_similarity: None,
+            }),
         )
         .await
         .unwrap();
@@ -1728,201 +2029,114 @@ async fn neo4j_client_attaches_scene_vectorization() {
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_image_description() {
+async fn neo4j_client_loads_scene_state_of_a_frame() {
     let server = MockServer::start_async().await;
-    let constraint = server
-        .mock_async(|when, then| {
-            when.method(POST)
-                .path("/db/neo4j/tx/commit")
-                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
-        })
-        .await;
-    let update = server
+    let fingerprint = SceneFingerprint {
+        hash: 0xff,
+        thumbnail: vec![7; 256],
+    };
+    let query = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("ImageDescriptionRun")
-                .body_contains("ImageDescription")
-                .body_contains("HAS_IMAGE_DESCRIPTION_RUN")
-                .body_contains("HAS_IMAGE_DESCRIPTION")
-                .body_contains("HAS_IMAGE_DESCRIPTION_VECTOR")
-                .body_contains("qdrant:image_descriptions:point-1")
-                .body_contains("sensation:image:1")
-                .body_contains("\"embedding_len\":768")
-                .body_contains("\"model\":\"vision-test\"")
-                .body_contains("\"embedding_model\":\"embed-test\"");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+                .body_contains("DUPLICATE_OF")
+                .body_contains("HAS_SCENE_VECTOR")
+                .body_contains("image:2");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": ["i.id", "i.scene_hash", "i.scene_thumbnail", "k.id", "v.point_id", "observed_at"],
+                    "data": [{
+                        "row": [
+                            "image:2",
+                            fingerprint.hash_hex(),
+                            fingerprint.thumbnail_base64(),
+                            "image:1",
+                            "point-2",
+                            "2026-05-05T12:34:57Z"
+                        ]
+                    }]
+                }],
+                "errors": []
+            }));
         })
         .await;
 
-    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_image_description(
-            &GraphImageFrame {
-                id: "image:1".into(),
-                image: ImageData {
-                    mime: "image/jpeg".into(),
-                    base64: "/9j/AA==".into(),
-                    captured_at: Some("2026-05-05T12:34:56Z".into()),
-                },
-                occurred_at: Some("2026-05-05T12:34:57Z".into()),
-                sensation_id: Some("sensation:image:1".into()),
-            },
-            "vision-test",
-            "embed-test",
-            &GraphImageDescription {
-                description_id: "image-description-text:image:1".into(),
-                text: "I see a test frame.".into(),
-                vector_id: "point-1".into(),
-                embedding_len: 768,
-            },
-        )
+    let scene = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .scene_frame("image:2")
         .await
+        .unwrap()
         .unwrap();
 
-    constraint.assert_async().await;
-    update.assert_async().await;
+    query.assert_async().await;
+    assert_eq!(scene.fingerprint, Some(fingerprint));
+    assert_eq!(scene.duplicate_of.as_deref(), Some("image:1"));
+    assert_eq!(scene.scene_vector_id.as_deref(), Some("point-2"));
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_geolocation_vectorization() {
+async fn neo4j_client_marks_keyframes_with_their_observation_time() {
     let server = MockServer::start_async().await;
-    let constraint = server
+    server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+                .body_contains("CREATE INDEX pete_image_keyframe_at");
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
+    let at = chrono::DateTime::parse_from_rfc3339("2026-05-05T12:34:57Z")
+        .unwrap()
+        .timestamp_millis();
     let update = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("GeolocationVectorizationRun")
-                .body_contains("HAS_GEOLOCATION_VECTORIZATION_RUN")
-                .body_contains("HAS_GEOLOCATION_VECTOR")
-                .body_contains("PROCESSED_GEOLOCATION")
-                .body_contains("qdrant:geolocations:point-1")
-                .body_contains("sensation:geolocation:1")
-                .body_contains("\"embedding_len\":3")
-                .body_contains("\"model\":\"earth-unit-sphere/v1\"");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
-        })
-        .await;
-
-    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_geolocation_vectorization(
-            &GraphGeolocation {
-                id: "geolocation:1".into(),
-                loc: GeoLoc {
-                    latitude: 37.7749,
-                    longitude: -122.4194,
-                    observed_at: Some("2026-05-05T12:34:56Z".into()),
-                },
-                occurred_at: Some("2026-05-05T12:34:57Z".into()),
-                sensation_id: Some("sensation:geolocation:1".into()),
-            },
-            "earth-unit-sphere/v1",
-            "point-1",
-            3,
-        )
-        .await
-        .unwrap();
-
-    constraint.assert_async().await;
-    update.assert_async().await;
-}
-
-#[tokio::test]
-async fn neo4j_client_attaches_vector_clusters() {
-    let server = MockServer::start_async().await;
-    let constraint = server
-        .mock_async(|when, then| {
-            when.method(POST)
-                .path("/db/neo4j/tx/commit")
-                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
-        })
-        .await;
-    let update = server
-        .mock_async(|when, then| {
-            when.method(POST)
-                .path("/db/neo4j/tx/commit")
-                .body_contains("ClusterDiscoveryRun")
-                .body_contains("Cluster")
-                .body_contains("PRODUCED_CLUSTER")
-                .body_contains("HAS_CLUSTER_MEMBER")
-                .body_contains("MEMBER_OF_CLUSTER")
-                .body_contains("qdrant:memories:point-1")
-                .body_contains("qdrant:memories:point-2")
-                .body_contains("\"cluster_count\":1")
-                .body_contains("\"source_count\":3")
-                .body_contains("\"member_count\":2")
-                .body_contains("\"algorithm\":\"cosine-threshold-components/v1\"");
+                .body_contains("\"scene_hash\":\"00000000000000ff\"")
+                .body_contains(format!("\"keyframe_at_ms\":{at}"));
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
+    let fingerprint = SceneFingerprint {
+        hash: 0xff,
+        thumbnail: vec![0; 256],
+    };
 
     Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_vector_clusters(
-            "memories",
-            "cosine-threshold-components/v1",
-            0.9,
-            2,
-            3,
-            &[VectorCluster {
-                cluster_id: "cluster:1".into(),
-                collection: "memories".into(),
-                threshold: 0.9,
-                centroid: vec![0.95, 0.05],
-                mean_similarity: 0.95,
-                members: vec![
-                    VectorClusterMember {
-                        point_id: "point-1".into(),
-                        average_similarity: 0.95,
-                    },
-                    VectorClusterMember {
-                        point_id: "point-2".into(),
-                        average_similarity: 0.95,
-                    },
-                ],
-            }],
-        )
+        .attach_scene_fingerprint(&scene_frame(), &fingerprint, None)
         .await
         .unwrap();
 
-    constraint.assert_async().await;
     update.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_vector_cluster_items() {
+async fn neo4j_client_finds_the_previous_keyframe_by_its_indexed_time() {
     let server = MockServer::start_async().await;
+    let fingerprint = SceneFingerprint {
+        hash: 0xff,
+        thumbnail: vec![7; 256],
+    };
+    let before = chrono::DateTime::parse_from_rfc3339("2026-05-05T12:34:57Z")
+        .unwrap()
+        .with_timezone(&Utc);
     let query = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("UNWIND $vector_ids AS vector_id")
-                .body_contains("HAS_MEMORY_VECTOR")
-                .body_contains("MATCH (owner)-[rel]-(neighbor:GraphNode)")
-                .body_contains("coalesce(head([label IN labels(neighbor) WHERE label")
-                .body_contains("WITH vector_id, owner, text, stimulus_texts, edge_texts, neighbor_texts")
-                .body_contains("RETURN vector_id, owner.id, labels(owner), text, stimulus_texts, edge_texts, neighbor_texts")
-                .body_contains("qdrant:memories:point-1")
-                .body_contains("\"limit\":10");
+                .body_contains("i.keyframe_at_ms <= $before_ms")
+                .body_contains("ORDER BY i.keyframe_at_ms DESC")
+                .body_contains(format!("\"before_ms\":{}", before.timestamp_millis()))
+                .body_contains("\"image_id\":\"image:2\"");
             then.status(200).json_body(json!({
                 "results": [{
-                    "columns": ["vector_id", "owner.id", "labels(owner)", "text", "stimulus_texts", "edge_texts", "neighbor_texts"],
                     "data": [{
                         "row": [
-                            "qdrant:memories:point-1",
-                            "sensation:1",
-                            ["GraphNode", "Sensation"],
-                            "sensation: coffee is brewing",
-                            ["text: coffee beans"],
-                            ["-[:OBSERVED]-> text:1"],
-                            ["TextObservation text: coffee beans"]
+                            "image:1",
+                            fingerprint.hash_hex(),
+                            fingerprint.thumbnail_base64(),
+                            null,
+                            null,
+                            "2026-05-05T12:34:50Z"
                         ]
                     }]
                 }],
@@ -1931,170 +2145,251 @@ async fn neo4j_client_loads_vector_cluster_items() {
         })
         .await;
 
-    let items = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .vector_cluster_items("memories", &["point-1".into()], 10)
+    let keyframe = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .previous_scene_keyframe("image:2", before)
         .await
+        .unwrap()
         .unwrap();
 
+    query.assert_async().await;
+    assert_eq!(keyframe.image_id, "image:1");
+    assert_eq!(keyframe.fingerprint, Some(fingerprint));
     assert_eq!(
-        items,
-        vec![GraphClusterItem {
-            vector_id: "qdrant:memories:point-1".into(),
-            node_id: "sensation:1".into(),
-            labels: vec!["GraphNode".into(), "Sensation".into()],
-            text: "sensation: coffee is brewing".into(),
-            stimuli: vec!["text: coffee beans".into()],
-            edges: vec!["-[:OBSERVED]-> text:1".into()],
-            neighbors: vec!["TextObservation text: coffee beans".into()],
-        }]
+        keyframe.observed_at.as_deref(),
+        Some("2026-05-05T12:34:50Z")
     );
-    query.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_face_identity_for_vector_neighbor() {
+async fn neo4j_client_reuses_the_description_of_a_keyframe() {
     let server = MockServer::start_async().await;
-    let query = server
+    let constraint = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("MATCH (v:GraphNode:Vector {id: $vector_id})")
-                .body_contains("face_cluster:GraphNode:Face")
-                .body_contains("face_instance:GraphNode:FaceInstance")
-                .body_contains("HAS_FACE_VECTOR")
-                .body_contains("candidate:Identity")
-                .body_contains("qdrant:faces:point-1");
-            then.status(200)
-                .body(r#"{"results":[{"data":[{"row":["cluster:face:1","Anna"]}]}],"errors":[]}"#);
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
-
-    let identity = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .face_identity_for_vector_neighbor("point-1")
-        .await
-        .unwrap()
-        .unwrap();
-
-    assert_eq!(identity.face_id, "cluster:face:1");
-    assert_eq!(identity.identity.as_deref(), Some("Anna"));
-    query.assert_async().await;
-}
-
-#[tokio::test]
-async fn neo4j_client_checks_face_cluster_identity_run_presence() {
-    let server = MockServer::start_async().await;
     let query = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("HAS_FACE_IDENTITY_RUN")
-                .body_contains("cluster:face:1");
+                .body_contains("HAS_IMAGE_DESCRIPTION_RUN")
+                .body_contains("run.reused_run_id IS NULL")
+                .body_contains("image:1");
             then.status(200).json_body(json!({
                 "results": [{
-                    "columns": ["EXISTS"],
-                    "data": [{"row": [true]}]
+                    "columns": ["run.id", "results"],
+                    "data": [{
+                        "row": [
+                            "image-description:image:1",
+                            [["HAS_IMAGE_DESCRIPTION", "image-description-text:image:1"]]
+                        ]
+                    }]
                 }],
                 "errors": []
             }));
         })
         .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("\"id\":\"image-description:image:2\"")
+                .body_contains("\"reused_run_id\":\"image-description:image:1\"")
+                .body_contains("REUSED")
+                .body_contains("\"to\":\"image-description-text:image:1\"");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
 
-    let has_identity_run = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .face_cluster_has_identity_run("cluster:face:1")
+    let reused = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .reuse_image_run(&scene_frame(), ImageRunKind::Description, "image:1")
         .await
         .unwrap();
 
-    assert!(has_identity_run);
+    assert!(reused);
     query.assert_async().await;
+    constraint.assert_async().await;
+    update.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_voice_identity_for_vector_neighbor() {
+async fn neo4j_client_attaches_tracked_faces_with_enter_sensations() {
     let server = MockServer::start_async().await;
-    let query = server
+    let constraint = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("MATCH (v:GraphNode:Vector {id: $vector_id})")
-                .body_contains("voice_cluster:GraphNode:Voice")
-                .body_contains("voice_signature:GraphNode:VoiceSignature")
-                .body_contains("HAS_VOICE_VECTOR")
-                .body_contains("candidate:Identity")
-                .body_contains("qdrant:voices:point-1");
-            then.status(200)
-                .body(r#"{"results":[{"data":[{"row":["cluster:voice:1","Anna"]}]}],"errors":[]}"#);
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("FaceTrack")
+                .body_contains("PART_OF_TRACK")
+                .body_contains("face-track:1")
+                .body_contains("\"kind\":\"face_track_entered\"")
+                .body_contains(format!(
+                    "\"how\":\"{}\"",
+                    psyche::face_track_entered_sensation_text(None)
+                ))
+                .body_contains("\"kind\":\"face_recognition\"");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
 
-    let identity = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .voice_identity_for_vector_neighbor("point-1")
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_tracked_face_recognition(
+            &GraphImageFrame {
+                id: "image:1".into(),
+                image: ImageData {
+                    mime: "image/jpeg".into(),
+                    base64: "/9j/AA==".into(),
+                    captured_at: Some("2026-05-05T12:34:56Z".into()),
+                    source: None,
+                },
+                occurred_at: None,
+                sensation_id: Some("sensation:image:1".into()),
+            },
+            "face_id",
+            &[GraphFaceDetection {
+                index: 0,
+                face_id: "face:1".into(),
+                crop: ImageData {
+                    mime: "image/jpeg".into(),
+                    base64: "/9j/crop==".into(),
+                    captured_at: Some("2026-05-05T12:34:56Z".into()),
+                    source: None,
+                },
+                vector_id: "point-1".into(),
+                embedding_len: 512,
+                recognition: None,
+                details: FaceDetails::default(),
+                track: Some(GraphFaceTrack {
+                    track_id: "face-track:1".into(),
+                    started: true,
+                    matched: true,
+                }),
+            }],
+        )
         .await
-        .unwrap()
         .unwrap();
 
-    assert_eq!(identity.voice_id, "cluster:voice:1");
-    assert_eq!(identity.identity.as_deref(), Some("Anna"));
-    query.assert_async().await;
+    constraint.assert_async().await;
+    update.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_checks_voice_cluster_identity_run_presence() {
+async fn neo4j_client_records_ended_face_tracks() {
     let server = MockServer::start_async().await;
-    let query = server
+    let constraint = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("HAS_VOICE_IDENTITY_RUN")
-                .body_contains("cluster:voice:1");
-            then.status(200).json_body(json!({
-                "results": [{
-                    "columns": ["EXISTS"],
-                    "data": [{"row": [true]}]
-                }],
-                "errors": []
-            }));
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("FaceTrack")
+                .body_contains("\"kind\":\"face_track_left\"")
+                .body_contains(format!(
+                    "\"how\":\"{}\"",
+                    psyche::face_track_left_sensation_text(Some("Anna"))
+                ))
+                .body_contains("\"frames\":12")
+                .body_contains("\"matched_face_id\":\"cluster:face:1\"");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let started_at = Utc::now();
 
-    let has_identity_run = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .voice_cluster_has_identity_run("cluster:voice:1")
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_face_track_ends(&[FaceTrack {
+            id: "face-track:1".into(),
+            started_at,
+            last_seen_at: started_at + chrono::Duration::seconds(6),
+            frames: 12,
+            bbox: None,
+            embedding: vec![1.0, 0.0],
+            confidence: 0.9,
+            recognition: Some(psyche::GraphFaceMatch {
+                face_id: "cluster:face:1".into(),
+                identity: Some("Anna".into()),
+                nearest_vector_id: "known-point".into(),
+                score: 0.93,
+            }),
+        }])
         .await
         .unwrap();
 
-    assert!(has_identity_run);
-    query.assert_async().await;
+    constraint.assert_async().await;
+    update.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_checks_vector_cluster_theme_presence() {
+async fn neo4j_client_attaches_scene_vectorization() {
     let server = MockServer::start_async().await;
-    let query = server
+    let constraint = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("HAS_THEME")
-                .body_contains("cluster:1");
-            then.status(200).json_body(json!({
-                "results": [{
-                    "columns": ["EXISTS"],
-                    "data": [{"row": [true]}]
-                }],
-                "errors": []
-            }));
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("SceneVectorizationRun")
+                .body_contains("HAS_SCENE_VECTORIZATION_RUN")
+                .body_contains("HAS_SCENE_VECTOR")
+                .body_contains("DERIVED_FROM")
+                .body_contains("qdrant:scene_vectors:point-1")
+                .body_contains("sensation:image:1")
+                .body_contains("\"embedding_len\":512")
+                .body_contains("\"model\":\"clip-test\"");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
 
-    let has_theme = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .vector_cluster_has_theme("cluster:1")
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_scene_vectorization(
+            &GraphImageFrame {
+                id: "image:1".into(),
+                image: ImageData {
+                    mime: "image/jpeg".into(),
+                    base64: "/9j/AA==".into(),
+                    captured_at: Some("2026-05-05T12:34:56Z".into()),
+                    source: None,
+                },
+                occurred_at: Some("2026-05-05T12:34:57Z".into()),
+                sensation_id: Some("sensation:image:1".into()),
+            },
+            "clip-test",
+            &GraphSceneVectorization {
+                vector_id: "point-1".into(),
+                embedding_len: 512,
+            },
+        )
         .await
         .unwrap();
 
-    assert!(has_theme);
-    query.assert_async().await;
+    constraint.assert_async().await;
+    update.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_vector_cluster_theme() {
+async fn neo4j_client_attaches_object_detection() {
     let server = MockServer::start_async().await;
     let constraint = server
         .mock_async(|when, then| {
@@ -2108,44 +2403,39 @@ async fn neo4j_client_attaches_vector_cluster_theme() {
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("ClusterThemeRun")
-                .body_contains("Theme")
-                .body_contains("HAS_THEME")
-                .body_contains("THEME_OF")
-                .body_contains("DERIVED_FROM_VECTOR")
-                .body_contains("coffee rituals")
-                .body_contains("qdrant:memories:point-1");
+                .body_contains("ObjectDetectionRun")
+                .body_contains("object-detection:image:1")
+                .body_contains("HAS_OBJECT_DETECTION_RUN")
+                .body_contains("DETECTED_OBJECT")
+                .body_contains("DERIVED_FROM")
+                .body_contains("\"object_labels\":[\"cup\"]")
+                .body_contains("\"detector\":\"yolo-test\"");
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
 
-    let cluster = VectorCluster {
-        cluster_id: "cluster:1".into(),
-        collection: "memories".into(),
-        threshold: 0.9,
-        centroid: vec![0.95, 0.05],
-        mean_similarity: 0.95,
-        members: vec![VectorClusterMember {
-            point_id: "point-1".into(),
-            average_similarity: 0.95,
-        }],
-    };
-    let items = vec![GraphClusterItem {
-        vector_id: "qdrant:memories:point-1".into(),
-        node_id: "impression:1".into(),
-        labels: vec!["Impression".into()],
-        text: "impression: coffee is brewing".into(),
-        stimuli: Vec::new(),
-        edges: Vec::new(),
-        neighbors: Vec::new(),
-    }];
-    let theme = GraphClusterTheme {
-        theme_id: "theme:cluster:1".into(),
-        text: "coffee rituals".into(),
-    };
-
     Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_vector_cluster_theme(&cluster, "gpt-oss", &items, &theme)
+        .attach_object_detection(
+            &GraphImageFrame {
+                id: "image:1".into(),
+                image: ImageData {
+                    mime: "image/jpeg".into(),
+                    base64: "/9j/AA==".into(),
+                    captured_at: Some("2026-05-05T12:34:56Z".into()),
+                    source: None,
+                },
+                occurred_at: None,
+                sensation_id: Some("sensation:image:1".into()),
+            },
+            "yolo-test",
+            &[GraphObjectDetection {
+                index: 0,
+                object_id: "object:cup:2:2026-05-05T12:34:56+00:00".into(),
+                sensation_id: "sensation:object:cup".into(),
+                label: Some("cup".into()),
+                confidence: Some(0.8),
+            }],
+        )
         .await
         .unwrap();
 
@@ -2154,7 +2444,7 @@ async fn neo4j_client_attaches_vector_cluster_theme() {
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_face_identity() {
+async fn neo4j_client_attaches_text_recognition() {
     let server = MockServer::start_async().await;
     let constraint = server
         .mock_async(|when, then| {
@@ -2168,46 +2458,48 @@ async fn neo4j_client_attaches_face_identity() {
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("FaceIdentityRun")
-                .body_contains("Identity")
-                .body_contains("Person")
-                .body_contains("HAS_FACE_IDENTITY_RUN")
-                .body_contains("HAS_IDENTITY")
-                .body_contains("IDENTITY_OF")
-                .body_contains("USED_CONTEXT")
-                .body_contains("Anna")
-                .body_contains("qdrant:faces:point-1");
+                .body_contains("text-recognition:image:1")
+                .body_contains("HAS_TEXT_RECOGNITION_RUN")
+                .body_contains("READ_TEXT")
+                .body_contains("CONTAINS_TEXT")
+                .body_contains(r#""repeat":true"#)
+                .body_contains(r#""texts":["EXIT","Room 12"]"#)
+                .body_contains("WHEN coalesce(t.last_read_at, '') < $read_at THEN $read_at")
+                .body_contains(r#""read_at":"2026-05-05T12:34:56+00:00""#)
+                .body_contains(r#""text_ids":["text-block:0","text-block:1"]"#)
+                .body_contains(r#""engine":"ocr-test""#);
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
-
-    let cluster = VectorCluster {
-        cluster_id: "cluster:face:1".into(),
-        collection: "faces".into(),
-        threshold: 0.9,
-        centroid: vec![0.95, 0.05],
-        mean_similarity: 0.95,
-        members: vec![VectorClusterMember {
-            point_id: "point-1".into(),
-            average_similarity: 0.95,
-        }],
-    };
-    let items = vec![GraphClusterItem {
-        vector_id: "qdrant:faces:point-1".into(),
-        node_id: "face:1".into(),
-        labels: vec!["FaceInstance".into()],
-        text: "face instance detected".into(),
-        stimuli: Vec::new(),
-        edges: Vec::new(),
-        neighbors: vec!["TextObservation text: Anna".into()],
-    }];
-    let identity = GraphFaceIdentityLabel {
-        identity_id: "identity:person:anna".into(),
-        name: "Anna".into(),
+    let reading = |index, text: &str, sensation_id: Option<&str>| GraphTextReading {
+        index,
+        text_id: format!("text-block:{index}"),
+        text: text.into(),
+        sensation_id: sensation_id.map(Into::into),
+        confidence: Some(0.9),
+        bbox: Some(BoundingBox::FULL),
     };
 
     Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_face_identity(&cluster, "gpt-oss", &items, Some(&identity))
+        .attach_text_recognition(
+            &GraphImageFrame {
+                id: "image:1".into(),
+                image: ImageData {
+                    mime: "image/jpeg".into(),
+                    base64: "/9j/AA==".into(),
+                    captured_at: Some("2026-05-05T12:34:56Z".into()),
+                    source: None,
+                },
+                occurred_at: None,
+                sensation_id: Some("sensation:image:1".into()),
+            },
+            "ocr-test",
+            "2026-05-05T12:34:56+00:00",
+            &[
+                reading(0, "EXIT", Some("sensation:text:0")),
+                reading(1, "Room 12", None),
+            ],
+        )
         .await
         .unwrap();
 
@@ -2216,7 +2508,7 @@ async fn neo4j_client_attaches_face_identity() {
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_voice_identity() {
+async fn neo4j_client_attaches_image_description() {
     let server = MockServer::start_async().await;
     let constraint = server
         .mock_async(|when, then| {
@@ -2230,46 +2522,42 @@ async fn neo4j_client_attaches_voice_identity() {
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("VoiceIdentityRun")
-                .body_contains("Identity")
-                .body_contains("Person")
-                .body_contains("HAS_VOICE_IDENTITY_RUN")
-                .body_contains("HAS_IDENTITY")
-                .body_contains("IDENTITY_OF")
-                .body_contains("USED_CONTEXT")
-                .body_contains("Anna")
-                .body_contains("qdrant:voices:point-1");
+                .body_contains("ImageDescriptionRun")
+                .body_contains("ImageDescription")
+                .body_contains("HAS_IMAGE_DESCRIPTION_RUN")
+                .body_contains("HAS_IMAGE_DESCRIPTION")
+                .body_contains("HAS_IMAGE_DESCRIPTION_VECTOR")
+                .body_contains("qdrant:image_descriptions:point-1")
+                .body_contains("sensation:image:1")
+                .body_contains("\"embedding_len\":768")
+                .body_contains("\"model\":\"vision-test\"")
+                .body_contains("\"embedding_model\":\"embed-test\"");
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
 
-    let cluster = VectorCluster {
-        cluster_id: "cluster:voice:1".into(),
-        collection: "voices".into(),
-        threshold: 0.9,
-        centroid: vec![0.95, 0.05],
-        mean_similarity: 0.95,
-        members: vec![VectorClusterMember {
-            point_id: "point-1".into(),
-            average_similarity: 0.95,
-        }],
-    };
-    let items = vec![GraphClusterItem {
-        vector_id: "qdrant:voices:point-1".into(),
-        node_id: "voice-signature:speaker:1".into(),
-        labels: vec!["VoiceSignature".into()],
-        text: "voice signature: f0 150 Hz, speech rate 4.5".into(),
-        stimuli: vec!["audio: Anna said hello".into()],
-        edges: Vec::new(),
-        neighbors: Vec::new(),
-    }];
-    let identity = GraphVoiceIdentityLabel {
-        identity_id: "identity:person:anna".into(),
-        name: "Anna".into(),
-    };
-
     Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_voice_identity(&cluster, "gpt-oss", &items, Some(&identity))
+        .attach_image_description(
+            &GraphImageFrame {
+                id: "image:1".into(),
+                image: ImageData {
+                    mime: "image/jpeg".into(),
+                    base64: "/9j/AA==".into(),
+                    captured_at: Some("2026-05-05T12:34:56Z".into()),
+                    source: None,
+                },
+                occurred_at: Some("2026-05-05T12:34:57Z".into()),
+                sensation_id: Some("sensation:image:1".into()),
+            },
+            "vision-test",
+            "embed-test",
+            &GraphImageDescription {
+                description_id: "image-description-text:image:1".into(),
+                text: "I see a test frame.".into(),
+                vector_id: "point-1".into(),
+                embedding_len: 768,
+            },
+        )
         .await
         .unwrap();
 
@@ -2278,67 +2566,57 @@ async fn neo4j_client_attaches_voice_identity() {
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_recent_face_identity_targets() {
+async fn neo4j_client_attaches_geolocation_vectorization() {
     let server = MockServer::start_async().await;
-    let query = server
+    let constraint = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("MATCH (face:GraphNode:FaceInstance)")
-                .body_contains("MATCHED_FACE")
-                .body_contains("HAS_FACE_VECTOR")
-                .body_contains("RETURN target.id, target_label, face.id, image.id, vector.id, identity_name, observed_at");
-            then.status(200).body(
-                r#"{"results":[{"data":[{"row":["cluster:face:1","Face","face:1","image:1","qdrant:faces:point-1","Anna","2026-05-07T12:00:00Z"]}]}],"errors":[]}"#,
-            );
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
-
-    let targets = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .recent_face_identity_targets(3)
-        .await
-        .unwrap();
-
-    query.assert_async().await;
-    assert_eq!(targets.len(), 1);
-    assert_eq!(targets[0].target_id, "cluster:face:1");
-    assert_eq!(targets[0].target_label, "Face");
-    assert_eq!(targets[0].face_instance_id, "face:1");
-    assert_eq!(targets[0].identity.as_deref(), Some("Anna"));
-}
-
-#[tokio::test]
-async fn neo4j_client_loads_recent_voice_identity_targets() {
-    let server = MockServer::start_async().await;
-    let query = server
+    let update = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("MATCH (signature:GraphNode:VoiceSignature)")
-                .body_contains("MATCHED_VOICE")
-                .body_contains("HAS_VOICE_VECTOR")
-                .body_contains("RETURN target.id, target_label, signature.id, clip.id, vector.id, identity_name, observed_at");
-            then.status(200).body(
-                r#"{"results":[{"data":[{"row":["voice-signature:speaker:1","VoiceSignature","voice-signature:speaker:1","audio:1","qdrant:voices:point-1",null,"2026-05-07T12:00:00Z"]}]}],"errors":[]}"#,
-            );
+                .body_contains("GeolocationVectorizationRun")
+                .body_contains("HAS_GEOLOCATION_VECTORIZATION_RUN")
+                .body_contains("HAS_GEOLOCATION_VECTOR")
+                .body_contains("PROCESSED_GEOLOCATION")
+                .body_contains("qdrant:geolocations:point-1")
+                .body_contains("sensation:geolocation:1")
+                .body_contains("\"embedding_len\":3")
+                .body_contains("\"model\":\"earth-unit-sphere/v1\"");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
 
-    let targets = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .recent_voice_identity_targets(3)
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_geolocation_vectorization(
+            &GraphGeolocation {
+                id: "geolocation:1".into(),
+                loc: GeoLoc {
+                    latitude: 37.7749,
+                    longitude: -122.4194,
+                    observed_at: Some("2026-05-05T12:34:56Z".into()),
+                },
+                occurred_at: Some("2026-05-05T12:34:57Z".into()),
+                sensation_id: Some("sensation:geolocation:1".into()),
+            },
+            "earth-unit-sphere/v1",
+            "point-1",
+            3,
+        )
         .await
         .unwrap();
 
-    query.assert_async().await;
-    assert_eq!(targets.len(), 1);
-    assert_eq!(targets[0].target_id, "voice-signature:speaker:1");
-    assert_eq!(targets[0].target_label, "VoiceSignature");
-    assert_eq!(targets[0].voice_signature_id, "voice-signature:speaker:1");
-    assert_eq!(targets[0].identity, None);
+    constraint.assert_async().await;
+    update.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_manual_face_identity() {
+async fn neo4j_client_attaches_vector_clusters() {
     let server = MockServer::start_async().await;
     let constraint = server
         .mock_async(|when, then| {
@@ -2352,32 +2630,46 @@ async fn neo4j_client_attaches_manual_face_identity() {
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("ManualFaceIdentityRun")
-                .body_contains("FaceInstance")
-                .body_contains("Identity")
-                .body_contains("Person")
-                .body_contains("HAS_MANUAL_IDENTITY_RUN")
-                .body_contains("HAS_IDENTITY")
-                .body_contains("IDENTITY_OF")
-                .body_contains("Travis")
-                .body_contains("identity:person:travis")
-                .body_contains("qdrant:faces:point-1");
+                .body_contains("ClusterDiscoveryRun")
+                .body_contains("Cluster")
+                .body_contains("PRODUCED_CLUSTER")
+                .body_contains("HAS_CLUSTER_MEMBER")
+                .body_contains("MEMBER_OF_CLUSTER")
+                .body_contains("qdrant:memories:point-1")
+                .body_contains("qdrant:memories:point-2")
+                .body_contains("\"cluster_count\":1")
+                .body_contains("\"source_count\":3")
+                .body_contains("\"member_count\":2")
+                .body_contains("\"algorithm\":\"cosine-threshold-components/v1\"");
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
 
-    let target = GraphFaceIdentityTarget {
-        target_id: "face:1".into(),
-        target_label: "FaceInstance".into(),
-        face_instance_id: "face:1".into(),
-        source_image_id: Some("image:1".into()),
-        vector_id: Some("qdrant:faces:point-1".into()),
-        identity: None,
-        occurred_at: "2026-05-07T12:00:00Z".into(),
-    };
-
     Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_manual_face_identity(&target, "Travis", "will")
+        .attach_vector_clusters(
+            "memories",
+            "cosine-threshold-components/v1",
+            0.9,
+            2,
+            3,
+            &[VectorCluster {
+                cluster_id: "cluster:1".into(),
+                collection: "memories".into(),
+                threshold: 0.9,
+                centroid: vec![0.95, 0.05],
+                mean_similarity: 0.95,
+                members: vec![
+                    VectorClusterMember {
+                        point_id: "point-1".into(),
+                        average_similarity: 0.95,
+                    },
+                    VectorClusterMember {
+                        point_id: "point-2".into(),
+                        average_similarity: 0.95,
+                    },
+                ],
+            }],
+        )
         .await
         .unwrap();
 
@@ -2386,690 +2678,2440 @@ async fn neo4j_client_attaches_manual_face_identity() {
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_manual_voice_identity() {
+async fn neo4j_client_loads_vector_cluster_items() {
     let server = MockServer::start_async().await;
-    let constraint = server
+    let query = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+                .body_contains("UNWIND $vector_ids AS vector_id")
+                .body_contains("HAS_MEMORY_VECTOR")
+                .body_contains("MATCH (owner)-[rel]-(neighbor:GraphNode)")
+                .body_contains("coalesce(head([label IN labels(neighbor) WHERE label")
+                .body_contains("WITH vector_id, owner, text, stimulus_texts, edge_texts, neighbor_texts")
+                .body_contains("RETURN vector_id, owner.id, labels(owner), text, stimulus_texts, edge_texts, neighbor_texts")
+                .body_contains("qdrant:memories:point-1")
+                .body_contains("\"limit\":10");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": ["vector_id", "owner.id", "labels(owner)", "text", "stimulus_texts", "edge_texts", "neighbor_texts"],
+                    "data": [{
+                        "row": [
+                            "qdrant:memories:point-1",
+                            "sensation:1",
+                            ["GraphNode", "Sensation"],
+                            "sensation: coffee is brewing",
+                            ["text: coffee beans"],
+                            ["-[:OBSERVED]-> text:1"],
+                            ["TextObservation text: coffee beans"]
+                        ]
+                    }]
+                }],
+                "errors": []
+            }));
         })
         .await;
-    let update = server
+
+    let items = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .vector_cluster_items("memories", &["point-1".into()], 10)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        items,
+        vec![GraphClusterItem {
+            vector_id: "qdrant:memories:point-1".into(),
+            node_id: "sensation:1".into(),
+            labels: vec!["GraphNode".into(), "Sensation".into()],
+            text: "sensation: coffee is brewing".into(),
+            stimuli: vec!["text: coffee beans".into()],
+            edges: vec!["-[:OBSERVED]-> text:1".into()],
+            neighbors: vec!["TextObservation text: coffee beans".into()],
+        }]
+    );
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_face_identity_for_vector_neighbor() {
+    let server = MockServer::start_async().await;
+    let query = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("ManualVoiceIdentityRun")
-                .body_contains("VoiceSignature")
-                .body_contains("Identity")
-                .body_contains("Person")
-                .body_contains("HAS_MANUAL_IDENTITY_RUN")
-                .body_contains("HAS_IDENTITY")
-                .body_contains("IDENTITY_OF")
-                .body_contains("Travis")
-                .body_contains("identity:person:travis")
-                .body_contains("qdrant:voices:point-1");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+                .body_contains("MATCH (v:GraphNode:Vector {id: $vector_id})")
+                .body_contains("face_cluster:GraphNode:Face")
+                .body_contains("face_instance:GraphNode:FaceInstance")
+                .body_contains("HAS_FACE_VECTOR")
+                .body_contains("candidate:Identity")
+                .body_contains("PART_OF_PERSON")
+                .body_contains("qdrant:faces:point-1");
+            then.status(200).body(
+                r#"{"results":[{"data":[{"row":["cluster:face:1","Anna","person:1"]}]}],"errors":[]}"#,
+            );
         })
         .await;
 
-    let target = GraphVoiceIdentityTarget {
-        target_id: "voice-signature:speaker:1".into(),
-        target_label: "VoiceSignature".into(),
-        voice_signature_id: "voice-signature:speaker:1".into(),
-        audio_clip_id: Some("audio:1".into()),
-        vector_id: Some("qdrant:voices:point-1".into()),
-        identity: None,
-        occurred_at: "2026-05-07T12:00:00Z".into(),
-    };
-
-    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_manual_voice_identity(&target, "Travis", "will")
+    let identity = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .face_identity_for_vector_neighbor("point-1")
         .await
+        .unwrap()
         .unwrap();
 
-    constraint.assert_async().await;
-    update.assert_async().await;
+    assert_eq!(identity.face_id, "cluster:face:1");
+    assert_eq!(identity.identity.as_deref(), Some("Anna"));
+    assert_eq!(identity.person_id.as_deref(), Some("person:1"));
+    query.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_latest_timeline_window_for_combobulation() {
+async fn neo4j_client_checks_face_cluster_identity_run_presence() {
     let server = MockServer::start_async().await;
     let query = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("MATCH (anchor:GraphNode:Sensation)")
-                .body_contains("combobulation_summary")
-                .body_contains("coalesce(anchor.how")
-                .body_contains("INCLUDED_IN_COMBOBULATION")
-                .body_contains("ORDER BY datetime(anchor_at) ASC, anchor.id ASC")
-                .body_contains("duration({seconds: $seconds})")
-                .body_contains("coalesce(n.occurred_at")
-                .body_contains("coalesce(n.how")
-                .body_contains("datetime(occurred_at) >= datetime(anchor_at)")
-                .body_contains("datetime(occurred_at) <= datetime(anchor_at) + duration({seconds: $seconds})")
-                .body_contains("NOT (n)-[:INCLUDED_IN_COMBOBULATION]")
-                .body_contains("timeline_order")
-                .body_contains("ORDER BY datetime(occurred_at) ASC, timeline_order ASC, n.id ASC")
-                .body_contains("RETURN anchor.id, anchor_at, row.id, row.event_id, row.labels, row.text, row.occurred_at")
-                .body_contains("\"seconds\":30")
-                .body_contains("\"limit\":80");
+                .body_contains("HAS_FACE_IDENTITY_RUN")
+                .body_contains("cluster:face:1");
             then.status(200).json_body(json!({
                 "results": [{
-                    "columns": ["anchor.id", "anchor_at", "n.id", "event_id", "labels(n)", "text", "occurred_at"],
-                    "data": [
-                        {"row": [
-                            "sensation:audio:2",
-                            "2026-05-05T12:35:00Z",
-                            "sensation:audio:1",
-                            "audio:1",
-                            ["GraphNode", "Sensation"],
-                            "audio sensation; transcript: hello",
-                            "2026-05-05T12:34:56Z"
-                        ]},
-                        {"row": [
-                            "sensation:audio:2",
-                            "2026-05-05T12:35:00Z",
-                            "sensation:audio:2",
-                            "audio:1",
-                            ["GraphNode", "Sensation"],
-                            "audio sensation; transcript: there",
-                            "2026-05-05T12:35:00Z"
-                        ]}
-                    ]
+                    "columns": ["EXISTS"],
+                    "data": [{"row": [true]}]
                 }],
                 "errors": []
             }));
         })
         .await;
 
-    let window = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .latest_timeline_window_for_combobulation(30, 80)
+    let has_identity_run = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .face_cluster_has_identity_run("cluster:face:1")
         .await
-        .unwrap()
         .unwrap();
 
-    assert_eq!(window.anchor_id, "sensation:audio:2");
-    assert_eq!(window.anchor_at, "2026-05-05T12:35:00Z");
-    assert_eq!(window.items.len(), 2);
-    assert_eq!(window.items[0].id, "sensation:audio:1");
-    assert_eq!(window.items[0].event_id, "audio:1");
-    assert_eq!(window.items[0].labels, ["GraphNode", "Sensation"]);
-    assert_eq!(window.items[1].text, "audio sensation; transcript: there");
+    assert!(has_identity_run);
     query.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_previous_timeline_window_for_combobulation() {
+async fn neo4j_client_loads_voice_identity_for_vector_neighbor() {
     let server = MockServer::start_async().await;
     let query = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("MATCH (anchor:GraphNode:Sensation)")
-                .body_contains("datetime(anchor_at) < datetime($before)")
-                .body_contains("INCLUDED_IN_COMBOBULATION")
-                .body_contains("duration({seconds: $seconds})")
-                .body_contains("coalesce(n.how")
-                .body_contains("ORDER BY datetime(anchor_at) ASC, anchor.id ASC")
-                .body_contains("ORDER BY datetime(occurred_at) ASC, timeline_order ASC, n.id ASC")
-                .body_contains("RETURN anchor.id, anchor_at, row.id, row.event_id, row.labels, row.text, row.occurred_at")
-                .body_contains("\"before\":\"2026-05-05T12:34:56Z\"")
-                .body_contains("\"seconds\":30")
-                .body_contains("\"limit\":80");
-            then.status(200).json_body(json!({
-                "results": [{
-                    "columns": ["anchor.id", "anchor_at", "n.id", "event_id", "labels(n)", "text", "occurred_at"],
-                    "data": [
-                        {"row": [
-                            "sensation:audio:0",
-                            "2026-05-05T12:34:30Z",
-                            "sensation:audio:-1",
-                            "audio:-1",
-                            ["GraphNode", "Sensation"],
-                            "audio sensation; transcript: earlier",
-                            "2026-05-05T12:34:20Z"
-                        ]},
-                        {"row": [
-                            "sensation:audio:0",
-                            "2026-05-05T12:34:30Z",
-                            "sensation:audio:0",
-                            "audio:0",
-                            ["GraphNode", "Sensation"],
-                            "audio sensation; transcript: before",
-                            "2026-05-05T12:34:30Z"
-                        ]}
-                    ]
-                }],
-                "errors": []
-            }));
+                .body_contains("MATCH (v:GraphNode:Vector {id: $vector_id})")
+                .body_contains("voice_cluster:GraphNode:Voice")
+                .body_contains("voice_signature:GraphNode:VoiceSignature")
+                .body_contains("HAS_VOICE_VECTOR")
+                .body_contains("candidate:Identity")
+                .body_contains("qdrant:voices:point-1");
+            then.status(200)
+                .body(r#"{"results":[{"data":[{"row":["cluster:voice:1","Anna"]}]}],"errors":[]}"#);
         })
         .await;
 
-    let window = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .previous_timeline_window_for_combobulation(30, 80, "2026-05-05T12:34:56Z")
+    let identity = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .voice_identity_for_vector_neighbor("point-1")
         .await
         .unwrap()
         .unwrap();
 
-    assert_eq!(window.anchor_id, "sensation:audio:0");
-    assert_eq!(window.anchor_at, "2026-05-05T12:34:30Z");
-    assert_eq!(window.items.len(), 2);
-    assert_eq!(window.items[0].id, "sensation:audio:-1");
-    assert_eq!(window.items[1].event_id, "audio:0");
-    assert_eq!(window.items[1].text, "audio sensation; transcript: before");
+    assert_eq!(identity.voice_id, "cluster:voice:1");
+    assert_eq!(identity.identity.as_deref(), Some("Anna"));
     query.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_sensation_timeline_with_sensation_order() {
+async fn neo4j_client_checks_voice_cluster_identity_run_presence() {
     let server = MockServer::start_async().await;
     let query = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("MATCH (n:GraphNode:Sensation)")
-                .body_contains("coalesce(n.how")
-                .body_contains("timeline_order")
-                .body_contains("ORDER BY datetime(occurred_at) DESC, timeline_order DESC, n.id DESC")
-                .body_contains("RETURN row.id, row.labels, row.kind, row.text, row.occurred_at, row.formed_at")
-                .body_contains("\"limit\":20");
+                .body_contains("HAS_VOICE_IDENTITY_RUN")
+                .body_contains("cluster:voice:1");
             then.status(200).json_body(json!({
                 "results": [{
-                    "columns": ["row.id", "row.labels", "row.kind", "row.text", "row.occurred_at", "row.formed_at"],
-                    "data": [
-                        {"row": [
-                            "sensation:image:1",
-                            ["GraphNode", "Sensation"],
-                            "image",
-                            psyche::IMAGE_SENSATION_TEXT,
-                            "2026-05-05T12:34:56Z",
-                            "2026-05-05T12:34:56Z"
-                        ]},
-                        {"row": [
-                            "sensation:face_recognition:1",
-                            ["GraphNode", "Sensation"],
-                            "face_recognition",
-                            psyche::face_count_sensation_text(1),
-                            "2026-05-05T12:34:56Z",
-                            "2026-05-05T12:34:57Z"
-                        ]},
-                        {"row": [
-                            "sensation:face_identity:1",
-                            ["GraphNode", "Sensation"],
-                            "face_identity",
-                            psyche::face_familiarity_sensation_text(false),
-                            "2026-05-05T12:34:56Z",
-                            "2026-05-05T12:34:57Z"
-                        ]}
-                    ]
+                    "columns": ["EXISTS"],
+                    "data": [{"row": [true]}]
                 }],
                 "errors": []
             }));
         })
         .await;
 
-    let end = chrono::DateTime::parse_from_rfc3339("2026-05-05T12:35:00Z")
-        .unwrap()
-        .with_timezone(&Utc);
-    let items = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .sensation_timeline(None, end, 20)
+    let has_identity_run = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .voice_cluster_has_identity_run("cluster:voice:1")
         .await
         .unwrap();
 
-    assert_eq!(items.len(), 3);
-    assert_eq!(items[0].text, psyche::IMAGE_SENSATION_TEXT);
-    assert_eq!(items[1].text, psyche::face_count_sensation_text(1));
-    assert_eq!(
-        items[2].text,
-        psyche::face_familiarity_sensation_text(false)
-    );
+    assert!(has_identity_run);
     query.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_conversation_timeline_with_user_and_conversant_words() {
+async fn neo4j_client_checks_vector_cluster_theme_presence() {
     let server = MockServer::start_async().await;
     let query = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("MATCH (n:GraphNode:Sensation)")
-                .body_contains("I hear someone on my web interface type: ")
-                .body_contains("I ought to say: ")
-                .body_contains("RETURN row.id, row.labels, row.kind, row.text, row.occurred_at, row.formed_at")
-                .body_contains("\"limit\":20");
+                .body_contains("HAS_THEME")
+                .body_contains("cluster:1");
             then.status(200).json_body(json!({
                 "results": [{
-                    "columns": ["row.id", "row.labels", "row.kind", "row.text", "row.occurred_at", "row.formed_at"],
-                    "data": [
-                        {"row": [
-                            "sensation:web:1",
-                            ["GraphNode", "Sensation"],
-                            "web_interface_text",
-                            "I hear someone on my web interface type: hello pete.",
-                            "2026-05-05T12:34:56Z",
-                            "2026-05-05T12:34:56Z"
-                        ]},
-                        {"row": [
-                            "sensation:cognitive:1",
-                            ["GraphNode", "Sensation"],
-                            "cognitive",
-                            "I ought to say: Hello there.",
-                            "2026-05-05T12:34:57Z",
-                            "2026-05-05T12:34:57Z"
-                        ]}
-                    ]
+                    "columns": ["EXISTS"],
+                    "data": [{"row": [true]}]
                 }],
                 "errors": []
             }));
         })
         .await;
 
-    let end = chrono::DateTime::parse_from_rfc3339("2026-05-05T12:35:00Z")
-        .unwrap()
-        .with_timezone(&Utc);
-    let items = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .conversation_timeline(None, end, 20)
+    let has_theme = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .vector_cluster_has_theme("cluster:1")
         .await
         .unwrap();
 
-    assert_eq!(items.len(), 2);
-    assert_eq!(
-        items[0].text,
-        "I hear someone on my web interface type: hello pete."
-    );
-    assert_eq!(items[1].text, "I ought to say: Hello there.");
+    assert!(has_theme);
     query.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_revisitable_timeline_window_for_combobulation() {
+async fn neo4j_client_attaches_vector_cluster_theme() {
     let server = MockServer::start_async().await;
-    let query = server
+    let constraint = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("MATCH (run:GraphNode:CombobulationRun)")
-                .body_contains("run.source_texts")
-                .body_contains("current_source_texts")
-                .body_contains("size(run.source_ids) <= $limit")
-                .body_contains("NOT EXISTS")
-                .body_contains("coalesce(n.how")
-                .body_contains("RETURN run.anchor_id, run.anchor_at, item.id, item.event_id, item.labels, item.text, item.occurred_at")
-                .body_contains("\"limit\":80");
-            then.status(200).json_body(json!({
-                "results": [{
-                    "columns": ["run.anchor_id", "run.anchor_at", "item.id", "item.event_id", "item.labels", "item.text", "item.occurred_at"],
-                    "data": [
-                        {"row": [
-                            "sensation:audio:2",
-                            "2026-05-05T12:35:00Z",
-                            "sensation:audio:1",
-                            "audio:1",
-                            ["GraphNode", "Sensation"],
-                            "audio sensation; transcript: hello",
-                            "2026-05-05T12:34:56Z"
-                        ]},
-                        {"row": [
-                            "sensation:audio:2",
-                            "2026-05-05T12:35:00Z",
-                            "sensation:audio:2",
-                            "audio:2",
-                            ["GraphNode", "Sensation"],
-                            "audio sensation; transcript: there",
-                            "2026-05-05T12:35:00Z"
-                        ]}
-                    ]
-                }],
-                "errors": []
-            }));
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("ClusterThemeRun")
+                .body_contains("Theme")
+                .body_contains("HAS_THEME")
+                .body_contains("THEME_OF")
+                .body_contains("DERIVED_FROM_VECTOR")
+                .body_contains("coffee rituals")
+                .body_contains("qdrant:memories:point-1");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
 
-    let window = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .latest_revisitable_timeline_window_for_combobulation(80)
+    let cluster = VectorCluster {
+        cluster_id: "cluster:1".into(),
+        collection: "memories".into(),
+        threshold: 0.9,
+        centroid: vec![0.95, 0.05],
+        mean_similarity: 0.95,
+        members: vec![VectorClusterMember {
+            point_id: "point-1".into(),
+            average_similarity: 0.95,
+        }],
+    };
+    let items = vec![GraphClusterItem {
+        vector_id: "qdrant:memories:point-1".into(),
+        node_id: "impression:1".into(),
+        labels: vec!["Impression".into()],
+        text: "impression: coffee is brewing".into(),
+        stimuli: Vec::new(),
+        edges: Vec::new(),
+        neighbors: Vec::new(),
+    }];
+    let theme = GraphClusterTheme {
+        theme_id: "theme:cluster:1".into(),
+        text: "coffee rituals".into(),
+    };
+
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_vector_cluster_theme(&cluster, "gpt-oss", &items, &theme)
         .await
-        .unwrap()
         .unwrap();
 
-    assert_eq!(window.anchor_id, "sensation:audio:2");
-    assert_eq!(window.anchor_at, "2026-05-05T12:35:00Z");
-    assert_eq!(window.items.len(), 2);
-    assert_eq!(window.items[0].id, "sensation:audio:1");
-    assert_eq!(window.items[1].event_id, "audio:2");
-    assert_eq!(window.items[1].text, "audio sensation; transcript: there");
-    query.assert_async().await;
+    constraint.assert_async().await;
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_attaches_face_identity() {
+    let server = MockServer::start_async().await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("FaceIdentityRun")
+                .body_contains("Identity")
+                .body_contains("Person")
+                .body_contains("HAS_FACE_IDENTITY_RUN")
+                .body_contains("HAS_IDENTITY")
+                .body_contains("IDENTITY_OF")
+                .body_contains("USED_CONTEXT")
+                .body_contains("Anna")
+                .body_contains("qdrant:faces:point-1");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    let cluster = VectorCluster {
+        cluster_id: "cluster:face:1".into(),
+        collection: "faces".into(),
+        threshold: 0.9,
+        centroid: vec![0.95, 0.05],
+        mean_similarity: 0.95,
+        members: vec![VectorClusterMember {
+            point_id: "point-1".into(),
+            average_similarity: 0.95,
+        }],
+    };
+    let items = vec![GraphClusterItem {
+        vector_id: "qdrant:faces:point-1".into(),
+        node_id: "face:1".into(),
+        labels: vec!["FaceInstance".into()],
+        text: "face instance detected".into(),
+        stimuli: Vec::new(),
+        edges: Vec::new(),
+        neighbors: vec!["TextObservation text: Anna".into()],
+    }];
+    let identity = GraphFaceIdentityLabel {
+        identity_id: "identity:person:anna".into(),
+        name: "Anna".into(),
+    };
+
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_face_identity(&cluster, "gpt-oss", &items, Some(&identity))
+        .await
+        .unwrap();
+
+    constraint.assert_async().await;
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_attaches_voice_identity() {
+    let server = MockServer::start_async().await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("VoiceIdentityRun")
+                .body_contains("Identity")
+                .body_contains("Person")
+                .body_contains("HAS_VOICE_IDENTITY_RUN")
+                .body_contains("HAS_IDENTITY")
+                .body_contains("IDENTITY_OF")
+                .body_contains("USED_CONTEXT")
+                .body_contains("Anna")
+                .body_contains("qdrant:voices:point-1");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    let cluster = VectorCluster {
+        cluster_id: "cluster:voice:1".into(),
+        collection: "voices".into(),
+        threshold: 0.9,
+        centroid: vec![0.95, 0.05],
+        mean_similarity: 0.95,
+        members: vec![VectorClusterMember {
+            point_id: "point-1".into(),
+            average_similarity: 0.95,
+        }],
+    };
+    let items = vec![GraphClusterItem {
+        vector_id: "qdrant:voices:point-1".into(),
+        node_id: "voice-signature:speaker:1".into(),
+        labels: vec!["VoiceSignature".into()],
+        text: "voice signature: f0 150 Hz, speech rate 4.5".into(),
+        stimuli: vec!["audio: Anna said hello".into()],
+        edges: Vec::new(),
+        neighbors: Vec::new(),
+    }];
+    let identity = GraphVoiceIdentityLabel {
+        identity_id: "identity:person:anna".into(),
+        name: "Anna".into(),
+    };
+
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_voice_identity(&cluster, "gpt-oss", &items, Some(&identity))
+        .await
+        .unwrap();
+
+    constraint.assert_async().await;
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_recent_face_identity_targets() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (face:GraphNode:FaceInstance)")
+                .body_contains("MATCHED_FACE")
+                .body_contains("HAS_FACE_VECTOR")
+                .body_contains("RETURN target.id, target_label, face.id, image.id, vector.id, identity_name, observed_at, person.id, face.pose");
+            then.status(200).body(
+                r#"{"results":[{"data":[{"row":["cluster:face:1","Face","face:1","image:1","qdrant:faces:point-1","Anna","2026-05-07T12:00:00Z",null,0.75]}]}],"errors":[]}"#,
+            );
+        })
+        .await;
+
+    let targets = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .recent_face_identity_targets(3)
+        .await
+        .unwrap();
+
+    query.assert_async().await;
+    assert_eq!(targets.len(), 1);
+    assert_eq!(targets[0].target_id, "cluster:face:1");
+    assert_eq!(targets[0].target_label, "Face");
+    assert_eq!(targets[0].face_instance_id, "face:1");
+    assert_eq!(targets[0].identity.as_deref(), Some("Anna"));
+    assert_eq!(targets[0].pose, Some(0.75));
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_recent_voice_identity_targets() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (signature:GraphNode:VoiceSignature)")
+                .body_contains("MATCHED_VOICE")
+                .body_contains("HAS_VOICE_VECTOR")
+                .body_contains("RETURN target.id, target_label, signature.id, clip.id, vector.id, identity_name, observed_at");
+            then.status(200).body(
+                r#"{"results":[{"data":[{"row":["voice-signature:speaker:1","VoiceSignature","voice-signature:speaker:1","audio:1","qdrant:voices:point-1",null,"2026-05-07T12:00:00Z"]}]}],"errors":[]}"#,
+            );
+        })
+        .await;
+
+    let targets = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .recent_voice_identity_targets(3)
+        .await
+        .unwrap();
+
+    query.assert_async().await;
+    assert_eq!(targets.len(), 1);
+    assert_eq!(targets[0].target_id, "voice-signature:speaker:1");
+    assert_eq!(targets[0].target_label, "VoiceSignature");
+    assert_eq!(targets[0].voice_signature_id, "voice-signature:speaker:1");
+    assert_eq!(targets[0].identity, None);
+}
+
+#[tokio::test]
+async fn neo4j_client_attaches_manual_face_identity() {
+    let server = MockServer::start_async().await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("ManualFaceIdentityRun")
+                .body_contains("FaceInstance")
+                .body_contains("Identity")
+                .body_contains("Person")
+                .body_contains("HAS_MANUAL_IDENTITY_RUN")
+                .body_contains("HAS_IDENTITY")
+                .body_contains("IDENTITY_OF")
+                .body_contains("Travis")
+                .body_contains("identity:person:travis")
+                .body_contains("qdrant:faces:point-1");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    let target = GraphFaceIdentityTarget {
+        target_id: "face:1".into(),
+        target_label: "FaceInstance".into(),
+        face_instance_id: "face:1".into(),
+        source_image_id: Some("image:1".into()),
+        vector_id: Some("qdrant:faces:point-1".into()),
+        identity: None,
+        occurred_at: "2026-05-07T12:00:00Z".into(),
+        person_id: None,
+        pose: None,
+    };
+
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_manual_face_identity(&target, "Travis", "will")
+        .await
+        .unwrap();
+
+    constraint.assert_async().await;
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_attaches_manual_voice_identity() {
+    let server = MockServer::start_async().await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("ManualVoiceIdentityRun")
+                .body_contains("VoiceSignature")
+                .body_contains("Identity")
+                .body_contains("Person")
+                .body_contains("HAS_MANUAL_IDENTITY_RUN")
+                .body_contains("HAS_IDENTITY")
+                .body_contains("IDENTITY_OF")
+                .body_contains("Travis")
+                .body_contains("identity:person:travis")
+                .body_contains("qdrant:voices:point-1");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    let target = GraphVoiceIdentityTarget {
+        target_id: "voice-signature:speaker:1".into(),
+        target_label: "VoiceSignature".into(),
+        voice_signature_id: "voice-signature:speaker:1".into(),
+        audio_clip_id: Some("audio:1".into()),
+        vector_id: Some("qdrant:voices:point-1".into()),
+        identity: None,
+        occurred_at: "2026-05-07T12:00:00Z".into(),
+        person_id: None,
+    };
+
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_manual_voice_identity(&target, "Travis", "will")
+        .await
+        .unwrap();
+
+    constraint.assert_async().await;
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_lists_identities_with_their_evidence() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (identity:GraphNode:Identity)")
+                .body_contains("MEMBER_OF_CLUSTER")
+                .body_contains(r#""identity_id":null"#);
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [],
+                    "data": [
+                        {"row": ["identity:person:anna", "Anna", 4, 1, ["face-cluster:1"], "2026-05-07T12:00:00Z", true]},
+                        {"row": ["identity:person:bob", "Bob", 0, 0, [], ""]}
+                    ]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let identities = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .identities()
+        .await
+        .unwrap();
+
+    query.assert_async().await;
+    assert_eq!(
+        identities,
+        vec![
+            GraphIdentity {
+                identity_id: "identity:person:anna".into(),
+                name: "Anna".into(),
+                face_samples: 4,
+                voice_samples: 1,
+                clusters: vec!["face-cluster:1".into()],
+                last_seen_at: Some("2026-05-07T12:00:00Z".into()),
+                opted_out: true,
+            },
+            GraphIdentity {
+                identity_id: "identity:person:bob".into(),
+                name: "Bob".into(),
+                face_samples: 0,
+                voice_samples: 0,
+                clusters: Vec::new(),
+                last_seen_at: None,
+                opted_out: false,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn neo4j_client_opts_identities_out_and_back_in() {
+    let server = MockServer::start_async().await;
+    let opt_out = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("SET identity.opted_out = $opted_out")
+                .body_contains(r#""identity_id":"identity:person:anna""#)
+                .body_contains(r#""opted_out":true"#);
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": ["identity:person:anna"]}]}],
+                "errors": []
+            }));
+        })
+        .await;
+    let missing = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains(r#""opted_out":false"#);
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": []}],
+                "errors": []
+            }));
+        })
+        .await;
+    let client = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into());
+
+    client
+        .set_identity_opt_out("identity:person:anna", true)
+        .await
+        .unwrap();
+    let error = client
+        .set_identity_opt_out("identity:person:nobody", false)
+        .await
+        .unwrap_err();
+
+    opt_out.assert_async().await;
+    missing.assert_async().await;
+    assert!(error.to_string().contains("not found"));
+}
+
+#[tokio::test]
+async fn neo4j_client_finds_opted_out_identity_of_face_vector() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("WHERE identity.opted_out = true")
+                .body_contains("PART_OF_PERSON")
+                .body_contains(r#""vector_id":"qdrant:faces:point-1""#);
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": ["identity:person:anna"]}]}],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let identity = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .opted_out_face_identity("point-1")
+        .await
+        .unwrap();
+
+    query.assert_async().await;
+    assert_eq!(identity.as_deref(), Some("identity:person:anna"));
+}
+
+#[tokio::test]
+async fn neo4j_client_checks_whether_anyone_opted_out() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("WHERE identity.opted_out = true")
+                .body_contains("LIMIT 1");
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": []}],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let any = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .has_opted_out_identities()
+        .await
+        .unwrap();
+
+    query.assert_async().await;
+    assert!(!any);
+}
+
+#[tokio::test]
+async fn neo4j_client_finds_and_purges_identity_data() {
+    let server = MockServer::start_async().await;
+    let find = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("HAS_VOICE_SAMPLE")
+                .body_contains("source_image_id")
+                .body_contains(r#""identity_id":"identity:person:anna""#);
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [],
+                    "data": [{"row": [
+                        ["face:1", "face:2", "face:1"],
+                        ["voice-signature:1", "voice-sample:1"],
+                        ["audio:1"],
+                        ["sensation:1"],
+                        [["faces", "point-1"], ["faces", "point-1"], ["voices", "point-2"]],
+                        [["image:1", 0.25, 0.1, 0.5, 0.5]]
+                    ]}]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+    let purge = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("DETACH DELETE node")
+                .body_contains("identity.purged_at")
+                .body_contains(r#""qdrant:voices:point-2""#)
+                .body_contains(r#""audio:1""#);
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": [9]}]}],
+                "errors": []
+            }));
+        })
+        .await;
+    let client = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into());
+
+    let found = client.identity_purge("identity:person:anna").await.unwrap();
+    let deleted = client.purge_identity(&found).await.unwrap();
+
+    find.assert_async().await;
+    purge.assert_async().await;
+    assert_eq!(deleted, 9);
+    assert_eq!(
+        found,
+        GraphIdentityPurge {
+            identity_id: "identity:person:anna".into(),
+            face_ids: vec!["face:1".into(), "face:2".into()],
+            voice_ids: vec!["voice-sample:1".into(), "voice-signature:1".into()],
+            audio_clip_ids: vec!["audio:1".into()],
+            sensation_ids: vec!["sensation:1".into()],
+            vectors: vec![
+                GraphIdentityVector {
+                    collection: "faces".into(),
+                    point_id: "point-1".into(),
+                },
+                GraphIdentityVector {
+                    collection: "voices".into(),
+                    point_id: "point-2".into(),
+                },
+            ],
+            face_regions: vec![GraphFaceRegion {
+                image_id: "image:1".into(),
+                bbox: BoundingBox {
+                    x: 0.25,
+                    y: 0.1,
+                    width: 0.5,
+                    height: 0.5,
+                },
+            }],
+        }
+    );
+    assert_eq!(found.node_ids().len(), 8);
+}
+
+#[tokio::test]
+async fn neo4j_client_refuses_to_rename_onto_an_existing_identity() {
+    let server = MockServer::start_async().await;
+    let lookup = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains(r#""identity_id":"identity:person:john""#);
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [],
+                    "data": [{"row": ["identity:person:john", "John", 2, 0, [], ""]}]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let error = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .rename_identity("identity:person:jon", "John")
+        .await
+        .unwrap_err();
+
+    lookup.assert_async().await;
+    assert!(error.to_string().contains("merge identity:person:jon"));
+}
+
+#[tokio::test]
+async fn neo4j_client_merges_identities() {
+    let server = MockServer::start_async().await;
+    let merge = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MERGE (target)-[:HAS_IDENTITY]->(into)")
+                .body_contains("MERGE (run)-[:PRODUCED]->(into)")
+                .body_contains("DETACH DELETE from")
+                .body_contains(r#""from_id":"identity:person:jon""#)
+                .body_contains(r#""into_id":"identity:person:john""#);
+            then.status(200).json_body(json!({
+                "results": [{"columns": ["moved"], "data": [{"row": [3]}]}],
+                "errors": []
+            }));
+        })
+        .await;
+    let client = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into());
+
+    let moved = client
+        .merge_identities("identity:person:jon", "identity:person:john")
+        .await
+        .unwrap();
+
+    merge.assert_async().await;
+    assert_eq!(moved, 3);
+    assert!(
+        client
+            .merge_identities("identity:person:john", "identity:person:john")
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn neo4j_client_splits_a_cluster_into_another_identity() {
+    let server = MockServer::start_async().await;
+    let split = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("DELETE link")
+                .body_contains(r#""target_id":"face-cluster:2""#);
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": ["Face"]}]}],
+                "errors": []
+            }));
+        })
+        .await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let attach = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("ManualFaceIdentityRun")
+                .body_contains("identity:person:mia")
+                .body_contains("face-cluster:2");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    let new_id = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .split_identity(
+            "identity:person:anna",
+            "face-cluster:2",
+            Some("Mia"),
+            "identities",
+        )
+        .await
+        .unwrap();
+
+    split.assert_async().await;
+    constraint.assert_async().await;
+    attach.assert_async().await;
+    assert_eq!(new_id.as_deref(), Some("identity:person:mia"));
+}
+
+#[tokio::test]
+async fn neo4j_client_links_a_face_and_voice_to_a_new_named_person() {
+    let server = MockServer::start_async().await;
+    let lookup = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("RETURN face_person.id, voice_person.id");
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": [null, null, "Anna"]}]}],
+                "errors": []
+            }));
+        })
+        .await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let link = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("Person")
+                .body_contains("person:sha256:")
+                .body_contains("PART_OF_PERSON")
+                .body_contains("HAS_FACE")
+                .body_contains("HAS_VOICE")
+                .body_contains("CO_OCCURS_WITH")
+                .body_contains("face:anna")
+                .body_contains("voice:anna")
+                .body_contains("Anna");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let number = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("SET person.ordinal = ordinal");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    let person_id = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .link_person(&GraphPersonCandidate {
+            face_id: "face:anna".into(),
+            voice_id: "voice:anna".into(),
+            windows: 4,
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+    lookup.assert_async().await;
+    constraint.assert_async().await;
+    link.assert_async().await;
+    number.assert_async().await;
+    assert!(person_id.starts_with("person:sha256:"));
+}
+
+#[tokio::test]
+async fn neo4j_client_adds_a_voice_to_the_person_of_its_face() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("RETURN face_person.id, voice_person.id");
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": ["person:anna", null]}]}],
+                "errors": []
+            }));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let link = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("HAS_VOICE")
+                .body_contains("person:anna");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("SET person.ordinal = ordinal");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    let person_id = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .link_person(&GraphPersonCandidate {
+            face_id: "face:anna".into(),
+            voice_id: "voice:2".into(),
+            windows: 3,
+        })
+        .await
+        .unwrap();
+
+    link.assert_async().await;
+    assert_eq!(person_id.as_deref(), Some("person:anna"));
+}
+
+#[tokio::test]
+async fn neo4j_client_unlinks_a_member_from_its_person() {
+    let server = MockServer::start_async().await;
+    let unlink = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("FOREACH (link IN links | DELETE link)")
+                .body_contains("SET pair.unlinked_at")
+                .body_contains(r#""member_id":"voice:2""#);
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": ["person:anna"]}]}],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let person_id = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .unlink_person_member("voice:2")
+        .await
+        .unwrap();
+
+    unlink.assert_async().await;
+    assert_eq!(person_id.as_deref(), Some("person:anna"));
+}
+
+#[tokio::test]
+async fn neo4j_client_lists_people_by_their_face_or_voice_names() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (person:GraphNode:Person)-[:HAS_FACE|HAS_VOICE]->");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [],
+                    "data": [
+                        {"row": ["person:1", "Anna", ["face:anna"], ["voice:1"], 1]},
+                        {"row": ["person:2", null, ["face:2"], ["voice:2"], 2]}
+                    ]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let people = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .people()
+        .await
+        .unwrap();
+
+    query.assert_async().await;
+    assert_eq!(
+        people,
+        vec![
+            GraphPerson {
+                person_id: "person:1".into(),
+                name: Some("Anna".into()),
+                face_ids: vec!["face:anna".into()],
+                voice_ids: vec!["voice:1".into()],
+                ordinal: Some(1),
+            },
+            GraphPerson {
+                person_id: "person:2".into(),
+                name: None,
+                face_ids: vec!["face:2".into()],
+                voice_ids: vec!["voice:2".into()],
+                ordinal: Some(2),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_latest_timeline_window_for_combobulation() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (anchor:GraphNode:Sensation)")
+                .body_contains("combobulation_summary")
+                .body_contains("coalesce(anchor.how")
+                .body_contains("INCLUDED_IN_COMBOBULATION")
+                .body_contains("ORDER BY datetime(anchor_at) ASC, anchor.id ASC")
+                .body_contains("duration({seconds: $seconds})")
+                .body_contains("coalesce(n.occurred_at")
+                .body_contains("coalesce(n.how")
+                .body_contains("datetime(occurred_at) >= datetime(anchor_at)")
+                .body_contains("datetime(occurred_at) <= datetime(anchor_at) + duration({seconds: $seconds})")
+                .body_contains("NOT (n)-[:INCLUDED_IN_COMBOBULATION]")
+                .body_contains("timeline_order")
+                .body_contains("ORDER BY datetime(occurred_at) ASC, timeline_order ASC, n.id ASC")
+                .body_contains("RETURN anchor.id, anchor_at, row.id, row.event_id, row.labels, row.text, row.occurred_at")
+                .body_contains("\"seconds\":30")
+                .body_contains("\"limit\":80");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": ["anchor.id", "anchor_at", "n.id", "event_id", "labels(n)", "text", "occurred_at"],
+                    "data": [
+                        {"row": [
+                            "sensation:audio:2",
+                            "2026-05-05T12:35:00Z",
+                            "sensation:audio:1",
+                            "audio:1",
+                            ["GraphNode", "Sensation"],
+                            "audio sensation; transcript: hello",
+                            "2026-05-05T12:34:56Z"
+                        ]},
+                        {"row": [
+                            "sensation:audio:2",
+                            "2026-05-05T12:35:00Z",
+                            "sensation:audio:2",
+                            "audio:1",
+                            ["GraphNode", "Sensation"],
+                            "audio sensation; transcript: there",
+                            "2026-05-05T12:35:00Z"
+                        ]}
+                    ]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let window = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .latest_timeline_window_for_combobulation(30, 80)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(window.anchor_id, "sensation:audio:2");
+    assert_eq!(window.anchor_at, "2026-05-05T12:35:00Z");
+    assert_eq!(window.items.len(), 2);
+    assert_eq!(window.items[0].id, "sensation:audio:1");
+    assert_eq!(window.items[0].event_id, "audio:1");
+    assert_eq!(window.items[0].labels, ["GraphNode", "Sensation"]);
+    assert_eq!(window.items[1].text, "audio sensation; transcript: there");
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_previous_timeline_window_for_combobulation() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (anchor:GraphNode:Sensation)")
+                .body_contains("datetime(anchor_at) < datetime($before)")
+                .body_contains("INCLUDED_IN_COMBOBULATION")
+                .body_contains("duration({seconds: $seconds})")
+                .body_contains("coalesce(n.how")
+                .body_contains("ORDER BY datetime(anchor_at) ASC, anchor.id ASC")
+                .body_contains("ORDER BY datetime(occurred_at) ASC, timeline_order ASC, n.id ASC")
+                .body_contains("RETURN anchor.id, anchor_at, row.id, row.event_id, row.labels, row.text, row.occurred_at")
+                .body_contains("\"before\":\"2026-05-05T12:34:56Z\"")
+                .body_contains("\"seconds\":30")
+                .body_contains("\"limit\":80");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": ["anchor.id", "anchor_at", "n.id", "event_id", "labels(n)", "text", "occurred_at"],
+                    "data": [
+                        {"row": [
+                            "sensation:audio:0",
+                            "2026-05-05T12:34:30Z",
+                            "sensation:audio:-1",
+                            "audio:-1",
+                            ["GraphNode", "Sensation"],
+                            "audio sensation; transcript: earlier",
+                            "2026-05-05T12:34:20Z"
+                        ]},
+                        {"row": [
+                            "sensation:audio:0",
+                            "2026-05-05T12:34:30Z",
+                            "sensation:audio:0",
+                            "audio:0",
+                            ["GraphNode", "Sensation"],
+                            "audio sensation; transcript: before",
+                            "2026-05-05T12:34:30Z"
+                        ]}
+                    ]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let window = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .previous_timeline_window_for_combobulation(30, 80, "2026-05-05T12:34:56Z")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(window.anchor_id, "sensation:audio:0");
+    assert_eq!(window.anchor_at, "2026-05-05T12:34:30Z");
+    assert_eq!(window.items.len(), 2);
+    assert_eq!(window.items[0].id, "sensation:audio:-1");
+    assert_eq!(window.items[1].event_id, "audio:0");
+    assert_eq!(window.items[1].text, "audio sensation; transcript: before");
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_sensation_timeline_with_sensation_order() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (n:GraphNode:Sensation)")
+                .body_contains("coalesce(n.how")
+                .body_contains("timeline_order")
+                .body_contains("ORDER BY datetime(occurred_at) DESC, timeline_order DESC, n.id DESC")
+                .body_contains("RETURN row.id, row.labels, row.kind, row.text, row.occurred_at, row.formed_at")
+                .body_contains("\"limit\":20");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": ["row.id", "row.labels", "row.kind", "row.text", "row.occurred_at", "row.formed_at"],
+                    "data": [
+                        {"row": [
+                            "sensation:image:1",
+                            ["GraphNode", "Sensation"],
+                            "image",
+                            psyche::IMAGE_SENSATION_TEXT,
+                            "2026-05-05T12:34:56Z",
+                            "2026-05-05T12:34:56Z"
+                        ]},
+                        {"row": [
+                            "sensation:face_recognition:1",
+                            ["GraphNode", "Sensation"],
+                            "face_recognition",
+                            psyche::face_count_sensation_text(1),
+                            "2026-05-05T12:34:56Z",
+                            "2026-05-05T12:34:57Z"
+                        ]},
+                        {"row": [
+                            "sensation:face_identity:1",
+                            ["GraphNode", "Sensation"],
+                            "face_identity",
+                            psyche::face_familiarity_sensation_text(false),
+                            "2026-05-05T12:34:56Z",
+                            "2026-05-05T12:34:57Z"
+                        ]}
+                    ]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let end = chrono::DateTime::parse_from_rfc3339("2026-05-05T12:35:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let items = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .sensation_timeline(None, end, 20)
+        .await
+        .unwrap();
+
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0].text, psyche::IMAGE_SENSATION_TEXT);
+    assert_eq!(items[1].text, psyche::face_count_sensation_text(1));
+    assert_eq!(
+        items[2].text,
+        psyche::face_familiarity_sensation_text(false)
+    );
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_conversation_timeline_with_user_and_conversant_words() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (n:GraphNode:Sensation)")
+                .body_contains("I hear someone on my web interface type: ")
+                .body_contains("I ought to say: ")
+                .body_contains("RETURN row.id, row.labels, row.kind, row.text, row.occurred_at, row.formed_at")
+                .body_contains("\"limit\":20");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": ["row.id", "row.labels", "row.kind", "row.text", "row.occurred_at", "row.formed_at"],
+                    "data": [
+                        {"row": [
+                            "sensation:web:1",
+                            ["GraphNode", "Sensation"],
+                            "web_interface_text",
+                            "I hear someone on my web interface type: hello pete.",
+                            "2026-05-05T12:34:56Z",
+                            "2026-05-05T12:34:56Z"
+                        ]},
+                        {"row": [
+                            "sensation:cognitive:1",
+                            ["GraphNode", "Sensation"],
+                            "cognitive",
+                            "I ought to say: Hello there.",
+                            "2026-05-05T12:34:57Z",
+                            "2026-05-05T12:34:57Z"
+                        ]}
+                    ]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let end = chrono::DateTime::parse_from_rfc3339("2026-05-05T12:35:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let items = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .conversation_timeline(None, end, 20)
+        .await
+        .unwrap();
+
+    assert_eq!(items.len(), 2);
+    assert_eq!(
+        items[0].text,
+        "I hear someone on my web interface type: hello pete."
+    );
+    assert_eq!(items[1].text, "I ought to say: Hello there.");
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_revisitable_timeline_window_for_combobulation() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (run:GraphNode:CombobulationRun)")
+                .body_contains("run.source_texts")
+                .body_contains("current_source_texts")
+                .body_contains("size(run.source_ids) <= $limit")
+                .body_contains("NOT EXISTS")
+                .body_contains("coalesce(n.how")
+                .body_contains("RETURN run.anchor_id, run.anchor_at, item.id, item.event_id, item.labels, item.text, item.occurred_at")
+                .body_contains("\"limit\":80");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": ["run.anchor_id", "run.anchor_at", "item.id", "item.event_id", "item.labels", "item.text", "item.occurred_at"],
+                    "data": [
+                        {"row": [
+                            "sensation:audio:2",
+                            "2026-05-05T12:35:00Z",
+                            "sensation:audio:1",
+                            "audio:1",
+                            ["GraphNode", "Sensation"],
+                            "audio sensation; transcript: hello",
+                            "2026-05-05T12:34:56Z"
+                        ]},
+                        {"row": [
+                            "sensation:audio:2",
+                            "2026-05-05T12:35:00Z",
+                            "sensation:audio:2",
+                            "audio:2",
+                            ["GraphNode", "Sensation"],
+                            "audio sensation; transcript: there",
+                            "2026-05-05T12:35:00Z"
+                        ]}
+                    ]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let window = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .latest_revisitable_timeline_window_for_combobulation(80)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(window.anchor_id, "sensation:audio:2");
+    assert_eq!(window.anchor_at, "2026-05-05T12:35:00Z");
+    assert_eq!(window.items.len(), 2);
+    assert_eq!(window.items[0].id, "sensation:audio:1");
+    assert_eq!(window.items[1].event_id, "audio:2");
+    assert_eq!(window.items[1].text, "audio sensation; transcript: there");
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_attaches_combobulation() {
+    let server = MockServer::start_async().await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CombobulationRun")
+                .body_contains("Awareness")
+                .body_contains("INCLUDED_IN_COMBOBULATION")
+                .body_contains("HAS_MEMORY_VECTOR")
+                .body_contains("qdrant:memories:point-1")
+                .body_contains("\"kind\":\"combobulation_summary\"")
+                .body_contains("\"derived\":true")
+                .body_contains("\"how\":\"I hear someone greeting me.\"")
+                .body_contains("\"combobulation_run_id\"")
+                .body_contains("\"awareness_id\":\"awareness:speech:2\"")
+                .body_contains("\"model\":\"wit-test\"")
+                .body_contains("\"embedding_model\":\"embed-test\"")
+                .body_contains("\"source_count\":2")
+                .body_contains("\"source_event_ids\":[\"audio:1\",\"audio:1\"]")
+                .body_contains("\"source_started_at\":\"2026-05-05T12:34:56Z\"")
+                .body_contains("\"source_ended_at\":\"2026-05-05T12:35:00Z\"")
+                .body_contains("\"source_texts\":[\"speech: hello\",\"speech: there\"]")
+                .matches(|req| {
+                    let Some(body) = req
+                        .body
+                        .as_deref()
+                        .and_then(|body| std::str::from_utf8(body).ok())
+                    else {
+                        return false;
+                    };
+                    let Ok(body) = serde_json::from_str::<Value>(body) else {
+                        return false;
+                    };
+                    body["statements"]
+                        .as_array()
+                        .and_then(|statements| {
+                            statements.iter().find_map(|statement| {
+                                let props = &statement["parameters"]["props"];
+                                (props["kind"].as_str() == Some("combobulation_summary"))
+                                    .then_some(props)
+                            })
+                        })
+                        .is_some_and(|props| {
+                            let occurred_at = props["occurred_at"].as_str();
+                            occurred_at == props["created_at"].as_str()
+                                && occurred_at == props["how_formed_at"].as_str()
+                                && occurred_at != Some("2026-05-05T12:34:56Z")
+                                && occurred_at != props["source_started_at"].as_str()
+                        })
+                });
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_combobulation(
+            &GraphTimelineWindow {
+                anchor_id: "speech:2".into(),
+                anchor_at: "2026-05-05T12:35:00Z".into(),
+                items: vec![
+                    GraphTimelineItem {
+                        id: "speech:1".into(),
+                        event_id: "audio:1".into(),
+                        labels: vec!["GraphNode".into(), "SpeechSegment".into()],
+                        text: "speech: hello".into(),
+                        occurred_at: "2026-05-05T12:34:56Z".into(),
+                    },
+                    GraphTimelineItem {
+                        id: "speech:2".into(),
+                        event_id: "audio:1".into(),
+                        labels: vec!["GraphNode".into(), "SpeechSegment".into()],
+                        text: "speech: there".into(),
+                        occurred_at: "2026-05-05T12:35:00Z".into(),
+                    },
+                ],
+            },
+            "wit-test",
+            "embed-test",
+            &GraphAwareness {
+                awareness_id: "awareness:speech:2".into(),
+                text: "I hear someone greeting me.".into(),
+                emoji: None,
+                vector_id: "point-1".into(),
+                embedding_len: 768,
+            },
+        )
+        .await
+        .unwrap();
+
+    constraint.assert_async().await;
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_latest_combobulation_emotion() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CombobulationSummary");
+            then.status(200).body(
+                r#"{"results":[{"data":[{"row":["awareness:1","","I think someone is nearby. 🙂"]}]}],"errors":[]}"#,
+            );
+        })
+        .await;
+
+    let emotion = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .latest_combobulation_emotion()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(emotion.id, "awareness:1");
+    assert_eq!(emotion.emoji, "🙂");
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_latest_combobulation() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("RETURN n.id, text, emoji, formed_at");
+            then.status(200).body(
+                r#"{"results":[{"data":[{"row":["awareness:1","I think someone is nearby. 🙂","","2026-05-07T12:00:00Z"]}]}],"errors":[]}"#,
+            );
+        })
+        .await;
+
+    let latest = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .latest_combobulation()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(latest.id, "awareness:1");
+    assert_eq!(latest.text, "I think someone is nearby. 🙂");
+    assert_eq!(latest.emoji.as_deref(), Some("🙂"));
+    assert_eq!(latest.formed_at, "2026-05-07T12:00:00Z");
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_latest_combobulation_sensation_at() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MATCH (n:GraphNode:Sensation)")
+                .body_contains("combobulation_summary")
+                .body_contains("RETURN occurred_at")
+                .body_contains("ORDER BY datetime(occurred_at) DESC");
+            then.status(200)
+                .body(r#"{"results":[{"data":[{"row":["2026-05-07T12:00:00Z"]}]}],"errors":[]}"#);
+        })
+        .await;
+
+    let occurred_at = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .latest_combobulation_sensation_at()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(occurred_at, "2026-05-07T12:00:00Z");
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_latest_presentable_face_emotion() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("I turn my face into");
+            then.status(200).body(
+                r#"{"results":[{"data":[{"row":["impression:1","😐","I turn my face into a 😐."]}]}],"errors":[]}"#,
+            );
+        })
+        .await;
+
+    let emotion = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .latest_presentable_face_emotion()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(emotion.id, "impression:1");
+    assert_eq!(emotion.emoji, "😐");
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_loads_latest_pending_speech_intention() {
+    let server = MockServer::start_async().await;
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("I ought to say: ")
+                .body_contains("I start saying: ")
+                .body_contains("I queue saying: ")
+                .body_contains(r#"I start saying \\\"\" + words + \"\\\".\""#)
+                .body_contains("RETURN n.id, words, formed_at");
+            then.status(200).body(
+                r#"{"results":[{"data":[{"row":["impression:1","Hello there.","2026-05-07T12:00:00Z"]}]}],"errors":[]}"#,
+            );
+        })
+        .await;
+
+    let intention = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .latest_pending_speech_intention()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(intention.id, "impression:1");
+    assert_eq!(intention.text, "Hello there.");
+    assert_eq!(intention.formed_at, "2026-05-07T12:00:00Z");
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_attaches_voice_recognition() {
+    let server = MockServer::start_async().await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("VoiceRecognitionRun")
+                .body_contains("VoiceSignature")
+                .body_contains("VoiceSample")
+                .body_contains("HAS_VOICE_RECOGNITION_RUN")
+                .body_contains("HAS_VOICE_VECTOR")
+                .body_contains("qdrant:voices:point-1")
+                .body_contains("sensation:audio:1")
+                .body_contains("\"kind\":\"voice_identity\"")
+                .body_contains("\"how\":\"I don't think I recognize this voice.\"")
+                .body_contains("fundamental_frequency")
+                .body_contains("quality_score")
+                .body_contains("\"model\":\"voxudio\"");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_voice_recognition(
+            &GraphVoiceClip {
+                id: "audio:1".into(),
+                clip: psyche::AudioClip {
+                    mime: "audio/pcm;format=s16le;rate=16000".into(),
+                    base64: "AAA=".into(),
+                    sample_rate: 16000,
+                    channels: 1,
+                    transcript: None,
+                    captured_at: Some("2026-05-05T12:34:56Z".into()),
+                },
+                occurred_at: Some("2026-05-05T12:34:57Z".into()),
+                sensation_id: Some("sensation:audio:1".into()),
+            },
+            "voxudio",
+            &GraphVoiceRecognition {
+                signature: GraphVoiceSignature {
+                    user_id: "speaker:1".into(),
+                    fundamental_frequency: 150.0,
+                    frequency_range: (100.0, 300.0),
+                    formant_frequencies: vec![800.0, 1200.0, 2500.0],
+                    speech_rate: 4.5,
+                    mfcc_signature: vec![0.1, 0.2],
+                    spectral_centroid: 1500.0,
+                    jitter: 0.5,
+                    shimmer: 3.0,
+                    harmonic_to_noise_ratio: 20.0,
+                    sample_count: 1,
+                    last_updated: Utc::now(),
+                    tags: vec!["voice".into()],
+                },
+                sample: GraphVoiceSample {
+                    id: "voice-sample:1".into(),
+                    user_id: "speaker:1".into(),
+                    duration_ms: 2000,
+                    sample_rate: 16000,
+                    fundamental_frequency: 150.0,
+                    formant_frequencies: vec![800.0, 1200.0, 2500.0],
+                    mfcc: vec![0.1, 0.2],
+                    quality_score: 0.9,
+                    timestamp: Utc::now(),
+                },
+                vector_id: "point-1".into(),
+                embedding_len: 256,
+                recognition: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    constraint.assert_async().await;
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_attaches_known_voice_identity_sensation() {
+    let server = MockServer::start_async().await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("\"kind\":\"voice_identity\"")
+                .body_contains("\"how\":\"I recognize Anna's voice.\"")
+                .body_contains("\"matched_voice_id\":\"cluster:voice:1\"")
+                .body_contains("\"identity_name\":\"Anna\"")
+                .body_contains("\"nearest_voice_vector_id\":\"known-point\"")
+                .body_contains("MATCHED_VOICE")
+                .body_contains("RECOGNIZED_AS")
+                .body_contains("MATCHED_NEAREST_VECTOR");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_voice_recognition(
+            &test_voice_clip(),
+            "voxudio",
+            &test_voice_recognition(Some(psyche::GraphVoiceMatch {
+                voice_id: "cluster:voice:1".into(),
+                identity: Some("Anna".into()),
+                nearest_vector_id: "known-point".into(),
+                score: 0.93,
+            })),
+        )
+        .await
+        .unwrap();
+
+    constraint.assert_async().await;
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_attaches_unknown_voice_match_sensation() {
+    let server = MockServer::start_async().await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("\"kind\":\"voice_identity\"")
+                .body_contains("\"how\":\"I recognize the voice, but I don't know who it is.\"")
+                .body_contains("\"matched_voice_id\":\"cluster:voice:2\"")
+                .body_contains("\"nearest_voice_vector_id\":\"known-point-2\"")
+                .body_contains("MATCHED_VOICE");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_voice_recognition(
+            &test_voice_clip(),
+            "voxudio",
+            &test_voice_recognition(Some(psyche::GraphVoiceMatch {
+                voice_id: "cluster:voice:2".into(),
+                identity: None,
+                nearest_vector_id: "known-point-2".into(),
+                score: 0.91,
+            })),
+        )
+        .await
+        .unwrap();
+
+    constraint.assert_async().await;
+    update.assert_async().await;
+}
+
+fn test_voice_clip() -> GraphVoiceClip {
+    GraphVoiceClip {
+        id: "audio:1".into(),
+        clip: psyche::AudioClip {
+            mime: "audio/pcm;format=s16le;rate=16000".into(),
+            base64: "AAA=".into(),
+            sample_rate: 16000,
+            channels: 1,
+            transcript: None,
+            captured_at: Some("2026-05-05T12:34:56Z".into()),
+        },
+        occurred_at: Some("2026-05-05T12:34:57Z".into()),
+        sensation_id: Some("sensation:audio:1".into()),
+    }
+}
+
+fn test_voice_recognition(recognition: Option<psyche::GraphVoiceMatch>) -> GraphVoiceRecognition {
+    GraphVoiceRecognition {
+        signature: GraphVoiceSignature {
+            user_id: "speaker:1".into(),
+            fundamental_frequency: 150.0,
+            frequency_range: (100.0, 300.0),
+            formant_frequencies: vec![800.0, 1200.0, 2500.0],
+            speech_rate: 4.5,
+            mfcc_signature: vec![0.1, 0.2],
+            spectral_centroid: 1500.0,
+            jitter: 0.5,
+            shimmer: 3.0,
+            harmonic_to_noise_ratio: 20.0,
+            sample_count: 1,
+            last_updated: Utc::now(),
+            tags: vec!["voice".into()],
+        },
+        sample: GraphVoiceSample {
+            id: "voice-sample:1".into(),
+            user_id: "speaker:1".into(),
+            duration_ms: 2000,
+            sample_rate: 16000,
+            fundamental_frequency: 150.0,
+            formant_frequencies: vec![800.0, 1200.0, 2500.0],
+            mfcc: vec![0.1, 0.2],
+            quality_score: 0.9,
+            timestamp: Utc::now(),
+        },
+        vector_id: "point-1".into(),
+        embedding_len: 256,
+        recognition,
+    }
+}
+
+#[tokio::test]
+async fn neo4j_client_attaches_skipped_voice_recognition() {
+    let server = MockServer::start_async().await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let update = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("VoiceRecognitionRun")
+                .body_contains("HAS_VOICE_RECOGNITION_RUN")
+                .body_contains("PROCESSED_AUDIO")
+                .body_contains("sensation:audio:1")
+                .body_contains("\"status\":\"skipped\"")
+                .body_contains("\"reason\":\"audio clip too short\"")
+                .body_contains("\"model\":\"voxudio\"");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_skipped_voice_recognition(
+            &GraphVoiceClip {
+                id: "audio:1".into(),
+                clip: psyche::AudioClip {
+                    mime: "audio/pcm;format=s16le;rate=16000".into(),
+                    base64: "AAA=".into(),
+                    sample_rate: 16000,
+                    channels: 1,
+                    transcript: None,
+                    captured_at: Some("2026-05-05T12:34:56Z".into()),
+                },
+                occurred_at: Some("2026-05-05T12:34:57Z".into()),
+                sensation_id: Some("sensation:audio:1".into()),
+            },
+            "voxudio",
+            "audio clip too short",
+        )
+        .await
+        .unwrap();
+
+    constraint.assert_async().await;
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_claims_work_lease_with_server_side_expiry() {
+    let server = MockServer::start_async().await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_work_lease_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let claim = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MERGE (l:WorkLease {id: $lease_id})")
+                .body_contains("(l.owner IS NULL OR l.expires_at_ms <= timestamp())")
+                .body_contains("l.failed_at_ms = timestamp()")
+                .body_contains("lease:transcription:audio:1")
+                .body_contains("\"ttl_ms\":30000")
+                .body_contains("\"max_attempts\":5");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "data": [{
+                        "row": [
+                            "lease:transcription:audio:1",
+                            "transcription",
+                            "audio:1",
+                            "worker-a",
+                            "token-1",
+                            1_778_000_030_000_i64,
+                            2
+                        ]
+                    }]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let lease = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .with_max_lease_attempts(5)
+        .claim_lease(
+            WorkLease::TRANSCRIPTION,
+            "audio:1",
+            "worker-a",
+            std::time::Duration::from_secs(30),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(lease.id, WorkLease::lease_id("transcription", "audio:1"));
+    assert_eq!(lease.owner, "worker-a");
+    assert_eq!(lease.token, "token-1");
+    assert_eq!(lease.expires_at_ms, 1_778_000_030_000);
+    assert_eq!(lease.attempts, 2);
+    constraint.assert_async().await;
+    claim.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_reports_work_lease_held_elsewhere() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/db/neo4j/tx/commit");
+            then.status(200)
+                .body(r#"{"results":[{"data":[]}],"errors":[]}"#);
+        })
+        .await;
+
+    let lease = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .claim_lease(
+            WorkLease::FACE_RECOGNITION,
+            "image:1",
+            "worker-b",
+            std::time::Duration::from_secs(30),
+        )
+        .await
+        .unwrap();
+
+    assert!(lease.is_none());
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_combobulation() {
+async fn neo4j_client_claims_worker_lease_on_worker_node() {
     let server = MockServer::start_async().await;
-    let constraint = server
+    server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+                .body_contains("CREATE CONSTRAINT pete_work_lease_id");
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
-    let update = server
+    let worker = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("CombobulationRun")
-                .body_contains("Awareness")
-                .body_contains("INCLUDED_IN_COMBOBULATION")
-                .body_contains("HAS_MEMORY_VECTOR")
-                .body_contains("qdrant:memories:point-1")
-                .body_contains("\"kind\":\"combobulation_summary\"")
-                .body_contains("\"derived\":true")
-                .body_contains("\"how\":\"I hear someone greeting me.\"")
-                .body_contains("\"combobulation_run_id\"")
-                .body_contains("\"awareness_id\":\"awareness:speech:2\"")
-                .body_contains("\"model\":\"wit-test\"")
-                .body_contains("\"embedding_model\":\"embed-test\"")
-                .body_contains("\"source_count\":2")
-                .body_contains("\"source_event_ids\":[\"audio:1\",\"audio:1\"]")
-                .body_contains("\"source_started_at\":\"2026-05-05T12:34:56Z\"")
-                .body_contains("\"source_ended_at\":\"2026-05-05T12:35:00Z\"")
-                .body_contains("\"source_texts\":[\"speech: hello\",\"speech: there\"]")
-                .matches(|req| {
-                    let Some(body) = req
-                        .body
-                        .as_deref()
-                        .and_then(|body| std::str::from_utf8(body).ok())
-                    else {
-                        return false;
-                    };
-                    let Ok(body) = serde_json::from_str::<Value>(body) else {
-                        return false;
-                    };
-                    body["statements"]
-                        .as_array()
-                        .and_then(|statements| {
-                            statements.iter().find_map(|statement| {
-                                let props = &statement["parameters"]["props"];
-                                (props["kind"].as_str() == Some("combobulation_summary"))
-                                    .then_some(props)
-                            })
-                        })
-                        .is_some_and(|props| {
-                            let occurred_at = props["occurred_at"].as_str();
-                            occurred_at == props["created_at"].as_str()
-                                && occurred_at == props["how_formed_at"].as_str()
-                                && occurred_at != Some("2026-05-05T12:34:56Z")
-                                && occurred_at != props["source_started_at"].as_str()
-                        })
-                });
+                .body_contains("`Worker`")
+                .body_contains("worker:face_tracking");
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
+    let claim = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MERGE (l:WorkLease {id: $lease_id})")
+                .body_contains("lease:face_tracking:worker:face_tracking")
+                .body_contains("\"max_attempts\":null");
+            then.status(200)
+                .body(r#"{"results":[{"data":[]}],"errors":[]}"#);
+        })
+        .await;
 
-    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_combobulation(
-            &GraphTimelineWindow {
-                anchor_id: "speech:2".into(),
-                anchor_at: "2026-05-05T12:35:00Z".into(),
-                items: vec![
-                    GraphTimelineItem {
-                        id: "speech:1".into(),
-                        event_id: "audio:1".into(),
-                        labels: vec!["GraphNode".into(), "SpeechSegment".into()],
-                        text: "speech: hello".into(),
-                        occurred_at: "2026-05-05T12:34:56Z".into(),
-                    },
-                    GraphTimelineItem {
-                        id: "speech:2".into(),
-                        event_id: "audio:1".into(),
-                        labels: vec!["GraphNode".into(), "SpeechSegment".into()],
-                        text: "speech: there".into(),
-                        occurred_at: "2026-05-05T12:35:00Z".into(),
-                    },
-                ],
-            },
-            "wit-test",
-            "embed-test",
-            &GraphAwareness {
-                awareness_id: "awareness:speech:2".into(),
-                text: "I hear someone greeting me.".into(),
-                emoji: None,
-                vector_id: "point-1".into(),
-                embedding_len: 768,
-            },
+    let lease = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .claim_worker_lease(
+            WorkLease::FACE_TRACKING,
+            "worker-b",
+            std::time::Duration::from_secs(30),
         )
         .await
         .unwrap();
 
-    constraint.assert_async().await;
-    update.assert_async().await;
+    assert!(lease.is_none());
+    worker.assert_async().await;
+    claim.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_latest_combobulation_emotion() {
+async fn neo4j_client_releases_only_its_own_work_lease() {
     let server = MockServer::start_async().await;
-    let query = server
+    let release = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("CombobulationSummary");
-            then.status(200).body(
-                r#"{"results":[{"data":[{"row":["awareness:1","","I think someone is nearby. 🙂"]}]}],"errors":[]}"#,
-            );
+                .body_contains("WHERE l.owner = $owner AND l.token = $token")
+                .body_contains("DETACH DELETE l");
+            then.status(200)
+                .body(r#"{"results":[{"data":[{"row":[0]}]}],"errors":[]}"#);
         })
         .await;
+    let lease = WorkLease {
+        id: WorkLease::lease_id("face_recognition", "image:1"),
+        kind: WorkLease::FACE_RECOGNITION.into(),
+        node_id: "image:1".into(),
+        owner: "worker-a".into(),
+        token: "stale".into(),
+        expires_at_ms: 0,
+        attempts: 1,
+    };
 
-    let emotion = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .latest_combobulation_emotion()
+    let released = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .release_lease(&lease)
         .await
-        .unwrap()
         .unwrap();
 
-    assert_eq!(emotion.id, "awareness:1");
-    assert_eq!(emotion.emoji, "🙂");
-    query.assert_async().await;
+    assert!(!released);
+    release.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_latest_combobulation() {
+async fn neo4j_client_abandons_work_lease_keeping_its_attempts() {
     let server = MockServer::start_async().await;
-    let query = server
+    let abandon = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("RETURN n.id, text, emoji, formed_at");
-            then.status(200).body(
-                r#"{"results":[{"data":[{"row":["awareness:1","I think someone is nearby. 🙂","","2026-05-07T12:00:00Z"]}]}],"errors":[]}"#,
-            );
+                .body_contains("WHERE l.owner = $owner AND l.token = $token")
+                .body_contains("SET l.owner = null, l.token = null")
+                .body_contains("lease:object_detection:image:2");
+            then.status(200)
+                .body(r#"{"results":[{"data":[{"row":[1]}]}],"errors":[]}"#);
         })
         .await;
+    let lease = WorkLease {
+        id: WorkLease::lease_id("object_detection", "image:2"),
+        kind: WorkLease::OBJECT_DETECTION.into(),
+        node_id: "image:2".into(),
+        owner: "worker-a".into(),
+        token: "token-2".into(),
+        expires_at_ms: 0,
+        attempts: 1,
+    };
 
-    let latest = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .latest_combobulation()
+    let abandoned = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .abandon_lease(&lease)
         .await
-        .unwrap()
         .unwrap();
 
-    assert_eq!(latest.id, "awareness:1");
-    assert_eq!(latest.text, "I think someone is nearby. 🙂");
-    assert_eq!(latest.emoji.as_deref(), Some("🙂"));
-    assert_eq!(latest.formed_at, "2026-05-07T12:00:00Z");
-    query.assert_async().await;
+    assert!(abandoned);
+    abandon.assert_async().await;
+}
+
+fn lease_claim_response(node_id: &str) -> Value {
+    json!({
+        "results": [{
+            "data": [{
+                "row": [
+                    WorkLease::lease_id("text_recognition", node_id),
+                    "text_recognition",
+                    node_id,
+                    "worker-a",
+                    "token-1",
+                    1_778_000_030_000_i64,
+                    1
+                ]
+            }]
+        }],
+        "errors": []
+    })
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_latest_combobulation_sensation_at() {
+async fn work_lease_is_released_after_successful_work() {
     let server = MockServer::start_async().await;
-    let query = server
+    server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("MATCH (n:GraphNode:Sensation)")
-                .body_contains("combobulation_summary")
-                .body_contains("RETURN occurred_at")
-                .body_contains("ORDER BY datetime(occurred_at) DESC");
+                .body_contains("CREATE CONSTRAINT pete_work_lease_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MERGE (l:WorkLease {id: $lease_id})");
+            then.status(200).json_body(lease_claim_response("image:1"));
+        })
+        .await;
+    let release = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("DETACH DELETE l")
+                .body_contains("token-1");
             then.status(200)
-                .body(r#"{"results":[{"data":[{"row":["2026-05-07T12:00:00Z"]}]}],"errors":[]}"#);
+                .body(r#"{"results":[{"data":[{"row":[1]}]}],"errors":[]}"#);
         })
         .await;
+    let graph = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into());
+    let settings = LeaseSettings::new(Some("worker-a".into()), 30_000);
+
+    let read = with_work_lease(
+        &graph,
+        WorkLease::TEXT_RECOGNITION,
+        "image:1",
+        &settings,
+        async { Ok::<_, anyhow::Error>("text") },
+    )
+    .await
+    .unwrap();
 
-    let occurred_at = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .latest_combobulation_sensation_at()
-        .await
-        .unwrap()
-        .unwrap();
+    assert_eq!(read, Some("text"));
+    release.assert_async().await;
+}
 
-    assert_eq!(occurred_at, "2026-05-07T12:00:00Z");
-    query.assert_async().await;
+#[tokio::test]
+async fn work_lease_is_abandoned_when_work_fails() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_work_lease_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MERGE (l:WorkLease {id: $lease_id})");
+            then.status(200).json_body(lease_claim_response("image:2"));
+        })
+        .await;
+    let abandon = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("SET l.owner = null, l.token = null");
+            then.status(200)
+                .body(r#"{"results":[{"data":[{"row":[1]}]}],"errors":[]}"#);
+        })
+        .await;
+    let graph = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into());
+    let settings = LeaseSettings::new(Some("worker-a".into()), 30_000);
+
+    let err = with_work_lease(
+        &graph,
+        WorkLease::TEXT_RECOGNITION,
+        "image:2",
+        &settings,
+        async { Err::<(), _>(anyhow::anyhow!("unreadable")) },
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "unreadable");
+    abandon.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_latest_presentable_face_emotion() {
+async fn work_lease_held_elsewhere_skips_the_work() {
     let server = MockServer::start_async().await;
-    let query = server
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/db/neo4j/tx/commit");
+            then.status(200)
+                .body(r#"{"results":[{"data":[]}],"errors":[]}"#);
+        })
+        .await;
+    let graph = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into());
+    let settings = LeaseSettings::new(None, 0);
+    let ran = std::sync::atomic::AtomicBool::new(false);
+
+    let result = with_work_lease(
+        &graph,
+        WorkLease::TEXT_RECOGNITION,
+        "image:3",
+        &settings,
+        async {
+            ran.store(true, std::sync::atomic::Ordering::SeqCst);
+            Ok::<_, anyhow::Error>(())
+        },
+    )
+    .await
+    .unwrap();
+
+    assert!(result.is_none());
+    assert!(!ran.load(std::sync::atomic::Ordering::SeqCst));
+    assert_eq!(settings.ttl, std::time::Duration::from_secs(1));
+}
+
+fn temp_blob_store() -> (std::path::PathBuf, std::sync::Arc<LocalBlobStore>) {
+    let root = std::env::temp_dir().join(format!("psyche-blobs-{}", uuid::Uuid::new_v4()));
+    let store = LocalBlobStore::new(root.clone()).with_gc_grace(std::time::Duration::ZERO);
+    (root, std::sync::Arc::new(store))
+}
+
+#[tokio::test]
+async fn neo4j_client_moves_stored_image_payloads_into_blob_store() {
+    let server = MockServer::start_async().await;
+    let hash = blob_hash(b"jpeg");
+    server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("I turn my face into");
-            then.status(200).body(
-                r#"{"results":[{"data":[{"row":["impression:1","😐","I turn my face into a 😐."]}]}],"errors":[]}"#,
-            );
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+        })
+        .await;
+    let commit = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("MERGE (n:GraphNode")
+                .body_contains(format!(r#""blob_hash":"{hash}""#))
+                .body_contains(r#""blob_size":4"#)
+                .body_contains("sensation:image:1");
+            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
+    let (root, blobs) = temp_blob_store();
 
-    let emotion = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .latest_presentable_face_emotion()
+    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .with_blob_store(blobs.clone())
+        .store_data(&json!({
+            "op": "merge_graph",
+            "nodes": [
+                {
+                    "label": "Image",
+                    "id": "image:1",
+                    "mime": "image/jpeg",
+                    "base64": "anBlZw==",
+                },
+                {
+                    "label": "Sensation",
+                    "id": "sensation:image:1",
+                    "base64": "kept",
+                }
+            ],
+            "relationships": [],
+        }))
         .await
-        .unwrap()
         .unwrap();
 
-    assert_eq!(emotion.id, "impression:1");
-    assert_eq!(emotion.emoji, "😐");
-    query.assert_async().await;
+    commit.assert_async().await;
+    assert_eq!(blobs.get(&hash).await.unwrap().unwrap(), b"jpeg");
+    std::fs::remove_dir_all(root).unwrap();
 }
 
 #[tokio::test]
-async fn neo4j_client_loads_latest_pending_speech_intention() {
+async fn neo4j_client_loads_image_frame_payloads_from_blob_store() {
     let server = MockServer::start_async().await;
+    let (root, blobs) = temp_blob_store();
+    let hash = blobs.insert(b"png").await.unwrap();
     let query = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("I ought to say: ")
-                .body_contains("I start saying: ")
-                .body_contains("I queue saying: ")
-                .body_contains(r#"I start saying \\\"\" + words + \"\\\".\""#)
-                .body_contains("RETURN n.id, words, formed_at");
-            then.status(200).body(
-                r#"{"results":[{"data":[{"row":["impression:1","Hello there.","2026-05-07T12:00:00Z"]}]}],"errors":[]}"#,
-            );
+                .body_contains("TextRecognitionRun")
+                .body_contains("blob_hash");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [],
+                    "data": [{
+                        "row": [
+                            "image:1",
+                            "image/png",
+                            format!("blob:{hash}"),
+                            null,
+                            "2026-05-05T12:34:56Z",
+                            "sensation:image:1"
+                        ]
+                    }]
+                }],
+                "errors": []
+            }));
         })
         .await;
 
-    let intention = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .latest_pending_speech_intention()
+    let frame = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .with_blob_store(blobs)
+        .latest_unprocessed_image_frame_for_text_recognition()
         .await
         .unwrap()
         .unwrap();
 
-    assert_eq!(intention.id, "impression:1");
-    assert_eq!(intention.text, "Hello there.");
-    assert_eq!(intention.formed_at, "2026-05-07T12:00:00Z");
+    assert_eq!(frame.image.base64, "cG5n");
     query.assert_async().await;
+    std::fs::remove_dir_all(root).unwrap();
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_voice_recognition() {
+async fn neo4j_client_migrates_inline_media_payloads() {
     let server = MockServer::start_async().await;
-    let constraint = server
+    let hash = blob_hash(b"pcm");
+    let list = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+                .body_contains("n:Image OR n:AudioClip")
+                .body_contains("LIMIT $limit")
+                .body_contains(r#""limit":50"#);
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [],
+                    "data": [{"row": ["audio-clip:1", "audio/pcm", "cGNt"]}]
+                }],
+                "errors": []
+            }));
         })
         .await;
-    let update = server
+    let migrate = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("VoiceRecognitionRun")
-                .body_contains("VoiceSignature")
-                .body_contains("VoiceSample")
-                .body_contains("HAS_VOICE_RECOGNITION_RUN")
-                .body_contains("HAS_VOICE_VECTOR")
-                .body_contains("qdrant:voices:point-1")
-                .body_contains("sensation:audio:1")
-                .body_contains("\"kind\":\"voice_identity\"")
-                .body_contains("\"how\":\"I don't think I recognize this voice.\"")
-                .body_contains("fundamental_frequency")
-                .body_contains("quality_score")
-                .body_contains("\"model\":\"voxudio\"");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+                .body_contains("UNWIND $moved")
+                .body_contains("REMOVE n.base64")
+                .body_contains(format!(r#""hash":"{hash}""#))
+                .body_contains(r#""id":"audio-clip:1""#);
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": [1]}]}],
+                "errors": []
+            }));
         })
         .await;
+    let (root, blobs) = temp_blob_store();
+    let graph = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into());
 
-    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_voice_recognition(
-            &GraphVoiceClip {
-                id: "audio:1".into(),
-                clip: psyche::AudioClip {
-                    mime: "audio/pcm;format=s16le;rate=16000".into(),
-                    base64: "AAA=".into(),
-                    sample_rate: 16000,
-                    channels: 1,
-                    transcript: None,
-                    captured_at: Some("2026-05-05T12:34:56Z".into()),
-                },
-                occurred_at: Some("2026-05-05T12:34:57Z".into()),
-                sensation_id: Some("sensation:audio:1".into()),
-            },
-            "voxudio",
-            &GraphVoiceRecognition {
-                signature: GraphVoiceSignature {
-                    user_id: "speaker:1".into(),
-                    fundamental_frequency: 150.0,
-                    frequency_range: (100.0, 300.0),
-                    formant_frequencies: vec![800.0, 1200.0, 2500.0],
-                    speech_rate: 4.5,
-                    mfcc_signature: vec![0.1, 0.2],
-                    spectral_centroid: 1500.0,
-                    jitter: 0.5,
-                    shimmer: 3.0,
-                    harmonic_to_noise_ratio: 20.0,
-                    sample_count: 1,
-                    last_updated: Utc::now(),
-                    tags: vec!["voice".into()],
-                },
-                sample: GraphVoiceSample {
-                    id: "voice-sample:1".into(),
-                    user_id: "speaker:1".into(),
-                    duration_ms: 2000,
-                    sample_rate: 16000,
-                    fundamental_frequency: 150.0,
-                    formant_frequencies: vec![800.0, 1200.0, 2500.0],
-                    mfcc: vec![0.1, 0.2],
-                    quality_score: 0.9,
-                    timestamp: Utc::now(),
-                },
-                vector_id: "point-1".into(),
-                embedding_len: 256,
-                recognition: None,
-            },
-        )
+    let payloads = graph.inline_media_payloads(50).await.unwrap();
+    assert_eq!(
+        payloads,
+        [GraphMediaPayload {
+            id: "audio-clip:1".into(),
+            mime: "audio/pcm".into(),
+            base64: "cGNt".into(),
+        }]
+    );
+    assert!(graph.move_media_payloads_to_blobs(&payloads).await.is_err());
+    let moved = graph
+        .with_blob_store(blobs.clone())
+        .move_media_payloads_to_blobs(&payloads)
         .await
         .unwrap();
 
-    constraint.assert_async().await;
-    update.assert_async().await;
+    assert_eq!(moved, 1);
+    assert_eq!(blobs.get(&hash).await.unwrap().unwrap(), b"pcm");
+    list.assert_async().await;
+    migrate.assert_hits_async(1).await;
+    std::fs::remove_dir_all(root).unwrap();
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_known_voice_identity_sensation() {
+async fn neo4j_client_loads_faces_and_objects_in_a_frame() {
     let server = MockServer::start_async().await;
-    let constraint = server
+    let query = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CONTAINS_FACE|CONTAINS_OBJECT")
+                .body_contains("PART_OF_TRACK")
+                .body_contains(r#""image_id":"image:1""#);
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [],
+                    "data": [
+                        {"row": ["face:1", "face", "Travis", "face-track:1", 0.5, 0.1, 0.2, 0.3]},
+                        {"row": ["object:1", "object", "", null, 0.1, 0.6, 0.1, 0.1]}
+                    ]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+
+    let regions = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .image_regions("image:1")
+        .await
+        .unwrap();
+
+    assert_eq!(regions.len(), 2);
+    assert_eq!(regions[0].name.as_deref(), Some("Travis"));
+    assert_eq!(regions[0].track_id.as_deref(), Some("face-track:1"));
+    assert!((regions[0].bbox.height - 0.3).abs() < 1e-6);
+    assert_eq!(regions[1].kind, "object");
+    assert_eq!(regions[1].name, None);
+    query.assert_async().await;
+}
+
+#[tokio::test]
+async fn neo4j_client_attaches_look_answers_to_their_frame() {
+    let server = MockServer::start_async().await;
+    server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
@@ -3077,179 +5119,153 @@ async fn neo4j_client_attaches_known_voice_identity_sensation() {
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
-    let update = server
+    let commit = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("\"kind\":\"voice_identity\"")
-                .body_contains("\"how\":\"I recognize Anna's voice.\"")
-                .body_contains("\"matched_voice_id\":\"cluster:voice:1\"")
-                .body_contains("\"identity_name\":\"Anna\"")
-                .body_contains("\"nearest_voice_vector_id\":\"known-point\"")
-                .body_contains("MATCHED_VOICE")
-                .body_contains("RECOGNIZED_AS")
-                .body_contains("MATCHED_NEAREST_VECTOR");
+                .body_contains("MERGE (n:GraphNode")
+                .body_contains(r#""kind":"look_at""#)
+                .body_contains("I look closer at Travis: I see a red mug.")
+                .body_contains(r#""question":"What is in their hands?""#)
+                .body_contains(r#""model":"gemma4""#)
+                .body_contains("face:1")
+                .body_contains("sensation:image:1");
             then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
         })
         .await;
+    let frame = GraphImageFrame {
+        id: "image:1".into(),
+        image: ImageData {
+            mime: "image/jpeg".into(),
+            base64: String::new(),
+            captured_at: None,
+            source: None,
+        },
+        occurred_at: None,
+        sensation_id: Some("sensation:image:1".into()),
+    };
 
-    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_voice_recognition(
-            &test_voice_clip(),
-            "voxudio",
-            &test_voice_recognition(Some(psyche::GraphVoiceMatch {
-                voice_id: "cluster:voice:1".into(),
-                identity: Some("Anna".into()),
-                nearest_vector_id: "known-point".into(),
-                score: 0.93,
-            })),
+    let sensation_id = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .attach_look_answer(
+            &frame,
+            &GraphLookAnswer {
+                region: "Travis".into(),
+                bbox: BoundingBox {
+                    x: 0.5,
+                    y: 0.1,
+                    width: 0.2,
+                    height: 0.3,
+                },
+                region_node_id: Some("face:1".into()),
+                question: "What is in their hands?".into(),
+                answer: "I see a red mug.".into(),
+                model: "gemma4".into(),
+            },
         )
         .await
         .unwrap();
 
-    constraint.assert_async().await;
-    update.assert_async().await;
+    assert!(sensation_id.starts_with("sensation:look_at:sha256:"));
+    commit.assert_async().await;
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_unknown_voice_match_sensation() {
+async fn neo4j_client_purge_deletes_blobs_no_node_still_uses() {
     let server = MockServer::start_async().await;
-    let constraint = server
+    let (root, blobs) = temp_blob_store();
+    let clip = blobs.insert(b"clip").await.unwrap();
+    let shared = blobs.insert(b"shared").await.unwrap();
+    let purge = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+                .body_contains("DETACH DELETE node");
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": [2, [clip, shared]]}]}],
+                "errors": []
+            }));
         })
         .await;
-    let update = server
+    let referenced = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("\"kind\":\"voice_identity\"")
-                .body_contains("\"how\":\"I recognize the voice, but I don't know who it is.\"")
-                .body_contains("\"matched_voice_id\":\"cluster:voice:2\"")
-                .body_contains("\"nearest_voice_vector_id\":\"known-point-2\"")
-                .body_contains("MATCHED_VOICE");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+                .body_contains("WHERE n.blob_hash IN $hashes")
+                .body_contains(&clip)
+                .body_contains(&shared);
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": [[shared]]}]}],
+                "errors": []
+            }));
         })
         .await;
 
-    Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_voice_recognition(
-            &test_voice_clip(),
-            "voxudio",
-            &test_voice_recognition(Some(psyche::GraphVoiceMatch {
-                voice_id: "cluster:voice:2".into(),
-                identity: None,
-                nearest_vector_id: "known-point-2".into(),
-                score: 0.91,
-            })),
-        )
+    let deleted = Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
+        .with_blob_store(blobs.clone())
+        .purge_identity(&GraphIdentityPurge {
+            identity_id: "identity:person:anna".into(),
+            audio_clip_ids: vec!["audio:1".into(), "audio:2".into()],
+            ..Default::default()
+        })
         .await
         .unwrap();
 
-    constraint.assert_async().await;
-    update.assert_async().await;
-}
-
-fn test_voice_clip() -> GraphVoiceClip {
-    GraphVoiceClip {
-        id: "audio:1".into(),
-        clip: psyche::AudioClip {
-            mime: "audio/pcm;format=s16le;rate=16000".into(),
-            base64: "AAA=".into(),
-            sample_rate: 16000,
-            channels: 1,
-            transcript: None,
-            captured_at: Some("2026-05-05T12:34:56Z".into()),
-        },
-        occurred_at: Some("2026-05-05T12:34:57Z".into()),
-        sensation_id: Some("sensation:audio:1".into()),
-    }
-}
-
-fn test_voice_recognition(recognition: Option<psyche::GraphVoiceMatch>) -> GraphVoiceRecognition {
-    GraphVoiceRecognition {
-        signature: GraphVoiceSignature {
-            user_id: "speaker:1".into(),
-            fundamental_frequency: 150.0,
-            frequency_range: (100.0, 300.0),
-            formant_frequencies: vec![800.0, 1200.0, 2500.0],
-            speech_rate: 4.5,
-            mfcc_signature: vec![0.1, 0.2],
-            spectral_centroid: 1500.0,
-            jitter: 0.5,
-            shimmer: 3.0,
-            harmonic_to_noise_ratio: 20.0,
-            sample_count: 1,
-            last_updated: Utc::now(),
-            tags: vec!["voice".into()],
-        },
-        sample: GraphVoiceSample {
-            id: "voice-sample:1".into(),
-            user_id: "speaker:1".into(),
-            duration_ms: 2000,
-            sample_rate: 16000,
-            fundamental_frequency: 150.0,
-            formant_frequencies: vec![800.0, 1200.0, 2500.0],
-            mfcc: vec![0.1, 0.2],
-            quality_score: 0.9,
-            timestamp: Utc::now(),
-        },
-        vector_id: "point-1".into(),
-        embedding_len: 256,
-        recognition,
-    }
+    purge.assert_async().await;
+    referenced.assert_async().await;
+    assert_eq!(deleted, 2);
+    assert!(blobs.get(&clip).await.unwrap().is_none());
+    assert_eq!(blobs.get(&shared).await.unwrap().unwrap(), b"shared");
+    std::fs::remove_dir_all(root).unwrap();
 }
 
 #[tokio::test]
-async fn neo4j_client_attaches_skipped_voice_recognition() {
+async fn neo4j_client_redaction_deletes_the_replaced_blob() {
     let server = MockServer::start_async().await;
-    let constraint = server
+    let (root, blobs) = temp_blob_store();
+    let original = blobs.insert(b"face").await.unwrap();
+    let redacted = blob_hash(b"blur");
+    let replace = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+                .body_contains("image.redacted_at")
+                .body_contains(format!(r#""blob_hash":"{redacted}""#));
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": ["image:1", original]}]}],
+                "errors": []
+            }));
         })
         .await;
-    let update = server
+    let referenced = server
         .mock_async(|when, then| {
             when.method(POST)
                 .path("/db/neo4j/tx/commit")
-                .body_contains("VoiceRecognitionRun")
-                .body_contains("HAS_VOICE_RECOGNITION_RUN")
-                .body_contains("PROCESSED_AUDIO")
-                .body_contains("sensation:audio:1")
-                .body_contains("\"status\":\"skipped\"")
-                .body_contains("\"reason\":\"audio clip too short\"")
-                .body_contains("\"model\":\"voxudio\"");
-            then.status(200).body(r#"{"results":[{}],"errors":[]}"#);
+                .body_contains("WHERE n.blob_hash IN $hashes")
+                .body_contains(&original);
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": [{"row": [[]]}]}],
+                "errors": []
+            }));
         })
         .await;
 
     Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into())
-        .attach_skipped_voice_recognition(
-            &GraphVoiceClip {
-                id: "audio:1".into(),
-                clip: psyche::AudioClip {
-                    mime: "audio/pcm;format=s16le;rate=16000".into(),
-                    base64: "AAA=".into(),
-                    sample_rate: 16000,
-                    channels: 1,
-                    transcript: None,
-                    captured_at: Some("2026-05-05T12:34:56Z".into()),
-                },
-                occurred_at: Some("2026-05-05T12:34:57Z".into()),
-                sensation_id: Some("sensation:audio:1".into()),
+        .with_blob_store(blobs.clone())
+        .replace_image_payload(
+            "image:1",
+            &ImageData {
+                mime: "image/jpeg".into(),
+                base64: "Ymx1cg==".into(),
+                captured_at: None,
+                source: None,
             },
-            "voxudio",
-            "audio clip too short",
         )
         .await
         .unwrap();
 
-    constraint.assert_async().await;
-    update.assert_async().await;
+    replace.assert_async().await;
+    referenced.assert_async().await;
+    assert!(blobs.get(&original).await.unwrap().is_none());
+    assert_eq!(blobs.get(&redacted).await.unwrap().unwrap(), b"blur");
+    std::fs::remove_dir_all(root).unwrap();
 }
@@ -0,0 +1,154 @@
+use psyche::{
+    BoundingBox, DummyObjectDetector, ImageData, Letterbox, ObjectDetector, ObjectInfo, YoloConfig,
+    decode_yolo,
+};
+
+fn labels() -> Vec<String> {
+    vec!["person".into(), "cup".into()]
+}
+
+/// Channel-major output for `candidates` of (cx, cy, w, h, person, cup).
+fn channel_major(candidates: &[[f32; 6]]) -> (Vec<f32>, Vec<usize>) {
+    let mut output = Vec::new();
+    for channel in 0..6 {
+        output.extend(candidates.iter().map(|candidate| candidate[channel]));
+    }
+    (output, vec![1, 6, candidates.len()])
+}
+
+fn close(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-4
+}
+
+#[test]
+fn keeps_the_strongest_of_overlapping_boxes_per_class() {
+    let (output, shape) = channel_major(&[
+        [100.0, 100.0, 80.0, 80.0, 0.9, 0.0],
+        [104.0, 100.0, 80.0, 80.0, 0.7, 0.0],
+        [104.0, 100.0, 40.0, 40.0, 0.0, 0.6],
+        [400.0, 400.0, 50.0, 50.0, 0.1, 0.2],
+    ]);
+    let letterbox = Letterbox::new(640, 640, 640);
+
+    let objects = decode_yolo(
+        &output,
+        &shape,
+        &letterbox,
+        &labels(),
+        &YoloConfig::default(),
+    )
+    .unwrap();
+
+    let found: Vec<_> = objects
+        .iter()
+        .map(|object| (object.label.as_deref().unwrap(), object.confidence.unwrap()))
+        .collect();
+    assert_eq!(found, [("person", 0.9), ("cup", 0.6)]);
+    assert_eq!(objects[0].embedding, [0.9, 0.0]);
+}
+
+#[test]
+fn maps_letterboxed_boxes_back_onto_the_image() {
+    // A 1280x640 image fills a 640 input at half scale, padded 160 above.
+    let letterbox = Letterbox::new(1280, 640, 640);
+    assert_eq!((letterbox.pad_x, letterbox.pad_y), (0, 160));
+
+    let (output, shape) = channel_major(&[[320.0, 320.0, 64.0, 32.0, 0.0, 0.8]]);
+    let objects = decode_yolo(
+        &output,
+        &shape,
+        &letterbox,
+        &labels(),
+        &YoloConfig::default(),
+    )
+    .unwrap();
+
+    let bbox = objects[0].bbox.unwrap();
+    assert!(close(bbox.x, 0.45) && close(bbox.width, 0.1));
+    assert!(close(bbox.y, 0.45) && close(bbox.height, 0.1));
+}
+
+#[test]
+fn reads_candidate_major_output_and_rejects_odd_shapes() {
+    let letterbox = Letterbox::new(640, 640, 640);
+    let output = [320.0, 320.0, 64.0, 64.0, 0.3, 0.5];
+
+    let objects = decode_yolo(
+        &output,
+        &[1, 1, 6],
+        &letterbox,
+        &labels(),
+        &YoloConfig::default(),
+    )
+    .unwrap();
+
+    assert_eq!(objects[0].label.as_deref(), Some("cup"));
+    assert!(
+        decode_yolo(
+            &output,
+            &[2, 3],
+            &letterbox,
+            &labels(),
+            &YoloConfig::default()
+        )
+        .is_err()
+    );
+    assert!(
+        decode_yolo(
+            &output,
+            &[1, 1, 6, 1],
+            &letterbox,
+            &labels(),
+            &YoloConfig::default()
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn detections_in_one_frame_get_distinct_ids() {
+    let object = |x| ObjectInfo {
+        label: Some("cup".into()),
+        bbox: Some(BoundingBox {
+            x,
+            y: 0.0,
+            width: 0.1,
+            height: 0.1,
+        }),
+        ..ObjectInfo::default()
+    };
+    let at = "2026-05-05T12:00:00+00:00";
+
+    assert_ne!(
+        object(0.1).observation_id(at),
+        object(0.5).observation_id(at)
+    );
+    assert_eq!(
+        ObjectInfo {
+            label: Some("cup".into()),
+            ..ObjectInfo::default()
+        }
+        .observation_id(at),
+        "object:cup:0:2026-05-05T12:00:00+00:00"
+    );
+    assert!(close(
+        BoundingBox::FULL.iou(&object(0.0).bbox.unwrap()),
+        0.01
+    ));
+}
+
+#[tokio::test]
+async fn dummy_detector_reports_the_whole_image() {
+    let objects = DummyObjectDetector
+        .detect_objects(&ImageData {
+            mime: "image/jpeg".into(),
+            base64: String::new(),
+            captured_at: None,
+            source: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0].bbox, Some(BoundingBox::FULL));
+}
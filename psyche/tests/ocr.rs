@@ -0,0 +1,135 @@
+use psyche::{
+    BoundingBox, DummyTextRecognizer, ImageData, OcrConfig, TextBlock, TextRecognizer, decode_ctc,
+    distinct_text_blocks, find_text_regions, parse_ocr_dictionary,
+};
+
+fn dictionary() -> Vec<String> {
+    ["E", "X", "I", "T"].map(String::from).to_vec()
+}
+
+/// Steps of one-hot-ish CTC output over blank plus `dictionary()`.
+fn steps(classes: &[(usize, f32)]) -> Vec<f32> {
+    let mut output = Vec::new();
+    for &(class, probability) in classes {
+        let mut step = vec![(1.0 - probability) / 4.0; 5];
+        step[class] = probability;
+        output.extend(step);
+    }
+    output
+}
+
+fn block(text: &str, confidence: f32) -> TextBlock {
+    TextBlock {
+        text: text.into(),
+        confidence: Some(confidence),
+        ..TextBlock::default()
+    }
+}
+
+#[test]
+fn finds_text_regions_in_reading_order() {
+    let (width, height) = (40, 20);
+    let mut map = vec![0.0; width * height];
+    let mut paint = |xs: std::ops::Range<usize>, ys: std::ops::Range<usize>, value| {
+        for y in ys {
+            for x in xs.clone() {
+                map[y * width + x] = value;
+            }
+        }
+    };
+    paint(22..38, 2..6, 0.9);
+    paint(2..18, 3..7, 0.8);
+    paint(2..30, 12..16, 0.95);
+    // Too faint on average, and too thin, to be text.
+    paint(2..30, 18..20, 0.4);
+    paint(35..36, 10..18, 0.9);
+
+    let regions = find_text_regions(&map, width, height, &OcrConfig::default());
+
+    let lefts: Vec<f32> = regions.iter().map(|(bbox, _)| bbox.x).collect();
+    assert_eq!(regions.len(), 3);
+    assert!(lefts[0] > 0.4 && lefts[1] == 0.0 && lefts[2] == 0.0);
+    assert!(regions[2].0.y > 0.4);
+    assert!((regions[2].1 - 0.95).abs() < 1e-6);
+    // Regions are grown back out past the detected pixels.
+    assert!(regions[2].0.width > 28.0 / 40.0);
+    assert!(find_text_regions(&map, width, height + 1, &OcrConfig::default()).is_empty());
+}
+
+#[test]
+fn decodes_ctc_output_collapsing_repeats_and_blanks() {
+    // E E _ X I _ I T
+    let output = steps(&[
+        (1, 0.9),
+        (1, 0.8),
+        (0, 0.9),
+        (2, 0.7),
+        (3, 0.9),
+        (0, 0.9),
+        (3, 0.9),
+        (4, 0.6),
+    ]);
+
+    let (text, confidence) = decode_ctc(&output, &[1, 8, 5], &dictionary()).unwrap();
+
+    assert_eq!(text, "EXIIT");
+    assert!((confidence - (0.9 + 0.7 + 0.9 + 0.9 + 0.6) / 5.0).abs() < 1e-6);
+    assert_eq!(
+        decode_ctc(&steps(&[(0, 0.9)]), &[1, 5], &dictionary()).unwrap(),
+        (String::new(), 0.0)
+    );
+}
+
+#[test]
+fn rejects_ctc_output_not_matching_the_dictionary() {
+    let output = steps(&[(1, 0.9), (2, 0.9)]);
+
+    assert!(decode_ctc(&output, &[1, 2, 5], &dictionary()[..3]).is_err());
+    assert!(decode_ctc(&output, &[1, 3, 5], &dictionary()).is_err());
+    assert!(decode_ctc(&output, &[1, 1, 2, 5], &dictionary()).is_err());
+}
+
+#[test]
+fn dictionaries_gain_the_space_class() {
+    assert_eq!(parse_ocr_dictionary("a\r\nb\n\n"), ["a", "b", " "]);
+    assert_eq!(parse_ocr_dictionary("a\n \n").len(), 2);
+}
+
+#[test]
+fn keeps_the_most_confident_reading_of_each_text() {
+    let blocks = distinct_text_blocks(vec![
+        block("Fire exit", 0.6),
+        block("  ", 1.0),
+        block("Room 12", 0.8),
+        block("FIRE  EXIT", 0.9),
+    ]);
+
+    let texts: Vec<&str> = blocks.iter().map(|block| block.text.as_str()).collect();
+    assert_eq!(texts, ["FIRE  EXIT", "Room 12"]);
+    assert_eq!(blocks[0].text_id(), block("fire exit", 0.1).text_id());
+    assert_ne!(blocks[0].text_id(), blocks[1].text_id());
+}
+
+#[tokio::test]
+async fn dummy_recognizer_stacks_its_lines() {
+    let blocks = DummyTextRecognizer::new(["EXIT", "Room 12"])
+        .recognize_text(&ImageData {
+            mime: "image/jpeg".into(),
+            base64: String::new(),
+            captured_at: None,
+            source: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(
+        blocks[1].bbox,
+        Some(BoundingBox {
+            x: 0.0,
+            y: 0.5,
+            width: 1.0,
+            height: 0.5,
+        })
+    );
+}
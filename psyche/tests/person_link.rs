@@ -0,0 +1,221 @@
+use chrono::{DateTime, Duration, Utc};
+use httpmock::{Method::POST, MockServer};
+use psyche::{
+    GraphPerson, GraphPersonCandidate, GraphPersonCoOccurrence, GraphPersonRecognition,
+    Neo4jClient, PersonLinkConfig, PersonLinker, PersonNames, person_co_occurrences,
+    strongest_person_pairs,
+};
+use serde_json::json;
+
+fn pair(face: &str, voice: &str, windows: u32) -> GraphPersonCandidate {
+    GraphPersonCandidate {
+        face_id: face.into(),
+        voice_id: voice.into(),
+        windows,
+    }
+}
+
+#[test]
+fn each_face_and_voice_keeps_its_most_frequent_partner() {
+    let pairs = strongest_person_pairs(vec![
+        pair("face:bob", "voice:anna", 4),
+        pair("face:anna", "voice:anna", 9),
+        pair("face:anna", "voice:bob", 5),
+        pair("face:bob", "voice:bob", 3),
+    ]);
+
+    assert_eq!(
+        pairs,
+        vec![
+            pair("face:anna", "voice:anna", 9),
+            pair("face:bob", "voice:bob", 3)
+        ]
+    );
+}
+
+#[test]
+fn no_candidates_link_nobody() {
+    assert!(strongest_person_pairs(Vec::new()).is_empty());
+}
+
+fn recognition(cluster_id: &str, voice: bool, at_ms: i64) -> GraphPersonRecognition {
+    GraphPersonRecognition {
+        cluster_id: cluster_id.into(),
+        voice,
+        at_ms,
+    }
+}
+
+#[test]
+fn co_occurrences_count_each_voice_window_once() {
+    let recognitions = [
+        recognition("face:anna", false, 9_000),
+        recognition("voice:anna", true, 10_500),
+        recognition("voice:anna", true, 11_000),
+        recognition("face:anna", false, 40_000),
+        recognition("voice:anna", true, 44_999),
+        recognition("voice:bob", true, 60_000),
+    ];
+
+    let pairs = person_co_occurrences(&recognitions, 5_000, 0);
+
+    assert_eq!(
+        pairs,
+        vec![GraphPersonCoOccurrence {
+            face_id: "face:anna".into(),
+            voice_id: "voice:anna".into(),
+            windows: vec![2, 8],
+        }]
+    );
+}
+
+#[test]
+fn co_occurrences_skip_pairs_counted_by_an_earlier_run() {
+    let recognitions = [
+        recognition("face:anna", false, 9_000),
+        recognition("voice:anna", true, 10_000),
+        recognition("face:bob", false, 18_000),
+        recognition("voice:bob", true, 21_000),
+    ];
+
+    let pairs = person_co_occurrences(&recognitions, 5_000, 20_000);
+
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].face_id, "face:bob");
+    assert_eq!(pairs[0].windows, vec![4]);
+}
+
+#[tokio::test]
+async fn linker_tallies_new_recognitions_and_skips_faces_and_voices_of_different_people() {
+    let server = MockServer::start_async().await;
+    let now = DateTime::parse_from_rfc3339("2026-05-05T12:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let at = |offset_ms: i64| (now - Duration::hours(1)).timestamp_millis() + offset_ms;
+    let progress = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("RETURN progress.through");
+            then.status(200).json_body(json!({
+                "results": [{"columns": [], "data": []}],
+                "errors": []
+            }));
+        })
+        .await;
+    let recognitions = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("RECOGNIZED_AS")
+                .body_contains(r#""since":"2026-04-28T11:59:55+00:00""#)
+                .body_contains(r#""until":"2026-05-05T11:59:55+00:00""#);
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [],
+                    "data": [
+                        {"row": ["face:anna", false, at(0)]},
+                        {"row": ["voice:bob", true, at(2_000)]},
+                        {"row": ["face:anna", false, at(60_000)]},
+                        {"row": ["voice:bob", true, at(61_000)]}
+                    ]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+    let constraint = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("CREATE CONSTRAINT pete_graph_node_id");
+            then.status(200)
+                .json_body(json!({"results": [], "errors": []}));
+        })
+        .await;
+    let windows = [at(2_000) / 5_000, at(61_000) / 5_000];
+    let record = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("UNWIND $pairs AS pair")
+                .body_contains(format!(r#""windows":[{},{}]"#, windows[0], windows[1]))
+                .body_contains(r#""through":"2026-05-05T11:59:55+00:00""#);
+            then.status(200)
+                .json_body(json!({"results": [], "errors": []}));
+        })
+        .await;
+    let candidates = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("WHERE link.windows >= $min_windows")
+                .body_contains(r#""min_windows":2"#);
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [],
+                    "data": [{"row": ["face:anna", "voice:bob", 2]}]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+    let people = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/db/neo4j/tx/commit")
+                .body_contains("PART_OF_PERSON")
+                .body_contains("RETURN face_person.id, voice_person.id");
+            then.status(200).json_body(json!({
+                "results": [{
+                    "columns": [],
+                    "data": [{"row": ["person:anna", "person:bob"]}]
+                }],
+                "errors": []
+            }));
+        })
+        .await;
+    let linker = PersonLinker::new(PersonLinkConfig {
+        window: std::time::Duration::from_secs(5),
+        min_windows: 2,
+        ..PersonLinkConfig::default()
+    });
+
+    let links = linker
+        .link(
+            &Neo4jClient::new(server.base_url(), "neo4j".into(), "password".into()),
+            now,
+        )
+        .await
+        .unwrap();
+
+    progress.assert_async().await;
+    recognitions.assert_async().await;
+    constraint.assert_async().await;
+    record.assert_async().await;
+    candidates.assert_async().await;
+    people.assert_async().await;
+    assert!(links.is_empty());
+}
+
+#[test]
+fn unnamed_people_keep_their_stored_ordinal() {
+    let person = |id: &str, name: Option<&str>, ordinal| GraphPerson {
+        person_id: id.into(),
+        name: name.map(Into::into),
+        face_ids: Vec::new(),
+        voice_ids: Vec::new(),
+        ordinal,
+    };
+    let names = PersonNames::new(&[
+        person("person:1", None, None),
+        person("person:2", Some("Anna"), Some(1)),
+        person("person:3", Some(" "), Some(3)),
+    ]);
+
+    assert_eq!(names.name("person:1", None), "unknown person 4");
+    assert_eq!(names.name("person:2", None), "Anna");
+    assert_eq!(names.name("person:3", None), "unknown person 3");
+    assert_eq!(names.name("person:3", Some("Bob")), "Bob");
+    assert_eq!(names.name("person:new", None), "unknown person");
+}
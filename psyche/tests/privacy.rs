@@ -0,0 +1,70 @@
+use psyche::{BoundingBox, OptOutAction, PrivacyConfig, pixelate};
+
+#[test]
+fn pixelates_only_the_region_into_average_blocks() {
+    // 4x2 RGB image whose right half is a face.
+    let mut pixels = vec![
+        1, 1, 1, 2, 2, 2, 10, 20, 30, 30, 40, 50, //
+        3, 3, 3, 4, 4, 4, 50, 60, 70, 70, 80, 90,
+    ];
+    let right_half = BoundingBox {
+        x: 0.5,
+        y: 0.0,
+        width: 0.5,
+        height: 1.0,
+    };
+
+    pixelate(&mut pixels, 4, 2, 3, &right_half, 1);
+
+    assert_eq!(&pixels[..6], &[1, 1, 1, 2, 2, 2]);
+    assert_eq!(&pixels[12..18], &[3, 3, 3, 4, 4, 4]);
+    for pixel in [
+        &pixels[6..9],
+        &pixels[9..12],
+        &pixels[18..21],
+        &pixels[21..],
+    ] {
+        assert_eq!(pixel, &[40, 50, 60]);
+    }
+}
+
+#[test]
+fn ignores_regions_outside_the_frame_and_short_buffers() {
+    let original: Vec<u8> = (0..16).collect();
+    let mut pixels = original.clone();
+    let outside = BoundingBox {
+        x: 1.5,
+        y: 0.0,
+        width: 0.5,
+        height: 1.0,
+    };
+    let whole = BoundingBox {
+        x: 0.0,
+        y: 0.0,
+        width: 1.0,
+        height: 1.0,
+    };
+
+    pixelate(&mut pixels, 4, 4, 1, &outside, 2);
+    pixelate(&mut pixels, 4, 5, 1, &whole, 2);
+
+    assert_eq!(pixels, original);
+}
+
+#[test]
+fn parses_opt_out_actions() {
+    assert_eq!(
+        " Blur ".parse::<OptOutAction>().unwrap(),
+        OptOutAction::Blur
+    );
+    assert_eq!(
+        "discard".parse::<OptOutAction>().unwrap(),
+        OptOutAction::Discard
+    );
+    assert!("forget".parse::<OptOutAction>().is_err());
+    assert_eq!(
+        PrivacyConfig::default().opt_out_action,
+        OptOutAction::Discard
+    );
+    assert!(!PrivacyConfig::default().strict);
+}
@@ -0,0 +1,51 @@
+use psyche::{Affect, Prosody, ProsodyMap};
+
+#[test]
+fn arousal_speeds_up_and_raises_voice() {
+    let map = ProsodyMap::default();
+    let calm = map.for_affect(Affect::new(0.0, -1.0));
+    let aroused = map.for_affect(Affect::new(0.0, 1.0));
+    assert!(aroused.rate > 1.0 && calm.rate < 1.0);
+    assert!(aroused.pitch > calm.pitch);
+    assert!(aroused.volume > calm.volume);
+    assert_eq!(aroused.style.as_deref(), Some("surprised"));
+    assert_eq!(calm.style.as_deref(), Some("calm"));
+    assert!(map.for_affect(Affect::default()).is_neutral());
+}
+
+#[test]
+fn emoji_map_to_quadrant_styles() {
+    let map = ProsodyMap::default();
+    assert_eq!(map.for_emoji("🤩").style.as_deref(), Some("excited"));
+    assert_eq!(map.for_emoji("🙂").style.as_deref(), Some("cheerful"));
+    assert_eq!(map.for_emoji("😡").style.as_deref(), Some("angry"));
+    assert_eq!(map.for_emoji("😢").style.as_deref(), Some("sad"));
+    assert_eq!(map.for_emoji("❤️"), map.for_emoji("❤"));
+    assert!(map.for_emoji("🦀").is_neutral());
+}
+
+#[test]
+fn json_config_overrides_defaults() {
+    let map = ProsodyMap::from_json(
+        r#"{
+            "rate_gain": 0.5,
+            "emojis": {"🦀": {"valence": 0.9, "arousal": 0.9}},
+            "overrides": {"😐": {"rate": 0.8, "voice": "flat"}},
+            "voices": {"excited": "p243"}
+        }"#,
+    )
+    .unwrap();
+    let crab = map.for_emoji("🦀");
+    assert!((crab.rate - 1.45).abs() < 1e-6);
+    assert_eq!(crab.voice.as_deref(), Some("p243"));
+    assert_eq!(
+        map.for_emoji("😐"),
+        Prosody {
+            rate: 0.8,
+            voice: Some("flat".into()),
+            ..Prosody::default()
+        }
+    );
+    assert_eq!(map.pitch_gain, ProsodyMap::default().pitch_gain);
+    assert!(ProsodyMap::from_json("{\"rate_gain\": \"fast\"}").is_err());
+}
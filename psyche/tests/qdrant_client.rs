@@ -302,6 +302,113 @@ async fn store_scene_vector_uses_own_collection_and_links_source_image() {
     upsert_point.assert_async().await;
 }
 
+#[tokio::test]
+async fn scene_vector_loads_a_stored_point() {
+    let server = MockServer::start_async().await;
+    let found = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/collections/scene_vectors/points/point-1");
+            then.status(200).body(
+                r#"{"result":{"id":"point-1","payload":{},"vector":[0.5,0.25]},"status":"ok"}"#,
+            );
+        })
+        .await;
+    let missing = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/collections/scene_vectors/points/point-2");
+            then.status(404).body("{}");
+        })
+        .await;
+    let client = QdrantClient::new(server.base_url());
+
+    let vector = client.scene_vector("point-1").await.unwrap();
+    let absent = client.scene_vector("point-2").await.unwrap();
+
+    assert_eq!(vector, Some(vec![0.5, 0.25]));
+    assert_eq!(absent, None);
+    found.assert_async().await;
+    missing.assert_async().await;
+}
+
+#[tokio::test]
+async fn identity_payloads_are_set_and_cleared() {
+    let server = MockServer::start_async().await;
+    let set = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/collections/faces/points/payload")
+                .query_param("wait", "true")
+                .body_contains(r#""identity_id":"identity:person:john""#)
+                .body_contains(r#""points":["point-1","point-2"]"#);
+            then.status(200)
+                .body(r#"{"result":{"status":"completed"},"status":"ok"}"#);
+        })
+        .await;
+    let cleared = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/collections/voices/points/payload/delete")
+                .query_param("wait", "true")
+                .body_contains(r#""keys":["identity_id","identity_name"]"#)
+                .body_contains(r#""points":["point-3"]"#);
+            then.status(200)
+                .body(r#"{"result":{"status":"completed"},"status":"ok"}"#);
+        })
+        .await;
+    let client = QdrantClient::new(server.base_url());
+    let points = ["point-1".to_string(), "point-2".to_string()];
+
+    client
+        .set_payload(
+            "faces",
+            &points,
+            serde_json::json!({ "identity_id": "identity:person:john", "identity_name": "John" }),
+        )
+        .await
+        .unwrap();
+    client
+        .delete_payload_keys(
+            "voices",
+            &["point-3".to_string()],
+            &["identity_id", "identity_name"],
+        )
+        .await
+        .unwrap();
+    client
+        .set_payload("faces", &[], serde_json::json!({}))
+        .await
+        .unwrap();
+
+    set.assert_hits_async(1).await;
+    cleared.assert_async().await;
+}
+
+#[tokio::test]
+async fn qdrant_client_deletes_points() {
+    let server = MockServer::start_async().await;
+    let deleted = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/collections/faces/points/delete")
+                .query_param("wait", "true")
+                .body_contains(r#""points":["point-1","point-2"]"#);
+            then.status(200)
+                .body(r#"{"result":{"status":"completed"},"status":"ok"}"#);
+        })
+        .await;
+    let client = QdrantClient::new(server.base_url());
+
+    client
+        .delete_points("faces", &["point-1".to_string(), "point-2".to_string()])
+        .await
+        .unwrap();
+    client.delete_points("faces", &[]).await.unwrap();
+
+    deleted.assert_hits_async(1).await;
+}
+
 #[tokio::test]
 async fn store_vector_uses_existing_memory_collection() {
     let server = MockServer::start_async().await;
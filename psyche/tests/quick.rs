@@ -163,6 +163,7 @@ async fn describes_faces_in_first_person() {
         mime: "image/png".into(),
         base64: "zzz".into(),
         captured_at: None,
+        source: None,
     };
     quick
         .observe(Sensation::of(FaceInfo {
@@ -171,6 +172,7 @@ async fn describes_faces_in_first_person() {
             crop,
             embedding: vec![0.1],
             vector_id: None,
+            details: Default::default(),
         }))
         .await;
 
@@ -189,6 +191,7 @@ async fn repeated_faces_are_framed_as_stream_frames() {
             mime: "image/png".into(),
             base64: "zzz".into(),
             captured_at: None,
+            source: None,
         };
         quick
             .observe(Sensation::of(FaceInfo {
@@ -197,6 +200,7 @@ async fn repeated_faces_are_framed_as_stream_frames() {
                 crop,
                 embedding: vec![0.1],
                 vector_id: None,
+                details: Default::default(),
             }))
             .await;
     }
@@ -249,6 +253,7 @@ async fn ignores_raw_image_frames() {
             mime: "image/png".into(),
             base64: "zzz".into(),
             captured_at: None,
+            source: None,
         }))
         .await;
 
@@ -0,0 +1,74 @@
+use psyche::{SceneChangeConfig, SceneFingerprint};
+
+/// A 64×48 frame lit from the left with a dark square at `square_x`.
+fn frame(square_x: usize, noise: u8) -> Vec<u8> {
+    (0..64 * 48)
+        .map(|i| {
+            let (x, y) = (i % 64, i / 64);
+            if (square_x..square_x + 16).contains(&x) && (16..32).contains(&y) {
+                20
+            } else {
+                (230 - x * 2) as u8 ^ (noise * (i % 3) as u8)
+            }
+        })
+        .collect()
+}
+
+fn fingerprint(pixels: &[u8]) -> SceneFingerprint {
+    SceneFingerprint::from_luma(pixels, 64, 48).unwrap()
+}
+
+#[test]
+fn sensor_noise_leaves_a_frame_a_duplicate() {
+    let config = SceneChangeConfig::default();
+    let keyframe = fingerprint(&frame(8, 0));
+
+    let comparison = config.compare(&fingerprint(&frame(8, 1)), &keyframe, None);
+
+    assert!(comparison.duplicate);
+    assert!(comparison.hash_distance <= config.max_hash_distance);
+    assert!(comparison.difference < 0.01);
+}
+
+#[test]
+fn something_moving_makes_a_new_keyframe() {
+    let config = SceneChangeConfig::default();
+    let keyframe = fingerprint(&frame(8, 0));
+
+    let comparison = config.compare(&fingerprint(&frame(40, 0)), &keyframe, None);
+
+    assert!(!comparison.duplicate);
+    assert!(comparison.hash_distance > config.max_hash_distance);
+}
+
+#[test]
+fn dissimilar_scene_vectors_veto_a_duplicate() {
+    let config = SceneChangeConfig::default();
+    let keyframe = fingerprint(&frame(8, 0));
+    let same = fingerprint(&frame(8, 0));
+
+    assert!(config.compare(&same, &keyframe, Some(0.99)).duplicate);
+    assert!(!config.compare(&same, &keyframe, Some(0.8)).duplicate);
+}
+
+#[test]
+fn fingerprints_survive_the_graph_round_trip() {
+    let original = fingerprint(&frame(8, 0));
+
+    let stored =
+        SceneFingerprint::from_stored(&original.hash_hex(), &original.thumbnail_base64()).unwrap();
+
+    assert_eq!(stored, original);
+    assert_eq!(original.hash_hex().len(), 16);
+    assert_eq!(SceneFingerprint::from_stored("zz", ""), None);
+    assert_eq!(SceneFingerprint::from_stored("ff", "AAAA"), None);
+}
+
+#[test]
+fn tiny_and_empty_images() {
+    let tiny = SceneFingerprint::from_luma(&[10, 200, 10, 200], 2, 2).unwrap();
+
+    assert_eq!(tiny.thumbnail.len(), 256);
+    assert_eq!(SceneFingerprint::from_luma(&[], 0, 0), None);
+    assert_eq!(SceneFingerprint::from_luma(&[1, 2], 2, 2), None);
+}
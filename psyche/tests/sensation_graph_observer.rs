@@ -1,10 +1,11 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{TimeZone, Utc};
 use psyche::{
-    AudioClip, BrowserMotion, CombobulationSummary, DeviceOrientation, GeoEmbedding, GeoLoc,
-    GraphStore, Heartbeat, ImageData, Impression, MotionVector, ObjectInfo, Sensation,
-    SensationGraphObserver, SensationObserver, Stimulus, Thought, audio_clip_id, geoloc_content_id,
-    geoloc_vector, image_content_id,
+    AddresseeVerdict, AudioClip, BoundingBox, BrowserMotion, CombobulationSummary,
+    DeviceOrientation, EchoAction, EchoConfig, EchoFilter, GeoEmbedding, GeoLoc, GraphStore,
+    Heartbeat, ImageData, ImageRedactor, Impression, MotionVector, ObjectInfo, Redaction,
+    Sensation, SensationGraphObserver, SensationObserver, Stimulus, TextBlock, Thought,
+    audio_clip_id, geoloc_content_id, geoloc_vector, image_content_id,
 };
 use serde_json::{Value, json};
 use std::sync::{Arc, Mutex};
@@ -353,6 +354,7 @@ async fn merges_duplicate_image_sensations_once() {
         mime: "image/png".into(),
         base64: "zzz".into(),
         captured_at: Some("2026-05-05T12:34:56Z".into()),
+        source: None,
     };
     let expected_id = image_content_id(&image);
     let sensation = Sensation::of(image.clone());
@@ -369,6 +371,92 @@ async fn merges_duplicate_image_sensations_once() {
     assert_eq!(stored[0]["relationships"][0]["type"], "OBSERVED");
 }
 
+/// Redactor that blanks frames marked "face" and drops frames marked
+/// "opted-out".
+struct StubRedactor;
+
+#[async_trait]
+impl ImageRedactor for StubRedactor {
+    async fn redact(&self, image: &ImageData) -> anyhow::Result<Redaction> {
+        Ok(match image.base64.as_str() {
+            "face" => Redaction::Redacted(ImageData {
+                base64: "blurred".into(),
+                ..image.clone()
+            }),
+            "opted-out" => Redaction::Discard,
+            _ => Redaction::Keep,
+        })
+    }
+}
+
+#[tokio::test]
+async fn stores_redacted_image_under_the_original_id() {
+    let graph = Arc::new(MockGraph::default());
+    let observer =
+        SensationGraphObserver::new(graph.clone()).with_image_redactor(Arc::new(StubRedactor));
+    let image = |base64: &str| ImageData {
+        mime: "image/jpeg".into(),
+        base64: base64.into(),
+        captured_at: None,
+        source: None,
+    };
+
+    observer
+        .observe_sensation(&Sensation::of(image("face")))
+        .await;
+    observer
+        .observe_sensation(&Sensation::of(image("opted-out")))
+        .await;
+
+    let stored = graph.0.lock().unwrap();
+    assert_eq!(stored.len(), 1);
+    let node = find_node_by_label(&stored[0], "Image");
+    assert_eq!(node["id"], image_content_id(&image("face")));
+    assert_eq!(node["base64"], "blurred");
+}
+
+/// Redactor that always fails.
+struct FailingRedactor {
+    strict: bool,
+}
+
+#[async_trait]
+impl ImageRedactor for FailingRedactor {
+    async fn redact(&self, _image: &ImageData) -> anyhow::Result<Redaction> {
+        anyhow::bail!("face detector unavailable")
+    }
+
+    fn strict(&self) -> bool {
+        self.strict
+    }
+}
+
+#[tokio::test]
+async fn stores_unredacted_frame_when_redaction_fails_outside_strict_mode() {
+    let image = ImageData {
+        mime: "image/jpeg".into(),
+        base64: "face".into(),
+        captured_at: None,
+        source: None,
+    };
+    for strict in [false, true] {
+        let graph = Arc::new(MockGraph::default());
+        let observer = SensationGraphObserver::new(graph.clone())
+            .with_image_redactor(Arc::new(FailingRedactor { strict }));
+
+        observer
+            .observe_sensation(&Sensation::of(image.clone()))
+            .await;
+
+        let stored = graph.0.lock().unwrap();
+        if strict {
+            assert!(stored.is_empty());
+        } else {
+            assert_eq!(find_node_by_label(&stored[0], "Image")["base64"], "face");
+        }
+    }
+}
+
 #[tokio::test]
 async fn stores_heartbeat_sensation() {
     let graph = Arc::new(MockGraph::default());
@@ -397,6 +485,7 @@ async fn stores_object_sensation_with_embedding_field() {
     let sensation = Sensation::of(ObjectInfo {
         label: Some("mug".into()),
         embedding: vec![0.1, 0.2, 0.3],
+        ..ObjectInfo::default()
     });
 
     observer.observe_sensation(&sensation).await;
@@ -412,6 +501,96 @@ async fn stores_object_sensation_with_embedding_field() {
     );
 }
 
+#[tokio::test]
+async fn links_detected_objects_to_their_source_image() {
+    let graph = Arc::new(MockGraph::default());
+    let observer = SensationGraphObserver::new(graph.clone());
+    let cup = |x| ObjectInfo {
+        label: Some("cup".into()),
+        confidence: Some(0.4),
+        bbox: Some(BoundingBox {
+            x,
+            y: 0.5,
+            width: 0.1,
+            height: 0.2,
+        }),
+        source_image_id: Some("image:1".into()),
+        ..ObjectInfo::default()
+    };
+    let at = Utc.with_ymd_and_hms(2026, 5, 5, 12, 0, 0).unwrap();
+
+    observer
+        .observe_sensation(&Sensation::of_at(cup(0.1), at))
+        .await;
+    observer
+        .observe_sensation(&Sensation::of_at(cup(0.6), at))
+        .await;
+
+    let stored = graph.0.lock().unwrap();
+    assert_eq!(stored.len(), 2);
+    assert_ne!(stored[0]["nodes"][1]["id"], stored[1]["nodes"][1]["id"]);
+    assert_eq!(stored[0]["nodes"][0]["how"], "I think I see a cup.");
+    let number = |field: &str| stored[0]["nodes"][1][field].as_f64().unwrap();
+    assert!((number("confidence") - 0.4).abs() < 1e-6);
+    assert!((number("bbox_x") - 0.1).abs() < 1e-6);
+    assert_eq!(stored[0]["nodes"][2]["label"], "Image");
+    let links: Vec<_> = stored[0]["relationships"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|rel| (rel["from"].as_str().unwrap(), rel["type"].as_str().unwrap()))
+        .collect();
+    assert!(links.contains(&("image:1", "CONTAINS_OBJECT")));
+    assert_eq!(
+        links
+            .iter()
+            .filter(|(_, kind)| *kind == "DERIVED_FROM")
+            .count(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn stores_text_read_in_an_image_once_per_reading() {
+    let graph = Arc::new(MockGraph::default());
+    let observer = SensationGraphObserver::new(graph.clone());
+    let exit = |text: &str| TextBlock {
+        text: text.into(),
+        confidence: Some(0.9),
+        bbox: Some(BoundingBox {
+            x: 0.2,
+            y: 0.1,
+            width: 0.3,
+            height: 0.1,
+        }),
+        source_image_id: Some("image:1".into()),
+    };
+    let at = Utc.with_ymd_and_hms(2026, 5, 5, 12, 0, 0).unwrap();
+
+    observer
+        .observe_sensation(&Sensation::of_at(exit("Fire  EXIT"), at))
+        .await;
+    observer
+        .observe_sensation(&Sensation::of_at(exit("fire exit"), at))
+        .await;
+
+    let stored = graph.0.lock().unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0]["nodes"][0]["kind"], "text");
+    assert_eq!(stored[0]["nodes"][0]["how"], "I read \"Fire EXIT\".");
+    assert_eq!(stored[0]["nodes"][1]["label"], "TextBlock");
+    assert_eq!(stored[0]["nodes"][1]["id"], exit("FIRE EXIT").text_id());
+    assert_eq!(stored[0]["nodes"][1]["normalized_text"], "fire exit");
+    let contains = stored[0]["relationships"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|rel| rel["type"] == "CONTAINS_TEXT")
+        .unwrap();
+    assert_eq!(contains["from"], "image:1");
+    assert!((contains["bbox_width"].as_f64().unwrap() - 0.3).abs() < 1e-6);
+}
+
 #[tokio::test]
 async fn stores_json_sensation_payload() {
     let graph = Arc::new(MockGraph::default());
@@ -444,3 +623,70 @@ async fn stores_unknown_sensation_marker() {
     assert_eq!(stored[0]["nodes"][1]["label"], "UnknownSensation");
     assert_eq!(stored[0]["relationships"][0]["type"], "OBSERVED");
 }
+
+#[tokio::test]
+async fn addressee_verdict_annotates_heard_speech_node() {
+    let graph = Arc::new(MockGraph::default());
+    let observer = SensationGraphObserver::new(graph.clone());
+    let verdict = AddresseeVerdict {
+        sensation_id: "sensation:heard_user_voice:abc:2026-05-07T12:00:00+00:00".into(),
+        addressed: true,
+        confidence: 0.8,
+        reasons: vec!["name".into()],
+    };
+
+    observer
+        .observe_sensation(&Sensation::of(verdict.clone()))
+        .await;
+    observer.observe_sensation(&Sensation::of(verdict)).await;
+
+    let stored = graph.0.lock().unwrap();
+    assert_eq!(stored.len(), 1);
+    let node = find_node_by_id(
+        &stored[0],
+        "sensation:heard_user_voice:abc:2026-05-07T12:00:00+00:00",
+    );
+    assert_eq!(node["addressed"], true);
+    assert_eq!(node["addressee_reasons"], json!(["name"]));
+    assert!(stored[0]["relationships"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn echo_decision_links_to_pete_speech_and_own_voice() {
+    let graph = Arc::new(MockGraph::default());
+    let observer = SensationGraphObserver::new(graph.clone());
+    let started = Utc::now();
+    let heard_at = started + chrono::Duration::seconds(1);
+    let filter = EchoFilter::new(EchoConfig {
+        action: EchoAction::Reclassify,
+        ..EchoConfig::default()
+    });
+    filter.speaking("Nice to meet you.", started);
+    let verdict = filter.check("nice to meet you", &[], heard_at);
+
+    observer
+        .observe_sensation(&Sensation::StartedSpeaking {
+            text: "Nice to meet you.".into(),
+            occurred_at: started,
+        })
+        .await;
+    observer
+        .observe_sensation(&Sensation::of_at(verdict.clone(), heard_at))
+        .await;
+
+    let stored = graph.0.lock().unwrap();
+    assert_eq!(stored.len(), 2);
+    let spoken_id = stored[0]["nodes"][0]["id"].as_str().unwrap();
+    let decision_id = format!("echo:{}", verdict.sensation_id);
+    let decision = find_node_by_id(&stored[1], &decision_id);
+    assert_eq!(decision["label"], "EchoDecision");
+    assert_eq!(decision["echo"], true);
+    assert_eq!(decision["action"], "reclassify");
+    find_relationship(&stored[1], "COMPARED_WITH", &decision_id, spoken_id);
+    find_relationship(
+        &stored[1],
+        "RECLASSIFIED_AS",
+        &decision_id,
+        &Sensation::heard_own_voice_at("nice to meet you", heard_at).id(),
+    );
+}
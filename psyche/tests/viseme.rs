@@ -0,0 +1,59 @@
+use psyche::{PhonemeTiming, phoneme_viseme, visemes_from_phonemes, visemes_from_text};
+
+fn phoneme(phoneme: &str, start_ms: u32, end_ms: u32) -> PhonemeTiming {
+    PhonemeTiming {
+        phoneme: phoneme.into(),
+        start_ms,
+        end_ms,
+    }
+}
+
+#[test]
+fn maps_arpabet_and_ipa_to_the_same_shapes() {
+    assert_eq!(phoneme_viseme("B"), Some("PP"));
+    assert_eq!(phoneme_viseme("AH0"), Some("aa"));
+    assert_eq!(phoneme_viseme("ə"), Some("aa"));
+    assert_eq!(phoneme_viseme("DH"), phoneme_viseme("ð"));
+    assert_eq!(phoneme_viseme("ˈiː"), Some("I"));
+    assert_eq!(phoneme_viseme("sp"), Some("sil"));
+    assert_eq!(phoneme_viseme("HH"), None);
+}
+
+#[test]
+fn phoneme_timelines_merge_repeated_shapes() {
+    let cues = visemes_from_phonemes(&[
+        phoneme("HH", 0, 50),
+        phoneme("M", 50, 100),
+        phoneme("P", 100, 150),
+        phoneme("HH", 150, 180),
+        phoneme("IY1", 180, 300),
+    ]);
+    let shapes: Vec<_> = cues
+        .iter()
+        .map(|cue| (cue.viseme.as_str(), cue.start_ms, cue.end_ms))
+        .collect();
+
+    assert_eq!(shapes, [("sil", 0, 50), ("PP", 50, 180), ("I", 180, 300)]);
+}
+
+#[test]
+fn text_timelines_cover_the_audio_without_gaps() {
+    let cues = visemes_from_text("Well, thank you. The fish was lovely!", 2_400);
+
+    assert_eq!(cues.first().unwrap().start_ms, 0);
+    assert_eq!(cues.last().unwrap().end_ms, 2_400);
+    assert_ne!(cues.last().unwrap().viseme, "sil");
+    for pair in cues.windows(2) {
+        assert_eq!(pair[0].end_ms, pair[1].start_ms);
+        assert_ne!(pair[0].viseme, pair[1].viseme);
+    }
+    for shape in ["U", "TH", "FF", "CH", "sil"] {
+        assert!(cues.iter().any(|cue| cue.viseme == shape), "{shape}");
+    }
+}
+
+#[test]
+fn text_timelines_need_sounds_and_time() {
+    assert!(visemes_from_text("...", 1_000).is_empty());
+    assert!(visemes_from_text("Hello", 0).is_empty());
+}
@@ -33,6 +33,7 @@ async fn captions_image() {
         mime: "image/png".into(),
         base64: "zzz".into(),
         captured_at: None,
+        source: None,
     })
     .await;
     let out = wit.tick().await;
@@ -49,6 +50,7 @@ async fn tick_does_not_consume_latest_image() {
         mime: "image/png".into(),
         base64: "zzz".into(),
         captured_at: None,
+        source: None,
     })
     .await;
 
@@ -64,6 +66,7 @@ async fn caption_stimulus_uses_image_capture_time() {
         mime: "image/png".into(),
         base64: "zzz".into(),
         captured_at: Some(captured_at.into()),
+        source: None,
     })
     .await;
 
@@ -83,6 +86,7 @@ async fn caption_prompt_explains_own_vision() {
         mime: "image/png".into(),
         base64: "zzz".into(),
         captured_at: None,
+        source: None,
     })
     .await;
 
@@ -1,5 +1,5 @@
 pub use psyche::{
-    BrowserMotion, ConversationEntry, GeoLoc, Thought, WillTypeScriptExecution,
+    BrowserMotion, ConversationEntry, GeoLoc, Thought, VisemeCue, WillTypeScriptExecution,
     WillTypeScriptResult, WitReport,
 };
 use serde::{Deserialize, Serialize};
@@ -25,6 +25,9 @@ pub enum WsPayload {
         words: String,
         #[serde(skip_serializing_if = "Option::is_none")]
         audio: Option<String>,
+        /// Mouth shapes to show while the audio plays.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        visemes: Option<Vec<VisemeCue>>,
     },
     /// Change in emotional expression as an emoji.
     Emote(String),
@@ -45,9 +48,22 @@ pub enum WsPayload {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         at: Option<String>,
     },
+    /// Provisional transcript of speech the user has not finished yet.
+    ///
+    /// `stable` marks a hypothesis that survived a re-decode unchanged. The
+    /// final wording still arrives as a [`ConversationEntry`](Self::ConversationEntry).
+    Interim {
+        text: String,
+        stable: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        at: Option<String>,
+    },
     See {
         data: String,
         at: Option<String>,
+        /// Camera or stream the frame came from, for clients with several.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Option<String>,
     },
     Hear {
         data: AudioData,
@@ -129,6 +145,12 @@ interface AudioData {{
 
 type SpeechPlaybackStatus = "Started" | "Finished" | "Interrupted";
 
+interface VisemeCue {{
+  viseme: string;
+  start_ms: number;
+  end_ms: number;
+}}
+
 interface WitReport {{
   name: string;
   prompt: string;
@@ -167,13 +189,14 @@ type {} = {};"#,
     }
 
     fn inline() -> String {
-        r#"{ type: "Say"; data: { words: string; audio?: string | null } }
+        r#"{ type: "Say"; data: { words: string; audio?: string | null; visemes?: VisemeCue[] | null } }
   | { type: "Emote"; data: string }
   | { type: "Think"; data: WitReport }
   | { type: "Text"; data: { text: string; at?: string } }
   | { type: "Echo"; data: { text: string; at?: string } }
   | { type: "SpeechPlayback"; data: { text: string; status: SpeechPlaybackStatus; at?: string } }
-  | { type: "See"; data: { data: string; at?: string | null } }
+  | { type: "Interim"; data: { text: string; stable: boolean; at?: string } }
+  | { type: "See"; data: { data: string; at?: string | null; source?: string } }
   | { type: "Hear"; data: { data: AudioData; at?: string | null } }
   | { type: "Geolocate"; data: { data: GeoLoc; at?: string } }
   | { type: "Motion"; data: { data: BrowserMotion; at?: string } }